@@ -0,0 +1,480 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use duration_unit_lookup::footprint;
+use object::{Object, ObjectSymbol, RelocationTarget, SymbolKind};
+
+const USAGE: &str = "\
+Write the disassembly of every technique's lookup routines to snapshot files.
+
+USAGE:
+    asm-snapshot [OPTIONS]
+
+The disassembly is read from the benchmark executable built by
+`cargo bench --no-run`. Each routine is written to
+`{out}/{technique}/{routine}.s` and a summary of the instruction mix of every
+routine is written to `{out}/summary.txt` (and printed). Addresses are
+normalized away, so that the snapshots only change when the code does.
+
+Every function that these routines call or jump to is written too, as long as
+it belongs to the technique (see `Footprint::code`) and wasn't inlined. e.g.,
+the C functions of `by-gencdfa1` are written to
+`{out}/by-gencdfa1/gencdfa1_find.s`.
+
+Routines that aren't in the executable (e.g., because no benchmark calls
+them) are skipped.
+
+OPTIONS:
+    --binary <path>    The executable to disassemble.
+                       [default: the newest designator-bench executable]
+    --out <dir>        Where to write the snapshots. [default: asm]
+    -h, --help         Print this help.
+";
+
+/// The routines of each technique that are disassembled.
+const ROUTINES: &[&str] =
+    &["lookup", "find", "lookup_ignore_ascii_case", "find_ignore_ascii_case"];
+
+/// The instruction mix of a single routine.
+#[derive(Debug, Default)]
+struct Summary {
+    /// The size of the routine's machine code, in bytes.
+    bytes: u64,
+    instructions: usize,
+    /// Conditional jumps.
+    branches: usize,
+    /// Unconditional direct jumps.
+    jumps: usize,
+    /// Indirect jumps, which is what jump tables compile to.
+    jump_tables: usize,
+    calls: usize,
+    /// Instructions that read memory. (Excluding `lea`, which doesn't.)
+    loads: usize,
+    /// Instructions that write memory.
+    stores: usize,
+    /// Any of the `mov` family of instructions, e.g., `movzx`.
+    movs: usize,
+}
+
+fn main() -> anyhow::Result<()> {
+    use lexopt::Arg;
+
+    anyhow::ensure!(
+        cfg!(target_arch = "x86_64"),
+        "only x86-64 is supported, since the instruction mix is computed \
+         from Intel syntax assembly",
+    );
+
+    let mut binary = None;
+    let mut out_dir = PathBuf::from("asm");
+    let mut parser = lexopt::Parser::from_env();
+    while let Some(arg) = parser.next()? {
+        match arg {
+            Arg::Short('h') | Arg::Long("help") => {
+                print!("{USAGE}");
+                return Ok(());
+            }
+            Arg::Long("binary") => binary = Some(parser.value()?.into()),
+            Arg::Long("out") => out_dir = parser.value()?.into(),
+            _ => return Err(arg.unexpected().into()),
+        }
+    }
+    let binary = match binary {
+        Some(binary) => binary,
+        None => find_bench_binary()?,
+    };
+    let symbols = Symbols::read(&binary)?;
+
+    let mut summaries = vec![];
+    for footprint in footprint::all() {
+        let dir = out_dir.join(footprint.name);
+        std::fs::create_dir_all(&dir)?;
+        // The functions to disassemble, as (symbol, snapshot name) pairs.
+        // Functions called from the ones disassembled are added as they are
+        // found.
+        let mut queue: Vec<(String, String)> = ROUTINES
+            .iter()
+            .map(|&routine| {
+                (format!("{}::{routine}", footprint.module), routine.into())
+            })
+            .collect();
+        let mut written = BTreeSet::new();
+        let mut i = 0;
+        while let Some((name, routine)) = queue.get(i).cloned() {
+            i += 1;
+            let Some(&(address, size)) = symbols.functions.get(&name) else {
+                continue;
+            };
+            let asm = disassemble(&binary, &symbols, &name, address, size)?;
+            for callee in callees(&asm) {
+                if footprint.contains(callee)
+                    && !queue.iter().any(|(name, _)| name == callee)
+                {
+                    queue.push((callee.to_string(), snapshot_name(callee)));
+                }
+            }
+            let mut summary = summarize(&asm);
+            summary.bytes = size;
+            std::fs::write(dir.join(format!("{routine}.s")), asm)?;
+            summaries.push((format!("{}/{routine}", footprint.name), summary));
+            written.insert(format!("{routine}.s"));
+        }
+        // Remove stale snapshots of routines that have disappeared.
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let Some(file_name) = path.file_name().and_then(|n| n.to_str())
+            else {
+                continue;
+            };
+            if file_name.ends_with(".s") && !written.contains(file_name) {
+                std::fs::remove_file(&path)?;
+            }
+        }
+    }
+    anyhow::ensure!(
+        !summaries.is_empty(),
+        "no lookup routines found in {}",
+        binary.display(),
+    );
+
+    let mut table = vec![];
+    write_summaries(&summaries, &mut table)?;
+    std::fs::write(out_dir.join("summary.txt"), &table)?;
+    std::io::stdout().write_all(&table)?;
+    Ok(())
+}
+
+/// Returns the newest benchmark executable built by Cargo.
+fn find_bench_binary() -> anyhow::Result<PathBuf> {
+    let target = match std::env::var_os("CARGO_TARGET_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from("target"),
+    };
+    let deps = target.join("release").join("deps");
+    let mut newest = None;
+    for entry in std::fs::read_dir(&deps)
+        .map_err(|e| anyhow::anyhow!("{}: {e}", deps.display()))?
+    {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !name.starts_with("designator_bench-") || name.contains('.') {
+            continue;
+        }
+        let modified = entry.metadata()?.modified()?;
+        if newest.as_ref().is_none_or(|&(time, _)| modified > time) {
+            newest = Some((modified, entry.path()));
+        }
+    }
+    match newest {
+        Some((_, path)) => Ok(path),
+        None => anyhow::bail!(
+            "no benchmark executable found in {} \
+             (run `cargo bench --no-run` first)",
+            deps.display(),
+        ),
+    }
+}
+
+/// The functions in an executable.
+struct Symbols {
+    /// The address and size of every function, keyed by its demangled path
+    /// (without the hash).
+    functions: BTreeMap<String, (u64, u64)>,
+    /// The demangled path of the function that each slot in the global
+    /// offset table points to, keyed by the address of the slot.
+    ///
+    /// Calls from Rust into C (e.g., to `gencdfa1_find`) are indirect calls
+    /// through this table, so this is the only way to tell what they call.
+    got: BTreeMap<u64, String>,
+}
+
+impl Symbols {
+    fn read(binary: &Path) -> anyhow::Result<Symbols> {
+        let data = std::fs::read(binary)
+            .map_err(|e| anyhow::anyhow!("{}: {e}", binary.display()))?;
+        let file = object::File::parse(&*data)?;
+        let mut functions = BTreeMap::new();
+        let mut paths = BTreeMap::new();
+        for symbol in file.symbols() {
+            if symbol.kind() != SymbolKind::Text || symbol.size() == 0 {
+                continue;
+            }
+            let Ok(name) = symbol.name() else { continue };
+            let path = format!("{:#}", rustc_demangle::demangle(name));
+            functions.insert(path.clone(), (symbol.address(), symbol.size()));
+            paths.insert(symbol.address(), path);
+        }
+        // Every slot in the table is filled in by a dynamic relocation.
+        // Since functions defined in the executable itself are never
+        // interposed, those relocations are relative to where the executable
+        // is loaded, i.e., the addend is the address of the function.
+        let mut got = BTreeMap::new();
+        for (slot, reloc) in file.dynamic_relocations().into_iter().flatten() {
+            if reloc.target() != RelocationTarget::Absolute {
+                continue;
+            }
+            let Ok(address) = u64::try_from(reloc.addend()) else { continue };
+            if let Some(path) = paths.get(&address) {
+                got.insert(slot, path.clone());
+            }
+        }
+        Ok(Symbols { functions, got })
+    }
+}
+
+/// Disassembles the function at the address given with `objdump`, and
+/// returns it with all addresses normalized away.
+///
+/// Each instruction is prefixed with its offset from the start of the
+/// function. Jump targets inside the function are written as offsets too,
+/// and `rip` relative displacements are dropped, since they change whenever
+/// anything else in the executable moves. Indirect calls and jumps through
+/// the global offset table are followed by the function they go to, e.g.,
+/// `call QWORD PTR [rip] <gencdfa1_find>`.
+fn disassemble(
+    binary: &Path,
+    symbols: &Symbols,
+    name: &str,
+    address: u64,
+    size: u64,
+) -> anyhow::Result<String> {
+    let output = Command::new("objdump")
+        .arg("--disassemble")
+        .arg("--demangle")
+        .arg("--no-show-raw-insn")
+        .arg("-M")
+        .arg("intel")
+        .arg(format!("--start-address={address:#x}"))
+        .arg(format!("--stop-address={:#x}", address + size))
+        .arg(binary)
+        .output()
+        .map_err(|e| anyhow::anyhow!("failed to run objdump: {e}"))?;
+    anyhow::ensure!(
+        output.status.success(),
+        "objdump failed: {}",
+        String::from_utf8_lossy(&output.stderr),
+    );
+
+    let mut asm = format!("{name}:\n");
+    for line in String::from_utf8(output.stdout)?.lines() {
+        // Instruction lines look like `  20a2a4:\tje     20a350 <...>`.
+        let Some((addr, insn)) = line.split_once(":\t") else { continue };
+        let Ok(addr) = u64::from_str_radix(addr.trim(), 16) else {
+            continue;
+        };
+        let insn = normalize(insn, symbols, name);
+        asm.push_str(&format!("{:>6x}:  {insn}\n", addr - address));
+    }
+    Ok(asm)
+}
+
+/// Returns the path of every function outside of the normalized disassembly
+/// given that it calls or jumps to, in order.
+fn callees(asm: &str) -> Vec<&str> {
+    let mut callees = vec![];
+    for line in asm.lines().skip(1) {
+        let Some((_, insn)) = line.split_once(":  ") else { continue };
+        if !insn.starts_with('j') && !insn.starts_with("call") {
+            continue;
+        }
+        // Targets outside of the function look like `<path>` or
+        // `<path+0x10>`, while targets inside of it look like `<+0x10>`.
+        let Some((_, target)) = insn.split_once('<') else { continue };
+        let target = target.trim_end_matches('>');
+        let target = match target.rsplit_once('+') {
+            Some((target, _)) => target,
+            None => target,
+        };
+        if !target.is_empty() && !callees.contains(&target) {
+            callees.push(target);
+        }
+    }
+    callees
+}
+
+/// Returns the name of the snapshot of the function with the path given,
+/// without the `.s` extension, e.g., `dfa.Dfa.find` for
+/// `duration_unit_lookup::dfa::Dfa<_>::find`.
+fn snapshot_name(path: &str) -> String {
+    let path = path.strip_prefix("duration_unit_lookup::").unwrap_or(path);
+    let mut name = String::new();
+    let mut depth = 0;
+    for c in path.replace("::", ".").chars() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            _ if depth > 0 => {}
+            c if c.is_ascii_alphanumeric() || "_.".contains(c) => name.push(c),
+            _ => {}
+        }
+    }
+    name
+}
+
+/// Removes everything from an instruction that depends on where things are
+/// in the executable.
+fn normalize(insn: &str, symbols: &Symbols, name: &str) -> String {
+    // Drop comments like `# 62738 <anon.f56f...>`, which only name the
+    // target of a `rip` relative operand. Unless that target is a slot in
+    // the global offset table, in which case the function the slot points
+    // to is kept.
+    let (insn, got_target) = match insn.split_once('#') {
+        Some((insn, comment)) => {
+            let slot = comment.split_whitespace().next().unwrap_or("");
+            let target = u64::from_str_radix(slot, 16)
+                .ok()
+                .and_then(|slot| symbols.got.get(&slot));
+            (insn, target)
+        }
+        None => (insn, None),
+    };
+    let mut tokens = vec![];
+    for (i, token) in insn.split_whitespace().enumerate() {
+        // Jump and call targets look like `20a350 <path+0xb0>`. Drop the
+        // address and keep the symbol, relative to this function if it's
+        // inside of it.
+        if i > 0 && token.chars().all(|c| c.is_ascii_hexdigit()) {
+            continue;
+        }
+        if let Some(target) = token.strip_prefix('<') {
+            let target = target.trim_end_matches('>');
+            // Beware of other functions whose name starts with this one's,
+            // e.g., `gencdfa2_find.cold`.
+            match target.strip_prefix(name) {
+                Some(offset)
+                    if offset.is_empty() || offset.starts_with('+') =>
+                {
+                    tokens.push(format!("<{offset}>"))
+                }
+                _ => tokens.push(format!("<{}>", strip_hash(target))),
+            }
+            continue;
+        }
+        tokens.push(strip_rip_displacement(token));
+    }
+    if let Some(target) = got_target {
+        tokens.push(format!("<{target}>"));
+    }
+    tokens.join(" ")
+}
+
+/// Replaces `[rip+0x1234]` with `[rip]`.
+fn strip_rip_displacement(token: &str) -> String {
+    let Some(start) = token.find("[rip") else {
+        return token.to_string();
+    };
+    match token[start..].find(']') {
+        Some(end) => {
+            format!("{}[rip]{}", &token[..start], &token[start + end + 1..])
+        }
+        None => token.to_string(),
+    }
+}
+
+/// Strips the hashes LLVM puts in the names of anonymous constants, e.g.,
+/// `anon.f56fea73f1f989e310b672b6ec34bebc.4.llvm.8301353317405272356`.
+fn strip_hash(target: &str) -> &str {
+    match target.strip_prefix("anon.") {
+        Some(_) => "anon",
+        None => target,
+    }
+}
+
+/// Computes the instruction mix of a normalized disassembly.
+fn summarize(asm: &str) -> Summary {
+    let mut summary = Summary::default();
+    for line in asm.lines().skip(1) {
+        let Some((_, insn)) = line.split_once(":  ") else { continue };
+        // Prefixes like `notrack` and `bnd` don't change what an
+        // instruction is.
+        let insn = insn
+            .strip_prefix("notrack ")
+            .or_else(|| insn.strip_prefix("bnd "))
+            .unwrap_or(insn);
+        let (mnemonic, operands) = match insn.split_once(' ') {
+            Some((mnemonic, operands)) => (mnemonic, operands),
+            None => (insn, ""),
+        };
+        summary.instructions += 1;
+        if mnemonic == "jmp" {
+            if operands.starts_with('<') {
+                summary.jumps += 1;
+            } else {
+                summary.jump_tables += 1;
+            }
+        } else if mnemonic.starts_with('j') {
+            summary.branches += 1;
+        } else if mnemonic == "call" {
+            summary.calls += 1;
+        }
+        if mnemonic.starts_with("mov") || mnemonic.starts_with("cmov") {
+            summary.movs += 1;
+        }
+        if mnemonic != "lea" && !mnemonic.starts_with("nop") {
+            // In Intel syntax, the destination is the first operand.
+            let (dst, src) = match operands.split_once(',') {
+                Some((dst, src)) => (dst, src),
+                None => (operands, ""),
+            };
+            if src.contains('[') || (dst.contains('[') && !is_store(mnemonic))
+            {
+                summary.loads += 1;
+            }
+            if dst.contains('[') && is_store(mnemonic) {
+                summary.stores += 1;
+            }
+        }
+    }
+    summary
+}
+
+/// Returns true if an instruction with a memory destination only writes to
+/// it. Every other instruction with a memory destination (like `cmp` or
+/// `add`) reads it too, and is counted as a load.
+fn is_store(mnemonic: &str) -> bool {
+    mnemonic.starts_with("mov") || mnemonic.starts_with("set")
+}
+
+fn write_summaries(
+    summaries: &[(String, Summary)],
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let width = summaries.iter().map(|(name, _)| name.len()).max();
+    let width = width.unwrap_or(0).max("routine".len());
+    writeln!(
+        out,
+        "{:<width$} {:>6} {:>6} {:>8} {:>6} {:>6} {:>6} {:>6} {:>6} {:>6}",
+        "routine",
+        "bytes",
+        "insns",
+        "branches",
+        "jumps",
+        "tables",
+        "calls",
+        "loads",
+        "stores",
+        "movs",
+    )?;
+    for (name, s) in summaries {
+        writeln!(
+            out,
+            "{:<width$} {:>6} {:>6} {:>8} {:>6} {:>6} {:>6} {:>6} {:>6} {:>6}",
+            name,
+            s.bytes,
+            s.instructions,
+            s.branches,
+            s.jumps,
+            s.jump_tables,
+            s.calls,
+            s.loads,
+            s.stores,
+            s.movs,
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,662 @@
+aho_corasick::automaton::try_find_fwd:
+     0:  push rbp
+     1:  push r15
+     3:  push r14
+     5:  push r13
+     7:  push r12
+     9:  push rbx
+     a:  sub rsp,0x58
+     e:  mov rbx,rdi
+    11:  mov rbp,QWORD PTR [rdx]
+    14:  mov r14,QWORD PTR [rdx+0x8]
+    18:  cmp rbp,r14
+    1b:  jbe <+0x29>
+    1d:  mov QWORD PTR [rbx],0x0
+    24:  jmp <+0x6ab>
+    29:  mov r13,QWORD PTR [rsi]
+    2c:  cmp BYTE PTR [r13+0x1a0],0x0
+    34:  je <+0x7e>
+    36:  movzx r9d,BYTE PTR [rdx+0x21]
+    3b:  cmp BYTE PTR [rdx+0x20],0x0
+    3f:  jne <+0x87>
+    41:  mov rax,QWORD PTR [r13+0x50]
+    45:  test rax,rax
+    48:  je <+0x31c>
+    4e:  test r9b,r9b
+    51:  jne <+0xc0>
+    53:  mov r12d,DWORD PTR [r13+0x198]
+    5a:  test r12,r12
+    5d:  je <+0x551>
+    63:  cmp r12d,DWORD PTR [r13+0x194]
+    6a:  jbe <+0x5d5>
+    70:  mov QWORD PTR [rsp+0x10],0x0
+    79:  jmp <+0x640>
+    7e:  mov r9b,0x1
+    81:  cmp BYTE PTR [rdx+0x20],0x0
+    85:  je <+0xb3>
+    87:  mov edi,DWORD PTR [r13+0x19c]
+    8e:  test rdi,rdi
+    91:  je <+0x155>
+    97:  mov r10d,DWORD PTR [r13+0x194]
+    9e:  cmp edi,r10d
+    a1:  jbe <+0x17c>
+    a7:  xor r15d,r15d
+    aa:  mov QWORD PTR [rsp],rax
+    ae:  jmp <+0x1f9>
+    b3:  mov rax,QWORD PTR [r13+0x50]
+    b7:  test rax,rax
+    ba:  je <+0x325>
+    c0:  mov r12d,DWORD PTR [r13+0x198]
+    c7:  test r12,r12
+    ca:  je <+0x428>
+    d0:  cmp r12d,DWORD PTR [r13+0x194]
+    d7:  jbe <+0x50a>
+    dd:  mov r8,QWORD PTR [rdx+0x10]
+    e1:  mov rcx,QWORD PTR [rdx+0x18]
+    e5:  mov rsi,QWORD PTR [r13+0x58]
+    e9:  mov rdx,QWORD PTR [rsi+0x10]
+    ed:  mov r10,QWORD PTR [rsi+0x20]
+    f1:  dec rdx
+    f4:  and rdx,0xfffffffffffffff0
+    f8:  lea rsi,[rax+rdx*1]
+    fc:  add rsi,0x10
+   100:  lea rdi,[rsp+0x30]
+   105:  mov QWORD PTR [rsp+0x28],rsi
+   10a:  mov QWORD PTR [rsp+0x8],r8
+   10f:  mov rdx,r8
+   112:  mov QWORD PTR [rsp],rcx
+   116:  mov r8,rbp
+   119:  mov r9,r14
+   11c:  mov rbp,r10
+   11f:  call r10
+   122:  mov rax,QWORD PTR [rsp+0x30]
+   127:  test rax,rax
+   12a:  je <+0x6a8>
+   130:  cmp eax,0x1
+   133:  je <+0x696>
+   139:  mov r15,QWORD PTR [rsp+0x38]
+   13e:  cmp r15,r14
+   141:  mov rcx,QWORD PTR [rsp]
+   145:  mov rdx,QWORD PTR [rsp+0x8]
+   14a:  jb <+0x482>
+   150:  jmp <+0x1d>
+   155:  call QWORD PTR [rip] <__rustc::__rust_no_alloc_shim_is_unstable_v2>
+   15b:  mov edi,0x2
+   160:  mov esi,0x1
+   165:  call QWORD PTR [rip] <__rustc::__rust_alloc>
+   16b:  test rax,rax
+   16e:  je <+0x9de>
+   174:  mov BYTE PTR [rax],0x0
+   177:  jmp <+0x557>
+   17c:  movzx ecx,BYTE PTR [r13+0x78]
+   181:  mov rax,rdi
+   184:  shr rax,cl
+   187:  add rax,0xfffffffffffffffe
+   18b:  mov rsi,QWORD PTR [r13+0x28]
+   18f:  cmp rax,rsi
+   192:  jae <+0xa7f>
+   198:  mov rcx,QWORD PTR [r13+0x20]
+   19c:  lea rax,[rax+rax*2]
+   1a0:  cmp QWORD PTR [rcx+rax*8+0x10],0x0
+   1a6:  je <+0xa15>
+   1ac:  lea rax,[rcx+rax*8]
+   1b0:  mov rax,QWORD PTR [rax+0x8]
+   1b4:  mov ecx,DWORD PTR [rax]
+   1b6:  mov rsi,QWORD PTR [r13+0x40]
+   1ba:  cmp rsi,rcx
+   1bd:  jbe <+0xa8f>
+   1c3:  mov rax,QWORD PTR [r13+0x38]
+   1c7:  mov QWORD PTR [rsp],rcx
+   1cb:  mov eax,DWORD PTR [rax+rcx*4]
+   1ce:  mov r11,rbp
+   1d1:  sub r11,rax
+   1d4:  jb <+0x926>
+   1da:  mov r15d,0x1
+   1e0:  test r9b,r9b
+   1e3:  je <+0x1f9>
+   1e5:  mov QWORD PTR [rbx],0x1
+   1ec:  mov QWORD PTR [rbx+0x8],r11
+   1f0:  mov QWORD PTR [rbx+0x10],rbp
+   1f4:  jmp <+0x464>
+   1f9:  cmp rbp,r14
+   1fc:  jae <+0x44f>
+   202:  mov r12,QWORD PTR [rdx+0x10]
+   206:  mov rsi,QWORD PTR [rdx+0x18]
+   20a:  lea rax,[rbp+0x1]
+   20e:  neg r14
+   211:  mov QWORD PTR [rsp+0x8],rbp
+   216:  jmp <+0x244>
+   218:  mov QWORD PTR [rsp],rcx
+   21c:  mov QWORD PTR [rsp+0x8],rax
+   221:  mov r11,rdx
+   224:  data16 data16 cs nop WORD PTR [rax+rax*1+0x0]
+   230:  lea rcx,[r14+rax*1]
+   234:  inc rcx
+   237:  inc rax
+   23a:  cmp rcx,0x1
+   23e:  je <+0x454>
+   244:  lea rcx,[rax-0x1]
+   248:  cmp rcx,rsi
+   24b:  jae <+0x9ee>
+   251:  movzx ecx,BYTE PTR [r12+rax*1-0x1]
+   257:  movzx ecx,BYTE PTR [r13+rcx*1+0x80]
+   260:  add edi,ecx
+   262:  mov rcx,QWORD PTR [r13+0x10]
+   266:  cmp rcx,rdi
+   269:  jbe <+0xa05>
+   26f:  mov rcx,QWORD PTR [r13+0x8]
+   273:  mov edi,DWORD PTR [rcx+rdi*4]
+   276:  cmp edi,DWORD PTR [r13+0x190]
+   27d:  ja <+0x230>
+   27f:  test edi,edi
+   281:  je <+0x454>
+   287:  cmp edi,r10d
+   28a:  ja <+0x230>
+   28c:  movzx ecx,BYTE PTR [r13+0x78]
+   291:  mov r8,rdi
+   294:  shr r8,cl
+   297:  add r8,0xfffffffffffffffe
+   29b:  mov rcx,QWORD PTR [r13+0x28]
+   29f:  cmp r8,rcx
+   2a2:  jae <+0xa26>
+   2a8:  mov rcx,QWORD PTR [r13+0x20]
+   2ac:  lea rdx,[r8+r8*2]
+   2b0:  cmp QWORD PTR [rcx+rdx*8+0x10],0x0
+   2b6:  je <+0xa15>
+   2bc:  lea rcx,[rcx+rdx*8]
+   2c0:  mov rcx,QWORD PTR [rcx+0x8]
+   2c4:  mov ecx,DWORD PTR [rcx]
+   2c6:  mov r8,QWORD PTR [r13+0x40]
+   2ca:  cmp r8,rcx
+   2cd:  jbe <+0xa39>
+   2d3:  mov rdx,QWORD PTR [r13+0x38]
+   2d7:  mov r8d,DWORD PTR [rdx+rcx*4]
+   2db:  mov rdx,rax
+   2de:  sub rdx,r8
+   2e1:  jb <+0x926>
+   2e7:  cmp rdx,rbp
+   2ea:  ja <+0x230>
+   2f0:  mov r15d,0x1
+   2f6:  test r9b,r9b
+   2f9:  je <+0x218>
+   2ff:  mov QWORD PTR [rbx],0x1
+   306:  mov rdx,rax
+   309:  sub rdx,r8
+   30c:  mov QWORD PTR [rbx+0x8],rdx
+   310:  mov QWORD PTR [rbx+0x10],rax
+   314:  mov DWORD PTR [rbx+0x18],ecx
+   317:  jmp <+0x6ab>
+   31c:  test r9b,r9b
+   31f:  je <+0x52d>
+   325:  mov edi,DWORD PTR [r13+0x198]
+   32c:  test rdi,rdi
+   32f:  je <+0x428>
+   335:  mov ecx,DWORD PTR [r13+0x194]
+   33c:  cmp edi,ecx
+   33e:  jbe <+0x567>
+   344:  cmp rbp,r14
+   347:  jae <+0x1d>
+   34d:  mov r8,QWORD PTR [rdx+0x10]
+   351:  mov rsi,QWORD PTR [rdx+0x18]
+   355:  mov rdx,rbp
+   358:  not rdx
+   35b:  mov r9,rbp
+   35e:  jmp <+0x36f>
+   360:  inc r9
+   363:  dec rdx
+   366:  cmp r14,r9
+   369:  je <+0x1d>
+   36f:  cmp r9,rsi
+   372:  jae <+0x9ee>
+   378:  movzx eax,BYTE PTR [r8+r9*1]
+   37d:  movzx eax,BYTE PTR [r13+rax*1+0x80]
+   386:  add edi,eax
+   388:  mov rax,QWORD PTR [r13+0x10]
+   38c:  cmp rax,rdi
+   38f:  jbe <+0xa6f>
+   395:  mov rax,QWORD PTR [r13+0x8]
+   399:  mov edi,DWORD PTR [rax+rdi*4]
+   39c:  cmp edi,DWORD PTR [r13+0x190]
+   3a3:  ja <+0x360>
+   3a5:  test edi,edi
+   3a7:  je <+0x1d>
+   3ad:  cmp edi,ecx
+   3af:  ja <+0x360>
+   3b1:  movzx ecx,BYTE PTR [r13+0x78]
+   3b6:  shr rdi,cl
+   3b9:  add rdi,0xfffffffffffffffe
+   3bd:  mov rsi,QWORD PTR [r13+0x28]
+   3c1:  cmp rdi,rsi
+   3c4:  jae <+0xad2>
+   3ca:  mov rax,QWORD PTR [r13+0x20]
+   3ce:  lea rcx,[rdi+rdi*2]
+   3d2:  cmp QWORD PTR [rax+rcx*8+0x10],0x0
+   3d8:  je <+0xa15>
+   3de:  lea rax,[rax+rcx*8]
+   3e2:  mov rax,QWORD PTR [rax+0x8]
+   3e6:  mov edi,DWORD PTR [rax]
+   3e8:  mov rsi,QWORD PTR [r13+0x40]
+   3ec:  cmp rsi,rdi
+   3ef:  jbe <+0xa9f>
+   3f5:  mov rax,rdx
+   3f8:  neg rax
+   3fb:  mov rcx,QWORD PTR [r13+0x38]
+   3ff:  mov ecx,DWORD PTR [rcx+rdi*4]
+   402:  cmp rax,rcx
+   405:  jb <+0x926>
+   40b:  add rcx,rdx
+   40e:  neg rcx
+   411:  mov QWORD PTR [rbx],0x1
+   418:  mov QWORD PTR [rbx+0x8],rcx
+   41c:  mov QWORD PTR [rbx+0x10],rax
+   420:  mov DWORD PTR [rbx+0x18],edi
+   423:  jmp <+0x6ab>
+   428:  call QWORD PTR [rip] <__rustc::__rust_no_alloc_shim_is_unstable_v2>
+   42e:  mov edi,0x2
+   433:  mov esi,0x1
+   438:  call QWORD PTR [rip] <__rustc::__rust_alloc>
+   43e:  test rax,rax
+   441:  je <+0x9de>
+   447:  mov BYTE PTR [rax],0x1
+   44a:  jmp <+0x557>
+   44f:  mov QWORD PTR [rsp+0x8],rbp
+   454:  mov QWORD PTR [rbx],r15
+   457:  mov QWORD PTR [rbx+0x8],r11
+   45b:  mov rax,QWORD PTR [rsp+0x8]
+   460:  mov QWORD PTR [rbx+0x10],rax
+   464:  mov rax,QWORD PTR [rsp]
+   468:  mov DWORD PTR [rbx+0x18],eax
+   46b:  jmp <+0x6ab>
+   470:  inc r15
+   473:  mov rax,r15
+   476:  mov r15,rax
+   479:  cmp rax,r14
+   47c:  jae <+0x1d>
+   482:  cmp r15,rcx
+   485:  jae <+0xa5c>
+   48b:  movzx eax,BYTE PTR [rdx+r15*1]
+   490:  movzx eax,BYTE PTR [r13+rax*1+0x80]
+   499:  add r12d,eax
+   49c:  mov rsi,QWORD PTR [r13+0x10]
+   4a0:  cmp rsi,r12
+   4a3:  jbe <+0xa4c>
+   4a9:  mov rax,QWORD PTR [r13+0x8]
+   4ad:  mov r12d,DWORD PTR [rax+r12*4]
+   4b1:  cmp r12d,DWORD PTR [r13+0x190]
+   4b8:  ja <+0x470>
+   4ba:  test r12d,r12d
+   4bd:  je <+0x1d>
+   4c3:  cmp r12d,DWORD PTR [r13+0x194]
+   4ca:  jbe <+0x971>
+   4d0:  lea rdi,[rsp+0x30]
+   4d5:  mov rsi,QWORD PTR [rsp+0x28]
+   4da:  mov r8,r15
+   4dd:  mov r9,r14
+   4e0:  call rbp
+   4e2:  cmp QWORD PTR [rsp+0x30],0x0
+   4e8:  je <+0x1d>
+   4ee:  mov rax,QWORD PTR [rsp+0x38]
+   4f3:  cmp rax,r15
+   4f6:  mov rcx,QWORD PTR [rsp]
+   4fa:  mov rdx,QWORD PTR [rsp+0x8]
+   4ff:  jbe <+0x470>
+   505:  jmp <+0x476>
+   50a:  movzx ecx,BYTE PTR [r13+0x78]
+   50f:  shr r12,cl
+   512:  add r12,0xfffffffffffffffe
+   516:  mov rsi,QWORD PTR [r13+0x28]
+   51a:  cmp r12,rsi
+   51d:  jae <+0xadf>
+   523:  mov rax,QWORD PTR [r13+0x20]
+   527:  lea rcx,[r12+r12*2]
+   52b:  jmp <+0x588>
+   52d:  mov edi,DWORD PTR [r13+0x198]
+   534:  test rdi,rdi
+   537:  je <+0x551>
+   539:  mov r8d,DWORD PTR [r13+0x194]
+   540:  cmp edi,r8d
+   543:  jbe <+0x7ea>
+   549:  xor r11d,r11d
+   54c:  jmp <+0x84a>
+   551:  call QWORD PTR [rip] <aho_corasick::util::error::MatchError::invalid_input_unanchored>
+   557:  mov QWORD PTR [rbx+0x8],rax
+   55b:  mov QWORD PTR [rbx],0x2
+   562:  jmp <+0x6ab>
+   567:  movzx ecx,BYTE PTR [r13+0x78]
+   56c:  shr rdi,cl
+   56f:  add rdi,0xfffffffffffffffe
+   573:  mov rsi,QWORD PTR [r13+0x28]
+   577:  cmp rdi,rsi
+   57a:  jae <+0xad2>
+   580:  mov rax,QWORD PTR [r13+0x20]
+   584:  lea rcx,[rdi+rdi*2]
+   588:  cmp QWORD PTR [rax+rcx*8+0x10],0x0
+   58e:  je <+0xa15>
+   594:  lea rax,[rax+rcx*8]
+   598:  mov rax,QWORD PTR [rax+0x8]
+   59c:  mov edi,DWORD PTR [rax]
+   59e:  mov rsi,QWORD PTR [r13+0x40]
+   5a2:  cmp rsi,rdi
+   5a5:  jbe <+0xa9f>
+   5ab:  mov rax,QWORD PTR [r13+0x38]
+   5af:  mov ecx,DWORD PTR [rax+rdi*4]
+   5b2:  mov rax,rbp
+   5b5:  sub rax,rcx
+   5b8:  jb <+0x926>
+   5be:  mov QWORD PTR [rbx],0x1
+   5c5:  mov QWORD PTR [rbx+0x8],rax
+   5c9:  mov QWORD PTR [rbx+0x10],rbp
+   5cd:  mov DWORD PTR [rbx+0x18],edi
+   5d0:  jmp <+0x6ab>
+   5d5:  movzx ecx,BYTE PTR [r13+0x78]
+   5da:  mov rdi,r12
+   5dd:  shr rdi,cl
+   5e0:  add rdi,0xfffffffffffffffe
+   5e4:  mov rsi,QWORD PTR [r13+0x28]
+   5e8:  cmp rdi,rsi
+   5eb:  jae <+0xad2>
+   5f1:  mov rcx,QWORD PTR [r13+0x20]
+   5f5:  lea rsi,[rdi+rdi*2]
+   5f9:  cmp QWORD PTR [rcx+rsi*8+0x10],0x0
+   5ff:  je <+0xa15>
+   605:  lea rcx,[rcx+rsi*8]
+   609:  mov rcx,QWORD PTR [rcx+0x8]
+   60d:  mov r15d,DWORD PTR [rcx]
+   610:  mov rsi,QWORD PTR [r13+0x40]
+   614:  cmp rsi,r15
+   617:  jbe <+0xaef>
+   61d:  mov rcx,QWORD PTR [r13+0x38]
+   621:  mov ecx,DWORD PTR [rcx+r15*4]
+   625:  mov rsi,rbp
+   628:  mov edi,0x1
+   62d:  mov QWORD PTR [rsp+0x10],rdi
+   632:  sub rsi,rcx
+   635:  mov QWORD PTR [rsp+0x20],rsi
+   63a:  jb <+0x926>
+   640:  mov r8,QWORD PTR [rdx+0x10]
+   644:  mov rcx,QWORD PTR [rdx+0x18]
+   648:  mov rsi,QWORD PTR [r13+0x58]
+   64c:  mov rdx,QWORD PTR [rsi+0x10]
+   650:  mov r10,QWORD PTR [rsi+0x20]
+   654:  dec rdx
+   657:  and rdx,0xfffffffffffffff0
+   65b:  lea rsi,[rax+rdx*1]
+   65f:  add rsi,0x10
+   663:  lea rdi,[rsp+0x30]
+   668:  mov QWORD PTR [rsp+0x50],rsi
+   66d:  mov QWORD PTR [rsp+0x8],r8
+   672:  mov rdx,r8
+   675:  mov QWORD PTR [rsp],rcx
+   679:  mov r8,rbp
+   67c:  mov r9,r14
+   67f:  mov QWORD PTR [rsp+0x28],r10
+   684:  call r10
+   687:  mov rax,QWORD PTR [rsp+0x30]
+   68c:  test rax,rax
+   68f:  je <+0x6a8>
+   691:  cmp eax,0x1
+   694:  jne <+0x6bd>
+   696:  mov rcx,QWORD PTR [rsp+0x48]
+   69b:  mov QWORD PTR [rbx+0x18],rcx
+   69f:  movups xmm0,XMMWORD PTR [rsp+0x38]
+   6a4:  movups XMMWORD PTR [rbx+0x8],xmm0
+   6a8:  mov QWORD PTR [rbx],rax
+   6ab:  mov rax,rbx
+   6ae:  add rsp,0x58
+   6b2:  pop rbx
+   6b3:  pop r12
+   6b5:  pop r13
+   6b7:  pop r14
+   6b9:  pop r15
+   6bb:  pop rbp
+   6bc:  ret
+   6bd:  mov QWORD PTR [rsp+0x18],r15
+   6c2:  mov rax,QWORD PTR [rsp+0x38]
+   6c7:  mov rcx,QWORD PTR [rsp]
+   6cb:  mov rdx,QWORD PTR [rsp+0x8]
+   6d0:  cmp rax,r14
+   6d3:  jae <+0x952>
+   6d9:  mov r15,rax
+   6dc:  cmp rax,rcx
+   6df:  jae <+0xa5c>
+   6e5:  movzx eax,BYTE PTR [rdx+r15*1]
+   6ea:  movzx eax,BYTE PTR [r13+rax*1+0x80]
+   6f3:  add r12d,eax
+   6f6:  mov rsi,QWORD PTR [r13+0x10]
+   6fa:  cmp rsi,r12
+   6fd:  jbe <+0xa4c>
+   703:  mov rax,QWORD PTR [r13+0x8]
+   707:  mov r12d,DWORD PTR [rax+r12*4]
+   70b:  cmp r12d,DWORD PTR [r13+0x190]
+   712:  ja <+0x75d>
+   714:  test r12d,r12d
+   717:  je <+0x952>
+   71d:  cmp r12d,DWORD PTR [r13+0x194]
+   724:  jbe <+0x768>
+   726:  lea rdi,[rsp+0x30]
+   72b:  mov rsi,QWORD PTR [rsp+0x50]
+   730:  mov r8,r15
+   733:  mov r9,r14
+   736:  call QWORD PTR [rsp+0x28]
+   73a:  cmp QWORD PTR [rsp+0x30],0x0
+   740:  je <+0x1d>
+   746:  mov rax,QWORD PTR [rsp+0x38]
+   74b:  cmp rax,r15
+   74e:  mov rcx,QWORD PTR [rsp]
+   752:  mov rdx,QWORD PTR [rsp+0x8]
+   757:  ja <+0x6d0>
+   75d:  inc r15
+   760:  mov rax,r15
+   763:  jmp <+0x6d0>
+   768:  mov r8,rcx
+   76b:  movzx ecx,BYTE PTR [r13+0x78]
+   770:  mov rdi,r12
+   773:  shr rdi,cl
+   776:  add rdi,0xfffffffffffffffe
+   77a:  mov rsi,QWORD PTR [r13+0x28]
+   77e:  cmp rdi,rsi
+   781:  jae <+0xad2>
+   787:  mov rax,QWORD PTR [r13+0x20]
+   78b:  lea rcx,[rdi+rdi*2]
+   78f:  cmp QWORD PTR [rax+rcx*8+0x10],0x0
+   795:  je <+0xa15>
+   79b:  lea rax,[rax+rcx*8]
+   79f:  mov rax,QWORD PTR [rax+0x8]
+   7a3:  mov edi,DWORD PTR [rax]
+   7a5:  mov rsi,QWORD PTR [r13+0x40]
+   7a9:  cmp rsi,rdi
+   7ac:  jbe <+0xa9f>
+   7b2:  inc r15
+   7b5:  mov rax,QWORD PTR [r13+0x38]
+   7b9:  mov QWORD PTR [rsp+0x18],rdi
+   7be:  mov ecx,DWORD PTR [rax+rdi*4]
+   7c1:  mov rsi,r15
+   7c4:  mov eax,0x1
+   7c9:  mov QWORD PTR [rsp+0x10],rax
+   7ce:  mov rbp,r15
+   7d1:  mov rax,r15
+   7d4:  sub rsi,rcx
+   7d7:  mov QWORD PTR [rsp+0x20],rsi
+   7dc:  mov rcx,r8
+   7df:  jae <+0x6d0>
+   7e5:  jmp <+0x926>
+   7ea:  movzx ecx,BYTE PTR [r13+0x78]
+   7ef:  mov rax,rdi
+   7f2:  shr rax,cl
+   7f5:  add rax,0xfffffffffffffffe
+   7f9:  mov rsi,QWORD PTR [r13+0x28]
+   7fd:  cmp rax,rsi
+   800:  jae <+0xa7f>
+   806:  mov rcx,QWORD PTR [r13+0x20]
+   80a:  lea rax,[rax+rax*2]
+   80e:  cmp QWORD PTR [rcx+rax*8+0x10],0x0
+   814:  je <+0xa15>
+   81a:  lea rax,[rcx+rax*8]
+   81e:  mov rax,QWORD PTR [rax+0x8]
+   822:  mov eax,DWORD PTR [rax]
+   824:  mov rsi,QWORD PTR [r13+0x40]
+   828:  cmp rsi,rax
+   82b:  jbe <+0xaff>
+   831:  mov rcx,QWORD PTR [r13+0x38]
+   835:  mov ecx,DWORD PTR [rcx+rax*4]
+   838:  mov r9,rbp
+   83b:  mov r11d,0x1
+   841:  sub r9,rcx
+   844:  jb <+0x926>
+   84a:  cmp rbp,r14
+   84d:  jae <+0x93f>
+   853:  mov r10,QWORD PTR [rdx+0x10]
+   857:  mov rsi,QWORD PTR [rdx+0x18]
+   85b:  lea rdx,[rbp+0x1]
+   85f:  neg r14
+   862:  mov r15,rbp
+   865:  jmp <+0x87b>
+   867:  lea rcx,[r14+rdx*1]
+   86b:  inc rcx
+   86e:  inc rdx
+   871:  cmp rcx,0x1
+   875:  je <+0x942>
+   87b:  lea rcx,[rdx-0x1]
+   87f:  cmp rcx,rsi
+   882:  jae <+0x9ee>
+   888:  movzx ecx,BYTE PTR [r10+rdx*1-0x1]
+   88e:  movzx ecx,BYTE PTR [r13+rcx*1+0x80]
+   897:  add edi,ecx
+   899:  mov rcx,QWORD PTR [r13+0x10]
+   89d:  cmp rcx,rdi
+   8a0:  jbe <+0xa05>
+   8a6:  mov rcx,QWORD PTR [r13+0x8]
+   8aa:  mov edi,DWORD PTR [rcx+rdi*4]
+   8ad:  cmp edi,DWORD PTR [r13+0x190]
+   8b4:  ja <+0x867>
+   8b6:  test edi,edi
+   8b8:  je <+0x942>
+   8be:  cmp edi,r8d
+   8c1:  ja <+0x867>
+   8c3:  movzx ecx,BYTE PTR [r13+0x78]
+   8c8:  mov rax,rdi
+   8cb:  shr rax,cl
+   8ce:  add rax,0xfffffffffffffffe
+   8d2:  mov rcx,QWORD PTR [r13+0x28]
+   8d6:  cmp rax,rcx
+   8d9:  jae <+0xaac>
+   8df:  mov rcx,QWORD PTR [r13+0x20]
+   8e3:  lea rax,[rax+rax*2]
+   8e7:  cmp QWORD PTR [rcx+rax*8+0x10],0x0
+   8ed:  je <+0xa15>
+   8f3:  lea rax,[rcx+rax*8]
+   8f7:  mov rax,QWORD PTR [rax+0x8]
+   8fb:  mov eax,DWORD PTR [rax]
+   8fd:  mov rcx,QWORD PTR [r13+0x40]
+   901:  cmp rcx,rax
+   904:  jbe <+0xabf>
+   90a:  mov rcx,QWORD PTR [r13+0x38]
+   90e:  mov ecx,DWORD PTR [rcx+rax*4]
+   911:  mov r9,rdx
+   914:  mov r11d,0x1
+   91a:  mov r15,rdx
+   91d:  sub r9,rcx
+   920:  jae <+0x867>
+   926:  lea rdi,[rip]
+   92d:  lea rdx,[rip]
+   934:  mov esi,0x25
+   939:  call QWORD PTR [rip] <core::panicking::panic_fmt>
+   93f:  mov r15,rbp
+   942:  mov QWORD PTR [rbx],r11
+   945:  mov QWORD PTR [rbx+0x8],r9
+   949:  mov QWORD PTR [rbx+0x10],r15
+   94d:  jmp <+0x468>
+   952:  mov rax,QWORD PTR [rsp+0x10]
+   957:  mov QWORD PTR [rbx],rax
+   95a:  mov rax,QWORD PTR [rsp+0x20]
+   95f:  mov QWORD PTR [rbx+0x8],rax
+   963:  mov QWORD PTR [rbx+0x10],rbp
+   967:  mov rax,QWORD PTR [rsp+0x18]
+   96c:  jmp <+0x468>
+   971:  movzx ecx,BYTE PTR [r13+0x78]
+   976:  shr r12,cl
+   979:  add r12,0xfffffffffffffffe
+   97d:  mov rsi,QWORD PTR [r13+0x28]
+   981:  cmp r12,rsi
+   984:  jae <+0xadf>
+   98a:  mov rax,QWORD PTR [r13+0x20]
+   98e:  lea rcx,[r12+r12*2]
+   992:  cmp QWORD PTR [rax+rcx*8+0x10],0x0
+   998:  je <+0xa15>
+   99a:  lea rax,[rax+rcx*8]
+   99e:  mov rax,QWORD PTR [rax+0x8]
+   9a2:  mov edi,DWORD PTR [rax]
+   9a4:  mov rsi,QWORD PTR [r13+0x40]
+   9a8:  cmp rsi,rdi
+   9ab:  jbe <+0xa9f>
+   9b1:  inc r15
+   9b4:  mov rax,QWORD PTR [r13+0x38]
+   9b8:  mov ecx,DWORD PTR [rax+rdi*4]
+   9bb:  mov rax,r15
+   9be:  sub rax,rcx
+   9c1:  jb <+0x926>
+   9c7:  mov QWORD PTR [rbx],0x1
+   9ce:  mov QWORD PTR [rbx+0x8],rax
+   9d2:  mov QWORD PTR [rbx+0x10],r15
+   9d6:  mov DWORD PTR [rbx+0x18],edi
+   9d9:  jmp <+0x6ab>
+   9de:  mov edi,0x1
+   9e3:  mov esi,0x2
+   9e8:  call QWORD PTR [rip] <alloc::alloc::handle_alloc_error>
+   9ee:  cmp rsi,rbp
+   9f1:  cmova rbp,rsi
+   9f5:  lea rdx,[rip]
+   9fc:  mov rdi,rbp
+   9ff:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   a05:  lea rdx,[rip]
+   a0c:  mov rsi,rcx
+   a0f:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   a15:  lea rdx,[rip]
+   a1c:  xor edi,edi
+   a1e:  xor esi,esi
+   a20:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   a26:  lea rdx,[rip]
+   a2d:  mov rdi,r8
+   a30:  mov rsi,rcx
+   a33:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   a39:  lea rdx,[rip]
+   a40:  mov rdi,rcx
+   a43:  mov rsi,r8
+   a46:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   a4c:  lea rdx,[rip]
+   a53:  mov rdi,r12
+   a56:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   a5c:  lea rdx,[rip]
+   a63:  mov rdi,r15
+   a66:  mov rsi,rcx
+   a69:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   a6f:  lea rdx,[rip]
+   a76:  mov rsi,rax
+   a79:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   a7f:  lea rdx,[rip]
+   a86:  mov rdi,rax
+   a89:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   a8f:  lea rdx,[rip]
+   a96:  mov rdi,rcx
+   a99:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   a9f:  lea rdx,[rip]
+   aa6:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   aac:  lea rdx,[rip]
+   ab3:  mov rdi,rax
+   ab6:  mov rsi,rcx
+   ab9:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   abf:  lea rdx,[rip]
+   ac6:  mov rdi,rax
+   ac9:  mov rsi,rcx
+   acc:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   ad2:  lea rdx,[rip]
+   ad9:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   adf:  lea rdx,[rip]
+   ae6:  mov rdi,r12
+   ae9:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   aef:  lea rdx,[rip]
+   af6:  mov rdi,r15
+   af9:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   aff:  lea rdx,[rip]
+   b06:  mov rdi,rax
+   b09:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
@@ -0,0 +1,14 @@
+aho_corasick::util::error::MatchError::invalid_input_unanchored:
+     0:  push rax
+     1:  call QWORD PTR [rip] <__rustc::__rust_no_alloc_shim_is_unstable_v2>
+     7:  mov edi,0x2
+     c:  mov esi,0x1
+    11:  call QWORD PTR [rip] <__rustc::__rust_alloc>
+    17:  test rax,rax
+    1a:  je <+0x21>
+    1c:  mov BYTE PTR [rax],0x1
+    1f:  pop rcx
+    20:  ret
+    21:  mov edi,0x1
+    26:  mov esi,0x2
+    2b:  call QWORD PTR [rip] <alloc::alloc::handle_alloc_error>
@@ -0,0 +1,91 @@
+duration_unit_lookup::aho::lookup_ignore_ascii_case:
+     0:  push r15
+     2:  push r14
+     4:  push r12
+     6:  push rbx
+     7:  sub rsp,0x58
+     b:  mov r15,rdx
+     e:  mov r14,rsi
+    11:  mov rbx,rdi
+    14:  mov QWORD PTR [rsp+0x30],0x0
+    1d:  mov QWORD PTR [rsp+0x38],rdx
+    22:  mov QWORD PTR [rsp+0x40],rsi
+    27:  mov QWORD PTR [rsp+0x48],rdx
+    2c:  mov WORD PTR [rsp+0x50],0x1
+    33:  mov r12,QWORD PTR [rip]
+    3a:  mov eax,DWORD PTR [r12+0x1a8]
+    42:  test eax,eax
+    44:  jne <+0xcb>
+    4a:  mov QWORD PTR [rsp+0x8],r12
+    4f:  lea rdi,[rsp+0x10]
+    54:  lea rsi,[rsp+0x8]
+    59:  lea rdx,[rsp+0x30]
+    5e:  call QWORD PTR [rip] <aho_corasick::automaton::try_find_fwd>
+    64:  mov rax,QWORD PTR [rsp+0x10]
+    69:  cmp rax,0x2
+    6d:  je <+0x105>
+    73:  cmp eax,0x1
+    76:  jne <+0x136>
+    7c:  mov edi,DWORD PTR [rsp+0x28]
+    80:  cmp edi,0x38
+    83:  jae <+0x166>
+    89:  mov rax,QWORD PTR [rsp+0x20]
+    8e:  mov rcx,r15
+    91:  sub rcx,rax
+    94:  jb <+0x150>
+    9a:  lea rdx,[rdi+rdi*2]
+    9e:  lea rsi,[rip]
+    a5:  mov edx,DWORD PTR [rsi+rdx*8+0x10]
+    a9:  add r14,rax
+    ac:  mov DWORD PTR [rbx+0x8],edx
+    af:  mov QWORD PTR [rbx+0x10],r14
+    b3:  mov QWORD PTR [rbx+0x18],rcx
+    b7:  xor eax,eax
+    b9:  mov QWORD PTR [rbx],rax
+    bc:  mov rax,rbx
+    bf:  add rsp,0x58
+    c3:  pop rbx
+    c4:  pop r12
+    c6:  pop r14
+    c8:  pop r15
+    ca:  ret
+    cb:  mov QWORD PTR [rsp+0x8],r12
+    d0:  lea rax,[rsp+0x8]
+    d5:  mov QWORD PTR [rsp+0x10],rax
+    da:  lea rdi,[r12+0x1a8]
+    e2:  lea rcx,[rip]
+    e9:  lea r8,[rip]
+    f0:  lea rdx,[rsp+0x10]
+    f5:  mov esi,0x1
+    fa:  call QWORD PTR [rip] <<std::sys::sync::once::futex::Once>::call>
+   100:  jmp <+0x4a>
+   105:  mov rax,QWORD PTR [rsp+0x18]
+   10a:  mov QWORD PTR [rsp+0x8],rax
+   10f:  lea rdi,[rip]
+   116:  lea rcx,[rip] <core::ptr::drop_in_place<aho_corasick::util::error::MatchError>>
+   11d:  lea r8,[rip]
+   124:  lea rdx,[rsp+0x8]
+   129:  mov esi,0x2b
+   12e:  call QWORD PTR [rip] <core::result::unwrap_failed>
+   134:  ud2
+   136:  lea rdi,[rbx+0x8]
+   13a:  mov rsi,r14
+   13d:  mov rdx,r15
+   140:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+   146:  mov eax,0x1
+   14b:  jmp <+0xb9>
+   150:  lea rcx,[rip]
+   157:  mov rdi,rax
+   15a:  mov rsi,r15
+   15d:  mov rdx,r15
+   160:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
+   166:  lea rdx,[rip]
+   16d:  mov esi,0x38
+   172:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   178:  mov rbx,rax
+   17b:  mov rdi,QWORD PTR [rsp+0x8]
+   180:  mov esi,0x2
+   185:  mov edx,0x1
+   18a:  call QWORD PTR [rip] <__rustc::__rust_dealloc>
+   190:  mov rdi,rbx
+   193:  call <_Unwind_Resume@plt>
@@ -0,0 +1,1130 @@
+gencdfa1_ci_find:
+     0:  mov rdx,rdi
+     3:  cmp rdi,rsi
+     6:  jae <+0x40>
+     8:  movzx eax,BYTE PTR [rdi]
+     b:  lea rcx,[rdi+0x1]
+     f:  sub eax,0x44
+    12:  cmp al,0x7e
+    14:  ja <+0x30>
+    16:  lea rdi,[rip]
+    1d:  movzx eax,al
+    20:  movsxd rax,DWORD PTR [rdi+rax*4]
+    24:  add rax,rdi
+    27:  jmp rax
+    29:  nop DWORD PTR [rax+0x0]
+    30:  xor edi,edi
+    32:  mov eax,0x9
+    37:  mov rdx,rdi
+    3a:  ret
+    3b:  nop DWORD PTR [rax+rax*1+0x0]
+    40:  xor edi,edi
+    42:  mov eax,0x9
+    47:  mov rdx,rdi
+    4a:  ret
+    4b:  nop DWORD PTR [rax+rax*1+0x0]
+    50:  cmp rcx,rsi
+    53:  jae <+0x2a0>
+    59:  movzx eax,BYTE PTR [rdx+0x1]
+    5d:  lea rcx,[rdx+0x2]
+    61:  cmp al,0x65
+    63:  je <+0x450>
+    69:  ja <+0x310>
+    6f:  cmp al,0x45
+    71:  je <+0x450>
+    77:  cmp al,0x52
+    79:  je <+0x4f0>
+    7f:  mov edi,0x1
+    84:  mov eax,0x9
+    89:  jmp <+0x37>
+    8b:  nop DWORD PTR [rax+rax*1+0x0]
+    90:  cmp rcx,rsi
+    93:  jae <+0x2b0>
+    99:  movzx eax,BYTE PTR [rdx+0x1]
+    9d:  lea rcx,[rdx+0x2]
+    a1:  cmp al,0x65
+    a3:  je <+0x478>
+    a9:  ja <+0x330>
+    af:  cmp al,0x45
+    b1:  je <+0x478>
+    b7:  cmp al,0x4b
+    b9:  je <+0x4c8>
+    bf:  mov edi,0x1
+    c4:  mov eax,0x7
+    c9:  jmp <+0x37>
+    ce:  xchg ax,ax
+    d0:  cmp rcx,rsi
+    d3:  jae <+0x270>
+    d9:  movzx eax,BYTE PTR [rdx+0x1]
+    dd:  and eax,0xffffffdf
+    e0:  cmp al,0x53
+    e2:  je <+0x388>
+    e8:  xor edi,edi
+    ea:  mov eax,0x9
+    ef:  jmp <+0x37>
+    f4:  nop DWORD PTR [rax+0x0]
+    f8:  cmp rcx,rsi
+    fb:  jae <+0x290>
+   101:  movzx eax,BYTE PTR [rdx+0x1]
+   105:  and eax,0xffffffdf
+   108:  cmp al,0x45
+   10a:  je <+0x404>
+   110:  mov edi,0x1
+   115:  mov eax,0x3
+   11a:  jmp <+0x37>
+   11f:  nop
+   120:  cmp rcx,rsi
+   123:  jae <+0x280>
+   129:  movzx eax,BYTE PTR [rdx+0x1]
+   12d:  lea rcx,[rdx+0x2]
+   131:  cmp al,0x61
+   133:  je <+0x3b8>
+   139:  ja <+0x370>
+   13f:  cmp al,0x41
+   141:  je <+0x3b8>
+   147:  cmp al,0x53
+   149:  jne <+0xf5d>
+   14f:  cmp rcx,rsi
+   152:  jae <+0x6d6>
+   158:  movzx eax,BYTE PTR [rdx+0x2]
+   15c:  and eax,0xffffffdf
+   15f:  cmp al,0x45
+   161:  je <+0x72b>
+   167:  mov edi,0x2
+   16c:  xor eax,eax
+   16e:  jmp <+0x37>
+   173:  nop DWORD PTR [rax+rax*1+0x0]
+   178:  cmp rcx,rsi
+   17b:  jae <+0x2c0>
+   181:  movzx eax,BYTE PTR [rdx+0x1]
+   185:  lea r10,[rdx+0x2]
+   189:  lea ecx,[rax-0x49]
+   18c:  cmp cl,0x2a
+   18f:  ja <+0x546>
+   195:  mov r8d,0x1
+   19b:  mov rax,r8
+   19e:  shl rax,cl
+   1a1:  movabs rcx,0x40000000400
+   1ab:  test rax,rcx
+   1ae:  jne <+0x66a>
+   1b4:  movabs rdi,0x4000000040
+   1be:  and rdi,rax
+   1c1:  jne <+0x691>
+   1c7:  movabs r9,0x100000001
+   1d1:  test rax,r9
+   1d4:  jne <+0x60c>
+   1da:  mov edi,0x1
+   1df:  mov eax,0x4
+   1e4:  jmp <+0x37>
+   1e9:  nop DWORD PTR [rax+0x0]
+   1f0:  cmp rcx,rsi
+   1f3:  jae <+0x2d0>
+   1f9:  movzx eax,BYTE PTR [rdx+0x1]
+   1fd:  lea rcx,[rdx+0x2]
+   201:  cmp al,0x6f
+   203:  je <+0x4a0>
+   209:  ja <+0x350>
+   20f:  cmp al,0x4f
+   211:  je <+0x4a0>
+   217:  cmp al,0x52
+   219:  je <+0x520>
+   21f:  mov edi,0x1
+   224:  mov eax,0x5
+   229:  jmp <+0x37>
+   22e:  xchg ax,ax
+   230:  cmp rcx,rsi
+   233:  jae <+0x258>
+   235:  movzx eax,BYTE PTR [rdx+0x1]
+   239:  and eax,0xffffffdf
+   23c:  cmp al,0x41
+   23e:  je <+0x3dc>
+   244:  mov edi,0x1
+   249:  mov eax,0x6
+   24e:  jmp <+0x37>
+   253:  nop DWORD PTR [rax+rax*1+0x0]
+   258:  mov edi,0x1
+   25d:  mov eax,0x6
+   262:  jmp <+0x37>
+   267:  nop WORD PTR [rax+rax*1+0x0]
+   270:  xor edi,edi
+   272:  mov eax,0x9
+   277:  jmp <+0x37>
+   27c:  nop DWORD PTR [rax+0x0]
+   280:  xor edi,edi
+   282:  mov eax,0x9
+   287:  jmp <+0x37>
+   28c:  nop DWORD PTR [rax+0x0]
+   290:  mov edi,0x1
+   295:  mov eax,0x3
+   29a:  jmp <+0x37>
+   29f:  nop
+   2a0:  mov edi,0x1
+   2a5:  mov eax,0x9
+   2aa:  jmp <+0x37>
+   2af:  nop
+   2b0:  mov edi,0x1
+   2b5:  mov eax,0x7
+   2ba:  jmp <+0x37>
+   2bf:  nop
+   2c0:  mov edi,0x1
+   2c5:  mov eax,0x4
+   2ca:  jmp <+0x37>
+   2cf:  nop
+   2d0:  mov edi,0x1
+   2d5:  mov eax,0x5
+   2da:  jmp <+0x37>
+   2df:  cmp rcx,rsi
+   2e2:  jae <+0x430>
+   2e8:  cmp BYTE PTR [rdx+0x1],0xb5
+   2ec:  jne <+0x2fb>
+   2ee:  lea rax,[rdx+0x2]
+   2f2:  cmp rax,rsi
+   2f5:  jb <+0x710>
+   2fb:  xor edi,edi
+   2fd:  mov eax,0x9
+   302:  jmp <+0x37>
+   307:  nop WORD PTR [rax+rax*1+0x0]
+   310:  cmp al,0x72
+   312:  je <+0x4f0>
+   318:  mov edi,0x1
+   31d:  mov eax,0x9
+   322:  jmp <+0x37>
+   327:  nop WORD PTR [rax+rax*1+0x0]
+   330:  cmp al,0x6b
+   332:  je <+0x4c8>
+   338:  mov edi,0x1
+   33d:  mov eax,0x7
+   342:  jmp <+0x37>
+   347:  nop WORD PTR [rax+rax*1+0x0]
+   350:  cmp al,0x72
+   352:  je <+0x520>
+   358:  mov edi,0x1
+   35d:  mov eax,0x5
+   362:  jmp <+0x37>
+   367:  nop WORD PTR [rax+rax*1+0x0]
+   370:  cmp al,0x73
+   372:  je <+0x14f>
+   378:  xor edi,edi
+   37a:  mov eax,0x9
+   37f:  jmp <+0x37>
+   384:  nop DWORD PTR [rax+0x0]
+   388:  lea rax,[rdx+0x2]
+   38c:  cmp rax,rsi
+   38f:  jae <+0x440>
+   395:  movzx eax,BYTE PTR [rdx+0x2]
+   399:  and eax,0xffffffdf
+   39c:  cmp al,0x45
+   39e:  je <+0x57d>
+   3a4:  mov edi,0x2
+   3a9:  mov eax,0x1
+   3ae:  jmp <+0x37>
+   3b3:  nop DWORD PTR [rax+rax*1+0x0]
+   3b8:  cmp rcx,rsi
+   3bb:  jae <+0x6ca>
+   3c1:  movzx eax,BYTE PTR [rdx+0x2]
+   3c5:  and eax,0xffffffdf
+   3c8:  cmp al,0x4e
+   3ca:  je <+0x555>
+   3d0:  xor edi,edi
+   3d2:  mov eax,0x9
+   3d7:  jmp <+0x37>
+   3dc:  lea rax,[rdx+0x2]
+   3e0:  cmp rax,rsi
+   3e3:  jae <+0x6ee>
+   3e9:  movzx eax,BYTE PTR [rdx+0x2]
+   3ed:  and eax,0xffffffdf
+   3f0:  cmp al,0x59
+   3f2:  je <+0x5e2>
+   3f8:  xor edi,edi
+   3fa:  mov eax,0x9
+   3ff:  jmp <+0x37>
+   404:  lea rax,[rdx+0x2]
+   408:  cmp rax,rsi
+   40b:  jae <+0x6e2>
+   411:  movzx eax,BYTE PTR [rdx+0x2]
+   415:  and eax,0xffffffdf
+   418:  cmp al,0x43
+   41a:  je <+0x5a5>
+   420:  xor edi,edi
+   422:  mov eax,0x9
+   427:  jmp <+0x37>
+   42c:  nop DWORD PTR [rax+0x0]
+   430:  xor edi,edi
+   432:  mov eax,0x9
+   437:  jmp <+0x37>
+   43c:  nop DWORD PTR [rax+0x0]
+   440:  mov edi,0x2
+   445:  mov eax,0x1
+   44a:  jmp <+0x37>
+   44f:  nop
+   450:  cmp rcx,rsi
+   453:  jae <+0x7ed>
+   459:  movzx eax,BYTE PTR [rdx+0x2]
+   45d:  and eax,0xffffffdf
+   460:  cmp al,0x41
+   462:  je <+0x7b9>
+   468:  xor edi,edi
+   46a:  mov eax,0x9
+   46f:  jmp <+0x37>
+   474:  nop DWORD PTR [rax+0x0]
+   478:  cmp rcx,rsi
+   47b:  jae <+0x808>
+   481:  movzx eax,BYTE PTR [rdx+0x2]
+   485:  and eax,0xffffffdf
+   488:  cmp al,0x45
+   48a:  je <+0x769>
+   490:  xor edi,edi
+   492:  mov eax,0x9
+   497:  jmp <+0x37>
+   49c:  nop DWORD PTR [rax+0x0]
+   4a0:  cmp rcx,rsi
+   4a3:  jae <+0x7e1>
+   4a9:  movzx eax,BYTE PTR [rdx+0x2]
+   4ad:  and eax,0xffffffdf
+   4b0:  cmp al,0x55
+   4b2:  je <+0x791>
+   4b8:  xor edi,edi
+   4ba:  mov eax,0x9
+   4bf:  jmp <+0x37>
+   4c4:  nop DWORD PTR [rax+0x0]
+   4c8:  cmp rcx,rsi
+   4cb:  jae <+0x7f9>
+   4d1:  movzx eax,BYTE PTR [rdx+0x2]
+   4d5:  xor edi,edi
+   4d7:  and eax,0xffffffdf
+   4da:  cmp al,0x53
+   4dc:  mov eax,0x7
+   4e1:  sete dil
+   4e5:  add rdi,0x2
+   4e9:  jmp <+0x37>
+   4ee:  xchg ax,ax
+   4f0:  cmp rcx,rsi
+   4f3:  jae <+0x875>
+   4f9:  movzx eax,BYTE PTR [rdx+0x2]
+   4fd:  xor edi,edi
+   4ff:  and eax,0xffffffdf
+   502:  cmp al,0x53
+   504:  mov eax,0x9
+   509:  sete dil
+   50d:  add rdi,0x2
+   511:  jmp <+0x37>
+   516:  cs nop WORD PTR [rax+rax*1+0x0]
+   520:  cmp rcx,rsi
+   523:  jae <+0x814>
+   529:  movzx eax,BYTE PTR [rdx+0x2]
+   52d:  xor edi,edi
+   52f:  and eax,0xffffffdf
+   532:  cmp al,0x53
+   534:  mov eax,0x5
+   539:  sete dil
+   53d:  add rdi,0x2
+   541:  jmp <+0x37>
+   546:  mov edi,0x1
+   54b:  mov eax,0x4
+   550:  jmp <+0x37>
+   555:  lea rax,[rdx+0x3]
+   559:  cmp rax,rsi
+   55c:  jae <+0x8d9>
+   562:  movzx eax,BYTE PTR [rdx+0x3]
+   566:  and eax,0xffffffdf
+   569:  cmp al,0x4f
+   56b:  je <+0x84d>
+   571:  xor edi,edi
+   573:  mov eax,0x9
+   578:  jmp <+0x37>
+   57d:  lea rax,[rdx+0x3]
+   581:  cmp rax,rsi
+   584:  jae <+0x8be>
+   58a:  movzx eax,BYTE PTR [rdx+0x3]
+   58e:  and eax,0xffffffdf
+   591:  cmp al,0x43
+   593:  je <+0x823>
+   599:  xor edi,edi
+   59b:  mov eax,0x9
+   5a0:  jmp <+0x37>
+   5a5:  lea rax,[rdx+0x3]
+   5a9:  cmp rax,rsi
+   5ac:  jae <+0x8af>
+   5b2:  movzx eax,BYTE PTR [rdx+0x3]
+   5b6:  cmp al,0x6f
+   5b8:  je <+0xa9b>
+   5be:  ja <+0x6fa>
+   5c4:  cmp al,0x4f
+   5c6:  je <+0xa9b>
+   5cc:  xor edi,edi
+   5ce:  cmp al,0x53
+   5d0:  mov eax,0x3
+   5d5:  sete dil
+   5d9:  add rdi,0x3
+   5dd:  jmp <+0x37>
+   5e2:  lea rax,[rdx+0x3]
+   5e6:  cmp rax,rsi
+   5e9:  jae <+0x8ca>
+   5ef:  movzx eax,BYTE PTR [rdx+0x3]
+   5f3:  xor edi,edi
+   5f5:  and eax,0xffffffdf
+   5f8:  cmp al,0x53
+   5fa:  mov eax,0x6
+   5ff:  sete dil
+   603:  add rdi,0x3
+   607:  jmp <+0x37>
+   60c:  mov eax,0x9
+   611:  cmp r10,rsi
+   614:  jae <+0x37>
+   61a:  movzx ecx,BYTE PTR [rdx+0x2]
+   61e:  lea r10,[rdx+0x3]
+   622:  sub ecx,0x43
+   625:  cmp cl,0x2b
+   628:  ja <+0x37>
+   62e:  shl r8,cl
+   631:  test r8,r9
+   634:  jne <+0xa5e>
+   63a:  movabs rax,0x20000000200
+   644:  test r8,rax
+   647:  jne <+0xa3a>
+   64d:  movabs rdi,0x80000000800
+   657:  and rdi,r8
+   65a:  jne <+0xa00>
+   660:  mov eax,0x9
+   665:  jmp <+0x37>
+   66a:  cmp r10,rsi
+   66d:  jae <+0x8e5>
+   673:  movzx eax,BYTE PTR [rdx+0x2]
+   677:  and eax,0xffffffdf
+   67a:  cmp al,0x45
+   67c:  je <+0x9a8>
+   682:  mov edi,0x2
+   687:  mov eax,0x2
+   68c:  jmp <+0x37>
+   691:  cmp r10,rsi
+   694:  jae <+0x8f4>
+   69a:  movzx eax,BYTE PTR [rdx+0x2]
+   69e:  cmp al,0x6e
+   6a0:  je <+0xad2>
+   6a6:  ja <+0x753>
+   6ac:  cmp al,0x4e
+   6ae:  je <+0xad2>
+   6b4:  xor edi,edi
+   6b6:  cmp al,0x53
+   6b8:  mov eax,0x8
+   6bd:  sete dil
+   6c1:  add rdi,0x2
+   6c5:  jmp <+0x37>
+   6ca:  xor edi,edi
+   6cc:  mov eax,0x9
+   6d1:  jmp <+0x37>
+   6d6:  mov edi,0x2
+   6db:  xor eax,eax
+   6dd:  jmp <+0x37>
+   6e2:  xor edi,edi
+   6e4:  mov eax,0x9
+   6e9:  jmp <+0x37>
+   6ee:  xor edi,edi
+   6f0:  mov eax,0x9
+   6f5:  jmp <+0x37>
+   6fa:  xor edi,edi
+   6fc:  cmp al,0x73
+   6fe:  mov eax,0x3
+   703:  sete dil
+   707:  add rdi,0x3
+   70b:  jmp <+0x37>
+   710:  movzx eax,BYTE PTR [rdx+0x2]
+   714:  and eax,0xffffffdf
+   717:  cmp al,0x53
+   719:  je <+0x884>
+   71f:  xor edi,edi
+   721:  mov eax,0x9
+   726:  jmp <+0x37>
+   72b:  lea rax,[rdx+0x3]
+   72f:  cmp rax,rsi
+   732:  jae <+0x9d0>
+   738:  movzx eax,BYTE PTR [rdx+0x3]
+   73c:  and eax,0xffffffdf
+   73f:  cmp al,0x43
+   741:  je <+0x903>
+   747:  xor edi,edi
+   749:  mov eax,0x9
+   74e:  jmp <+0x37>
+   753:  xor edi,edi
+   755:  cmp al,0x73
+   757:  mov eax,0x8
+   75c:  sete dil
+   760:  add rdi,0x2
+   764:  jmp <+0x37>
+   769:  lea rax,[rdx+0x3]
+   76d:  cmp rax,rsi
+   770:  jae <+0x9e8>
+   776:  movzx eax,BYTE PTR [rdx+0x3]
+   77a:  and eax,0xffffffdf
+   77d:  cmp al,0x4b
+   77f:  je <+0x92a>
+   785:  xor edi,edi
+   787:  mov eax,0x9
+   78c:  jmp <+0x37>
+   791:  lea rax,[rdx+0x3]
+   795:  cmp rax,rsi
+   798:  jae <+0x9dc>
+   79e:  movzx eax,BYTE PTR [rdx+0x3]
+   7a2:  and eax,0xffffffdf
+   7a5:  cmp al,0x52
+   7a7:  je <+0x954>
+   7ad:  xor edi,edi
+   7af:  mov eax,0x9
+   7b4:  jmp <+0x37>
+   7b9:  lea rax,[rdx+0x3]
+   7bd:  cmp rax,rsi
+   7c0:  jae <+0x9f4>
+   7c6:  movzx eax,BYTE PTR [rdx+0x3]
+   7ca:  and eax,0xffffffdf
+   7cd:  cmp al,0x52
+   7cf:  je <+0x97e>
+   7d5:  xor edi,edi
+   7d7:  mov eax,0x9
+   7dc:  jmp <+0x37>
+   7e1:  xor edi,edi
+   7e3:  mov eax,0x9
+   7e8:  jmp <+0x37>
+   7ed:  xor edi,edi
+   7ef:  mov eax,0x9
+   7f4:  jmp <+0x37>
+   7f9:  mov edi,0x2
+   7fe:  mov eax,0x7
+   803:  jmp <+0x37>
+   808:  xor edi,edi
+   80a:  mov eax,0x9
+   80f:  jmp <+0x37>
+   814:  mov edi,0x2
+   819:  mov eax,0x5
+   81e:  jmp <+0x37>
+   823:  lea rax,[rdx+0x4]
+   827:  cmp rax,rsi
+   82a:  jae <+0xa8c>
+   830:  movzx eax,BYTE PTR [rdx+0x4]
+   834:  xor edi,edi
+   836:  and eax,0xffffffdf
+   839:  cmp al,0x53
+   83b:  mov eax,0x1
+   840:  sete dil
+   844:  add rdi,0x4
+   848:  jmp <+0x37>
+   84d:  lea rax,[rdx+0x4]
+   851:  cmp rax,rsi
+   854:  jae <+0xa80>
+   85a:  movzx eax,BYTE PTR [rdx+0x4]
+   85e:  and eax,0xffffffdf
+   861:  cmp al,0x53
+   863:  je <+0xb22>
+   869:  mov edi,0x4
+   86e:  xor eax,eax
+   870:  jmp <+0x37>
+   875:  mov edi,0x2
+   87a:  mov eax,0x9
+   87f:  jmp <+0x37>
+   884:  lea rax,[rdx+0x3]
+   888:  cmp rax,rsi
+   88b:  jae <+0xac3>
+   891:  movzx eax,BYTE PTR [rdx+0x3]
+   895:  and eax,0xffffffdf
+   898:  cmp al,0x45
+   89a:  je <+0xafa>
+   8a0:  mov edi,0x3
+   8a5:  mov eax,0x1
+   8aa:  jmp <+0x37>
+   8af:  mov edi,0x3
+   8b4:  mov eax,0x3
+   8b9:  jmp <+0x37>
+   8be:  xor edi,edi
+   8c0:  mov eax,0x9
+   8c5:  jmp <+0x37>
+   8ca:  mov edi,0x3
+   8cf:  mov eax,0x6
+   8d4:  jmp <+0x37>
+   8d9:  xor edi,edi
+   8db:  mov eax,0x9
+   8e0:  jmp <+0x37>
+   8e5:  mov edi,0x2
+   8ea:  mov eax,0x2
+   8ef:  jmp <+0x37>
+   8f4:  mov edi,0x2
+   8f9:  mov eax,0x8
+   8fe:  jmp <+0x37>
+   903:  lea rax,[rdx+0x4]
+   907:  cmp rax,rsi
+   90a:  jae <+0xb8b>
+   910:  movzx eax,BYTE PTR [rdx+0x4]
+   914:  xor edi,edi
+   916:  and eax,0xffffffdf
+   919:  cmp al,0x53
+   91b:  sete dil
+   91f:  xor eax,eax
+   921:  add rdi,0x4
+   925:  jmp <+0x37>
+   92a:  lea rax,[rdx+0x4]
+   92e:  cmp rax,rsi
+   931:  jae <+0xb97>
+   937:  movzx eax,BYTE PTR [rdx+0x4]
+   93b:  xor edi,edi
+   93d:  and eax,0xffffffdf
+   940:  cmp al,0x53
+   942:  mov eax,0x7
+   947:  sete dil
+   94b:  add rdi,0x4
+   94f:  jmp <+0x37>
+   954:  lea rax,[rdx+0x4]
+   958:  cmp rax,rsi
+   95b:  jae <+0xbb5>
+   961:  movzx eax,BYTE PTR [rdx+0x4]
+   965:  xor edi,edi
+   967:  and eax,0xffffffdf
+   96a:  cmp al,0x53
+   96c:  mov eax,0x5
+   971:  sete dil
+   975:  add rdi,0x4
+   979:  jmp <+0x37>
+   97e:  lea rax,[rdx+0x4]
+   982:  cmp rax,rsi
+   985:  jae <+0xba6>
+   98b:  movzx eax,BYTE PTR [rdx+0x4]
+   98f:  xor edi,edi
+   991:  and eax,0xffffffdf
+   994:  cmp al,0x53
+   996:  mov eax,0x9
+   99b:  sete dil
+   99f:  add rdi,0x4
+   9a3:  jmp <+0x37>
+   9a8:  lea rax,[rdx+0x3]
+   9ac:  cmp rax,rsi
+   9af:  jae <+0xbc4>
+   9b5:  movzx eax,BYTE PTR [rdx+0x3]
+   9b9:  and eax,0xffffffdf
+   9bc:  cmp al,0x43
+   9be:  je <+0xb4a>
+   9c4:  xor edi,edi
+   9c6:  mov eax,0x9
+   9cb:  jmp <+0x37>
+   9d0:  xor edi,edi
+   9d2:  mov eax,0x9
+   9d7:  jmp <+0x37>
+   9dc:  xor edi,edi
+   9de:  mov eax,0x9
+   9e3:  jmp <+0x37>
+   9e8:  xor edi,edi
+   9ea:  mov eax,0x9
+   9ef:  jmp <+0x37>
+   9f4:  xor edi,edi
+   9f6:  mov eax,0x9
+   9fb:  jmp <+0x37>
+   a00:  cmp r10,rsi
+   a03:  jae <+0xc7a>
+   a09:  movzx eax,BYTE PTR [rdx+0x3]
+   a0d:  cmp al,0x73
+   a0f:  je <+0xeeb>
+   a15:  ja <+0xb74>
+   a1b:  cmp al,0x53
+   a1d:  je <+0xdae>
+   a23:  cmp al,0x55
+   a25:  je <+0xd7c>
+   a2b:  mov edi,0x3
+   a30:  mov eax,0x4
+   a35:  jmp <+0x37>
+   a3a:  cmp r10,rsi
+   a3d:  jae <+0xc1e>
+   a43:  movzx eax,BYTE PTR [rdx+0x3]
+   a47:  and eax,0xffffffdf
+   a4a:  cmp al,0x4c
+   a4c:  je <+0xbf6>
+   a52:  xor edi,edi
+   a54:  mov eax,0x9
+   a59:  jmp <+0x37>
+   a5e:  cmp r10,rsi
+   a61:  jae <+0x37>
+   a67:  movzx eax,BYTE PTR [rdx+0x3]
+   a6b:  and eax,0xffffffdf
+   a6e:  cmp al,0x52
+   a70:  je <+0xbd0>
+   a76:  mov eax,0x9
+   a7b:  jmp <+0x37>
+   a80:  mov edi,0x4
+   a85:  xor eax,eax
+   a87:  jmp <+0x37>
+   a8c:  mov edi,0x4
+   a91:  mov eax,0x1
+   a96:  jmp <+0x37>
+   a9b:  lea rax,[rdx+0x4]
+   a9f:  cmp rax,rsi
+   aa2:  jae <+0xc95>
+   aa8:  movzx eax,BYTE PTR [rdx+0x4]
+   aac:  and eax,0xffffffdf
+   aaf:  cmp al,0x4e
+   ab1:  je <+0xc52>
+   ab7:  xor edi,edi
+   ab9:  mov eax,0x9
+   abe:  jmp <+0x37>
+   ac3:  mov edi,0x3
+   ac8:  mov eax,0x1
+   acd:  jmp <+0x37>
+   ad2:  lea rax,[rdx+0x3]
+   ad6:  cmp rax,rsi
+   ad9:  jae <+0xc89>
+   adf:  movzx eax,BYTE PTR [rdx+0x3]
+   ae3:  and eax,0xffffffdf
+   ae6:  cmp al,0x54
+   ae8:  je <+0xc2a>
+   aee:  xor edi,edi
+   af0:  mov eax,0x9
+   af5:  jmp <+0x37>
+   afa:  lea rax,[rdx+0x4]
+   afe:  cmp rax,rsi
+   b01:  jae <+0xccb>
+   b07:  movzx eax,BYTE PTR [rdx+0x4]
+   b0b:  and eax,0xffffffdf
+   b0e:  cmp al,0x43
+   b10:  je <+0xca1>
+   b16:  xor edi,edi
+   b18:  mov eax,0x9
+   b1d:  jmp <+0x37>
+   b22:  lea rax,[rdx+0x5]
+   b26:  cmp rax,rsi
+   b29:  jae <+0xd64>
+   b2f:  movzx eax,BYTE PTR [rdx+0x5]
+   b33:  and eax,0xffffffdf
+   b36:  cmp al,0x45
+   b38:  je <+0xd3c>
+   b3e:  mov edi,0x5
+   b43:  xor eax,eax
+   b45:  jmp <+0x37>
+   b4a:  lea rax,[rdx+0x4]
+   b4e:  cmp rax,rsi
+   b51:  jae <+0xd02>
+   b57:  movzx eax,BYTE PTR [rdx+0x4]
+   b5b:  xor edi,edi
+   b5d:  and eax,0xffffffdf
+   b60:  cmp al,0x53
+   b62:  mov eax,0x2
+   b67:  sete dil
+   b6b:  add rdi,0x4
+   b6f:  jmp <+0x37>
+   b74:  cmp al,0x75
+   b76:  je <+0xd7c>
+   b7c:  mov edi,0x3
+   b81:  mov eax,0x4
+   b86:  jmp <+0x37>
+   b8b:  mov edi,0x4
+   b90:  xor eax,eax
+   b92:  jmp <+0x37>
+   b97:  mov edi,0x4
+   b9c:  mov eax,0x7
+   ba1:  jmp <+0x37>
+   ba6:  mov edi,0x4
+   bab:  mov eax,0x9
+   bb0:  jmp <+0x37>
+   bb5:  mov edi,0x4
+   bba:  mov eax,0x5
+   bbf:  jmp <+0x37>
+   bc4:  xor edi,edi
+   bc6:  mov eax,0x9
+   bcb:  jmp <+0x37>
+   bd0:  lea rax,[rdx+0x4]
+   bd4:  cmp rax,rsi
+   bd7:  jae <+0xda4>
+   bdd:  movzx eax,BYTE PTR [rdx+0x4]
+   be1:  and eax,0xffffffdf
+   be4:  cmp al,0x4f
+   be6:  je <+0xcd7>
+   bec:  mov eax,0x9
+   bf1:  jmp <+0x37>
+   bf6:  lea rax,[rdx+0x4]
+   bfa:  cmp rax,rsi
+   bfd:  jae <+0xd70>
+   c03:  movzx eax,BYTE PTR [rdx+0x4]
+   c07:  and eax,0xffffffdf
+   c0a:  cmp al,0x49
+   c0c:  je <+0xd11>
+   c12:  xor edi,edi
+   c14:  mov eax,0x9
+   c19:  jmp <+0x37>
+   c1e:  xor edi,edi
+   c20:  mov eax,0x9
+   c25:  jmp <+0x37>
+   c2a:  lea rax,[rdx+0x4]
+   c2e:  cmp rax,rsi
+   c31:  jae <+0xe19>
+   c37:  movzx eax,BYTE PTR [rdx+0x4]
+   c3b:  and eax,0xffffffdf
+   c3e:  cmp al,0x48
+   c40:  je <+0xdf3>
+   c46:  xor edi,edi
+   c48:  mov eax,0x9
+   c4d:  jmp <+0x37>
+   c52:  lea rax,[rdx+0x5]
+   c56:  cmp rax,rsi
+   c59:  jae <+0xde7>
+   c5f:  movzx eax,BYTE PTR [rdx+0x5]
+   c63:  and eax,0xffffffdf
+   c66:  cmp al,0x44
+   c68:  je <+0xdbd>
+   c6e:  xor edi,edi
+   c70:  mov eax,0x9
+   c75:  jmp <+0x37>
+   c7a:  mov edi,0x3
+   c7f:  mov eax,0x4
+   c84:  jmp <+0x37>
+   c89:  xor edi,edi
+   c8b:  mov eax,0x9
+   c90:  jmp <+0x37>
+   c95:  xor edi,edi
+   c97:  mov eax,0x9
+   c9c:  jmp <+0x37>
+   ca1:  lea rax,[rdx+0x5]
+   ca5:  cmp rax,rsi
+   ca8:  jae <+0xefa>
+   cae:  movzx eax,BYTE PTR [rdx+0x5]
+   cb2:  xor edi,edi
+   cb4:  and eax,0xffffffdf
+   cb7:  cmp al,0x53
+   cb9:  mov eax,0x1
+   cbe:  sete dil
+   cc2:  add rdi,0x5
+   cc6:  jmp <+0x37>
+   ccb:  xor edi,edi
+   ccd:  mov eax,0x9
+   cd2:  jmp <+0x37>
+   cd7:  lea rax,[rdx+0x5]
+   cdb:  cmp rax,rsi
+   cde:  jae <+0xe5f>
+   ce4:  movzx eax,BYTE PTR [rdx+0x5]
+   ce8:  and eax,0xffffffdf
+   ceb:  cmp al,0x53
+   ced:  je <+0xe34>
+   cf3:  mov edi,0x5
+   cf8:  mov eax,0x1
+   cfd:  jmp <+0x37>
+   d02:  mov edi,0x4
+   d07:  mov eax,0x2
+   d0c:  jmp <+0x37>
+   d11:  lea rax,[rdx+0x5]
+   d15:  cmp rax,rsi
+   d18:  jae <+0xedc>
+   d1e:  movzx eax,BYTE PTR [rdx+0x5]
+   d22:  and eax,0xffffffdf
+   d25:  cmp al,0x53
+   d27:  je <+0xeb1>
+   d2d:  mov edi,0x5
+   d32:  mov eax,0x2
+   d37:  jmp <+0x37>
+   d3c:  lea rax,[rdx+0x6]
+   d40:  cmp rax,rsi
+   d43:  jae <+0xf29>
+   d49:  movzx eax,BYTE PTR [rdx+0x6]
+   d4d:  and eax,0xffffffdf
+   d50:  cmp al,0x43
+   d52:  je <+0xf09>
+   d58:  xor edi,edi
+   d5a:  mov eax,0x9
+   d5f:  jmp <+0x37>
+   d64:  mov edi,0x5
+   d69:  xor eax,eax
+   d6b:  jmp <+0x37>
+   d70:  xor edi,edi
+   d72:  mov eax,0x9
+   d77:  jmp <+0x37>
+   d7c:  lea rax,[rdx+0x4]
+   d80:  cmp rax,rsi
+   d83:  jae <+0xea5>
+   d89:  movzx eax,BYTE PTR [rdx+0x4]
+   d8d:  and eax,0xffffffdf
+   d90:  cmp al,0x54
+   d92:  je <+0xe7d>
+   d98:  xor edi,edi
+   d9a:  mov eax,0x9
+   d9f:  jmp <+0x37>
+   da4:  mov eax,0x9
+   da9:  jmp <+0x37>
+   dae:  mov edi,0x4
+   db3:  mov eax,0x4
+   db8:  jmp <+0x37>
+   dbd:  lea rax,[rdx+0x6]
+   dc1:  cmp rax,rsi
+   dc4:  jae <+0xe6e>
+   dca:  movzx eax,BYTE PTR [rdx+0x6]
+   dce:  xor edi,edi
+   dd0:  and eax,0xffffffdf
+   dd3:  cmp al,0x53
+   dd5:  mov eax,0x3
+   dda:  sete dil
+   dde:  add rdi,0x6
+   de2:  jmp <+0x37>
+   de7:  xor edi,edi
+   de9:  mov eax,0x9
+   dee:  jmp <+0x37>
+   df3:  lea rax,[rdx+0x5]
+   df7:  cmp rax,rsi
+   dfa:  jae <+0xe25>
+   dfc:  movzx eax,BYTE PTR [rdx+0x5]
+   e00:  xor edi,edi
+   e02:  and eax,0xffffffdf
+   e05:  cmp al,0x53
+   e07:  mov eax,0x8
+   e0c:  sete dil
+   e10:  add rdi,0x5
+   e14:  jmp <+0x37>
+   e19:  xor edi,edi
+   e1b:  mov eax,0x9
+   e20:  jmp <+0x37>
+   e25:  mov edi,0x5
+   e2a:  mov eax,0x8
+   e2f:  jmp <+0x37>
+   e34:  lea rax,[rdx+0x6]
+   e38:  cmp rax,rsi
+   e3b:  jae <+0xf93>
+   e41:  movzx eax,BYTE PTR [rdx+0x6]
+   e45:  and eax,0xffffffdf
+   e48:  cmp al,0x45
+   e4a:  je <+0xf75>
+   e50:  mov edi,0x6
+   e55:  mov eax,0x1
+   e5a:  jmp <+0x37>
+   e5f:  mov edi,0x5
+   e64:  mov eax,0x1
+   e69:  jmp <+0x37>
+   e6e:  mov edi,0x6
+   e73:  mov eax,0x3
+   e78:  jmp <+0x37>
+   e7d:  lea rax,[rdx+0x5]
+   e81:  cmp rax,rsi
+   e84:  jae <+0x107f>
+   e8a:  movzx eax,BYTE PTR [rdx+0x5]
+   e8e:  and eax,0xffffffdf
+   e91:  cmp al,0x45
+   e93:  je <+0x1059>
+   e99:  xor edi,edi
+   e9b:  mov eax,0x9
+   ea0:  jmp <+0x37>
+   ea5:  xor edi,edi
+   ea7:  mov eax,0x9
+   eac:  jmp <+0x37>
+   eb1:  lea rax,[rdx+0x6]
+   eb5:  cmp rax,rsi
+   eb8:  jae <+0x10ba>
+   ebe:  movzx eax,BYTE PTR [rdx+0x6]
+   ec2:  and eax,0xffffffdf
+   ec5:  cmp al,0x45
+   ec7:  je <+0x109a>
+   ecd:  mov edi,0x6
+   ed2:  mov eax,0x2
+   ed7:  jmp <+0x37>
+   edc:  mov edi,0x5
+   ee1:  mov eax,0x2
+   ee6:  jmp <+0x37>
+   eeb:  mov edi,0x4
+   ef0:  mov eax,0x4
+   ef5:  jmp <+0x37>
+   efa:  mov edi,0x5
+   eff:  mov eax,0x1
+   f04:  jmp <+0x37>
+   f09:  lea rax,[rdx+0x7]
+   f0d:  cmp rax,rsi
+   f10:  jae <+0xf69>
+   f12:  movzx eax,BYTE PTR [rdx+0x7]
+   f16:  and eax,0xffffffdf
+   f19:  cmp al,0x4f
+   f1b:  je <+0xf35>
+   f1d:  xor edi,edi
+   f1f:  mov eax,0x9
+   f24:  jmp <+0x37>
+   f29:  xor edi,edi
+   f2b:  mov eax,0x9
+   f30:  jmp <+0x37>
+   f35:  lea rax,[rdx+0x8]
+   f39:  cmp rax,rsi
+   f3c:  jae <+0x11ae>
+   f42:  movzx eax,BYTE PTR [rdx+0x8]
+   f46:  and eax,0xffffffdf
+   f49:  cmp al,0x4e
+   f4b:  je <+0x118e>
+   f51:  xor edi,edi
+   f53:  mov eax,0x9
+   f58:  jmp <+0x37>
+   f5d:  xor edi,edi
+   f5f:  mov eax,0x9
+   f64:  jmp <+0x37>
+   f69:  xor edi,edi
+   f6b:  mov eax,0x9
+   f70:  jmp <+0x37>
+   f75:  lea rax,[rdx+0x7]
+   f79:  cmp rax,rsi
+   f7c:  jae <+0xfc0>
+   f7e:  movzx eax,BYTE PTR [rdx+0x7]
+   f82:  and eax,0xffffffdf
+   f85:  cmp al,0x43
+   f87:  je <+0xfa2>
+   f89:  mov eax,0x9
+   f8e:  jmp <+0x37>
+   f93:  mov edi,0x6
+   f98:  mov eax,0x1
+   f9d:  jmp <+0x37>
+   fa2:  lea rax,[rdx+0x8]
+   fa6:  cmp rax,rsi
+   fa9:  jae <+0xfe8>
+   fab:  movzx eax,BYTE PTR [rdx+0x8]
+   faf:  and eax,0xffffffdf
+   fb2:  cmp al,0x4f
+   fb4:  je <+0xfca>
+   fb6:  mov eax,0x9
+   fbb:  jmp <+0x37>
+   fc0:  mov eax,0x9
+   fc5:  jmp <+0x37>
+   fca:  lea rax,[rdx+0x9]
+   fce:  cmp rax,rsi
+   fd1:  jae <+0x1010>
+   fd3:  movzx eax,BYTE PTR [rdx+0x9]
+   fd7:  and eax,0xffffffdf
+   fda:  cmp al,0x4e
+   fdc:  je <+0xff2>
+   fde:  mov eax,0x9
+   fe3:  jmp <+0x37>
+   fe8:  mov eax,0x9
+   fed:  jmp <+0x37>
+   ff2:  lea rax,[rdx+0xa]
+   ff6:  cmp rax,rsi
+   ff9:  jae <+0x1040>
+   ffb:  movzx eax,BYTE PTR [rdx+0xa]
+   fff:  and eax,0xffffffdf
+  1002:  cmp al,0x44
+  1004:  je <+0x101a>
+  1006:  mov eax,0x9
+  100b:  jmp <+0x37>
+  1010:  mov eax,0x9
+  1015:  jmp <+0x37>
+  101a:  lea rax,[rdx+0xb]
+  101e:  cmp rax,rsi
+  1021:  jae <+0x104a>
+  1023:  movzx eax,BYTE PTR [rdx+0xb]
+  1027:  xor edi,edi
+  1029:  and eax,0xffffffdf
+  102c:  cmp al,0x53
+  102e:  mov eax,0x1
+  1033:  sete dil
+  1037:  add rdi,0xb
+  103b:  jmp <+0x37>
+  1040:  mov eax,0x9
+  1045:  jmp <+0x37>
+  104a:  mov edi,0xb
+  104f:  mov eax,0x1
+  1054:  jmp <+0x37>
+  1059:  lea rax,[rdx+0x6]
+  105d:  cmp rax,rsi
+  1060:  jae <+0x108b>
+  1062:  movzx eax,BYTE PTR [rdx+0x6]
+  1066:  xor edi,edi
+  1068:  and eax,0xffffffdf
+  106b:  cmp al,0x53
+  106d:  mov eax,0x4
+  1072:  sete dil
+  1076:  add rdi,0x6
+  107a:  jmp <+0x37>
+  107f:  xor edi,edi
+  1081:  mov eax,0x9
+  1086:  jmp <+0x37>
+  108b:  mov edi,0x6
+  1090:  mov eax,0x4
+  1095:  jmp <+0x37>
+  109a:  lea rax,[rdx+0x7]
+  109e:  cmp rax,rsi
+  10a1:  jae <+0x10e9>
+  10a3:  movzx eax,BYTE PTR [rdx+0x7]
+  10a7:  and eax,0xffffffdf
+  10aa:  cmp al,0x43
+  10ac:  je <+0x10c9>
+  10ae:  xor edi,edi
+  10b0:  mov eax,0x9
+  10b5:  jmp <+0x37>
+  10ba:  mov edi,0x6
+  10bf:  mov eax,0x2
+  10c4:  jmp <+0x37>
+  10c9:  lea rax,[rdx+0x8]
+  10cd:  cmp rax,rsi
+  10d0:  jae <+0x1115>
+  10d2:  movzx eax,BYTE PTR [rdx+0x8]
+  10d6:  and eax,0xffffffdf
+  10d9:  cmp al,0x4f
+  10db:  je <+0x10f5>
+  10dd:  xor edi,edi
+  10df:  mov eax,0x9
+  10e4:  jmp <+0x37>
+  10e9:  xor edi,edi
+  10eb:  mov eax,0x9
+  10f0:  jmp <+0x37>
+  10f5:  lea rax,[rdx+0x9]
+  10f9:  cmp rax,rsi
+  10fc:  jae <+0x1141>
+  10fe:  movzx eax,BYTE PTR [rdx+0x9]
+  1102:  and eax,0xffffffdf
+  1105:  cmp al,0x4e
+  1107:  je <+0x1121>
+  1109:  xor edi,edi
+  110b:  mov eax,0x9
+  1110:  jmp <+0x37>
+  1115:  xor edi,edi
+  1117:  mov eax,0x9
+  111c:  jmp <+0x37>
+  1121:  lea rax,[rdx+0xa]
+  1125:  cmp rax,rsi
+  1128:  jae <+0x1173>
+  112a:  movzx eax,BYTE PTR [rdx+0xa]
+  112e:  and eax,0xffffffdf
+  1131:  cmp al,0x44
+  1133:  je <+0x114d>
+  1135:  xor edi,edi
+  1137:  mov eax,0x9
+  113c:  jmp <+0x37>
+  1141:  xor edi,edi
+  1143:  mov eax,0x9
+  1148:  jmp <+0x37>
+  114d:  lea rax,[rdx+0xb]
+  1151:  cmp rax,rsi
+  1154:  jae <+0x117f>
+  1156:  movzx eax,BYTE PTR [rdx+0xb]
+  115a:  xor edi,edi
+  115c:  and eax,0xffffffdf
+  115f:  cmp al,0x53
+  1161:  mov eax,0x2
+  1166:  sete dil
+  116a:  add rdi,0xb
+  116e:  jmp <+0x37>
+  1173:  xor edi,edi
+  1175:  mov eax,0x9
+  117a:  jmp <+0x37>
+  117f:  mov edi,0xb
+  1184:  mov eax,0x2
+  1189:  jmp <+0x37>
+  118e:  lea rax,[rdx+0x9]
+  1192:  cmp rax,rsi
+  1195:  jae <+0x11dd>
+  1197:  movzx eax,BYTE PTR [rdx+0x9]
+  119b:  and eax,0xffffffdf
+  119e:  cmp al,0x44
+  11a0:  je <+0x11ba>
+  11a2:  xor edi,edi
+  11a4:  mov eax,0x9
+  11a9:  jmp <+0x37>
+  11ae:  xor edi,edi
+  11b0:  mov eax,0x9
+  11b5:  jmp <+0x37>
+  11ba:  lea rax,[rdx+0xa]
+  11be:  cmp rax,rsi
+  11c1:  jae <+0x11e9>
+  11c3:  movzx eax,BYTE PTR [rdx+0xa]
+  11c7:  xor edi,edi
+  11c9:  and eax,0xffffffdf
+  11cc:  cmp al,0x53
+  11ce:  sete dil
+  11d2:  xor eax,eax
+  11d4:  add rdi,0xa
+  11d8:  jmp <+0x37>
+  11dd:  xor edi,edi
+  11df:  mov eax,0x9
+  11e4:  jmp <+0x37>
+  11e9:  mov edi,0xa
+  11ee:  xor eax,eax
+  11f0:  jmp <+0x37>
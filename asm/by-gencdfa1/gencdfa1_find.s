@@ -0,0 +1,863 @@
+gencdfa1_find:
+     0:  mov rdx,rdi
+     3:  mov rcx,rsi
+     6:  cmp rdi,rsi
+     9:  jae <+0x90>
+     f:  movzx eax,BYTE PTR [rdx]
+    12:  lea rdi,[rdi+0x1]
+    16:  cmp al,0x79
+    18:  ja <+0x40>
+    1a:  cmp al,0x63
+    1c:  jbe <+0x380>
+    22:  sub eax,0x64
+    25:  cmp al,0x15
+    27:  ja <+0x80>
+    29:  lea rsi,[rip]
+    30:  movzx eax,al
+    33:  movsxd rax,DWORD PTR [rsi+rax*4]
+    37:  add rax,rsi
+    3a:  jmp rax
+    3c:  nop DWORD PTR [rax+0x0]
+    40:  cmp al,0xc2
+    42:  jne <+0x70>
+    44:  cmp rdi,rsi
+    47:  jae <+0x3e0>
+    4d:  cmp BYTE PTR [rdx+0x1],0xb5
+    51:  jne <+0x60>
+    53:  lea rax,[rdx+0x2]
+    57:  cmp rax,rsi
+    5a:  jb <+0x458>
+    60:  xor esi,esi
+    62:  mov eax,0x9
+    67:  jmp <+0x77>
+    69:  nop DWORD PTR [rax+0x0]
+    70:  xor esi,esi
+    72:  mov eax,0x9
+    77:  mov rdx,rsi
+    7a:  ret
+    7b:  nop DWORD PTR [rax+rax*1+0x0]
+    80:  xor esi,esi
+    82:  mov eax,0x9
+    87:  mov rdx,rsi
+    8a:  ret
+    8b:  nop DWORD PTR [rax+rax*1+0x0]
+    90:  xor esi,esi
+    92:  mov eax,0x9
+    97:  mov rdx,rsi
+    9a:  ret
+    9b:  nop DWORD PTR [rax+rax*1+0x0]
+    a0:  cmp rdi,rcx
+    a3:  jae <+0x3f0>
+    a9:  movzx eax,BYTE PTR [rdx+0x1]
+    ad:  lea rsi,[rdx+0x2]
+    b1:  cmp al,0x65
+    b3:  je <+0x4f9>
+    b9:  cmp al,0x72
+    bb:  je <+0x4d8>
+    c1:  mov esi,0x1
+    c6:  mov eax,0x9
+    cb:  jmp <+0x77>
+    cd:  nop DWORD PTR [rax]
+    d0:  cmp rdi,rcx
+    d3:  jae <+0x390>
+    d9:  movzx eax,BYTE PTR [rdx+0x1]
+    dd:  lea rsi,[rdx+0x2]
+    e1:  cmp al,0x65
+    e3:  je <+0x521>
+    e9:  cmp al,0x6b
+    eb:  je <+0x4b0>
+    f1:  mov esi,0x1
+    f6:  mov eax,0x7
+    fb:  jmp <+0x77>
+   100:  cmp rdi,rcx
+   103:  jae <+0x3d0>
+   109:  xor esi,esi
+   10b:  cmp BYTE PTR [rdx+0x1],0x73
+   10f:  mov eax,0x9
+   114:  jne <+0x77>
+   11a:  lea rax,[rdx+0x2]
+   11e:  cmp rax,rcx
+   121:  jae <+0x60b>
+   127:  cmp BYTE PTR [rdx+0x2],0x65
+   12b:  mov esi,0x2
+   130:  mov eax,0x1
+   135:  jne <+0x77>
+   13b:  lea rax,[rdx+0x3]
+   13f:  cmp rax,rcx
+   142:  jae <+0x7c0>
+   148:  xor esi,esi
+   14a:  cmp BYTE PTR [rdx+0x3],0x63
+   14e:  mov eax,0x9
+   153:  jne <+0x77>
+   159:  lea rax,[rdx+0x4]
+   15d:  cmp rax,rcx
+   160:  jae <+0x91a>
+   166:  xor esi,esi
+   168:  cmp BYTE PTR [rdx+0x4],0x73
+   16c:  mov eax,0x1
+   171:  sete sil
+   175:  add rsi,0x4
+   179:  jmp <+0x77>
+   17e:  xchg ax,ax
+   180:  cmp rdi,rcx
+   183:  jae <+0x3c0>
+   189:  cmp BYTE PTR [rdx+0x1],0x65
+   18d:  mov esi,0x1
+   192:  mov eax,0x3
+   197:  jne <+0x77>
+   19d:  lea rax,[rdx+0x2]
+   1a1:  cmp rax,rcx
+   1a4:  jae <+0x61a>
+   1aa:  xor esi,esi
+   1ac:  cmp BYTE PTR [rdx+0x2],0x63
+   1b0:  mov eax,0x9
+   1b5:  jne <+0x77>
+   1bb:  lea rdi,[rdx+0x3]
+   1bf:  cmp rdi,rcx
+   1c2:  jae <+0x7cc>
+   1c8:  movzx edi,BYTE PTR [rdx+0x3]
+   1cc:  cmp dil,0x6f
+   1d0:  je <+0x894>
+   1d6:  xor esi,esi
+   1d8:  cmp dil,0x73
+   1dc:  mov eax,0x3
+   1e1:  sete sil
+   1e5:  add rsi,0x3
+   1e9:  jmp <+0x77>
+   1ee:  xchg ax,ax
+   1f0:  cmp rdi,rcx
+   1f3:  jae <+0x410>
+   1f9:  movzx eax,BYTE PTR [rdx+0x1]
+   1fd:  lea rsi,[rdx+0x2]
+   201:  cmp al,0x61
+   203:  je <+0x430>
+   209:  cmp al,0x73
+   20b:  jne <+0x420>
+   211:  cmp rsi,rcx
+   214:  jae <+0x632>
+   21a:  xor eax,eax
+   21c:  cmp BYTE PTR [rdx+0x2],0x65
+   220:  mov esi,0x2
+   225:  jne <+0x77>
+   22b:  lea rax,[rdx+0x3]
+   22f:  cmp rax,rcx
+   232:  jae <+0x7b4>
+   238:  xor esi,esi
+   23a:  cmp BYTE PTR [rdx+0x3],0x63
+   23e:  mov eax,0x9
+   243:  jne <+0x77>
+   249:  lea rax,[rdx+0x4]
+   24d:  cmp rax,rcx
+   250:  jae <+0x96c>
+   256:  xor esi,esi
+   258:  cmp BYTE PTR [rdx+0x4],0x73
+   25c:  sete sil
+   260:  xor eax,eax
+   262:  add rsi,0x4
+   266:  jmp <+0x77>
+   26b:  nop DWORD PTR [rax+rax*1+0x0]
+   270:  cmp rdi,rcx
+   273:  jae <+0x400>
+   279:  movzx edi,BYTE PTR [rdx+0x1]
+   27d:  lea r8,[rdx+0x2]
+   281:  cmp dil,0x6f
+   285:  je <+0x5e0>
+   28b:  cmp dil,0x73
+   28f:  je <+0x599>
+   295:  mov esi,0x1
+   29a:  mov eax,0x4
+   29f:  cmp dil,0x69
+   2a3:  jne <+0x77>
+   2a9:  cmp r8,rcx
+   2ac:  jae <+0x6cf>
+   2b2:  movzx esi,BYTE PTR [rdx+0x2]
+   2b6:  lea rdi,[rdx+0x3]
+   2ba:  cmp sil,0x6c
+   2be:  je <+0x7db>
+   2c4:  cmp sil,0x6e
+   2c8:  je <+0x82b>
+   2ce:  cmp sil,0x63
+   2d2:  je <+0x803>
+   2d8:  xor esi,esi
+   2da:  mov eax,0x9
+   2df:  jmp <+0x77>
+   2e4:  nop DWORD PTR [rax+0x0]
+   2e8:  cmp rdi,rcx
+   2eb:  jae <+0x3b0>
+   2f1:  movzx eax,BYTE PTR [rdx+0x1]
+   2f5:  lea rsi,[rdx+0x2]
+   2f9:  cmp al,0x6f
+   2fb:  je <+0x571>
+   301:  cmp al,0x72
+   303:  je <+0x550>
+   309:  mov esi,0x1
+   30e:  mov eax,0x5
+   313:  jmp <+0x77>
+   318:  nop DWORD PTR [rax+rax*1+0x0]
+   320:  cmp rdi,rcx
+   323:  jae <+0x3a0>
+   325:  cmp BYTE PTR [rdx+0x1],0x61
+   329:  mov esi,0x1
+   32e:  mov eax,0x6
+   333:  jne <+0x77>
+   339:  lea rax,[rdx+0x2]
+   33d:  cmp rax,rcx
+   340:  jae <+0x626>
+   346:  xor esi,esi
+   348:  cmp BYTE PTR [rdx+0x2],0x79
+   34c:  mov eax,0x9
+   351:  jne <+0x77>
+   357:  lea rax,[rdx+0x3]
+   35b:  cmp rax,rcx
+   35e:  jae <+0x7a5>
+   364:  xor esi,esi
+   366:  cmp BYTE PTR [rdx+0x3],0x73
+   36a:  mov eax,0x6
+   36f:  sete sil
+   373:  add rsi,0x3
+   377:  jmp <+0x77>
+   37c:  nop DWORD PTR [rax+0x0]
+   380:  xor esi,esi
+   382:  mov eax,0x9
+   387:  mov rdx,rsi
+   38a:  ret
+   38b:  nop DWORD PTR [rax+rax*1+0x0]
+   390:  mov esi,0x1
+   395:  mov eax,0x7
+   39a:  jmp <+0x77>
+   39f:  nop
+   3a0:  mov esi,0x1
+   3a5:  mov eax,0x6
+   3aa:  jmp <+0x77>
+   3af:  nop
+   3b0:  mov esi,0x1
+   3b5:  mov eax,0x5
+   3ba:  jmp <+0x77>
+   3bf:  nop
+   3c0:  mov esi,0x1
+   3c5:  mov eax,0x3
+   3ca:  jmp <+0x77>
+   3cf:  nop
+   3d0:  xor esi,esi
+   3d2:  mov eax,0x9
+   3d7:  jmp <+0x77>
+   3dc:  nop DWORD PTR [rax+0x0]
+   3e0:  xor esi,esi
+   3e2:  mov eax,0x9
+   3e7:  jmp <+0x77>
+   3ec:  nop DWORD PTR [rax+0x0]
+   3f0:  mov esi,0x1
+   3f5:  mov eax,0x9
+   3fa:  jmp <+0x77>
+   3ff:  nop
+   400:  mov esi,0x1
+   405:  mov eax,0x4
+   40a:  jmp <+0x77>
+   40f:  nop
+   410:  xor esi,esi
+   412:  mov eax,0x9
+   417:  jmp <+0x77>
+   41c:  nop DWORD PTR [rax+0x0]
+   420:  xor esi,esi
+   422:  mov eax,0x9
+   427:  jmp <+0x77>
+   42c:  nop DWORD PTR [rax+0x0]
+   430:  cmp rsi,rcx
+   433:  jae <+0x63e>
+   439:  cmp BYTE PTR [rdx+0x2],0x6e
+   43d:  jne <+0x44c>
+   43f:  lea rax,[rdx+0x3]
+   443:  cmp rax,rcx
+   446:  jb <+0x69b>
+   44c:  xor esi,esi
+   44e:  mov eax,0x9
+   453:  jmp <+0x77>
+   458:  xor esi,esi
+   45a:  cmp BYTE PTR [rdx+0x2],0x73
+   45e:  mov eax,0x9
+   463:  jne <+0x77>
+   469:  lea rax,[rdx+0x3]
+   46d:  cmp rax,rcx
+   470:  jae <+0x796>
+   476:  cmp BYTE PTR [rdx+0x3],0x65
+   47a:  mov esi,0x3
+   47f:  mov eax,0x1
+   484:  jne <+0x77>
+   48a:  lea rax,[rdx+0x4]
+   48e:  cmp rax,rcx
+   491:  jae <+0x94e>
+   497:  cmp BYTE PTR [rdx+0x4],0x63
+   49b:  je <+0x929>
+   4a1:  xor esi,esi
+   4a3:  mov eax,0x9
+   4a8:  jmp <+0x77>
+   4ad:  nop DWORD PTR [rax]
+   4b0:  cmp rsi,rcx
+   4b3:  jae <+0x66e>
+   4b9:  xor esi,esi
+   4bb:  cmp BYTE PTR [rdx+0x2],0x73
+   4bf:  mov eax,0x7
+   4c4:  sete sil
+   4c8:  add rsi,0x2
+   4cc:  jmp <+0x77>
+   4d1:  nop DWORD PTR [rax+0x0]
+   4d8:  cmp rsi,rcx
+   4db:  jae <+0x67d>
+   4e1:  xor esi,esi
+   4e3:  cmp BYTE PTR [rdx+0x2],0x73
+   4e7:  mov eax,0x9
+   4ec:  sete sil
+   4f0:  add rsi,0x2
+   4f4:  jmp <+0x77>
+   4f9:  cmp rsi,rcx
+   4fc:  jae <+0x662>
+   502:  cmp BYTE PTR [rdx+0x2],0x61
+   506:  jne <+0x515>
+   508:  lea rax,[rdx+0x3]
+   50c:  cmp rax,rcx
+   50f:  jb <+0x6f9>
+   515:  xor esi,esi
+   517:  mov eax,0x9
+   51c:  jmp <+0x77>
+   521:  cmp rsi,rcx
+   524:  jae <+0x64a>
+   52a:  cmp BYTE PTR [rdx+0x2],0x65
+   52e:  jne <+0x53d>
+   530:  lea rax,[rdx+0x3]
+   534:  cmp rax,rcx
+   537:  jb <+0x760>
+   53d:  xor esi,esi
+   53f:  mov eax,0x9
+   544:  jmp <+0x77>
+   549:  nop DWORD PTR [rax+0x0]
+   550:  cmp rsi,rcx
+   553:  jae <+0x68c>
+   559:  xor esi,esi
+   55b:  cmp BYTE PTR [rdx+0x2],0x73
+   55f:  mov eax,0x5
+   564:  sete sil
+   568:  add rsi,0x2
+   56c:  jmp <+0x77>
+   571:  cmp rsi,rcx
+   574:  jae <+0x656>
+   57a:  cmp BYTE PTR [rdx+0x2],0x75
+   57e:  jne <+0x58d>
+   580:  lea rax,[rdx+0x3]
+   584:  cmp rax,rcx
+   587:  jb <+0x72a>
+   58d:  xor esi,esi
+   58f:  mov eax,0x9
+   594:  jmp <+0x77>
+   599:  cmp r8,rcx
+   59c:  jae <+0x6db>
+   5a2:  cmp BYTE PTR [rdx+0x2],0x65
+   5a6:  mov esi,0x2
+   5ab:  mov eax,0x2
+   5b0:  jne <+0x77>
+   5b6:  lea rsi,[rdx+0x3]
+   5ba:  cmp rsi,rcx
+   5bd:  jae <+0x888>
+   5c3:  cmp BYTE PTR [rdx+0x3],0x63
+   5c7:  je <+0x8c0>
+   5cd:  xor esi,esi
+   5cf:  mov eax,0x9
+   5d4:  jmp <+0x77>
+   5d9:  nop DWORD PTR [rax+0x0]
+   5e0:  cmp r8,rcx
+   5e3:  jae <+0x6ea>
+   5e9:  movzx eax,BYTE PTR [rdx+0x2]
+   5ed:  cmp al,0x6e
+   5ef:  je <+0x860>
+   5f5:  xor esi,esi
+   5f7:  cmp al,0x73
+   5f9:  mov eax,0x8
+   5fe:  sete sil
+   602:  add rsi,0x2
+   606:  jmp <+0x77>
+   60b:  mov esi,0x2
+   610:  mov eax,0x1
+   615:  jmp <+0x77>
+   61a:  xor esi,esi
+   61c:  mov eax,0x9
+   621:  jmp <+0x77>
+   626:  xor esi,esi
+   628:  mov eax,0x9
+   62d:  jmp <+0x77>
+   632:  mov esi,0x2
+   637:  xor eax,eax
+   639:  jmp <+0x77>
+   63e:  xor esi,esi
+   640:  mov eax,0x9
+   645:  jmp <+0x77>
+   64a:  xor esi,esi
+   64c:  mov eax,0x9
+   651:  jmp <+0x77>
+   656:  xor esi,esi
+   658:  mov eax,0x9
+   65d:  jmp <+0x77>
+   662:  xor esi,esi
+   664:  mov eax,0x9
+   669:  jmp <+0x77>
+   66e:  mov esi,0x2
+   673:  mov eax,0x7
+   678:  jmp <+0x77>
+   67d:  mov esi,0x2
+   682:  mov eax,0x9
+   687:  jmp <+0x77>
+   68c:  mov esi,0x2
+   691:  mov eax,0x5
+   696:  jmp <+0x77>
+   69b:  xor esi,esi
+   69d:  cmp BYTE PTR [rdx+0x3],0x6f
+   6a1:  mov eax,0x9
+   6a6:  jne <+0x77>
+   6ac:  lea rax,[rdx+0x4]
+   6b0:  cmp rax,rcx
+   6b3:  jae <+0x99b>
+   6b9:  cmp BYTE PTR [rdx+0x4],0x73
+   6bd:  je <+0x978>
+   6c3:  mov esi,0x4
+   6c8:  xor eax,eax
+   6ca:  jmp <+0x77>
+   6cf:  xor esi,esi
+   6d1:  mov eax,0x9
+   6d6:  jmp <+0x77>
+   6db:  mov esi,0x2
+   6e0:  mov eax,0x2
+   6e5:  jmp <+0x77>
+   6ea:  mov esi,0x2
+   6ef:  mov eax,0x8
+   6f4:  jmp <+0x77>
+   6f9:  xor esi,esi
+   6fb:  cmp BYTE PTR [rdx+0x3],0x72
+   6ff:  mov eax,0x9
+   704:  jne <+0x77>
+   70a:  lea rsi,[rdx+0x4]
+   70e:  cmp rsi,rcx
+   711:  jae <+0xa03>
+   717:  xor esi,esi
+   719:  cmp BYTE PTR [rdx+0x4],0x73
+   71d:  sete sil
+   721:  add rsi,0x4
+   725:  jmp <+0x77>
+   72a:  xor esi,esi
+   72c:  cmp BYTE PTR [rdx+0x3],0x72
+   730:  mov eax,0x9
+   735:  jne <+0x77>
+   73b:  lea rax,[rdx+0x4]
+   73f:  cmp rax,rcx
+   742:  jae <+0xa0d>
+   748:  xor esi,esi
+   74a:  cmp BYTE PTR [rdx+0x4],0x73
+   74e:  mov eax,0x5
+   753:  sete sil
+   757:  add rsi,0x4
+   75b:  jmp <+0x77>
+   760:  xor esi,esi
+   762:  cmp BYTE PTR [rdx+0x3],0x6b
+   766:  mov eax,0x9
+   76b:  jne <+0x77>
+   771:  lea rax,[rdx+0x4]
+   775:  cmp rax,rcx
+   778:  jae <+0xa58>
+   77e:  xor esi,esi
+   780:  cmp BYTE PTR [rdx+0x4],0x73
+   784:  mov eax,0x7
+   789:  sete sil
+   78d:  add rsi,0x4
+   791:  jmp <+0x77>
+   796:  mov esi,0x3
+   79b:  mov eax,0x1
+   7a0:  jmp <+0x77>
+   7a5:  mov esi,0x3
+   7aa:  mov eax,0x6
+   7af:  jmp <+0x77>
+   7b4:  xor esi,esi
+   7b6:  mov eax,0x9
+   7bb:  jmp <+0x77>
+   7c0:  xor esi,esi
+   7c2:  mov eax,0x9
+   7c7:  jmp <+0x77>
+   7cc:  mov esi,0x3
+   7d1:  mov eax,0x3
+   7d6:  jmp <+0x77>
+   7db:  cmp rdi,rcx
+   7de:  jae <+0x8f8>
+   7e4:  cmp BYTE PTR [rdx+0x3],0x6c
+   7e8:  jne <+0x7f7>
+   7ea:  lea rax,[rdx+0x4]
+   7ee:  cmp rax,rcx
+   7f1:  jb <+0x95a>
+   7f7:  xor esi,esi
+   7f9:  mov eax,0x9
+   7fe:  jmp <+0x77>
+   803:  cmp rdi,rcx
+   806:  jae <+0x8ec>
+   80c:  cmp BYTE PTR [rdx+0x3],0x72
+   810:  jne <+0x81f>
+   812:  lea rax,[rdx+0x4]
+   816:  cmp rax,rcx
+   819:  jb <+0x904>
+   81f:  xor esi,esi
+   821:  mov eax,0x9
+   826:  jmp <+0x77>
+   82b:  mov esi,0x3
+   830:  cmp rdi,rcx
+   833:  jae <+0x77>
+   839:  movzx esi,BYTE PTR [rdx+0x3]
+   83d:  cmp sil,0x73
+   841:  je <+0x9f9>
+   847:  cmp sil,0x75
+   84b:  je <+0x9cd>
+   851:  mov esi,0x3
+   856:  mov eax,0x4
+   85b:  jmp <+0x77>
+   860:  lea rax,[rdx+0x3]
+   864:  cmp rax,rcx
+   867:  jae <+0x8e0>
+   869:  cmp BYTE PTR [rdx+0x3],0x74
+   86d:  jne <+0x87c>
+   86f:  lea rax,[rdx+0x4]
+   873:  cmp rax,rcx
+   876:  jb <+0xa76>
+   87c:  xor esi,esi
+   87e:  mov eax,0x9
+   883:  jmp <+0x77>
+   888:  xor esi,esi
+   88a:  mov eax,0x9
+   88f:  jmp <+0x77>
+   894:  lea rdi,[rdx+0x4]
+   898:  cmp rdi,rcx
+   89b:  jae <+0x77>
+   8a1:  cmp BYTE PTR [rdx+0x4],0x6e
+   8a5:  jne <+0x8b4>
+   8a7:  lea rax,[rdx+0x5]
+   8ab:  cmp rax,rcx
+   8ae:  jb <+0xa42>
+   8b4:  xor esi,esi
+   8b6:  mov eax,0x9
+   8bb:  jmp <+0x77>
+   8c0:  lea rsi,[rdx+0x4]
+   8c4:  cmp rsi,rcx
+   8c7:  jae <+0xa8c>
+   8cd:  xor esi,esi
+   8cf:  cmp BYTE PTR [rdx+0x4],0x73
+   8d3:  sete sil
+   8d7:  add rsi,0x4
+   8db:  jmp <+0x77>
+   8e0:  xor esi,esi
+   8e2:  mov eax,0x9
+   8e7:  jmp <+0x77>
+   8ec:  xor esi,esi
+   8ee:  mov eax,0x9
+   8f3:  jmp <+0x77>
+   8f8:  xor esi,esi
+   8fa:  mov eax,0x9
+   8ff:  jmp <+0x77>
+   904:  cmp BYTE PTR [rdx+0x4],0x6f
+   908:  je <+0xa1c>
+   90e:  xor esi,esi
+   910:  mov eax,0x9
+   915:  jmp <+0x77>
+   91a:  mov esi,0x4
+   91f:  mov eax,0x1
+   924:  jmp <+0x77>
+   929:  lea rax,[rdx+0x5]
+   92d:  cmp rax,rcx
+   930:  jae <+0xa67>
+   936:  xor esi,esi
+   938:  cmp BYTE PTR [rdx+0x5],0x73
+   93c:  mov eax,0x1
+   941:  sete sil
+   945:  add rsi,0x5
+   949:  jmp <+0x77>
+   94e:  xor esi,esi
+   950:  mov eax,0x9
+   955:  jmp <+0x77>
+   95a:  cmp BYTE PTR [rdx+0x4],0x69
+   95e:  je <+0x9a7>
+   960:  xor esi,esi
+   962:  mov eax,0x9
+   967:  jmp <+0x77>
+   96c:  mov esi,0x4
+   971:  xor eax,eax
+   973:  jmp <+0x77>
+   978:  lea rax,[rdx+0x5]
+   97c:  cmp rax,rcx
+   97f:  jae <+0xa96>
+   985:  cmp BYTE PTR [rdx+0x5],0x65
+   989:  je <+0xaa2>
+   98f:  mov esi,0x5
+   994:  xor eax,eax
+   996:  jmp <+0x77>
+   99b:  mov esi,0x4
+   9a0:  xor eax,eax
+   9a2:  jmp <+0x77>
+   9a7:  lea rax,[rdx+0x5]
+   9ab:  cmp rax,rcx
+   9ae:  jae <+0xc83>
+   9b4:  cmp BYTE PTR [rdx+0x5],0x73
+   9b8:  je <+0xb8b>
+   9be:  mov esi,0x5
+   9c3:  mov eax,0x2
+   9c8:  jmp <+0x77>
+   9cd:  lea rax,[rdx+0x4]
+   9d1:  cmp rax,rcx
+   9d4:  jae <+0xb44>
+   9da:  cmp BYTE PTR [rdx+0x4],0x74
+   9de:  jne <+0x9ed>
+   9e0:  lea rax,[rdx+0x5]
+   9e4:  cmp rax,rcx
+   9e7:  jb <+0xb50>
+   9ed:  xor esi,esi
+   9ef:  mov eax,0x9
+   9f4:  jmp <+0x77>
+   9f9:  mov esi,0x4
+   9fe:  jmp <+0x77>
+   a03:  mov esi,0x4
+   a08:  jmp <+0x77>
+   a0d:  mov esi,0x4
+   a12:  mov eax,0x5
+   a17:  jmp <+0x77>
+   a1c:  lea rax,[rdx+0x5]
+   a20:  cmp rax,rcx
+   a23:  jae <+0xbb1>
+   a29:  cmp BYTE PTR [rdx+0x5],0x73
+   a2d:  je <+0xb1e>
+   a33:  mov esi,0x5
+   a38:  mov eax,0x1
+   a3d:  jmp <+0x77>
+   a42:  cmp BYTE PTR [rdx+0x5],0x64
+   a46:  je <+0xb66>
+   a4c:  xor esi,esi
+   a4e:  mov eax,0x9
+   a53:  jmp <+0x77>
+   a58:  mov esi,0x4
+   a5d:  mov eax,0x7
+   a62:  jmp <+0x77>
+   a67:  mov esi,0x5
+   a6c:  mov eax,0x1
+   a71:  jmp <+0x77>
+   a76:  cmp BYTE PTR [rdx+0x4],0x68
+   a7a:  je <+0xc5e>
+   a80:  xor esi,esi
+   a82:  mov eax,0x9
+   a87:  jmp <+0x77>
+   a8c:  mov esi,0x4
+   a91:  jmp <+0x77>
+   a96:  mov esi,0x5
+   a9b:  xor eax,eax
+   a9d:  jmp <+0x77>
+   aa2:  lea rax,[rdx+0x6]
+   aa6:  cmp rax,rcx
+   aa9:  jae <+0xac6>
+   aab:  cmp BYTE PTR [rdx+0x6],0x63
+   aaf:  jne <+0xaba>
+   ab1:  lea rax,[rdx+0x7]
+   ab5:  cmp rax,rcx
+   ab8:  jb <+0xad2>
+   aba:  xor esi,esi
+   abc:  mov eax,0x9
+   ac1:  jmp <+0x77>
+   ac6:  xor esi,esi
+   ac8:  mov eax,0x9
+   acd:  jmp <+0x77>
+   ad2:  cmp BYTE PTR [rdx+0x7],0x6f
+   ad6:  jne <+0xae1>
+   ad8:  lea rax,[rdx+0x8]
+   adc:  cmp rax,rcx
+   adf:  jb <+0xaed>
+   ae1:  xor esi,esi
+   ae3:  mov eax,0x9
+   ae8:  jmp <+0x77>
+   aed:  cmp BYTE PTR [rdx+0x8],0x6e
+   af1:  jne <+0xafc>
+   af3:  lea rax,[rdx+0x9]
+   af7:  cmp rax,rcx
+   afa:  jb <+0xb08>
+   afc:  xor esi,esi
+   afe:  mov eax,0x9
+   b03:  jmp <+0x77>
+   b08:  cmp BYTE PTR [rdx+0x9],0x64
+   b0c:  je <+0xbc0>
+   b12:  xor esi,esi
+   b14:  mov eax,0x9
+   b19:  jmp <+0x77>
+   b1e:  lea rax,[rdx+0x6]
+   b22:  cmp rax,rcx
+   b25:  jae <+0xcc6>
+   b2b:  cmp BYTE PTR [rdx+0x6],0x65
+   b2f:  je <+0xbe2>
+   b35:  mov esi,0x6
+   b3a:  mov eax,0x1
+   b3f:  jmp <+0x77>
+   b44:  xor esi,esi
+   b46:  mov eax,0x9
+   b4b:  jmp <+0x77>
+   b50:  cmp BYTE PTR [rdx+0x5],0x65
+   b54:  je <+0xcff>
+   b5a:  xor esi,esi
+   b5c:  mov eax,0x9
+   b61:  jmp <+0x77>
+   b66:  lea rax,[rdx+0x6]
+   b6a:  cmp rax,rcx
+   b6d:  jae <+0xce1>
+   b73:  xor esi,esi
+   b75:  cmp BYTE PTR [rdx+0x6],0x73
+   b79:  mov eax,0x3
+   b7e:  sete sil
+   b82:  add rsi,0x6
+   b86:  jmp <+0x77>
+   b8b:  lea rax,[rdx+0x6]
+   b8f:  cmp rax,rcx
+   b92:  jae <+0xd5f>
+   b98:  cmp BYTE PTR [rdx+0x6],0x65
+   b9c:  je <+0xc92>
+   ba2:  mov esi,0x6
+   ba7:  mov eax,0x2
+   bac:  jmp <+0x77>
+   bb1:  mov esi,0x5
+   bb6:  mov eax,0x1
+   bbb:  jmp <+0x77>
+   bc0:  lea rax,[rdx+0xa]
+   bc4:  cmp rax,rcx
+   bc7:  jae <+0xcd5>
+   bcd:  xor esi,esi
+   bcf:  cmp BYTE PTR [rdx+0xa],0x73
+   bd3:  sete sil
+   bd7:  xor eax,eax
+   bd9:  add rsi,0xa
+   bdd:  jmp <+0x77>
+   be2:  lea rax,[rdx+0x7]
+   be6:  cmp rax,rcx
+   be9:  jae <+0xc06>
+   beb:  lea rax,[rdx+0x8]
+   bef:  cmp rax,rcx
+   bf2:  jae <+0xbfa>
+   bf4:  cmp BYTE PTR [rdx+0x7],0x63
+   bf8:  je <+0xc12>
+   bfa:  xor esi,esi
+   bfc:  mov eax,0x9
+   c01:  jmp <+0x77>
+   c06:  xor esi,esi
+   c08:  mov eax,0x9
+   c0d:  jmp <+0x77>
+   c12:  cmp BYTE PTR [rdx+0x8],0x6f
+   c16:  jne <+0xc21>
+   c18:  lea rax,[rdx+0x9]
+   c1c:  cmp rax,rcx
+   c1f:  jb <+0xc2d>
+   c21:  xor esi,esi
+   c23:  mov eax,0x9
+   c28:  jmp <+0x77>
+   c2d:  cmp BYTE PTR [rdx+0x9],0x6e
+   c31:  jne <+0xc3c>
+   c33:  lea rax,[rdx+0xa]
+   c37:  cmp rax,rcx
+   c3a:  jb <+0xc48>
+   c3c:  xor esi,esi
+   c3e:  mov eax,0x9
+   c43:  jmp <+0x77>
+   c48:  cmp BYTE PTR [rdx+0xa],0x64
+   c4c:  je <+0xd20>
+   c52:  xor esi,esi
+   c54:  mov eax,0x9
+   c59:  jmp <+0x77>
+   c5e:  lea rax,[rdx+0x5]
+   c62:  cmp rax,rcx
+   c65:  jae <+0xcf0>
+   c6b:  xor esi,esi
+   c6d:  cmp BYTE PTR [rdx+0x5],0x73
+   c71:  mov eax,0x8
+   c76:  sete sil
+   c7a:  add rsi,0x5
+   c7e:  jmp <+0x77>
+   c83:  mov esi,0x5
+   c88:  mov eax,0x2
+   c8d:  jmp <+0x77>
+   c92:  lea rax,[rdx+0x7]
+   c96:  cmp rax,rcx
+   c99:  jae <+0xcba>
+   c9b:  cmp BYTE PTR [rdx+0x7],0x63
+   c9f:  jne <+0xcae>
+   ca1:  lea rax,[rdx+0x8]
+   ca5:  cmp rax,rcx
+   ca8:  jb <+0xd6e>
+   cae:  xor esi,esi
+   cb0:  mov eax,0x9
+   cb5:  jmp <+0x77>
+   cba:  xor esi,esi
+   cbc:  mov eax,0x9
+   cc1:  jmp <+0x77>
+   cc6:  mov esi,0x6
+   ccb:  mov eax,0x1
+   cd0:  jmp <+0x77>
+   cd5:  mov esi,0xa
+   cda:  xor eax,eax
+   cdc:  jmp <+0x77>
+   ce1:  mov esi,0x6
+   ce6:  mov eax,0x3
+   ceb:  jmp <+0x77>
+   cf0:  mov esi,0x5
+   cf5:  mov eax,0x8
+   cfa:  jmp <+0x77>
+   cff:  lea rax,[rdx+0x6]
+   d03:  cmp rax,rcx
+   d06:  jae <+0xd41>
+   d08:  xor esi,esi
+   d0a:  cmp BYTE PTR [rdx+0x6],0x73
+   d0e:  mov eax,0x4
+   d13:  sete sil
+   d17:  add rsi,0x6
+   d1b:  jmp <+0x77>
+   d20:  lea rax,[rdx+0xb]
+   d24:  cmp rax,rcx
+   d27:  jae <+0xd50>
+   d29:  xor esi,esi
+   d2b:  cmp BYTE PTR [rdx+0xb],0x73
+   d2f:  mov eax,0x1
+   d34:  sete sil
+   d38:  add rsi,0xb
+   d3c:  jmp <+0x77>
+   d41:  mov esi,0x6
+   d46:  mov eax,0x4
+   d4b:  jmp <+0x77>
+   d50:  mov esi,0xb
+   d55:  mov eax,0x1
+   d5a:  jmp <+0x77>
+   d5f:  mov esi,0x6
+   d64:  mov eax,0x2
+   d69:  jmp <+0x77>
+   d6e:  cmp BYTE PTR [rdx+0x8],0x6f
+   d72:  jne <+0xd7d>
+   d74:  lea rax,[rdx+0x9]
+   d78:  cmp rax,rcx
+   d7b:  jb <+0xd89>
+   d7d:  xor esi,esi
+   d7f:  mov eax,0x9
+   d84:  jmp <+0x77>
+   d89:  cmp BYTE PTR [rdx+0x9],0x6e
+   d8d:  jne <+0xd98>
+   d8f:  lea rax,[rdx+0xa]
+   d93:  cmp rax,rcx
+   d96:  jb <+0xda4>
+   d98:  xor esi,esi
+   d9a:  mov eax,0x9
+   d9f:  jmp <+0x77>
+   da4:  cmp BYTE PTR [rdx+0xa],0x64
+   da8:  je <+0xdb6>
+   daa:  xor esi,esi
+   dac:  mov eax,0x9
+   db1:  jmp <+0x77>
+   db6:  lea rax,[rdx+0xb]
+   dba:  cmp rax,rcx
+   dbd:  jae <+0xdd7>
+   dbf:  xor esi,esi
+   dc1:  cmp BYTE PTR [rdx+0xb],0x73
+   dc5:  mov eax,0x2
+   dca:  sete sil
+   dce:  add rsi,0xb
+   dd2:  jmp <+0x77>
+   dd7:  mov esi,0xb
+   ddc:  mov eax,0x2
+   de1:  jmp <+0x77>
@@ -0,0 +1,3 @@
+gencdfa2_ci_find.cold:
+     0:  mov rdi,r10
+     3:  jmp <gencdfa2_ci_find+0x288>
@@ -0,0 +1,1490 @@
+gencdfa2_ci_find:
+     0:  mov rdx,rdi
+     3:  mov r8,rsi
+     6:  cmp rdi,rsi
+     9:  jae <+0x50>
+     b:  movzx eax,BYTE PTR [rdx]
+     e:  lea r9,[rip]
+    15:  lea rdi,[rdi+0x1]
+    19:  movzx eax,BYTE PTR [r9+rax*1]
+    1e:  sub eax,0x4
+    21:  cmp al,0x2f
+    23:  ja <+0x40>
+    25:  lea rcx,[rip]
+    2c:  movzx eax,al
+    2f:  movsxd rax,DWORD PTR [rcx+rax*4]
+    33:  add rax,rcx
+    36:  jmp rax
+    38:  nop DWORD PTR [rax+rax*1+0x0]
+    40:  xor esi,esi
+    42:  mov eax,0x9
+    47:  mov rdx,rsi
+    4a:  ret
+    4b:  nop DWORD PTR [rax+rax*1+0x0]
+    50:  xor esi,esi
+    52:  mov eax,0x9
+    57:  mov rdx,rsi
+    5a:  ret
+    5b:  nop DWORD PTR [rax+rax*1+0x0]
+    60:  cmp rdi,r8
+    63:  jae <+0x730>
+    69:  movzx eax,BYTE PTR [rdi]
+    6c:  movzx eax,BYTE PTR [r9+rax*1]
+    71:  cmp al,0x11
+    73:  je <+0x7d>
+    75:  cmp al,0x29
+    77:  jne <+0x540>
+    7d:  lea rax,[rdi+0x1]
+    81:  cmp rax,r8
+    84:  jae <+0x790>
+    8a:  movzx eax,BYTE PTR [rdi+0x1]
+    8e:  movzx ecx,BYTE PTR [r9+rax*1]
+    93:  cmp cl,0x34
+    96:  ja <+0x8ee>
+    9c:  movabs rsi,0x1fffffdfffffdf
+    a6:  mov eax,0x1
+    ab:  shl rax,cl
+    ae:  and rsi,rax
+    b1:  je <+0xdcc>
+    b7:  mov esi,0x2
+    bc:  mov eax,0x1
+    c1:  jmp <+0x47>
+    c3:  nop DWORD PTR [rax+rax*1+0x0]
+    c8:  cmp rdi,r8
+    cb:  jae <+0x740>
+    d1:  movzx eax,BYTE PTR [rdi]
+    d4:  lea r11,[rdi+0x1]
+    d8:  movzx ecx,BYTE PTR [r9+rax*1]
+    dd:  cmp cl,0x34
+    e0:  ja <+0x190>
+    e6:  mov edx,0x1
+    eb:  mov esi,0x1
+    f0:  movabs r10,0x1ffebffffebfff
+    fa:  mov rax,rdx
+    fd:  shl rax,cl
+   100:  mov rcx,rax
+   103:  mov eax,0x5
+   108:  test rcx,r10
+   10b:  jne <+0x47>
+   111:  movabs rsi,0x10000010000
+   11b:  and rsi,rcx
+   11e:  jne <+0x79f>
+   124:  movabs rax,0x4000004000
+   12e:  test rcx,rax
+   131:  je <+0x190>
+   133:  mov eax,0x9
+   138:  cmp r11,r8
+   13b:  jae <+0x47>
+   141:  movzx edx,BYTE PTR [rdi+0x1]
+   145:  movzx edx,BYTE PTR [r9+rdx*1]
+   14a:  cmp dl,0x13
+   14d:  je <+0x158>
+   14f:  cmp dl,0x2b
+   152:  jne <+0x47>
+   158:  add rdi,0x2
+   15c:  cmp rdi,r8
+   15f:  jae <+0xb40>
+   165:  movzx eax,BYTE PTR [rdi]
+   168:  movzx eax,BYTE PTR [r9+rax*1]
+   16d:  cmp al,0x10
+   16f:  je <+0xad0>
+   175:  cmp al,0x28
+   177:  je <+0xad0>
+   17d:  xor esi,esi
+   17f:  mov eax,0x9
+   184:  jmp <+0x47>
+   189:  nop DWORD PTR [rax+0x0]
+   190:  mov rdi,r11
+   193:  cmp rdi,r8
+   196:  jae <+0x750>
+   19c:  movzx eax,BYTE PTR [rdi]
+   19f:  lea r10,[rdi+0x1]
+   1a3:  cmp BYTE PTR [r9+rax*1],0x34
+   1a8:  ja <gencdfa2_ci_find.cold>
+   1ae:  movzx eax,BYTE PTR [r9+rax*1]
+   1b3:  lea rdx,[rip]
+   1ba:  movsxd rax,DWORD PTR [rdx+rax*4]
+   1be:  add rax,rdx
+   1c1:  jmp rax
+   1c3:  nop DWORD PTR [rax+rax*1+0x0]
+   1c8:  cmp rdi,r8
+   1cb:  jae <+0x760>
+   1d1:  movzx eax,BYTE PTR [rdi]
+   1d4:  lea r10,[rdi+0x1]
+   1d8:  movzx ecx,BYTE PTR [r9+rax*1]
+   1dd:  cmp cl,0x34
+   1e0:  ja <+0x5b0>
+   1e6:  mov edx,0x1
+   1eb:  mov esi,0x1
+   1f0:  movabs r11,0x1ffeffdffeffdf
+   1fa:  mov rax,rdx
+   1fd:  shl rax,cl
+   200:  mov rcx,rax
+   203:  mov eax,0x9
+   208:  test rcx,r11
+   20b:  jne <+0x47>
+   211:  movabs rsi,0x10000010000
+   21b:  and rsi,rcx
+   21e:  jne <+0x800>
+   224:  test ecx,0x20000020
+   22a:  je <+0x5b0>
+   230:  cmp r10,r8
+   233:  jae <+0x47>
+   239:  movzx edx,BYTE PTR [rdi+0x1]
+   23d:  movzx edx,BYTE PTR [r9+rdx*1]
+   242:  cmp dl,0x1
+   245:  je <+0x250>
+   247:  cmp dl,0x19
+   24a:  jne <+0x47>
+   250:  lea rdx,[rdi+0x2]
+   254:  mov eax,0x9
+   259:  cmp rdx,r8
+   25c:  jae <+0x47>
+   262:  movzx edx,BYTE PTR [rdi+0x2]
+   266:  movzx edx,BYTE PTR [r9+rdx*1]
+   26b:  cmp dl,0x10
+   26e:  je <+0x279>
+   270:  cmp dl,0x28
+   273:  jne <+0x47>
+   279:  add rdi,0x3
+   27d:  jmp <+0x412>
+   282:  nop WORD PTR [rax+rax*1+0x0]
+   288:  cmp rdi,r8
+   28b:  jae <+0x770>
+   291:  movzx eax,BYTE PTR [rdi]
+   294:  lea rdx,[rdi+0x1]
+   298:  movzx eax,BYTE PTR [r9+rax*1]
+   29d:  cmp al,0x19
+   29f:  je <+0x580>
+   2a5:  ja <+0x518>
+   2ab:  cmp al,0x1
+   2ad:  je <+0x580>
+   2b3:  cmp al,0x11
+   2b5:  jne <+0x1697>
+   2bb:  cmp rdx,r8
+   2be:  jae <+0x602>
+   2c4:  movzx eax,BYTE PTR [rdi+0x1]
+   2c8:  movzx ecx,BYTE PTR [r9+rax*1]
+   2cd:  cmp cl,0x34
+   2d0:  ja <+0x9f7>
+   2d6:  movabs rsi,0x1fffffdfffffdf
+   2e0:  mov eax,0x1
+   2e5:  shl rax,cl
+   2e8:  and rsi,rax
+   2eb:  je <+0xa30>
+   2f1:  mov esi,0x2
+   2f6:  xor eax,eax
+   2f8:  jmp <+0x47>
+   2fd:  nop DWORD PTR [rax]
+   300:  cmp rdi,rsi
+   303:  jae <+0x570>
+   309:  movzx eax,BYTE PTR [rdx+0x1]
+   30d:  lea rdi,[rdx+0x2]
+   311:  movzx ecx,BYTE PTR [r9+rax*1]
+   316:  cmp cl,0x34
+   319:  ja <+0x1c8>
+   31f:  mov r10d,0x1
+   325:  mov esi,0x1
+   32a:  movabs r11,0x1ffffbdffffbdf
+   334:  mov rax,r10
+   337:  shl rax,cl
+   33a:  mov rcx,rax
+   33d:  mov eax,0x7
+   342:  test rcx,r11
+   345:  jne <+0x47>
+   34b:  movabs rsi,0x400000400
+   355:  and rsi,rcx
+   358:  jne <+0x852>
+   35e:  test ecx,0x20000020
+   364:  je <+0x1c8>
+   36a:  mov eax,0x9
+   36f:  cmp rdi,r8
+   372:  jae <+0x47>
+   378:  movzx ecx,BYTE PTR [rdx+0x2]
+   37c:  movzx ecx,BYTE PTR [r9+rcx*1]
+   381:  cmp cl,0x5
+   384:  je <+0x38f>
+   386:  cmp cl,0x1d
+   389:  jne <+0x47>
+   38f:  lea rcx,[rdx+0x3]
+   393:  mov eax,0x9
+   398:  cmp rcx,r8
+   39b:  jae <+0x47>
+   3a1:  movzx ecx,BYTE PTR [rdx+0x3]
+   3a5:  movzx ecx,BYTE PTR [r9+rcx*1]
+   3aa:  cmp cl,0xa
+   3ad:  je <+0x3b8>
+   3af:  cmp cl,0x22
+   3b2:  jne <+0x47>
+   3b8:  lea rdi,[rdx+0x4]
+   3bc:  cmp rdi,r8
+   3bf:  jae <+0xd93>
+   3c5:  movzx eax,BYTE PTR [rdi]
+   3c8:  movzx ecx,BYTE PTR [r9+rax*1]
+   3cd:  cmp cl,0x34
+   3d0:  ja <+0x40e>
+   3d2:  mov edx,0x1
+   3d7:  mov esi,0x4
+   3dc:  mov eax,0x7
+   3e1:  shl rdx,cl
+   3e4:  movabs rcx,0x1ffdfffffdffff
+   3ee:  test rdx,rcx
+   3f1:  jne <+0x47>
+   3f7:  mov ecx,0x1000001
+   3fc:  mov esi,0x5
+   401:  shl rcx,0x11
+   405:  test rdx,rcx
+   408:  jne <+0x47>
+   40e:  add rdi,0x1
+   412:  cmp rdi,r8
+   415:  jae <+0xdbd>
+   41b:  movzx eax,BYTE PTR [rdi]
+   41e:  movzx ecx,BYTE PTR [r9+rax*1]
+   423:  cmp cl,0x34
+   426:  ja <+0x11b7>
+   42c:  mov eax,0x1
+   431:  mov esi,0x4
+   436:  shl rax,cl
+   439:  movabs rcx,0x1ffdfffffdffff
+   443:  mov rdx,rax
+   446:  mov eax,0x9
+   44b:  test rdx,rcx
+   44e:  jne <+0x47>
+   454:  mov eax,0x1000001
+   459:  shl rax,0x11
+   45d:  and rax,rdx
+   460:  cmp rax,0x1
+   464:  sbb rsi,rsi
+   467:  and rsi,0xfffffffffffffffe
+   46b:  add rsi,0x5
+   46f:  cmp rax,0x1
+   473:  sbb eax,eax
+   475:  and eax,0xfffffff8
+   478:  add eax,0x9
+   47b:  jmp <+0x47>
+   480:  cmp rdi,rsi
+   483:  jae <+0x560>
+   489:  movzx eax,BYTE PTR [rdx+0x1]
+   48d:  lea rdi,[rdx+0x2]
+   491:  movzx ecx,BYTE PTR [r9+rax*1]
+   496:  cmp cl,0x34
+   499:  ja <+0x60>
+   49f:  movabs rsi,0x1fffffdfffffdf
+   4a9:  mov eax,0x1
+   4ae:  shl rax,cl
+   4b1:  and rsi,rax
+   4b4:  je <+0xc55>
+   4ba:  mov esi,0x1
+   4bf:  mov eax,0x3
+   4c4:  jmp <+0x47>
+   4c9:  nop DWORD PTR [rax+0x0]
+   4d0:  cmp rdi,rsi
+   4d3:  jae <+0x550>
+   4d5:  movzx eax,BYTE PTR [rdx+0x1]
+   4d9:  lea rdi,[rdx+0x2]
+   4dd:  movzx ecx,BYTE PTR [r9+rax*1]
+   4e2:  cmp cl,0x34
+   4e5:  ja <+0xc8>
+   4eb:  movabs rsi,0x1ffffffdfffffd
+   4f5:  mov eax,0x1
+   4fa:  shl rax,cl
+   4fd:  and rsi,rax
+   500:  je <+0xbb7>
+   506:  mov esi,0x1
+   50b:  mov eax,0x6
+   510:  jmp <+0x47>
+   515:  nop DWORD PTR [rax]
+   518:  cmp al,0x29
+   51a:  je <+0x2bb>
+   520:  xor esi,esi
+   522:  mov eax,0x9
+   527:  jmp <+0x47>
+   52c:  nop DWORD PTR [rax+0x0]
+   530:  mov esi,0x1
+   535:  mov eax,0x4
+   53a:  jmp <+0x47>
+   53f:  nop
+   540:  xor esi,esi
+   542:  mov eax,0x9
+   547:  jmp <+0x47>
+   54c:  nop DWORD PTR [rax+0x0]
+   550:  mov esi,0x1
+   555:  mov eax,0x6
+   55a:  jmp <+0x47>
+   55f:  nop
+   560:  mov esi,0x1
+   565:  mov eax,0x3
+   56a:  jmp <+0x47>
+   56f:  nop
+   570:  mov esi,0x1
+   575:  mov eax,0x7
+   57a:  jmp <+0x47>
+   57f:  nop
+   580:  cmp rdx,r8
+   583:  jae <+0x8e2>
+   589:  movzx eax,BYTE PTR [rdx]
+   58c:  movzx eax,BYTE PTR [r9+rax*1]
+   591:  cmp al,0xd
+   593:  je <+0x8b8>
+   599:  cmp al,0x25
+   59b:  je <+0x8b8>
+   5a1:  xor esi,esi
+   5a3:  mov eax,0x9
+   5a8:  jmp <+0x47>
+   5ad:  nop DWORD PTR [rax]
+   5b0:  mov rdi,r10
+   5b3:  cmp rdi,r8
+   5b6:  jae <+0x780>
+   5bc:  movzx edx,BYTE PTR [rdi]
+   5bf:  xor esi,esi
+   5c1:  add rdi,0x1
+   5c5:  mov eax,0x9
+   5ca:  cmp BYTE PTR [r9+rdx*1],0x31
+   5cf:  jne <+0x47>
+   5d5:  cmp rdi,r8
+   5d8:  jae <+0x9df>
+   5de:  movzx eax,BYTE PTR [rdi]
+   5e1:  movzx eax,BYTE PTR [r9+rax*1]
+   5e6:  cmp al,0x11
+   5e8:  je <+0x8fd>
+   5ee:  cmp al,0x29
+   5f0:  je <+0x8fd>
+   5f6:  xor esi,esi
+   5f8:  mov eax,0x9
+   5fd:  jmp <+0x47>
+   602:  mov esi,0x2
+   607:  xor eax,eax
+   609:  jmp <+0x47>
+   60e:  mov eax,0x1000001
+   613:  shl rax,0xd
+   617:  test rdx,rax
+   61a:  jne <+0xf06>
+   620:  mov ecx,0x1000001
+   625:  mov esi,0x3
+   62a:  mov eax,0x8
+   62f:  shl rcx,0x11
+   633:  test rdx,rcx
+   636:  jne <+0x47>
+   63c:  cmp r10,r8
+   63f:  jae <+0xa06>
+   645:  movzx eax,BYTE PTR [r10]
+   649:  lea rdx,[r10+0x1]
+   64d:  movzx ecx,BYTE PTR [r9+rax*1]
+   652:  cmp cl,0x34
+   655:  ja <+0x580>
+   65b:  movabs rsi,0x1fffffdfffffdf
+   665:  mov eax,0x1
+   66a:  shl rax,cl
+   66d:  and rsi,rax
+   670:  je <+0x125b>
+   676:  mov esi,0x2
+   67b:  mov eax,0x2
+   680:  jmp <+0x47>
+   685:  cmp r10,r8
+   688:  jae <+0xa15>
+   68e:  movzx eax,BYTE PTR [rdi+0x1]
+   692:  lea r10,[rdi+0x2]
+   696:  movzx ecx,BYTE PTR [r9+rax*1]
+   69b:  cmp cl,0x34
+   69e:  ja <+0x63c>
+   6a0:  movabs rsi,0x1ffddffffddfff
+   6aa:  mov edx,0x1
+   6af:  shl rdx,cl
+   6b2:  and rsi,rdx
+   6b5:  je <+0x60e>
+   6bb:  mov esi,0x2
+   6c0:  mov eax,0x8
+   6c5:  jmp <+0x47>
+   6ca:  cmp r10,r8
+   6cd:  jae <+0xa24>
+   6d3:  movzx eax,BYTE PTR [rdi+0x1]
+   6d7:  lea r10,[rdi+0x2]
+   6db:  movzx ecx,BYTE PTR [r9+rax*1]
+   6e0:  cmp cl,0x25
+   6e3:  ja <+0xd78>
+   6e9:  mov eax,0x1
+   6ee:  mov rsi,rax
+   6f1:  shl rsi,cl
+   6f4:  test esi,0x8000008
+   6fa:  jne <+0xcd9>
+   700:  movabs rdx,0x800000800
+   70a:  test rsi,rdx
+   70d:  jne <+0xb89>
+   713:  movabs rdx,0x2000002000
+   71d:  and rsi,rdx
+   720:  jne <+0xd22>
+   726:  mov eax,0x9
+   72b:  jmp <+0x47>
+   730:  xor esi,esi
+   732:  mov eax,0x9
+   737:  jmp <+0x47>
+   73c:  nop DWORD PTR [rax+0x0]
+   740:  mov esi,0x1
+   745:  mov eax,0x5
+   74a:  jmp <+0x47>
+   74f:  nop
+   750:  mov esi,0x1
+   755:  mov eax,0x4
+   75a:  jmp <+0x47>
+   75f:  nop
+   760:  mov esi,0x1
+   765:  mov eax,0x9
+   76a:  jmp <+0x47>
+   76f:  nop
+   770:  xor esi,esi
+   772:  mov eax,0x9
+   777:  jmp <+0x47>
+   77c:  nop DWORD PTR [rax+0x0]
+   780:  xor esi,esi
+   782:  mov eax,0x9
+   787:  jmp <+0x47>
+   78c:  nop DWORD PTR [rax+0x0]
+   790:  mov esi,0x2
+   795:  mov eax,0x1
+   79a:  jmp <+0x47>
+   79f:  mov esi,0x2
+   7a4:  cmp r11,r8
+   7a7:  jae <+0x47>
+   7ad:  movzx ecx,BYTE PTR [rdi+0x1]
+   7b1:  movzx ecx,BYTE PTR [r9+rcx*1]
+   7b6:  cmp cl,0x34
+   7b9:  ja <+0xd13>
+   7bf:  shl rdx,cl
+   7c2:  movabs rcx,0x1ffdfffffdffff
+   7cc:  test rdx,rcx
+   7cf:  jne <+0x47>
+   7d5:  mov eax,0x1000001
+   7da:  shl rax,0x11
+   7de:  and rdx,rax
+   7e1:  cmp rdx,0x1
+   7e5:  sbb rsi,rsi
+   7e8:  and rsi,0xfffffffffffffffe
+   7ec:  add rsi,0x3
+   7f0:  cmp rdx,0x1
+   7f4:  sbb eax,eax
+   7f6:  add eax,0x5
+   7f9:  jmp <+0x47>
+   7fe:  xchg ax,ax
+   800:  mov esi,0x2
+   805:  cmp r10,r8
+   808:  jae <+0x47>
+   80e:  movzx ecx,BYTE PTR [rdi+0x1]
+   812:  movzx ecx,BYTE PTR [r9+rcx*1]
+   817:  cmp cl,0x34
+   81a:  ja <+0x849>
+   81c:  shl rdx,cl
+   81f:  movabs rcx,0x1ffdfffffdffff
+   829:  test rdx,rcx
+   82c:  jne <+0x47>
+   832:  mov ecx,0x1000001
+   837:  mov esi,0x3
+   83c:  shl rcx,0x11
+   840:  test rdx,rcx
+   843:  jne <+0x47>
+   849:  add rdi,0x2
+   84d:  jmp <+0x5d5>
+   852:  mov esi,0x2
+   857:  cmp rdi,r8
+   85a:  jae <+0x47>
+   860:  movzx edx,BYTE PTR [rdx+0x2]
+   864:  movzx ecx,BYTE PTR [r9+rdx*1]
+   869:  cmp cl,0x34
+   86c:  ja <+0xd69>
+   872:  movabs rdx,0x1ffdfffffdffff
+   87c:  shl r10,cl
+   87f:  test r10,rdx
+   882:  jne <+0x47>
+   888:  mov eax,0x1000001
+   88d:  shl rax,0x11
+   891:  and r10,rax
+   894:  cmp r10,0x1
+   898:  sbb rsi,rsi
+   89b:  and rsi,0xfffffffffffffffe
+   89f:  add rsi,0x3
+   8a3:  cmp r10,0x1
+   8a7:  sbb eax,eax
+   8a9:  and eax,0x2
+   8ac:  add eax,0x7
+   8af:  jmp <+0x47>
+   8b4:  nop DWORD PTR [rax+0x0]
+   8b8:  lea rax,[rdx+0x1]
+   8bc:  cmp rax,r8
+   8bf:  jae <+0x9eb>
+   8c5:  movzx eax,BYTE PTR [rdx+0x1]
+   8c9:  movzx eax,BYTE PTR [r9+rax*1]
+   8ce:  cmp al,0xe
+   8d0:  je <+0x946>
+   8d2:  cmp al,0x26
+   8d4:  je <+0x946>
+   8d6:  xor esi,esi
+   8d8:  mov eax,0x9
+   8dd:  jmp <+0x47>
+   8e2:  xor esi,esi
+   8e4:  mov eax,0x9
+   8e9:  jmp <+0x47>
+   8ee:  mov esi,0x1
+   8f3:  mov eax,0x7
+   8f8:  jmp <+0x47>
+   8fd:  lea rax,[rdi+0x1]
+   901:  cmp rax,r8
+   904:  jae <+0xb4c>
+   90a:  movzx eax,BYTE PTR [rdi+0x1]
+   90e:  movzx ecx,BYTE PTR [r9+rax*1]
+   913:  cmp cl,0x34
+   916:  ja <+0xed0>
+   91c:  movabs rsi,0x1fffffdfffffdf
+   926:  mov eax,0x1
+   92b:  shl rax,cl
+   92e:  and rsi,rax
+   931:  je <+0x1307>
+   937:  mov esi,0x3
+   93c:  mov eax,0x1
+   941:  jmp <+0x47>
+   946:  add rdx,0x2
+   94a:  cmp rdx,r8
+   94d:  jae <+0xd07>
+   953:  movzx eax,BYTE PTR [rdx]
+   956:  lea rdi,[rdx+0x1]
+   95a:  movzx ecx,BYTE PTR [r9+rax*1]
+   95f:  cmp cl,0x34
+   962:  ja <+0xa68>
+   968:  mov r10d,0x1
+   96e:  mov esi,0x4
+   973:  movabs r11,0x1ffdfffffdffff
+   97d:  mov rax,r10
+   980:  shl rax,cl
+   983:  mov rcx,rax
+   986:  xor eax,eax
+   988:  test rcx,r11
+   98b:  jne <+0x47>
+   991:  movabs r11,0x20000020000
+   99b:  test rcx,r11
+   99e:  je <+0xa68>
+   9a4:  cmp rdi,r8
+   9a7:  jae <+0x11c6>
+   9ad:  movzx ecx,BYTE PTR [rdx+0x1]
+   9b1:  movzx ecx,BYTE PTR [r9+rcx*1]
+   9b6:  cmp cl,0x34
+   9b9:  ja <+0x47>
+   9bf:  movabs rsi,0x1fffffdfffffdf
+   9c9:  shl r10,cl
+   9cc:  and rsi,r10
+   9cf:  je <+0x1729>
+   9d5:  mov esi,0x5
+   9da:  jmp <+0x47>
+   9df:  xor esi,esi
+   9e1:  mov eax,0x9
+   9e6:  jmp <+0x47>
+   9eb:  xor esi,esi
+   9ed:  mov eax,0x9
+   9f2:  jmp <+0x47>
+   9f7:  mov esi,0x1
+   9fc:  mov eax,0x3
+   a01:  jmp <+0x47>
+   a06:  mov esi,0x2
+   a0b:  mov eax,0x2
+   a10:  jmp <+0x47>
+   a15:  mov esi,0x2
+   a1a:  mov eax,0x8
+   a1f:  jmp <+0x47>
+   a24:  xor esi,esi
+   a26:  mov eax,0x9
+   a2b:  jmp <+0x47>
+   a30:  test eax,0x20000020
+   a35:  je <+0x14ec>
+   a3b:  lea rdx,[rdi+0x2]
+   a3f:  mov eax,0x9
+   a44:  cmp rdx,r8
+   a47:  jae <+0x47>
+   a4d:  movzx edx,BYTE PTR [rdi+0x2]
+   a51:  movzx edx,BYTE PTR [r9+rdx*1]
+   a56:  cmp dl,0x3
+   a59:  je <+0xa64>
+   a5b:  cmp dl,0x1b
+   a5e:  jne <+0x47>
+   a64:  add rdi,0x3
+   a68:  cmp rdi,r8
+   a6b:  jae <+0xda2>
+   a71:  movzx eax,BYTE PTR [rdi]
+   a74:  movzx ecx,BYTE PTR [r9+rax*1]
+   a79:  cmp cl,0x34
+   a7c:  ja <+0x1067>
+   a82:  mov eax,0x1
+   a87:  mov esi,0x4
+   a8c:  shl rax,cl
+   a8f:  movabs rcx,0x1ffdfffffdffff
+   a99:  mov rdx,rax
+   a9c:  xor eax,eax
+   a9e:  test rdx,rcx
+   aa1:  jne <+0x47>
+   aa7:  mov eax,0x1000001
+   aac:  shl rax,0x11
+   ab0:  and rax,rdx
+   ab3:  cmp rax,0x1
+   ab7:  sbb rsi,rsi
+   aba:  and rsi,0xfffffffffffffffe
+   abe:  add rsi,0x5
+   ac2:  cmp rax,0x1
+   ac6:  sbb eax,eax
+   ac8:  and eax,0x3
+   acb:  jmp <+0x47>
+   ad0:  lea rax,[rdi+0x1]
+   ad4:  cmp rax,r8
+   ad7:  jae <+0xdae>
+   add:  movzx eax,BYTE PTR [rdi+0x1]
+   ae1:  movzx ecx,BYTE PTR [r9+rax*1]
+   ae6:  cmp cl,0x34
+   ae9:  ja <+0x11a8>
+   aef:  mov eax,0x1
+   af4:  mov esi,0x4
+   af9:  shl rax,cl
+   afc:  movabs rcx,0x1ffdfffffdffff
+   b06:  mov rdx,rax
+   b09:  mov eax,0x5
+   b0e:  test rdx,rcx
+   b11:  jne <+0x47>
+   b17:  mov eax,0x1000001
+   b1c:  shl rax,0x11
+   b20:  and rax,rdx
+   b23:  cmp rax,0x1
+   b27:  sbb rsi,rsi
+   b2a:  and rsi,0xfffffffffffffffe
+   b2e:  add rsi,0x5
+   b32:  cmp rax,0x1
+   b36:  sbb eax,eax
+   b38:  add eax,0x5
+   b3b:  jmp <+0x47>
+   b40:  xor esi,esi
+   b42:  mov eax,0x9
+   b47:  jmp <+0x47>
+   b4c:  mov esi,0x3
+   b51:  mov eax,0x1
+   b56:  jmp <+0x47>
+   b5b:  mov eax,0x1000001
+   b60:  shl rax,0x13
+   b64:  test rdx,rax
+   b67:  jne <+0x1413>
+   b6d:  mov ecx,0x1000001
+   b72:  mov esi,0x4
+   b77:  mov eax,0x4
+   b7c:  shl rcx,0x11
+   b80:  test rdx,rcx
+   b83:  jne <+0x47>
+   b89:  cmp r10,r8
+   b8c:  jae <+0xedf>
+   b92:  movzx eax,BYTE PTR [r10]
+   b96:  movzx eax,BYTE PTR [r9+rax*1]
+   b9b:  cmp al,0xb
+   b9d:  je <+0xe6b>
+   ba3:  cmp al,0x23
+   ba5:  je <+0xe6b>
+   bab:  xor esi,esi
+   bad:  mov eax,0x9
+   bb2:  jmp <+0x47>
+   bb7:  test eax,0x2000002
+   bbc:  je <+0xc8>
+   bc2:  mov eax,0x9
+   bc7:  cmp rdi,r8
+   bca:  jae <+0x47>
+   bd0:  movzx ecx,BYTE PTR [rdx+0x2]
+   bd4:  movzx ecx,BYTE PTR [r9+rcx*1]
+   bd9:  cmp cl,0x17
+   bdc:  je <+0xbe7>
+   bde:  cmp cl,0x2f
+   be1:  jne <+0x47>
+   be7:  lea rax,[rdx+0x3]
+   beb:  cmp rax,r8
+   bee:  jae <+0xc46>
+   bf0:  movzx eax,BYTE PTR [rdx+0x3]
+   bf4:  movzx ecx,BYTE PTR [r9+rax*1]
+   bf9:  cmp cl,0x34
+   bfc:  ja <+0xc3d>
+   bfe:  mov eax,0x1
+   c03:  mov esi,0x3
+   c08:  movabs rdi,0x1ffdfffffdffff
+   c12:  shl rax,cl
+   c15:  mov rcx,rax
+   c18:  mov eax,0x6
+   c1d:  test rcx,rdi
+   c20:  jne <+0x47>
+   c26:  mov edi,0x1000001
+   c2b:  mov esi,0x4
+   c30:  shl rdi,0x11
+   c34:  test rcx,rdi
+   c37:  jne <+0x47>
+   c3d:  lea rdi,[rdx+0x4]
+   c41:  jmp <+0x15c>
+   c46:  mov esi,0x3
+   c4b:  mov eax,0x6
+   c50:  jmp <+0x47>
+   c55:  test eax,0x20000020
+   c5a:  je <+0x60>
+   c60:  mov eax,0x9
+   c65:  cmp rdi,r8
+   c68:  jae <+0x47>
+   c6e:  movzx ecx,BYTE PTR [rdx+0x2]
+   c72:  movzx ecx,BYTE PTR [r9+rcx*1]
+   c77:  cmp cl,0x3
+   c7a:  je <+0xc85>
+   c7c:  cmp cl,0x1b
+   c7f:  jne <+0x47>
+   c85:  lea rax,[rdx+0x3]
+   c89:  cmp rax,r8
+   c8c:  jae <+0xcca>
+   c8e:  movzx eax,BYTE PTR [rdx+0x3]
+   c92:  movzx ecx,BYTE PTR [r9+rax*1]
+   c97:  cmp cl,0x34
+   c9a:  ja <+0xd84>
+   ca0:  movabs rsi,0x1ffdbffffdbfff
+   caa:  mov eax,0x1
+   caf:  shl rax,cl
+   cb2:  and rsi,rax
+   cb5:  je <+0xfcf>
+   cbb:  mov esi,0x3
+   cc0:  mov eax,0x3
+   cc5:  jmp <+0x47>
+   cca:  mov esi,0x3
+   ccf:  mov eax,0x3
+   cd4:  jmp <+0x47>
+   cd9:  cmp r10,r8
+   cdc:  jae <+0xeeb>
+   ce2:  movzx eax,BYTE PTR [rdi+0x2]
+   ce6:  movzx eax,BYTE PTR [r9+rax*1]
+   ceb:  cmp al,0x10
+   ced:  je <+0xe9e>
+   cf3:  cmp al,0x28
+   cf5:  je <+0xe9e>
+   cfb:  xor esi,esi
+   cfd:  mov eax,0x9
+   d02:  jmp <+0x47>
+   d07:  mov esi,0x4
+   d0c:  xor eax,eax
+   d0e:  jmp <+0x47>
+   d13:  mov esi,0x1
+   d18:  mov eax,0x4
+   d1d:  jmp <+0x47>
+   d22:  cmp r10,r8
+   d25:  jae <+0xef7>
+   d2b:  movzx edx,BYTE PTR [rdi+0x2]
+   d2f:  lea r10,[rdi+0x3]
+   d33:  movzx ecx,BYTE PTR [r9+rdx*1]
+   d38:  cmp cl,0x34
+   d3b:  ja <+0xb89>
+   d41:  movabs rsi,0x1ff5fffff5ffff
+   d4b:  shl rax,cl
+   d4e:  mov rdx,rax
+   d51:  and rsi,rax
+   d54:  je <+0xb5b>
+   d5a:  mov esi,0x3
+   d5f:  mov eax,0x4
+   d64:  jmp <+0x47>
+   d69:  mov esi,0x1
+   d6e:  mov eax,0x9
+   d73:  jmp <+0x47>
+   d78:  xor esi,esi
+   d7a:  mov eax,0x9
+   d7f:  jmp <+0x47>
+   d84:  mov esi,0x2
+   d89:  mov eax,0x1
+   d8e:  jmp <+0x47>
+   d93:  mov esi,0x4
+   d98:  mov eax,0x7
+   d9d:  jmp <+0x47>
+   da2:  mov esi,0x4
+   da7:  xor eax,eax
+   da9:  jmp <+0x47>
+   dae:  mov esi,0x4
+   db3:  mov eax,0x5
+   db8:  jmp <+0x47>
+   dbd:  mov esi,0x4
+   dc2:  mov eax,0x9
+   dc7:  jmp <+0x47>
+   dcc:  test eax,0x20000020
+   dd1:  je <+0x14ba>
+   dd7:  lea rdx,[rdi+0x2]
+   ddb:  mov eax,0x9
+   de0:  cmp rdx,r8
+   de3:  jae <+0x47>
+   de9:  movzx edx,BYTE PTR [rdi+0x2]
+   ded:  movzx edx,BYTE PTR [r9+rdx*1]
+   df2:  cmp dl,0x3
+   df5:  je <+0xe00>
+   df7:  cmp dl,0x1b
+   dfa:  jne <+0x47>
+   e00:  lea rax,[rdi+0x3]
+   e04:  cmp rax,r8
+   e07:  jae <+0xe5c>
+   e09:  movzx eax,BYTE PTR [rdi+0x3]
+   e0d:  movzx ecx,BYTE PTR [r9+rax*1]
+   e12:  cmp cl,0x34
+   e15:  ja <+0xe53>
+   e17:  mov edx,0x1
+   e1c:  mov esi,0x4
+   e21:  mov eax,0x1
+   e26:  shl rdx,cl
+   e29:  movabs rcx,0x1ffdfffffdffff
+   e33:  test rdx,rcx
+   e36:  jne <+0x47>
+   e3c:  mov ecx,0x1000001
+   e41:  mov esi,0x5
+   e46:  shl rcx,0x11
+   e4a:  test rdx,rcx
+   e4d:  jne <+0x47>
+   e53:  add rdi,0x4
+   e57:  jmp <+0x3bc>
+   e5c:  mov esi,0x4
+   e61:  mov eax,0x1
+   e66:  jmp <+0x47>
+   e6b:  lea rax,[r10+0x1]
+   e6f:  cmp rax,r8
+   e72:  jae <+0x11d0>
+   e78:  movzx eax,BYTE PTR [r10+0x1]
+   e7d:  movzx eax,BYTE PTR [r9+rax*1]
+   e82:  cmp al,0x8
+   e84:  je <+0x1076>
+   e8a:  cmp al,0x20
+   e8c:  je <+0x1076>
+   e92:  xor esi,esi
+   e94:  mov eax,0x9
+   e99:  jmp <+0x47>
+   e9e:  lea rax,[rdi+0x3]
+   ea2:  cmp rax,r8
+   ea5:  jae <+0x11dc>
+   eab:  movzx eax,BYTE PTR [rdi+0x3]
+   eaf:  movzx eax,BYTE PTR [r9+rax*1]
+   eb4:  cmp al,0xe
+   eb6:  je <+0x110c>
+   ebc:  cmp al,0x26
+   ebe:  je <+0x110c>
+   ec4:  xor esi,esi
+   ec6:  mov eax,0x9
+   ecb:  jmp <+0x47>
+   ed0:  mov esi,0x3
+   ed5:  mov eax,0x6
+   eda:  jmp <+0x47>
+   edf:  xor esi,esi
+   ee1:  mov eax,0x9
+   ee6:  jmp <+0x47>
+   eeb:  xor esi,esi
+   eed:  mov eax,0x9
+   ef2:  jmp <+0x47>
+   ef7:  mov esi,0x3
+   efc:  mov eax,0x4
+   f01:  jmp <+0x47>
+   f06:  cmp r10,r8
+   f09:  jae <+0xfc5>
+   f0f:  movzx eax,BYTE PTR [rdi+0x2]
+   f13:  movzx eax,BYTE PTR [r9+rax*1]
+   f18:  cmp al,0x12
+   f1a:  je <+0xf2a>
+   f1c:  cmp al,0x2a
+   f1e:  je <+0xf2a>
+   f20:  mov eax,0x9
+   f25:  jmp <+0x47>
+   f2a:  lea rax,[rdi+0x3]
+   f2e:  cmp rax,r8
+   f31:  jae <+0x145f>
+   f37:  movzx eax,BYTE PTR [rdi+0x3]
+   f3b:  movzx edx,BYTE PTR [r9+rax*1]
+   f40:  cmp dl,0x7
+   f43:  je <+0xf53>
+   f45:  mov eax,0x9
+   f4a:  cmp dl,0x1f
+   f4d:  jne <+0x47>
+   f53:  add rdi,0x4
+   f57:  cmp rdi,r8
+   f5a:  jae <+0x1450>
+   f60:  movzx eax,BYTE PTR [rdi]
+   f63:  movzx ecx,BYTE PTR [r9+rax*1]
+   f68:  cmp cl,0x34
+   f6b:  ja <+0x16d0>
+   f71:  mov eax,0x1
+   f76:  mov esi,0x5
+   f7b:  shl rax,cl
+   f7e:  movabs rcx,0x1ffdfffffdffff
+   f88:  mov rdx,rax
+   f8b:  mov eax,0x8
+   f90:  test rdx,rcx
+   f93:  jne <+0x47>
+   f99:  mov eax,0x1000001
+   f9e:  shl rax,0x11
+   fa2:  and rax,rdx
+   fa5:  cmp rax,0x1
+   fa9:  sbb rsi,rsi
+   fac:  and rsi,0xfffffffffffffffe
+   fb0:  add rsi,0x6
+   fb4:  cmp rax,0x1
+   fb8:  sbb eax,eax
+   fba:  and eax,0xfffffffa
+   fbd:  add eax,0x8
+   fc0:  jmp <+0x47>
+   fc5:  mov eax,0x9
+   fca:  jmp <+0x47>
+   fcf:  mov ecx,0x1000001
+   fd4:  shl rcx,0xe
+   fd8:  test rax,rcx
+   fdb:  jne <+0x1009>
+   fdd:  mov edx,0x1000001
+   fe2:  shl rdx,0x11
+   fe6:  and rax,rdx
+   fe9:  cmp rax,0x1
+   fed:  sbb rsi,rsi
+   ff0:  and rsi,0xfffffffffffffffe
+   ff4:  add rsi,0x4
+   ff8:  cmp rax,0x1
+   ffc:  sbb eax,eax
+   ffe:  and eax,0xfffffffe
+  1001:  add eax,0x3
+  1004:  jmp <+0x47>
+  1009:  lea rax,[rdx+0x4]
+  100d:  cmp rax,r8
+  1010:  jae <+0x105d>
+  1012:  movzx eax,BYTE PTR [rdx+0x4]
+  1016:  movzx eax,BYTE PTR [r9+rax*1]
+  101b:  cmp al,0xd
+  101d:  je <+0x102d>
+  101f:  cmp al,0x25
+  1021:  je <+0x102d>
+  1023:  mov eax,0x9
+  1028:  jmp <+0x47>
+  102d:  lea rax,[rdx+0x5]
+  1031:  cmp rax,r8
+  1034:  jae <+0x1446>
+  103a:  movzx eax,BYTE PTR [rdx+0x5]
+  103e:  movzx eax,BYTE PTR [r9+rax*1]
+  1043:  cmp al,0x4
+  1045:  je <+0x11e8>
+  104b:  cmp al,0x1c
+  104d:  je <+0x11e8>
+  1053:  mov eax,0x9
+  1058:  jmp <+0x47>
+  105d:  mov eax,0x9
+  1062:  jmp <+0x47>
+  1067:  mov esi,0x3
+  106c:  mov eax,0x3
+  1071:  jmp <+0x47>
+  1076:  lea rax,[r10+0x2]
+  107a:  cmp rax,r8
+  107d:  jae <+0x1437>
+  1083:  movzx eax,BYTE PTR [r10+0x2]
+  1088:  lea rdx,[r10+0x3]
+  108c:  movzx ecx,BYTE PTR [r9+rax*1]
+  1091:  cmp cl,0x34
+  1094:  ja <+0x1110>
+  1096:  mov edi,0x1
+  109b:  mov esi,0x5
+  10a0:  movabs r11,0x1ffdfffffdffff
+  10aa:  mov rax,rdi
+  10ad:  shl rax,cl
+  10b0:  mov rcx,rax
+  10b3:  mov eax,0x2
+  10b8:  test rcx,r11
+  10bb:  jne <+0x47>
+  10c1:  movabs r11,0x20000020000
+  10cb:  test rcx,r11
+  10ce:  je <+0x1110>
+  10d0:  cmp rdx,r8
+  10d3:  jae <+0x14e2>
+  10d9:  movzx edx,BYTE PTR [r10+0x3]
+  10de:  movzx ecx,BYTE PTR [r9+rdx*1]
+  10e3:  cmp cl,0x34
+  10e6:  ja <+0x14d8>
+  10ec:  movabs rsi,0x1fffffdfffffdf
+  10f6:  shl rdi,cl
+  10f9:  and rsi,rdi
+  10fc:  je <+0x16a3>
+  1102:  mov esi,0x6
+  1107:  jmp <+0x47>
+  110c:  lea rdx,[rdi+0x4]
+  1110:  cmp rdx,r8
+  1113:  jae <+0x12f8>
+  1119:  movzx eax,BYTE PTR [rdx]
+  111c:  lea rdi,[rdx+0x1]
+  1120:  movzx ecx,BYTE PTR [r9+rax*1]
+  1125:  cmp cl,0x34
+  1128:  ja <+0xf57>
+  112e:  mov r10d,0x1
+  1134:  mov esi,0x5
+  1139:  movabs r11,0x1ffdfffffdffff
+  1143:  mov rax,r10
+  1146:  shl rax,cl
+  1149:  mov rcx,rax
+  114c:  mov eax,0x1
+  1151:  test rcx,r11
+  1154:  jne <+0x47>
+  115a:  movabs r11,0x20000020000
+  1164:  test rcx,r11
+  1167:  je <+0xf57>
+  116d:  cmp rdi,r8
+  1170:  jae <+0x14fb>
+  1176:  movzx ecx,BYTE PTR [rdx+0x1]
+  117a:  movzx ecx,BYTE PTR [r9+rcx*1]
+  117f:  cmp cl,0x34
+  1182:  ja <+0x1532>
+  1188:  movabs rsi,0x1fffffdfffffdf
+  1192:  shl r10,cl
+  1195:  and rsi,r10
+  1198:  je <+0x1505>
+  119e:  mov esi,0x6
+  11a3:  jmp <+0x47>
+  11a8:  mov esi,0x3
+  11ad:  mov eax,0x4
+  11b2:  jmp <+0x47>
+  11b7:  mov esi,0x3
+  11bc:  mov eax,0x1
+  11c1:  jmp <+0x47>
+  11c6:  mov esi,0x5
+  11cb:  jmp <+0x47>
+  11d0:  xor esi,esi
+  11d2:  mov eax,0x9
+  11d7:  jmp <+0x47>
+  11dc:  xor esi,esi
+  11de:  mov eax,0x9
+  11e3:  jmp <+0x47>
+  11e8:  lea rax,[rdx+0x6]
+  11ec:  cmp rax,r8
+  11ef:  jae <+0x1469>
+  11f5:  movzx eax,BYTE PTR [rdx+0x6]
+  11f9:  movzx ecx,BYTE PTR [r9+rax*1]
+  11fe:  cmp cl,0x34
+  1201:  ja <+0x14c9>
+  1207:  mov eax,0x1
+  120c:  mov esi,0x6
+  1211:  shl rax,cl
+  1214:  movabs rcx,0x1ffdfffffdffff
+  121e:  mov rdx,rax
+  1221:  mov eax,0x3
+  1226:  test rdx,rcx
+  1229:  jne <+0x47>
+  122f:  mov eax,0x1000001
+  1234:  shl rax,0x11
+  1238:  and rax,rdx
+  123b:  cmp rax,0x1
+  123f:  sbb rsi,rsi
+  1242:  and rsi,0xfffffffffffffffe
+  1246:  add rsi,0x7
+  124a:  cmp rax,0x1
+  124e:  sbb eax,eax
+  1250:  and eax,0xfffffffe
+  1253:  add eax,0x3
+  1256:  jmp <+0x47>
+  125b:  test eax,0x20000020
+  1260:  je <+0x580>
+  1266:  mov eax,0x9
+  126b:  cmp rdx,r8
+  126e:  jae <+0x47>
+  1274:  movzx edx,BYTE PTR [r10+0x1]
+  1279:  movzx edx,BYTE PTR [r9+rdx*1]
+  127e:  cmp dl,0x3
+  1281:  je <+0x128c>
+  1283:  cmp dl,0x1b
+  1286:  jne <+0x47>
+  128c:  lea rax,[r10+0x2]
+  1290:  cmp rax,r8
+  1293:  jae <+0x12e9>
+  1295:  movzx eax,BYTE PTR [r10+0x2]
+  129a:  movzx ecx,BYTE PTR [r9+rax*1]
+  129f:  cmp cl,0x34
+  12a2:  ja <+0x12e0>
+  12a4:  mov edx,0x1
+  12a9:  mov esi,0x4
+  12ae:  mov eax,0x2
+  12b3:  shl rdx,cl
+  12b6:  movabs rcx,0x1ffdfffffdffff
+  12c0:  test rdx,rcx
+  12c3:  jne <+0x47>
+  12c9:  mov ecx,0x1000001
+  12ce:  mov esi,0x5
+  12d3:  shl rcx,0x11
+  12d7:  test rdx,rcx
+  12da:  jne <+0x47>
+  12e0:  lea rdx,[r10+0x3]
+  12e4:  jmp <+0x94a>
+  12e9:  mov esi,0x4
+  12ee:  mov eax,0x2
+  12f3:  jmp <+0x47>
+  12f8:  mov esi,0x5
+  12fd:  mov eax,0x1
+  1302:  jmp <+0x47>
+  1307:  test eax,0x20000020
+  130c:  je <+0x1688>
+  1312:  lea rdx,[rdi+0x2]
+  1316:  mov eax,0x9
+  131b:  cmp rdx,r8
+  131e:  jae <+0x47>
+  1324:  movzx edx,BYTE PTR [rdi+0x2]
+  1328:  movzx edx,BYTE PTR [r9+rdx*1]
+  132d:  cmp dl,0x3
+  1330:  je <+0x133b>
+  1332:  cmp dl,0x1b
+  1335:  jne <+0x47>
+  133b:  lea rax,[rdi+0x3]
+  133f:  cmp rax,r8
+  1342:  jae <+0x1404>
+  1348:  movzx eax,BYTE PTR [rdi+0x3]
+  134c:  movzx ecx,BYTE PTR [r9+rax*1]
+  1351:  cmp cl,0x34
+  1354:  ja <+0x1392>
+  1356:  mov edx,0x1
+  135b:  mov esi,0x5
+  1360:  mov eax,0x1
+  1365:  shl rdx,cl
+  1368:  movabs rcx,0x1ffdfffffdffff
+  1372:  test rdx,rcx
+  1375:  jne <+0x47>
+  137b:  mov ecx,0x1000001
+  1380:  mov esi,0x6
+  1385:  shl rcx,0x11
+  1389:  test rdx,rcx
+  138c:  jne <+0x47>
+  1392:  add rdi,0x4
+  1396:  cmp rdi,r8
+  1399:  jae <+0x1679>
+  139f:  movzx eax,BYTE PTR [rdi]
+  13a2:  movzx ecx,BYTE PTR [r9+rax*1]
+  13a7:  cmp cl,0x34
+  13aa:  ja <+0x166a>
+  13b0:  mov eax,0x1
+  13b5:  mov esi,0x6
+  13ba:  shl rax,cl
+  13bd:  movabs rcx,0x1ffdfffffdffff
+  13c7:  mov rdx,rax
+  13ca:  mov eax,0x4
+  13cf:  test rdx,rcx
+  13d2:  jne <+0x47>
+  13d8:  mov eax,0x1000001
+  13dd:  shl rax,0x11
+  13e1:  and rax,rdx
+  13e4:  cmp rax,0x1
+  13e8:  sbb rsi,rsi
+  13eb:  and rsi,0xfffffffffffffffe
+  13ef:  add rsi,0x7
+  13f3:  cmp rax,0x1
+  13f7:  sbb eax,eax
+  13f9:  and eax,0xfffffffe
+  13fc:  add eax,0x4
+  13ff:  jmp <+0x47>
+  1404:  mov esi,0x5
+  1409:  mov eax,0x1
+  140e:  jmp <+0x47>
+  1413:  cmp r10,r8
+  1416:  jae <+0x14a6>
+  141c:  movzx eax,BYTE PTR [rdi+0x3]
+  1420:  movzx eax,BYTE PTR [r9+rax*1]
+  1425:  cmp al,0x12
+  1427:  je <+0x1478>
+  1429:  cmp al,0x2a
+  142b:  je <+0x1478>
+  142d:  mov eax,0x9
+  1432:  jmp <+0x47>
+  1437:  mov esi,0x5
+  143c:  mov eax,0x2
+  1441:  jmp <+0x47>
+  1446:  mov eax,0x9
+  144b:  jmp <+0x47>
+  1450:  mov esi,0x5
+  1455:  mov eax,0x8
+  145a:  jmp <+0x47>
+  145f:  mov eax,0x9
+  1464:  jmp <+0x47>
+  1469:  mov esi,0x6
+  146e:  mov eax,0x3
+  1473:  jmp <+0x47>
+  1478:  lea rax,[rdi+0x4]
+  147c:  cmp rax,r8
+  147f:  jae <+0x14b0>
+  1481:  movzx eax,BYTE PTR [rdi+0x4]
+  1485:  movzx edx,BYTE PTR [r9+rax*1]
+  148a:  cmp dl,0x5
+  148d:  je <+0x149d>
+  148f:  mov eax,0x9
+  1494:  cmp dl,0x1d
+  1497:  jne <+0x47>
+  149d:  add rdi,0x5
+  14a1:  jmp <+0x1396>
+  14a6:  mov eax,0x9
+  14ab:  jmp <+0x47>
+  14b0:  mov eax,0x9
+  14b5:  jmp <+0x47>
+  14ba:  mov esi,0x1
+  14bf:  mov eax,0x7
+  14c4:  jmp <+0x47>
+  14c9:  mov esi,0x5
+  14ce:  mov eax,0x1
+  14d3:  jmp <+0x47>
+  14d8:  mov eax,0x1
+  14dd:  jmp <+0x47>
+  14e2:  mov esi,0x6
+  14e7:  jmp <+0x47>
+  14ec:  mov esi,0x1
+  14f1:  mov eax,0x3
+  14f6:  jmp <+0x47>
+  14fb:  mov esi,0x6
+  1500:  jmp <+0x47>
+  1505:  test r10d,0x20000020
+  150c:  je <+0x156a>
+  150e:  lea rax,[rdx+0x2]
+  1512:  cmp rax,r8
+  1515:  jae <+0x1560>
+  1517:  movzx eax,BYTE PTR [rdx+0x2]
+  151b:  movzx eax,BYTE PTR [r9+rax*1]
+  1520:  cmp al,0x3
+  1522:  je <+0x153c>
+  1524:  cmp al,0x1b
+  1526:  je <+0x153c>
+  1528:  mov eax,0x9
+  152d:  jmp <+0x47>
+  1532:  mov eax,0x8
+  1537:  jmp <+0x47>
+  153c:  lea rax,[rdx+0x3]
+  1540:  cmp rax,r8
+  1543:  jae <+0x15a1>
+  1545:  movzx eax,BYTE PTR [rdx+0x3]
+  1549:  movzx eax,BYTE PTR [r9+rax*1]
+  154e:  cmp al,0xe
+  1550:  je <+0x1579>
+  1552:  cmp al,0x26
+  1554:  je <+0x1579>
+  1556:  mov eax,0x9
+  155b:  jmp <+0x47>
+  1560:  mov eax,0x9
+  1565:  jmp <+0x47>
+  156a:  mov esi,0x5
+  156f:  mov eax,0x8
+  1574:  jmp <+0x47>
+  1579:  lea rax,[rdx+0x4]
+  157d:  cmp rax,r8
+  1580:  jae <+0x1645>
+  1586:  movzx eax,BYTE PTR [rdx+0x4]
+  158a:  movzx eax,BYTE PTR [r9+rax*1]
+  158f:  cmp al,0xd
+  1591:  je <+0x15ab>
+  1593:  cmp al,0x25
+  1595:  je <+0x15ab>
+  1597:  mov eax,0x9
+  159c:  jmp <+0x47>
+  15a1:  mov eax,0x9
+  15a6:  jmp <+0x47>
+  15ab:  lea rax,[rdx+0x5]
+  15af:  cmp rax,r8
+  15b2:  jae <+0x163b>
+  15b8:  movzx eax,BYTE PTR [rdx+0x5]
+  15bc:  movzx ecx,BYTE PTR [r9+rax*1]
+  15c1:  cmp cl,0x4
+  15c4:  je <+0x15d4>
+  15c6:  mov eax,0x9
+  15cb:  cmp cl,0x1c
+  15ce:  jne <+0x47>
+  15d4:  add rdx,0x6
+  15d8:  cmp rdx,r8
+  15db:  jae <+0x165b>
+  15dd:  movzx eax,BYTE PTR [rdx]
+  15e0:  movzx ecx,BYTE PTR [r9+rax*1]
+  15e5:  cmp cl,0x34
+  15e8:  ja <+0x164f>
+  15ea:  mov eax,0x1
+  15ef:  mov esi,0xb
+  15f4:  shl rax,cl
+  15f7:  movabs rcx,0x1ffdfffffdffff
+  1601:  mov rdx,rax
+  1604:  mov eax,0x1
+  1609:  test rdx,rcx
+  160c:  jne <+0x47>
+  1612:  mov eax,0x1000001
+  1617:  shl rax,0x11
+  161b:  and rax,rdx
+  161e:  cmp rax,0x1
+  1622:  sbb rsi,rsi
+  1625:  and rsi,0xfffffffffffffffe
+  1629:  add rsi,0xc
+  162d:  cmp rax,0x1
+  1631:  sbb eax,eax
+  1633:  add eax,0x1
+  1636:  jmp <+0x47>
+  163b:  mov eax,0x9
+  1640:  jmp <+0x47>
+  1645:  mov eax,0x9
+  164a:  jmp <+0x47>
+  164f:  mov esi,0xa
+  1654:  xor eax,eax
+  1656:  jmp <+0x47>
+  165b:  mov esi,0xb
+  1660:  mov eax,0x1
+  1665:  jmp <+0x47>
+  166a:  mov esi,0x5
+  166f:  mov eax,0x2
+  1674:  jmp <+0x47>
+  1679:  mov esi,0x6
+  167e:  mov eax,0x4
+  1683:  jmp <+0x47>
+  1688:  mov esi,0x3
+  168d:  mov eax,0x6
+  1692:  jmp <+0x47>
+  1697:  xor esi,esi
+  1699:  mov eax,0x9
+  169e:  jmp <+0x47>
+  16a3:  test edi,0x20000020
+  16a9:  je <+0x171a>
+  16ab:  lea rax,[r10+0x4]
+  16af:  cmp rax,r8
+  16b2:  jae <+0x1710>
+  16b4:  movzx eax,BYTE PTR [r10+0x4]
+  16b9:  movzx eax,BYTE PTR [r9+rax*1]
+  16be:  cmp al,0x3
+  16c0:  je <+0x16df>
+  16c2:  cmp al,0x1b
+  16c4:  je <+0x16df>
+  16c6:  mov eax,0x9
+  16cb:  jmp <+0x47>
+  16d0:  mov esi,0x4
+  16d5:  mov eax,0x2
+  16da:  jmp <+0x47>
+  16df:  lea rax,[r10+0x5]
+  16e3:  cmp rax,r8
+  16e6:  jae <+0x17bb>
+  16ec:  movzx eax,BYTE PTR [r10+0x5]
+  16f1:  movzx eax,BYTE PTR [r9+rax*1]
+  16f6:  cmp al,0xe
+  16f8:  je <+0x1792>
+  16fe:  cmp al,0x26
+  1700:  je <+0x1792>
+  1706:  mov eax,0x9
+  170b:  jmp <+0x47>
+  1710:  mov eax,0x9
+  1715:  jmp <+0x47>
+  171a:  mov esi,0x5
+  171f:  mov eax,0x1
+  1724:  jmp <+0x47>
+  1729:  test r10d,0x20000020
+  1730:  je <+0x1788>
+  1732:  lea rax,[rdx+0x2]
+  1736:  cmp rax,r8
+  1739:  jae <+0x1756>
+  173b:  movzx eax,BYTE PTR [rdx+0x2]
+  173f:  movzx eax,BYTE PTR [r9+rax*1]
+  1744:  cmp al,0x3
+  1746:  je <+0x1760>
+  1748:  cmp al,0x1b
+  174a:  je <+0x1760>
+  174c:  mov eax,0x9
+  1751:  jmp <+0x47>
+  1756:  mov eax,0x9
+  175b:  jmp <+0x47>
+  1760:  lea rax,[rdx+0x3]
+  1764:  cmp rax,r8
+  1767:  jae <+0x17f5>
+  176d:  movzx eax,BYTE PTR [rdx+0x3]
+  1771:  movzx eax,BYTE PTR [r9+rax*1]
+  1776:  cmp al,0xe
+  1778:  je <+0x17c5>
+  177a:  cmp al,0x26
+  177c:  je <+0x17c5>
+  177e:  mov eax,0x9
+  1783:  jmp <+0x47>
+  1788:  mov esi,0x4
+  178d:  jmp <+0x47>
+  1792:  lea rax,[r10+0x6]
+  1796:  cmp rax,r8
+  1799:  jae <+0x18a0>
+  179f:  movzx eax,BYTE PTR [r10+0x6]
+  17a4:  movzx eax,BYTE PTR [r9+rax*1]
+  17a9:  cmp al,0xd
+  17ab:  je <+0x17ff>
+  17ad:  cmp al,0x25
+  17af:  je <+0x17ff>
+  17b1:  mov eax,0x9
+  17b6:  jmp <+0x47>
+  17bb:  mov eax,0x9
+  17c0:  jmp <+0x47>
+  17c5:  lea rax,[rdx+0x4]
+  17c9:  cmp rax,r8
+  17cc:  jae <+0x18d2>
+  17d2:  movzx eax,BYTE PTR [rdx+0x4]
+  17d6:  movzx eax,BYTE PTR [r9+rax*1]
+  17db:  cmp al,0xd
+  17dd:  je <+0x18aa>
+  17e3:  cmp al,0x25
+  17e5:  je <+0x18aa>
+  17eb:  mov eax,0x9
+  17f0:  jmp <+0x47>
+  17f5:  mov eax,0x9
+  17fa:  jmp <+0x47>
+  17ff:  lea rax,[r10+0x7]
+  1803:  cmp rax,r8
+  1806:  jae <+0x1896>
+  180c:  movzx eax,BYTE PTR [r10+0x7]
+  1811:  movzx edx,BYTE PTR [r9+rax*1]
+  1816:  cmp dl,0x4
+  1819:  je <+0x1829>
+  181b:  mov eax,0x9
+  1820:  cmp dl,0x1c
+  1823:  jne <+0x47>
+  1829:  lea rdx,[r10+0x8]
+  182d:  cmp rdx,r8
+  1830:  jae <+0x1887>
+  1832:  movzx eax,BYTE PTR [rdx]
+  1835:  movzx ecx,BYTE PTR [r9+rax*1]
+  183a:  cmp cl,0x34
+  183d:  ja <+0x187e>
+  183f:  mov eax,0x1
+  1844:  mov esi,0xb
+  1849:  movabs rdi,0x1ffdfffffdffff
+  1853:  shl rax,cl
+  1856:  mov rcx,rax
+  1859:  mov eax,0x2
+  185e:  test rcx,rdi
+  1861:  jne <+0x47>
+  1867:  mov edi,0x1000001
+  186c:  mov esi,0xc
+  1871:  shl rdi,0x11
+  1875:  test rcx,rdi
+  1878:  jne <+0x47>
+  187e:  add rdx,0x1
+  1882:  jmp <+0x15d8>
+  1887:  mov esi,0xb
+  188c:  mov eax,0x2
+  1891:  jmp <+0x47>
+  1896:  mov eax,0x9
+  189b:  jmp <+0x47>
+  18a0:  mov eax,0x9
+  18a5:  jmp <+0x47>
+  18aa:  lea rax,[rdx+0x5]
+  18ae:  cmp rax,r8
+  18b1:  jae <+0x1944>
+  18b7:  movzx eax,BYTE PTR [rdx+0x5]
+  18bb:  movzx eax,BYTE PTR [r9+rax*1]
+  18c0:  cmp al,0x4
+  18c2:  je <+0x18dc>
+  18c4:  cmp al,0x1c
+  18c6:  je <+0x18dc>
+  18c8:  mov eax,0x9
+  18cd:  jmp <+0x47>
+  18d2:  mov eax,0x9
+  18d7:  jmp <+0x47>
+  18dc:  lea rax,[rdx+0x6]
+  18e0:  cmp rax,r8
+  18e3:  jae <+0x1938>
+  18e5:  movzx eax,BYTE PTR [rdx+0x6]
+  18e9:  movzx ecx,BYTE PTR [r9+rax*1]
+  18ee:  cmp cl,0x34
+  18f1:  ja <+0x192f>
+  18f3:  mov eax,0x1
+  18f8:  mov esi,0xa
+  18fd:  movabs rdi,0x1ffdfffffdffff
+  1907:  shl rax,cl
+  190a:  mov rcx,rax
+  190d:  xor eax,eax
+  190f:  test rcx,rdi
+  1912:  jne <+0x47>
+  1918:  mov edi,0x1000001
+  191d:  mov esi,0xb
+  1922:  shl rdi,0x11
+  1926:  test rcx,rdi
+  1929:  jne <+0x47>
+  192f:  add rdx,0x7
+  1933:  jmp <+0x182d>
+  1938:  mov esi,0xa
+  193d:  xor eax,eax
+  193f:  jmp <+0x47>
+  1944:  mov eax,0x9
+  1949:  jmp <+0x47>
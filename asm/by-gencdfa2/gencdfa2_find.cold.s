@@ -0,0 +1,7 @@
+gencdfa2_find.cold:
+     0:  mov rsi,rcx
+     3:  jmp <gencdfa2_find+0xe0>
+     8:  mov rsi,rcx
+     b:  jmp <gencdfa2_find+0x110>
+    10:  mov rsi,rcx
+    13:  jmp <gencdfa2_find+0x1c0>
@@ -0,0 +1,1295 @@
+gencdfa2_find:
+     0:  mov rdx,rdi
+     3:  mov rdi,rsi
+     6:  cmp rdx,rsi
+     9:  jae <+0x50>
+     b:  movzx eax,BYTE PTR [rdx]
+     e:  lea r8,[rip]
+    15:  lea rsi,[rdx+0x1]
+    19:  movzx eax,BYTE PTR [r8+rax*1]
+    1e:  sub eax,0x4
+    21:  cmp al,0x17
+    23:  ja <+0x40>
+    25:  lea rcx,[rip]
+    2c:  movzx eax,al
+    2f:  movsxd rax,DWORD PTR [rcx+rax*4]
+    33:  add rax,rcx
+    36:  jmp rax
+    38:  nop DWORD PTR [rax+rax*1+0x0]
+    40:  xor ecx,ecx
+    42:  mov eax,0x9
+    47:  mov rdx,rcx
+    4a:  ret
+    4b:  nop DWORD PTR [rax+rax*1+0x0]
+    50:  xor ecx,ecx
+    52:  mov eax,0x9
+    57:  mov rdx,rcx
+    5a:  ret
+    5b:  nop DWORD PTR [rax+rax*1+0x0]
+    60:  cmp rsi,rdi
+    63:  jae <+0x5f8>
+    69:  movzx edx,BYTE PTR [rsi]
+    6c:  xor ecx,ecx
+    6e:  mov eax,0x9
+    73:  cmp BYTE PTR [r8+rdx*1],0x11
+    78:  jne <+0x47>
+    7a:  lea rdx,[rsi+0x1]
+    7e:  cmp rdx,rdi
+    81:  jae <+0x7a9>
+    87:  movzx edx,BYTE PTR [rsi+0x1]
+    8b:  movzx edx,BYTE PTR [r8+rdx*1]
+    90:  cmp dl,0x5
+    93:  je <+0x877>
+    99:  ja <+0x65c>
+    9f:  mov ecx,0x2
+    a4:  mov eax,0x1
+    a9:  jmp <+0x47>
+    ab:  nop DWORD PTR [rax+rax*1+0x0]
+    b0:  cmp rsi,rdi
+    b3:  jae <+0x608>
+    b9:  movzx eax,BYTE PTR [rsi]
+    bc:  lea rcx,[rsi+0x1]
+    c0:  cmp BYTE PTR [r8+rax*1],0x1c
+    c5:  ja <gencdfa2_find.cold>
+    cb:  movzx eax,BYTE PTR [r8+rax*1]
+    d0:  lea rdx,[rip]
+    d7:  movsxd rax,DWORD PTR [rdx+rax*4]
+    db:  add rax,rdx
+    de:  jmp rax
+    e0:  cmp rsi,rdi
+    e3:  jae <+0x300>
+    e9:  movzx eax,BYTE PTR [rsi]
+    ec:  lea rcx,[rsi+0x1]
+    f0:  cmp BYTE PTR [r8+rax*1],0x1c
+    f5:  ja <gencdfa2_find.cold+0x10>
+    fb:  movzx eax,BYTE PTR [r8+rax*1]
+   100:  lea rdx,[rip]
+   107:  movsxd rax,DWORD PTR [rdx+rax*4]
+   10b:  add rax,rdx
+   10e:  jmp rax
+   110:  cmp rsi,rdi
+   113:  jae <+0x310>
+   119:  movzx edx,BYTE PTR [rsi]
+   11c:  xor ecx,ecx
+   11e:  add rsi,0x1
+   122:  mov eax,0x9
+   127:  cmp BYTE PTR [r8+rdx*1],0x19
+   12c:  jne <+0x47>
+   132:  cmp rsi,rdi
+   135:  jae <+0x79d>
+   13b:  movzx edx,BYTE PTR [rsi]
+   13e:  xor ecx,ecx
+   140:  mov eax,0x9
+   145:  cmp BYTE PTR [r8+rdx*1],0x11
+   14a:  jne <+0x47>
+   150:  lea rdx,[rsi+0x1]
+   154:  cmp rdx,rdi
+   157:  jae <+0x9de>
+   15d:  movzx edx,BYTE PTR [rsi+0x1]
+   161:  movzx edx,BYTE PTR [r8+rdx*1]
+   166:  cmp dl,0x5
+   169:  je <+0xb9e>
+   16f:  ja <+0x8dc>
+   175:  mov ecx,0x3
+   17a:  mov eax,0x1
+   17f:  jmp <+0x47>
+   184:  nop DWORD PTR [rax+0x0]
+   188:  cmp rsi,rdi
+   18b:  jae <+0x320>
+   191:  movzx eax,BYTE PTR [rsi]
+   194:  lea rcx,[rsi+0x1]
+   198:  cmp BYTE PTR [r8+rax*1],0x1c
+   19d:  ja <gencdfa2_find.cold+0x8>
+   1a3:  movzx eax,BYTE PTR [r8+rax*1]
+   1a8:  lea rdx,[rip]
+   1af:  movsxd rax,DWORD PTR [rdx+rax*4]
+   1b3:  add rax,rdx
+   1b6:  jmp rax
+   1b8:  nop DWORD PTR [rax+rax*1+0x0]
+   1c0:  cmp rsi,rdi
+   1c3:  jae <+0x330>
+   1c9:  movzx eax,BYTE PTR [rsi]
+   1cc:  lea rdx,[rsi+0x1]
+   1d0:  movzx eax,BYTE PTR [r8+rax*1]
+   1d5:  cmp al,0x1
+   1d7:  je <+0x630>
+   1dd:  cmp al,0x11
+   1df:  jne <+0x620>
+   1e5:  cmp rdx,rdi
+   1e8:  jae <+0x7e1>
+   1ee:  movzx eax,BYTE PTR [rsi+0x1]
+   1f2:  movzx eax,BYTE PTR [r8+rax*1]
+   1f7:  cmp al,0x5
+   1f9:  je <+0x903>
+   1ff:  ja <+0x780>
+   205:  mov ecx,0x2
+   20a:  xor eax,eax
+   20c:  jmp <+0x47>
+   211:  nop DWORD PTR [rax+0x0]
+   218:  cmp rsi,rdi
+   21b:  jae <+0x350>
+   221:  movzx eax,BYTE PTR [rdx+0x1]
+   225:  lea rsi,[rdx+0x2]
+   229:  movzx eax,BYTE PTR [r8+rax*1]
+   22e:  cmp al,0x5
+   230:  je <+0x6d8>
+   236:  ja <+0x5d8>
+   23c:  mov ecx,0x1
+   241:  mov eax,0x3
+   246:  jmp <+0x47>
+   24b:  nop DWORD PTR [rax+rax*1+0x0]
+   250:  cmp rsi,rdi
+   253:  jae <+0x360>
+   259:  movzx eax,BYTE PTR [rdx+0x1]
+   25d:  lea rsi,[rdx+0x2]
+   261:  movzx eax,BYTE PTR [r8+rax*1]
+   266:  cmp al,0x1
+   268:  je <+0x680>
+   26e:  ja <+0x5b8>
+   274:  mov ecx,0x1
+   279:  mov eax,0x6
+   27e:  jmp <+0x47>
+   283:  nop DWORD PTR [rax+rax*1+0x0]
+   288:  cmp rsi,rdi
+   28b:  jae <+0x340>
+   291:  movzx eax,BYTE PTR [rdx+0x1]
+   295:  lea rsi,[rdx+0x2]
+   299:  cmp BYTE PTR [r8+rax*1],0x1c
+   29e:  ja <+0x188>
+   2a4:  movzx eax,BYTE PTR [r8+rax*1]
+   2a9:  lea rcx,[rip]
+   2b0:  movsxd rax,DWORD PTR [rcx+rax*4]
+   2b4:  add rax,rcx
+   2b7:  jmp rax
+   2b9:  nop DWORD PTR [rax+0x0]
+   2c0:  mov ecx,0x1
+   2c5:  mov eax,0x5
+   2ca:  jmp <+0x47>
+   2cf:  nop
+   2d0:  mov ecx,0x1
+   2d5:  mov eax,0x9
+   2da:  jmp <+0x47>
+   2df:  nop
+   2e0:  mov ecx,0x1
+   2e5:  mov eax,0x4
+   2ea:  jmp <+0x47>
+   2ef:  nop
+   2f0:  mov ecx,0x1
+   2f5:  mov eax,0x7
+   2fa:  jmp <+0x47>
+   2ff:  nop
+   300:  mov ecx,0x1
+   305:  mov eax,0x4
+   30a:  jmp <+0x47>
+   30f:  nop
+   310:  xor ecx,ecx
+   312:  mov eax,0x9
+   317:  jmp <+0x47>
+   31c:  nop DWORD PTR [rax+0x0]
+   320:  mov ecx,0x1
+   325:  mov eax,0x9
+   32a:  jmp <+0x47>
+   32f:  nop
+   330:  xor ecx,ecx
+   332:  mov eax,0x9
+   337:  jmp <+0x47>
+   33c:  nop DWORD PTR [rax+0x0]
+   340:  mov ecx,0x1
+   345:  mov eax,0x7
+   34a:  jmp <+0x47>
+   34f:  nop
+   350:  mov ecx,0x1
+   355:  mov eax,0x3
+   35a:  jmp <+0x47>
+   35f:  nop
+   360:  mov ecx,0x1
+   365:  mov eax,0x6
+   36a:  jmp <+0x47>
+   36f:  cmp rcx,rdi
+   372:  jae <+0x85c>
+   378:  movzx edx,BYTE PTR [rsi+0x1]
+   37c:  xor ecx,ecx
+   37e:  add rsi,0x2
+   382:  mov eax,0x9
+   387:  cmp BYTE PTR [r8+rdx*1],0x13
+   38c:  jne <+0x47>
+   392:  cmp rsi,rdi
+   395:  jae <+0xb74>
+   39b:  movzx edx,BYTE PTR [rsi]
+   39e:  xor ecx,ecx
+   3a0:  mov eax,0x9
+   3a5:  cmp BYTE PTR [r8+rdx*1],0x10
+   3aa:  jne <+0x47>
+   3b0:  lea rax,[rsi+0x1]
+   3b4:  cmp rax,rdi
+   3b7:  jae <+0xdb3>
+   3bd:  movzx eax,BYTE PTR [rsi+0x1]
+   3c1:  movzx eax,BYTE PTR [r8+rax*1]
+   3c6:  cmp al,0x11
+   3c8:  je <+0xed2>
+   3ce:  ja <+0xcaf>
+   3d4:  mov ecx,0x4
+   3d9:  mov eax,0x5
+   3de:  jmp <+0x47>
+   3e3:  nop DWORD PTR [rax+rax*1+0x0]
+   3e8:  cmp rcx,rdi
+   3eb:  jae <+0x868>
+   3f1:  movzx eax,BYTE PTR [rsi+0x1]
+   3f5:  movzx eax,BYTE PTR [r8+rax*1]
+   3fa:  cmp al,0x11
+   3fc:  je <+0x9ed>
+   402:  ja <+0x7c4>
+   408:  mov ecx,0x2
+   40d:  mov eax,0x5
+   412:  jmp <+0x47>
+   417:  cmp rcx,rdi
+   41a:  jae <+0x8f4>
+   420:  movzx eax,BYTE PTR [rsi+0x1]
+   424:  movzx eax,BYTE PTR [r8+rax*1]
+   429:  cmp al,0x11
+   42b:  je <+0x9fc>
+   431:  ja <+0x7ed>
+   437:  mov ecx,0x2
+   43c:  mov eax,0x9
+   441:  jmp <+0x47>
+   446:  cmp rcx,rdi
+   449:  jae <+0x8d0>
+   44f:  movzx eax,BYTE PTR [rsi+0x1]
+   453:  cmp BYTE PTR [r8+rax*1],0x1
+   458:  jne <+0x467>
+   45a:  lea rax,[rsi+0x2]
+   45e:  cmp rax,rdi
+   461:  jb <+0xa0b>
+   467:  xor ecx,ecx
+   469:  mov eax,0x9
+   46e:  jmp <+0x47>
+   473:  cmp rcx,rdi
+   476:  jae <+0x994>
+   47c:  movzx eax,BYTE PTR [rcx]
+   47f:  lea rdx,[rcx+0x1]
+   483:  movzx eax,BYTE PTR [r8+rax*1]
+   488:  cmp al,0x5
+   48a:  je <+0xa67>
+   490:  ja <+0x82a>
+   496:  mov ecx,0x2
+   49b:  mov eax,0x2
+   4a0:  jmp <+0x47>
+   4a5:  cmp rcx,rdi
+   4a8:  jae <+0x9a3>
+   4ae:  movzx eax,BYTE PTR [rsi+0x1]
+   4b2:  lea rcx,[rsi+0x2]
+   4b6:  cmp BYTE PTR [r8+rax*1],0x1c
+   4bb:  ja <+0x473>
+   4bd:  movzx eax,BYTE PTR [r8+rax*1]
+   4c2:  lea rdx,[rip]
+   4c9:  movsxd rax,DWORD PTR [rdx+rax*4]
+   4cd:  add rax,rdx
+   4d0:  jmp rax
+   4d2:  mov ecx,0x2
+   4d7:  mov eax,0x8
+   4dc:  jmp <+0x47>
+   4e1:  cmp rcx,rdi
+   4e4:  jae <+0x9b2>
+   4ea:  movzx eax,BYTE PTR [rsi+0x1]
+   4ee:  lea rdx,[rsi+0x2]
+   4f2:  movzx eax,BYTE PTR [r8+rax*1]
+   4f7:  cmp al,0xb
+   4f9:  je <+0xad3>
+   4ff:  cmp al,0xd
+   501:  je <+0xb2c>
+   507:  cmp al,0x3
+   509:  je <+0xaff>
+   50f:  xor ecx,ecx
+   511:  mov eax,0x9
+   516:  jmp <+0x47>
+   51b:  cmp rsi,rdi
+   51e:  jae <+0x988>
+   524:  movzx eax,BYTE PTR [rdx+0x2]
+   528:  cmp BYTE PTR [r8+rax*1],0x5
+   52d:  jne <+0x53c>
+   52f:  lea rax,[rdx+0x3]
+   533:  cmp rax,rdi
+   536:  jb <+0xa8b>
+   53c:  xor ecx,ecx
+   53e:  mov eax,0x9
+   543:  jmp <+0x47>
+   548:  cmp rsi,rdi
+   54b:  jae <+0x92b>
+   551:  movzx eax,BYTE PTR [rdx+0x2]
+   555:  movzx eax,BYTE PTR [r8+rax*1]
+   55a:  cmp al,0x11
+   55c:  je <+0xa58>
+   562:  ja <+0x80c>
+   568:  mov ecx,0x2
+   56d:  mov eax,0x7
+   572:  jmp <+0x47>
+   577:  cmp rcx,rdi
+   57a:  jae <+0xd44>
+   580:  movzx eax,BYTE PTR [rsi+0x2]
+   584:  cmp BYTE PTR [r8+rax*1],0x12
+   589:  jne <+0x598>
+   58b:  lea rax,[rsi+0x3]
+   58f:  cmp rax,rdi
+   592:  jb <+0xe04>
+   598:  xor ecx,ecx
+   59a:  mov eax,0x9
+   59f:  jmp <+0x47>
+   5a4:  mov ecx,0x3
+   5a9:  mov eax,0x8
+   5ae:  jmp <+0x47>
+   5b3:  nop DWORD PTR [rax+rax*1+0x0]
+   5b8:  sub eax,0x2
+   5bb:  cmp al,0x1a
+   5bd:  ja <+0xb0>
+   5c3:  mov ecx,0x1
+   5c8:  mov eax,0x6
+   5cd:  jmp <+0x47>
+   5d2:  nop WORD PTR [rax+rax*1+0x0]
+   5d8:  sub eax,0x6
+   5db:  cmp al,0x16
+   5dd:  ja <+0x60>
+   5e3:  mov ecx,0x1
+   5e8:  mov eax,0x3
+   5ed:  jmp <+0x47>
+   5f2:  nop WORD PTR [rax+rax*1+0x0]
+   5f8:  xor ecx,ecx
+   5fa:  mov eax,0x9
+   5ff:  jmp <+0x47>
+   604:  nop DWORD PTR [rax+0x0]
+   608:  mov ecx,0x1
+   60d:  mov eax,0x5
+   612:  jmp <+0x47>
+   617:  nop WORD PTR [rax+rax*1+0x0]
+   620:  xor ecx,ecx
+   622:  mov eax,0x9
+   627:  jmp <+0x47>
+   62c:  nop DWORD PTR [rax+0x0]
+   630:  cmp rdx,rdi
+   633:  jae <+0x7b8>
+   639:  movzx eax,BYTE PTR [rdx]
+   63c:  cmp BYTE PTR [r8+rax*1],0xd
+   641:  jne <+0x650>
+   643:  lea rax,[rdx+0x1]
+   647:  cmp rax,rdi
+   64a:  jb <+0x93a>
+   650:  xor ecx,ecx
+   652:  mov eax,0x9
+   657:  jmp <+0x47>
+   65c:  lea eax,[rdx-0x6]
+   65f:  cmp al,0x17
+   661:  sbb rcx,rcx
+   664:  not rcx
+   667:  add rcx,0x2
+   66b:  cmp al,0x17
+   66d:  sbb eax,eax
+   66f:  and eax,0xfffffffa
+   672:  add eax,0x7
+   675:  jmp <+0x47>
+   67a:  nop WORD PTR [rax+rax*1+0x0]
+   680:  cmp rsi,rdi
+   683:  jae <+0x844>
+   689:  movzx esi,BYTE PTR [rdx+0x2]
+   68d:  xor ecx,ecx
+   68f:  mov eax,0x9
+   694:  cmp BYTE PTR [r8+rsi*1],0x17
+   699:  jne <+0x47>
+   69f:  lea rax,[rdx+0x3]
+   6a3:  cmp rax,rdi
+   6a6:  jae <+0xb8f>
+   6ac:  movzx eax,BYTE PTR [rdx+0x3]
+   6b0:  movzx eax,BYTE PTR [r8+rax*1]
+   6b5:  cmp al,0x11
+   6b7:  je <+0xc54>
+   6bd:  ja <+0x9be>
+   6c3:  mov ecx,0x3
+   6c8:  mov eax,0x6
+   6cd:  jmp <+0x47>
+   6d2:  nop WORD PTR [rax+rax*1+0x0]
+   6d8:  cmp rsi,rdi
+   6db:  jae <+0x850>
+   6e1:  movzx esi,BYTE PTR [rdx+0x2]
+   6e5:  xor ecx,ecx
+   6e7:  mov eax,0x9
+   6ec:  cmp BYTE PTR [r8+rsi*1],0x3
+   6f1:  jne <+0x47>
+   6f7:  lea rax,[rdx+0x3]
+   6fb:  cmp rax,rdi
+   6fe:  jae <+0xb80>
+   704:  movzx eax,BYTE PTR [rdx+0x3]
+   708:  cmp BYTE PTR [r8+rax*1],0x1c
+   70d:  ja <+0x101a>
+   713:  movzx eax,BYTE PTR [r8+rax*1]
+   718:  lea rcx,[rip]
+   71f:  movsxd rax,DWORD PTR [rcx+rax*4]
+   723:  add rax,rcx
+   726:  jmp rax
+   728:  mov ecx,0x3
+   72d:  mov eax,0x3
+   732:  jmp <+0x47>
+   737:  lea rax,[rdx+0x4]
+   73b:  cmp rax,rdi
+   73e:  jae <+0xf19>
+   744:  movzx eax,BYTE PTR [rdx+0x4]
+   748:  cmp BYTE PTR [r8+rax*1],0xd
+   74d:  jne <+0x75c>
+   74f:  lea rax,[rdx+0x5]
+   753:  cmp rax,rdi
+   756:  jb <+0x1029>
+   75c:  xor ecx,ecx
+   75e:  mov eax,0x9
+   763:  jmp <+0x47>
+   768:  mov ecx,0x4
+   76d:  mov eax,0x3
+   772:  jmp <+0x47>
+   777:  nop WORD PTR [rax+rax*1+0x0]
+   780:  sub eax,0x6
+   783:  cmp al,0x17
+   785:  sbb rcx,rcx
+   788:  not rcx
+   78b:  add rcx,0x2
+   78f:  cmp al,0x17
+   791:  sbb eax,eax
+   793:  not eax
+   795:  and eax,0x3
+   798:  jmp <+0x47>
+   79d:  xor ecx,ecx
+   79f:  mov eax,0x9
+   7a4:  jmp <+0x47>
+   7a9:  mov ecx,0x2
+   7ae:  mov eax,0x1
+   7b3:  jmp <+0x47>
+   7b8:  xor ecx,ecx
+   7ba:  mov eax,0x9
+   7bf:  jmp <+0x47>
+   7c4:  sub eax,0x12
+   7c7:  cmp al,0xb
+   7c9:  sbb rcx,rcx
+   7cc:  not rcx
+   7cf:  add rcx,0x2
+   7d3:  cmp al,0xb
+   7d5:  sbb eax,eax
+   7d7:  not eax
+   7d9:  add eax,0x5
+   7dc:  jmp <+0x47>
+   7e1:  mov ecx,0x2
+   7e6:  xor eax,eax
+   7e8:  jmp <+0x47>
+   7ed:  lea edx,[rax-0x12]
+   7f0:  mov ecx,0x2
+   7f5:  mov eax,0x9
+   7fa:  cmp dl,0xa
+   7fd:  jbe <+0x47>
+   803:  add rsi,0x2
+   807:  jmp <+0x132>
+   80c:  sub eax,0x12
+   80f:  cmp al,0xb
+   811:  sbb rcx,rcx
+   814:  not rcx
+   817:  add rcx,0x2
+   81b:  cmp al,0xb
+   81d:  sbb eax,eax
+   81f:  and eax,0xfffffffe
+   822:  add eax,0x9
+   825:  jmp <+0x47>
+   82a:  sub eax,0x6
+   82d:  cmp al,0x16
+   82f:  ja <+0x630>
+   835:  mov ecx,0x2
+   83a:  mov eax,0x2
+   83f:  jmp <+0x47>
+   844:  xor ecx,ecx
+   846:  mov eax,0x9
+   84b:  jmp <+0x47>
+   850:  xor ecx,ecx
+   852:  mov eax,0x9
+   857:  jmp <+0x47>
+   85c:  xor ecx,ecx
+   85e:  mov eax,0x9
+   863:  jmp <+0x47>
+   868:  mov ecx,0x2
+   86d:  mov eax,0x5
+   872:  jmp <+0x47>
+   877:  lea rdx,[rsi+0x2]
+   87b:  cmp rdx,rdi
+   87e:  jae <+0x47>
+   884:  movzx edx,BYTE PTR [rsi+0x2]
+   888:  cmp BYTE PTR [r8+rdx*1],0x3
+   88d:  jne <+0x47>
+   893:  lea rax,[rsi+0x3]
+   897:  cmp rax,rdi
+   89a:  jae <+0xd89>
+   8a0:  movzx eax,BYTE PTR [rsi+0x3]
+   8a4:  movzx eax,BYTE PTR [r8+rax*1]
+   8a9:  cmp al,0x11
+   8ab:  je <+0xec3>
+   8b1:  ja <+0xc63>
+   8b7:  mov ecx,0x4
+   8bc:  mov eax,0x1
+   8c1:  jmp <+0x47>
+   8c6:  cs nop WORD PTR [rax+rax*1+0x0]
+   8d0:  xor ecx,ecx
+   8d2:  mov eax,0x9
+   8d7:  jmp <+0x47>
+   8dc:  sub edx,0x6
+   8df:  mov ecx,0x3
+   8e4:  cmp dl,0x17
+   8e7:  sbb eax,eax
+   8e9:  and eax,0xfffffffb
+   8ec:  add eax,0x6
+   8ef:  jmp <+0x47>
+   8f4:  mov ecx,0x2
+   8f9:  mov eax,0x9
+   8fe:  jmp <+0x47>
+   903:  lea rax,[rsi+0x2]
+   907:  cmp rax,rdi
+   90a:  jae <+0xb68>
+   910:  movzx eax,BYTE PTR [rsi+0x2]
+   914:  cmp BYTE PTR [r8+rax*1],0x3
+   919:  je <+0xbed>
+   91f:  xor ecx,ecx
+   921:  mov eax,0x9
+   926:  jmp <+0x47>
+   92b:  mov ecx,0x2
+   930:  mov eax,0x7
+   935:  jmp <+0x47>
+   93a:  movzx esi,BYTE PTR [rdx+0x1]
+   93e:  xor ecx,ecx
+   940:  add rdx,0x2
+   944:  mov eax,0x9
+   949:  cmp BYTE PTR [r8+rsi*1],0xe
+   94e:  jne <+0x47>
+   954:  cmp rdx,rdi
+   957:  jae <+0xca3>
+   95d:  movzx eax,BYTE PTR [rdx]
+   960:  lea rsi,[rdx+0x1]
+   964:  movzx eax,BYTE PTR [r8+rax*1]
+   969:  cmp al,0x11
+   96b:  je <+0xddc>
+   971:  ja <+0xc18>
+   977:  mov ecx,0x4
+   97c:  xor eax,eax
+   97e:  jmp <+0x47>
+   983:  nop DWORD PTR [rax+rax*1+0x0]
+   988:  xor ecx,ecx
+   98a:  mov eax,0x9
+   98f:  jmp <+0x47>
+   994:  mov ecx,0x2
+   999:  mov eax,0x2
+   99e:  jmp <+0x47>
+   9a3:  mov ecx,0x2
+   9a8:  mov eax,0x8
+   9ad:  jmp <+0x47>
+   9b2:  xor ecx,ecx
+   9b4:  mov eax,0x9
+   9b9:  jmp <+0x47>
+   9be:  lea esi,[rax-0x12]
+   9c1:  mov ecx,0x3
+   9c6:  mov eax,0x6
+   9cb:  cmp sil,0xa
+   9cf:  jbe <+0x47>
+   9d5:  lea rsi,[rdx+0x4]
+   9d9:  jmp <+0x392>
+   9de:  mov ecx,0x3
+   9e3:  mov eax,0x1
+   9e8:  jmp <+0x47>
+   9ed:  mov ecx,0x3
+   9f2:  mov eax,0x5
+   9f7:  jmp <+0x47>
+   9fc:  mov ecx,0x3
+   a01:  mov eax,0x9
+   a06:  jmp <+0x47>
+   a0b:  movzx edx,BYTE PTR [rsi+0x2]
+   a0f:  xor ecx,ecx
+   a11:  add rsi,0x3
+   a15:  mov eax,0x9
+   a1a:  cmp BYTE PTR [r8+rdx*1],0x10
+   a1f:  jne <+0x47>
+   a25:  cmp rsi,rdi
+   a28:  jae <+0xd6b>
+   a2e:  movzx eax,BYTE PTR [rsi]
+   a31:  movzx eax,BYTE PTR [r8+rax*1]
+   a36:  cmp al,0x11
+   a38:  je <+0xe1f>
+   a3e:  ja <+0xcd8>
+   a44:  mov ecx,0x4
+   a49:  mov eax,0x9
+   a4e:  jmp <+0x47>
+   a53:  nop DWORD PTR [rax+rax*1+0x0]
+   a58:  mov ecx,0x3
+   a5d:  mov eax,0x7
+   a62:  jmp <+0x47>
+   a67:  cmp rdx,rdi
+   a6a:  jae <+0xc7d>
+   a70:  movzx eax,BYTE PTR [rcx+0x1]
+   a74:  cmp BYTE PTR [r8+rax*1],0x3
+   a79:  je <+0xd05>
+   a7f:  xor ecx,ecx
+   a81:  mov eax,0x9
+   a86:  jmp <+0x47>
+   a8b:  movzx esi,BYTE PTR [rdx+0x3]
+   a8f:  xor ecx,ecx
+   a91:  mov eax,0x9
+   a96:  cmp BYTE PTR [r8+rsi*1],0xa
+   a9b:  jne <+0x47>
+   aa1:  lea rsi,[rdx+0x4]
+   aa5:  cmp rsi,rdi
+   aa8:  jae <+0xd7a>
+   aae:  movzx eax,BYTE PTR [rsi]
+   ab1:  movzx eax,BYTE PTR [r8+rax*1]
+   ab6:  cmp al,0x11
+   ab8:  je <+0xeb4>
+   abe:  ja <+0xc89>
+   ac4:  mov ecx,0x4
+   ac9:  mov eax,0x7
+   ace:  jmp <+0x47>
+   ad3:  cmp rdx,rdi
+   ad6:  jae <+0xc48>
+   adc:  movzx eax,BYTE PTR [rdx]
+   adf:  cmp BYTE PTR [r8+rax*1],0xb
+   ae4:  jne <+0xaf3>
+   ae6:  lea rax,[rdx+0x1]
+   aea:  cmp rax,rdi
+   aed:  jb <+0xd98>
+   af3:  xor ecx,ecx
+   af5:  mov eax,0x9
+   afa:  jmp <+0x47>
+   aff:  cmp rdx,rdi
+   b02:  jae <+0xccc>
+   b08:  movzx eax,BYTE PTR [rsi+0x2]
+   b0c:  cmp BYTE PTR [r8+rax*1],0x10
+   b11:  jne <+0xb20>
+   b13:  lea rax,[rsi+0x3]
+   b17:  cmp rax,rdi
+   b1a:  jb <+0xd50>
+   b20:  xor ecx,ecx
+   b22:  mov eax,0x9
+   b27:  jmp <+0x47>
+   b2c:  cmp rdx,rdi
+   b2f:  jae <+0xcf6>
+   b35:  movzx eax,BYTE PTR [rsi+0x2]
+   b39:  lea rdx,[rsi+0x3]
+   b3d:  cmp BYTE PTR [r8+rax*1],0x1c
+   b42:  ja <+0xad3>
+   b44:  movzx eax,BYTE PTR [r8+rax*1]
+   b49:  lea rcx,[rip]
+   b50:  movsxd rax,DWORD PTR [rcx+rax*4]
+   b54:  add rax,rcx
+   b57:  jmp rax
+   b59:  mov ecx,0x3
+   b5e:  mov eax,0x4
+   b63:  jmp <+0x47>
+   b68:  xor ecx,ecx
+   b6a:  mov eax,0x9
+   b6f:  jmp <+0x47>
+   b74:  xor ecx,ecx
+   b76:  mov eax,0x9
+   b7b:  jmp <+0x47>
+   b80:  mov ecx,0x3
+   b85:  mov eax,0x3
+   b8a:  jmp <+0x47>
+   b8f:  mov ecx,0x3
+   b94:  mov eax,0x6
+   b99:  jmp <+0x47>
+   b9e:  lea rdx,[rsi+0x2]
+   ba2:  cmp rdx,rdi
+   ba5:  jae <+0x47>
+   bab:  movzx edx,BYTE PTR [rsi+0x2]
+   baf:  cmp BYTE PTR [r8+rdx*1],0x3
+   bb4:  jne <+0x47>
+   bba:  lea rax,[rsi+0x3]
+   bbe:  cmp rax,rdi
+   bc1:  jae <+0xf4e>
+   bc7:  movzx eax,BYTE PTR [rsi+0x3]
+   bcb:  movzx eax,BYTE PTR [r8+rax*1]
+   bd0:  cmp al,0x11
+   bd2:  je <+0xfc0>
+   bd8:  ja <+0xf5d>
+   bde:  mov ecx,0x5
+   be3:  mov eax,0x1
+   be8:  jmp <+0x47>
+   bed:  add rsi,0x3
+   bf1:  cmp rsi,rdi
+   bf4:  jae <+0xd38>
+   bfa:  movzx eax,BYTE PTR [rsi]
+   bfd:  movzx eax,BYTE PTR [r8+rax*1]
+   c02:  cmp al,0x11
+   c04:  je <+0xe99>
+   c0a:  ja <+0xc2b>
+   c0c:  mov ecx,0x4
+   c11:  xor eax,eax
+   c13:  jmp <+0x47>
+   c18:  sub eax,0x12
+   c1b:  cmp al,0xa
+   c1d:  ja <+0xbf1>
+   c1f:  mov ecx,0x4
+   c24:  xor eax,eax
+   c26:  jmp <+0x47>
+   c2b:  sub eax,0x12
+   c2e:  cmp al,0xb
+   c30:  sbb rcx,rcx
+   c33:  not rcx
+   c36:  add rcx,0x4
+   c3a:  cmp al,0xb
+   c3c:  sbb eax,eax
+   c3e:  not eax
+   c40:  and eax,0x3
+   c43:  jmp <+0x47>
+   c48:  xor ecx,ecx
+   c4a:  mov eax,0x9
+   c4f:  jmp <+0x47>
+   c54:  mov ecx,0x4
+   c59:  mov eax,0x6
+   c5e:  jmp <+0x47>
+   c63:  sub eax,0x12
+   c66:  cmp al,0xa
+   c68:  ja <+0xe65>
+   c6e:  mov ecx,0x4
+   c73:  mov eax,0x1
+   c78:  jmp <+0x47>
+   c7d:  xor ecx,ecx
+   c7f:  mov eax,0x9
+   c84:  jmp <+0x47>
+   c89:  sub eax,0x12
+   c8c:  cmp al,0xa
+   c8e:  ja <+0xe6e>
+   c94:  mov ecx,0x4
+   c99:  mov eax,0x7
+   c9e:  jmp <+0x47>
+   ca3:  mov ecx,0x4
+   ca8:  xor eax,eax
+   caa:  jmp <+0x47>
+   caf:  sub eax,0x12
+   cb2:  cmp al,0xb
+   cb4:  sbb rcx,rcx
+   cb7:  not rcx
+   cba:  add rcx,0x4
+   cbe:  cmp al,0xb
+   cc0:  sbb eax,eax
+   cc2:  not eax
+   cc4:  add eax,0x5
+   cc7:  jmp <+0x47>
+   ccc:  xor ecx,ecx
+   cce:  mov eax,0x9
+   cd3:  jmp <+0x47>
+   cd8:  sub eax,0x12
+   cdb:  cmp al,0xb
+   cdd:  sbb rcx,rcx
+   ce0:  not rcx
+   ce3:  add rcx,0x4
+   ce7:  cmp al,0xb
+   ce9:  sbb eax,eax
+   ceb:  and eax,0x8
+   cee:  add eax,0x1
+   cf1:  jmp <+0x47>
+   cf6:  mov ecx,0x3
+   cfb:  mov eax,0x4
+   d00:  jmp <+0x47>
+   d05:  lea rax,[rcx+0x2]
+   d09:  cmp rax,rdi
+   d0c:  jae <+0xea5>
+   d12:  movzx eax,BYTE PTR [rcx+0x2]
+   d16:  movzx eax,BYTE PTR [r8+rax*1]
+   d1b:  cmp al,0x11
+   d1d:  je <+0x100b>
+   d23:  ja <+0xdc2>
+   d29:  mov ecx,0x4
+   d2e:  mov eax,0x2
+   d33:  jmp <+0x47>
+   d38:  mov ecx,0x4
+   d3d:  xor eax,eax
+   d3f:  jmp <+0x47>
+   d44:  xor ecx,ecx
+   d46:  mov eax,0x9
+   d4b:  jmp <+0x47>
+   d50:  movzx eax,BYTE PTR [rsi+0x3]
+   d54:  cmp BYTE PTR [r8+rax*1],0xe
+   d59:  je <+0xe2e>
+   d5f:  xor ecx,ecx
+   d61:  mov eax,0x9
+   d66:  jmp <+0x47>
+   d6b:  mov ecx,0x4
+   d70:  mov eax,0x9
+   d75:  jmp <+0x47>
+   d7a:  mov ecx,0x4
+   d7f:  mov eax,0x7
+   d84:  jmp <+0x47>
+   d89:  mov ecx,0x4
+   d8e:  mov eax,0x1
+   d93:  jmp <+0x47>
+   d98:  movzx eax,BYTE PTR [rdx+0x1]
+   d9c:  cmp BYTE PTR [r8+rax*1],0x8
+   da1:  je <+0xee1>
+   da7:  xor ecx,ecx
+   da9:  mov eax,0x9
+   dae:  jmp <+0x47>
+   db3:  mov ecx,0x4
+   db8:  mov eax,0x5
+   dbd:  jmp <+0x47>
+   dc2:  sub eax,0x12
+   dc5:  cmp al,0xa
+   dc7:  ja <+0x1279>
+   dcd:  mov ecx,0x4
+   dd2:  mov eax,0x2
+   dd7:  jmp <+0x47>
+   ddc:  cmp rsi,rdi
+   ddf:  jae <+0xe8d>
+   de5:  movzx eax,BYTE PTR [rdx+0x1]
+   de9:  movzx eax,BYTE PTR [r8+rax*1]
+   dee:  cmp al,0x5
+   df0:  je <+0x11b4>
+   df6:  ja <+0xe77>
+   df8:  mov ecx,0x5
+   dfd:  xor eax,eax
+   dff:  jmp <+0x47>
+   e04:  movzx eax,BYTE PTR [rsi+0x3]
+   e08:  cmp BYTE PTR [r8+rax*1],0x7
+   e0d:  je <+0x1253>
+   e13:  xor ecx,ecx
+   e15:  mov eax,0x9
+   e1a:  jmp <+0x47>
+   e1f:  mov ecx,0x5
+   e24:  mov eax,0x9
+   e29:  jmp <+0x47>
+   e2e:  lea rax,[rsi+0x4]
+   e32:  cmp rax,rdi
+   e35:  jae <+0x1337>
+   e3b:  movzx edx,BYTE PTR [rax]
+   e3e:  lea rsi,[rax+0x1]
+   e42:  movzx edx,BYTE PTR [r8+rdx*1]
+   e47:  cmp dl,0x11
+   e4a:  je <+0x12ec>
+   e50:  ja <+0x131c>
+   e56:  mov ecx,0x5
+   e5b:  mov eax,0x1
+   e60:  jmp <+0x47>
+   e65:  add rsi,0x4
+   e69:  jmp <+0xaa5>
+   e6e:  add rsi,0x1
+   e72:  jmp <+0xa25>
+   e77:  sub eax,0x6
+   e7a:  cmp al,0x17
+   e7c:  sbb rcx,rcx
+   e7f:  xor eax,eax
+   e81:  not rcx
+   e84:  add rcx,0x5
+   e88:  jmp <+0x47>
+   e8d:  mov ecx,0x5
+   e92:  xor eax,eax
+   e94:  jmp <+0x47>
+   e99:  mov ecx,0x5
+   e9e:  xor eax,eax
+   ea0:  jmp <+0x47>
+   ea5:  mov ecx,0x4
+   eaa:  mov eax,0x2
+   eaf:  jmp <+0x47>
+   eb4:  mov ecx,0x5
+   eb9:  mov eax,0x7
+   ebe:  jmp <+0x47>
+   ec3:  mov ecx,0x5
+   ec8:  mov eax,0x1
+   ecd:  jmp <+0x47>
+   ed2:  mov ecx,0x5
+   ed7:  mov eax,0x5
+   edc:  jmp <+0x47>
+   ee1:  lea rax,[rdx+0x2]
+   ee5:  cmp rax,rdi
+   ee8:  jae <+0x10a9>
+   eee:  movzx ecx,BYTE PTR [rdx+0x2]
+   ef2:  lea rax,[rdx+0x3]
+   ef6:  movzx ecx,BYTE PTR [r8+rcx*1]
+   efb:  cmp cl,0x11
+   efe:  je <+0x1082>
+   f04:  ja <+0x1040>
+   f0a:  mov ecx,0x5
+   f0f:  mov eax,0x2
+   f14:  jmp <+0x47>
+   f19:  xor ecx,ecx
+   f1b:  mov eax,0x9
+   f20:  jmp <+0x47>
+   f25:  cmp rdx,rdi
+   f28:  jae <+0xfb4>
+   f2e:  movzx eax,BYTE PTR [rsi+0x3]
+   f32:  cmp BYTE PTR [r8+rax*1],0x12
+   f37:  jne <+0xf42>
+   f39:  lea rax,[rsi+0x4]
+   f3d:  cmp rax,rdi
+   f40:  jb <+0xf73>
+   f42:  xor ecx,ecx
+   f44:  mov eax,0x9
+   f49:  jmp <+0x47>
+   f4e:  mov ecx,0x5
+   f53:  mov eax,0x1
+   f58:  jmp <+0x47>
+   f5d:  sub eax,0x12
+   f60:  cmp al,0xa
+   f62:  ja <+0xf8e>
+   f64:  mov ecx,0x5
+   f69:  mov eax,0x1
+   f6e:  jmp <+0x47>
+   f73:  movzx eax,BYTE PTR [rsi+0x4]
+   f77:  add rsi,0x5
+   f7b:  cmp BYTE PTR [r8+rax*1],0x5
+   f80:  je <+0xf92>
+   f82:  xor ecx,ecx
+   f84:  mov eax,0x9
+   f89:  jmp <+0x47>
+   f8e:  add rsi,0x4
+   f92:  cmp rsi,rdi
+   f95:  jae <+0xffc>
+   f97:  movzx eax,BYTE PTR [rsi]
+   f9a:  movzx eax,BYTE PTR [r8+rax*1]
+   f9f:  cmp al,0x11
+   fa1:  je <+0xfed>
+   fa3:  ja <+0xfcf>
+   fa5:  mov ecx,0x6
+   faa:  mov eax,0x4
+   faf:  jmp <+0x47>
+   fb4:  xor ecx,ecx
+   fb6:  mov eax,0x9
+   fbb:  jmp <+0x47>
+   fc0:  mov ecx,0x6
+   fc5:  mov eax,0x1
+   fca:  jmp <+0x47>
+   fcf:  sub eax,0x12
+   fd2:  cmp al,0xb
+   fd4:  sbb rcx,rcx
+   fd7:  not rcx
+   fda:  add rcx,0x6
+   fde:  cmp al,0xb
+   fe0:  sbb eax,eax
+   fe2:  and eax,0x2
+   fe5:  add eax,0x2
+   fe8:  jmp <+0x47>
+   fed:  mov ecx,0x7
+   ff2:  mov eax,0x4
+   ff7:  jmp <+0x47>
+   ffc:  mov ecx,0x6
+  1001:  mov eax,0x4
+  1006:  jmp <+0x47>
+  100b:  mov ecx,0x5
+  1010:  mov eax,0x2
+  1015:  jmp <+0x47>
+  101a:  mov ecx,0x2
+  101f:  mov eax,0x1
+  1024:  jmp <+0x47>
+  1029:  movzx eax,BYTE PTR [rdx+0x5]
+  102d:  cmp BYTE PTR [r8+rax*1],0x4
+  1032:  je <+0x105b>
+  1034:  xor ecx,ecx
+  1036:  mov eax,0x9
+  103b:  jmp <+0x47>
+  1040:  sub ecx,0x12
+  1043:  cmp cl,0xa
+  1046:  ja <+0xe32>
+  104c:  mov ecx,0x5
+  1051:  mov eax,0x2
+  1056:  jmp <+0x47>
+  105b:  lea rax,[rdx+0x6]
+  105f:  cmp rax,rdi
+  1062:  jae <+0x10c7>
+  1064:  movzx eax,BYTE PTR [rdx+0x6]
+  1068:  movzx eax,BYTE PTR [r8+rax*1]
+  106d:  cmp al,0x11
+  106f:  je <+0x10b8>
+  1071:  ja <+0x10d6>
+  1073:  mov ecx,0x6
+  1078:  mov eax,0x3
+  107d:  jmp <+0x47>
+  1082:  cmp rax,rdi
+  1085:  jae <+0x1146>
+  108b:  movzx eax,BYTE PTR [rdx+0x3]
+  108f:  movzx eax,BYTE PTR [r8+rax*1]
+  1094:  cmp al,0x5
+  1096:  je <+0x1111>
+  1098:  ja <+0x10f4>
+  109a:  mov ecx,0x6
+  109f:  mov eax,0x2
+  10a4:  jmp <+0x47>
+  10a9:  mov ecx,0x5
+  10ae:  mov eax,0x2
+  10b3:  jmp <+0x47>
+  10b8:  mov ecx,0x7
+  10bd:  mov eax,0x3
+  10c2:  jmp <+0x47>
+  10c7:  mov ecx,0x6
+  10cc:  mov eax,0x3
+  10d1:  jmp <+0x47>
+  10d6:  sub eax,0x12
+  10d9:  cmp al,0xb
+  10db:  sbb rcx,rcx
+  10de:  not rcx
+  10e1:  add rcx,0x6
+  10e5:  cmp al,0xb
+  10e7:  sbb eax,eax
+  10e9:  and eax,0x2
+  10ec:  add eax,0x1
+  10ef:  jmp <+0x47>
+  10f4:  sub eax,0x6
+  10f7:  cmp al,0x17
+  10f9:  sbb rcx,rcx
+  10fc:  not rcx
+  10ff:  add rcx,0x6
+  1103:  cmp al,0x17
+  1105:  sbb eax,eax
+  1107:  not eax
+  1109:  add eax,0x2
+  110c:  jmp <+0x47>
+  1111:  lea rax,[rdx+0x4]
+  1115:  cmp rax,rdi
+  1118:  jae <+0x113a>
+  111a:  movzx eax,BYTE PTR [rdx+0x4]
+  111e:  cmp BYTE PTR [r8+rax*1],0x3
+  1123:  jne <+0x112e>
+  1125:  lea rax,[rdx+0x5]
+  1129:  cmp rax,rdi
+  112c:  jb <+0x1155>
+  112e:  xor ecx,ecx
+  1130:  mov eax,0x9
+  1135:  jmp <+0x47>
+  113a:  xor ecx,ecx
+  113c:  mov eax,0x9
+  1141:  jmp <+0x47>
+  1146:  mov ecx,0x6
+  114b:  mov eax,0x2
+  1150:  jmp <+0x47>
+  1155:  movzx eax,BYTE PTR [rdx+0x5]
+  1159:  cmp BYTE PTR [r8+rax*1],0xe
+  115e:  jne <+0x1169>
+  1160:  lea rax,[rdx+0x6]
+  1164:  cmp rax,rdi
+  1167:  jb <+0x1175>
+  1169:  xor ecx,ecx
+  116b:  mov eax,0x9
+  1170:  jmp <+0x47>
+  1175:  movzx eax,BYTE PTR [rdx+0x6]
+  1179:  cmp BYTE PTR [r8+rax*1],0xd
+  117e:  jne <+0x1189>
+  1180:  lea rax,[rdx+0x7]
+  1184:  cmp rax,rdi
+  1187:  jb <+0x1195>
+  1189:  xor ecx,ecx
+  118b:  mov eax,0x9
+  1190:  jmp <+0x47>
+  1195:  movzx eax,BYTE PTR [rdx+0x7]
+  1199:  add rdx,0x8
+  119d:  cmp BYTE PTR [r8+rax*1],0x4
+  11a2:  je <+0x12c2>
+  11a8:  xor ecx,ecx
+  11aa:  mov eax,0x9
+  11af:  jmp <+0x47>
+  11b4:  lea rax,[rdx+0x2]
+  11b8:  cmp rax,rdi
+  11bb:  jae <+0x11dd>
+  11bd:  movzx eax,BYTE PTR [rdx+0x2]
+  11c1:  cmp BYTE PTR [r8+rax*1],0x3
+  11c6:  jne <+0x11d1>
+  11c8:  lea rax,[rdx+0x3]
+  11cc:  cmp rax,rdi
+  11cf:  jb <+0x11e9>
+  11d1:  xor ecx,ecx
+  11d3:  mov eax,0x9
+  11d8:  jmp <+0x47>
+  11dd:  xor ecx,ecx
+  11df:  mov eax,0x9
+  11e4:  jmp <+0x47>
+  11e9:  movzx eax,BYTE PTR [rdx+0x3]
+  11ed:  cmp BYTE PTR [r8+rax*1],0xe
+  11f2:  jne <+0x11fd>
+  11f4:  lea rax,[rdx+0x4]
+  11f8:  cmp rax,rdi
+  11fb:  jb <+0x1209>
+  11fd:  xor ecx,ecx
+  11ff:  mov eax,0x9
+  1204:  jmp <+0x47>
+  1209:  movzx eax,BYTE PTR [rdx+0x4]
+  120d:  cmp BYTE PTR [r8+rax*1],0xd
+  1212:  jne <+0x121d>
+  1214:  lea rax,[rdx+0x5]
+  1218:  cmp rax,rdi
+  121b:  jb <+0x1229>
+  121d:  xor ecx,ecx
+  121f:  mov eax,0x9
+  1224:  jmp <+0x47>
+  1229:  movzx eax,BYTE PTR [rdx+0x5]
+  122d:  cmp BYTE PTR [r8+rax*1],0x4
+  1232:  je <+0x136f>
+  1238:  xor ecx,ecx
+  123a:  mov eax,0x9
+  123f:  jmp <+0x47>
+  1244:  mov ecx,0x4
+  1249:  mov eax,0x4
+  124e:  jmp <+0x47>
+  1253:  add rsi,0x4
+  1257:  cmp rsi,rdi
+  125a:  jae <+0x12af>
+  125c:  movzx eax,BYTE PTR [rsi]
+  125f:  movzx eax,BYTE PTR [r8+rax*1]
+  1264:  cmp al,0x11
+  1266:  je <+0x12a0>
+  1268:  ja <+0x1282>
+  126a:  mov ecx,0x5
+  126f:  mov eax,0x8
+  1274:  jmp <+0x47>
+  1279:  lea rdx,[rcx+0x3]
+  127d:  jmp <+0x954>
+  1282:  sub eax,0x12
+  1285:  cmp al,0xb
+  1287:  sbb rcx,rcx
+  128a:  not rcx
+  128d:  add rcx,0x5
+  1291:  cmp al,0xb
+  1293:  sbb eax,eax
+  1295:  and eax,0x6
+  1298:  add eax,0x2
+  129b:  jmp <+0x47>
+  12a0:  mov ecx,0x6
+  12a5:  mov eax,0x8
+  12aa:  jmp <+0x47>
+  12af:  mov ecx,0x5
+  12b4:  mov eax,0x8
+  12b9:  jmp <+0x47>
+  12be:  add rdx,0x7
+  12c2:  cmp rdx,rdi
+  12c5:  jae <+0x1458>
+  12cb:  movzx eax,BYTE PTR [rdx]
+  12ce:  movzx eax,BYTE PTR [r8+rax*1]
+  12d3:  cmp al,0x11
+  12d5:  je <+0x1360>
+  12db:  ja <+0x1346>
+  12dd:  mov ecx,0xb
+  12e2:  mov eax,0x2
+  12e7:  jmp <+0x47>
+  12ec:  cmp rsi,rdi
+  12ef:  jae <+0x13f2>
+  12f5:  movzx edx,BYTE PTR [rax+0x1]
+  12f9:  movzx edx,BYTE PTR [r8+rdx*1]
+  12fe:  cmp dl,0x5
+  1301:  je <+0x13bd>
+  1307:  ja <+0x139f>
+  130d:  mov ecx,0x6
+  1312:  mov eax,0x1
+  1317:  jmp <+0x47>
+  131c:  sub edx,0x12
+  131f:  cmp dl,0xa
+  1322:  ja <+0x1257>
+  1328:  mov ecx,0x5
+  132d:  mov eax,0x1
+  1332:  jmp <+0x47>
+  1337:  mov ecx,0x5
+  133c:  mov eax,0x1
+  1341:  jmp <+0x47>
+  1346:  sub eax,0x12
+  1349:  cmp al,0xa
+  134b:  ja <+0x1496>
+  1351:  mov ecx,0xb
+  1356:  mov eax,0x2
+  135b:  jmp <+0x47>
+  1360:  mov ecx,0xc
+  1365:  mov eax,0x2
+  136a:  jmp <+0x47>
+  136f:  lea rax,[rdx+0x6]
+  1373:  cmp rax,rdi
+  1376:  jae <+0x148a>
+  137c:  movzx eax,BYTE PTR [rdx+0x6]
+  1380:  movzx eax,BYTE PTR [r8+rax*1]
+  1385:  cmp al,0x11
+  1387:  je <+0x147e>
+  138d:  ja <+0x1467>
+  1393:  mov ecx,0xa
+  1398:  xor eax,eax
+  139a:  jmp <+0x47>
+  139f:  lea eax,[rdx-0x6]
+  13a2:  cmp al,0x17
+  13a4:  sbb rcx,rcx
+  13a7:  not rcx
+  13aa:  add rcx,0x6
+  13ae:  cmp al,0x17
+  13b0:  sbb eax,eax
+  13b2:  and eax,0xfffffff9
+  13b5:  add eax,0x8
+  13b8:  jmp <+0x47>
+  13bd:  lea rdx,[rax+0x2]
+  13c1:  cmp rdx,rdi
+  13c4:  jae <+0x13e6>
+  13c6:  movzx edx,BYTE PTR [rax+0x2]
+  13ca:  cmp BYTE PTR [r8+rdx*1],0x3
+  13cf:  jne <+0x13da>
+  13d1:  lea rdx,[rax+0x3]
+  13d5:  cmp rdx,rdi
+  13d8:  jb <+0x1401>
+  13da:  xor ecx,ecx
+  13dc:  mov eax,0x9
+  13e1:  jmp <+0x47>
+  13e6:  xor ecx,ecx
+  13e8:  mov eax,0x9
+  13ed:  jmp <+0x47>
+  13f2:  mov ecx,0x6
+  13f7:  mov eax,0x1
+  13fc:  jmp <+0x47>
+  1401:  movzx edx,BYTE PTR [rax+0x3]
+  1405:  cmp BYTE PTR [r8+rdx*1],0xe
+  140a:  jne <+0x1415>
+  140c:  lea rdx,[rax+0x4]
+  1410:  cmp rdx,rdi
+  1413:  jb <+0x1421>
+  1415:  xor ecx,ecx
+  1417:  mov eax,0x9
+  141c:  jmp <+0x47>
+  1421:  movzx edx,BYTE PTR [rax+0x4]
+  1425:  cmp BYTE PTR [r8+rdx*1],0xd
+  142a:  jne <+0x1435>
+  142c:  lea rdx,[rax+0x5]
+  1430:  cmp rdx,rdi
+  1433:  jb <+0x1441>
+  1435:  xor ecx,ecx
+  1437:  mov eax,0x9
+  143c:  jmp <+0x47>
+  1441:  movzx edx,BYTE PTR [rax+0x5]
+  1445:  cmp BYTE PTR [r8+rdx*1],0x4
+  144a:  je <+0x14bc>
+  144c:  xor ecx,ecx
+  144e:  mov eax,0x9
+  1453:  jmp <+0x47>
+  1458:  mov ecx,0xb
+  145d:  mov eax,0x2
+  1462:  jmp <+0x47>
+  1467:  sub eax,0x12
+  146a:  cmp al,0xa
+  146c:  ja <+0x12be>
+  1472:  mov ecx,0xa
+  1477:  xor eax,eax
+  1479:  jmp <+0x47>
+  147e:  mov ecx,0xb
+  1483:  xor eax,eax
+  1485:  jmp <+0x47>
+  148a:  mov ecx,0xa
+  148f:  xor eax,eax
+  1491:  jmp <+0x47>
+  1496:  add rdx,0x1
+  149a:  cmp rdx,rdi
+  149d:  jae <+0x14ed>
+  149f:  movzx eax,BYTE PTR [rdx]
+  14a2:  movzx eax,BYTE PTR [r8+rax*1]
+  14a7:  cmp al,0x11
+  14a9:  je <+0x14de>
+  14ab:  ja <+0x14c2>
+  14ad:  mov ecx,0xb
+  14b2:  mov eax,0x1
+  14b7:  jmp <+0x47>
+  14bc:  lea rdx,[rax+0x6]
+  14c0:  jmp <+0x149a>
+  14c2:  sub eax,0x12
+  14c5:  cmp al,0xb
+  14c7:  sbb rcx,rcx
+  14ca:  not rcx
+  14cd:  add rcx,0xb
+  14d1:  cmp al,0xb
+  14d3:  setb al
+  14d6:  movzx eax,al
+  14d9:  jmp <+0x47>
+  14de:  mov ecx,0xc
+  14e3:  mov eax,0x1
+  14e8:  jmp <+0x47>
+  14ed:  mov ecx,0xb
+  14f2:  mov eax,0x1
+  14f7:  jmp <+0x47>
@@ -0,0 +1,37 @@
+duration_unit_lookup::by_gencdfa2::lookup_ignore_ascii_case:
+     0:  push r15
+     2:  push r14
+     4:  push rbx
+     5:  mov r14,rdx
+     8:  mov r15,rsi
+     b:  mov rbx,rdi
+     e:  add rsi,rdx
+    11:  mov rdi,r15
+    14:  call QWORD PTR [rip] <gencdfa2_ci_find>
+    1a:  test rdx,rdx
+    1d:  je <+0x43>
+    1f:  mov rcx,r14
+    22:  sub rcx,rdx
+    25:  jb <+0x5a>
+    27:  add r15,rdx
+    2a:  mov DWORD PTR [rbx+0x8],eax
+    2d:  mov QWORD PTR [rbx+0x10],r15
+    31:  mov QWORD PTR [rbx+0x18],rcx
+    35:  xor eax,eax
+    37:  mov QWORD PTR [rbx],rax
+    3a:  mov rax,rbx
+    3d:  pop rbx
+    3e:  pop r14
+    40:  pop r15
+    42:  ret
+    43:  lea rdi,[rbx+0x8]
+    47:  mov rsi,r15
+    4a:  mov rdx,r14
+    4d:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+    53:  mov eax,0x1
+    58:  jmp <+0x37>
+    5a:  lea rcx,[rip]
+    61:  mov rdi,rdx
+    64:  mov rsi,r14
+    67:  mov rdx,r14
+    6a:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
@@ -0,0 +1,684 @@
+duration_unit_lookup::by_gendfa1::lookup:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0x86a>
+     a:  mov r8b,0x1
+     d:  xor eax,eax
+     f:  lea rcx,[rip]
+    16:  jmp <+0x50>
+    18:  xor r8d,r8d
+    1b:  cmp r9d,0x73
+    1f:  mov r10d,0x15
+    25:  cmovne r10d,r8d
+    29:  cmp r9d,0x61
+    2d:  mov r8d,0x14
+    33:  data16 data16 data16 cs nop WORD PTR [rax+rax*1+0x0]
+    40:  cmovne r8d,r10d
+    44:  inc rax
+    47:  cmp rdx,rax
+    4a:  je <+0x82f>
+    50:  movzx r9d,BYTE PTR [rsi+rax*1]
+    55:  movzx r8d,r8b
+    59:  movsxd r8,DWORD PTR [rcx+r8*4]
+    5d:  add r8,rcx
+    60:  jmp r8
+    63:  xor r8d,r8d
+    66:  lea r10d,[r9-0x64]
+    6a:  cmp r10d,0x15
+    6e:  ja <+0x7d2>
+    74:  lea r9,[rip]
+    7b:  movsxd r10,DWORD PTR [r9+r10*4]
+    7f:  add r10,r9
+    82:  jmp r10
+    85:  mov r8b,0x2
+    88:  jmp <+0x44>
+    8a:  cmp r9d,0x65
+    8e:  sete r8b
+    92:  or r8b,0x16
+    96:  jmp <+0x44>
+    98:  mov r8b,0x3c
+    9b:  jmp <+0x44>
+    9d:  xor r10d,r10d
+    a0:  cmp r9d,0x61
+    a4:  mov r8d,0x35
+    aa:  jmp <+0x40>
+    ac:  xor r10d,r10d
+    af:  cmp r9d,0x72
+    b3:  mov r8d,0x3b
+    b9:  jmp <+0x40>
+    bb:  xor r10d,r10d
+    be:  cmp r9d,0xb5
+    c5:  mov r8d,0x1f
+    cb:  jmp <+0x40>
+    d0:  xor r10d,r10d
+    d3:  cmp r9d,0x6f
+    d7:  mov r8d,0x45
+    dd:  jmp <+0x40>
+    e2:  mov r8b,0x4c
+    e5:  jmp <+0x44>
+    ea:  cmp r9d,0x73
+    ee:  sete r8b
+    f2:  add r8b,0x33
+    f6:  jmp <+0x44>
+    fb:  cmp r9d,0x6c
+    ff:  sete r8b
+   103:  shl r8b,0x6
+   107:  jmp <+0x44>
+   10c:  cmp r9d,0x65
+   110:  sete r8b
+   114:  or r8b,0x2a
+   118:  jmp <+0x44>
+   11d:  cmp r9d,0x73
+   121:  sete r8b
+   125:  add r8b,0x5d
+   129:  jmp <+0x44>
+   12e:  xor r10d,r10d
+   131:  cmp r9d,0x75
+   135:  mov r8d,0x21
+   13b:  jmp <+0x40>
+   140:  xor r10d,r10d
+   143:  cmp r9d,0x63
+   147:  mov r8d,0x44
+   14d:  jmp <+0x40>
+   152:  xor r10d,r10d
+   155:  cmp r9d,0x74
+   159:  mov r8d,0x42
+   15f:  jmp <+0x40>
+   164:  xor r10d,r10d
+   167:  cmp r9d,0x74
+   16b:  mov r8d,0x55
+   171:  jmp <+0x40>
+   176:  mov r8b,0x4e
+   179:  jmp <+0x44>
+   17e:  mov r8b,0x43
+   181:  jmp <+0x44>
+   186:  xor r10d,r10d
+   189:  cmp r9d,0x72
+   18d:  mov r8d,0x41
+   193:  jmp <+0x40>
+   198:  mov r8b,0x68
+   19b:  jmp <+0x44>
+   1a0:  cmp r9d,0x73
+   1a4:  sete r8b
+   1a8:  or r8b,0x6e
+   1ac:  jmp <+0x44>
+   1b1:  xor r10d,r10d
+   1b4:  cmp r9d,0x64
+   1b8:  mov r8d,0x74
+   1be:  jmp <+0x40>
+   1c3:  cmp r9d,0x73
+   1c7:  sete r8b
+   1cb:  add r8b,0x39
+   1cf:  jmp <+0x44>
+   1d4:  cmp r9d,0x65
+   1d8:  sete r8b
+   1dc:  add r8b,0x2d
+   1e0:  jmp <+0x44>
+   1e5:  mov r8b,0x77
+   1e8:  jmp <+0x44>
+   1ed:  mov r8b,0x19
+   1f0:  cmp r9b,0x65
+   1f4:  jb <+0x44>
+   1fa:  cmp r9d,0x65
+   1fe:  setne r10b
+   202:  cmp r9b,0x6b
+   206:  setb r11b
+   20a:  cmp r9b,0x6b
+   20e:  ja <+0x44>
+   214:  and r10b,r11b
+   217:  jne <+0x44>
+   21d:  cmp r9d,0x65
+   221:  sete r8b
+   225:  xor r8b,0x1b
+   229:  jmp <+0x44>
+   22e:  cmp r9d,0x73
+   232:  sete r8b
+   236:  or r8b,0x36
+   23a:  jmp <+0x44>
+   23f:  cmp r9d,0x73
+   243:  sete r8b
+   247:  or r8b,0x52
+   24b:  jmp <+0x44>
+   250:  mov r8b,0x73
+   253:  jmp <+0x44>
+   258:  mov r8b,0x70
+   25b:  jmp <+0x44>
+   260:  mov r8b,0x10
+   263:  cmp r9b,0x69
+   267:  jb <+0x44>
+   26d:  cmp r9d,0x69
+   271:  setne r10b
+   275:  cmp r9b,0x6f
+   279:  setb r11b
+   27d:  test r10b,r11b
+   280:  jne <+0x44>
+   286:  lea r10d,[r9-0x70]
+   28a:  cmp r10b,0x3
+   28e:  setb r10b
+   292:  cmp r9b,0x74
+   296:  setae r11b
+   29a:  or r11b,r10b
+   29d:  jne <+0x44>
+   2a3:  cmp r9d,0x69
+   2a7:  je <+0x81f>
+   2ad:  cmp r9d,0x73
+   2b1:  jne <+0x827>
+   2b7:  mov r8b,0x13
+   2ba:  jmp <+0x44>
+   2bf:  cmp r9d,0x73
+   2c3:  sete r8b
+   2c7:  or r8b,0x7a
+   2cb:  jmp <+0x44>
+   2d0:  cmp r9d,0x65
+   2d4:  sete r8b
+   2d8:  or r8b,0x7e
+   2dc:  jmp <+0x44>
+   2e1:  mov r8b,0x89
+   2e4:  jmp <+0x44>
+   2e9:  xor r10d,r10d
+   2ec:  cmp r9d,0x63
+   2f0:  mov r8d,0x46
+   2f6:  jmp <+0x40>
+   2fb:  cmp r9d,0x73
+   2ff:  sete r8b
+   303:  or r8b,0x82
+   307:  jmp <+0x44>
+   30c:  xor r10d,r10d
+   30f:  cmp r9d,0x6f
+   313:  mov r8d,0x8a
+   319:  jmp <+0x40>
+   31e:  mov r8b,0x27
+   321:  cmp r9b,0x6e
+   325:  jb <+0x44>
+   32b:  cmp r9d,0x6e
+   32f:  setne r10b
+   333:  cmp r9b,0x73
+   337:  setb r11b
+   33b:  cmp r9b,0x73
+   33f:  ja <+0x44>
+   345:  and r10b,r11b
+   348:  jne <+0x44>
+   34e:  cmp r9d,0x6e
+   352:  sete r8b
+   356:  xor r8b,0x29
+   35a:  jmp <+0x44>
+   35f:  xor r10d,r10d
+   362:  cmp r9d,0x73
+   366:  mov r8d,0x38
+   36c:  jmp <+0x40>
+   371:  cmp r9d,0x73
+   375:  sete r8b
+   379:  or r8b,0x6a
+   37d:  jmp <+0x44>
+   382:  mov r8b,0x84
+   385:  jmp <+0x44>
+   38a:  cmp r9d,0x65
+   38e:  sete r8b
+   392:  or r8b,0x30
+   396:  jmp <+0x44>
+   39b:  cmp r9d,0x73
+   39f:  sete r8b
+   3a3:  add r8b,0x63
+   3a7:  jmp <+0x44>
+   3ac:  cmp r9d,0x73
+   3b0:  sete r8b
+   3b4:  or r8b,0x96
+   3b8:  jmp <+0x44>
+   3bd:  xor r10d,r10d
+   3c0:  cmp r9d,0x63
+   3c4:  mov r8d,0x67
+   3ca:  jmp <+0x40>
+   3cf:  mov r8b,0x98
+   3d2:  jmp <+0x44>
+   3d7:  mov r8b,0x60
+   3da:  jmp <+0x44>
+   3df:  xor r10d,r10d
+   3e2:  cmp r9d,0x64
+   3e6:  mov r8d,0x8f
+   3ec:  jmp <+0x40>
+   3f1:  cmp r9d,0x73
+   3f5:  sete r8b
+   3f9:  or r8b,0x94
+   3fd:  jmp <+0x44>
+   402:  mov r8b,0x99
+   405:  jmp <+0x44>
+   40a:  xor r10d,r10d
+   40d:  cmp r9d,0x6f
+   411:  mov r8d,0x88
+   417:  jmp <+0x40>
+   41c:  xor r10d,r10d
+   41f:  cmp r9d,0x6e
+   423:  mov r8d,0x8c
+   429:  jmp <+0x40>
+   42e:  mov r8b,0x9a
+   431:  jmp <+0x44>
+   436:  xor r10d,r10d
+   439:  cmp r9d,0x6f
+   43d:  mov r8d,0x8b
+   443:  jmp <+0x40>
+   448:  cmp r9d,0x73
+   44c:  sete r8b
+   450:  or r8b,0x92
+   454:  jmp <+0x44>
+   459:  xor r10d,r10d
+   45c:  cmp r9d,0x63
+   460:  mov r8d,0x87
+   466:  jmp <+0x40>
+   46b:  xor r10d,r10d
+   46e:  cmp r9d,0x69
+   472:  mov r8d,0x56
+   478:  jmp <+0x40>
+   47d:  mov r8b,0x80
+   480:  jmp <+0x44>
+   485:  xor r10d,r10d
+   488:  cmp r9d,0x64
+   48c:  mov r8d,0x90
+   492:  jmp <+0x40>
+   497:  xor r10d,r10d
+   49a:  cmp r9d,0x64
+   49e:  mov r8d,0x91
+   4a4:  jmp <+0x40>
+   4a9:  cmp r9d,0x73
+   4ad:  sete r8b
+   4b1:  or r8b,0x6c
+   4b5:  jmp <+0x44>
+   4ba:  xor r10d,r10d
+   4bd:  cmp r9d,0x6e
+   4c1:  mov r8d,0x8e
+   4c7:  jmp <+0x40>
+   4cc:  xor r10d,r10d
+   4cf:  cmp r9d,0x6e
+   4d3:  mov r8d,0x8d
+   4d9:  jmp <+0x40>
+   4de:  mov r8b,0x85
+   4e1:  jmp <+0x44>
+   4e6:  xor r10d,r10d
+   4e9:  cmp r9d,0x6f
+   4ed:  mov r8d,0x57
+   4f3:  jmp <+0x40>
+   4f8:  xor r10d,r10d
+   4fb:  cmp r9d,0x63
+   4ff:  mov r8d,0x4a
+   505:  jmp <+0x40>
+   50a:  xor r10d,r10d
+   50d:  cmp r9d,0x65
+   511:  mov r8d,0x69
+   517:  jmp <+0x40>
+   51c:  cmp r9d,0x73
+   520:  sete r8b
+   524:  add r8b,0x59
+   528:  jmp <+0x44>
+   52d:  mov r8b,0xd
+   530:  cmp r9b,0x6f
+   534:  jb <+0x44>
+   53a:  cmp r9d,0x6f
+   53e:  setne r10b
+   542:  cmp r9b,0x72
+   546:  setb r11b
+   54a:  cmp r9b,0x72
+   54e:  ja <+0x44>
+   554:  and r10b,r11b
+   557:  jne <+0x44>
+   55d:  cmp r9d,0x6f
+   561:  sete r8b
+   565:  xor r8b,0xf
+   569:  jmp <+0x44>
+   56e:  cmp r9d,0x65
+   572:  sete r8b
+   576:  or r8b,0x7c
+   57a:  jmp <+0x44>
+   57f:  xor r10d,r10d
+   582:  cmp r9d,0x63
+   586:  mov r8d,0x81
+   58c:  jmp <+0x40>
+   591:  mov r8b,0x75
+   594:  jmp <+0x44>
+   599:  xor r10d,r10d
+   59c:  cmp r9d,0x63
+   5a0:  mov r8d,0x86
+   5a6:  jmp <+0x40>
+   5ab:  cmp r9d,0x73
+   5af:  sete r8b
+   5b3:  add r8b,0x65
+   5b7:  jmp <+0x44>
+   5bc:  xor r10d,r10d
+   5bf:  cmp r9d,0x6b
+   5c3:  mov r8d,0x4b
+   5c9:  jmp <+0x40>
+   5ce:  cmp r9d,0x79
+   5d2:  sete r8b
+   5d6:  shl r8b,0x5
+   5da:  jmp <+0x44>
+   5df:  mov r8b,0x1c
+   5e2:  cmp r9b,0x65
+   5e6:  jb <+0x44>
+   5ec:  cmp r9d,0x65
+   5f0:  setne r10b
+   5f4:  cmp r9b,0x72
+   5f8:  setb r11b
+   5fc:  cmp r9b,0x72
+   600:  ja <+0x44>
+   606:  and r10b,r11b
+   609:  jne <+0x44>
+   60f:  cmp r9d,0x65
+   613:  sete r9b
+   617:  mov r8b,0x1e
+   61a:  sub r8b,r9b
+   61d:  jmp <+0x44>
+   622:  mov r8b,0x76
+   625:  jmp <+0x44>
+   62a:  cmp r9d,0x65
+   62e:  sete r8b
+   632:  add r8b,0x71
+   636:  jmp <+0x44>
+   63b:  xor r10d,r10d
+   63e:  cmp r9d,0x65
+   642:  mov r8d,0x32
+   648:  jmp <+0x40>
+   64d:  cmp r9d,0x65
+   651:  sete r8b
+   655:  add r8b,0x4f
+   659:  jmp <+0x44>
+   65e:  xor r10d,r10d
+   661:  cmp r9d,0x72
+   665:  mov r8d,0x4d
+   66b:  jmp <+0x40>
+   670:  cmp r9d,0x73
+   674:  sete r8b
+   678:  or r8b,0x78
+   67c:  jmp <+0x44>
+   681:  mov r8b,0x3d
+   684:  cmp r9b,0x73
+   688:  jb <+0x44>
+   68e:  cmp r9d,0x73
+   692:  sete r8b
+   696:  add r8b,0x3d
+   69a:  cmp r9d,0x75
+   69e:  movzx r9d,r8b
+   6a2:  mov r8d,0x3f
+   6a8:  cmovne r8d,r9d
+   6ac:  jmp <+0x44>
+   6b1:  xor r10d,r10d
+   6b4:  cmp r9d,0x6e
+   6b8:  mov r8d,0x2c
+   6be:  jmp <+0x40>
+   6c3:  mov r8b,0x47
+   6c6:  cmp r9b,0x6f
+   6ca:  jb <+0x44>
+   6d0:  cmp r9d,0x6f
+   6d4:  setne r10b
+   6d8:  cmp r9b,0x73
+   6dc:  setb r11b
+   6e0:  cmp r9b,0x73
+   6e4:  ja <+0x44>
+   6ea:  and r10b,r11b
+   6ed:  jne <+0x44>
+   6f3:  cmp r9d,0x6f
+   6f7:  sete r8b
+   6fb:  xor r8b,0x49
+   6ff:  jmp <+0x44>
+   704:  mov r8b,0x51
+   707:  jmp <+0x44>
+   70c:  cmp r9d,0x61
+   710:  sete r8b
+   714:  add r8b,0xb
+   718:  jmp <+0x44>
+   71d:  xor r10d,r10d
+   720:  cmp r9d,0x6e
+   724:  mov r8d,0x5f
+   72a:  jmp <+0x40>
+   72f:  cmp r9d,0x73
+   733:  sete r8b
+   737:  add r8b,0x5b
+   73b:  jmp <+0x44>
+   740:  cmp r9d,0x73
+   744:  sete r8b
+   748:  add r8b,0x61
+   74c:  jmp <+0x44>
+   751:  xor r10d,r10d
+   754:  cmp r9d,0x68
+   758:  mov r8d,0x58
+   75e:  jmp <+0x40>
+   763:  xor r10d,r10d
+   766:  cmp r9d,0x63
+   76a:  mov r8d,0x2f
+   770:  jmp <+0x40>
+   775:  mov r8b,0x54
+   778:  jmp <+0x44>
+   77d:  xor r10d,r10d
+   780:  cmp r9d,0x73
+   784:  mov r8d,0x18
+   78a:  jmp <+0x40>
+   78f:  cmp r9d,0x73
+   793:  sete r8b
+   797:  or r8b,0x22
+   79b:  jmp <+0x44>
+   7a0:  cmp r9d,0x63
+   7a4:  je <+0x7c2>
+   7a6:  cmp r9d,0x6c
+   7aa:  je <+0x7ba>
+   7ac:  cmp r9d,0x6e
+   7b0:  jne <+0x7ca>
+   7b2:  mov r8b,0x24
+   7b5:  jmp <+0x44>
+   7ba:  mov r8b,0x25
+   7bd:  jmp <+0x44>
+   7c2:  mov r8b,0x26
+   7c5:  jmp <+0x44>
+   7ca:  xor r8d,r8d
+   7cd:  jmp <+0x44>
+   7d2:  cmp r9d,0xc2
+   7d9:  jne <+0x44>
+   7df:  mov r8b,0xa
+   7e2:  jmp <+0x44>
+   7e7:  mov r8b,0x9
+   7ea:  jmp <+0x44>
+   7ef:  mov r8b,0x8
+   7f2:  jmp <+0x44>
+   7f7:  mov r8b,0x4
+   7fa:  jmp <+0x44>
+   7ff:  mov r8b,0x3
+   802:  jmp <+0x44>
+   807:  mov r8b,0x6
+   80a:  jmp <+0x44>
+   80f:  mov r8b,0x5
+   812:  jmp <+0x44>
+   817:  mov r8b,0x7
+   81a:  jmp <+0x44>
+   81f:  mov r8b,0x11
+   822:  jmp <+0x44>
+   827:  mov r8b,0x12
+   82a:  jmp <+0x44>
+   82f:  movzx r8d,r8b
+   833:  add r8d,0xfffffffe
+   837:  cmp r8d,0x98
+   83e:  ja <+0x86a>
+   840:  mov ecx,0x6
+   845:  mov eax,0x1
+   84a:  lea r9,[rip]
+   851:  movsxd r8,DWORD PTR [r9+r8*4]
+   855:  add r8,r9
+   858:  jmp r8
+   85b:  mov ecx,0x1
+   860:  mov eax,0x5
+   865:  jmp <+0xa8f>
+   86a:  lea rax,[rdi+0x8]
+   86e:  mov rbx,rdi
+   871:  mov rdi,rax
+   874:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+   87a:  mov rdi,rbx
+   87d:  mov eax,0x1
+   882:  mov QWORD PTR [rdi],rax
+   885:  mov rax,rdi
+   888:  pop rbx
+   889:  ret
+   88a:  mov ecx,0x1
+   88f:  mov eax,0x6
+   894:  jmp <+0xa8f>
+   899:  mov ecx,0x2
+   89e:  mov eax,0x5
+   8a3:  jmp <+0xa8f>
+   8a8:  xor ecx,ecx
+   8aa:  mov eax,0x5
+   8af:  jmp <+0xa8f>
+   8b4:  xor ecx,ecx
+   8b6:  mov eax,0x4
+   8bb:  jmp <+0xa8f>
+   8c0:  mov ecx,0x1
+   8c5:  jmp <+0xa8a>
+   8ca:  mov ecx,0x4
+   8cf:  mov eax,0x3
+   8d4:  jmp <+0xa8f>
+   8d9:  xor ecx,ecx
+   8db:  jmp <+0xa8a>
+   8e0:  mov ecx,0x2
+   8e5:  jmp <+0xa8a>
+   8ea:  mov ecx,0x7
+   8ef:  mov eax,0x3
+   8f4:  jmp <+0xa8f>
+   8f9:  mov ecx,0x7
+   8fe:  jmp <+0xa8a>
+   903:  mov ecx,0x3
+   908:  mov eax,0x4
+   90d:  jmp <+0xa8f>
+   912:  mov ecx,0x7
+   917:  mov eax,0x4
+   91c:  jmp <+0xa8f>
+   921:  mov ecx,0x4
+   926:  mov eax,0x6
+   92b:  jmp <+0xa8f>
+   930:  mov ecx,0x1
+   935:  mov eax,0x3
+   93a:  jmp <+0xa8f>
+   93f:  mov ecx,0x8
+   944:  mov eax,0x6
+   949:  jmp <+0xa8f>
+   94e:  mov ecx,0x3
+   953:  mov eax,0x3
+   958:  jmp <+0xa8f>
+   95d:  mov ecx,0x9
+   962:  jmp <+0xa8a>
+   967:  mov ecx,0x5
+   96c:  mov eax,0x4
+   971:  jmp <+0xa8f>
+   976:  mov ecx,0x8
+   97b:  mov eax,0x5
+   980:  jmp <+0xa8f>
+   985:  mov ecx,0x5
+   98a:  mov eax,0x5
+   98f:  jmp <+0xa8f>
+   994:  mov ecx,0x1
+   999:  mov eax,0xb
+   99e:  jmp <+0xa8f>
+   9a3:  mov ecx,0x9
+   9a8:  mov eax,0x5
+   9ad:  jmp <+0xa8f>
+   9b2:  mov ecx,0x3
+   9b7:  mov eax,0x7
+   9bc:  jmp <+0xa8f>
+   9c1:  mov ecx,0x1
+   9c6:  mov eax,0xc
+   9cb:  jmp <+0xa8f>
+   9d0:  mov ecx,0x5
+   9d5:  mov eax,0x3
+   9da:  jmp <+0xa8f>
+   9df:  mov ecx,0x9
+   9e4:  mov eax,0x3
+   9e9:  jmp <+0xa8f>
+   9ee:  mov ecx,0x2
+   9f3:  mov eax,0x6
+   9f8:  jmp <+0xa8f>
+   9fd:  mov ecx,0x2
+   a02:  mov eax,0xc
+   a07:  jmp <+0xa8f>
+   a0c:  mov ecx,0x9
+   a11:  mov eax,0x4
+   a16:  jmp <+0xa8f>
+   a18:  mov ecx,0x2
+   a1d:  mov eax,0x4
+   a22:  jmp <+0xa8f>
+   a24:  mov ecx,0x2
+   a29:  mov eax,0xb
+   a2e:  jmp <+0xa8f>
+   a30:  mov ecx,0x3
+   a35:  mov eax,0x6
+   a3a:  jmp <+0xa8f>
+   a3c:  xor ecx,ecx
+   a3e:  mov eax,0xa
+   a43:  jmp <+0xa8f>
+   a45:  mov ecx,0x4
+   a4a:  mov eax,0x7
+   a4f:  jmp <+0xa8f>
+   a51:  xor ecx,ecx
+   a53:  mov eax,0xb
+   a58:  jmp <+0xa8f>
+   a5a:  mov ecx,0x1
+   a5f:  mov eax,0x4
+   a64:  jmp <+0xa8f>
+   a66:  mov ecx,0x8
+   a6b:  mov eax,0x3
+   a70:  jmp <+0xa8f>
+   a72:  mov ecx,0x4
+   a77:  mov eax,0x4
+   a7c:  jmp <+0xa8f>
+   a7e:  mov ecx,0x5
+   a83:  jmp <+0xa8a>
+   a85:  mov ecx,0x8
+   a8a:  mov eax,0x2
+   a8f:  mov r8,rdx
+   a92:  sub r8,rax
+   a95:  jb <+0xb62>
+   a9b:  add rsi,rax
+   a9e:  mov DWORD PTR [rdi+0x8],ecx
+   aa1:  mov QWORD PTR [rdi+0x10],rsi
+   aa5:  mov QWORD PTR [rdi+0x18],r8
+   aa9:  xor eax,eax
+   aab:  mov QWORD PTR [rdi],rax
+   aae:  mov rax,rdi
+   ab1:  pop rbx
+   ab2:  ret
+   ab3:  mov ecx,0x7
+   ab8:  mov eax,0x5
+   abd:  jmp <+0xa8f>
+   abf:  mov eax,0x3
+   ac4:  jmp <+0xa8f>
+   ac6:  mov ecx,0x9
+   acb:  jmp <+0xa8f>
+   acd:  mov ecx,0x7
+   ad2:  jmp <+0xa8f>
+   ad4:  mov ecx,0x3
+   ad9:  jmp <+0xa8f>
+   adb:  mov ecx,0x5
+   ae0:  jmp <+0xa8f>
+   ae2:  mov eax,0x4
+   ae7:  jmp <+0xa8f>
+   ae9:  mov ecx,0x4
+   aee:  jmp <+0xa8f>
+   af0:  mov ecx,0x5
+   af5:  mov eax,0x1
+   afa:  jmp <+0xa8f>
+   afc:  mov ecx,0x7
+   b01:  mov eax,0x1
+   b06:  jmp <+0xa8f>
+   b08:  mov ecx,0x9
+   b0d:  mov eax,0x1
+   b12:  jmp <+0xa8f>
+   b17:  mov ecx,0x6
+   b1c:  mov eax,0x3
+   b21:  jmp <+0xa8f>
+   b26:  mov ecx,0x4
+   b2b:  mov eax,0x1
+   b30:  jmp <+0xa8f>
+   b35:  mov ecx,0x6
+   b3a:  mov eax,0x4
+   b3f:  jmp <+0xa8f>
+   b44:  mov ecx,0x6
+   b49:  mov eax,0x1
+   b4e:  jmp <+0xa8f>
+   b53:  mov ecx,0x3
+   b58:  mov eax,0x1
+   b5d:  jmp <+0xa8f>
+   b62:  lea rcx,[rip]
+   b69:  mov rdi,rax
+   b6c:  mov rsi,rdx
+   b6f:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
@@ -0,0 +1,1128 @@
+duration_unit_lookup::by_gendfa1::lookup_ignore_ascii_case:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0xf51>
+     a:  mov r8b,0x1
+     d:  xor eax,eax
+     f:  lea rcx,[rip]
+    16:  jmp <+0x2c>
+    18:  mov r8b,0x3c
+    1b:  nop DWORD PTR [rax+rax*1+0x0]
+    20:  inc rax
+    23:  cmp rdx,rax
+    26:  je <+0xf16>
+    2c:  movzx r9d,BYTE PTR [rsi+rax*1]
+    31:  movzx r8d,r8b
+    35:  movsxd r8,DWORD PTR [rcx+r8*4]
+    39:  add r8,rcx
+    3c:  jmp r8
+    3f:  xor r8d,r8d
+    42:  add r9d,0xffffffbc
+    46:  cmp r9d,0x7e
+    4a:  ja <+0x20>
+    4c:  lea r10,[rip]
+    53:  movsxd r9,DWORD PTR [r10+r9*4]
+    57:  add r9,r10
+    5a:  jmp r9
+    5d:  mov r8b,0x2
+    60:  jmp <+0x20>
+    62:  xor r8d,r8d
+    65:  cmp r9d,0x60
+    69:  jg <+0xe84>
+    6f:  cmp r9d,0x41
+    73:  je <+0xe9c>
+    79:  cmp r9d,0x53
+    7d:  je <+0xe94>
+    83:  jmp <+0x20>
+    85:  mov r8b,0x16
+    88:  cmp r9b,0x45
+    8c:  jb <+0x20>
+    8e:  cmp r9d,0x45
+    92:  setne r8b
+    96:  cmp r9b,0x65
+    9a:  setb r10b
+    9e:  and r10b,r8b
+    a1:  cmp r9b,0x66
+    a5:  setae r8b
+    a9:  or r8b,r10b
+    ac:  xor r8b,0x17
+    b0:  jmp <+0x20>
+    b5:  add r9b,0xbf
+    b9:  xor r10d,r10d
+    bc:  test r9b,0xdf
+    c0:  mov r8d,0x35
+    c6:  cmovne r8d,r10d
+    ca:  jmp <+0x20>
+    cf:  add r9b,0xae
+    d3:  xor r10d,r10d
+    d6:  test r9b,0xdf
+    da:  mov r8d,0x3b
+    e0:  cmovne r8d,r10d
+    e4:  jmp <+0x20>
+    e9:  xor r10d,r10d
+    ec:  cmp r9d,0xb5
+    f3:  mov r8d,0x1f
+    f9:  cmovne r8d,r10d
+    fd:  jmp <+0x20>
+   102:  add r9b,0xb1
+   106:  xor r10d,r10d
+   109:  test r9b,0xdf
+   10d:  mov r8d,0x45
+   113:  cmovne r8d,r10d
+   117:  jmp <+0x20>
+   11c:  mov r8b,0x4c
+   11f:  jmp <+0x20>
+   124:  mov r8b,0x33
+   127:  cmp r9b,0x53
+   12b:  jb <+0x20>
+   131:  cmp r9d,0x53
+   135:  setne r8b
+   139:  cmp r9b,0x73
+   13d:  setb r10b
+   141:  and r10b,r8b
+   144:  cmp r9b,0x74
+   148:  setae r9b
+   14c:  or r9b,r10b
+   14f:  mov r8b,0x34
+   152:  sub r8b,r9b
+   155:  jmp <+0x20>
+   15a:  add r9b,0xb4
+   15e:  test r9b,0xdf
+   162:  sete r8b
+   166:  shl r8b,0x6
+   16a:  jmp <+0x20>
+   16f:  mov r8b,0x2a
+   172:  cmp r9b,0x45
+   176:  jb <+0x20>
+   17c:  cmp r9d,0x45
+   180:  setne r8b
+   184:  cmp r9b,0x65
+   188:  setb r10b
+   18c:  and r10b,r8b
+   18f:  cmp r9b,0x66
+   193:  setae r8b
+   197:  or r8b,r10b
+   19a:  xor r8b,0x2b
+   19e:  jmp <+0x20>
+   1a3:  mov r8b,0x5d
+   1a6:  cmp r9b,0x53
+   1aa:  jb <+0x20>
+   1b0:  cmp r9d,0x53
+   1b4:  setne r8b
+   1b8:  cmp r9b,0x73
+   1bc:  setb r10b
+   1c0:  and r10b,r8b
+   1c3:  cmp r9b,0x74
+   1c7:  setae r9b
+   1cb:  or r9b,r10b
+   1ce:  mov r8b,0x5e
+   1d1:  sub r8b,r9b
+   1d4:  jmp <+0x20>
+   1d9:  add r9b,0xab
+   1dd:  xor r10d,r10d
+   1e0:  test r9b,0xdf
+   1e4:  mov r8d,0x21
+   1ea:  cmovne r8d,r10d
+   1ee:  jmp <+0x20>
+   1f3:  add r9b,0xbd
+   1f7:  xor r10d,r10d
+   1fa:  test r9b,0xdf
+   1fe:  mov r8d,0x44
+   204:  cmovne r8d,r10d
+   208:  jmp <+0x20>
+   20d:  add r9b,0xac
+   211:  xor r10d,r10d
+   214:  test r9b,0xdf
+   218:  mov r8d,0x42
+   21e:  cmovne r8d,r10d
+   222:  jmp <+0x20>
+   227:  add r9b,0xac
+   22b:  xor r10d,r10d
+   22e:  test r9b,0xdf
+   232:  mov r8d,0x55
+   238:  cmovne r8d,r10d
+   23c:  jmp <+0x20>
+   241:  mov r8b,0x4e
+   244:  jmp <+0x20>
+   249:  mov r8b,0x43
+   24c:  jmp <+0x20>
+   251:  add r9b,0xae
+   255:  xor r10d,r10d
+   258:  test r9b,0xdf
+   25c:  mov r8d,0x41
+   262:  cmovne r8d,r10d
+   266:  jmp <+0x20>
+   26b:  mov r8b,0x68
+   26e:  jmp <+0x20>
+   273:  mov r8b,0x6e
+   276:  cmp r9b,0x53
+   27a:  jb <+0x20>
+   280:  cmp r9d,0x53
+   284:  setne r8b
+   288:  cmp r9b,0x73
+   28c:  setb r10b
+   290:  and r10b,r8b
+   293:  cmp r9b,0x74
+   297:  setae r8b
+   29b:  or r8b,r10b
+   29e:  xor r8b,0x6f
+   2a2:  jmp <+0x20>
+   2a7:  add r9b,0xbc
+   2ab:  xor r10d,r10d
+   2ae:  test r9b,0xdf
+   2b2:  mov r8d,0x74
+   2b8:  cmovne r8d,r10d
+   2bc:  jmp <+0x20>
+   2c1:  mov r8b,0x39
+   2c4:  cmp r9b,0x53
+   2c8:  jb <+0x20>
+   2ce:  cmp r9d,0x53
+   2d2:  setne r8b
+   2d6:  cmp r9b,0x73
+   2da:  setb r10b
+   2de:  and r10b,r8b
+   2e1:  cmp r9b,0x74
+   2e5:  setae r9b
+   2e9:  or r9b,r10b
+   2ec:  mov r8b,0x3a
+   2ef:  sub r8b,r9b
+   2f2:  jmp <+0x20>
+   2f7:  mov r8b,0x2d
+   2fa:  cmp r9b,0x45
+   2fe:  jb <+0x20>
+   304:  cmp r9d,0x45
+   308:  setne r8b
+   30c:  cmp r9b,0x65
+   310:  setb r10b
+   314:  and r10b,r8b
+   317:  cmp r9b,0x66
+   31b:  setae r9b
+   31f:  or r9b,r10b
+   322:  mov r8b,0x2e
+   325:  sub r8b,r9b
+   328:  jmp <+0x20>
+   32d:  mov r8b,0x77
+   330:  jmp <+0x20>
+   335:  mov r8b,0x19
+   338:  cmp r9b,0x45
+   33c:  jb <+0x20>
+   342:  cmp r9d,0x45
+   346:  setne r10b
+   34a:  cmp r9b,0x4b
+   34e:  setb r11b
+   352:  and r11b,r10b
+   355:  lea r10d,[r9-0x4c]
+   359:  cmp r10b,0x19
+   35d:  setb r10b
+   361:  or r10b,r11b
+   364:  jne <+0x20>
+   36a:  lea r10d,[r9-0x66]
+   36e:  cmp r10b,0x5
+   372:  setb r10b
+   376:  cmp r9b,0x6c
+   37a:  setae r11b
+   37e:  or r11b,r10b
+   381:  jne <+0x20>
+   387:  mov r8b,0x1a
+   38a:  add r9d,0xffffffbb
+   38e:  lea r10,[rip]
+   395:  movsxd r9,DWORD PTR [r10+r9*4]
+   399:  add r9,r10
+   39c:  jmp r9
+   39f:  mov r8b,0x1b
+   3a2:  jmp <+0x20>
+   3a7:  mov r8b,0x36
+   3aa:  cmp r9b,0x53
+   3ae:  jb <+0x20>
+   3b4:  cmp r9d,0x53
+   3b8:  setne r8b
+   3bc:  cmp r9b,0x73
+   3c0:  setb r10b
+   3c4:  and r10b,r8b
+   3c7:  cmp r9b,0x74
+   3cb:  setae r8b
+   3cf:  or r8b,r10b
+   3d2:  xor r8b,0x37
+   3d6:  jmp <+0x20>
+   3db:  mov r8b,0x52
+   3de:  cmp r9b,0x53
+   3e2:  jb <+0x20>
+   3e8:  cmp r9d,0x53
+   3ec:  setne r8b
+   3f0:  cmp r9b,0x73
+   3f4:  setb r10b
+   3f8:  and r10b,r8b
+   3fb:  cmp r9b,0x74
+   3ff:  setae r8b
+   403:  or r8b,r10b
+   406:  xor r8b,0x53
+   40a:  jmp <+0x20>
+   40f:  mov r8b,0x73
+   412:  jmp <+0x20>
+   417:  mov r8b,0x70
+   41a:  jmp <+0x20>
+   41f:  mov r8b,0x10
+   422:  cmp r9b,0x49
+   426:  jb <+0x20>
+   42c:  cmp r9d,0x49
+   430:  setne r10b
+   434:  cmp r9b,0x4f
+   438:  setb r11b
+   43c:  lea ebx,[r9-0x6a]
+   440:  cmp bl,0x5
+   443:  jb <+0x20>
+   449:  lea ebx,[r9-0x54]
+   44d:  cmp bl,0x15
+   450:  jb <+0x20>
+   456:  and r10b,r11b
+   459:  jne <+0x20>
+   45f:  lea r10d,[r9-0x50]
+   463:  cmp r10b,0x3
+   467:  jb <+0x20>
+   46d:  lea r10d,[r9-0x70]
+   471:  cmp r10b,0x3
+   475:  setb r10b
+   479:  cmp r9b,0x74
+   47d:  setae r11b
+   481:  or r11b,r10b
+   484:  jne <+0x20>
+   48a:  mov r8b,0x11
+   48d:  add r9d,0xffffffb7
+   491:  movabs r10,0x100000001
+   49b:  bt r10,r9
+   49f:  jb <+0x20>
+   4a5:  movabs r8,0x4000000040
+   4af:  bt r8,r9
+   4b3:  jae <+0xf0e>
+   4b9:  mov r8b,0x12
+   4bc:  jmp <+0x20>
+   4c1:  mov r8b,0x7a
+   4c4:  cmp r9b,0x53
+   4c8:  jb <+0x20>
+   4ce:  cmp r9d,0x53
+   4d2:  setne r8b
+   4d6:  cmp r9b,0x73
+   4da:  setb r10b
+   4de:  and r10b,r8b
+   4e1:  cmp r9b,0x74
+   4e5:  setae r8b
+   4e9:  or r8b,r10b
+   4ec:  xor r8b,0x7b
+   4f0:  jmp <+0x20>
+   4f5:  mov r8b,0x7e
+   4f8:  cmp r9b,0x45
+   4fc:  jb <+0x20>
+   502:  cmp r9d,0x45
+   506:  setne r8b
+   50a:  cmp r9b,0x65
+   50e:  setb r10b
+   512:  and r10b,r8b
+   515:  cmp r9b,0x66
+   519:  setae r8b
+   51d:  or r8b,r10b
+   520:  xor r8b,0x7f
+   524:  jmp <+0x20>
+   529:  mov r8b,0x89
+   52c:  jmp <+0x20>
+   531:  add r9b,0xbd
+   535:  xor r10d,r10d
+   538:  test r9b,0xdf
+   53c:  mov r8d,0x46
+   542:  cmovne r8d,r10d
+   546:  jmp <+0x20>
+   54b:  mov r8b,0x82
+   54e:  cmp r9b,0x53
+   552:  jb <+0x20>
+   558:  cmp r9d,0x53
+   55c:  setne r8b
+   560:  cmp r9b,0x73
+   564:  setb r10b
+   568:  and r10b,r8b
+   56b:  cmp r9b,0x74
+   56f:  setae r8b
+   573:  or r8b,r10b
+   576:  xor r8b,0x83
+   57a:  jmp <+0x20>
+   57f:  add r9b,0xb1
+   583:  xor r10d,r10d
+   586:  test r9b,0xdf
+   58a:  mov r8d,0x8a
+   590:  cmovne r8d,r10d
+   594:  jmp <+0x20>
+   599:  mov r8b,0x27
+   59c:  cmp r9b,0x4e
+   5a0:  jb <+0x20>
+   5a6:  cmp r9d,0x4e
+   5aa:  setne r10b
+   5ae:  cmp r9b,0x53
+   5b2:  setb r11b
+   5b6:  and r11b,r10b
+   5b9:  lea r10d,[r9-0x54]
+   5bd:  cmp r10b,0x1a
+   5c1:  setb r10b
+   5c5:  or r10b,r11b
+   5c8:  jne <+0x20>
+   5ce:  lea r10d,[r9-0x6f]
+   5d2:  cmp r10b,0x4
+   5d6:  setb r10b
+   5da:  cmp r9b,0x74
+   5de:  setae r11b
+   5e2:  or r11b,r10b
+   5e5:  jne <+0x20>
+   5eb:  mov r8b,0x28
+   5ee:  add r9d,0xffffffb2
+   5f2:  lea r10,[rip]
+   5f9:  movsxd r9,DWORD PTR [r10+r9*4]
+   5fd:  add r9,r10
+   600:  jmp r9
+   603:  mov r8b,0x29
+   606:  jmp <+0x20>
+   60b:  add r9b,0xad
+   60f:  xor r10d,r10d
+   612:  test r9b,0xdf
+   616:  mov r8d,0x38
+   61c:  cmovne r8d,r10d
+   620:  jmp <+0x20>
+   625:  mov r8b,0x6a
+   628:  cmp r9b,0x53
+   62c:  jb <+0x20>
+   632:  cmp r9d,0x53
+   636:  setne r8b
+   63a:  cmp r9b,0x73
+   63e:  setb r10b
+   642:  and r10b,r8b
+   645:  cmp r9b,0x74
+   649:  setae r8b
+   64d:  or r8b,r10b
+   650:  xor r8b,0x6b
+   654:  jmp <+0x20>
+   659:  mov r8b,0x84
+   65c:  jmp <+0x20>
+   661:  mov r8b,0x30
+   664:  cmp r9b,0x45
+   668:  jb <+0x20>
+   66e:  cmp r9d,0x45
+   672:  setne r8b
+   676:  cmp r9b,0x65
+   67a:  setb r10b
+   67e:  and r10b,r8b
+   681:  cmp r9b,0x66
+   685:  setae r8b
+   689:  or r8b,r10b
+   68c:  xor r8b,0x31
+   690:  jmp <+0x20>
+   695:  mov r8b,0x63
+   698:  cmp r9b,0x53
+   69c:  jb <+0x20>
+   6a2:  cmp r9d,0x53
+   6a6:  setne r8b
+   6aa:  cmp r9b,0x73
+   6ae:  setb r10b
+   6b2:  and r10b,r8b
+   6b5:  cmp r9b,0x74
+   6b9:  setae r9b
+   6bd:  or r9b,r10b
+   6c0:  mov r8b,0x64
+   6c3:  sub r8b,r9b
+   6c6:  jmp <+0x20>
+   6cb:  mov r8b,0x96
+   6ce:  cmp r9b,0x53
+   6d2:  jb <+0x20>
+   6d8:  cmp r9d,0x53
+   6dc:  setne r8b
+   6e0:  cmp r9b,0x73
+   6e4:  setb r10b
+   6e8:  and r10b,r8b
+   6eb:  cmp r9b,0x74
+   6ef:  setae r8b
+   6f3:  or r8b,r10b
+   6f6:  xor r8b,0x97
+   6fa:  jmp <+0x20>
+   6ff:  add r9b,0xbd
+   703:  xor r10d,r10d
+   706:  test r9b,0xdf
+   70a:  mov r8d,0x67
+   710:  cmovne r8d,r10d
+   714:  jmp <+0x20>
+   719:  mov r8b,0x98
+   71c:  jmp <+0x20>
+   721:  mov r8b,0x60
+   724:  jmp <+0x20>
+   729:  add r9b,0xbc
+   72d:  xor r10d,r10d
+   730:  test r9b,0xdf
+   734:  mov r8d,0x8f
+   73a:  cmovne r8d,r10d
+   73e:  jmp <+0x20>
+   743:  mov r8b,0x94
+   746:  cmp r9b,0x53
+   74a:  jb <+0x20>
+   750:  cmp r9d,0x53
+   754:  setne r8b
+   758:  cmp r9b,0x73
+   75c:  setb r10b
+   760:  and r10b,r8b
+   763:  cmp r9b,0x74
+   767:  setae r8b
+   76b:  or r8b,r10b
+   76e:  xor r8b,0x95
+   772:  jmp <+0x20>
+   777:  mov r8b,0x99
+   77a:  jmp <+0x20>
+   77f:  add r9b,0xb1
+   783:  xor r10d,r10d
+   786:  test r9b,0xdf
+   78a:  mov r8d,0x88
+   790:  cmovne r8d,r10d
+   794:  jmp <+0x20>
+   799:  add r9b,0xb2
+   79d:  xor r10d,r10d
+   7a0:  test r9b,0xdf
+   7a4:  mov r8d,0x8c
+   7aa:  cmovne r8d,r10d
+   7ae:  jmp <+0x20>
+   7b3:  mov r8b,0x9a
+   7b6:  jmp <+0x20>
+   7bb:  add r9b,0xb1
+   7bf:  xor r10d,r10d
+   7c2:  test r9b,0xdf
+   7c6:  mov r8d,0x8b
+   7cc:  cmovne r8d,r10d
+   7d0:  jmp <+0x20>
+   7d5:  mov r8b,0x92
+   7d8:  cmp r9b,0x53
+   7dc:  jb <+0x20>
+   7e2:  cmp r9d,0x53
+   7e6:  setne r8b
+   7ea:  cmp r9b,0x73
+   7ee:  setb r10b
+   7f2:  and r10b,r8b
+   7f5:  cmp r9b,0x74
+   7f9:  setae r8b
+   7fd:  or r8b,r10b
+   800:  xor r8b,0x93
+   804:  jmp <+0x20>
+   809:  add r9b,0xbd
+   80d:  xor r10d,r10d
+   810:  test r9b,0xdf
+   814:  mov r8d,0x87
+   81a:  cmovne r8d,r10d
+   81e:  jmp <+0x20>
+   823:  add r9b,0xb7
+   827:  xor r10d,r10d
+   82a:  test r9b,0xdf
+   82e:  mov r8d,0x56
+   834:  cmovne r8d,r10d
+   838:  jmp <+0x20>
+   83d:  mov r8b,0x80
+   840:  jmp <+0x20>
+   845:  add r9b,0xbc
+   849:  xor r10d,r10d
+   84c:  test r9b,0xdf
+   850:  mov r8d,0x90
+   856:  cmovne r8d,r10d
+   85a:  jmp <+0x20>
+   85f:  add r9b,0xbc
+   863:  xor r10d,r10d
+   866:  test r9b,0xdf
+   86a:  mov r8d,0x91
+   870:  cmovne r8d,r10d
+   874:  jmp <+0x20>
+   879:  mov r8b,0x6c
+   87c:  cmp r9b,0x53
+   880:  jb <+0x20>
+   886:  cmp r9d,0x53
+   88a:  setne r8b
+   88e:  cmp r9b,0x73
+   892:  setb r10b
+   896:  and r10b,r8b
+   899:  cmp r9b,0x74
+   89d:  setae r8b
+   8a1:  or r8b,r10b
+   8a4:  xor r8b,0x6d
+   8a8:  jmp <+0x20>
+   8ad:  add r9b,0xb2
+   8b1:  xor r10d,r10d
+   8b4:  test r9b,0xdf
+   8b8:  mov r8d,0x8e
+   8be:  cmovne r8d,r10d
+   8c2:  jmp <+0x20>
+   8c7:  add r9b,0xb2
+   8cb:  xor r10d,r10d
+   8ce:  test r9b,0xdf
+   8d2:  mov r8d,0x8d
+   8d8:  cmovne r8d,r10d
+   8dc:  jmp <+0x20>
+   8e1:  mov r8b,0x85
+   8e4:  jmp <+0x20>
+   8e9:  add r9b,0xb1
+   8ed:  xor r10d,r10d
+   8f0:  test r9b,0xdf
+   8f4:  mov r8d,0x57
+   8fa:  cmovne r8d,r10d
+   8fe:  jmp <+0x20>
+   903:  add r9b,0xbd
+   907:  xor r10d,r10d
+   90a:  test r9b,0xdf
+   90e:  mov r8d,0x4a
+   914:  cmovne r8d,r10d
+   918:  jmp <+0x20>
+   91d:  add r9b,0xbb
+   921:  xor r10d,r10d
+   924:  test r9b,0xdf
+   928:  mov r8d,0x69
+   92e:  cmovne r8d,r10d
+   932:  jmp <+0x20>
+   937:  mov r8b,0x59
+   93a:  cmp r9b,0x53
+   93e:  jb <+0x20>
+   944:  cmp r9d,0x53
+   948:  setne r8b
+   94c:  cmp r9b,0x73
+   950:  setb r10b
+   954:  and r10b,r8b
+   957:  cmp r9b,0x74
+   95b:  setae r9b
+   95f:  or r9b,r10b
+   962:  mov r8b,0x5a
+   965:  sub r8b,r9b
+   968:  jmp <+0x20>
+   96d:  mov r8b,0xd
+   970:  cmp r9b,0x4f
+   974:  jb <+0x20>
+   97a:  cmp r9d,0x4f
+   97e:  setne r10b
+   982:  cmp r9b,0x52
+   986:  setb r11b
+   98a:  and r11b,r10b
+   98d:  lea r10d,[r9-0x53]
+   991:  cmp r10b,0x1c
+   995:  setb r10b
+   999:  or r10b,r11b
+   99c:  jne <+0x20>
+   9a2:  mov r10d,r9d
+   9a5:  and r10b,0xfe
+   9a9:  cmp r10b,0x70
+   9ad:  sete r10b
+   9b1:  cmp r9b,0x73
+   9b5:  setae r11b
+   9b9:  or r11b,r10b
+   9bc:  jne <+0x20>
+   9c2:  mov r8b,0xe
+   9c5:  add r9d,0xffffffb1
+   9c9:  lea r10,[rip]
+   9d0:  movsxd r9,DWORD PTR [r10+r9*4]
+   9d4:  add r9,r10
+   9d7:  jmp r9
+   9da:  mov r8b,0xf
+   9dd:  jmp <+0x20>
+   9e2:  mov r8b,0x7c
+   9e5:  cmp r9b,0x45
+   9e9:  jb <+0x20>
+   9ef:  cmp r9d,0x45
+   9f3:  setne r8b
+   9f7:  cmp r9b,0x65
+   9fb:  setb r10b
+   9ff:  and r10b,r8b
+   a02:  cmp r9b,0x66
+   a06:  setae r8b
+   a0a:  or r8b,r10b
+   a0d:  xor r8b,0x7d
+   a11:  jmp <+0x20>
+   a16:  add r9b,0xbd
+   a1a:  xor r10d,r10d
+   a1d:  test r9b,0xdf
+   a21:  mov r8d,0x81
+   a27:  cmovne r8d,r10d
+   a2b:  jmp <+0x20>
+   a30:  mov r8b,0x75
+   a33:  jmp <+0x20>
+   a38:  add r9b,0xbd
+   a3c:  xor r10d,r10d
+   a3f:  test r9b,0xdf
+   a43:  mov r8d,0x86
+   a49:  cmovne r8d,r10d
+   a4d:  jmp <+0x20>
+   a52:  mov r8b,0x65
+   a55:  cmp r9b,0x53
+   a59:  jb <+0x20>
+   a5f:  cmp r9d,0x53
+   a63:  setne r8b
+   a67:  cmp r9b,0x73
+   a6b:  setb r10b
+   a6f:  and r10b,r8b
+   a72:  cmp r9b,0x74
+   a76:  setae r9b
+   a7a:  or r9b,r10b
+   a7d:  mov r8b,0x66
+   a80:  sub r8b,r9b
+   a83:  jmp <+0x20>
+   a88:  add r9b,0xb5
+   a8c:  xor r10d,r10d
+   a8f:  test r9b,0xdf
+   a93:  mov r8d,0x4b
+   a99:  cmovne r8d,r10d
+   a9d:  jmp <+0x20>
+   aa2:  add r9b,0xa7
+   aa6:  test r9b,0xdf
+   aaa:  sete r8b
+   aae:  shl r8b,0x5
+   ab2:  jmp <+0x20>
+   ab7:  mov r8b,0x1c
+   aba:  cmp r9b,0x45
+   abe:  jb <+0x20>
+   ac4:  cmp r9d,0x45
+   ac8:  setne r10b
+   acc:  cmp r9b,0x52
+   ad0:  setb r11b
+   ad4:  and r11b,r10b
+   ad7:  lea r10d,[r9-0x53]
+   adb:  cmp r10b,0x12
+   adf:  setb r10b
+   ae3:  or r10b,r11b
+   ae6:  jne <+0x20>
+   aec:  lea r10d,[r9-0x66]
+   af0:  cmp r10b,0xc
+   af4:  setb r10b
+   af8:  cmp r9b,0x73
+   afc:  setae r11b
+   b00:  or r11b,r10b
+   b03:  jne <+0x20>
+   b09:  mov r8b,0x1d
+   b0c:  cmp r9d,0x64
+   b10:  jg <+0xef4>
+   b16:  cmp r9d,0x45
+   b1a:  jne <+0xefe>
+   b20:  jmp <+0x20>
+   b25:  mov r8b,0x76
+   b28:  jmp <+0x20>
+   b2d:  mov r8b,0x71
+   b30:  cmp r9b,0x45
+   b34:  jb <+0x20>
+   b3a:  cmp r9d,0x45
+   b3e:  setne r8b
+   b42:  cmp r9b,0x65
+   b46:  setb r10b
+   b4a:  and r10b,r8b
+   b4d:  cmp r9b,0x66
+   b51:  setae r9b
+   b55:  or r9b,r10b
+   b58:  mov r8b,0x72
+   b5b:  sub r8b,r9b
+   b5e:  jmp <+0x20>
+   b63:  add r9b,0xbb
+   b67:  xor r10d,r10d
+   b6a:  test r9b,0xdf
+   b6e:  mov r8d,0x32
+   b74:  cmovne r8d,r10d
+   b78:  jmp <+0x20>
+   b7d:  mov r8b,0x4f
+   b80:  cmp r9b,0x45
+   b84:  jb <+0x20>
+   b8a:  cmp r9d,0x45
+   b8e:  setne r8b
+   b92:  cmp r9b,0x65
+   b96:  setb r10b
+   b9a:  and r10b,r8b
+   b9d:  cmp r9b,0x66
+   ba1:  setae r9b
+   ba5:  or r9b,r10b
+   ba8:  mov r8b,0x50
+   bab:  sub r8b,r9b
+   bae:  jmp <+0x20>
+   bb3:  add r9b,0xae
+   bb7:  xor r10d,r10d
+   bba:  test r9b,0xdf
+   bbe:  mov r8d,0x4d
+   bc4:  cmovne r8d,r10d
+   bc8:  jmp <+0x20>
+   bcd:  mov r8b,0x78
+   bd0:  cmp r9b,0x53
+   bd4:  jb <+0x20>
+   bda:  cmp r9d,0x53
+   bde:  setne r8b
+   be2:  cmp r9b,0x73
+   be6:  setb r10b
+   bea:  and r10b,r8b
+   bed:  cmp r9b,0x74
+   bf1:  setae r8b
+   bf5:  or r8b,r10b
+   bf8:  xor r8b,0x79
+   bfc:  jmp <+0x20>
+   c01:  mov r8b,0x3d
+   c04:  cmp r9b,0x53
+   c08:  jb <+0x20>
+   c0e:  add r9d,0xffffffad
+   c12:  cmp r9d,0x22
+   c16:  ja <+0x20>
+   c1c:  movabs r10,0x100000001
+   c26:  bt r10,r9
+   c2a:  jb <+0xeb4>
+   c30:  movabs r10,0x200000002
+   c3a:  bt r10,r9
+   c3e:  jb <+0x20>
+   c44:  movabs r10,0x400000004
+   c4e:  bt r10,r9
+   c52:  jae <+0x20>
+   c58:  mov r8b,0x3f
+   c5b:  jmp <+0x20>
+   c60:  add r9b,0xb2
+   c64:  xor r10d,r10d
+   c67:  test r9b,0xdf
+   c6b:  mov r8d,0x2c
+   c71:  cmovne r8d,r10d
+   c75:  jmp <+0x20>
+   c7a:  mov r8b,0x47
+   c7d:  cmp r9b,0x4f
+   c81:  jb <+0x20>
+   c87:  cmp r9d,0x4f
+   c8b:  setne r10b
+   c8f:  cmp r9b,0x53
+   c93:  setb r11b
+   c97:  and r11b,r10b
+   c9a:  lea r10d,[r9-0x54]
+   c9e:  cmp r10b,0x1b
+   ca2:  setb r10b
+   ca6:  or r10b,r11b
+   ca9:  jne <+0x20>
+   caf:  lea r10d,[r9-0x70]
+   cb3:  cmp r10b,0x3
+   cb7:  setb r10b
+   cbb:  cmp r9b,0x74
+   cbf:  setae r11b
+   cc3:  or r11b,r10b
+   cc6:  jne <+0x20>
+   ccc:  add r9b,0xb1
+   cd0:  rol r9b,0x6
+   cd4:  movzx r8d,r9b
+   cd8:  lea r9,[rip]
+   cdf:  movzx r8d,BYTE PTR [r8+r9*1]
+   ce4:  jmp <+0x20>
+   ce9:  mov r8b,0x51
+   cec:  jmp <+0x20>
+   cf1:  mov r8b,0xb
+   cf4:  cmp r9b,0x41
+   cf8:  jb <+0x20>
+   cfe:  cmp r9d,0x41
+   d02:  setne r8b
+   d06:  cmp r9b,0x61
+   d0a:  setb r10b
+   d0e:  and r10b,r8b
+   d11:  cmp r9b,0x62
+   d15:  setae r9b
+   d19:  or r9b,r10b
+   d1c:  mov r8b,0xc
+   d1f:  sub r8b,r9b
+   d22:  jmp <+0x20>
+   d27:  add r9b,0xb2
+   d2b:  xor r10d,r10d
+   d2e:  test r9b,0xdf
+   d32:  mov r8d,0x5f
+   d38:  cmovne r8d,r10d
+   d3c:  jmp <+0x20>
+   d41:  mov r8b,0x5b
+   d44:  cmp r9b,0x53
+   d48:  jb <+0x20>
+   d4e:  cmp r9d,0x53
+   d52:  setne r8b
+   d56:  cmp r9b,0x73
+   d5a:  setb r10b
+   d5e:  and r10b,r8b
+   d61:  cmp r9b,0x74
+   d65:  setae r9b
+   d69:  or r9b,r10b
+   d6c:  mov r8b,0x5c
+   d6f:  sub r8b,r9b
+   d72:  jmp <+0x20>
+   d77:  mov r8b,0x61
+   d7a:  cmp r9b,0x53
+   d7e:  jb <+0x20>
+   d84:  cmp r9d,0x53
+   d88:  setne r8b
+   d8c:  cmp r9b,0x73
+   d90:  setb r10b
+   d94:  and r10b,r8b
+   d97:  cmp r9b,0x74
+   d9b:  setae r9b
+   d9f:  or r9b,r10b
+   da2:  mov r8b,0x62
+   da5:  sub r8b,r9b
+   da8:  jmp <+0x20>
+   dad:  add r9b,0xb8
+   db1:  xor r10d,r10d
+   db4:  test r9b,0xdf
+   db8:  mov r8d,0x58
+   dbe:  cmovne r8d,r10d
+   dc2:  jmp <+0x20>
+   dc7:  add r9b,0xbd
+   dcb:  xor r10d,r10d
+   dce:  test r9b,0xdf
+   dd2:  mov r8d,0x2f
+   dd8:  cmovne r8d,r10d
+   ddc:  jmp <+0x20>
+   de1:  mov r8b,0x54
+   de4:  jmp <+0x20>
+   de9:  add r9b,0xad
+   ded:  xor r10d,r10d
+   df0:  test r9b,0xdf
+   df4:  mov r8d,0x18
+   dfa:  cmovne r8d,r10d
+   dfe:  jmp <+0x20>
+   e03:  mov r8b,0x22
+   e06:  cmp r9b,0x53
+   e0a:  jb <+0x20>
+   e10:  cmp r9d,0x53
+   e14:  setne r8b
+   e18:  cmp r9b,0x73
+   e1c:  setb r10b
+   e20:  and r10b,r8b
+   e23:  cmp r9b,0x74
+   e27:  setae r8b
+   e2b:  or r8b,r10b
+   e2e:  xor r8b,0x23
+   e32:  jmp <+0x20>
+   e37:  xor r8d,r8d
+   e3a:  add r9d,0xffffffbd
+   e3e:  cmp r9d,0x2b
+   e42:  ja <+0x20>
+   e48:  movabs r10,0x100000001
+   e52:  bt r10,r9
+   e56:  jb <+0xeac>
+   e58:  movabs r10,0x20000000200
+   e62:  bt r10,r9
+   e66:  jb <+0xea4>
+   e68:  movabs r10,0x80000000800
+   e72:  bt r10,r9
+   e76:  jae <+0x20>
+   e7c:  mov r8b,0x24
+   e7f:  jmp <+0x20>
+   e84:  cmp r9d,0x61
+   e88:  je <+0xe9c>
+   e8a:  cmp r9d,0x73
+   e8e:  jne <+0x20>
+   e94:  mov r8b,0x15
+   e97:  jmp <+0x20>
+   e9c:  mov r8b,0x14
+   e9f:  jmp <+0x20>
+   ea4:  mov r8b,0x25
+   ea7:  jmp <+0x20>
+   eac:  mov r8b,0x26
+   eaf:  jmp <+0x20>
+   eb4:  mov r8b,0x3e
+   eb7:  jmp <+0x20>
+   ebc:  mov r8b,0x6
+   ebf:  jmp <+0x20>
+   ec4:  mov r8b,0x7
+   ec7:  jmp <+0x20>
+   ecc:  mov r8b,0x4
+   ecf:  jmp <+0x20>
+   ed4:  mov r8b,0x3
+   ed7:  jmp <+0x20>
+   edc:  mov r8b,0x8
+   edf:  jmp <+0x20>
+   ee4:  mov r8b,0x5
+   ee7:  jmp <+0x20>
+   eec:  mov r8b,0x9
+   eef:  jmp <+0x20>
+   ef4:  cmp r9d,0x65
+   ef8:  je <+0x20>
+   efe:  mov r8b,0x1e
+   f01:  jmp <+0x20>
+   f06:  mov r8b,0xa
+   f09:  jmp <+0x20>
+   f0e:  mov r8b,0x13
+   f11:  jmp <+0x20>
+   f16:  movzx r8d,r8b
+   f1a:  add r8d,0xfffffffe
+   f1e:  cmp r8d,0x98
+   f25:  ja <+0xf51>
+   f27:  mov ecx,0x6
+   f2c:  mov eax,0x1
+   f31:  lea r9,[rip]
+   f38:  movsxd r8,DWORD PTR [r9+r8*4]
+   f3c:  add r8,r9
+   f3f:  jmp r8
+   f42:  mov ecx,0x1
+   f47:  mov eax,0x5
+   f4c:  jmp <+0x1176>
+   f51:  lea rax,[rdi+0x8]
+   f55:  mov rbx,rdi
+   f58:  mov rdi,rax
+   f5b:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+   f61:  mov rdi,rbx
+   f64:  mov eax,0x1
+   f69:  mov QWORD PTR [rdi],rax
+   f6c:  mov rax,rdi
+   f6f:  pop rbx
+   f70:  ret
+   f71:  mov ecx,0x1
+   f76:  mov eax,0x6
+   f7b:  jmp <+0x1176>
+   f80:  mov ecx,0x2
+   f85:  mov eax,0x5
+   f8a:  jmp <+0x1176>
+   f8f:  xor ecx,ecx
+   f91:  mov eax,0x5
+   f96:  jmp <+0x1176>
+   f9b:  xor ecx,ecx
+   f9d:  mov eax,0x4
+   fa2:  jmp <+0x1176>
+   fa7:  mov ecx,0x1
+   fac:  jmp <+0x1171>
+   fb1:  mov ecx,0x4
+   fb6:  mov eax,0x3
+   fbb:  jmp <+0x1176>
+   fc0:  xor ecx,ecx
+   fc2:  jmp <+0x1171>
+   fc7:  mov ecx,0x2
+   fcc:  jmp <+0x1171>
+   fd1:  mov ecx,0x7
+   fd6:  mov eax,0x3
+   fdb:  jmp <+0x1176>
+   fe0:  mov ecx,0x7
+   fe5:  jmp <+0x1171>
+   fea:  mov ecx,0x3
+   fef:  mov eax,0x4
+   ff4:  jmp <+0x1176>
+   ff9:  mov ecx,0x7
+   ffe:  mov eax,0x4
+  1003:  jmp <+0x1176>
+  1008:  mov ecx,0x4
+  100d:  mov eax,0x6
+  1012:  jmp <+0x1176>
+  1017:  mov ecx,0x1
+  101c:  mov eax,0x3
+  1021:  jmp <+0x1176>
+  1026:  mov ecx,0x8
+  102b:  mov eax,0x6
+  1030:  jmp <+0x1176>
+  1035:  mov ecx,0x3
+  103a:  mov eax,0x3
+  103f:  jmp <+0x1176>
+  1044:  mov ecx,0x9
+  1049:  jmp <+0x1171>
+  104e:  mov ecx,0x5
+  1053:  mov eax,0x4
+  1058:  jmp <+0x1176>
+  105d:  mov ecx,0x8
+  1062:  mov eax,0x5
+  1067:  jmp <+0x1176>
+  106c:  mov ecx,0x5
+  1071:  mov eax,0x5
+  1076:  jmp <+0x1176>
+  107b:  mov ecx,0x1
+  1080:  mov eax,0xb
+  1085:  jmp <+0x1176>
+  108a:  mov ecx,0x9
+  108f:  mov eax,0x5
+  1094:  jmp <+0x1176>
+  1099:  mov ecx,0x3
+  109e:  mov eax,0x7
+  10a3:  jmp <+0x1176>
+  10a8:  mov ecx,0x1
+  10ad:  mov eax,0xc
+  10b2:  jmp <+0x1176>
+  10b7:  mov ecx,0x5
+  10bc:  mov eax,0x3
+  10c1:  jmp <+0x1176>
+  10c6:  mov ecx,0x9
+  10cb:  mov eax,0x3
+  10d0:  jmp <+0x1176>
+  10d5:  mov ecx,0x2
+  10da:  mov eax,0x6
+  10df:  jmp <+0x1176>
+  10e4:  mov ecx,0x2
+  10e9:  mov eax,0xc
+  10ee:  jmp <+0x1176>
+  10f3:  mov ecx,0x9
+  10f8:  mov eax,0x4
+  10fd:  jmp <+0x1176>
+  10ff:  mov ecx,0x2
+  1104:  mov eax,0x4
+  1109:  jmp <+0x1176>
+  110b:  mov ecx,0x2
+  1110:  mov eax,0xb
+  1115:  jmp <+0x1176>
+  1117:  mov ecx,0x3
+  111c:  mov eax,0x6
+  1121:  jmp <+0x1176>
+  1123:  xor ecx,ecx
+  1125:  mov eax,0xa
+  112a:  jmp <+0x1176>
+  112c:  mov ecx,0x4
+  1131:  mov eax,0x7
+  1136:  jmp <+0x1176>
+  1138:  xor ecx,ecx
+  113a:  mov eax,0xb
+  113f:  jmp <+0x1176>
+  1141:  mov ecx,0x1
+  1146:  mov eax,0x4
+  114b:  jmp <+0x1176>
+  114d:  mov ecx,0x8
+  1152:  mov eax,0x3
+  1157:  jmp <+0x1176>
+  1159:  mov ecx,0x4
+  115e:  mov eax,0x4
+  1163:  jmp <+0x1176>
+  1165:  mov ecx,0x5
+  116a:  jmp <+0x1171>
+  116c:  mov ecx,0x8
+  1171:  mov eax,0x2
+  1176:  mov r8,rdx
+  1179:  sub r8,rax
+  117c:  jb <+0x1249>
+  1182:  add rsi,rax
+  1185:  mov DWORD PTR [rdi+0x8],ecx
+  1188:  mov QWORD PTR [rdi+0x10],rsi
+  118c:  mov QWORD PTR [rdi+0x18],r8
+  1190:  xor eax,eax
+  1192:  mov QWORD PTR [rdi],rax
+  1195:  mov rax,rdi
+  1198:  pop rbx
+  1199:  ret
+  119a:  mov ecx,0x7
+  119f:  mov eax,0x5
+  11a4:  jmp <+0x1176>
+  11a6:  mov eax,0x3
+  11ab:  jmp <+0x1176>
+  11ad:  mov ecx,0x9
+  11b2:  jmp <+0x1176>
+  11b4:  mov ecx,0x7
+  11b9:  jmp <+0x1176>
+  11bb:  mov ecx,0x3
+  11c0:  jmp <+0x1176>
+  11c2:  mov ecx,0x5
+  11c7:  jmp <+0x1176>
+  11c9:  mov eax,0x4
+  11ce:  jmp <+0x1176>
+  11d0:  mov ecx,0x4
+  11d5:  jmp <+0x1176>
+  11d7:  mov ecx,0x5
+  11dc:  mov eax,0x1
+  11e1:  jmp <+0x1176>
+  11e3:  mov ecx,0x7
+  11e8:  mov eax,0x1
+  11ed:  jmp <+0x1176>
+  11ef:  mov ecx,0x9
+  11f4:  mov eax,0x1
+  11f9:  jmp <+0x1176>
+  11fe:  mov ecx,0x6
+  1203:  mov eax,0x3
+  1208:  jmp <+0x1176>
+  120d:  mov ecx,0x4
+  1212:  mov eax,0x1
+  1217:  jmp <+0x1176>
+  121c:  mov ecx,0x6
+  1221:  mov eax,0x4
+  1226:  jmp <+0x1176>
+  122b:  mov ecx,0x6
+  1230:  mov eax,0x1
+  1235:  jmp <+0x1176>
+  123a:  mov ecx,0x3
+  123f:  mov eax,0x1
+  1244:  jmp <+0x1176>
+  1249:  lea rcx,[rip]
+  1250:  mov rdi,rax
+  1253:  mov rsi,rdx
+  1256:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
+  125c:  ud2
@@ -0,0 +1,369 @@
+duration_unit_lookup::by_gendfa2::lookup:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0x63d>
+     a:  movzx ecx,BYTE PTR [rsi]
+     d:  lea eax,[rcx-0x64]
+    10:  cmp eax,0x15
+    13:  ja <+0x78>
+    15:  lea rcx,[rip]
+    1c:  movsxd rax,DWORD PTR [rcx+rax*4]
+    20:  add rax,rcx
+    23:  jmp rax
+    25:  mov ecx,0x6
+    2a:  mov eax,0x1
+    2f:  cmp rdx,0x1
+    33:  je <+0x511>
+    39:  cmp BYTE PTR [rsi+0x1],0x61
+    3d:  jne <+0x511>
+    43:  cmp rdx,0x3
+    47:  jb <+0x63d>
+    4d:  cmp BYTE PTR [rsi+0x2],0x79
+    51:  jne <+0x63d>
+    57:  mov eax,0x3
+    5c:  cmp rdx,0x3
+    60:  je <+0x511>
+    66:  xor eax,eax
+    68:  cmp BYTE PTR [rsi+0x3],0x73
+    6c:  sete al
+    6f:  add rax,0x3
+    73:  jmp <+0x511>
+    78:  cmp ecx,0xc2
+    7e:  jne <+0x63d>
+    84:  cmp rdx,0x1
+    88:  je <+0x63d>
+    8e:  cmp BYTE PTR [rsi+0x1],0xb5
+    92:  jne <+0x63d>
+    98:  cmp rdx,0x3
+    9c:  jb <+0x63d>
+    a2:  cmp BYTE PTR [rsi+0x2],0x73
+    a6:  jne <+0x63d>
+    ac:  mov ecx,0x1
+    b1:  mov eax,0x3
+    b6:  cmp rdx,0x3
+    ba:  je <+0x511>
+    c0:  cmp BYTE PTR [rsi+0x3],0x65
+    c4:  jne <+0x511>
+    ca:  cmp rdx,0x5
+    ce:  jb <+0x63d>
+    d4:  cmp BYTE PTR [rsi+0x4],0x63
+    d8:  jne <+0x63d>
+    de:  mov eax,0x5
+    e3:  cmp rdx,0x5
+    e7:  je <+0x511>
+    ed:  xor eax,eax
+    ef:  cmp BYTE PTR [rsi+0x5],0x73
+    f3:  sete al
+    f6:  add rax,0x5
+    fa:  jmp <+0x511>
+    ff:  cmp rdx,0x1
+   103:  je <+0x63d>
+   109:  movzx eax,BYTE PTR [rsi+0x1]
+   10d:  cmp eax,0x73
+   110:  je <+0x467>
+   116:  cmp eax,0x61
+   119:  jne <+0x63d>
+   11f:  cmp rdx,0x2
+   123:  jbe <+0x63d>
+   129:  cmp rdx,0x3
+   12d:  je <+0x63d>
+   133:  cmp BYTE PTR [rsi+0x2],0x6e
+   137:  jne <+0x63d>
+   13d:  cmp BYTE PTR [rsi+0x3],0x6f
+   141:  jne <+0x63d>
+   147:  xor ecx,ecx
+   149:  mov eax,0x4
+   14e:  cmp rdx,0x5
+   152:  jb <+0x511>
+   158:  cmp BYTE PTR [rsi+0x4],0x73
+   15c:  jne <+0x511>
+   162:  mov eax,0x5
+   167:  cmp rdx,0x5
+   16b:  je <+0x511>
+   171:  cmp BYTE PTR [rsi+0x5],0x65
+   175:  jne <+0x511>
+   17b:  cmp rdx,0x7
+   17f:  jb <+0x63d>
+   185:  je <+0x63d>
+   18b:  cmp BYTE PTR [rsi+0x6],0x63
+   18f:  jne <+0x63d>
+   195:  cmp BYTE PTR [rsi+0x7],0x6f
+   199:  jne <+0x63d>
+   19f:  cmp rdx,0x9
+   1a3:  jb <+0x63d>
+   1a9:  je <+0x63d>
+   1af:  cmp BYTE PTR [rsi+0x8],0x6e
+   1b3:  jne <+0x63d>
+   1b9:  cmp BYTE PTR [rsi+0x9],0x64
+   1bd:  jne <+0x63d>
+   1c3:  mov eax,0xa
+   1c8:  cmp rdx,0xb
+   1cc:  jb <+0x511>
+   1d2:  xor eax,eax
+   1d4:  cmp BYTE PTR [rsi+0xa],0x73
+   1d8:  sete al
+   1db:  or rax,0xa
+   1df:  jmp <+0x511>
+   1e4:  mov ecx,0x9
+   1e9:  mov eax,0x1
+   1ee:  cmp rdx,0x1
+   1f2:  je <+0x511>
+   1f8:  movzx r8d,BYTE PTR [rsi+0x1]
+   1fd:  cmp r8d,0x72
+   201:  je <+0x446>
+   207:  cmp r8d,0x65
+   20b:  jne <+0x511>
+   211:  cmp rdx,0x2
+   215:  jbe <+0x63d>
+   21b:  cmp rdx,0x3
+   21f:  je <+0x63d>
+   225:  cmp BYTE PTR [rsi+0x2],0x61
+   229:  je <+0x40a>
+   22f:  jmp <+0x63d>
+   234:  mov ecx,0x7
+   239:  mov eax,0x1
+   23e:  cmp rdx,0x1
+   242:  je <+0x511>
+   248:  movzx r8d,BYTE PTR [rsi+0x1]
+   24d:  cmp r8d,0x6b
+   251:  je <+0x446>
+   257:  cmp r8d,0x65
+   25b:  jne <+0x511>
+   261:  cmp rdx,0x2
+   265:  jbe <+0x63d>
+   26b:  cmp rdx,0x3
+   26f:  je <+0x63d>
+   275:  cmp BYTE PTR [rsi+0x2],0x65
+   279:  jne <+0x63d>
+   27f:  cmp BYTE PTR [rsi+0x3],0x6b
+   283:  je <+0x4f9>
+   289:  jmp <+0x63d>
+   28e:  mov ecx,0x3
+   293:  mov eax,0x1
+   298:  cmp rdx,0x1
+   29c:  je <+0x511>
+   2a2:  cmp BYTE PTR [rsi+0x1],0x65
+   2a6:  jne <+0x511>
+   2ac:  cmp rdx,0x3
+   2b0:  jb <+0x63d>
+   2b6:  cmp BYTE PTR [rsi+0x2],0x63
+   2ba:  jne <+0x63d>
+   2c0:  mov eax,0x3
+   2c5:  cmp rdx,0x3
+   2c9:  je <+0x511>
+   2cf:  movzx r8d,BYTE PTR [rsi+0x3]
+   2d4:  cmp r8d,0x73
+   2d8:  je <+0x3b9>
+   2de:  cmp r8d,0x6f
+   2e2:  jne <+0x511>
+   2e8:  cmp rdx,0x4
+   2ec:  jbe <+0x63d>
+   2f2:  cmp rdx,0x5
+   2f6:  je <+0x63d>
+   2fc:  cmp BYTE PTR [rsi+0x4],0x6e
+   300:  jne <+0x63d>
+   306:  cmp BYTE PTR [rsi+0x5],0x64
+   30a:  jne <+0x63d>
+   310:  mov eax,0x6
+   315:  cmp rdx,0x7
+   319:  jb <+0x511>
+   31f:  xor eax,eax
+   321:  cmp BYTE PTR [rsi+0x6],0x73
+   325:  sete al
+   328:  or rax,0x6
+   32c:  jmp <+0x511>
+   331:  mov ecx,0x4
+   336:  mov eax,0x1
+   33b:  cmp rdx,0x1
+   33f:  je <+0x511>
+   345:  movzx r8d,BYTE PTR [rsi+0x1]
+   34a:  cmp r8d,0x73
+   34e:  je <+0x4cf>
+   354:  cmp r8d,0x6f
+   358:  je <+0x479>
+   35e:  cmp r8d,0x69
+   362:  jne <+0x511>
+   368:  cmp rdx,0x2
+   36c:  jbe <+0x63d>
+   372:  movzx eax,BYTE PTR [rsi+0x2]
+   376:  cmp eax,0x63
+   379:  je <+0x535>
+   37f:  cmp eax,0x6c
+   382:  je <+0x56f>
+   388:  cmp eax,0x6e
+   38b:  jne <+0x63d>
+   391:  mov eax,0x3
+   396:  cmp rdx,0x3
+   39a:  je <+0x511>
+   3a0:  movzx r8d,BYTE PTR [rsi+0x3]
+   3a5:  cmp r8d,0x75
+   3a9:  je <+0x625>
+   3af:  cmp r8d,0x73
+   3b3:  jne <+0x511>
+   3b9:  mov eax,0x4
+   3be:  jmp <+0x511>
+   3c3:  mov ecx,0x5
+   3c8:  mov eax,0x1
+   3cd:  cmp rdx,0x1
+   3d1:  je <+0x511>
+   3d7:  movzx r8d,BYTE PTR [rsi+0x1]
+   3dc:  cmp r8d,0x72
+   3e0:  je <+0x446>
+   3e2:  cmp r8d,0x6f
+   3e6:  jne <+0x511>
+   3ec:  cmp rdx,0x2
+   3f0:  jbe <+0x63d>
+   3f6:  cmp rdx,0x3
+   3fa:  je <+0x63d>
+   400:  cmp BYTE PTR [rsi+0x2],0x75
+   404:  jne <+0x63d>
+   40a:  cmp BYTE PTR [rsi+0x3],0x72
+   40e:  je <+0x4f9>
+   414:  jmp <+0x63d>
+   419:  cmp rdx,0x1
+   41d:  je <+0x63d>
+   423:  cmp BYTE PTR [rsi+0x1],0x73
+   427:  jne <+0x63d>
+   42d:  mov ecx,0x1
+   432:  mov eax,0x2
+   437:  cmp rdx,0x3
+   43b:  jae <+0x4df>
+   441:  jmp <+0x511>
+   446:  mov eax,0x2
+   44b:  cmp rdx,0x2
+   44f:  jbe <+0x511>
+   455:  xor eax,eax
+   457:  cmp BYTE PTR [rsi+0x2],0x73
+   45b:  sete al
+   45e:  or rax,0x2
+   462:  jmp <+0x511>
+   467:  xor ecx,ecx
+   469:  mov eax,0x2
+   46e:  cmp rdx,0x2
+   472:  ja <+0x4df>
+   474:  jmp <+0x511>
+   479:  mov ecx,0x8
+   47e:  mov eax,0x2
+   483:  cmp rdx,0x2
+   487:  jbe <+0x511>
+   48d:  movzx r8d,BYTE PTR [rsi+0x2]
+   492:  cmp r8d,0x73
+   496:  je <+0x61b>
+   49c:  cmp r8d,0x6e
+   4a0:  jne <+0x511>
+   4a2:  cmp rdx,0x3
+   4a6:  je <+0x63d>
+   4ac:  cmp BYTE PTR [rsi+0x3],0x74
+   4b0:  jne <+0x63d>
+   4b6:  cmp rdx,0x5
+   4ba:  jb <+0x63d>
+   4c0:  cmp BYTE PTR [rsi+0x4],0x68
+   4c4:  je <+0xde>
+   4ca:  jmp <+0x63d>
+   4cf:  mov ecx,0x2
+   4d4:  mov eax,0x2
+   4d9:  cmp rdx,0x2
+   4dd:  jbe <+0x511>
+   4df:  cmp BYTE PTR [rsi+0x2],0x65
+   4e3:  jne <+0x511>
+   4e5:  cmp rdx,0x3
+   4e9:  je <+0x63d>
+   4ef:  cmp BYTE PTR [rsi+0x3],0x63
+   4f3:  jne <+0x63d>
+   4f9:  mov eax,0x4
+   4fe:  cmp rdx,0x5
+   502:  jb <+0x511>
+   504:  xor eax,eax
+   506:  cmp BYTE PTR [rsi+0x4],0x73
+   50a:  sete al
+   50d:  or rax,0x4
+   511:  mov r8,rdx
+   514:  sub r8,rax
+   517:  jb <+0x65d>
+   51d:  add rsi,rax
+   520:  mov DWORD PTR [rdi+0x8],ecx
+   523:  mov QWORD PTR [rdi+0x10],rsi
+   527:  mov QWORD PTR [rdi+0x18],r8
+   52b:  xor eax,eax
+   52d:  mov QWORD PTR [rdi],rax
+   530:  mov rax,rdi
+   533:  pop rbx
+   534:  ret
+   535:  cmp rdx,0x3
+   539:  je <+0x63d>
+   53f:  cmp BYTE PTR [rsi+0x3],0x72
+   543:  jne <+0x63d>
+   549:  cmp rdx,0x5
+   54d:  jb <+0x63d>
+   553:  cmp BYTE PTR [rsi+0x4],0x6f
+   557:  jne <+0x63d>
+   55d:  mov ecx,0x1
+   562:  mov eax,0x5
+   567:  cmp rdx,0x5
+   56b:  je <+0x511>
+   56d:  jmp <+0x5ab>
+   56f:  cmp rdx,0x3
+   573:  je <+0x63d>
+   579:  cmp BYTE PTR [rsi+0x3],0x6c
+   57d:  jne <+0x63d>
+   583:  cmp rdx,0x5
+   587:  jb <+0x63d>
+   58d:  cmp BYTE PTR [rsi+0x4],0x69
+   591:  jne <+0x63d>
+   597:  mov ecx,0x2
+   59c:  mov eax,0x5
+   5a1:  cmp rdx,0x5
+   5a5:  je <+0x511>
+   5ab:  cmp BYTE PTR [rsi+0x5],0x73
+   5af:  jne <+0x511>
+   5b5:  mov eax,0x6
+   5ba:  cmp rdx,0x7
+   5be:  jb <+0x511>
+   5c4:  cmp BYTE PTR [rsi+0x6],0x65
+   5c8:  jne <+0x511>
+   5ce:  cmp rdx,0x7
+   5d2:  je <+0x63d>
+   5d4:  cmp BYTE PTR [rsi+0x7],0x63
+   5d8:  jne <+0x63d>
+   5da:  cmp rdx,0x9
+   5de:  jb <+0x63d>
+   5e0:  je <+0x63d>
+   5e2:  cmp BYTE PTR [rsi+0x8],0x6f
+   5e6:  jne <+0x63d>
+   5e8:  cmp BYTE PTR [rsi+0x9],0x6e
+   5ec:  jne <+0x63d>
+   5ee:  cmp rdx,0xb
+   5f2:  jb <+0x63d>
+   5f4:  cmp BYTE PTR [rsi+0xa],0x64
+   5f8:  jne <+0x63d>
+   5fa:  mov eax,0xb
+   5ff:  cmp rdx,0xb
+   603:  je <+0x511>
+   609:  xor eax,eax
+   60b:  cmp BYTE PTR [rsi+0xb],0x73
+   60f:  sete al
+   612:  add rax,0xb
+   616:  jmp <+0x511>
+   61b:  mov eax,0x3
+   620:  jmp <+0x511>
+   625:  cmp rdx,0x5
+   629:  jb <+0x63d>
+   62b:  je <+0x63d>
+   62d:  cmp BYTE PTR [rsi+0x4],0x74
+   631:  jne <+0x63d>
+   633:  cmp BYTE PTR [rsi+0x5],0x65
+   637:  je <+0x310>
+   63d:  lea rax,[rdi+0x8]
+   641:  mov rbx,rdi
+   644:  mov rdi,rax
+   647:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+   64d:  mov rdi,rbx
+   650:  mov eax,0x1
+   655:  mov QWORD PTR [rdi],rax
+   658:  mov rax,rdi
+   65b:  pop rbx
+   65c:  ret
+   65d:  lea rcx,[rip]
+   664:  mov rdi,rax
+   667:  mov rsi,rdx
+   66a:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
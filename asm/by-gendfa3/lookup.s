@@ -0,0 +1,411 @@
+duration_unit_lookup::by_gendfa3::lookup:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0xf6>
+     a:  movzx ecx,BYTE PTR [rsi]
+     d:  lea eax,[rcx-0x64]
+    10:  cmp eax,0x15
+    13:  ja <+0x78>
+    15:  lea rcx,[rip]
+    1c:  movsxd rax,DWORD PTR [rcx+rax*4]
+    20:  add rax,rcx
+    23:  jmp rax
+    25:  mov eax,0x1
+    2a:  mov ecx,0x6
+    2f:  cmp rdx,0x1
+    33:  je <+0x3ad>
+    39:  cmp BYTE PTR [rsi+0x1],0x61
+    3d:  jne <+0x3ad>
+    43:  cmp rdx,0x3
+    47:  jb <+0xf6>
+    4d:  cmp BYTE PTR [rsi+0x2],0x79
+    51:  jne <+0xf6>
+    57:  mov eax,0x3
+    5c:  cmp rdx,0x3
+    60:  je <+0x3ad>
+    66:  xor eax,eax
+    68:  cmp BYTE PTR [rsi+0x3],0x73
+    6c:  sete al
+    6f:  add rax,0x3
+    73:  jmp <+0x3ad>
+    78:  cmp ecx,0xc2
+    7e:  jne <+0xf6>
+    80:  cmp rdx,0x1
+    84:  je <+0xf6>
+    86:  cmp BYTE PTR [rsi+0x1],0xb5
+    8a:  sete al
+    8d:  cmp rdx,0x3
+    91:  setae cl
+    94:  and cl,al
+    96:  cmp cl,0x1
+    99:  jne <+0xf6>
+    9b:  cmp BYTE PTR [rsi+0x2],0x73
+    9f:  jne <+0xf6>
+    a1:  mov eax,0x3
+    a6:  mov ecx,0x1
+    ab:  cmp rdx,0x3
+    af:  je <+0x3ad>
+    b5:  cmp BYTE PTR [rsi+0x3],0x65
+    b9:  jne <+0x3ad>
+    bf:  cmp rdx,0x5
+    c3:  jb <+0xf6>
+    c5:  cmp BYTE PTR [rsi+0x4],0x63
+    c9:  jne <+0xf6>
+    cb:  mov eax,0x5
+    d0:  cmp rdx,0x5
+    d4:  je <+0x3ad>
+    da:  xor eax,eax
+    dc:  cmp BYTE PTR [rsi+0x5],0x73
+    e0:  sete al
+    e3:  add rax,0x5
+    e7:  jmp <+0x3ad>
+    ec:  cmp rdx,0x1
+    f0:  jne <+0x45f>
+    f6:  lea rax,[rdi+0x8]
+    fa:  mov rbx,rdi
+    fd:  mov rdi,rax
+   100:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+   106:  mov rdi,rbx
+   109:  mov eax,0x1
+   10e:  mov QWORD PTR [rdi],rax
+   111:  mov rax,rdi
+   114:  pop rbx
+   115:  ret
+   116:  mov eax,0x1
+   11b:  mov ecx,0x9
+   120:  cmp rdx,0x1
+   124:  je <+0x3ad>
+   12a:  movzx r8d,BYTE PTR [rsi+0x1]
+   12f:  cmp r8d,0x72
+   133:  je <+0x395>
+   139:  cmp r8d,0x65
+   13d:  jne <+0x3ad>
+   143:  cmp rdx,0x2
+   147:  je <+0xf6>
+   149:  cmp BYTE PTR [rsi+0x2],0x61
+   14d:  je <+0x328>
+   153:  jmp <+0xf6>
+   155:  mov eax,0x1
+   15a:  mov ecx,0x7
+   15f:  cmp rdx,0x1
+   163:  je <+0x3ad>
+   169:  movzx r8d,BYTE PTR [rsi+0x1]
+   16e:  cmp r8d,0x6b
+   172:  je <+0x395>
+   178:  cmp r8d,0x65
+   17c:  jne <+0x3ad>
+   182:  cmp rdx,0x2
+   186:  je <+0xf6>
+   18c:  cmp BYTE PTR [rsi+0x2],0x65
+   190:  jne <+0xf6>
+   196:  cmp rdx,0x4
+   19a:  jb <+0xf6>
+   1a0:  cmp BYTE PTR [rsi+0x3],0x6b
+   1a4:  je <+0x37b>
+   1aa:  jmp <+0xf6>
+   1af:  mov eax,0x1
+   1b4:  mov ecx,0x3
+   1b9:  cmp rdx,0x1
+   1bd:  je <+0x3ad>
+   1c3:  cmp BYTE PTR [rsi+0x1],0x65
+   1c7:  jne <+0x3ad>
+   1cd:  cmp rdx,0x2
+   1d1:  je <+0xf6>
+   1d7:  cmp BYTE PTR [rsi+0x2],0x63
+   1db:  jne <+0xf6>
+   1e1:  mov eax,0x3
+   1e6:  cmp rdx,0x4
+   1ea:  jb <+0x3ad>
+   1f0:  movzx r8d,BYTE PTR [rsi+0x3]
+   1f5:  cmp r8d,0x73
+   1f9:  je <+0x438>
+   1ff:  cmp r8d,0x6f
+   203:  jne <+0x3ad>
+   209:  cmp rdx,0x4
+   20d:  je <+0xf6>
+   213:  cmp BYTE PTR [rsi+0x4],0x6e
+   217:  jne <+0xf6>
+   21d:  cmp rdx,0x6
+   221:  jb <+0xf6>
+   227:  cmp BYTE PTR [rsi+0x5],0x64
+   22b:  jne <+0xf6>
+   231:  mov eax,0x6
+   236:  cmp rdx,0x6
+   23a:  je <+0x3ad>
+   240:  xor eax,eax
+   242:  cmp BYTE PTR [rsi+0x6],0x73
+   246:  sete al
+   249:  or rax,0x6
+   24d:  jmp <+0x3ad>
+   252:  mov eax,0x1
+   257:  mov ecx,0x4
+   25c:  cmp rdx,0x1
+   260:  je <+0x3ad>
+   266:  movzx r8d,BYTE PTR [rsi+0x1]
+   26b:  cmp r8d,0x73
+   26f:  je <+0x41f>
+   275:  cmp r8d,0x6f
+   279:  je <+0x3d1>
+   27f:  cmp r8d,0x69
+   283:  jne <+0x3ad>
+   289:  cmp rdx,0x3
+   28d:  jb <+0xf6>
+   293:  mov r8,rdi
+   296:  movzx eax,BYTE PTR [rsi+0x2]
+   29a:  cmp eax,0x63
+   29d:  je <+0x5a0>
+   2a3:  cmp eax,0x6c
+   2a6:  je <+0x5e1>
+   2ac:  cmp eax,0x6e
+   2af:  mov rdi,r8
+   2b2:  jne <+0xf6>
+   2b8:  mov eax,0x3
+   2bd:  cmp rdx,0x3
+   2c1:  je <+0x6e8>
+   2c7:  movzx edi,BYTE PTR [rsi+0x3]
+   2cb:  cmp edi,0x75
+   2ce:  je <+0x6b2>
+   2d4:  cmp edi,0x73
+   2d7:  jne <+0x6e8>
+   2dd:  mov eax,0x4
+   2e2:  jmp <+0x6e8>
+   2e7:  mov eax,0x1
+   2ec:  mov ecx,0x5
+   2f1:  cmp rdx,0x1
+   2f5:  je <+0x3ad>
+   2fb:  movzx r8d,BYTE PTR [rsi+0x1]
+   300:  cmp r8d,0x72
+   304:  je <+0x395>
+   30a:  cmp r8d,0x6f
+   30e:  jne <+0x3ad>
+   314:  cmp rdx,0x2
+   318:  je <+0xf6>
+   31e:  cmp BYTE PTR [rsi+0x2],0x75
+   322:  jne <+0xf6>
+   328:  cmp rdx,0x4
+   32c:  jb <+0xf6>
+   332:  cmp BYTE PTR [rsi+0x3],0x72
+   336:  je <+0x37b>
+   338:  jmp <+0xf6>
+   33d:  cmp rdx,0x1
+   341:  je <+0xf6>
+   347:  cmp BYTE PTR [rsi+0x1],0x73
+   34b:  jne <+0xf6>
+   351:  mov eax,0x2
+   356:  mov ecx,0x1
+   35b:  cmp rdx,0x2
+   35f:  je <+0x3ad>
+   361:  cmp BYTE PTR [rsi+0x2],0x65
+   365:  jne <+0x3ad>
+   367:  cmp rdx,0x4
+   36b:  jb <+0xf6>
+   371:  cmp BYTE PTR [rsi+0x3],0x63
+   375:  jne <+0xf6>
+   37b:  mov eax,0x4
+   380:  cmp rdx,0x4
+   384:  je <+0x3ad>
+   386:  xor eax,eax
+   388:  cmp BYTE PTR [rsi+0x4],0x73
+   38c:  sete al
+   38f:  or rax,0x4
+   393:  jmp <+0x3ad>
+   395:  mov eax,0x2
+   39a:  cmp rdx,0x2
+   39e:  je <+0x3ad>
+   3a0:  xor eax,eax
+   3a2:  cmp BYTE PTR [rsi+0x2],0x73
+   3a6:  sete al
+   3a9:  or rax,0x2
+   3ad:  mov r8,rdx
+   3b0:  sub r8,rax
+   3b3:  jb <+0x44c>
+   3b9:  add rsi,rax
+   3bc:  mov DWORD PTR [rdi+0x8],ecx
+   3bf:  mov QWORD PTR [rdi+0x10],rsi
+   3c3:  mov QWORD PTR [rdi+0x18],r8
+   3c7:  xor eax,eax
+   3c9:  mov QWORD PTR [rdi],rax
+   3cc:  mov rax,rdi
+   3cf:  pop rbx
+   3d0:  ret
+   3d1:  mov eax,0x2
+   3d6:  mov ecx,0x8
+   3db:  cmp rdx,0x3
+   3df:  jb <+0x3ad>
+   3e1:  movzx r8d,BYTE PTR [rsi+0x2]
+   3e6:  cmp r8d,0x73
+   3ea:  je <+0x442>
+   3ec:  cmp r8d,0x6e
+   3f0:  jne <+0x3ad>
+   3f2:  cmp rdx,0x3
+   3f6:  je <+0xf6>
+   3fc:  cmp BYTE PTR [rsi+0x3],0x74
+   400:  jne <+0xf6>
+   406:  cmp rdx,0x5
+   40a:  jb <+0xf6>
+   410:  cmp BYTE PTR [rsi+0x4],0x68
+   414:  je <+0xcb>
+   41a:  jmp <+0xf6>
+   41f:  mov eax,0x2
+   424:  mov ecx,0x2
+   429:  cmp rdx,0x2
+   42d:  jne <+0x361>
+   433:  jmp <+0x3ad>
+   438:  mov eax,0x4
+   43d:  jmp <+0x3ad>
+   442:  mov eax,0x3
+   447:  jmp <+0x3ad>
+   44c:  lea rcx,[rip]
+   453:  mov rdi,rax
+   456:  mov rsi,rdx
+   459:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
+   45f:  mov r8,rdi
+   462:  movzx eax,BYTE PTR [rsi+0x1]
+   466:  cmp eax,0x73
+   469:  je <+0x54a>
+   46f:  cmp eax,0x61
+   472:  mov rdi,r8
+   475:  jne <+0xf6>
+   47b:  cmp rdx,0x2
+   47f:  je <+0xf6>
+   485:  cmp BYTE PTR [rsi+0x2],0x6e
+   489:  jne <+0xf6>
+   48f:  cmp rdx,0x4
+   493:  jb <+0xf6>
+   499:  cmp BYTE PTR [rsi+0x3],0x6f
+   49d:  jne <+0xf6>
+   4a3:  mov eax,0x4
+   4a8:  xor ecx,ecx
+   4aa:  cmp rdx,0x4
+   4ae:  je <+0x6e8>
+   4b4:  cmp BYTE PTR [rsi+0x4],0x73
+   4b8:  jne <+0x6e8>
+   4be:  mov eax,0x5
+   4c3:  cmp rdx,0x6
+   4c7:  jb <+0x6e8>
+   4cd:  cmp BYTE PTR [rsi+0x5],0x65
+   4d1:  jne <+0x6e8>
+   4d7:  cmp rdx,0x6
+   4db:  mov rdi,r8
+   4de:  je <+0xf6>
+   4e4:  cmp BYTE PTR [rsi+0x6],0x63
+   4e8:  jne <+0xf6>
+   4ee:  cmp rdx,0x8
+   4f2:  jb <+0xf6>
+   4f8:  je <+0xf6>
+   4fe:  cmp BYTE PTR [rsi+0x7],0x6f
+   502:  jne <+0xf6>
+   508:  cmp BYTE PTR [rsi+0x8],0x6e
+   50c:  jne <+0xf6>
+   512:  cmp rdx,0xa
+   516:  jb <+0xf6>
+   51c:  cmp BYTE PTR [rsi+0x9],0x64
+   520:  jne <+0xf6>
+   526:  mov eax,0xa
+   52b:  cmp rdx,0xa
+   52f:  je <+0x6e8>
+   535:  xor eax,eax
+   537:  cmp BYTE PTR [rsi+0xa],0x73
+   53b:  sete al
+   53e:  or rax,0xa
+   542:  mov rdi,r8
+   545:  jmp <+0x3ad>
+   54a:  mov eax,0x2
+   54f:  xor ecx,ecx
+   551:  cmp rdx,0x2
+   555:  je <+0x6e8>
+   55b:  cmp BYTE PTR [rsi+0x2],0x65
+   55f:  jne <+0x6e8>
+   565:  cmp rdx,0x4
+   569:  mov rdi,r8
+   56c:  jb <+0xf6>
+   572:  cmp BYTE PTR [rsi+0x3],0x63
+   576:  jne <+0xf6>
+   57c:  mov eax,0x4
+   581:  cmp rdx,0x4
+   585:  je <+0x6e8>
+   58b:  xor eax,eax
+   58d:  cmp BYTE PTR [rsi+0x4],0x73
+   591:  sete al
+   594:  or rax,0x4
+   598:  mov rdi,r8
+   59b:  jmp <+0x3ad>
+   5a0:  cmp rdx,0x3
+   5a4:  mov rdi,r8
+   5a7:  je <+0xf6>
+   5ad:  cmp BYTE PTR [rsi+0x3],0x72
+   5b1:  jne <+0xf6>
+   5b7:  cmp rdx,0x5
+   5bb:  jb <+0xf6>
+   5c1:  cmp BYTE PTR [rsi+0x4],0x6f
+   5c5:  jne <+0xf6>
+   5cb:  mov eax,0x5
+   5d0:  mov ecx,0x1
+   5d5:  cmp rdx,0x5
+   5d9:  je <+0x6e8>
+   5df:  jmp <+0x620>
+   5e1:  cmp rdx,0x3
+   5e5:  mov rdi,r8
+   5e8:  je <+0xf6>
+   5ee:  cmp BYTE PTR [rsi+0x3],0x6c
+   5f2:  jne <+0xf6>
+   5f8:  cmp rdx,0x5
+   5fc:  jb <+0xf6>
+   602:  cmp BYTE PTR [rsi+0x4],0x69
+   606:  jne <+0xf6>
+   60c:  mov eax,0x5
+   611:  mov ecx,0x2
+   616:  cmp rdx,0x5
+   61a:  je <+0x6e8>
+   620:  cmp BYTE PTR [rsi+0x5],0x73
+   624:  jne <+0x6e8>
+   62a:  mov eax,0x6
+   62f:  cmp rdx,0x7
+   633:  jb <+0x6e8>
+   639:  cmp BYTE PTR [rsi+0x6],0x65
+   63d:  jne <+0x6e8>
+   643:  cmp rdx,0x7
+   647:  mov rdi,r8
+   64a:  je <+0xf6>
+   650:  cmp BYTE PTR [rsi+0x7],0x63
+   654:  jne <+0xf6>
+   65a:  cmp rdx,0x9
+   65e:  jb <+0xf6>
+   664:  je <+0xf6>
+   66a:  cmp BYTE PTR [rsi+0x8],0x6f
+   66e:  jne <+0xf6>
+   674:  cmp BYTE PTR [rsi+0x9],0x6e
+   678:  jne <+0xf6>
+   67e:  cmp rdx,0xb
+   682:  jb <+0xf6>
+   688:  cmp BYTE PTR [rsi+0xa],0x64
+   68c:  jne <+0xf6>
+   692:  mov eax,0xb
+   697:  cmp rdx,0xb
+   69b:  je <+0x6e8>
+   69d:  xor eax,eax
+   69f:  cmp BYTE PTR [rsi+0xb],0x73
+   6a3:  sete al
+   6a6:  add rax,0xb
+   6aa:  mov rdi,r8
+   6ad:  jmp <+0x3ad>
+   6b2:  cmp rdx,0x4
+   6b6:  mov rdi,r8
+   6b9:  je <+0xf6>
+   6bf:  cmp BYTE PTR [rsi+0x4],0x74
+   6c3:  jne <+0xf6>
+   6c9:  cmp rdx,0x6
+   6cd:  jb <+0xf6>
+   6d3:  cmp BYTE PTR [rsi+0x5],0x65
+   6d7:  jne <+0xf6>
+   6dd:  mov eax,0x6
+   6e2:  cmp rdx,0x6
+   6e6:  jne <+0x6f0>
+   6e8:  mov rdi,r8
+   6eb:  jmp <+0x3ad>
+   6f0:  xor eax,eax
+   6f2:  cmp BYTE PTR [rsi+0x6],0x73
+   6f6:  sete al
+   6f9:  or rax,0x6
+   6fd:  mov rdi,r8
+   700:  jmp <+0x3ad>
@@ -0,0 +1,535 @@
+duration_unit_lookup::by_gendfa3::lookup_ignore_ascii_case:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0x91a>
+     a:  movzx eax,BYTE PTR [rsi]
+     d:  add eax,0xffffffbc
+    10:  cmp eax,0x7e
+    13:  ja <+0x91a>
+    19:  lea rcx,[rip]
+    20:  movsxd rax,DWORD PTR [rcx+rax*4]
+    24:  add rax,rcx
+    27:  jmp rax
+    29:  mov eax,0x1
+    2e:  mov ecx,0x6
+    33:  cmp rdx,0x1
+    37:  je <+0x6cd>
+    3d:  movzx r8d,BYTE PTR [rsi+0x1]
+    42:  or r8d,0x20
+    46:  cmp r8d,0x61
+    4a:  jne <+0x6cd>
+    50:  cmp rdx,0x3
+    54:  jb <+0x91a>
+    5a:  movzx eax,BYTE PTR [rsi+0x2]
+    5e:  or eax,0x20
+    61:  cmp eax,0x79
+    64:  jne <+0x91a>
+    6a:  mov eax,0x3
+    6f:  cmp rdx,0x3
+    73:  je <+0x6cd>
+    79:  movzx r8d,BYTE PTR [rsi+0x3]
+    7e:  or r8d,0x20
+    82:  cmp r8d,0x73
+    86:  je <+0x6c8>
+    8c:  jmp <+0x6cd>
+    91:  mov eax,0x1
+    96:  mov ecx,0x3
+    9b:  cmp rdx,0x1
+    9f:  je <+0x6cd>
+    a5:  movzx r8d,BYTE PTR [rsi+0x1]
+    aa:  or r8d,0x20
+    ae:  cmp r8d,0x65
+    b2:  jne <+0x6cd>
+    b8:  cmp rdx,0x2
+    bc:  je <+0x91a>
+    c2:  movzx eax,BYTE PTR [rsi+0x2]
+    c6:  or eax,0x20
+    c9:  cmp eax,0x63
+    cc:  jne <+0x91a>
+    d2:  mov eax,0x3
+    d7:  cmp rdx,0x4
+    db:  jb <+0x6cd>
+    e1:  movzx r8d,BYTE PTR [rsi+0x3]
+    e6:  add r8b,0xb1
+    ea:  rol r8b,0x6
+    ee:  cmp r8b,0x9
+    f2:  ja <+0x6cd>
+    f8:  movzx r8d,r8b
+    fc:  lea r9,[rip]
+   103:  movsxd r8,DWORD PTR [r9+r8*4]
+   107:  add r8,r9
+   10a:  jmp r8
+   10d:  cmp rdx,0x4
+   111:  je <+0x91a>
+   117:  movzx eax,BYTE PTR [rsi+0x4]
+   11b:  or eax,0x20
+   11e:  cmp eax,0x6e
+   121:  jne <+0x91a>
+   127:  cmp rdx,0x6
+   12b:  jb <+0x91a>
+   131:  movzx eax,BYTE PTR [rsi+0x5]
+   135:  or eax,0x20
+   138:  cmp eax,0x64
+   13b:  jne <+0x91a>
+   141:  mov eax,0x6
+   146:  cmp rdx,0x6
+   14a:  je <+0x6cd>
+   150:  movzx r8d,BYTE PTR [rsi+0x6]
+   155:  or r8d,0x20
+   159:  cmp r8d,0x73
+   15d:  jne <+0x6cd>
+   163:  mov eax,0x7
+   168:  jmp <+0x6cd>
+   16d:  mov eax,0x1
+   172:  mov ecx,0x7
+   177:  cmp rdx,0x1
+   17b:  je <+0x6cd>
+   181:  movzx r8d,BYTE PTR [rsi+0x1]
+   186:  add r8d,0xffffffbb
+   18a:  cmp r8d,0x26
+   18e:  ja <+0x6cd>
+   194:  lea r9,[rip]
+   19b:  movsxd r8,DWORD PTR [r9+r8*4]
+   19f:  add r8,r9
+   1a2:  jmp r8
+   1a5:  cmp rdx,0x2
+   1a9:  je <+0x91a>
+   1af:  movzx eax,BYTE PTR [rsi+0x2]
+   1b3:  or eax,0x20
+   1b6:  cmp eax,0x65
+   1b9:  jne <+0x91a>
+   1bf:  cmp rdx,0x4
+   1c3:  jb <+0x91a>
+   1c9:  movzx eax,BYTE PTR [rsi+0x3]
+   1cd:  or eax,0x20
+   1d0:  cmp eax,0x6b
+   1d3:  je <+0x58c>
+   1d9:  jmp <+0x91a>
+   1de:  mov eax,0x1
+   1e3:  mov ecx,0x5
+   1e8:  cmp rdx,0x1
+   1ec:  je <+0x6cd>
+   1f2:  movzx r8d,BYTE PTR [rsi+0x1]
+   1f7:  add r8d,0xffffffb1
+   1fb:  cmp r8d,0x23
+   1ff:  ja <+0x6cd>
+   205:  lea r9,[rip]
+   20c:  movsxd r8,DWORD PTR [r9+r8*4]
+   210:  add r8,r9
+   213:  jmp r8
+   216:  cmp rdx,0x2
+   21a:  je <+0x91a>
+   220:  movzx eax,BYTE PTR [rsi+0x2]
+   224:  or eax,0x20
+   227:  cmp eax,0x75
+   22a:  je <+0x572>
+   230:  jmp <+0x91a>
+   235:  cmp rdx,0x1
+   239:  je <+0x91a>
+   23f:  movzx eax,BYTE PTR [rsi+0x1]
+   243:  or eax,0x20
+   246:  cmp eax,0x73
+   249:  jne <+0x91a>
+   24f:  mov eax,0x2
+   254:  mov ecx,0x1
+   259:  cmp rdx,0x2
+   25d:  je <+0x6cd>
+   263:  jmp <+0x51a>
+   268:  cmp rdx,0x1
+   26c:  je <+0x91a>
+   272:  movzx eax,BYTE PTR [rsi+0x1]
+   276:  cmp eax,0x60
+   279:  jg <+0x3e9>
+   27f:  cmp eax,0x41
+   282:  je <+0x3fb>
+   288:  cmp eax,0x53
+   28b:  je <+0x509>
+   291:  jmp <+0x91a>
+   296:  mov eax,0x1
+   29b:  mov ecx,0x4
+   2a0:  cmp rdx,0x1
+   2a4:  je <+0x6cd>
+   2aa:  movzx r8d,BYTE PTR [rsi+0x1]
+   2af:  add r8d,0xffffffb7
+   2b3:  cmp r8d,0x2a
+   2b7:  ja <+0x6cd>
+   2bd:  movabs r9,0x100000001
+   2c7:  bt r9,r8
+   2cb:  jb <+0x655>
+   2d1:  movabs r9,0x4000000040
+   2db:  bt r9,r8
+   2df:  jb <+0x5e4>
+   2e5:  movabs r9,0x40000000400
+   2ef:  bt r9,r8
+   2f3:  jae <+0x6cd>
+   2f9:  mov eax,0x2
+   2fe:  mov ecx,0x2
+   303:  cmp rdx,0x2
+   307:  jne <+0x51a>
+   30d:  jmp <+0x6cd>
+   312:  mov eax,0x1
+   317:  mov ecx,0x9
+   31c:  cmp rdx,0x1
+   320:  je <+0x6cd>
+   326:  movzx r8d,BYTE PTR [rsi+0x1]
+   32b:  cmp r8d,0x64
+   32f:  jg <+0x548>
+   335:  cmp r8d,0x45
+   339:  je <+0x558>
+   33f:  cmp r8d,0x52
+   343:  je <+0x5b8>
+   349:  jmp <+0x6cd>
+   34e:  cmp rdx,0x1
+   352:  je <+0x91a>
+   358:  cmp BYTE PTR [rsi+0x1],0xb5
+   35c:  jne <+0x91a>
+   362:  cmp rdx,0x3
+   366:  jb <+0x91a>
+   36c:  movzx eax,BYTE PTR [rsi+0x2]
+   370:  or eax,0x20
+   373:  cmp eax,0x73
+   376:  jne <+0x91a>
+   37c:  mov eax,0x3
+   381:  mov ecx,0x1
+   386:  cmp rdx,0x3
+   38a:  je <+0x6cd>
+   390:  movzx r8d,BYTE PTR [rsi+0x3]
+   395:  or r8d,0x20
+   399:  cmp r8d,0x65
+   39d:  jne <+0x6cd>
+   3a3:  cmp rdx,0x5
+   3a7:  jb <+0x91a>
+   3ad:  movzx eax,BYTE PTR [rsi+0x4]
+   3b1:  or eax,0x20
+   3b4:  cmp eax,0x63
+   3b7:  jne <+0x91a>
+   3bd:  mov eax,0x5
+   3c2:  cmp rdx,0x5
+   3c6:  je <+0x6cd>
+   3cc:  movzx r8d,BYTE PTR [rsi+0x5]
+   3d1:  or r8d,0x20
+   3d5:  cmp r8d,0x73
+   3d9:  jne <+0x6cd>
+   3df:  mov eax,0x6
+   3e4:  jmp <+0x6cd>
+   3e9:  cmp eax,0x73
+   3ec:  je <+0x509>
+   3f2:  cmp eax,0x61
+   3f5:  jne <+0x91a>
+   3fb:  cmp rdx,0x2
+   3ff:  je <+0x91a>
+   405:  movzx eax,BYTE PTR [rsi+0x2]
+   409:  or eax,0x20
+   40c:  cmp eax,0x6e
+   40f:  jne <+0x91a>
+   415:  cmp rdx,0x4
+   419:  jb <+0x91a>
+   41f:  movzx eax,BYTE PTR [rsi+0x3]
+   423:  or eax,0x20
+   426:  cmp eax,0x6f
+   429:  jne <+0x91a>
+   42f:  mov eax,0x4
+   434:  xor ecx,ecx
+   436:  cmp rdx,0x4
+   43a:  je <+0x6cd>
+   440:  movzx r8d,BYTE PTR [rsi+0x4]
+   445:  or r8d,0x20
+   449:  cmp r8d,0x73
+   44d:  jne <+0x6cd>
+   453:  mov eax,0x5
+   458:  cmp rdx,0x6
+   45c:  jb <+0x6cd>
+   462:  movzx r8d,BYTE PTR [rsi+0x5]
+   467:  or r8d,0x20
+   46b:  cmp r8d,0x65
+   46f:  jne <+0x6cd>
+   475:  cmp rdx,0x6
+   479:  je <+0x91a>
+   47f:  movzx eax,BYTE PTR [rsi+0x6]
+   483:  or eax,0x20
+   486:  cmp eax,0x63
+   489:  jne <+0x91a>
+   48f:  cmp rdx,0x8
+   493:  jb <+0x91a>
+   499:  movzx eax,BYTE PTR [rsi+0x7]
+   49d:  or eax,0x20
+   4a0:  cmp eax,0x6f
+   4a3:  jne <+0x91a>
+   4a9:  cmp rdx,0x8
+   4ad:  je <+0x91a>
+   4b3:  movzx eax,BYTE PTR [rsi+0x8]
+   4b7:  or eax,0x20
+   4ba:  cmp eax,0x6e
+   4bd:  jne <+0x91a>
+   4c3:  cmp rdx,0xa
+   4c7:  jb <+0x91a>
+   4cd:  movzx eax,BYTE PTR [rsi+0x9]
+   4d1:  or eax,0x20
+   4d4:  cmp eax,0x64
+   4d7:  jne <+0x91a>
+   4dd:  mov eax,0xa
+   4e2:  cmp rdx,0xa
+   4e6:  je <+0x6cd>
+   4ec:  movzx r8d,BYTE PTR [rsi+0xa]
+   4f1:  or r8d,0x20
+   4f5:  cmp r8d,0x73
+   4f9:  jne <+0x6cd>
+   4ff:  mov eax,0xb
+   504:  jmp <+0x6cd>
+   509:  mov eax,0x2
+   50e:  xor ecx,ecx
+   510:  cmp rdx,0x2
+   514:  je <+0x6cd>
+   51a:  movzx r8d,BYTE PTR [rsi+0x2]
+   51f:  or r8d,0x20
+   523:  cmp r8d,0x65
+   527:  jne <+0x6cd>
+   52d:  cmp rdx,0x4
+   531:  jb <+0x91a>
+   537:  movzx eax,BYTE PTR [rsi+0x3]
+   53b:  or eax,0x20
+   53e:  cmp eax,0x63
+   541:  je <+0x58c>
+   543:  jmp <+0x91a>
+   548:  cmp r8d,0x72
+   54c:  je <+0x5b8>
+   54e:  cmp r8d,0x65
+   552:  jne <+0x6cd>
+   558:  cmp rdx,0x2
+   55c:  je <+0x91a>
+   562:  movzx eax,BYTE PTR [rsi+0x2]
+   566:  or eax,0x20
+   569:  cmp eax,0x61
+   56c:  jne <+0x91a>
+   572:  cmp rdx,0x4
+   576:  jb <+0x91a>
+   57c:  movzx eax,BYTE PTR [rsi+0x3]
+   580:  or eax,0x20
+   583:  cmp eax,0x72
+   586:  jne <+0x91a>
+   58c:  mov eax,0x4
+   591:  cmp rdx,0x4
+   595:  je <+0x6cd>
+   59b:  movzx r8d,BYTE PTR [rsi+0x4]
+   5a0:  or r8d,0x20
+   5a4:  cmp r8d,0x73
+   5a8:  jne <+0x6cd>
+   5ae:  mov eax,0x5
+   5b3:  jmp <+0x6cd>
+   5b8:  mov eax,0x2
+   5bd:  cmp rdx,0x2
+   5c1:  je <+0x6cd>
+   5c7:  movzx r8d,BYTE PTR [rsi+0x2]
+   5cc:  or r8d,0x20
+   5d0:  cmp r8d,0x73
+   5d4:  jne <+0x6cd>
+   5da:  mov eax,0x3
+   5df:  jmp <+0x6cd>
+   5e4:  mov eax,0x2
+   5e9:  mov ecx,0x8
+   5ee:  cmp rdx,0x3
+   5f2:  jb <+0x6cd>
+   5f8:  movzx r8d,BYTE PTR [rsi+0x2]
+   5fd:  add r8d,0xffffffb2
+   601:  cmp r8d,0x25
+   605:  ja <+0x6cd>
+   60b:  lea r9,[rip]
+   612:  movsxd r8,DWORD PTR [r9+r8*4]
+   616:  add r8,r9
+   619:  jmp r8
+   61c:  cmp rdx,0x3
+   620:  je <+0x91a>
+   626:  movzx eax,BYTE PTR [rsi+0x3]
+   62a:  or eax,0x20
+   62d:  cmp eax,0x74
+   630:  jne <+0x91a>
+   636:  cmp rdx,0x5
+   63a:  jb <+0x91a>
+   640:  movzx eax,BYTE PTR [rsi+0x4]
+   644:  or eax,0x20
+   647:  cmp eax,0x68
+   64a:  je <+0x3bd>
+   650:  jmp <+0x91a>
+   655:  cmp rdx,0x3
+   659:  jb <+0x91a>
+   65f:  movzx eax,BYTE PTR [rsi+0x2]
+   663:  add eax,0xffffffbd
+   666:  cmp eax,0x2b
+   669:  ja <+0x91a>
+   66f:  bt r9,rax
+   673:  jb <+0x7f5>
+   679:  movabs r8,0x20000000200
+   683:  bt r8,rax
+   687:  jb <+0x6f1>
+   689:  movabs r8,0x80000000800
+   693:  bt r8,rax
+   697:  jae <+0x91a>
+   69d:  mov eax,0x3
+   6a2:  cmp rdx,0x3
+   6a6:  je <+0x6cd>
+   6a8:  movzx r8d,BYTE PTR [rsi+0x3]
+   6ad:  add r8d,0xffffffad
+   6b1:  cmp r8d,0x22
+   6b5:  ja <+0x6cd>
+   6b7:  lea r9,[rip]
+   6be:  movsxd r8,DWORD PTR [r9+r8*4]
+   6c2:  add r8,r9
+   6c5:  jmp r8
+   6c8:  mov eax,0x4
+   6cd:  mov r8,rdx
+   6d0:  sub r8,rax
+   6d3:  jb <+0x93a>
+   6d9:  add rsi,rax
+   6dc:  mov DWORD PTR [rdi+0x8],ecx
+   6df:  mov QWORD PTR [rdi+0x10],rsi
+   6e3:  mov QWORD PTR [rdi+0x18],r8
+   6e7:  xor eax,eax
+   6e9:  mov QWORD PTR [rdi],rax
+   6ec:  mov rax,rdi
+   6ef:  pop rbx
+   6f0:  ret
+   6f1:  cmp rdx,0x3
+   6f5:  je <+0x91a>
+   6fb:  movzx eax,BYTE PTR [rsi+0x3]
+   6ff:  or eax,0x20
+   702:  cmp eax,0x6c
+   705:  jne <+0x91a>
+   70b:  cmp rdx,0x5
+   70f:  jb <+0x91a>
+   715:  movzx eax,BYTE PTR [rsi+0x4]
+   719:  or eax,0x20
+   71c:  cmp eax,0x69
+   71f:  jne <+0x91a>
+   725:  mov eax,0x5
+   72a:  mov ecx,0x2
+   72f:  cmp rdx,0x5
+   733:  je <+0x6cd>
+   735:  movzx r8d,BYTE PTR [rsi+0x5]
+   73a:  or r8d,0x20
+   73e:  cmp r8d,0x73
+   742:  jne <+0x6cd>
+   744:  mov eax,0x6
+   749:  cmp rdx,0x7
+   74d:  jb <+0x6cd>
+   753:  movzx r8d,BYTE PTR [rsi+0x6]
+   758:  or r8d,0x20
+   75c:  cmp r8d,0x65
+   760:  jne <+0x6cd>
+   766:  cmp rdx,0x7
+   76a:  je <+0x91a>
+   770:  movzx eax,BYTE PTR [rsi+0x7]
+   774:  or eax,0x20
+   777:  cmp eax,0x63
+   77a:  jne <+0x91a>
+   780:  cmp rdx,0x9
+   784:  jb <+0x91a>
+   78a:  movzx eax,BYTE PTR [rsi+0x8]
+   78e:  or eax,0x20
+   791:  cmp eax,0x6f
+   794:  jne <+0x91a>
+   79a:  cmp rdx,0x9
+   79e:  je <+0x91a>
+   7a4:  movzx eax,BYTE PTR [rsi+0x9]
+   7a8:  or eax,0x20
+   7ab:  cmp eax,0x6e
+   7ae:  jne <+0x91a>
+   7b4:  cmp rdx,0xb
+   7b8:  jb <+0x91a>
+   7be:  movzx eax,BYTE PTR [rsi+0xa]
+   7c2:  or eax,0x20
+   7c5:  cmp eax,0x64
+   7c8:  jne <+0x91a>
+   7ce:  mov eax,0xb
+   7d3:  cmp rdx,0xb
+   7d7:  je <+0x6cd>
+   7dd:  movzx r8d,BYTE PTR [rsi+0xb]
+   7e2:  or r8d,0x20
+   7e6:  cmp r8d,0x73
+   7ea:  je <+0x8e8>
+   7f0:  jmp <+0x6cd>
+   7f5:  cmp rdx,0x3
+   7f9:  je <+0x91a>
+   7ff:  movzx eax,BYTE PTR [rsi+0x3]
+   803:  or eax,0x20
+   806:  cmp eax,0x72
+   809:  jne <+0x91a>
+   80f:  cmp rdx,0x5
+   813:  jb <+0x91a>
+   819:  movzx eax,BYTE PTR [rsi+0x4]
+   81d:  or eax,0x20
+   820:  cmp eax,0x6f
+   823:  jne <+0x91a>
+   829:  mov eax,0x5
+   82e:  mov ecx,0x1
+   833:  cmp rdx,0x5
+   837:  je <+0x6cd>
+   83d:  movzx r8d,BYTE PTR [rsi+0x5]
+   842:  or r8d,0x20
+   846:  cmp r8d,0x73
+   84a:  jne <+0x6cd>
+   850:  mov eax,0x6
+   855:  cmp rdx,0x7
+   859:  jb <+0x6cd>
+   85f:  movzx r8d,BYTE PTR [rsi+0x6]
+   864:  or r8d,0x20
+   868:  cmp r8d,0x65
+   86c:  jne <+0x6cd>
+   872:  cmp rdx,0x7
+   876:  je <+0x91a>
+   87c:  movzx eax,BYTE PTR [rsi+0x7]
+   880:  or eax,0x20
+   883:  cmp eax,0x63
+   886:  jne <+0x91a>
+   88c:  cmp rdx,0x9
+   890:  jb <+0x91a>
+   896:  movzx eax,BYTE PTR [rsi+0x8]
+   89a:  or eax,0x20
+   89d:  cmp eax,0x6f
+   8a0:  jne <+0x91a>
+   8a2:  cmp rdx,0x9
+   8a6:  je <+0x91a>
+   8a8:  movzx eax,BYTE PTR [rsi+0x9]
+   8ac:  or eax,0x20
+   8af:  cmp eax,0x6e
+   8b2:  jne <+0x91a>
+   8b4:  cmp rdx,0xb
+   8b8:  jb <+0x91a>
+   8ba:  movzx eax,BYTE PTR [rsi+0xa]
+   8be:  or eax,0x20
+   8c1:  cmp eax,0x64
+   8c4:  jne <+0x91a>
+   8c6:  mov eax,0xb
+   8cb:  cmp rdx,0xb
+   8cf:  je <+0x6cd>
+   8d5:  movzx r8d,BYTE PTR [rsi+0xb]
+   8da:  or r8d,0x20
+   8de:  cmp r8d,0x73
+   8e2:  jne <+0x6cd>
+   8e8:  mov eax,0xc
+   8ed:  jmp <+0x6cd>
+   8f2:  cmp rdx,0x4
+   8f6:  je <+0x91a>
+   8f8:  movzx eax,BYTE PTR [rsi+0x4]
+   8fc:  or eax,0x20
+   8ff:  cmp eax,0x74
+   902:  jne <+0x91a>
+   904:  cmp rdx,0x6
+   908:  jb <+0x91a>
+   90a:  movzx eax,BYTE PTR [rsi+0x5]
+   90e:  or eax,0x20
+   911:  cmp eax,0x65
+   914:  je <+0x141>
+   91a:  lea rax,[rdi+0x8]
+   91e:  mov rbx,rdi
+   921:  mov rdi,rax
+   924:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+   92a:  mov rdi,rbx
+   92d:  mov eax,0x1
+   932:  mov QWORD PTR [rdi],rax
+   935:  mov rax,rdi
+   938:  pop rbx
+   939:  ret
+   93a:  lea rcx,[rip]
+   941:  mov rdi,rax
+   944:  mov rsi,rdx
+   947:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
@@ -0,0 +1,391 @@
+duration_unit_lookup::by_gendfa4::lookup:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0x697>
+     a:  movzx ecx,BYTE PTR [rsi]
+     d:  lea eax,[rcx-0x64]
+    10:  cmp eax,0x15
+    13:  ja <+0x78>
+    15:  lea rcx,[rip]
+    1c:  movsxd rax,DWORD PTR [rcx+rax*4]
+    20:  add rax,rcx
+    23:  jmp rax
+    25:  mov ecx,0x6
+    2a:  mov eax,0x1
+    2f:  cmp rdx,0x1
+    33:  je <+0x4e3>
+    39:  cmp BYTE PTR [rsi+0x1],0x61
+    3d:  jne <+0x4e3>
+    43:  cmp rdx,0x3
+    47:  jb <+0x697>
+    4d:  cmp BYTE PTR [rsi+0x2],0x79
+    51:  jne <+0x697>
+    57:  mov eax,0x3
+    5c:  cmp rdx,0x3
+    60:  je <+0x4e3>
+    66:  xor eax,eax
+    68:  cmp BYTE PTR [rsi+0x3],0x73
+    6c:  sete al
+    6f:  add rax,0x3
+    73:  jmp <+0x4e3>
+    78:  cmp ecx,0xc2
+    7e:  jne <+0x697>
+    84:  cmp rdx,0x1
+    88:  je <+0x697>
+    8e:  cmp BYTE PTR [rsi+0x1],0xb5
+    92:  jne <+0x697>
+    98:  cmp rdx,0x3
+    9c:  jb <+0x697>
+    a2:  cmp BYTE PTR [rsi+0x2],0x73
+    a6:  jne <+0x697>
+    ac:  mov ecx,0x1
+    b1:  mov eax,0x3
+    b6:  cmp rdx,0x3
+    ba:  je <+0x4e3>
+    c0:  cmp BYTE PTR [rsi+0x3],0x65
+    c4:  jne <+0x4e3>
+    ca:  cmp rdx,0x5
+    ce:  jb <+0x697>
+    d4:  cmp BYTE PTR [rsi+0x4],0x63
+    d8:  jne <+0x697>
+    de:  mov eax,0x5
+    e3:  cmp rdx,0x5
+    e7:  je <+0x4e3>
+    ed:  xor eax,eax
+    ef:  cmp BYTE PTR [rsi+0x5],0x73
+    f3:  sete al
+    f6:  add rax,0x5
+    fa:  jmp <+0x4e3>
+    ff:  cmp rdx,0x1
+   103:  je <+0x697>
+   109:  movzx eax,BYTE PTR [rsi+0x1]
+   10d:  cmp eax,0x73
+   110:  je <+0x439>
+   116:  cmp eax,0x61
+   119:  jne <+0x697>
+   11f:  cmp rdx,0x3
+   123:  jb <+0x697>
+   129:  je <+0x697>
+   12f:  cmp BYTE PTR [rsi+0x2],0x6e
+   133:  jne <+0x697>
+   139:  cmp BYTE PTR [rsi+0x3],0x6f
+   13d:  jne <+0x697>
+   143:  xor ecx,ecx
+   145:  mov eax,0x4
+   14a:  cmp rdx,0x5
+   14e:  jb <+0x4e3>
+   154:  cmp BYTE PTR [rsi+0x4],0x73
+   158:  jne <+0x4e3>
+   15e:  mov eax,0x5
+   163:  cmp rdx,0x5
+   167:  je <+0x4e3>
+   16d:  cmp BYTE PTR [rsi+0x5],0x65
+   171:  jne <+0x4e3>
+   177:  cmp rdx,0x7
+   17b:  jb <+0x697>
+   181:  je <+0x697>
+   187:  cmp BYTE PTR [rsi+0x6],0x63
+   18b:  jne <+0x697>
+   191:  cmp BYTE PTR [rsi+0x7],0x6f
+   195:  jne <+0x697>
+   19b:  cmp rdx,0x9
+   19f:  jb <+0x697>
+   1a5:  je <+0x697>
+   1ab:  cmp BYTE PTR [rsi+0x8],0x6e
+   1af:  jne <+0x697>
+   1b5:  cmp BYTE PTR [rsi+0x9],0x64
+   1b9:  jne <+0x697>
+   1bf:  mov eax,0xa
+   1c4:  cmp rdx,0xb
+   1c8:  jb <+0x4e3>
+   1ce:  xor eax,eax
+   1d0:  cmp BYTE PTR [rsi+0xa],0x73
+   1d4:  sete al
+   1d7:  or rax,0xa
+   1db:  jmp <+0x4e3>
+   1e0:  mov ecx,0x9
+   1e5:  mov eax,0x1
+   1ea:  cmp rdx,0x1
+   1ee:  je <+0x4e3>
+   1f4:  movzx r8d,BYTE PTR [rsi+0x1]
+   1f9:  cmp r8d,0x72
+   1fd:  je <+0x418>
+   203:  cmp r8d,0x65
+   207:  jne <+0x4e3>
+   20d:  cmp rdx,0x2
+   211:  jbe <+0x697>
+   217:  cmp rdx,0x3
+   21b:  je <+0x697>
+   221:  cmp BYTE PTR [rsi+0x2],0x61
+   225:  je <+0x3dc>
+   22b:  jmp <+0x697>
+   230:  mov ecx,0x7
+   235:  mov eax,0x1
+   23a:  cmp rdx,0x1
+   23e:  je <+0x4e3>
+   244:  movzx r8d,BYTE PTR [rsi+0x1]
+   249:  cmp r8d,0x6b
+   24d:  je <+0x418>
+   253:  cmp r8d,0x65
+   257:  jne <+0x4e3>
+   25d:  cmp rdx,0x2
+   261:  jbe <+0x697>
+   267:  cmp rdx,0x3
+   26b:  je <+0x697>
+   271:  cmp BYTE PTR [rsi+0x2],0x65
+   275:  jne <+0x697>
+   27b:  cmp BYTE PTR [rsi+0x3],0x6b
+   27f:  je <+0x4cb>
+   285:  jmp <+0x697>
+   28a:  mov ecx,0x3
+   28f:  mov eax,0x1
+   294:  cmp rdx,0x1
+   298:  je <+0x4e3>
+   29e:  cmp BYTE PTR [rsi+0x1],0x65
+   2a2:  jne <+0x4e3>
+   2a8:  cmp rdx,0x3
+   2ac:  jb <+0x697>
+   2b2:  cmp BYTE PTR [rsi+0x2],0x63
+   2b6:  jne <+0x697>
+   2bc:  mov eax,0x3
+   2c1:  cmp rdx,0x3
+   2c5:  je <+0x4e3>
+   2cb:  movzx r8d,BYTE PTR [rsi+0x3]
+   2d0:  cmp r8d,0x6f
+   2d4:  je <+0x662>
+   2da:  cmp r8d,0x73
+   2de:  jne <+0x4e3>
+   2e4:  mov eax,0x4
+   2e9:  jmp <+0x4e3>
+   2ee:  mov ecx,0x4
+   2f3:  mov eax,0x1
+   2f8:  cmp rdx,0x1
+   2fc:  je <+0x4e3>
+   302:  movzx r8d,BYTE PTR [rsi+0x1]
+   307:  cmp r8d,0x73
+   30b:  je <+0x4a1>
+   311:  cmp r8d,0x6f
+   315:  je <+0x44b>
+   31b:  cmp r8d,0x69
+   31f:  jne <+0x4e3>
+   325:  cmp rdx,0x3
+   329:  jb <+0x697>
+   32f:  movzx eax,BYTE PTR [rsi+0x2]
+   333:  cmp eax,0x63
+   336:  je <+0x507>
+   33c:  cmp eax,0x6c
+   33f:  je <+0x5b6>
+   345:  cmp eax,0x6e
+   348:  jne <+0x697>
+   34e:  mov eax,0x3
+   353:  cmp rdx,0x3
+   357:  je <+0x4e3>
+   35d:  movzx r8d,BYTE PTR [rsi+0x3]
+   362:  cmp r8d,0x75
+   366:  jne <+0x2da>
+   36c:  cmp rdx,0x5
+   370:  jb <+0x697>
+   376:  je <+0x697>
+   37c:  cmp BYTE PTR [rsi+0x4],0x74
+   380:  jne <+0x697>
+   386:  cmp BYTE PTR [rsi+0x5],0x65
+   38a:  je <+0x676>
+   390:  jmp <+0x697>
+   395:  mov ecx,0x5
+   39a:  mov eax,0x1
+   39f:  cmp rdx,0x1
+   3a3:  je <+0x4e3>
+   3a9:  movzx r8d,BYTE PTR [rsi+0x1]
+   3ae:  cmp r8d,0x72
+   3b2:  je <+0x418>
+   3b4:  cmp r8d,0x6f
+   3b8:  jne <+0x4e3>
+   3be:  cmp rdx,0x2
+   3c2:  jbe <+0x697>
+   3c8:  cmp rdx,0x3
+   3cc:  je <+0x697>
+   3d2:  cmp BYTE PTR [rsi+0x2],0x75
+   3d6:  jne <+0x697>
+   3dc:  cmp BYTE PTR [rsi+0x3],0x72
+   3e0:  je <+0x4cb>
+   3e6:  jmp <+0x697>
+   3eb:  cmp rdx,0x1
+   3ef:  je <+0x697>
+   3f5:  cmp BYTE PTR [rsi+0x1],0x73
+   3f9:  jne <+0x697>
+   3ff:  mov ecx,0x1
+   404:  mov eax,0x2
+   409:  cmp rdx,0x3
+   40d:  jae <+0x4b1>
+   413:  jmp <+0x4e3>
+   418:  mov eax,0x2
+   41d:  cmp rdx,0x2
+   421:  jbe <+0x4e3>
+   427:  xor eax,eax
+   429:  cmp BYTE PTR [rsi+0x2],0x73
+   42d:  sete al
+   430:  or rax,0x2
+   434:  jmp <+0x4e3>
+   439:  xor ecx,ecx
+   43b:  mov eax,0x2
+   440:  cmp rdx,0x3
+   444:  jae <+0x4b1>
+   446:  jmp <+0x4e3>
+   44b:  mov ecx,0x8
+   450:  mov eax,0x2
+   455:  cmp rdx,0x3
+   459:  jb <+0x4e3>
+   45f:  movzx r8d,BYTE PTR [rsi+0x2]
+   464:  cmp r8d,0x73
+   468:  je <+0x6b7>
+   46e:  cmp r8d,0x6e
+   472:  jne <+0x4e3>
+   474:  cmp rdx,0x3
+   478:  je <+0x697>
+   47e:  cmp BYTE PTR [rsi+0x3],0x74
+   482:  jne <+0x697>
+   488:  cmp rdx,0x5
+   48c:  jb <+0x697>
+   492:  cmp BYTE PTR [rsi+0x4],0x68
+   496:  je <+0xde>
+   49c:  jmp <+0x697>
+   4a1:  mov ecx,0x2
+   4a6:  mov eax,0x2
+   4ab:  cmp rdx,0x3
+   4af:  jb <+0x4e3>
+   4b1:  cmp BYTE PTR [rsi+0x2],0x65
+   4b5:  jne <+0x4e3>
+   4b7:  cmp rdx,0x3
+   4bb:  je <+0x697>
+   4c1:  cmp BYTE PTR [rsi+0x3],0x63
+   4c5:  jne <+0x697>
+   4cb:  mov eax,0x4
+   4d0:  cmp rdx,0x5
+   4d4:  jb <+0x4e3>
+   4d6:  xor eax,eax
+   4d8:  cmp BYTE PTR [rsi+0x4],0x73
+   4dc:  sete al
+   4df:  or rax,0x4
+   4e3:  mov r8,rdx
+   4e6:  sub r8,rax
+   4e9:  jb <+0x6c1>
+   4ef:  add rsi,rax
+   4f2:  mov DWORD PTR [rdi+0x8],ecx
+   4f5:  mov QWORD PTR [rdi+0x10],rsi
+   4f9:  mov QWORD PTR [rdi+0x18],r8
+   4fd:  xor eax,eax
+   4ff:  mov QWORD PTR [rdi],rax
+   502:  mov rax,rdi
+   505:  pop rbx
+   506:  ret
+   507:  cmp rdx,0x3
+   50b:  je <+0x697>
+   511:  cmp BYTE PTR [rsi+0x3],0x72
+   515:  jne <+0x697>
+   51b:  cmp rdx,0x5
+   51f:  jb <+0x697>
+   525:  cmp BYTE PTR [rsi+0x4],0x6f
+   529:  jne <+0x697>
+   52f:  mov ecx,0x1
+   534:  mov eax,0x5
+   539:  cmp rdx,0x5
+   53d:  je <+0x4e3>
+   53f:  cmp BYTE PTR [rsi+0x5],0x73
+   543:  jne <+0x4e3>
+   545:  mov eax,0x6
+   54a:  cmp rdx,0x7
+   54e:  jb <+0x4e3>
+   550:  cmp BYTE PTR [rsi+0x6],0x65
+   554:  jne <+0x4e3>
+   556:  cmp rdx,0x7
+   55a:  je <+0x697>
+   560:  cmp BYTE PTR [rsi+0x7],0x63
+   564:  jne <+0x697>
+   56a:  cmp rdx,0x9
+   56e:  jb <+0x697>
+   574:  je <+0x697>
+   57a:  cmp BYTE PTR [rsi+0x8],0x6f
+   57e:  jne <+0x697>
+   584:  cmp BYTE PTR [rsi+0x9],0x6e
+   588:  jne <+0x697>
+   58e:  cmp rdx,0xb
+   592:  jb <+0x697>
+   598:  cmp BYTE PTR [rsi+0xa],0x64
+   59c:  jne <+0x697>
+   5a2:  mov eax,0xb
+   5a7:  cmp rdx,0xb
+   5ab:  je <+0x4e3>
+   5b1:  jmp <+0x650>
+   5b6:  cmp rdx,0x3
+   5ba:  je <+0x697>
+   5c0:  cmp BYTE PTR [rsi+0x3],0x6c
+   5c4:  jne <+0x697>
+   5ca:  cmp rdx,0x5
+   5ce:  jb <+0x697>
+   5d4:  cmp BYTE PTR [rsi+0x4],0x69
+   5d8:  jne <+0x697>
+   5de:  mov ecx,0x2
+   5e3:  mov eax,0x5
+   5e8:  cmp rdx,0x6
+   5ec:  jb <+0x4e3>
+   5f2:  cmp BYTE PTR [rsi+0x5],0x73
+   5f6:  jne <+0x4e3>
+   5fc:  mov eax,0x6
+   601:  cmp rdx,0x6
+   605:  je <+0x4e3>
+   60b:  cmp BYTE PTR [rsi+0x6],0x65
+   60f:  jne <+0x4e3>
+   615:  cmp rdx,0x8
+   619:  jb <+0x697>
+   61b:  je <+0x697>
+   61d:  cmp BYTE PTR [rsi+0x7],0x63
+   621:  jne <+0x697>
+   623:  cmp BYTE PTR [rsi+0x8],0x6f
+   627:  jne <+0x697>
+   629:  cmp rdx,0xa
+   62d:  jb <+0x697>
+   62f:  cmp BYTE PTR [rsi+0x9],0x6e
+   633:  jne <+0x697>
+   635:  cmp rdx,0xb
+   639:  jb <+0x697>
+   63b:  cmp BYTE PTR [rsi+0xa],0x64
+   63f:  jne <+0x697>
+   641:  mov eax,0xb
+   646:  cmp rdx,0xc
+   64a:  jb <+0x4e3>
+   650:  xor eax,eax
+   652:  cmp BYTE PTR [rsi+0xb],0x73
+   656:  sete al
+   659:  add rax,0xb
+   65d:  jmp <+0x4e3>
+   662:  cmp rdx,0x5
+   666:  jb <+0x697>
+   668:  je <+0x697>
+   66a:  cmp BYTE PTR [rsi+0x4],0x6e
+   66e:  jne <+0x697>
+   670:  cmp BYTE PTR [rsi+0x5],0x64
+   674:  jne <+0x697>
+   676:  mov eax,0x6
+   67b:  cmp rdx,0x7
+   67f:  jb <+0x4e3>
+   685:  xor eax,eax
+   687:  cmp BYTE PTR [rsi+0x6],0x73
+   68b:  sete al
+   68e:  or rax,0x6
+   692:  jmp <+0x4e3>
+   697:  lea rax,[rdi+0x8]
+   69b:  mov rbx,rdi
+   69e:  mov rdi,rax
+   6a1:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+   6a7:  mov rdi,rbx
+   6aa:  mov eax,0x1
+   6af:  mov QWORD PTR [rdi],rax
+   6b2:  mov rax,rdi
+   6b5:  pop rbx
+   6b6:  ret
+   6b7:  mov eax,0x3
+   6bc:  jmp <+0x4e3>
+   6c1:  lea rcx,[rip]
+   6c8:  mov rdi,rax
+   6cb:  mov rsi,rdx
+   6ce:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
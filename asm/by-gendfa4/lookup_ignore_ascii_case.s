@@ -0,0 +1,520 @@
+duration_unit_lookup::by_gendfa4::lookup_ignore_ascii_case:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0x89a>
+     a:  movzx eax,BYTE PTR [rsi]
+     d:  add eax,0xffffffbc
+    10:  cmp eax,0x7e
+    13:  ja <+0x89a>
+    19:  lea rcx,[rip]
+    20:  movsxd rax,DWORD PTR [rcx+rax*4]
+    24:  add rax,rcx
+    27:  jmp rax
+    29:  mov ecx,0x6
+    2e:  mov eax,0x1
+    33:  cmp rdx,0x1
+    37:  je <+0x61b>
+    3d:  movzx r8d,BYTE PTR [rsi+0x1]
+    42:  or r8d,0x20
+    46:  cmp r8d,0x61
+    4a:  jne <+0x61b>
+    50:  cmp rdx,0x3
+    54:  jb <+0x89a>
+    5a:  movzx eax,BYTE PTR [rsi+0x2]
+    5e:  or eax,0x20
+    61:  cmp eax,0x79
+    64:  jne <+0x89a>
+    6a:  mov eax,0x3
+    6f:  cmp rdx,0x3
+    73:  je <+0x61b>
+    79:  movzx r8d,BYTE PTR [rsi+0x3]
+    7e:  add r8b,0xad
+    82:  and r8b,0xdf
+    86:  xor eax,eax
+    88:  cmp r8b,0x1
+    8c:  adc rax,0x3
+    90:  jmp <+0x61b>
+    95:  mov ecx,0x3
+    9a:  mov eax,0x1
+    9f:  cmp rdx,0x1
+    a3:  je <+0x61b>
+    a9:  movzx r8d,BYTE PTR [rsi+0x1]
+    ae:  or r8d,0x20
+    b2:  cmp r8d,0x65
+    b6:  jne <+0x61b>
+    bc:  cmp rdx,0x3
+    c0:  jb <+0x89a>
+    c6:  movzx eax,BYTE PTR [rsi+0x2]
+    ca:  or eax,0x20
+    cd:  cmp eax,0x63
+    d0:  jne <+0x89a>
+    d6:  mov eax,0x3
+    db:  cmp rdx,0x3
+    df:  je <+0x61b>
+    e5:  movzx r8d,BYTE PTR [rsi+0x3]
+    ea:  add r8b,0xb1
+    ee:  rol r8b,0x6
+    f2:  cmp r8b,0x9
+    f6:  ja <+0x61b>
+    fc:  movzx r8d,r8b
+   100:  lea r9,[rip]
+   107:  movsxd r8,DWORD PTR [r9+r8*4]
+   10b:  add r8,r9
+   10e:  jmp r8
+   111:  cmp rdx,0x5
+   115:  jb <+0x89a>
+   11b:  movzx eax,BYTE PTR [rsi+0x4]
+   11f:  or eax,0x20
+   122:  cmp eax,0x6e
+   125:  jne <+0x89a>
+   12b:  cmp rdx,0x5
+   12f:  je <+0x89a>
+   135:  movzx eax,BYTE PTR [rsi+0x5]
+   139:  or eax,0x20
+   13c:  cmp eax,0x64
+   13f:  jne <+0x89a>
+   145:  mov eax,0x6
+   14a:  cmp rdx,0x7
+   14e:  jb <+0x61b>
+   154:  movzx r8d,BYTE PTR [rsi+0x6]
+   159:  add r8b,0xad
+   15d:  and r8b,0xdf
+   161:  xor eax,eax
+   163:  cmp r8b,0x1
+   167:  adc rax,0x6
+   16b:  jmp <+0x61b>
+   170:  mov ecx,0x7
+   175:  mov eax,0x1
+   17a:  cmp rdx,0x1
+   17e:  je <+0x61b>
+   184:  movzx r8d,BYTE PTR [rsi+0x1]
+   189:  add r8d,0xffffffbb
+   18d:  cmp r8d,0x26
+   191:  ja <+0x61b>
+   197:  lea r9,[rip]
+   19e:  movsxd r8,DWORD PTR [r9+r8*4]
+   1a2:  add r8,r9
+   1a5:  jmp r8
+   1a8:  cmp rdx,0x2
+   1ac:  jbe <+0x89a>
+   1b2:  movzx eax,BYTE PTR [rsi+0x2]
+   1b6:  or eax,0x20
+   1b9:  cmp eax,0x65
+   1bc:  jne <+0x89a>
+   1c2:  cmp rdx,0x3
+   1c6:  je <+0x89a>
+   1cc:  movzx eax,BYTE PTR [rsi+0x3]
+   1d0:  or eax,0x20
+   1d3:  cmp eax,0x6b
+   1d6:  je <+0x5d5>
+   1dc:  jmp <+0x89a>
+   1e1:  mov ecx,0x5
+   1e6:  mov eax,0x1
+   1eb:  cmp rdx,0x1
+   1ef:  je <+0x61b>
+   1f5:  movzx r8d,BYTE PTR [rsi+0x1]
+   1fa:  add r8d,0xffffffb1
+   1fe:  cmp r8d,0x23
+   202:  ja <+0x61b>
+   208:  lea r9,[rip]
+   20f:  movsxd r8,DWORD PTR [r9+r8*4]
+   213:  add r8,r9
+   216:  jmp r8
+   219:  cmp rdx,0x2
+   21d:  jbe <+0x89a>
+   223:  movzx eax,BYTE PTR [rsi+0x2]
+   227:  or eax,0x20
+   22a:  cmp eax,0x75
+   22d:  je <+0x5bb>
+   233:  jmp <+0x89a>
+   238:  cmp rdx,0x1
+   23c:  je <+0x89a>
+   242:  movzx eax,BYTE PTR [rsi+0x1]
+   246:  or eax,0x20
+   249:  cmp eax,0x73
+   24c:  jne <+0x89a>
+   252:  mov ecx,0x1
+   257:  mov eax,0x2
+   25c:  cmp rdx,0x3
+   260:  jb <+0x61b>
+   266:  movzx r8d,BYTE PTR [rsi+0x2]
+   26b:  or r8d,0x20
+   26f:  cmp r8d,0x65
+   273:  jne <+0x61b>
+   279:  cmp rdx,0x3
+   27d:  je <+0x89a>
+   283:  movzx eax,BYTE PTR [rsi+0x3]
+   287:  or eax,0x20
+   28a:  cmp eax,0x63
+   28d:  je <+0x5d5>
+   293:  jmp <+0x89a>
+   298:  cmp rdx,0x1
+   29c:  je <+0x89a>
+   2a2:  movzx eax,BYTE PTR [rsi+0x1]
+   2a6:  cmp eax,0x60
+   2a9:  jg <+0x418>
+   2af:  cmp eax,0x41
+   2b2:  je <+0x42a>
+   2b8:  cmp eax,0x53
+   2bb:  je <+0x535>
+   2c1:  jmp <+0x89a>
+   2c6:  mov ecx,0x4
+   2cb:  mov eax,0x1
+   2d0:  cmp rdx,0x1
+   2d4:  je <+0x61b>
+   2da:  movzx r8d,BYTE PTR [rsi+0x1]
+   2df:  add r8d,0xffffffb7
+   2e3:  cmp r8d,0x2a
+   2e7:  ja <+0x61b>
+   2ed:  movabs r9,0x100000001
+   2f7:  bt r9,r8
+   2fb:  jb <+0x6a8>
+   301:  movabs r9,0x4000000040
+   30b:  bt r9,r8
+   30f:  jb <+0x63f>
+   315:  movabs r9,0x40000000400
+   31f:  bt r9,r8
+   323:  jae <+0x61b>
+   329:  mov ecx,0x2
+   32e:  mov eax,0x2
+   333:  cmp rdx,0x3
+   337:  jae <+0x266>
+   33d:  jmp <+0x61b>
+   342:  mov ecx,0x9
+   347:  mov eax,0x1
+   34c:  cmp rdx,0x1
+   350:  je <+0x61b>
+   356:  movzx r8d,BYTE PTR [rsi+0x1]
+   35b:  cmp r8d,0x64
+   35f:  jg <+0x595>
+   365:  cmp r8d,0x45
+   369:  je <+0x5a1>
+   36f:  cmp r8d,0x52
+   373:  je <+0x5f9>
+   379:  jmp <+0x61b>
+   37e:  cmp rdx,0x1
+   382:  je <+0x89a>
+   388:  cmp BYTE PTR [rsi+0x1],0xb5
+   38c:  jne <+0x89a>
+   392:  cmp rdx,0x3
+   396:  jb <+0x89a>
+   39c:  movzx eax,BYTE PTR [rsi+0x2]
+   3a0:  or eax,0x20
+   3a3:  cmp eax,0x73
+   3a6:  jne <+0x89a>
+   3ac:  mov ecx,0x1
+   3b1:  mov eax,0x3
+   3b6:  cmp rdx,0x3
+   3ba:  je <+0x61b>
+   3c0:  movzx r8d,BYTE PTR [rsi+0x3]
+   3c5:  or r8d,0x20
+   3c9:  cmp r8d,0x65
+   3cd:  jne <+0x61b>
+   3d3:  cmp rdx,0x5
+   3d7:  jb <+0x89a>
+   3dd:  movzx eax,BYTE PTR [rsi+0x4]
+   3e1:  or eax,0x20
+   3e4:  cmp eax,0x63
+   3e7:  jne <+0x89a>
+   3ed:  mov eax,0x5
+   3f2:  cmp rdx,0x5
+   3f6:  je <+0x61b>
+   3fc:  movzx r8d,BYTE PTR [rsi+0x5]
+   401:  add r8b,0xad
+   405:  and r8b,0xdf
+   409:  xor eax,eax
+   40b:  cmp r8b,0x1
+   40f:  adc rax,0x5
+   413:  jmp <+0x61b>
+   418:  cmp eax,0x73
+   41b:  je <+0x535>
+   421:  cmp eax,0x61
+   424:  jne <+0x89a>
+   42a:  cmp rdx,0x3
+   42e:  jb <+0x89a>
+   434:  movzx eax,BYTE PTR [rsi+0x2]
+   438:  or eax,0x20
+   43b:  cmp eax,0x6e
+   43e:  jne <+0x89a>
+   444:  cmp rdx,0x3
+   448:  je <+0x89a>
+   44e:  movzx eax,BYTE PTR [rsi+0x3]
+   452:  or eax,0x20
+   455:  cmp eax,0x6f
+   458:  jne <+0x89a>
+   45e:  xor ecx,ecx
+   460:  mov eax,0x4
+   465:  cmp rdx,0x5
+   469:  jb <+0x61b>
+   46f:  movzx r8d,BYTE PTR [rsi+0x4]
+   474:  or r8d,0x20
+   478:  cmp r8d,0x73
+   47c:  jne <+0x61b>
+   482:  mov eax,0x5
+   487:  cmp rdx,0x5
+   48b:  je <+0x61b>
+   491:  movzx r8d,BYTE PTR [rsi+0x5]
+   496:  or r8d,0x20
+   49a:  cmp r8d,0x65
+   49e:  jne <+0x61b>
+   4a4:  cmp rdx,0x7
+   4a8:  jb <+0x89a>
+   4ae:  movzx eax,BYTE PTR [rsi+0x6]
+   4b2:  or eax,0x20
+   4b5:  cmp eax,0x63
+   4b8:  jne <+0x89a>
+   4be:  cmp rdx,0x7
+   4c2:  je <+0x89a>
+   4c8:  movzx eax,BYTE PTR [rsi+0x7]
+   4cc:  or eax,0x20
+   4cf:  cmp eax,0x6f
+   4d2:  jne <+0x89a>
+   4d8:  cmp rdx,0x9
+   4dc:  jb <+0x89a>
+   4e2:  movzx eax,BYTE PTR [rsi+0x8]
+   4e6:  or eax,0x20
+   4e9:  cmp eax,0x6e
+   4ec:  jne <+0x89a>
+   4f2:  cmp rdx,0x9
+   4f6:  je <+0x89a>
+   4fc:  movzx eax,BYTE PTR [rsi+0x9]
+   500:  or eax,0x20
+   503:  cmp eax,0x64
+   506:  jne <+0x89a>
+   50c:  mov eax,0xa
+   511:  cmp rdx,0xb
+   515:  jb <+0x61b>
+   51b:  movzx eax,BYTE PTR [rsi+0xa]
+   51f:  add al,0xad
+   521:  and al,0xdf
+   523:  xor ecx,ecx
+   525:  cmp al,0x1
+   527:  mov eax,0x0
+   52c:  adc rax,0xa
+   530:  jmp <+0x61b>
+   535:  xor ecx,ecx
+   537:  mov eax,0x2
+   53c:  cmp rdx,0x3
+   540:  jb <+0x61b>
+   546:  movzx r8d,BYTE PTR [rsi+0x2]
+   54b:  or r8d,0x20
+   54f:  cmp r8d,0x65
+   553:  jne <+0x61b>
+   559:  cmp rdx,0x3
+   55d:  je <+0x89a>
+   563:  movzx eax,BYTE PTR [rsi+0x3]
+   567:  or eax,0x20
+   56a:  cmp eax,0x63
+   56d:  jne <+0x89a>
+   573:  mov eax,0x4
+   578:  cmp rdx,0x5
+   57c:  jb <+0x61b>
+   582:  movzx eax,BYTE PTR [rsi+0x4]
+   586:  add al,0xad
+   588:  and al,0xdf
+   58a:  xor ecx,ecx
+   58c:  cmp al,0x1
+   58e:  mov eax,0x0
+   593:  jmp <+0x5f3>
+   595:  cmp r8d,0x72
+   599:  je <+0x5f9>
+   59b:  cmp r8d,0x65
+   59f:  jne <+0x61b>
+   5a1:  cmp rdx,0x2
+   5a5:  jbe <+0x89a>
+   5ab:  movzx eax,BYTE PTR [rsi+0x2]
+   5af:  or eax,0x20
+   5b2:  cmp eax,0x61
+   5b5:  jne <+0x89a>
+   5bb:  cmp rdx,0x3
+   5bf:  je <+0x89a>
+   5c5:  movzx eax,BYTE PTR [rsi+0x3]
+   5c9:  or eax,0x20
+   5cc:  cmp eax,0x72
+   5cf:  jne <+0x89a>
+   5d5:  mov eax,0x4
+   5da:  cmp rdx,0x5
+   5de:  jb <+0x61b>
+   5e0:  movzx r8d,BYTE PTR [rsi+0x4]
+   5e5:  add r8b,0xad
+   5e9:  and r8b,0xdf
+   5ed:  xor eax,eax
+   5ef:  cmp r8b,0x1
+   5f3:  adc rax,0x4
+   5f7:  jmp <+0x61b>
+   5f9:  mov eax,0x2
+   5fe:  cmp rdx,0x2
+   602:  jbe <+0x61b>
+   604:  movzx r8d,BYTE PTR [rsi+0x2]
+   609:  add r8b,0xad
+   60d:  and r8b,0xdf
+   611:  xor eax,eax
+   613:  cmp r8b,0x1
+   617:  adc rax,0x2
+   61b:  mov r8,rdx
+   61e:  sub r8,rax
+   621:  jb <+0x8ba>
+   627:  add rsi,rax
+   62a:  mov DWORD PTR [rdi+0x8],ecx
+   62d:  mov QWORD PTR [rdi+0x10],rsi
+   631:  mov QWORD PTR [rdi+0x18],r8
+   635:  xor eax,eax
+   637:  mov QWORD PTR [rdi],rax
+   63a:  mov rax,rdi
+   63d:  pop rbx
+   63e:  ret
+   63f:  mov ecx,0x8
+   644:  mov eax,0x2
+   649:  cmp rdx,0x3
+   64d:  jb <+0x61b>
+   64f:  movzx r8d,BYTE PTR [rsi+0x2]
+   654:  add r8d,0xffffffb2
+   658:  cmp r8d,0x25
+   65c:  ja <+0x61b>
+   65e:  lea r9,[rip]
+   665:  movsxd r8,DWORD PTR [r9+r8*4]
+   669:  add r8,r9
+   66c:  jmp r8
+   66f:  cmp rdx,0x3
+   673:  je <+0x89a>
+   679:  movzx eax,BYTE PTR [rsi+0x3]
+   67d:  or eax,0x20
+   680:  cmp eax,0x74
+   683:  jne <+0x89a>
+   689:  cmp rdx,0x5
+   68d:  jb <+0x89a>
+   693:  movzx eax,BYTE PTR [rsi+0x4]
+   697:  or eax,0x20
+   69a:  cmp eax,0x68
+   69d:  je <+0x3ed>
+   6a3:  jmp <+0x89a>
+   6a8:  cmp rdx,0x3
+   6ac:  jb <+0x89a>
+   6b2:  movzx eax,BYTE PTR [rsi+0x2]
+   6b6:  add eax,0xffffffbd
+   6b9:  cmp eax,0x2b
+   6bc:  ja <+0x89a>
+   6c2:  bt r9,rax
+   6c6:  jb <+0x768>
+   6cc:  movabs r8,0x20000000200
+   6d6:  bt r8,rax
+   6da:  jb <+0x72d>
+   6dc:  movabs r8,0x80000000800
+   6e6:  bt r8,rax
+   6ea:  jae <+0x89a>
+   6f0:  mov eax,0x3
+   6f5:  cmp rdx,0x3
+   6f9:  je <+0x61b>
+   6ff:  movzx r8d,BYTE PTR [rsi+0x3]
+   704:  add r8d,0xffffffad
+   708:  cmp r8d,0x22
+   70c:  ja <+0x61b>
+   712:  lea r9,[rip]
+   719:  movsxd r8,DWORD PTR [r9+r8*4]
+   71d:  add r8,r9
+   720:  jmp r8
+   723:  mov eax,0x4
+   728:  jmp <+0x61b>
+   72d:  cmp rdx,0x3
+   731:  je <+0x89a>
+   737:  movzx eax,BYTE PTR [rsi+0x3]
+   73b:  or eax,0x20
+   73e:  cmp eax,0x6c
+   741:  jne <+0x89a>
+   747:  cmp rdx,0x5
+   74b:  jb <+0x89a>
+   751:  movzx eax,BYTE PTR [rsi+0x4]
+   755:  or eax,0x20
+   758:  cmp eax,0x69
+   75b:  jne <+0x89a>
+   761:  mov ecx,0x2
+   766:  jmp <+0x7a1>
+   768:  cmp rdx,0x3
+   76c:  je <+0x89a>
+   772:  movzx eax,BYTE PTR [rsi+0x3]
+   776:  or eax,0x20
+   779:  cmp eax,0x72
+   77c:  jne <+0x89a>
+   782:  cmp rdx,0x5
+   786:  jb <+0x89a>
+   78c:  movzx eax,BYTE PTR [rsi+0x4]
+   790:  or eax,0x20
+   793:  cmp eax,0x6f
+   796:  jne <+0x89a>
+   79c:  mov ecx,0x1
+   7a1:  mov eax,0x5
+   7a6:  cmp rdx,0x5
+   7aa:  je <+0x61b>
+   7b0:  movzx r8d,BYTE PTR [rsi+0x5]
+   7b5:  or r8d,0x20
+   7b9:  cmp r8d,0x73
+   7bd:  jne <+0x61b>
+   7c3:  mov eax,0x6
+   7c8:  cmp rdx,0x7
+   7cc:  jb <+0x61b>
+   7d2:  movzx r8d,BYTE PTR [rsi+0x6]
+   7d7:  or r8d,0x20
+   7db:  cmp r8d,0x65
+   7df:  jne <+0x61b>
+   7e5:  cmp rdx,0x7
+   7e9:  je <+0x89a>
+   7ef:  movzx eax,BYTE PTR [rsi+0x7]
+   7f3:  or eax,0x20
+   7f6:  cmp eax,0x63
+   7f9:  jne <+0x89a>
+   7ff:  cmp rdx,0x9
+   803:  jb <+0x89a>
+   809:  movzx eax,BYTE PTR [rsi+0x8]
+   80d:  or eax,0x20
+   810:  cmp eax,0x6f
+   813:  jne <+0x89a>
+   819:  cmp rdx,0x9
+   81d:  je <+0x89a>
+   81f:  movzx eax,BYTE PTR [rsi+0x9]
+   823:  or eax,0x20
+   826:  cmp eax,0x6e
+   829:  jne <+0x89a>
+   82b:  cmp rdx,0xb
+   82f:  jb <+0x89a>
+   831:  movzx eax,BYTE PTR [rsi+0xa]
+   835:  or eax,0x20
+   838:  cmp eax,0x64
+   83b:  jne <+0x89a>
+   83d:  mov eax,0xb
+   842:  cmp rdx,0xb
+   846:  je <+0x61b>
+   84c:  movzx r8d,BYTE PTR [rsi+0xb]
+   851:  add r8b,0xad
+   855:  and r8b,0xdf
+   859:  xor eax,eax
+   85b:  cmp r8b,0x1
+   85f:  adc rax,0xb
+   863:  jmp <+0x61b>
+   868:  mov eax,0x3
+   86d:  jmp <+0x61b>
+   872:  cmp rdx,0x5
+   876:  jb <+0x89a>
+   878:  movzx eax,BYTE PTR [rsi+0x4]
+   87c:  or eax,0x20
+   87f:  cmp eax,0x74
+   882:  jne <+0x89a>
+   884:  cmp rdx,0x5
+   888:  je <+0x89a>
+   88a:  movzx eax,BYTE PTR [rsi+0x5]
+   88e:  or eax,0x20
+   891:  cmp eax,0x65
+   894:  je <+0x145>
+   89a:  lea rax,[rdi+0x8]
+   89e:  mov rbx,rdi
+   8a1:  mov rdi,rax
+   8a4:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+   8aa:  mov rdi,rbx
+   8ad:  mov eax,0x1
+   8b2:  mov QWORD PTR [rdi],rax
+   8b5:  mov rax,rdi
+   8b8:  pop rbx
+   8b9:  ret
+   8ba:  lea rcx,[rip]
+   8c1:  mov rdi,rax
+   8c4:  mov rsi,rdx
+   8c7:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
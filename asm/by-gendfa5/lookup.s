@@ -0,0 +1,68 @@
+duration_unit_lookup::by_gendfa5::lookup:
+     0:  push rbx
+     1:  mov r9,rdx
+     4:  neg r9
+     7:  mov cx,0x720
+     b:  mov rax,0xffffffffffffffff
+    12:  mov r8,QWORD PTR [rip]
+    19:  nop DWORD PTR [rax+0x0]
+    20:  lea r10,[r9+rax*1]
+    24:  movzx ecx,cx
+    27:  cmp r10,0xffffffffffffffff
+    2b:  je <+0x6a>
+    2d:  movzx r10d,BYTE PTR [rsi+rax*1+0x1]
+    33:  movzx r10d,BYTE PTR [r8+r10*1+0xe4]
+    3c:  add rcx,r10
+    3f:  cmp rcx,0x135f
+    46:  ja <+0xe9>
+    4c:  movzx ecx,WORD PTR [r8+rcx*2+0x1e4]
+    55:  inc rax
+    58:  cmp rcx,0x700
+    5f:  ja <+0x20>
+    61:  shr ecx,0x5
+    64:  mov ecx,DWORD PTR [r8+rcx*4]
+    68:  jmp <+0x91>
+    6a:  cmp ecx,0x134b
+    70:  ja <+0xfe>
+    76:  movzx eax,WORD PTR [r8+rcx*2+0x20c]
+    7f:  cmp rax,0x700
+    85:  ja <+0xb6>
+    87:  shr eax,0x5
+    8a:  mov ecx,DWORD PTR [r8+rax*4]
+    8e:  mov rax,rdx
+    91:  cmp ecx,0xa
+    94:  je <+0xb6>
+    96:  mov r8,rdx
+    99:  sub r8,rax
+    9c:  jb <+0xd6>
+    9e:  add rsi,rax
+    a1:  mov DWORD PTR [rdi+0x8],ecx
+    a4:  mov QWORD PTR [rdi+0x10],rsi
+    a8:  mov QWORD PTR [rdi+0x18],r8
+    ac:  xor eax,eax
+    ae:  mov QWORD PTR [rdi],rax
+    b1:  mov rax,rdi
+    b4:  pop rbx
+    b5:  ret
+    b6:  lea rax,[rdi+0x8]
+    ba:  mov rbx,rdi
+    bd:  mov rdi,rax
+    c0:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+    c6:  mov rdi,rbx
+    c9:  mov eax,0x1
+    ce:  mov QWORD PTR [rdi],rax
+    d1:  mov rax,rdi
+    d4:  pop rbx
+    d5:  ret
+    d6:  lea rcx,[rip]
+    dd:  mov rdi,rax
+    e0:  mov rsi,rdx
+    e3:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
+    e9:  lea rdx,[rip]
+    f0:  mov esi,0x1360
+    f5:  mov rdi,rcx
+    f8:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+    fe:  lea rdi,[rcx+0x14]
+   102:  lea rdx,[rip]
+   109:  mov esi,0x1360
+   10e:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
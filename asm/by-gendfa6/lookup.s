@@ -0,0 +1,708 @@
+duration_unit_lookup::by_gendfa6::lookup:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0x8f1>
+     a:  mov r8b,0x1
+     d:  xor eax,eax
+     f:  lea rcx,[rip]
+    16:  jmp <+0x50>
+    18:  xor r8d,r8d
+    1b:  cmp r9d,0x73
+    1f:  mov r10d,0x15
+    25:  cmovne r10d,r8d
+    29:  cmp r9d,0x61
+    2d:  mov r8d,0x14
+    33:  data16 data16 data16 cs nop WORD PTR [rax+rax*1+0x0]
+    40:  cmovne r8d,r10d
+    44:  inc rax
+    47:  cmp rdx,rax
+    4a:  je <+0x8b6>
+    50:  movzx r9d,BYTE PTR [rsi+rax*1]
+    55:  movzx r8d,r8b
+    59:  movsxd r8,DWORD PTR [rcx+r8*4]
+    5d:  add r8,rcx
+    60:  jmp r8
+    63:  mov r8,QWORD PTR [rip]
+    6a:  movzx r9d,BYTE PTR [r8+r9*1]
+    6f:  xor r8d,r8d
+    72:  add r9d,0xfffffffc
+    76:  cmp r9d,0x17
+    7a:  ja <+0x44>
+    7c:  lea r10,[rip]
+    83:  movsxd r9,DWORD PTR [r10+r9*4]
+    87:  add r9,r10
+    8a:  jmp r9
+    8d:  mov r8b,0x2
+    90:  jmp <+0x44>
+    92:  cmp r9d,0x65
+    96:  sete r8b
+    9a:  or r8b,0x16
+    9e:  jmp <+0x44>
+    a0:  mov r8b,0x3c
+    a3:  jmp <+0x44>
+    a5:  xor r10d,r10d
+    a8:  cmp r9d,0x61
+    ac:  mov r8d,0x35
+    b2:  jmp <+0x40>
+    b4:  xor r10d,r10d
+    b7:  cmp r9d,0x72
+    bb:  mov r8d,0x3b
+    c1:  jmp <+0x40>
+    c6:  xor r10d,r10d
+    c9:  cmp r9d,0xb5
+    d0:  mov r8d,0x1f
+    d6:  jmp <+0x40>
+    db:  xor r10d,r10d
+    de:  cmp r9d,0x6f
+    e2:  mov r8d,0x45
+    e8:  jmp <+0x40>
+    ed:  mov r8b,0x4c
+    f0:  jmp <+0x44>
+    f5:  cmp r9d,0x73
+    f9:  sete r8b
+    fd:  add r8b,0x33
+   101:  jmp <+0x44>
+   106:  cmp r9d,0x6c
+   10a:  sete r8b
+   10e:  shl r8b,0x6
+   112:  jmp <+0x44>
+   117:  cmp r9d,0x65
+   11b:  sete r8b
+   11f:  or r8b,0x2a
+   123:  jmp <+0x44>
+   128:  cmp r9d,0x73
+   12c:  sete r8b
+   130:  add r8b,0x5d
+   134:  jmp <+0x44>
+   139:  xor r10d,r10d
+   13c:  cmp r9d,0x75
+   140:  mov r8d,0x21
+   146:  jmp <+0x40>
+   14b:  xor r10d,r10d
+   14e:  cmp r9d,0x63
+   152:  mov r8d,0x44
+   158:  jmp <+0x40>
+   15d:  xor r10d,r10d
+   160:  cmp r9d,0x74
+   164:  mov r8d,0x42
+   16a:  jmp <+0x40>
+   16f:  xor r10d,r10d
+   172:  cmp r9d,0x74
+   176:  mov r8d,0x55
+   17c:  jmp <+0x40>
+   181:  mov r8b,0x4e
+   184:  jmp <+0x44>
+   189:  mov r8b,0x43
+   18c:  jmp <+0x44>
+   191:  xor r10d,r10d
+   194:  cmp r9d,0x72
+   198:  mov r8d,0x41
+   19e:  jmp <+0x40>
+   1a3:  mov r8b,0x68
+   1a6:  jmp <+0x44>
+   1ab:  cmp r9d,0x73
+   1af:  sete r8b
+   1b3:  or r8b,0x6e
+   1b7:  jmp <+0x44>
+   1bc:  xor r10d,r10d
+   1bf:  cmp r9d,0x64
+   1c3:  mov r8d,0x74
+   1c9:  jmp <+0x40>
+   1ce:  cmp r9d,0x73
+   1d2:  sete r8b
+   1d6:  add r8b,0x39
+   1da:  jmp <+0x44>
+   1df:  cmp r9d,0x65
+   1e3:  sete r8b
+   1e7:  add r8b,0x2d
+   1eb:  jmp <+0x44>
+   1f0:  mov r8b,0x77
+   1f3:  jmp <+0x44>
+   1f8:  mov r8b,0x19
+   1fb:  cmp r9b,0x65
+   1ff:  jb <+0x44>
+   205:  cmp r9,0x65
+   209:  setne r10b
+   20d:  cmp r9b,0x6b
+   211:  setb r11b
+   215:  test r10b,r11b
+   218:  jne <+0x44>
+   21e:  mov r10,QWORD PTR [rip]
+   225:  movzx r10d,BYTE PTR [r10+r9*1]
+   22a:  add r10b,0xf5
+   22e:  cmp r10b,0x12
+   232:  jb <+0x44>
+   238:  xor r8d,r8d
+   23b:  cmp r9d,0x6b
+   23f:  mov r10d,0x1b
+   245:  cmovne r10d,r8d
+   249:  cmp r9d,0x65
+   24d:  mov r8d,0x1a
+   253:  jmp <+0x40>
+   258:  cmp r9d,0x73
+   25c:  sete r8b
+   260:  or r8b,0x36
+   264:  jmp <+0x44>
+   269:  cmp r9d,0x73
+   26d:  sete r8b
+   271:  or r8b,0x52
+   275:  jmp <+0x44>
+   27a:  mov r8b,0x73
+   27d:  jmp <+0x44>
+   282:  mov r8b,0x70
+   285:  jmp <+0x44>
+   28a:  mov r8b,0x10
+   28d:  cmp r9b,0x69
+   291:  jb <+0x44>
+   297:  mov r10,QWORD PTR [rip]
+   29e:  movzx r10d,BYTE PTR [r10+r9*1]
+   2a3:  cmp r9d,0x69
+   2a7:  setne r11b
+   2ab:  cmp r9b,0x6f
+   2af:  setb r9b
+   2b3:  lea ebx,[r10-0x12]
+   2b7:  cmp bl,0xb
+   2ba:  jb <+0x44>
+   2c0:  and r11b,r9b
+   2c3:  jne <+0x44>
+   2c9:  lea r9d,[r10-0xf]
+   2cd:  cmp r9b,0x2
+   2d1:  jb <+0x44>
+   2d7:  cmp r10d,0x11
+   2db:  je <+0x8ae>
+   2e1:  cmp r10d,0xe
+   2e5:  je <+0x8a6>
+   2eb:  cmp r10d,0x8
+   2ef:  jne <+0x84e>
+   2f5:  mov r8b,0x11
+   2f8:  jmp <+0x44>
+   2fd:  cmp r9d,0x73
+   301:  sete r8b
+   305:  or r8b,0x7a
+   309:  jmp <+0x44>
+   30e:  cmp r9d,0x65
+   312:  sete r8b
+   316:  or r8b,0x7e
+   31a:  jmp <+0x44>
+   31f:  mov r8b,0x89
+   322:  jmp <+0x44>
+   327:  xor r10d,r10d
+   32a:  cmp r9d,0x63
+   32e:  mov r8d,0x46
+   334:  jmp <+0x40>
+   339:  cmp r9d,0x73
+   33d:  sete r8b
+   341:  or r8b,0x82
+   345:  jmp <+0x44>
+   34a:  xor r10d,r10d
+   34d:  cmp r9d,0x6f
+   351:  mov r8d,0x8a
+   357:  jmp <+0x40>
+   35c:  mov r8b,0x27
+   35f:  cmp r9b,0x6e
+   363:  jb <+0x44>
+   369:  cmp r9d,0x6e
+   36d:  setne r10b
+   371:  cmp r9b,0x73
+   375:  setb r11b
+   379:  test r10b,r11b
+   37c:  jne <+0x44>
+   382:  mov r10,QWORD PTR [rip]
+   389:  movzx r10d,BYTE PTR [r10+r9*1]
+   38e:  add r10b,0xee
+   392:  cmp r10b,0xb
+   396:  jb <+0x44>
+   39c:  xor r8d,r8d
+   39f:  cmp r9d,0x73
+   3a3:  mov r10d,0x29
+   3a9:  cmovne r10d,r8d
+   3ad:  cmp r9d,0x6e
+   3b1:  mov r8d,0x28
+   3b7:  jmp <+0x40>
+   3bc:  xor r10d,r10d
+   3bf:  cmp r9d,0x73
+   3c3:  mov r8d,0x38
+   3c9:  jmp <+0x40>
+   3ce:  cmp r9d,0x73
+   3d2:  sete r8b
+   3d6:  or r8b,0x6a
+   3da:  jmp <+0x44>
+   3df:  mov r8b,0x84
+   3e2:  jmp <+0x44>
+   3e7:  cmp r9d,0x65
+   3eb:  sete r8b
+   3ef:  or r8b,0x30
+   3f3:  jmp <+0x44>
+   3f8:  cmp r9d,0x73
+   3fc:  sete r8b
+   400:  add r8b,0x63
+   404:  jmp <+0x44>
+   409:  cmp r9d,0x73
+   40d:  sete r8b
+   411:  or r8b,0x96
+   415:  jmp <+0x44>
+   41a:  xor r10d,r10d
+   41d:  cmp r9d,0x63
+   421:  mov r8d,0x67
+   427:  jmp <+0x40>
+   42c:  mov r8b,0x98
+   42f:  jmp <+0x44>
+   434:  mov r8b,0x60
+   437:  jmp <+0x44>
+   43c:  xor r10d,r10d
+   43f:  cmp r9d,0x64
+   443:  mov r8d,0x8f
+   449:  jmp <+0x40>
+   44e:  cmp r9d,0x73
+   452:  sete r8b
+   456:  or r8b,0x94
+   45a:  jmp <+0x44>
+   45f:  mov r8b,0x99
+   462:  jmp <+0x44>
+   467:  xor r10d,r10d
+   46a:  cmp r9d,0x6f
+   46e:  mov r8d,0x88
+   474:  jmp <+0x40>
+   479:  xor r10d,r10d
+   47c:  cmp r9d,0x6e
+   480:  mov r8d,0x8c
+   486:  jmp <+0x40>
+   48b:  mov r8b,0x9a
+   48e:  jmp <+0x44>
+   493:  xor r10d,r10d
+   496:  cmp r9d,0x6f
+   49a:  mov r8d,0x8b
+   4a0:  jmp <+0x40>
+   4a5:  cmp r9d,0x73
+   4a9:  sete r8b
+   4ad:  or r8b,0x92
+   4b1:  jmp <+0x44>
+   4b6:  xor r10d,r10d
+   4b9:  cmp r9d,0x63
+   4bd:  mov r8d,0x87
+   4c3:  jmp <+0x40>
+   4c8:  xor r10d,r10d
+   4cb:  cmp r9d,0x69
+   4cf:  mov r8d,0x56
+   4d5:  jmp <+0x40>
+   4da:  mov r8b,0x80
+   4dd:  jmp <+0x44>
+   4e2:  xor r10d,r10d
+   4e5:  cmp r9d,0x64
+   4e9:  mov r8d,0x90
+   4ef:  jmp <+0x40>
+   4f4:  xor r10d,r10d
+   4f7:  cmp r9d,0x64
+   4fb:  mov r8d,0x91
+   501:  jmp <+0x40>
+   506:  cmp r9d,0x73
+   50a:  sete r8b
+   50e:  or r8b,0x6c
+   512:  jmp <+0x44>
+   517:  xor r10d,r10d
+   51a:  cmp r9d,0x6e
+   51e:  mov r8d,0x8e
+   524:  jmp <+0x40>
+   529:  xor r10d,r10d
+   52c:  cmp r9d,0x6e
+   530:  mov r8d,0x8d
+   536:  jmp <+0x40>
+   53b:  mov r8b,0x85
+   53e:  jmp <+0x44>
+   543:  xor r10d,r10d
+   546:  cmp r9d,0x6f
+   54a:  mov r8d,0x57
+   550:  jmp <+0x40>
+   555:  xor r10d,r10d
+   558:  cmp r9d,0x63
+   55c:  mov r8d,0x4a
+   562:  jmp <+0x40>
+   567:  xor r10d,r10d
+   56a:  cmp r9d,0x65
+   56e:  mov r8d,0x69
+   574:  jmp <+0x40>
+   579:  cmp r9d,0x73
+   57d:  sete r8b
+   581:  add r8b,0x59
+   585:  jmp <+0x44>
+   58a:  mov r8b,0xd
+   58d:  cmp r9b,0x6f
+   591:  jb <+0x44>
+   597:  cmp r9d,0x6f
+   59b:  sete r8b
+   59f:  add r8b,0xd
+   5a3:  cmp r9d,0x72
+   5a7:  movzx r9d,r8b
+   5ab:  mov r8d,0xf
+   5b1:  cmovne r8d,r9d
+   5b5:  jmp <+0x44>
+   5ba:  cmp r9d,0x65
+   5be:  sete r8b
+   5c2:  or r8b,0x7c
+   5c6:  jmp <+0x44>
+   5cb:  xor r10d,r10d
+   5ce:  cmp r9d,0x63
+   5d2:  mov r8d,0x81
+   5d8:  jmp <+0x40>
+   5dd:  mov r8b,0x75
+   5e0:  jmp <+0x44>
+   5e5:  xor r10d,r10d
+   5e8:  cmp r9d,0x63
+   5ec:  mov r8d,0x86
+   5f2:  jmp <+0x40>
+   5f7:  cmp r9d,0x73
+   5fb:  sete r8b
+   5ff:  add r8b,0x65
+   603:  jmp <+0x44>
+   608:  xor r10d,r10d
+   60b:  cmp r9d,0x6b
+   60f:  mov r8d,0x4b
+   615:  jmp <+0x40>
+   61a:  cmp r9d,0x79
+   61e:  sete r8b
+   622:  shl r8b,0x5
+   626:  jmp <+0x44>
+   62b:  mov r8b,0x1c
+   62e:  cmp r9b,0x65
+   632:  jb <+0x44>
+   638:  cmp r9d,0x65
+   63c:  setne r10b
+   640:  cmp r9b,0x72
+   644:  setb r11b
+   648:  test r10b,r11b
+   64b:  jne <+0x44>
+   651:  mov r10,QWORD PTR [rip]
+   658:  movzx r10d,BYTE PTR [r10+r9*1]
+   65d:  add r10b,0xef
+   661:  cmp r10b,0xc
+   665:  jb <+0x44>
+   66b:  xor r8d,r8d
+   66e:  cmp r9d,0x72
+   672:  mov r10d,0x1e
+   678:  cmovne r10d,r8d
+   67c:  cmp r9d,0x65
+   680:  mov r8d,0x1d
+   686:  jmp <+0x40>
+   68b:  mov r8b,0x76
+   68e:  jmp <+0x44>
+   693:  cmp r9d,0x65
+   697:  sete r8b
+   69b:  add r8b,0x71
+   69f:  jmp <+0x44>
+   6a4:  xor r10d,r10d
+   6a7:  cmp r9d,0x65
+   6ab:  mov r8d,0x32
+   6b1:  jmp <+0x40>
+   6b6:  cmp r9d,0x65
+   6ba:  sete r8b
+   6be:  add r8b,0x4f
+   6c2:  jmp <+0x44>
+   6c7:  xor r10d,r10d
+   6ca:  cmp r9d,0x72
+   6ce:  mov r8d,0x4d
+   6d4:  jmp <+0x40>
+   6d9:  cmp r9d,0x73
+   6dd:  sete r8b
+   6e1:  or r8b,0x78
+   6e5:  jmp <+0x44>
+   6ea:  mov r8b,0x3d
+   6ed:  cmp r9b,0x73
+   6f1:  jb <+0x44>
+   6f7:  cmp r9,0x73
+   6fb:  sete r8b
+   6ff:  add r8b,0x3d
+   703:  cmp r9,0x75
+   707:  movzx r9d,r8b
+   70b:  mov r8d,0x3f
+   711:  cmovne r8d,r9d
+   715:  jmp <+0x44>
+   71a:  xor r10d,r10d
+   71d:  cmp r9d,0x6e
+   721:  mov r8d,0x2c
+   727:  jmp <+0x40>
+   72c:  mov r8b,0x47
+   72f:  cmp r9b,0x6f
+   733:  jb <+0x44>
+   739:  cmp r9d,0x6f
+   73d:  setne r10b
+   741:  cmp r9b,0x73
+   745:  setb r11b
+   749:  test r10b,r11b
+   74c:  jne <+0x44>
+   752:  mov r10,QWORD PTR [rip]
+   759:  movzx r10d,BYTE PTR [r10+r9*1]
+   75e:  add r10b,0xee
+   762:  cmp r10b,0xb
+   766:  jb <+0x44>
+   76c:  xor r8d,r8d
+   76f:  cmp r9d,0x73
+   773:  mov r10d,0x49
+   779:  cmovne r10d,r8d
+   77d:  cmp r9d,0x6f
+   781:  mov r8d,0x48
+   787:  jmp <+0x40>
+   78c:  mov r8b,0x51
+   78f:  jmp <+0x44>
+   794:  cmp r9d,0x61
+   798:  sete r8b
+   79c:  add r8b,0xb
+   7a0:  jmp <+0x44>
+   7a5:  xor r10d,r10d
+   7a8:  cmp r9d,0x6e
+   7ac:  mov r8d,0x5f
+   7b2:  jmp <+0x40>
+   7b7:  cmp r9d,0x73
+   7bb:  sete r8b
+   7bf:  add r8b,0x5b
+   7c3:  jmp <+0x44>
+   7c8:  cmp r9d,0x73
+   7cc:  sete r8b
+   7d0:  add r8b,0x61
+   7d4:  jmp <+0x44>
+   7d9:  xor r10d,r10d
+   7dc:  cmp r9d,0x68
+   7e0:  mov r8d,0x58
+   7e6:  jmp <+0x40>
+   7eb:  xor r10d,r10d
+   7ee:  cmp r9d,0x63
+   7f2:  mov r8d,0x2f
+   7f8:  jmp <+0x40>
+   7fd:  mov r8b,0x54
+   800:  jmp <+0x44>
+   805:  xor r10d,r10d
+   808:  cmp r9d,0x73
+   80c:  mov r8d,0x18
+   812:  jmp <+0x40>
+   817:  cmp r9d,0x73
+   81b:  sete r8b
+   81f:  or r8b,0x22
+   823:  jmp <+0x44>
+   828:  mov r8,QWORD PTR [rip]
+   82f:  movzx r8d,BYTE PTR [r8+r9*1]
+   834:  cmp r8d,0x3
+   838:  je <+0x85e>
+   83a:  cmp r8d,0xb
+   83e:  je <+0x856>
+   840:  cmp r8d,0xd
+   844:  jne <+0x84e>
+   846:  mov r8b,0x24
+   849:  jmp <+0x44>
+   84e:  xor r8d,r8d
+   851:  jmp <+0x44>
+   856:  mov r8b,0x25
+   859:  jmp <+0x44>
+   85e:  mov r8b,0x26
+   861:  jmp <+0x44>
+   866:  mov r8b,0x4
+   869:  jmp <+0x44>
+   86e:  mov r8b,0x7
+   871:  jmp <+0x44>
+   876:  mov r8b,0x3
+   879:  jmp <+0x44>
+   87e:  mov r8b,0x8
+   881:  jmp <+0x44>
+   886:  mov r8b,0x9
+   889:  jmp <+0x44>
+   88e:  mov r8b,0x5
+   891:  jmp <+0x44>
+   896:  mov r8b,0x6
+   899:  jmp <+0x44>
+   89e:  mov r8b,0xa
+   8a1:  jmp <+0x44>
+   8a6:  mov r8b,0x12
+   8a9:  jmp <+0x44>
+   8ae:  mov r8b,0x13
+   8b1:  jmp <+0x44>
+   8b6:  movzx r8d,r8b
+   8ba:  add r8d,0xfffffffe
+   8be:  cmp r8d,0x98
+   8c5:  ja <+0x8f1>
+   8c7:  mov ecx,0x6
+   8cc:  mov eax,0x1
+   8d1:  lea r9,[rip]
+   8d8:  movsxd r8,DWORD PTR [r9+r8*4]
+   8dc:  add r8,r9
+   8df:  jmp r8
+   8e2:  mov ecx,0x1
+   8e7:  mov eax,0x5
+   8ec:  jmp <+0xb16>
+   8f1:  lea rax,[rdi+0x8]
+   8f5:  mov rbx,rdi
+   8f8:  mov rdi,rax
+   8fb:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+   901:  mov rdi,rbx
+   904:  mov eax,0x1
+   909:  mov QWORD PTR [rdi],rax
+   90c:  mov rax,rdi
+   90f:  pop rbx
+   910:  ret
+   911:  mov ecx,0x1
+   916:  mov eax,0x6
+   91b:  jmp <+0xb16>
+   920:  mov ecx,0x2
+   925:  mov eax,0x5
+   92a:  jmp <+0xb16>
+   92f:  xor ecx,ecx
+   931:  mov eax,0x5
+   936:  jmp <+0xb16>
+   93b:  xor ecx,ecx
+   93d:  mov eax,0x4
+   942:  jmp <+0xb16>
+   947:  mov ecx,0x1
+   94c:  jmp <+0xb11>
+   951:  mov ecx,0x4
+   956:  mov eax,0x3
+   95b:  jmp <+0xb16>
+   960:  xor ecx,ecx
+   962:  jmp <+0xb11>
+   967:  mov ecx,0x2
+   96c:  jmp <+0xb11>
+   971:  mov ecx,0x7
+   976:  mov eax,0x3
+   97b:  jmp <+0xb16>
+   980:  mov ecx,0x7
+   985:  jmp <+0xb11>
+   98a:  mov ecx,0x3
+   98f:  mov eax,0x4
+   994:  jmp <+0xb16>
+   999:  mov ecx,0x7
+   99e:  mov eax,0x4
+   9a3:  jmp <+0xb16>
+   9a8:  mov ecx,0x4
+   9ad:  mov eax,0x6
+   9b2:  jmp <+0xb16>
+   9b7:  mov ecx,0x1
+   9bc:  mov eax,0x3
+   9c1:  jmp <+0xb16>
+   9c6:  mov ecx,0x8
+   9cb:  mov eax,0x6
+   9d0:  jmp <+0xb16>
+   9d5:  mov ecx,0x3
+   9da:  mov eax,0x3
+   9df:  jmp <+0xb16>
+   9e4:  mov ecx,0x9
+   9e9:  jmp <+0xb11>
+   9ee:  mov ecx,0x5
+   9f3:  mov eax,0x4
+   9f8:  jmp <+0xb16>
+   9fd:  mov ecx,0x8
+   a02:  mov eax,0x5
+   a07:  jmp <+0xb16>
+   a0c:  mov ecx,0x5
+   a11:  mov eax,0x5
+   a16:  jmp <+0xb16>
+   a1b:  mov ecx,0x1
+   a20:  mov eax,0xb
+   a25:  jmp <+0xb16>
+   a2a:  mov ecx,0x9
+   a2f:  mov eax,0x5
+   a34:  jmp <+0xb16>
+   a39:  mov ecx,0x3
+   a3e:  mov eax,0x7
+   a43:  jmp <+0xb16>
+   a48:  mov ecx,0x1
+   a4d:  mov eax,0xc
+   a52:  jmp <+0xb16>
+   a57:  mov ecx,0x5
+   a5c:  mov eax,0x3
+   a61:  jmp <+0xb16>
+   a66:  mov ecx,0x9
+   a6b:  mov eax,0x3
+   a70:  jmp <+0xb16>
+   a75:  mov ecx,0x2
+   a7a:  mov eax,0x6
+   a7f:  jmp <+0xb16>
+   a84:  mov ecx,0x2
+   a89:  mov eax,0xc
+   a8e:  jmp <+0xb16>
+   a93:  mov ecx,0x9
+   a98:  mov eax,0x4
+   a9d:  jmp <+0xb16>
+   a9f:  mov ecx,0x2
+   aa4:  mov eax,0x4
+   aa9:  jmp <+0xb16>
+   aab:  mov ecx,0x2
+   ab0:  mov eax,0xb
+   ab5:  jmp <+0xb16>
+   ab7:  mov ecx,0x3
+   abc:  mov eax,0x6
+   ac1:  jmp <+0xb16>
+   ac3:  xor ecx,ecx
+   ac5:  mov eax,0xa
+   aca:  jmp <+0xb16>
+   acc:  mov ecx,0x4
+   ad1:  mov eax,0x7
+   ad6:  jmp <+0xb16>
+   ad8:  xor ecx,ecx
+   ada:  mov eax,0xb
+   adf:  jmp <+0xb16>
+   ae1:  mov ecx,0x1
+   ae6:  mov eax,0x4
+   aeb:  jmp <+0xb16>
+   aed:  mov ecx,0x8
+   af2:  mov eax,0x3
+   af7:  jmp <+0xb16>
+   af9:  mov ecx,0x4
+   afe:  mov eax,0x4
+   b03:  jmp <+0xb16>
+   b05:  mov ecx,0x5
+   b0a:  jmp <+0xb11>
+   b0c:  mov ecx,0x8
+   b11:  mov eax,0x2
+   b16:  mov r8,rdx
+   b19:  sub r8,rax
+   b1c:  jb <+0xbe9>
+   b22:  add rsi,rax
+   b25:  mov DWORD PTR [rdi+0x8],ecx
+   b28:  mov QWORD PTR [rdi+0x10],rsi
+   b2c:  mov QWORD PTR [rdi+0x18],r8
+   b30:  xor eax,eax
+   b32:  mov QWORD PTR [rdi],rax
+   b35:  mov rax,rdi
+   b38:  pop rbx
+   b39:  ret
+   b3a:  mov ecx,0x7
+   b3f:  mov eax,0x5
+   b44:  jmp <+0xb16>
+   b46:  mov eax,0x3
+   b4b:  jmp <+0xb16>
+   b4d:  mov ecx,0x9
+   b52:  jmp <+0xb16>
+   b54:  mov ecx,0x7
+   b59:  jmp <+0xb16>
+   b5b:  mov ecx,0x3
+   b60:  jmp <+0xb16>
+   b62:  mov ecx,0x5
+   b67:  jmp <+0xb16>
+   b69:  mov eax,0x4
+   b6e:  jmp <+0xb16>
+   b70:  mov ecx,0x4
+   b75:  jmp <+0xb16>
+   b77:  mov ecx,0x5
+   b7c:  mov eax,0x1
+   b81:  jmp <+0xb16>
+   b83:  mov ecx,0x7
+   b88:  mov eax,0x1
+   b8d:  jmp <+0xb16>
+   b8f:  mov ecx,0x9
+   b94:  mov eax,0x1
+   b99:  jmp <+0xb16>
+   b9e:  mov ecx,0x6
+   ba3:  mov eax,0x3
+   ba8:  jmp <+0xb16>
+   bad:  mov ecx,0x4
+   bb2:  mov eax,0x1
+   bb7:  jmp <+0xb16>
+   bbc:  mov ecx,0x6
+   bc1:  mov eax,0x4
+   bc6:  jmp <+0xb16>
+   bcb:  mov ecx,0x6
+   bd0:  mov eax,0x1
+   bd5:  jmp <+0xb16>
+   bda:  mov ecx,0x3
+   bdf:  mov eax,0x1
+   be4:  jmp <+0xb16>
+   be9:  lea rcx,[rip]
+   bf0:  mov rdi,rax
+   bf3:  mov rsi,rdx
+   bf6:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
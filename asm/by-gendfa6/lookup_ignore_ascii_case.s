@@ -0,0 +1,1348 @@
+duration_unit_lookup::by_gendfa6::lookup_ignore_ascii_case:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0x13a5>
+     a:  mov r9b,0x1
+     d:  xor eax,eax
+     f:  lea r8,[rip]
+    16:  jmp <+0x2c>
+    18:  mov r9b,0x3c
+    1b:  nop DWORD PTR [rax+rax*1+0x0]
+    20:  inc rax
+    23:  cmp rdx,rax
+    26:  je <+0x136a>
+    2c:  movzx r10d,BYTE PTR [rsi+rax*1]
+    31:  movzx ecx,r9b
+    35:  movsxd rcx,DWORD PTR [r8+rcx*4]
+    39:  add rcx,r8
+    3c:  jmp rcx
+    3e:  mov rcx,QWORD PTR [rip]
+    45:  movzx ecx,BYTE PTR [rcx+r10*1]
+    4a:  xor r9d,r9d
+    4d:  add ecx,0xfffffffc
+    50:  cmp ecx,0x2f
+    53:  ja <+0x20>
+    55:  lea r10,[rip]
+    5c:  movsxd rcx,DWORD PTR [r10+rcx*4]
+    60:  add rcx,r10
+    63:  jmp rcx
+    65:  mov r9b,0x2
+    68:  jmp <+0x20>
+    6a:  mov rcx,QWORD PTR [rip]
+    71:  movzx ecx,BYTE PTR [rcx+r10*1]
+    76:  dec cl
+    78:  rol cl,0x5
+    7b:  cmp cl,0x6
+    7e:  jae <+0x1267>
+    84:  shl cl,0x3
+    87:  movabs r9,0x150014150014
+    91:  shr r9,cl
+    94:  jmp <+0x20>
+    96:  mov r9b,0x16
+    99:  cmp r10b,0x45
+    9d:  jb <+0x20>
+    9f:  cmp r10d,0x45
+    a3:  setne cl
+    a6:  cmp r10b,0x65
+    aa:  setb r11b
+    ae:  test cl,r11b
+    b1:  jne <+0x20>
+    b7:  mov rcx,QWORD PTR [rip]
+    be:  movzx ecx,BYTE PTR [rcx+r10*1]
+    c3:  add cl,0xe2
+    c6:  cmp cl,0x17
+    c9:  jb <+0x20>
+    cf:  and r10b,0xdf
+    d3:  xor ecx,ecx
+    d5:  cmp r10b,0x45
+    d9:  mov r9d,0x17
+    df:  cmovne r9d,ecx
+    e3:  jmp <+0x20>
+    e8:  and r10b,0xdf
+    ec:  xor ecx,ecx
+    ee:  cmp r10b,0x41
+    f2:  mov r9d,0x35
+    f8:  cmovne r9d,ecx
+    fc:  jmp <+0x20>
+   101:  and r10b,0xdf
+   105:  xor ecx,ecx
+   107:  cmp r10b,0x52
+   10b:  mov r9d,0x3b
+   111:  cmovne r9d,ecx
+   115:  jmp <+0x20>
+   11a:  xor ecx,ecx
+   11c:  cmp r10d,0xb5
+   123:  mov r9d,0x1f
+   129:  cmovne r9d,ecx
+   12d:  jmp <+0x20>
+   132:  and r10b,0xdf
+   136:  xor ecx,ecx
+   138:  cmp r10b,0x4f
+   13c:  mov r9d,0x45
+   142:  cmovne r9d,ecx
+   146:  jmp <+0x20>
+   14b:  mov r9b,0x4c
+   14e:  jmp <+0x20>
+   153:  mov r9b,0x33
+   156:  cmp r10b,0x53
+   15a:  jb <+0x20>
+   160:  cmp r10d,0x53
+   164:  setne cl
+   167:  cmp r10b,0x73
+   16b:  setb r11b
+   16f:  test cl,r11b
+   172:  jne <+0x20>
+   178:  mov rcx,QWORD PTR [rip]
+   17f:  movzx ecx,BYTE PTR [rcx+r10*1]
+   184:  add cl,0xd6
+   187:  cmp cl,0xb
+   18a:  jb <+0x20>
+   190:  and r10b,0xdf
+   194:  xor ecx,ecx
+   196:  cmp r10b,0x53
+   19a:  mov r9d,0x34
+   1a0:  cmovne r9d,ecx
+   1a4:  jmp <+0x20>
+   1a9:  and r10b,0xdf
+   1ad:  cmp r10b,0x4c
+   1b1:  sete r9b
+   1b5:  shl r9b,0x6
+   1b9:  jmp <+0x20>
+   1be:  mov r9b,0x2a
+   1c1:  cmp r10b,0x45
+   1c5:  jb <+0x20>
+   1cb:  cmp r10d,0x45
+   1cf:  setne cl
+   1d2:  cmp r10b,0x65
+   1d6:  setb r11b
+   1da:  test cl,r11b
+   1dd:  jne <+0x20>
+   1e3:  mov rcx,QWORD PTR [rip]
+   1ea:  movzx ecx,BYTE PTR [rcx+r10*1]
+   1ef:  add cl,0xe2
+   1f2:  cmp cl,0x17
+   1f5:  jb <+0x20>
+   1fb:  and r10b,0xdf
+   1ff:  xor ecx,ecx
+   201:  cmp r10b,0x45
+   205:  mov r9d,0x2b
+   20b:  cmovne r9d,ecx
+   20f:  jmp <+0x20>
+   214:  mov r9b,0x5d
+   217:  cmp r10b,0x53
+   21b:  jb <+0x20>
+   221:  cmp r10d,0x53
+   225:  setne cl
+   228:  cmp r10b,0x73
+   22c:  setb r11b
+   230:  test cl,r11b
+   233:  jne <+0x20>
+   239:  mov rcx,QWORD PTR [rip]
+   240:  movzx ecx,BYTE PTR [rcx+r10*1]
+   245:  add cl,0xd6
+   248:  cmp cl,0xb
+   24b:  jb <+0x20>
+   251:  and r10b,0xdf
+   255:  xor ecx,ecx
+   257:  cmp r10b,0x53
+   25b:  mov r9d,0x5e
+   261:  cmovne r9d,ecx
+   265:  jmp <+0x20>
+   26a:  and r10b,0xdf
+   26e:  xor ecx,ecx
+   270:  cmp r10b,0x55
+   274:  mov r9d,0x21
+   27a:  cmovne r9d,ecx
+   27e:  jmp <+0x20>
+   283:  and r10b,0xdf
+   287:  xor ecx,ecx
+   289:  cmp r10b,0x43
+   28d:  mov r9d,0x44
+   293:  cmovne r9d,ecx
+   297:  jmp <+0x20>
+   29c:  and r10b,0xdf
+   2a0:  xor ecx,ecx
+   2a2:  cmp r10b,0x54
+   2a6:  mov r9d,0x42
+   2ac:  cmovne r9d,ecx
+   2b0:  jmp <+0x20>
+   2b5:  and r10b,0xdf
+   2b9:  xor ecx,ecx
+   2bb:  cmp r10b,0x54
+   2bf:  mov r9d,0x55
+   2c5:  cmovne r9d,ecx
+   2c9:  jmp <+0x20>
+   2ce:  mov r9b,0x4e
+   2d1:  jmp <+0x20>
+   2d6:  mov r9b,0x43
+   2d9:  jmp <+0x20>
+   2de:  and r10b,0xdf
+   2e2:  xor ecx,ecx
+   2e4:  cmp r10b,0x52
+   2e8:  mov r9d,0x41
+   2ee:  cmovne r9d,ecx
+   2f2:  jmp <+0x20>
+   2f7:  mov r9b,0x68
+   2fa:  jmp <+0x20>
+   2ff:  mov r9b,0x6e
+   302:  cmp r10b,0x53
+   306:  jb <+0x20>
+   30c:  cmp r10d,0x53
+   310:  setne cl
+   313:  cmp r10b,0x73
+   317:  setb r11b
+   31b:  test cl,r11b
+   31e:  jne <+0x20>
+   324:  mov rcx,QWORD PTR [rip]
+   32b:  movzx ecx,BYTE PTR [rcx+r10*1]
+   330:  add cl,0xd6
+   333:  cmp cl,0xb
+   336:  jb <+0x20>
+   33c:  and r10b,0xdf
+   340:  xor ecx,ecx
+   342:  cmp r10b,0x53
+   346:  mov r9d,0x6f
+   34c:  cmovne r9d,ecx
+   350:  jmp <+0x20>
+   355:  and r10b,0xdf
+   359:  xor ecx,ecx
+   35b:  cmp r10b,0x44
+   35f:  mov r9d,0x74
+   365:  cmovne r9d,ecx
+   369:  jmp <+0x20>
+   36e:  mov r9b,0x39
+   371:  cmp r10b,0x53
+   375:  jb <+0x20>
+   37b:  cmp r10d,0x53
+   37f:  setne cl
+   382:  cmp r10b,0x73
+   386:  setb r11b
+   38a:  test cl,r11b
+   38d:  jne <+0x20>
+   393:  mov rcx,QWORD PTR [rip]
+   39a:  movzx ecx,BYTE PTR [rcx+r10*1]
+   39f:  add cl,0xd6
+   3a2:  cmp cl,0xb
+   3a5:  jb <+0x20>
+   3ab:  and r10b,0xdf
+   3af:  xor ecx,ecx
+   3b1:  cmp r10b,0x53
+   3b5:  mov r9d,0x3a
+   3bb:  cmovne r9d,ecx
+   3bf:  jmp <+0x20>
+   3c4:  mov r9b,0x2d
+   3c7:  cmp r10b,0x45
+   3cb:  jb <+0x20>
+   3d1:  cmp r10d,0x45
+   3d5:  setne cl
+   3d8:  cmp r10b,0x65
+   3dc:  setb r11b
+   3e0:  test cl,r11b
+   3e3:  jne <+0x20>
+   3e9:  mov rcx,QWORD PTR [rip]
+   3f0:  movzx ecx,BYTE PTR [rcx+r10*1]
+   3f5:  add cl,0xe2
+   3f8:  cmp cl,0x17
+   3fb:  jb <+0x20>
+   401:  and r10b,0xdf
+   405:  xor ecx,ecx
+   407:  cmp r10b,0x45
+   40b:  mov r9d,0x2e
+   411:  cmovne r9d,ecx
+   415:  jmp <+0x20>
+   41a:  mov r9b,0x77
+   41d:  jmp <+0x20>
+   422:  mov r9b,0x19
+   425:  cmp r10b,0x45
+   429:  jb <+0x20>
+   42f:  mov rcx,QWORD PTR [rip]
+   436:  movzx ecx,BYTE PTR [rcx+r10*1]
+   43b:  cmp r10d,0x45
+   43f:  setne r11b
+   443:  cmp r10b,0x4b
+   447:  setb r10b
+   44b:  lea ebx,[rcx-0x23]
+   44e:  cmp bl,0x12
+   451:  jb <+0x20>
+   457:  lea ebx,[rcx-0x1e]
+   45a:  cmp bl,0x4
+   45d:  jb <+0x20>
+   463:  and r11b,r10b
+   466:  jne <+0x20>
+   46c:  lea r10d,[rcx-0xb]
+   470:  cmp r10b,0x12
+   474:  jb <+0x20>
+   47a:  xor r9d,r9d
+   47d:  add ecx,0xfffffffb
+   480:  cmp ecx,0x1d
+   483:  ja <+0x20>
+   489:  lea r10,[rip]
+   490:  movsxd rcx,DWORD PTR [r10+rcx*4]
+   494:  add rcx,r10
+   497:  jmp rcx
+   499:  mov r9b,0x1a
+   49c:  jmp <+0x20>
+   4a1:  mov r9b,0x36
+   4a4:  cmp r10b,0x53
+   4a8:  jb <+0x20>
+   4ae:  cmp r10d,0x53
+   4b2:  setne cl
+   4b5:  cmp r10b,0x73
+   4b9:  setb r11b
+   4bd:  test cl,r11b
+   4c0:  jne <+0x20>
+   4c6:  mov rcx,QWORD PTR [rip]
+   4cd:  movzx ecx,BYTE PTR [rcx+r10*1]
+   4d2:  add cl,0xd6
+   4d5:  cmp cl,0xb
+   4d8:  jb <+0x20>
+   4de:  and r10b,0xdf
+   4e2:  xor ecx,ecx
+   4e4:  cmp r10b,0x53
+   4e8:  mov r9d,0x37
+   4ee:  cmovne r9d,ecx
+   4f2:  jmp <+0x20>
+   4f7:  mov r9b,0x52
+   4fa:  cmp r10b,0x53
+   4fe:  jb <+0x20>
+   504:  cmp r10d,0x53
+   508:  setne cl
+   50b:  cmp r10b,0x73
+   50f:  setb r11b
+   513:  test cl,r11b
+   516:  jne <+0x20>
+   51c:  mov rcx,QWORD PTR [rip]
+   523:  movzx ecx,BYTE PTR [rcx+r10*1]
+   528:  add cl,0xd6
+   52b:  cmp cl,0xb
+   52e:  jb <+0x20>
+   534:  and r10b,0xdf
+   538:  xor ecx,ecx
+   53a:  cmp r10b,0x53
+   53e:  mov r9d,0x53
+   544:  cmovne r9d,ecx
+   548:  jmp <+0x20>
+   54d:  mov r9b,0x73
+   550:  jmp <+0x20>
+   555:  mov r9b,0x70
+   558:  jmp <+0x20>
+   55d:  mov r9b,0x10
+   560:  cmp r10b,0x49
+   564:  jb <+0x20>
+   56a:  mov rcx,QWORD PTR [rip]
+   571:  movzx ecx,BYTE PTR [rcx+r10*1]
+   576:  cmp r10d,0x49
+   57a:  setne r11b
+   57e:  cmp r10b,0x4f
+   582:  movd xmm0,ecx
+   586:  punpcklbw xmm0,xmm0
+   58a:  pshuflw xmm0,xmm0,0x0
+   58f:  paddb xmm0,XMMWORD PTR [rip]
+   597:  setb r10b
+   59b:  movd xmm1,DWORD PTR [rip]
+   5a3:  pmaxub xmm1,xmm0
+   5a7:  pcmpeqb xmm1,xmm0
+   5ab:  punpcklbw xmm1,xmm1
+   5af:  punpcklwd xmm1,xmm1
+   5b3:  movmskps ebx,xmm1
+   5b6:  xor ebx,0xf
+   5b9:  jne <+0x20>
+   5bf:  lea ebx,[rcx-0x2a]
+   5c2:  cmp bl,0xb
+   5c5:  jb <+0x20>
+   5cb:  and r11b,r10b
+   5ce:  jne <+0x20>
+   5d4:  xor r9d,r9d
+   5d7:  cmp ecx,0x29
+   5da:  ja <+0x20>
+   5e0:  mov ecx,ecx
+   5e2:  movabs r10,0x100000100
+   5ec:  bt r10,rcx
+   5f0:  jb <+0x130e>
+   5f6:  movabs r10,0x4000004000
+   600:  bt r10,rcx
+   604:  jb <+0x1306>
+   60a:  movabs r10,0x20000020000
+   614:  bt r10,rcx
+   618:  jae <+0x20>
+   61e:  mov r9b,0x13
+   621:  jmp <+0x20>
+   626:  mov r9b,0x7a
+   629:  cmp r10b,0x53
+   62d:  jb <+0x20>
+   633:  cmp r10d,0x53
+   637:  setne cl
+   63a:  cmp r10b,0x73
+   63e:  setb r11b
+   642:  test cl,r11b
+   645:  jne <+0x20>
+   64b:  mov rcx,QWORD PTR [rip]
+   652:  movzx ecx,BYTE PTR [rcx+r10*1]
+   657:  add cl,0xd6
+   65a:  cmp cl,0xb
+   65d:  jb <+0x20>
+   663:  and r10b,0xdf
+   667:  xor ecx,ecx
+   669:  cmp r10b,0x53
+   66d:  mov r9d,0x7b
+   673:  cmovne r9d,ecx
+   677:  jmp <+0x20>
+   67c:  mov r9b,0x7e
+   67f:  cmp r10b,0x45
+   683:  jb <+0x20>
+   689:  cmp r10d,0x45
+   68d:  setne cl
+   690:  cmp r10b,0x65
+   694:  setb r11b
+   698:  test cl,r11b
+   69b:  jne <+0x20>
+   6a1:  mov rcx,QWORD PTR [rip]
+   6a8:  movzx ecx,BYTE PTR [rcx+r10*1]
+   6ad:  add cl,0xe2
+   6b0:  cmp cl,0x17
+   6b3:  jb <+0x20>
+   6b9:  and r10b,0xdf
+   6bd:  xor ecx,ecx
+   6bf:  cmp r10b,0x45
+   6c3:  mov r9d,0x7f
+   6c9:  cmovne r9d,ecx
+   6cd:  jmp <+0x20>
+   6d2:  mov r9b,0x89
+   6d5:  jmp <+0x20>
+   6da:  and r10b,0xdf
+   6de:  xor ecx,ecx
+   6e0:  cmp r10b,0x43
+   6e4:  mov r9d,0x46
+   6ea:  cmovne r9d,ecx
+   6ee:  jmp <+0x20>
+   6f3:  mov r9b,0x82
+   6f6:  cmp r10b,0x53
+   6fa:  jb <+0x20>
+   700:  cmp r10d,0x53
+   704:  setne cl
+   707:  cmp r10b,0x73
+   70b:  setb r11b
+   70f:  test cl,r11b
+   712:  jne <+0x20>
+   718:  mov rcx,QWORD PTR [rip]
+   71f:  movzx ecx,BYTE PTR [rcx+r10*1]
+   724:  add cl,0xd6
+   727:  cmp cl,0xb
+   72a:  jb <+0x20>
+   730:  and r10b,0xdf
+   734:  xor ecx,ecx
+   736:  cmp r10b,0x53
+   73a:  mov r9d,0x83
+   740:  cmovne r9d,ecx
+   744:  jmp <+0x20>
+   749:  and r10b,0xdf
+   74d:  xor ecx,ecx
+   74f:  cmp r10b,0x4f
+   753:  mov r9d,0x8a
+   759:  cmovne r9d,ecx
+   75d:  jmp <+0x20>
+   762:  mov r9b,0x27
+   765:  cmp r10b,0x4e
+   769:  jb <+0x20>
+   76f:  mov rcx,QWORD PTR [rip]
+   776:  movzx ecx,BYTE PTR [rcx+r10*1]
+   77b:  cmp r10d,0x4e
+   77f:  setne r11b
+   783:  cmp r10b,0x53
+   787:  setb r10b
+   78b:  lea ebx,[rcx-0x2a]
+   78e:  cmp bl,0xb
+   791:  jb <+0x20>
+   797:  lea ebx,[rcx-0x26]
+   79a:  cmp bl,0x3
+   79d:  jb <+0x20>
+   7a3:  and r11b,r10b
+   7a6:  jne <+0x20>
+   7ac:  lea r10d,[rcx-0x12]
+   7b0:  cmp r10b,0x13
+   7b4:  jb <+0x20>
+   7ba:  add cl,0xf3
+   7bd:  rol cl,0x6
+   7c0:  cmp cl,0x8
+   7c3:  jae <+0x12ae>
+   7c9:  shl cl,0x3
+   7cc:  movabs r9,0x2928000000002928
+   7d6:  jmp <+0x91>
+   7db:  and r10b,0xdf
+   7df:  xor ecx,ecx
+   7e1:  cmp r10b,0x53
+   7e5:  mov r9d,0x38
+   7eb:  cmovne r9d,ecx
+   7ef:  jmp <+0x20>
+   7f4:  mov r9b,0x6a
+   7f7:  cmp r10b,0x53
+   7fb:  jb <+0x20>
+   801:  cmp r10d,0x53
+   805:  setne cl
+   808:  cmp r10b,0x73
+   80c:  setb r11b
+   810:  test cl,r11b
+   813:  jne <+0x20>
+   819:  mov rcx,QWORD PTR [rip]
+   820:  movzx ecx,BYTE PTR [rcx+r10*1]
+   825:  add cl,0xd6
+   828:  cmp cl,0xb
+   82b:  jb <+0x20>
+   831:  and r10b,0xdf
+   835:  xor ecx,ecx
+   837:  cmp r10b,0x53
+   83b:  mov r9d,0x6b
+   841:  cmovne r9d,ecx
+   845:  jmp <+0x20>
+   84a:  mov r9b,0x84
+   84d:  jmp <+0x20>
+   852:  mov r9b,0x30
+   855:  cmp r10b,0x45
+   859:  jb <+0x20>
+   85f:  cmp r10,0x45
+   863:  setne cl
+   866:  cmp r10b,0x65
+   86a:  setb r11b
+   86e:  test cl,r11b
+   871:  jne <+0x20>
+   877:  mov rcx,QWORD PTR [rip]
+   87e:  movzx ecx,BYTE PTR [rcx+r10*1]
+   883:  add cl,0xe2
+   886:  cmp cl,0x17
+   889:  jb <+0x20>
+   88f:  and r10b,0xdf
+   893:  xor ecx,ecx
+   895:  cmp r10b,0x45
+   899:  mov r9d,0x31
+   89f:  cmovne r9d,ecx
+   8a3:  jmp <+0x20>
+   8a8:  mov r9b,0x63
+   8ab:  cmp r10b,0x53
+   8af:  jb <+0x20>
+   8b5:  cmp r10d,0x53
+   8b9:  setne cl
+   8bc:  cmp r10b,0x73
+   8c0:  setb r11b
+   8c4:  test cl,r11b
+   8c7:  jne <+0x20>
+   8cd:  mov rcx,QWORD PTR [rip]
+   8d4:  movzx ecx,BYTE PTR [rcx+r10*1]
+   8d9:  add cl,0xd6
+   8dc:  cmp cl,0xb
+   8df:  jb <+0x20>
+   8e5:  and r10b,0xdf
+   8e9:  xor ecx,ecx
+   8eb:  cmp r10b,0x53
+   8ef:  mov r9d,0x64
+   8f5:  cmovne r9d,ecx
+   8f9:  jmp <+0x20>
+   8fe:  mov r9b,0x96
+   901:  cmp r10b,0x53
+   905:  jb <+0x20>
+   90b:  cmp r10d,0x53
+   90f:  setne cl
+   912:  cmp r10b,0x73
+   916:  setb r11b
+   91a:  test cl,r11b
+   91d:  jne <+0x20>
+   923:  mov rcx,QWORD PTR [rip]
+   92a:  movzx ecx,BYTE PTR [rcx+r10*1]
+   92f:  add cl,0xd6
+   932:  cmp cl,0xb
+   935:  jb <+0x20>
+   93b:  and r10b,0xdf
+   93f:  xor ecx,ecx
+   941:  cmp r10b,0x53
+   945:  mov r9d,0x97
+   94b:  cmovne r9d,ecx
+   94f:  jmp <+0x20>
+   954:  and r10b,0xdf
+   958:  xor ecx,ecx
+   95a:  cmp r10b,0x43
+   95e:  mov r9d,0x67
+   964:  cmovne r9d,ecx
+   968:  jmp <+0x20>
+   96d:  mov r9b,0x98
+   970:  jmp <+0x20>
+   975:  mov r9b,0x60
+   978:  jmp <+0x20>
+   97d:  and r10b,0xdf
+   981:  xor ecx,ecx
+   983:  cmp r10b,0x44
+   987:  mov r9d,0x8f
+   98d:  cmovne r9d,ecx
+   991:  jmp <+0x20>
+   996:  mov r9b,0x94
+   999:  cmp r10b,0x53
+   99d:  jb <+0x20>
+   9a3:  cmp r10d,0x53
+   9a7:  setne cl
+   9aa:  cmp r10b,0x73
+   9ae:  setb r11b
+   9b2:  test cl,r11b
+   9b5:  jne <+0x20>
+   9bb:  mov rcx,QWORD PTR [rip]
+   9c2:  movzx ecx,BYTE PTR [rcx+r10*1]
+   9c7:  add cl,0xd6
+   9ca:  cmp cl,0xb
+   9cd:  jb <+0x20>
+   9d3:  and r10b,0xdf
+   9d7:  xor ecx,ecx
+   9d9:  cmp r10b,0x53
+   9dd:  mov r9d,0x95
+   9e3:  cmovne r9d,ecx
+   9e7:  jmp <+0x20>
+   9ec:  mov r9b,0x99
+   9ef:  jmp <+0x20>
+   9f4:  and r10b,0xdf
+   9f8:  xor ecx,ecx
+   9fa:  cmp r10b,0x4f
+   9fe:  mov r9d,0x88
+   a04:  cmovne r9d,ecx
+   a08:  jmp <+0x20>
+   a0d:  and r10b,0xdf
+   a11:  xor ecx,ecx
+   a13:  cmp r10b,0x4e
+   a17:  mov r9d,0x8c
+   a1d:  cmovne r9d,ecx
+   a21:  jmp <+0x20>
+   a26:  mov r9b,0x9a
+   a29:  jmp <+0x20>
+   a2e:  and r10b,0xdf
+   a32:  xor ecx,ecx
+   a34:  cmp r10b,0x4f
+   a38:  mov r9d,0x8b
+   a3e:  cmovne r9d,ecx
+   a42:  jmp <+0x20>
+   a47:  mov r9b,0x92
+   a4a:  cmp r10b,0x53
+   a4e:  jb <+0x20>
+   a54:  cmp r10d,0x53
+   a58:  setne cl
+   a5b:  cmp r10b,0x73
+   a5f:  setb r11b
+   a63:  test cl,r11b
+   a66:  jne <+0x20>
+   a6c:  mov rcx,QWORD PTR [rip]
+   a73:  movzx ecx,BYTE PTR [rcx+r10*1]
+   a78:  add cl,0xd6
+   a7b:  cmp cl,0xb
+   a7e:  jb <+0x20>
+   a84:  and r10b,0xdf
+   a88:  xor ecx,ecx
+   a8a:  cmp r10b,0x53
+   a8e:  mov r9d,0x93
+   a94:  cmovne r9d,ecx
+   a98:  jmp <+0x20>
+   a9d:  and r10b,0xdf
+   aa1:  xor ecx,ecx
+   aa3:  cmp r10b,0x43
+   aa7:  mov r9d,0x87
+   aad:  cmovne r9d,ecx
+   ab1:  jmp <+0x20>
+   ab6:  and r10b,0xdf
+   aba:  xor ecx,ecx
+   abc:  cmp r10b,0x49
+   ac0:  mov r9d,0x56
+   ac6:  cmovne r9d,ecx
+   aca:  jmp <+0x20>
+   acf:  mov r9b,0x80
+   ad2:  jmp <+0x20>
+   ad7:  and r10b,0xdf
+   adb:  xor ecx,ecx
+   add:  cmp r10b,0x44
+   ae1:  mov r9d,0x90
+   ae7:  cmovne r9d,ecx
+   aeb:  jmp <+0x20>
+   af0:  and r10b,0xdf
+   af4:  xor ecx,ecx
+   af6:  cmp r10b,0x44
+   afa:  mov r9d,0x91
+   b00:  cmovne r9d,ecx
+   b04:  jmp <+0x20>
+   b09:  mov r9b,0x6c
+   b0c:  cmp r10b,0x53
+   b10:  jb <+0x20>
+   b16:  cmp r10d,0x53
+   b1a:  setne cl
+   b1d:  cmp r10b,0x73
+   b21:  setb r11b
+   b25:  test cl,r11b
+   b28:  jne <+0x20>
+   b2e:  mov rcx,QWORD PTR [rip]
+   b35:  movzx ecx,BYTE PTR [rcx+r10*1]
+   b3a:  add cl,0xd6
+   b3d:  cmp cl,0xb
+   b40:  jb <+0x20>
+   b46:  and r10b,0xdf
+   b4a:  xor ecx,ecx
+   b4c:  cmp r10b,0x53
+   b50:  mov r9d,0x6d
+   b56:  cmovne r9d,ecx
+   b5a:  jmp <+0x20>
+   b5f:  and r10b,0xdf
+   b63:  xor ecx,ecx
+   b65:  cmp r10b,0x4e
+   b69:  mov r9d,0x8e
+   b6f:  cmovne r9d,ecx
+   b73:  jmp <+0x20>
+   b78:  and r10b,0xdf
+   b7c:  xor ecx,ecx
+   b7e:  cmp r10b,0x4e
+   b82:  mov r9d,0x8d
+   b88:  cmovne r9d,ecx
+   b8c:  jmp <+0x20>
+   b91:  mov r9b,0x85
+   b94:  jmp <+0x20>
+   b99:  and r10b,0xdf
+   b9d:  xor ecx,ecx
+   b9f:  cmp r10b,0x4f
+   ba3:  mov r9d,0x57
+   ba9:  cmovne r9d,ecx
+   bad:  jmp <+0x20>
+   bb2:  and r10b,0xdf
+   bb6:  xor ecx,ecx
+   bb8:  cmp r10b,0x43
+   bbc:  mov r9d,0x4a
+   bc2:  cmovne r9d,ecx
+   bc6:  jmp <+0x20>
+   bcb:  and r10b,0xdf
+   bcf:  xor ecx,ecx
+   bd1:  cmp r10b,0x45
+   bd5:  mov r9d,0x69
+   bdb:  cmovne r9d,ecx
+   bdf:  jmp <+0x20>
+   be4:  mov r9b,0x59
+   be7:  cmp r10b,0x53
+   beb:  jb <+0x20>
+   bf1:  cmp r10d,0x53
+   bf5:  setne cl
+   bf8:  cmp r10b,0x73
+   bfc:  setb r11b
+   c00:  test cl,r11b
+   c03:  jne <+0x20>
+   c09:  mov rcx,QWORD PTR [rip]
+   c10:  movzx ecx,BYTE PTR [rcx+r10*1]
+   c15:  add cl,0xd6
+   c18:  cmp cl,0xb
+   c1b:  jb <+0x20>
+   c21:  and r10b,0xdf
+   c25:  xor ecx,ecx
+   c27:  cmp r10b,0x53
+   c2b:  mov r9d,0x5a
+   c31:  cmovne r9d,ecx
+   c35:  jmp <+0x20>
+   c3a:  mov r9b,0xd
+   c3d:  cmp r10b,0x4f
+   c41:  jb <+0x20>
+   c47:  mov rcx,QWORD PTR [rip]
+   c4e:  movzx ecx,BYTE PTR [rcx+r10*1]
+   c53:  cmp ecx,0x28
+   c56:  ja <+0x1316>
+   c5c:  mov r10d,ecx
+   c5f:  movabs r11,0x4000004000
+   c69:  bt r11,r10
+   c6d:  jb <+0x129e>
+   c73:  movabs r11,0x8000008000
+   c7d:  bt r11,r10
+   c81:  jb <+0x20>
+   c87:  movabs r11,0x10000010000
+   c91:  bt r11,r10
+   c95:  jae <+0x1316>
+   c9b:  mov r9b,0xf
+   c9e:  jmp <+0x20>
+   ca3:  mov r9b,0x7c
+   ca6:  cmp r10b,0x45
+   caa:  jb <+0x20>
+   cb0:  cmp r10d,0x45
+   cb4:  setne cl
+   cb7:  cmp r10b,0x65
+   cbb:  setb r11b
+   cbf:  test cl,r11b
+   cc2:  jne <+0x20>
+   cc8:  mov rcx,QWORD PTR [rip]
+   ccf:  movzx ecx,BYTE PTR [rcx+r10*1]
+   cd4:  add cl,0xe2
+   cd7:  cmp cl,0x17
+   cda:  jb <+0x20>
+   ce0:  and r10b,0xdf
+   ce4:  xor ecx,ecx
+   ce6:  cmp r10b,0x45
+   cea:  mov r9d,0x7d
+   cf0:  cmovne r9d,ecx
+   cf4:  jmp <+0x20>
+   cf9:  and r10b,0xdf
+   cfd:  xor ecx,ecx
+   cff:  cmp r10b,0x43
+   d03:  mov r9d,0x81
+   d09:  cmovne r9d,ecx
+   d0d:  jmp <+0x20>
+   d12:  mov r9b,0x75
+   d15:  jmp <+0x20>
+   d1a:  and r10b,0xdf
+   d1e:  xor ecx,ecx
+   d20:  cmp r10b,0x43
+   d24:  mov r9d,0x86
+   d2a:  cmovne r9d,ecx
+   d2e:  jmp <+0x20>
+   d33:  mov r9b,0x65
+   d36:  cmp r10b,0x53
+   d3a:  jb <+0x20>
+   d40:  cmp r10d,0x53
+   d44:  setne cl
+   d47:  cmp r10b,0x73
+   d4b:  setb r11b
+   d4f:  test cl,r11b
+   d52:  jne <+0x20>
+   d58:  mov rcx,QWORD PTR [rip]
+   d5f:  movzx ecx,BYTE PTR [rcx+r10*1]
+   d64:  add cl,0xd6
+   d67:  cmp cl,0xb
+   d6a:  jb <+0x20>
+   d70:  and r10b,0xdf
+   d74:  xor ecx,ecx
+   d76:  cmp r10b,0x53
+   d7a:  mov r9d,0x66
+   d80:  cmovne r9d,ecx
+   d84:  jmp <+0x20>
+   d89:  and r10b,0xdf
+   d8d:  xor ecx,ecx
+   d8f:  cmp r10b,0x4b
+   d93:  mov r9d,0x4b
+   d99:  cmovne r9d,ecx
+   d9d:  jmp <+0x20>
+   da2:  and r10b,0xdf
+   da6:  cmp r10b,0x59
+   daa:  sete r9b
+   dae:  shl r9b,0x5
+   db2:  jmp <+0x20>
+   db7:  mov r9b,0x1c
+   dba:  cmp r10b,0x45
+   dbe:  jb <+0x20>
+   dc4:  mov rcx,QWORD PTR [rip]
+   dcb:  movzx ecx,BYTE PTR [rcx+r10*1]
+   dd0:  cmp r10d,0x45
+   dd4:  setne r11b
+   dd8:  cmp r10b,0x52
+   ddc:  setb r10b
+   de0:  lea ebx,[rcx-0x29]
+   de3:  cmp bl,0xc
+   de6:  jb <+0x20>
+   dec:  lea ebx,[rcx-0x1e]
+   def:  cmp bl,0xa
+   df2:  jb <+0x20>
+   df8:  and r11b,r10b
+   dfb:  jne <+0x20>
+   e01:  lea r10d,[rcx-0x11]
+   e05:  cmp r10b,0xc
+   e09:  jb <+0x20>
+   e0f:  xor r9d,r9d
+   e12:  add ecx,0xfffffffb
+   e15:  cmp ecx,0x23
+   e18:  ja <+0x20>
+   e1e:  lea r10,[rip]
+   e25:  movsxd rcx,DWORD PTR [r10+rcx*4]
+   e29:  add rcx,r10
+   e2c:  jmp rcx
+   e2e:  mov r9b,0x1d
+   e31:  jmp <+0x20>
+   e36:  mov r9b,0x76
+   e39:  jmp <+0x20>
+   e3e:  mov r9b,0x71
+   e41:  cmp r10b,0x45
+   e45:  jb <+0x20>
+   e4b:  cmp r10d,0x45
+   e4f:  setne cl
+   e52:  cmp r10b,0x65
+   e56:  setb r11b
+   e5a:  test cl,r11b
+   e5d:  jne <+0x20>
+   e63:  mov rcx,QWORD PTR [rip]
+   e6a:  movzx ecx,BYTE PTR [rcx+r10*1]
+   e6f:  add cl,0xe2
+   e72:  cmp cl,0x17
+   e75:  jb <+0x20>
+   e7b:  and r10b,0xdf
+   e7f:  xor ecx,ecx
+   e81:  cmp r10b,0x45
+   e85:  mov r9d,0x72
+   e8b:  cmovne r9d,ecx
+   e8f:  jmp <+0x20>
+   e94:  and r10b,0xdf
+   e98:  xor ecx,ecx
+   e9a:  cmp r10b,0x45
+   e9e:  mov r9d,0x32
+   ea4:  cmovne r9d,ecx
+   ea8:  jmp <+0x20>
+   ead:  mov r9b,0x4f
+   eb0:  cmp r10b,0x45
+   eb4:  jb <+0x20>
+   eba:  cmp r10d,0x45
+   ebe:  setne cl
+   ec1:  cmp r10b,0x65
+   ec5:  setb r11b
+   ec9:  test cl,r11b
+   ecc:  jne <+0x20>
+   ed2:  mov rcx,QWORD PTR [rip]
+   ed9:  movzx ecx,BYTE PTR [rcx+r10*1]
+   ede:  add cl,0xe2
+   ee1:  cmp cl,0x17
+   ee4:  jb <+0x20>
+   eea:  and r10b,0xdf
+   eee:  xor ecx,ecx
+   ef0:  cmp r10b,0x45
+   ef4:  mov r9d,0x50
+   efa:  cmovne r9d,ecx
+   efe:  jmp <+0x20>
+   f03:  and r10b,0xdf
+   f07:  xor ecx,ecx
+   f09:  cmp r10b,0x52
+   f0d:  mov r9d,0x4d
+   f13:  cmovne r9d,ecx
+   f17:  jmp <+0x20>
+   f1c:  mov r9b,0x78
+   f1f:  cmp r10b,0x53
+   f23:  jb <+0x20>
+   f29:  cmp r10d,0x53
+   f2d:  setne cl
+   f30:  cmp r10b,0x73
+   f34:  setb r11b
+   f38:  test cl,r11b
+   f3b:  jne <+0x20>
+   f41:  mov rcx,QWORD PTR [rip]
+   f48:  movzx ecx,BYTE PTR [rcx+r10*1]
+   f4d:  add cl,0xd6
+   f50:  cmp cl,0xb
+   f53:  jb <+0x20>
+   f59:  and r10b,0xdf
+   f5d:  xor ecx,ecx
+   f5f:  cmp r10b,0x53
+   f63:  mov r9d,0x79
+   f69:  cmovne r9d,ecx
+   f6d:  jmp <+0x20>
+   f72:  mov r9b,0x3d
+   f75:  cmp r10b,0x53
+   f79:  jb <+0x20>
+   f7f:  mov rcx,QWORD PTR [rip]
+   f86:  movzx ecx,BYTE PTR [rcx+r10*1]
+   f8b:  cmp ecx,0x2b
+   f8e:  ja <+0x133c>
+   f94:  mov r10d,ecx
+   f97:  movabs r11,0x20000020000
+   fa1:  bt r11,r10
+   fa5:  jb <+0x12a6>
+   fab:  movabs r11,0x40000040000
+   fb5:  bt r11,r10
+   fb9:  jb <+0x20>
+   fbf:  movabs r11,0x80000080000
+   fc9:  bt r11,r10
+   fcd:  jae <+0x133c>
+   fd3:  mov r9b,0x3f
+   fd6:  jmp <+0x20>
+   fdb:  and r10b,0xdf
+   fdf:  xor ecx,ecx
+   fe1:  cmp r10b,0x4e
+   fe5:  mov r9d,0x2c
+   feb:  cmovne r9d,ecx
+   fef:  jmp <+0x20>
+   ff4:  mov r9b,0x47
+   ff7:  cmp r10b,0x4f
+   ffb:  jb <+0x20>
+  1001:  mov rcx,QWORD PTR [rip]
+  1008:  movzx ecx,BYTE PTR [rcx+r10*1]
+  100d:  cmp r10d,0x4f
+  1011:  setne r11b
+  1015:  cmp r10b,0x53
+  1019:  setb r10b
+  101d:  lea ebx,[rcx-0x2a]
+  1020:  cmp bl,0xb
+  1023:  jb <+0x20>
+  1029:  lea ebx,[rcx-0x27]
+  102c:  cmp bl,0x2
+  102f:  jb <+0x20>
+  1035:  and r11b,r10b
+  1038:  jne <+0x20>
+  103e:  lea r10d,[rcx-0x12]
+  1042:  cmp r10b,0x14
+  1046:  jb <+0x20>
+  104c:  xor r9d,r9d
+  104f:  add ecx,0xfffffff2
+  1052:  cmp ecx,0x1b
+  1055:  ja <+0x20>
+  105b:  lea r10,[rip]
+  1062:  movsxd rcx,DWORD PTR [r10+rcx*4]
+  1066:  add rcx,r10
+  1069:  jmp rcx
+  106b:  mov r9b,0x48
+  106e:  jmp <+0x20>
+  1073:  mov r9b,0x51
+  1076:  jmp <+0x20>
+  107b:  mov rcx,QWORD PTR [rip]
+  1082:  movzx ecx,BYTE PTR [rcx+r10*1]
+  1087:  mov r9b,0xb
+  108a:  test ecx,ecx
+  108c:  je <+0x20>
+  1092:  cmp ecx,0x1
+  1095:  je <+0x10a0>
+  1097:  cmp ecx,0x19
+  109a:  jne <+0x127f>
+  10a0:  mov r9b,0xc
+  10a3:  jmp <+0x20>
+  10a8:  and r10b,0xdf
+  10ac:  xor ecx,ecx
+  10ae:  cmp r10b,0x4e
+  10b2:  mov r9d,0x5f
+  10b8:  cmovne r9d,ecx
+  10bc:  jmp <+0x20>
+  10c1:  mov r9b,0x5b
+  10c4:  cmp r10b,0x53
+  10c8:  jb <+0x20>
+  10ce:  cmp r10d,0x53
+  10d2:  setne cl
+  10d5:  cmp r10b,0x73
+  10d9:  setb r11b
+  10dd:  test cl,r11b
+  10e0:  jne <+0x20>
+  10e6:  mov rcx,QWORD PTR [rip]
+  10ed:  movzx ecx,BYTE PTR [rcx+r10*1]
+  10f2:  add cl,0xd6
+  10f5:  cmp cl,0xb
+  10f8:  jb <+0x20>
+  10fe:  and r10b,0xdf
+  1102:  xor ecx,ecx
+  1104:  cmp r10b,0x53
+  1108:  mov r9d,0x5c
+  110e:  cmovne r9d,ecx
+  1112:  jmp <+0x20>
+  1117:  mov r9b,0x61
+  111a:  cmp r10b,0x53
+  111e:  jb <+0x20>
+  1124:  cmp r10d,0x53
+  1128:  setne cl
+  112b:  cmp r10b,0x73
+  112f:  setb r11b
+  1133:  test cl,r11b
+  1136:  jne <+0x20>
+  113c:  mov rcx,QWORD PTR [rip]
+  1143:  movzx ecx,BYTE PTR [rcx+r10*1]
+  1148:  add cl,0xd6
+  114b:  cmp cl,0xb
+  114e:  jb <+0x20>
+  1154:  and r10b,0xdf
+  1158:  xor ecx,ecx
+  115a:  cmp r10b,0x53
+  115e:  mov r9d,0x62
+  1164:  cmovne r9d,ecx
+  1168:  jmp <+0x20>
+  116d:  and r10b,0xdf
+  1171:  xor ecx,ecx
+  1173:  cmp r10b,0x48
+  1177:  mov r9d,0x58
+  117d:  cmovne r9d,ecx
+  1181:  jmp <+0x20>
+  1186:  and r10b,0xdf
+  118a:  xor ecx,ecx
+  118c:  cmp r10b,0x43
+  1190:  mov r9d,0x2f
+  1196:  cmovne r9d,ecx
+  119a:  jmp <+0x20>
+  119f:  mov r9b,0x54
+  11a2:  jmp <+0x20>
+  11a7:  and r10b,0xdf
+  11ab:  xor ecx,ecx
+  11ad:  cmp r10b,0x53
+  11b1:  mov r9d,0x18
+  11b7:  cmovne r9d,ecx
+  11bb:  jmp <+0x20>
+  11c0:  mov r9b,0x22
+  11c3:  cmp r10b,0x53
+  11c7:  jb <+0x20>
+  11cd:  cmp r10d,0x53
+  11d1:  setne cl
+  11d4:  cmp r10b,0x73
+  11d8:  setb r11b
+  11dc:  test cl,r11b
+  11df:  jne <+0x20>
+  11e5:  mov rcx,QWORD PTR [rip]
+  11ec:  movzx ecx,BYTE PTR [rcx+r10*1]
+  11f1:  add cl,0xd6
+  11f4:  cmp cl,0xb
+  11f7:  jb <+0x20>
+  11fd:  and r10b,0xdf
+  1201:  xor ecx,ecx
+  1203:  cmp r10b,0x53
+  1207:  mov r9d,0x23
+  120d:  cmovne r9d,ecx
+  1211:  jmp <+0x20>
+  1216:  mov rcx,QWORD PTR [rip]
+  121d:  movzx ecx,BYTE PTR [rcx+r10*1]
+  1222:  xor r9d,r9d
+  1225:  cmp rcx,0x25
+  1229:  ja <+0x20>
+  122f:  mov r10d,0x8000008
+  1235:  bt r10,rcx
+  1239:  jb <+0x1277>
+  123b:  movabs r10,0x800000800
+  1245:  bt r10,rcx
+  1249:  jb <+0x126f>
+  124b:  movabs r10,0x2000002000
+  1255:  bt r10,rcx
+  1259:  jae <+0x20>
+  125f:  mov r9b,0x24
+  1262:  jmp <+0x20>
+  1267:  xor r9d,r9d
+  126a:  jmp <+0x20>
+  126f:  mov r9b,0x25
+  1272:  jmp <+0x20>
+  1277:  mov r9b,0x26
+  127a:  jmp <+0x20>
+  127f:  cmp r10b,0x61
+  1283:  jb <+0x20>
+  1289:  xor ecx,ecx
+  128b:  cmp r10d,0x61
+  128f:  mov r9d,0xb
+  1295:  cmove r9d,ecx
+  1299:  jmp <+0x20>
+  129e:  mov r9b,0xe
+  12a1:  jmp <+0x20>
+  12a6:  mov r9b,0x3e
+  12a9:  jmp <+0x20>
+  12ae:  xor r9d,r9d
+  12b1:  jmp <+0x20>
+  12b6:  mov r9b,0x6
+  12b9:  jmp <+0x20>
+  12be:  mov r9b,0x7
+  12c1:  jmp <+0x20>
+  12c6:  mov r9b,0x4
+  12c9:  jmp <+0x20>
+  12ce:  mov r9b,0x3
+  12d1:  jmp <+0x20>
+  12d6:  mov r9b,0x8
+  12d9:  jmp <+0x20>
+  12de:  mov r9b,0x5
+  12e1:  jmp <+0x20>
+  12e6:  mov r9b,0x9
+  12e9:  jmp <+0x20>
+  12ee:  mov r9b,0x1b
+  12f1:  jmp <+0x20>
+  12f6:  mov r9b,0x1e
+  12f9:  jmp <+0x20>
+  12fe:  mov r9b,0x49
+  1301:  jmp <+0x20>
+  1306:  mov r9b,0x12
+  1309:  jmp <+0x20>
+  130e:  mov r9b,0x11
+  1311:  jmp <+0x20>
+  1316:  lea r10d,[rcx-0x11]
+  131a:  cmp r10b,0x15
+  131e:  jb <+0x20>
+  1324:  add cl,0xd7
+  1327:  xor r10d,r10d
+  132a:  cmp cl,0xc
+  132d:  mov r9d,0xd
+  1333:  cmovae r9d,r10d
+  1337:  jmp <+0x20>
+  133c:  lea r10d,[rcx-0x14]
+  1340:  cmp r10b,0x15
+  1344:  jb <+0x20>
+  134a:  add cl,0xd4
+  134d:  xor r10d,r10d
+  1350:  cmp cl,0x9
+  1353:  mov r9d,0x3d
+  1359:  cmovae r9d,r10d
+  135d:  jmp <+0x20>
+  1362:  mov r9b,0xa
+  1365:  jmp <+0x20>
+  136a:  movzx r8d,r9b
+  136e:  add r8d,0xfffffffe
+  1372:  cmp r8d,0x98
+  1379:  ja <+0x13a5>
+  137b:  mov ecx,0x6
+  1380:  mov eax,0x1
+  1385:  lea r9,[rip]
+  138c:  movsxd r8,DWORD PTR [r9+r8*4]
+  1390:  add r8,r9
+  1393:  jmp r8
+  1396:  mov ecx,0x1
+  139b:  mov eax,0x5
+  13a0:  jmp <+0x15ca>
+  13a5:  lea rax,[rdi+0x8]
+  13a9:  mov rbx,rdi
+  13ac:  mov rdi,rax
+  13af:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+  13b5:  mov rdi,rbx
+  13b8:  mov eax,0x1
+  13bd:  mov QWORD PTR [rdi],rax
+  13c0:  mov rax,rdi
+  13c3:  pop rbx
+  13c4:  ret
+  13c5:  mov ecx,0x1
+  13ca:  mov eax,0x6
+  13cf:  jmp <+0x15ca>
+  13d4:  mov ecx,0x2
+  13d9:  mov eax,0x5
+  13de:  jmp <+0x15ca>
+  13e3:  xor ecx,ecx
+  13e5:  mov eax,0x5
+  13ea:  jmp <+0x15ca>
+  13ef:  xor ecx,ecx
+  13f1:  mov eax,0x4
+  13f6:  jmp <+0x15ca>
+  13fb:  mov ecx,0x1
+  1400:  jmp <+0x15c5>
+  1405:  mov ecx,0x4
+  140a:  mov eax,0x3
+  140f:  jmp <+0x15ca>
+  1414:  xor ecx,ecx
+  1416:  jmp <+0x15c5>
+  141b:  mov ecx,0x2
+  1420:  jmp <+0x15c5>
+  1425:  mov ecx,0x7
+  142a:  mov eax,0x3
+  142f:  jmp <+0x15ca>
+  1434:  mov ecx,0x7
+  1439:  jmp <+0x15c5>
+  143e:  mov ecx,0x3
+  1443:  mov eax,0x4
+  1448:  jmp <+0x15ca>
+  144d:  mov ecx,0x7
+  1452:  mov eax,0x4
+  1457:  jmp <+0x15ca>
+  145c:  mov ecx,0x4
+  1461:  mov eax,0x6
+  1466:  jmp <+0x15ca>
+  146b:  mov ecx,0x1
+  1470:  mov eax,0x3
+  1475:  jmp <+0x15ca>
+  147a:  mov ecx,0x8
+  147f:  mov eax,0x6
+  1484:  jmp <+0x15ca>
+  1489:  mov ecx,0x3
+  148e:  mov eax,0x3
+  1493:  jmp <+0x15ca>
+  1498:  mov ecx,0x9
+  149d:  jmp <+0x15c5>
+  14a2:  mov ecx,0x5
+  14a7:  mov eax,0x4
+  14ac:  jmp <+0x15ca>
+  14b1:  mov ecx,0x8
+  14b6:  mov eax,0x5
+  14bb:  jmp <+0x15ca>
+  14c0:  mov ecx,0x5
+  14c5:  mov eax,0x5
+  14ca:  jmp <+0x15ca>
+  14cf:  mov ecx,0x1
+  14d4:  mov eax,0xb
+  14d9:  jmp <+0x15ca>
+  14de:  mov ecx,0x9
+  14e3:  mov eax,0x5
+  14e8:  jmp <+0x15ca>
+  14ed:  mov ecx,0x3
+  14f2:  mov eax,0x7
+  14f7:  jmp <+0x15ca>
+  14fc:  mov ecx,0x1
+  1501:  mov eax,0xc
+  1506:  jmp <+0x15ca>
+  150b:  mov ecx,0x5
+  1510:  mov eax,0x3
+  1515:  jmp <+0x15ca>
+  151a:  mov ecx,0x9
+  151f:  mov eax,0x3
+  1524:  jmp <+0x15ca>
+  1529:  mov ecx,0x2
+  152e:  mov eax,0x6
+  1533:  jmp <+0x15ca>
+  1538:  mov ecx,0x2
+  153d:  mov eax,0xc
+  1542:  jmp <+0x15ca>
+  1547:  mov ecx,0x9
+  154c:  mov eax,0x4
+  1551:  jmp <+0x15ca>
+  1553:  mov ecx,0x2
+  1558:  mov eax,0x4
+  155d:  jmp <+0x15ca>
+  155f:  mov ecx,0x2
+  1564:  mov eax,0xb
+  1569:  jmp <+0x15ca>
+  156b:  mov ecx,0x3
+  1570:  mov eax,0x6
+  1575:  jmp <+0x15ca>
+  1577:  xor ecx,ecx
+  1579:  mov eax,0xa
+  157e:  jmp <+0x15ca>
+  1580:  mov ecx,0x4
+  1585:  mov eax,0x7
+  158a:  jmp <+0x15ca>
+  158c:  xor ecx,ecx
+  158e:  mov eax,0xb
+  1593:  jmp <+0x15ca>
+  1595:  mov ecx,0x1
+  159a:  mov eax,0x4
+  159f:  jmp <+0x15ca>
+  15a1:  mov ecx,0x8
+  15a6:  mov eax,0x3
+  15ab:  jmp <+0x15ca>
+  15ad:  mov ecx,0x4
+  15b2:  mov eax,0x4
+  15b7:  jmp <+0x15ca>
+  15b9:  mov ecx,0x5
+  15be:  jmp <+0x15c5>
+  15c0:  mov ecx,0x8
+  15c5:  mov eax,0x2
+  15ca:  mov r8,rdx
+  15cd:  sub r8,rax
+  15d0:  jb <+0x169d>
+  15d6:  add rsi,rax
+  15d9:  mov DWORD PTR [rdi+0x8],ecx
+  15dc:  mov QWORD PTR [rdi+0x10],rsi
+  15e0:  mov QWORD PTR [rdi+0x18],r8
+  15e4:  xor eax,eax
+  15e6:  mov QWORD PTR [rdi],rax
+  15e9:  mov rax,rdi
+  15ec:  pop rbx
+  15ed:  ret
+  15ee:  mov ecx,0x7
+  15f3:  mov eax,0x5
+  15f8:  jmp <+0x15ca>
+  15fa:  mov eax,0x3
+  15ff:  jmp <+0x15ca>
+  1601:  mov ecx,0x9
+  1606:  jmp <+0x15ca>
+  1608:  mov ecx,0x7
+  160d:  jmp <+0x15ca>
+  160f:  mov ecx,0x3
+  1614:  jmp <+0x15ca>
+  1616:  mov ecx,0x5
+  161b:  jmp <+0x15ca>
+  161d:  mov eax,0x4
+  1622:  jmp <+0x15ca>
+  1624:  mov ecx,0x4
+  1629:  jmp <+0x15ca>
+  162b:  mov ecx,0x5
+  1630:  mov eax,0x1
+  1635:  jmp <+0x15ca>
+  1637:  mov ecx,0x7
+  163c:  mov eax,0x1
+  1641:  jmp <+0x15ca>
+  1643:  mov ecx,0x9
+  1648:  mov eax,0x1
+  164d:  jmp <+0x15ca>
+  1652:  mov ecx,0x6
+  1657:  mov eax,0x3
+  165c:  jmp <+0x15ca>
+  1661:  mov ecx,0x4
+  1666:  mov eax,0x1
+  166b:  jmp <+0x15ca>
+  1670:  mov ecx,0x6
+  1675:  mov eax,0x4
+  167a:  jmp <+0x15ca>
+  167f:  mov ecx,0x6
+  1684:  mov eax,0x1
+  1689:  jmp <+0x15ca>
+  168e:  mov ecx,0x3
+  1693:  mov eax,0x1
+  1698:  jmp <+0x15ca>
+  169d:  lea rcx,[rip]
+  16a4:  mov rdi,rax
+  16a7:  mov rsi,rdx
+  16aa:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
@@ -0,0 +1,60 @@
+duration_unit_lookup::by_trie1::lookup:
+     0:  push r14
+     2:  push rbx
+     3:  push rax
+     4:  test rdx,rdx
+     7:  je <+0x9f>
+     d:  lea r11,[rsi+0x1]
+    11:  lea rbx,[rdx-0x1]
+    15:  mov r8d,0xa
+    1b:  mov al,0x1
+    1d:  lea r14,[rip]
+    24:  data16 data16 cs nop WORD PTR [rax+rax*1+0x0]
+    30:  movzx ecx,BYTE PTR [r11-0x1]
+    35:  movzx ecx,BYTE PTR [rcx+r14*1+0x958]
+    3e:  cmp rcx,0x14
+    42:  jae <+0xb9>
+    44:  movzx eax,al
+    47:  lea rax,[rax+rax*2]
+    4b:  lea rax,[r14+rax*8]
+    4f:  movzx eax,BYTE PTR [rcx+rax*1+0x4]
+    54:  test rax,rax
+    57:  je <+0x7d>
+    59:  cmp al,0x62
+    5b:  ja <+0xce>
+    5d:  lea rcx,[rax+rax*2]
+    61:  mov ecx,DWORD PTR [r14+rcx*8]
+    65:  cmp ecx,0xa
+    68:  cmovne r10,r11
+    6c:  cmovne r9,rbx
+    70:  cmovne r8d,ecx
+    74:  inc r11
+    77:  add rbx,0xffffffffffffffff
+    7b:  jb <+0x30>
+    7d:  cmp r8d,0xa
+    81:  je <+0x9f>
+    83:  mov DWORD PTR [rdi+0x8],r8d
+    87:  mov QWORD PTR [rdi+0x10],r10
+    8b:  mov QWORD PTR [rdi+0x18],r9
+    8f:  xor eax,eax
+    91:  mov QWORD PTR [rdi],rax
+    94:  mov rax,rdi
+    97:  add rsp,0x8
+    9b:  pop rbx
+    9c:  pop r14
+    9e:  ret
+    9f:  lea rax,[rdi+0x8]
+    a3:  mov rbx,rdi
+    a6:  mov rdi,rax
+    a9:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+    af:  mov rdi,rbx
+    b2:  mov eax,0x1
+    b7:  jmp <+0x91>
+    b9:  lea rdx,[rip]
+    c0:  mov esi,0x14
+    c5:  mov rdi,rcx
+    c8:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+    ce:  lea rdx,[rip]
+    d5:  mov esi,0x63
+    da:  mov rdi,rax
+    dd:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
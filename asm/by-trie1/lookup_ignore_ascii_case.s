@@ -0,0 +1,66 @@
+duration_unit_lookup::by_trie1::lookup_ignore_ascii_case:
+     0:  push rbp
+     1:  push r14
+     3:  push rbx
+     4:  test rdx,rdx
+     7:  je <+0xb2>
+     d:  lea r11,[rsi+0x1]
+    11:  lea rbx,[rdx-0x1]
+    15:  mov r8d,0xa
+    1b:  mov al,0x1
+    1d:  lea r14,[rip]
+    24:  data16 data16 cs nop WORD PTR [rax+rax*1+0x0]
+    30:  movzx ecx,BYTE PTR [r11-0x1]
+    35:  lea ebp,[rcx-0x41]
+    38:  cmp bpl,0x1a
+    3c:  setb bpl
+    40:  shl bpl,0x5
+    44:  or bpl,cl
+    47:  movzx ecx,bpl
+    4b:  movzx ecx,BYTE PTR [rcx+r14*1+0x958]
+    54:  cmp rcx,0x14
+    58:  jae <+0xcc>
+    5a:  movzx eax,al
+    5d:  lea rax,[rax+rax*2]
+    61:  lea rax,[r14+rax*8]
+    65:  movzx eax,BYTE PTR [rcx+rax*1+0x4]
+    6a:  test rax,rax
+    6d:  je <+0x93>
+    6f:  cmp al,0x62
+    71:  ja <+0xe1>
+    73:  lea rcx,[rax+rax*2]
+    77:  mov ecx,DWORD PTR [r14+rcx*8]
+    7b:  cmp ecx,0xa
+    7e:  cmovne r10,r11
+    82:  cmovne r9,rbx
+    86:  cmovne r8d,ecx
+    8a:  inc r11
+    8d:  add rbx,0xffffffffffffffff
+    91:  jb <+0x30>
+    93:  cmp r8d,0xa
+    97:  je <+0xb2>
+    99:  mov DWORD PTR [rdi+0x8],r8d
+    9d:  mov QWORD PTR [rdi+0x10],r10
+    a1:  mov QWORD PTR [rdi+0x18],r9
+    a5:  xor eax,eax
+    a7:  mov QWORD PTR [rdi],rax
+    aa:  mov rax,rdi
+    ad:  pop rbx
+    ae:  pop r14
+    b0:  pop rbp
+    b1:  ret
+    b2:  lea rax,[rdi+0x8]
+    b6:  mov rbx,rdi
+    b9:  mov rdi,rax
+    bc:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+    c2:  mov rdi,rbx
+    c5:  mov eax,0x1
+    ca:  jmp <+0xa7>
+    cc:  lea rdx,[rip]
+    d3:  mov esi,0x14
+    d8:  mov rdi,rcx
+    db:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+    e1:  lea rdx,[rip]
+    e8:  mov esi,0x63
+    ed:  mov rdi,rax
+    f0:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
@@ -0,0 +1,70 @@
+duration_unit_lookup::by_trie2::lookup:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0xb3>
+     a:  mov r10,rdx
+     d:  neg r10
+    10:  mov cl,0x1
+    12:  mov r9d,0xa
+    18:  mov r11d,0x1
+    1e:  xor eax,eax
+    20:  lea rbx,[rip]
+    27:  nop WORD PTR [rax+rax*1+0x0]
+    30:  movzx r8d,BYTE PTR [rsi+r11*1-0x1]
+    36:  movzx r8d,BYTE PTR [r8+rbx*1+0x958]
+    3f:  cmp r8,0x14
+    43:  jae <+0xe6>
+    49:  movzx ecx,cl
+    4c:  lea rcx,[rcx+rcx*2]
+    50:  lea rcx,[rbx+rcx*8]
+    54:  movzx ecx,BYTE PTR [r8+rcx*1+0x4]
+    5a:  test rcx,rcx
+    5d:  je <+0x8c>
+    5f:  cmp cl,0x62
+    62:  ja <+0xfb>
+    68:  lea r8,[rcx+rcx*2]
+    6c:  mov r8d,DWORD PTR [rbx+r8*8]
+    70:  cmp r8d,0xa
+    74:  cmovne rax,r11
+    78:  cmovne r9d,r8d
+    7c:  lea r8,[r10+r11*1]
+    80:  inc r8
+    83:  inc r11
+    86:  cmp r8,0x1
+    8a:  jne <+0x30>
+    8c:  cmp r9d,0xa
+    90:  je <+0xb3>
+    92:  mov rcx,rdx
+    95:  sub rcx,rax
+    98:  jb <+0xd3>
+    9a:  add rsi,rax
+    9d:  mov DWORD PTR [rdi+0x8],r9d
+    a1:  mov QWORD PTR [rdi+0x10],rsi
+    a5:  mov QWORD PTR [rdi+0x18],rcx
+    a9:  xor eax,eax
+    ab:  mov QWORD PTR [rdi],rax
+    ae:  mov rax,rdi
+    b1:  pop rbx
+    b2:  ret
+    b3:  lea rax,[rdi+0x8]
+    b7:  mov rbx,rdi
+    ba:  mov rdi,rax
+    bd:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+    c3:  mov rdi,rbx
+    c6:  mov eax,0x1
+    cb:  mov QWORD PTR [rdi],rax
+    ce:  mov rax,rdi
+    d1:  pop rbx
+    d2:  ret
+    d3:  lea rcx,[rip]
+    da:  mov rdi,rax
+    dd:  mov rsi,rdx
+    e0:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
+    e6:  lea rdx,[rip]
+    ed:  mov esi,0x14
+    f2:  mov rdi,r8
+    f5:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+    fb:  lea rdx,[rip]
+   102:  mov esi,0x63
+   107:  mov rdi,rcx
+   10a:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
@@ -0,0 +1,77 @@
+duration_unit_lookup::by_trie2::lookup_ignore_ascii_case:
+     0:  push rbp
+     1:  push rbx
+     2:  push rax
+     3:  test rdx,rdx
+     6:  je <+0xcf>
+     c:  mov r10,rdx
+     f:  neg r10
+    12:  mov cl,0x1
+    14:  mov r9d,0xa
+    1a:  mov r11d,0x1
+    20:  xor eax,eax
+    22:  lea rbx,[rip]
+    29:  nop DWORD PTR [rax+0x0]
+    30:  movzx r8d,BYTE PTR [rsi+r11*1-0x1]
+    36:  lea ebp,[r8-0x41]
+    3a:  cmp bpl,0x1a
+    3e:  setb bpl
+    42:  shl bpl,0x5
+    46:  or bpl,r8b
+    49:  movzx r8d,bpl
+    4d:  movzx r8d,BYTE PTR [r8+rbx*1+0x958]
+    56:  cmp r8,0x14
+    5a:  jae <+0xfc>
+    60:  movzx ecx,cl
+    63:  lea rcx,[rcx+rcx*2]
+    67:  lea rcx,[rbx+rcx*8]
+    6b:  movzx ecx,BYTE PTR [r8+rcx*1+0x4]
+    71:  test rcx,rcx
+    74:  je <+0xa3>
+    76:  cmp cl,0x62
+    79:  ja <+0x111>
+    7f:  lea r8,[rcx+rcx*2]
+    83:  mov r8d,DWORD PTR [rbx+r8*8]
+    87:  cmp r8d,0xa
+    8b:  cmovne rax,r11
+    8f:  cmovne r9d,r8d
+    93:  lea r8,[r10+r11*1]
+    97:  inc r8
+    9a:  inc r11
+    9d:  cmp r8,0x1
+    a1:  jne <+0x30>
+    a3:  cmp r9d,0xa
+    a7:  je <+0xcf>
+    a9:  mov rcx,rdx
+    ac:  sub rcx,rax
+    af:  jb <+0xe9>
+    b1:  add rsi,rax
+    b4:  mov DWORD PTR [rdi+0x8],r9d
+    b8:  mov QWORD PTR [rdi+0x10],rsi
+    bc:  mov QWORD PTR [rdi+0x18],rcx
+    c0:  xor eax,eax
+    c2:  mov QWORD PTR [rdi],rax
+    c5:  mov rax,rdi
+    c8:  add rsp,0x8
+    cc:  pop rbx
+    cd:  pop rbp
+    ce:  ret
+    cf:  lea rax,[rdi+0x8]
+    d3:  mov rbx,rdi
+    d6:  mov rdi,rax
+    d9:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+    df:  mov rdi,rbx
+    e2:  mov eax,0x1
+    e7:  jmp <+0xc2>
+    e9:  lea rcx,[rip]
+    f0:  mov rdi,rax
+    f3:  mov rsi,rdx
+    f6:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
+    fc:  lea rdx,[rip]
+   103:  mov esi,0x14
+   108:  mov rdi,r8
+   10b:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   111:  lea rdx,[rip]
+   118:  mov esi,0x63
+   11d:  mov rdi,rcx
+   120:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
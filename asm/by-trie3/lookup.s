@@ -0,0 +1,82 @@
+duration_unit_lookup::by_trie3::lookup:
+     0:  push rbp
+     1:  push r14
+     3:  push rbx
+     4:  test rdx,rdx
+     7:  je <+0xd1>
+     d:  mov r11,rdx
+    10:  neg r11
+    13:  mov cl,0x1
+    15:  mov ebx,0x1
+    1a:  xor eax,eax
+    1c:  lea r9,[rip]
+    23:  mov r10b,0x1
+    26:  xor r14d,r14d
+    29:  nop DWORD PTR [rax+0x0]
+    30:  movzx r8d,BYTE PTR [rsi+rbx*1-0x1]
+    36:  movzx r8d,BYTE PTR [r8+r9*1+0x958]
+    3f:  cmp r8,0x14
+    43:  jae <+0x10b>
+    49:  movzx ecx,cl
+    4c:  lea rcx,[rcx+rcx*2]
+    50:  lea rcx,[r9+rcx*8]
+    54:  movzx ecx,BYTE PTR [r8+rcx*1+0x4]
+    5a:  test rcx,rcx
+    5d:  je <+0x97>
+    5f:  cmp cl,0x63
+    62:  jae <+0x120>
+    68:  lea r8,[rcx+rcx*2]
+    6c:  cmp DWORD PTR [r9+r8*8],0xa
+    71:  setne r8b
+    75:  movzx ebp,cl
+    78:  movzx r10d,r10b
+    7c:  cmovne r10d,ebp
+    80:  cmovne rax,rbx
+    84:  or r14b,r8b
+    87:  lea r8,[r11+rbx*1]
+    8b:  inc r8
+    8e:  inc rbx
+    91:  cmp r8,0x1
+    95:  jne <+0x30>
+    97:  test r14b,0x1
+    9b:  je <+0xd1>
+    9d:  movzx ecx,r10b
+    a1:  lea rcx,[rcx+rcx*2]
+    a5:  mov ecx,DWORD PTR [r9+rcx*8]
+    a9:  cmp ecx,0xa
+    ac:  je <+0xeb>
+    ae:  mov r8,rdx
+    b1:  sub r8,rax
+    b4:  jb <+0xf8>
+    b6:  add rsi,rax
+    b9:  mov DWORD PTR [rdi+0x8],ecx
+    bc:  mov QWORD PTR [rdi+0x10],rsi
+    c0:  mov QWORD PTR [rdi+0x18],r8
+    c4:  xor eax,eax
+    c6:  mov QWORD PTR [rdi],rax
+    c9:  mov rax,rdi
+    cc:  pop rbx
+    cd:  pop r14
+    cf:  pop rbp
+    d0:  ret
+    d1:  lea rax,[rdi+0x8]
+    d5:  mov rbx,rdi
+    d8:  mov rdi,rax
+    db:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+    e1:  mov rdi,rbx
+    e4:  mov eax,0x1
+    e9:  jmp <+0xc6>
+    eb:  lea rdi,[rip]
+    f2:  call QWORD PTR [rip] <core::option::unwrap_failed>
+    f8:  lea rcx,[rip]
+    ff:  mov rdi,rax
+   102:  mov rsi,rdx
+   105:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
+   10b:  lea rdx,[rip]
+   112:  mov esi,0x14
+   117:  mov rdi,r8
+   11a:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   120:  lea rdx,[rip]
+   127:  mov esi,0x63
+   12c:  mov rdi,rcx
+   12f:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
@@ -0,0 +1,88 @@
+duration_unit_lookup::by_trie3::lookup_ignore_ascii_case:
+     0:  push rbp
+     1:  push r14
+     3:  push rbx
+     4:  test rdx,rdx
+     7:  je <+0xe8>
+     d:  mov r11,rdx
+    10:  neg r11
+    13:  mov cl,0x1
+    15:  mov ebx,0x1
+    1a:  xor eax,eax
+    1c:  lea r9,[rip]
+    23:  mov r10b,0x1
+    26:  xor r14d,r14d
+    29:  nop DWORD PTR [rax+0x0]
+    30:  movzx r8d,BYTE PTR [rsi+rbx*1-0x1]
+    36:  lea ebp,[r8-0x41]
+    3a:  cmp bpl,0x1a
+    3e:  setb bpl
+    42:  shl bpl,0x5
+    46:  or bpl,r8b
+    49:  movzx r8d,bpl
+    4d:  movzx r8d,BYTE PTR [r8+r9*1+0x958]
+    56:  cmp r8,0x14
+    5a:  jae <+0x122>
+    60:  movzx ecx,cl
+    63:  lea rcx,[rcx+rcx*2]
+    67:  lea rcx,[r9+rcx*8]
+    6b:  movzx ecx,BYTE PTR [r8+rcx*1+0x4]
+    71:  test rcx,rcx
+    74:  je <+0xae>
+    76:  cmp cl,0x63
+    79:  jae <+0x137>
+    7f:  lea r8,[rcx+rcx*2]
+    83:  cmp DWORD PTR [r9+r8*8],0xa
+    88:  setne r8b
+    8c:  movzx ebp,cl
+    8f:  movzx r10d,r10b
+    93:  cmovne r10d,ebp
+    97:  cmovne rax,rbx
+    9b:  or r14b,r8b
+    9e:  lea r8,[r11+rbx*1]
+    a2:  inc r8
+    a5:  inc rbx
+    a8:  cmp r8,0x1
+    ac:  jne <+0x30>
+    ae:  test r14b,0x1
+    b2:  je <+0xe8>
+    b4:  movzx ecx,r10b
+    b8:  lea rcx,[rcx+rcx*2]
+    bc:  mov ecx,DWORD PTR [r9+rcx*8]
+    c0:  cmp ecx,0xa
+    c3:  je <+0x102>
+    c5:  mov r8,rdx
+    c8:  sub r8,rax
+    cb:  jb <+0x10f>
+    cd:  add rsi,rax
+    d0:  mov DWORD PTR [rdi+0x8],ecx
+    d3:  mov QWORD PTR [rdi+0x10],rsi
+    d7:  mov QWORD PTR [rdi+0x18],r8
+    db:  xor eax,eax
+    dd:  mov QWORD PTR [rdi],rax
+    e0:  mov rax,rdi
+    e3:  pop rbx
+    e4:  pop r14
+    e6:  pop rbp
+    e7:  ret
+    e8:  lea rax,[rdi+0x8]
+    ec:  mov rbx,rdi
+    ef:  mov rdi,rax
+    f2:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+    f8:  mov rdi,rbx
+    fb:  mov eax,0x1
+   100:  jmp <+0xdd>
+   102:  lea rdi,[rip]
+   109:  call QWORD PTR [rip] <core::option::unwrap_failed>
+   10f:  lea rcx,[rip]
+   116:  mov rdi,rax
+   119:  mov rsi,rdx
+   11c:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
+   122:  lea rdx,[rip]
+   129:  mov esi,0x14
+   12e:  mov rdi,r8
+   131:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
+   137:  lea rdx,[rip]
+   13e:  mov esi,0x63
+   143:  mov rdi,rcx
+   146:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
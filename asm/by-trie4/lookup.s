@@ -0,0 +1,78 @@
+duration_unit_lookup::by_trie4::lookup:
+     0:  push rbp
+     1:  push r15
+     3:  push r14
+     5:  push r12
+     7:  push rbx
+     8:  test rdx,rdx
+     b:  je <+0xd5>
+    11:  mov r9,rdx
+    14:  neg r9
+    17:  mov r10w,0x474
+    1c:  mov r11d,0x1
+    22:  xor eax,eax
+    24:  lea r8,[rip]
+    2b:  xor ebx,ebx
+    2d:  mov cx,0x474
+    31:  jmp <+0x60>
+    33:  data16 data16 data16 cs nop WORD PTR [rax+rax*1+0x0]
+    40:  mov ebp,ebx
+    42:  mov r14d,r10d
+    45:  mov r15,rax
+    48:  lea r12,[r9+r11*1]
+    4c:  inc r12
+    4f:  inc r11
+    52:  mov rax,r15
+    55:  mov r10d,r14d
+    58:  mov ebx,ebp
+    5a:  cmp r12,0x1
+    5e:  je <+0xa1>
+    60:  movzx r14d,BYTE PTR [rsi+r11*1-0x1]
+    66:  movzx r14d,BYTE PTR [r14+r8*1+0x2e78]
+    6f:  movzx ecx,cx
+    72:  add rcx,r14
+    75:  cmp rcx,0x7bc
+    7c:  jae <+0x102>
+    82:  movzx ecx,WORD PTR [r8+rcx*2+0x1ef0]
+    8b:  cmp ecx,0x461
+    91:  jae <+0x40>
+    93:  mov bpl,0x1
+    96:  mov r14d,ecx
+    99:  mov r15,r11
+    9c:  test cx,cx
+    9f:  jne <+0x48>
+    a1:  test bl,0x1
+    a4:  je <+0xd5>
+    a6:  mov rcx,rdx
+    a9:  sub rcx,rax
+    ac:  jb <+0xef>
+    ae:  movzx edx,r10w
+    b2:  mov edx,DWORD PTR [r8+rdx*4]
+    b6:  add rsi,rax
+    b9:  mov DWORD PTR [rdi+0x8],edx
+    bc:  mov QWORD PTR [rdi+0x10],rsi
+    c0:  mov QWORD PTR [rdi+0x18],rcx
+    c4:  xor eax,eax
+    c6:  mov QWORD PTR [rdi],rax
+    c9:  mov rax,rdi
+    cc:  pop rbx
+    cd:  pop r12
+    cf:  pop r14
+    d1:  pop r15
+    d3:  pop rbp
+    d4:  ret
+    d5:  lea rax,[rdi+0x8]
+    d9:  mov rbx,rdi
+    dc:  mov rdi,rax
+    df:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+    e5:  mov rdi,rbx
+    e8:  mov eax,0x1
+    ed:  jmp <+0xc6>
+    ef:  lea rcx,[rip]
+    f6:  mov rdi,rax
+    f9:  mov rsi,rdx
+    fc:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
+   102:  lea rdx,[rip]
+   109:  mov esi,0x7bc
+   10e:  mov rdi,rcx
+   111:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
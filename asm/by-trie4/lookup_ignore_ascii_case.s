@@ -0,0 +1,84 @@
+duration_unit_lookup::by_trie4::lookup_ignore_ascii_case:
+     0:  push rbp
+     1:  push r15
+     3:  push r14
+     5:  push r12
+     7:  push rbx
+     8:  test rdx,rdx
+     b:  je <+0xec>
+    11:  mov r9,rdx
+    14:  neg r9
+    17:  mov r10w,0x474
+    1c:  mov r11d,0x1
+    22:  xor eax,eax
+    24:  lea r8,[rip]
+    2b:  xor ebx,ebx
+    2d:  mov cx,0x474
+    31:  jmp <+0x60>
+    33:  data16 data16 data16 cs nop WORD PTR [rax+rax*1+0x0]
+    40:  mov ebp,ebx
+    42:  mov r14d,r10d
+    45:  mov r15,rax
+    48:  lea r12,[r9+r11*1]
+    4c:  inc r12
+    4f:  inc r11
+    52:  mov rax,r15
+    55:  mov r10d,r14d
+    58:  mov ebx,ebp
+    5a:  cmp r12,0x1
+    5e:  je <+0xb8>
+    60:  movzx r14d,BYTE PTR [rsi+r11*1-0x1]
+    66:  lea ebp,[r14-0x41]
+    6a:  cmp bpl,0x1a
+    6e:  setb bpl
+    72:  shl bpl,0x5
+    76:  or bpl,r14b
+    79:  movzx r14d,bpl
+    7d:  movzx r14d,BYTE PTR [r14+r8*1+0x2e78]
+    86:  movzx ecx,cx
+    89:  add rcx,r14
+    8c:  cmp rcx,0x7bc
+    93:  jae <+0x119>
+    99:  movzx ecx,WORD PTR [r8+rcx*2+0x1ef0]
+    a2:  cmp ecx,0x461
+    a8:  jae <+0x40>
+    aa:  mov bpl,0x1
+    ad:  mov r14d,ecx
+    b0:  mov r15,r11
+    b3:  test cx,cx
+    b6:  jne <+0x48>
+    b8:  test bl,0x1
+    bb:  je <+0xec>
+    bd:  mov rcx,rdx
+    c0:  sub rcx,rax
+    c3:  jb <+0x106>
+    c5:  movzx edx,r10w
+    c9:  mov edx,DWORD PTR [r8+rdx*4]
+    cd:  add rsi,rax
+    d0:  mov DWORD PTR [rdi+0x8],edx
+    d3:  mov QWORD PTR [rdi+0x10],rsi
+    d7:  mov QWORD PTR [rdi+0x18],rcx
+    db:  xor eax,eax
+    dd:  mov QWORD PTR [rdi],rax
+    e0:  mov rax,rdi
+    e3:  pop rbx
+    e4:  pop r12
+    e6:  pop r14
+    e8:  pop r15
+    ea:  pop rbp
+    eb:  ret
+    ec:  lea rax,[rdi+0x8]
+    f0:  mov rbx,rdi
+    f3:  mov rdi,rax
+    f6:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+    fc:  mov rdi,rbx
+    ff:  mov eax,0x1
+   104:  jmp <+0xdd>
+   106:  lea rcx,[rip]
+   10d:  mov rdi,rax
+   110:  mov rsi,rdx
+   113:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
+   119:  lea rdx,[rip]
+   120:  mov esi,0x7bc
+   125:  mov rdi,rcx
+   128:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
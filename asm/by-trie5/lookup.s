@@ -0,0 +1,68 @@
+duration_unit_lookup::by_trie5::lookup:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0xc0>
+     a:  xor ecx,ecx
+     c:  jmp <+0x18>
+     e:  xchg ax,ax
+    10:  inc rcx
+    13:  cmp rdx,rcx
+    16:  je <+0x3e>
+    18:  movzx eax,BYTE PTR [rsi+rcx*1]
+    1c:  lea r8d,[rax-0x7b]
+    20:  cmp r8b,0xe5
+    24:  ja <+0x10>
+    26:  cmp eax,0xc2
+    2b:  je <+0x10>
+    2d:  cmp eax,0xb5
+    32:  je <+0x10>
+    34:  test rcx,rcx
+    37:  jne <+0x46>
+    39:  jmp <+0xc0>
+    3e:  mov rcx,rdx
+    41:  test rcx,rcx
+    44:  je <+0xc0>
+    46:  lea r8,[rsi+rcx*1]
+    4a:  mov ax,0x474
+    4e:  xor r10d,r10d
+    51:  lea r9,[rip]
+    58:  nop DWORD PTR [rax+rax*1+0x0]
+    60:  movzx r11d,BYTE PTR [rsi+r10*1]
+    65:  movzx r11d,BYTE PTR [r11+r9*1+0x2e78]
+    6e:  movzx eax,ax
+    71:  add rax,r11
+    74:  cmp rax,0x7bc
+    7a:  jae <+0xe0>
+    7c:  movzx eax,WORD PTR [r9+rax*2+0x1ef0]
+    85:  inc r10
+    88:  cmp rcx,r10
+    8b:  jne <+0x60>
+    8d:  lea r10d,[rax-0x461]
+    94:  movzx r10d,r10w
+    98:  cmp r10d,0xfba0
+    9f:  jb <+0xc0>
+    a1:  movzx eax,ax
+    a4:  mov eax,DWORD PTR [r9+rax*4]
+    a8:  sub rdx,rcx
+    ab:  mov DWORD PTR [rdi+0x8],eax
+    ae:  mov QWORD PTR [rdi+0x10],r8
+    b2:  mov QWORD PTR [rdi+0x18],rdx
+    b6:  xor eax,eax
+    b8:  mov QWORD PTR [rdi],rax
+    bb:  mov rax,rdi
+    be:  pop rbx
+    bf:  ret
+    c0:  lea rax,[rdi+0x8]
+    c4:  mov rbx,rdi
+    c7:  mov rdi,rax
+    ca:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+    d0:  mov rdi,rbx
+    d3:  mov eax,0x1
+    d8:  mov QWORD PTR [rdi],rax
+    db:  mov rax,rdi
+    de:  pop rbx
+    df:  ret
+    e0:  lea rdx,[rip]
+    e7:  mov esi,0x7bc
+    ec:  mov rdi,rax
+    ef:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
@@ -0,0 +1,80 @@
+duration_unit_lookup::by_trie5::lookup_ignore_ascii_case:
+     0:  push rbx
+     1:  test rdx,rdx
+     4:  je <+0xe4>
+     a:  xor ecx,ecx
+     c:  jmp <+0x18>
+     e:  xchg ax,ax
+    10:  inc rcx
+    13:  cmp rdx,rcx
+    16:  je <+0x4e>
+    18:  movzx r8d,BYTE PTR [rsi+rcx*1]
+    1d:  lea eax,[r8-0x41]
+    21:  cmp al,0x1a
+    23:  setb al
+    26:  shl al,0x5
+    29:  or al,r8b
+    2c:  lea r8d,[rax-0x7b]
+    30:  cmp r8b,0xe5
+    34:  ja <+0x10>
+    36:  cmp al,0xc2
+    38:  je <+0x10>
+    3a:  movzx eax,al
+    3d:  cmp eax,0xb5
+    42:  je <+0x10>
+    44:  test rcx,rcx
+    47:  jne <+0x5a>
+    49:  jmp <+0xe4>
+    4e:  mov rcx,rdx
+    51:  test rcx,rcx
+    54:  je <+0xe4>
+    5a:  lea r8,[rsi+rcx*1]
+    5e:  mov ax,0x474
+    62:  xor r10d,r10d
+    65:  lea r9,[rip]
+    6c:  nop DWORD PTR [rax+0x0]
+    70:  movzx r11d,BYTE PTR [rsi+r10*1]
+    75:  lea ebx,[r11-0x41]
+    79:  cmp bl,0x1a
+    7c:  setb bl
+    7f:  shl bl,0x5
+    82:  or bl,r11b
+    85:  movzx r11d,bl
+    89:  movzx r11d,BYTE PTR [r11+r9*1+0x2e78]
+    92:  movzx eax,ax
+    95:  add rax,r11
+    98:  cmp rax,0x7bc
+    9e:  jae <+0x104>
+    a0:  movzx eax,WORD PTR [r9+rax*2+0x1ef0]
+    a9:  inc r10
+    ac:  cmp rcx,r10
+    af:  jne <+0x70>
+    b1:  lea r10d,[rax-0x461]
+    b8:  movzx r10d,r10w
+    bc:  cmp r10d,0xfba0
+    c3:  jb <+0xe4>
+    c5:  movzx eax,ax
+    c8:  mov eax,DWORD PTR [r9+rax*4]
+    cc:  sub rdx,rcx
+    cf:  mov DWORD PTR [rdi+0x8],eax
+    d2:  mov QWORD PTR [rdi+0x10],r8
+    d6:  mov QWORD PTR [rdi+0x18],rdx
+    da:  xor eax,eax
+    dc:  mov QWORD PTR [rdi],rax
+    df:  mov rax,rdi
+    e2:  pop rbx
+    e3:  ret
+    e4:  lea rax,[rdi+0x8]
+    e8:  mov rbx,rdi
+    eb:  mov rdi,rax
+    ee:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+    f4:  mov rdi,rbx
+    f7:  mov eax,0x1
+    fc:  mov QWORD PTR [rdi],rax
+    ff:  mov rax,rdi
+   102:  pop rbx
+   103:  ret
+   104:  lea rdx,[rip]
+   10b:  mov esi,0x7bc
+   110:  mov rdi,rax
+   113:  call QWORD PTR [rip] <core::panicking::panic_bounds_check>
@@ -0,0 +1,462 @@
+duration_unit_lookup::hashify::lookup:
+     0:  mov rax,rdi
+     3:  test rdx,rdx
+     6:  je <+0x841>
+     c:  xor ecx,ecx
+     e:  jmp <+0x18>
+    10:  inc rcx
+    13:  cmp rdx,rcx
+    16:  je <+0x38>
+    18:  movzx edi,BYTE PTR [rsi+rcx*1]
+    1c:  lea r8d,[rdi-0x7b]
+    20:  cmp r8b,0xe5
+    24:  ja <+0x10>
+    26:  cmp edi,0xc2
+    2c:  je <+0x10>
+    2e:  cmp edi,0xb5
+    34:  je <+0x10>
+    36:  jmp <+0x3b>
+    38:  mov rcx,rdx
+    3b:  lea rdi,[rcx-0x1]
+    3f:  cmp rdi,0xb
+    43:  ja <+0x841>
+    49:  lea r8,[rip]
+    50:  movsxd rdi,DWORD PTR [r8+rdi*4]
+    54:  add rdi,r8
+    57:  jmp rdi
+    59:  movzx edi,BYTE PTR [rsi]
+    5c:  add edi,0xffffff9c
+    5f:  cmp edi,0x15
+    62:  ja <+0x841>
+    68:  lea r8,[rip]
+    6f:  movsxd rdi,DWORD PTR [r8+rdi*4]
+    73:  add rdi,r8
+    76:  jmp rdi
+    78:  mov edi,0x6
+    7d:  jmp <+0x82a>
+    82:  movzx edi,BYTE PTR [rsi+0x2]
+    86:  xor dil,BYTE PTR [rsi]
+    89:  movzx edi,dil
+    8d:  cmp dil,0x1d
+    91:  ja <+0x6b1>
+    97:  lea r8,[rip]
+    9e:  movsxd rdi,DWORD PTR [r8+rdi*4]
+    a2:  add rdi,r8
+    a5:  jmp rdi
+    a7:  mov edi,DWORD PTR [rsi]
+    a9:  movzx r8d,BYTE PTR [rsi+0x4]
+    ae:  shl r8,0x20
+    b2:  or r8,rdi
+    b5:  movabs rdi,0x736365736d
+    bf:  add rdi,0xc08ee01
+    c6:  cmp r8,rdi
+    c9:  je <+0x796>
+    cf:  jmp <+0x841>
+    d4:  movzx edi,WORD PTR [rsi+0x8]
+    d8:  movabs r8,0x6f6365736f6e616e
+    e2:  xor r8,QWORD PTR [rsi]
+    e5:  xor rdi,0x646e
+    ec:  or rdi,r8
+    ef:  je <+0x796>
+    f5:  jmp <+0x841>
+    fa:  movzx edi,BYTE PTR [rsi+0x1]
+    fe:  xor dil,BYTE PTR [rsi]
+   101:  movzx edi,dil
+   105:  add edi,0xfffffffc
+   108:  cmp edi,0x1a
+   10b:  ja <+0x841>
+   111:  lea r8,[rip]
+   118:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   11c:  add rdi,r8
+   11f:  jmp rdi
+   121:  mov edi,0x4
+   126:  cmp DWORD PTR [rsi],0x736e696d
+   12c:  je <+0x82a>
+   132:  jmp <+0x841>
+   137:  movzx edi,BYTE PTR [rsi]
+   13a:  cmp edi,0x6d
+   13d:  je <+0x2c8>
+   143:  cmp edi,0x73
+   146:  jne <+0x841>
+   14c:  movzx edi,WORD PTR [rsi+0x4]
+   150:  movzx r8d,BYTE PTR [rsi+0x6]
+   155:  shl r8d,0x10
+   159:  or r8d,edi
+   15c:  shl r8,0x20
+   160:  mov r9d,DWORD PTR [rsi]
+   163:  or r9,r8
+   166:  mov edi,0x3
+   16b:  movabs r8,0x73646e6f636573
+   175:  jmp <+0x2f1>
+   17a:  movzx edi,BYTE PTR [rsi+0x1]
+   17e:  xor dil,BYTE PTR [rsi]
+   181:  movzx r8d,dil
+   185:  lea edi,[r8-0x2]
+   189:  cmp edi,0x1a
+   18c:  ja <+0x4e1>
+   192:  lea r8,[rip]
+   199:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   19d:  add rdi,r8
+   1a0:  jmp rdi
+   1a2:  movzx edi,WORD PTR [rsi]
+   1a5:  movzx r8d,BYTE PTR [rsi+0x2]
+   1aa:  shl r8d,0x10
+   1ae:  or r8d,edi
+   1b1:  mov edi,0x8
+   1b6:  cmp r8d,0x736f6d
+   1bd:  je <+0x82a>
+   1c3:  jmp <+0x841>
+   1c8:  movzx edi,BYTE PTR [rsi+0x1]
+   1cc:  xor dil,BYTE PTR [rsi]
+   1cf:  movzx edi,dil
+   1d3:  add edi,0xfffffffe
+   1d6:  cmp edi,0x1c
+   1d9:  ja <+0x841>
+   1df:  lea r8,[rip]
+   1e6:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   1ea:  add rdi,r8
+   1ed:  jmp rdi
+   1ef:  mov edi,0x8
+   1f4:  cmp WORD PTR [rsi],0x6f6d
+   1f9:  je <+0x82a>
+   1ff:  jmp <+0x841>
+   204:  movzx edi,BYTE PTR [rsi+0x3]
+   208:  add edi,0xffffff9b
+   20b:  cmp edi,0x10
+   20e:  ja <+0x841>
+   214:  lea r8,[rip]
+   21b:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   21f:  add rdi,r8
+   222:  jmp rdi
+   224:  mov edi,DWORD PTR [rsi]
+   226:  movzx r8d,WORD PTR [rsi+0x4]
+   22b:  shl r8,0x20
+   22f:  or r8,rdi
+   232:  mov edi,0x1
+   237:  movabs r9,0x73636573b5c2
+   241:  jmp <+0x825>
+   246:  movzx edi,BYTE PTR [rsi+0x2]
+   24a:  cmp edi,0x63
+   24d:  je <+0x32a>
+   253:  cmp edi,0x6c
+   256:  je <+0x355>
+   25c:  cmp edi,0x6e
+   25f:  jne <+0x841>
+   265:  movzx edi,WORD PTR [rsi+0x8]
+   269:  movzx r8d,BYTE PTR [rsi+0xa]
+   26e:  shl r8d,0x10
+   272:  or r8,rdi
+   275:  movabs rdi,0x6f6365736f6e616e
+   27f:  xor rdi,QWORD PTR [rsi]
+   282:  xor r8,0x73646e
+   289:  or r8,rdi
+   28c:  je <+0x796>
+   292:  jmp <+0x841>
+   297:  movzx edi,BYTE PTR [rsi+0x2]
+   29b:  cmp edi,0x63
+   29e:  je <+0x2ff>
+   2a0:  cmp edi,0x6c
+   2a3:  jne <+0x841>
+   2a9:  movabs r8,0x636573696c6c696d
+   2b3:  xor r8,QWORD PTR [rsi]
+   2b6:  mov r9d,DWORD PTR [rsi+0x8]
+   2ba:  xor r9,0x73646e6f
+   2c1:  mov edi,0x2
+   2c6:  jmp <+0x31c>
+   2c8:  movzx edi,WORD PTR [rsi+0x4]
+   2cc:  movzx r8d,BYTE PTR [rsi+0x6]
+   2d1:  shl r8d,0x10
+   2d5:  or r8d,edi
+   2d8:  shl r8,0x20
+   2dc:  mov r9d,DWORD PTR [rsi]
+   2df:  or r9,r8
+   2e2:  mov edi,0x4
+   2e7:  movabs r8,0x736574756e696d
+   2f1:  cmp r9,r8
+   2f4:  je <+0x82a>
+   2fa:  jmp <+0x841>
+   2ff:  movabs r8,0x6365736f7263696d
+   309:  xor r8,QWORD PTR [rsi]
+   30c:  mov r9d,DWORD PTR [rsi+0x8]
+   310:  xor r9,0x73646e6f
+   317:  mov edi,0x1
+   31c:  or r9,r8
+   31f:  je <+0x82a>
+   325:  jmp <+0x841>
+   32a:  movzx edi,WORD PTR [rsi+0x8]
+   32e:  movzx r8d,BYTE PTR [rsi+0xa]
+   333:  shl r8d,0x10
+   337:  movabs r9,0x6365736f7263696d
+   341:  xor r9,QWORD PTR [rsi]
+   344:  or r8,rdi
+   347:  xor r8,0x646e6f
+   34e:  mov edi,0x1
+   353:  jmp <+0x37e>
+   355:  movzx edi,WORD PTR [rsi+0x8]
+   359:  movzx r8d,BYTE PTR [rsi+0xa]
+   35e:  shl r8d,0x10
+   362:  movabs r9,0x636573696c6c696d
+   36c:  xor r9,QWORD PTR [rsi]
+   36f:  or r8,rdi
+   372:  xor r8,0x646e6f
+   379:  mov edi,0x2
+   37e:  or r8,r9
+   381:  je <+0x82a>
+   387:  jmp <+0x841>
+   38c:  mov edi,0x4
+   391:  jmp <+0x82a>
+   396:  mov edi,0x5
+   39b:  jmp <+0x82a>
+   3a0:  mov edi,0x7
+   3a5:  jmp <+0x82a>
+   3aa:  mov edi,DWORD PTR [rsi]
+   3ac:  movzx r8d,WORD PTR [rsi+0x4]
+   3b1:  shl r8,0x20
+   3b5:  or r8,rdi
+   3b8:  mov edi,0x3
+   3bd:  movabs r9,0x646e6f636573
+   3c7:  jmp <+0x825>
+   3cc:  mov edi,0x3
+   3d1:  jmp <+0x82a>
+   3d6:  mov edi,DWORD PTR [rsi]
+   3d8:  movzx r8d,WORD PTR [rsi+0x4]
+   3dd:  shl r8,0x20
+   3e1:  or r8,rdi
+   3e4:  mov edi,0x2
+   3e9:  movabs r9,0x73696c6c696d
+   3f3:  jmp <+0x825>
+   3f8:  mov edi,DWORD PTR [rsi]
+   3fa:  movzx r8d,WORD PTR [rsi+0x4]
+   3ff:  shl r8,0x20
+   403:  or r8,rdi
+   406:  mov edi,0x8
+   40b:  movabs r9,0x7368746e6f6d
+   415:  jmp <+0x825>
+   41a:  mov edi,0x9
+   41f:  jmp <+0x82a>
+   424:  mov edi,DWORD PTR [rsi]
+   426:  movzx r8d,WORD PTR [rsi+0x4]
+   42b:  shl r8,0x20
+   42f:  or r8,rdi
+   432:  mov edi,0x1
+   437:  movabs r9,0x736f7263696d
+   441:  jmp <+0x825>
+   446:  mov edi,DWORD PTR [rsi]
+   448:  movzx r8d,WORD PTR [rsi+0x4]
+   44d:  shl r8,0x20
+   451:  or r8,rdi
+   454:  mov edi,0x4
+   459:  movabs r9,0x6574756e696d
+   463:  jmp <+0x825>
+   468:  mov edi,0x7
+   46d:  cmp WORD PTR [rsi],0x6b77
+   472:  je <+0x82a>
+   478:  jmp <+0x841>
+   47d:  mov edi,0x5
+   482:  cmp WORD PTR [rsi],0x7268
+   487:  je <+0x82a>
+   48d:  jmp <+0x841>
+   492:  mov edi,0x2
+   497:  cmp WORD PTR [rsi],0x736d
+   49c:  je <+0x82a>
+   4a2:  jmp <+0x841>
+   4a7:  mov edi,0x9
+   4ac:  cmp WORD PTR [rsi],0x7279
+   4b1:  je <+0x82a>
+   4b7:  jmp <+0x841>
+   4bc:  mov edi,0x1
+   4c1:  cmp WORD PTR [rsi],0x7375
+   4c6:  je <+0x82a>
+   4cc:  jmp <+0x841>
+   4d1:  cmp WORD PTR [rsi],0x736e
+   4d6:  je <+0x796>
+   4dc:  jmp <+0x841>
+   4e1:  cmp r8d,0x77
+   4e5:  jne <+0x841>
+   4eb:  movzx edi,WORD PTR [rsi]
+   4ee:  movzx r8d,BYTE PTR [rsi+0x2]
+   4f3:  shl r8d,0x10
+   4f7:  or r8d,edi
+   4fa:  mov edi,0x1
+   4ff:  cmp r8d,0x73b5c2
+   506:  je <+0x82a>
+   50c:  jmp <+0x841>
+   511:  movzx edi,WORD PTR [rsi]
+   514:  movzx r8d,BYTE PTR [rsi+0x2]
+   519:  shl r8d,0x10
+   51d:  or r8d,edi
+   520:  mov edi,0x7
+   525:  cmp r8d,0x736b77
+   52c:  je <+0x82a>
+   532:  jmp <+0x841>
+   537:  movzx edi,WORD PTR [rsi]
+   53a:  movzx r8d,BYTE PTR [rsi+0x2]
+   53f:  shl r8d,0x10
+   543:  or r8d,edi
+   546:  mov edi,0x6
+   54b:  cmp r8d,0x796164
+   552:  je <+0x82a>
+   558:  jmp <+0x841>
+   55d:  movzx edi,WORD PTR [rsi]
+   560:  movzx r8d,BYTE PTR [rsi+0x2]
+   565:  shl r8d,0x10
+   569:  or r8d,edi
+   56c:  mov edi,0x3
+   571:  cmp r8d,0x636573
+   578:  je <+0x82a>
+   57e:  jmp <+0x841>
+   583:  movzx edi,WORD PTR [rsi]
+   586:  movzx r8d,BYTE PTR [rsi+0x2]
+   58b:  shl r8d,0x10
+   58f:  or r8d,edi
+   592:  mov edi,0x5
+   597:  cmp r8d,0x737268
+   59e:  je <+0x82a>
+   5a4:  jmp <+0x841>
+   5a9:  movzx edi,WORD PTR [rsi]
+   5ac:  movzx r8d,BYTE PTR [rsi+0x2]
+   5b1:  shl r8d,0x10
+   5b5:  or r8d,edi
+   5b8:  mov edi,0x4
+   5bd:  cmp r8d,0x6e696d
+   5c4:  je <+0x82a>
+   5ca:  jmp <+0x841>
+   5cf:  movzx edi,WORD PTR [rsi]
+   5d2:  movzx r8d,BYTE PTR [rsi+0x2]
+   5d7:  shl r8d,0x10
+   5db:  or r8d,edi
+   5de:  mov edi,0x9
+   5e3:  cmp r8d,0x737279
+   5ea:  je <+0x82a>
+   5f0:  jmp <+0x841>
+   5f5:  cmp DWORD PTR [rsi],0x6f6e616e
+   5fb:  je <+0x796>
+   601:  jmp <+0x841>
+   606:  mov edi,0x9
+   60b:  cmp DWORD PTR [rsi],0x72616579
+   611:  je <+0x82a>
+   617:  jmp <+0x841>
+   61c:  mov edi,0x5
+   621:  cmp DWORD PTR [rsi],0x72756f68
+   627:  je <+0x82a>
+   62d:  jmp <+0x841>
+   632:  mov edi,0x3
+   637:  cmp DWORD PTR [rsi],0x73636573
+   63d:  je <+0x82a>
+   643:  jmp <+0x841>
+   648:  mov edi,0x1
+   64d:  cmp DWORD PTR [rsi],0x63657375
+   653:  je <+0x82a>
+   659:  jmp <+0x841>
+   65e:  mov edi,0x6
+   663:  cmp DWORD PTR [rsi],0x73796164
+   669:  je <+0x82a>
+   66f:  jmp <+0x841>
+   674:  mov edi,0x7
+   679:  cmp DWORD PTR [rsi],0x6b656577
+   67f:  je <+0x82a>
+   685:  jmp <+0x841>
+   68a:  cmp DWORD PTR [rsi],0x6365736e
+   690:  je <+0x796>
+   696:  jmp <+0x841>
+   69b:  mov edi,0x2
+   6a0:  cmp DWORD PTR [rsi],0x6365736d
+   6a6:  je <+0x82a>
+   6ac:  jmp <+0x841>
+   6b1:  cmp edi,0xb1
+   6b7:  jne <+0x841>
+   6bd:  mov edi,DWORD PTR [rsi]
+   6bf:  movzx r8d,BYTE PTR [rsi+0x4]
+   6c4:  shl r8,0x20
+   6c8:  or r8,rdi
+   6cb:  mov edi,0x1
+   6d0:  movabs r9,0x636573b5c2
+   6da:  jmp <+0x825>
+   6df:  mov edi,DWORD PTR [rsi]
+   6e1:  movzx r8d,BYTE PTR [rsi+0x4]
+   6e6:  shl r8,0x20
+   6ea:  or r8,rdi
+   6ed:  movabs r9,0x736365736d
+   6f7:  add r9,0xf0ffbfb
+   6fe:  mov edi,0x5
+   703:  jmp <+0x825>
+   708:  mov edi,DWORD PTR [rsi]
+   70a:  movzx r8d,BYTE PTR [rsi+0x4]
+   70f:  shl r8,0x20
+   713:  or r8,rdi
+   716:  movabs r9,0x736365736d
+   720:  add r9,0x8
+   724:  mov edi,0x1
+   729:  jmp <+0x825>
+   72e:  mov edi,DWORD PTR [rsi]
+   730:  movzx r8d,BYTE PTR [rsi+0x4]
+   735:  shl r8,0x20
+   739:  or r8,rdi
+   73c:  mov edi,0x2
+   741:  movabs r9,0x736365736d
+   74b:  jmp <+0x825>
+   750:  mov edi,DWORD PTR [rsi]
+   752:  movzx r8d,BYTE PTR [rsi+0x4]
+   757:  shl r8,0x20
+   75b:  or r8,rdi
+   75e:  mov edi,0x1
+   763:  movabs r9,0x6f7263696d
+   76d:  jmp <+0x825>
+   772:  mov edi,DWORD PTR [rsi]
+   774:  movzx r8d,BYTE PTR [rsi+0x4]
+   779:  shl r8,0x20
+   77d:  or r8,rdi
+   780:  movabs rdi,0x736365736d
+   78a:  inc rdi
+   78d:  cmp r8,rdi
+   790:  jne <+0x841>
+   796:  xor edi,edi
+   798:  jmp <+0x82a>
+   79d:  mov edi,DWORD PTR [rsi]
+   79f:  movzx r8d,BYTE PTR [rsi+0x4]
+   7a4:  shl r8,0x20
+   7a8:  or r8,rdi
+   7ab:  mov edi,0x8
+   7b0:  movabs r9,0x68746e6f6d
+   7ba:  jmp <+0x825>
+   7bc:  mov edi,DWORD PTR [rsi]
+   7be:  movzx r8d,BYTE PTR [rsi+0x4]
+   7c3:  shl r8,0x20
+   7c7:  or r8,rdi
+   7ca:  movabs r9,0x736365736d
+   7d4:  add r9,0x7fff20a
+   7db:  mov edi,0x7
+   7e0:  jmp <+0x825>
+   7e2:  mov edi,DWORD PTR [rsi]
+   7e4:  movzx r8d,BYTE PTR [rsi+0x4]
+   7e9:  shl r8,0x20
+   7ed:  or r8,rdi
+   7f0:  mov edi,0x2
+   7f5:  movabs r9,0x696c6c696d
+   7ff:  jmp <+0x825>
+   801:  mov edi,DWORD PTR [rsi]
+   803:  movzx r8d,BYTE PTR [rsi+0x4]
+   808:  shl r8,0x20
+   80c:  or r8,rdi
+   80f:  movabs r9,0x736365736d
+   819:  add r9,0xefbf20c
+   820:  mov edi,0x9
+   825:  cmp r8,r9
+   828:  jne <+0x841>
+   82a:  sub rdx,rcx
+   82d:  add rsi,rcx
+   830:  mov DWORD PTR [rax+0x8],edi
+   833:  mov QWORD PTR [rax+0x10],rsi
+   837:  mov QWORD PTR [rax+0x18],rdx
+   83b:  xor ecx,ecx
+   83d:  mov QWORD PTR [rax],rcx
+   840:  ret
+   841:  push rbx
+   842:  lea rdi,[rax+0x8]
+   846:  mov rbx,rax
+   849:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+   84f:  mov rax,rbx
+   852:  mov ecx,0x1
+   857:  pop rbx
+   858:  mov QWORD PTR [rax],rcx
+   85b:  ret
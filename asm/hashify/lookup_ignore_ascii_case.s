@@ -0,0 +1,513 @@
+duration_unit_lookup::hashify::lookup_ignore_ascii_case:
+     0:  push rbx
+     1:  sub rsp,0x10
+     5:  mov rax,rdi
+     8:  test rdx,rdx
+     b:  je <+0x96c>
+    11:  xor ecx,ecx
+    13:  jmp <+0x28>
+    15:  data16 cs nop WORD PTR [rax+rax*1+0x0]
+    20:  inc rcx
+    23:  cmp rdx,rcx
+    26:  je <+0x77>
+    28:  movzx r8d,BYTE PTR [rsi+rcx*1]
+    2d:  lea edi,[r8-0x41]
+    31:  cmp dil,0x1a
+    35:  setb dil
+    39:  shl dil,0x5
+    3d:  or dil,r8b
+    40:  lea r8d,[rdi-0x7b]
+    44:  cmp r8b,0xe5
+    48:  ja <+0x20>
+    4a:  cmp dil,0xc2
+    4e:  je <+0x20>
+    50:  movzx edi,dil
+    54:  cmp edi,0xb5
+    5a:  je <+0x20>
+    5c:  mov DWORD PTR [rsp+0x8],0x0
+    64:  mov QWORD PTR [rsp],0x0
+    6c:  cmp rcx,0xc
+    70:  jbe <+0x94>
+    72:  jmp <+0x96c>
+    77:  mov rcx,rdx
+    7a:  mov DWORD PTR [rsp+0x8],0x0
+    82:  mov QWORD PTR [rsp],0x0
+    8a:  cmp rcx,0xc
+    8e:  ja <+0x96c>
+    94:  test rcx,rcx
+    97:  je <+0x96c>
+    9d:  cmp rcx,0x1
+    a1:  jne <+0xa8>
+    a3:  xor r8d,r8d
+    a6:  jmp <+0xf8>
+    a8:  mov edi,ecx
+    aa:  and edi,0xe
+    ad:  xor r8d,r8d
+    b0:  movzx r9d,BYTE PTR [rsi+r8*1]
+    b5:  lea r10d,[r9-0x41]
+    b9:  cmp r10b,0x1a
+    bd:  setb r10b
+    c1:  shl r10b,0x5
+    c5:  or r10b,r9b
+    c8:  mov BYTE PTR [rsp+r8*1],r10b
+    cc:  movzx r9d,BYTE PTR [rsi+r8*1+0x1]
+    d2:  lea r10d,[r9-0x41]
+    d6:  cmp r10b,0x1a
+    da:  setb r10b
+    de:  shl r10b,0x5
+    e2:  or r10b,r9b
+    e5:  mov BYTE PTR [rsp+r8*1+0x1],r10b
+    ea:  add r8,0x2
+    ee:  cmp rdi,r8
+    f1:  jne <+0xb0>
+    f3:  test cl,0x1
+    f6:  je <+0x114>
+    f8:  movzx edi,BYTE PTR [rsi+r8*1]
+    fd:  lea r9d,[rdi-0x41]
+   101:  cmp r9b,0x1a
+   105:  setb r9b
+   109:  shl r9b,0x5
+   10d:  or r9b,dil
+   110:  mov BYTE PTR [rsp+r8*1],r9b
+   114:  lea rdi,[rcx-0x1]
+   118:  cmp rdi,0xb
+   11c:  ja <+0x96c>
+   122:  lea r8,[rip]
+   129:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   12d:  add rdi,r8
+   130:  jmp rdi
+   132:  movzx edi,BYTE PTR [rsp]
+   136:  add edi,0xffffff9c
+   139:  cmp edi,0x15
+   13c:  ja <+0x96c>
+   142:  lea r8,[rip]
+   149:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   14d:  add rdi,r8
+   150:  jmp rdi
+   152:  mov edi,0x6
+   157:  jmp <+0x950>
+   15c:  movzx edi,BYTE PTR [rsp+0x2]
+   161:  xor dil,BYTE PTR [rsp]
+   165:  movzx edi,dil
+   169:  cmp dil,0x1d
+   16d:  ja <+0x7c3>
+   173:  lea r8,[rip]
+   17a:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   17e:  add rdi,r8
+   181:  jmp rdi
+   183:  mov edi,DWORD PTR [rsp]
+   186:  movzx r8d,BYTE PTR [rsp+0x4]
+   18c:  shl r8,0x20
+   190:  or r8,rdi
+   193:  movabs rdi,0x736365736d
+   19d:  add rdi,0xc08ee01
+   1a4:  cmp r8,rdi
+   1a7:  je <+0x8b4>
+   1ad:  jmp <+0x96c>
+   1b2:  movzx edi,WORD PTR [rsp+0x8]
+   1b7:  movabs r8,0x6f6365736f6e616e
+   1c1:  xor r8,QWORD PTR [rsp]
+   1c5:  xor rdi,0x646e
+   1cc:  or rdi,r8
+   1cf:  je <+0x8b4>
+   1d5:  jmp <+0x96c>
+   1da:  movzx edi,BYTE PTR [rsp+0x1]
+   1df:  xor dil,BYTE PTR [rsp]
+   1e3:  movzx edi,dil
+   1e7:  add edi,0xfffffffc
+   1ea:  cmp edi,0x1a
+   1ed:  ja <+0x96c>
+   1f3:  lea r8,[rip]
+   1fa:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   1fe:  add rdi,r8
+   201:  jmp rdi
+   203:  mov edi,0x4
+   208:  cmp DWORD PTR [rsp],0x736e696d
+   20f:  je <+0x950>
+   215:  jmp <+0x96c>
+   21a:  movzx edi,BYTE PTR [rsp]
+   21e:  cmp edi,0x6d
+   221:  je <+0x3bc>
+   227:  cmp edi,0x73
+   22a:  jne <+0x96c>
+   230:  movzx edi,BYTE PTR [rsp+0x6]
+   235:  shl edi,0x10
+   238:  movzx r8d,WORD PTR [rsp+0x4]
+   23e:  or r8d,edi
+   241:  shl r8,0x20
+   245:  mov r9d,DWORD PTR [rsp]
+   249:  or r9,r8
+   24c:  mov edi,0x3
+   251:  movabs r8,0x73646e6f636573
+   25b:  jmp <+0x3e7>
+   260:  movzx edi,BYTE PTR [rsp+0x1]
+   265:  xor dil,BYTE PTR [rsp]
+   269:  movzx r8d,dil
+   26d:  lea edi,[r8-0x2]
+   271:  cmp edi,0x1a
+   274:  ja <+0x5e3>
+   27a:  lea r8,[rip]
+   281:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   285:  add rdi,r8
+   288:  jmp rdi
+   28a:  movzx edi,BYTE PTR [rsp+0x2]
+   28f:  shl edi,0x10
+   292:  movzx r8d,WORD PTR [rsp]
+   297:  or r8d,edi
+   29a:  mov edi,0x8
+   29f:  cmp r8d,0x736f6d
+   2a6:  je <+0x950>
+   2ac:  jmp <+0x96c>
+   2b1:  movzx edi,BYTE PTR [rsp+0x1]
+   2b6:  xor dil,BYTE PTR [rsp]
+   2ba:  movzx edi,dil
+   2be:  add edi,0xfffffffe
+   2c1:  cmp edi,0x1c
+   2c4:  ja <+0x96c>
+   2ca:  lea r8,[rip]
+   2d1:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   2d5:  add rdi,r8
+   2d8:  jmp rdi
+   2da:  mov edi,0x8
+   2df:  cmp WORD PTR [rsp],0x6f6d
+   2e5:  je <+0x950>
+   2eb:  jmp <+0x96c>
+   2f0:  movzx edi,BYTE PTR [rsp+0x3]
+   2f5:  add edi,0xffffff9b
+   2f8:  cmp edi,0x10
+   2fb:  ja <+0x96c>
+   301:  lea r8,[rip]
+   308:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   30c:  add rdi,r8
+   30f:  jmp rdi
+   311:  mov edi,DWORD PTR [rsp]
+   314:  movzx r8d,WORD PTR [rsp+0x4]
+   31a:  shl r8,0x20
+   31e:  or r8,rdi
+   321:  mov edi,0x1
+   326:  movabs r9,0x73636573b5c2
+   330:  jmp <+0x94b>
+   335:  movzx edi,BYTE PTR [rsp+0x2]
+   33a:  cmp edi,0x63
+   33d:  je <+0x416>
+   343:  cmp edi,0x6c
+   346:  je <+0x444>
+   34c:  cmp edi,0x6e
+   34f:  jne <+0x96c>
+   355:  movzx edi,WORD PTR [rsp+0x8]
+   35a:  movzx r8d,BYTE PTR [rsp+0xa]
+   360:  shl r8d,0x10
+   364:  or r8,rdi
+   367:  xor r8,0x73646e
+   36e:  movabs rdi,0x6f6365736f6e616e
+   378:  xor rdi,QWORD PTR [rsp]
+   37c:  or rdi,r8
+   37f:  je <+0x8b4>
+   385:  jmp <+0x96c>
+   38a:  movzx edi,BYTE PTR [rsp+0x2]
+   38f:  cmp edi,0x63
+   392:  je <+0x3f5>
+   394:  cmp edi,0x6c
+   397:  jne <+0x96c>
+   39d:  movabs r8,0x636573696c6c696d
+   3a7:  xor r8,QWORD PTR [rsp]
+   3ab:  mov r9d,DWORD PTR [rsp+0x8]
+   3b0:  xor r9,0x73646e6f
+   3b7:  jmp <+0x46b>
+   3bc:  movzx edi,BYTE PTR [rsp+0x6]
+   3c1:  shl edi,0x10
+   3c4:  movzx r8d,WORD PTR [rsp+0x4]
+   3ca:  or r8d,edi
+   3cd:  shl r8,0x20
+   3d1:  mov r9d,DWORD PTR [rsp]
+   3d5:  or r9,r8
+   3d8:  mov edi,0x4
+   3dd:  movabs r8,0x736574756e696d
+   3e7:  cmp r9,r8
+   3ea:  je <+0x950>
+   3f0:  jmp <+0x96c>
+   3f5:  movabs r8,0x6365736f7263696d
+   3ff:  xor r8,QWORD PTR [rsp]
+   403:  mov r9d,DWORD PTR [rsp+0x8]
+   408:  xor r9,0x73646e6f
+   40f:  mov edi,0x1
+   414:  jmp <+0x470>
+   416:  movzx edi,WORD PTR [rsp+0x8]
+   41b:  movzx r8d,BYTE PTR [rsp+0xa]
+   421:  shl r8d,0x10
+   425:  or r8,rdi
+   428:  xor r8,0x646e6f
+   42f:  movabs r9,0x6365736f7263696d
+   439:  xor r9,QWORD PTR [rsp]
+   43d:  mov edi,0x1
+   442:  jmp <+0x470>
+   444:  movzx edi,WORD PTR [rsp+0x8]
+   449:  movzx r8d,BYTE PTR [rsp+0xa]
+   44f:  shl r8d,0x10
+   453:  or r8,rdi
+   456:  xor r8,0x646e6f
+   45d:  movabs r9,0x636573696c6c696d
+   467:  xor r9,QWORD PTR [rsp]
+   46b:  mov edi,0x2
+   470:  or r9,r8
+   473:  je <+0x950>
+   479:  jmp <+0x96c>
+   47e:  mov edi,0x4
+   483:  jmp <+0x950>
+   488:  mov edi,0x5
+   48d:  jmp <+0x950>
+   492:  mov edi,0x7
+   497:  jmp <+0x950>
+   49c:  mov edi,DWORD PTR [rsp]
+   49f:  movzx r8d,WORD PTR [rsp+0x4]
+   4a5:  shl r8,0x20
+   4a9:  or r8,rdi
+   4ac:  mov edi,0x3
+   4b1:  movabs r9,0x646e6f636573
+   4bb:  jmp <+0x94b>
+   4c0:  mov edi,0x3
+   4c5:  jmp <+0x950>
+   4ca:  mov edi,DWORD PTR [rsp]
+   4cd:  movzx r8d,WORD PTR [rsp+0x4]
+   4d3:  shl r8,0x20
+   4d7:  or r8,rdi
+   4da:  mov edi,0x2
+   4df:  movabs r9,0x73696c6c696d
+   4e9:  jmp <+0x94b>
+   4ee:  mov edi,DWORD PTR [rsp]
+   4f1:  movzx r8d,WORD PTR [rsp+0x4]
+   4f7:  shl r8,0x20
+   4fb:  or r8,rdi
+   4fe:  mov edi,0x8
+   503:  movabs r9,0x7368746e6f6d
+   50d:  jmp <+0x94b>
+   512:  mov edi,0x9
+   517:  jmp <+0x950>
+   51c:  mov edi,DWORD PTR [rsp]
+   51f:  movzx r8d,WORD PTR [rsp+0x4]
+   525:  shl r8,0x20
+   529:  or r8,rdi
+   52c:  mov edi,0x1
+   531:  movabs r9,0x736f7263696d
+   53b:  jmp <+0x94b>
+   540:  mov edi,DWORD PTR [rsp]
+   543:  movzx r8d,WORD PTR [rsp+0x4]
+   549:  shl r8,0x20
+   54d:  or r8,rdi
+   550:  mov edi,0x4
+   555:  movabs r9,0x6574756e696d
+   55f:  jmp <+0x94b>
+   564:  mov edi,0x7
+   569:  cmp WORD PTR [rsp],0x6b77
+   56f:  je <+0x950>
+   575:  jmp <+0x96c>
+   57a:  mov edi,0x5
+   57f:  cmp WORD PTR [rsp],0x7268
+   585:  je <+0x950>
+   58b:  jmp <+0x96c>
+   590:  mov edi,0x2
+   595:  cmp WORD PTR [rsp],0x736d
+   59b:  je <+0x950>
+   5a1:  jmp <+0x96c>
+   5a6:  mov edi,0x9
+   5ab:  cmp WORD PTR [rsp],0x7279
+   5b1:  je <+0x950>
+   5b7:  jmp <+0x96c>
+   5bc:  mov edi,0x1
+   5c1:  cmp WORD PTR [rsp],0x7375
+   5c7:  je <+0x950>
+   5cd:  jmp <+0x96c>
+   5d2:  cmp WORD PTR [rsp],0x736e
+   5d8:  je <+0x8b4>
+   5de:  jmp <+0x96c>
+   5e3:  cmp r8d,0x77
+   5e7:  jne <+0x96c>
+   5ed:  movzx edi,BYTE PTR [rsp+0x2]
+   5f2:  shl edi,0x10
+   5f5:  movzx r8d,WORD PTR [rsp]
+   5fa:  or r8d,edi
+   5fd:  mov edi,0x1
+   602:  cmp r8d,0x73b5c2
+   609:  je <+0x950>
+   60f:  jmp <+0x96c>
+   614:  movzx edi,BYTE PTR [rsp+0x2]
+   619:  shl edi,0x10
+   61c:  movzx r8d,WORD PTR [rsp]
+   621:  or r8d,edi
+   624:  mov edi,0x7
+   629:  cmp r8d,0x736b77
+   630:  je <+0x950>
+   636:  jmp <+0x96c>
+   63b:  movzx edi,BYTE PTR [rsp+0x2]
+   640:  shl edi,0x10
+   643:  movzx r8d,WORD PTR [rsp]
+   648:  or r8d,edi
+   64b:  mov edi,0x6
+   650:  cmp r8d,0x796164
+   657:  je <+0x950>
+   65d:  jmp <+0x96c>
+   662:  movzx edi,BYTE PTR [rsp+0x2]
+   667:  shl edi,0x10
+   66a:  movzx r8d,WORD PTR [rsp]
+   66f:  or r8d,edi
+   672:  mov edi,0x3
+   677:  cmp r8d,0x636573
+   67e:  je <+0x950>
+   684:  jmp <+0x96c>
+   689:  movzx edi,BYTE PTR [rsp+0x2]
+   68e:  shl edi,0x10
+   691:  movzx r8d,WORD PTR [rsp]
+   696:  or r8d,edi
+   699:  mov edi,0x5
+   69e:  cmp r8d,0x737268
+   6a5:  je <+0x950>
+   6ab:  jmp <+0x96c>
+   6b0:  movzx edi,BYTE PTR [rsp+0x2]
+   6b5:  shl edi,0x10
+   6b8:  movzx r8d,WORD PTR [rsp]
+   6bd:  or r8d,edi
+   6c0:  mov edi,0x4
+   6c5:  cmp r8d,0x6e696d
+   6cc:  je <+0x950>
+   6d2:  jmp <+0x96c>
+   6d7:  movzx edi,BYTE PTR [rsp+0x2]
+   6dc:  shl edi,0x10
+   6df:  movzx r8d,WORD PTR [rsp]
+   6e4:  or r8d,edi
+   6e7:  mov edi,0x9
+   6ec:  cmp r8d,0x737279
+   6f3:  je <+0x950>
+   6f9:  jmp <+0x96c>
+   6fe:  cmp DWORD PTR [rsp],0x6f6e616e
+   705:  je <+0x8b4>
+   70b:  jmp <+0x96c>
+   710:  mov edi,0x9
+   715:  cmp DWORD PTR [rsp],0x72616579
+   71c:  je <+0x950>
+   722:  jmp <+0x96c>
+   727:  mov edi,0x5
+   72c:  cmp DWORD PTR [rsp],0x72756f68
+   733:  je <+0x950>
+   739:  jmp <+0x96c>
+   73e:  mov edi,0x3
+   743:  cmp DWORD PTR [rsp],0x73636573
+   74a:  je <+0x950>
+   750:  jmp <+0x96c>
+   755:  mov edi,0x1
+   75a:  cmp DWORD PTR [rsp],0x63657375
+   761:  je <+0x950>
+   767:  jmp <+0x96c>
+   76c:  mov edi,0x6
+   771:  cmp DWORD PTR [rsp],0x73796164
+   778:  je <+0x950>
+   77e:  jmp <+0x96c>
+   783:  mov edi,0x7
+   788:  cmp DWORD PTR [rsp],0x6b656577
+   78f:  je <+0x950>
+   795:  jmp <+0x96c>
+   79a:  cmp DWORD PTR [rsp],0x6365736e
+   7a1:  je <+0x8b4>
+   7a7:  jmp <+0x96c>
+   7ac:  mov edi,0x2
+   7b1:  cmp DWORD PTR [rsp],0x6365736d
+   7b8:  je <+0x950>
+   7be:  jmp <+0x96c>
+   7c3:  cmp edi,0xb1
+   7c9:  jne <+0x96c>
+   7cf:  mov edi,DWORD PTR [rsp]
+   7d2:  movzx r8d,BYTE PTR [rsp+0x4]
+   7d8:  shl r8,0x20
+   7dc:  or r8,rdi
+   7df:  mov edi,0x1
+   7e4:  movabs r9,0x636573b5c2
+   7ee:  jmp <+0x94b>
+   7f3:  mov edi,DWORD PTR [rsp]
+   7f6:  movzx r8d,BYTE PTR [rsp+0x4]
+   7fc:  shl r8,0x20
+   800:  or r8,rdi
+   803:  movabs r9,0x736365736d
+   80d:  add r9,0xf0ffbfb
+   814:  mov edi,0x5
+   819:  jmp <+0x94b>
+   81e:  mov edi,DWORD PTR [rsp]
+   821:  movzx r8d,BYTE PTR [rsp+0x4]
+   827:  shl r8,0x20
+   82b:  or r8,rdi
+   82e:  movabs r9,0x736365736d
+   838:  add r9,0x8
+   83c:  mov edi,0x1
+   841:  jmp <+0x94b>
+   846:  mov edi,DWORD PTR [rsp]
+   849:  movzx r8d,BYTE PTR [rsp+0x4]
+   84f:  shl r8,0x20
+   853:  or r8,rdi
+   856:  mov edi,0x2
+   85b:  movabs r9,0x736365736d
+   865:  jmp <+0x94b>
+   86a:  mov edi,DWORD PTR [rsp]
+   86d:  movzx r8d,BYTE PTR [rsp+0x4]
+   873:  shl r8,0x20
+   877:  or r8,rdi
+   87a:  mov edi,0x1
+   87f:  movabs r9,0x6f7263696d
+   889:  jmp <+0x94b>
+   88e:  mov edi,DWORD PTR [rsp]
+   891:  movzx r8d,BYTE PTR [rsp+0x4]
+   897:  shl r8,0x20
+   89b:  or r8,rdi
+   89e:  movabs rdi,0x736365736d
+   8a8:  inc rdi
+   8ab:  cmp r8,rdi
+   8ae:  jne <+0x96c>
+   8b4:  xor edi,edi
+   8b6:  jmp <+0x950>
+   8bb:  mov edi,DWORD PTR [rsp]
+   8be:  movzx r8d,BYTE PTR [rsp+0x4]
+   8c4:  shl r8,0x20
+   8c8:  or r8,rdi
+   8cb:  mov edi,0x8
+   8d0:  movabs r9,0x68746e6f6d
+   8da:  jmp <+0x94b>
+   8dc:  mov edi,DWORD PTR [rsp]
+   8df:  movzx r8d,BYTE PTR [rsp+0x4]
+   8e5:  shl r8,0x20
+   8e9:  or r8,rdi
+   8ec:  movabs r9,0x736365736d
+   8f6:  add r9,0x7fff20a
+   8fd:  mov edi,0x7
+   902:  jmp <+0x94b>
+   904:  mov edi,DWORD PTR [rsp]
+   907:  movzx r8d,BYTE PTR [rsp+0x4]
+   90d:  shl r8,0x20
+   911:  or r8,rdi
+   914:  mov edi,0x2
+   919:  movabs r9,0x696c6c696d
+   923:  jmp <+0x94b>
+   925:  mov edi,DWORD PTR [rsp]
+   928:  movzx r8d,BYTE PTR [rsp+0x4]
+   92e:  shl r8,0x20
+   932:  or r8,rdi
+   935:  movabs r9,0x736365736d
+   93f:  add r9,0xefbf20c
+   946:  mov edi,0x9
+   94b:  cmp r8,r9
+   94e:  jne <+0x96c>
+   950:  add rsi,rcx
+   953:  sub rdx,rcx
+   956:  mov DWORD PTR [rax+0x8],edi
+   959:  mov QWORD PTR [rax+0x10],rsi
+   95d:  mov QWORD PTR [rax+0x18],rdx
+   961:  xor ecx,ecx
+   963:  mov QWORD PTR [rax],rcx
+   966:  add rsp,0x10
+   96a:  pop rbx
+   96b:  ret
+   96c:  lea rdi,[rax+0x8]
+   970:  mov rbx,rax
+   973:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+   979:  mov rax,rbx
+   97c:  mov ecx,0x1
+   981:  mov QWORD PTR [rax],rcx
+   984:  add rsp,0x10
+   988:  pop rbx
+   989:  ret
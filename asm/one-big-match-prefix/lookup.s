@@ -0,0 +1,706 @@
+duration_unit_lookup::one_big_match_prefix::lookup:
+     0:  push rbx
+     1:  cmp rdx,0xb
+     5:  jbe <+0xa1>
+     b:  movzx eax,BYTE PTR [rsi]
+     e:  cmp al,0x6d
+    10:  jne <+0xa6>
+    16:  movzx eax,BYTE PTR [rsi+0x1]
+    1a:  cmp al,0x69
+    1c:  jne <+0x27a>
+    22:  movzx eax,BYTE PTR [rsi+0x2]
+    26:  cmp eax,0x63
+    29:  je <+0x405>
+    2f:  cmp eax,0x6c
+    32:  jne <+0x191>
+    38:  cmp BYTE PTR [rsi+0x3],0x6c
+    3c:  jne <+0x191>
+    42:  cmp BYTE PTR [rsi+0x4],0x69
+    46:  jne <+0x191>
+    4c:  cmp BYTE PTR [rsi+0x5],0x73
+    50:  jne <+0x191>
+    56:  cmp BYTE PTR [rsi+0x6],0x65
+    5a:  jne <+0x191>
+    60:  cmp BYTE PTR [rsi+0x7],0x63
+    64:  jne <+0x191>
+    6a:  cmp BYTE PTR [rsi+0x8],0x6f
+    6e:  jne <+0x191>
+    74:  cmp BYTE PTR [rsi+0x9],0x6e
+    78:  jne <+0x191>
+    7e:  cmp BYTE PTR [rsi+0xa],0x64
+    82:  jne <+0x191>
+    88:  cmp BYTE PTR [rsi+0xb],0x73
+    8c:  jne <+0x191>
+    92:  mov ecx,0x2
+    97:  mov eax,0xc
+    9c:  jmp <+0xb6b>
+    a1:  jne <+0x108>
+    a3:  movzx eax,BYTE PTR [rsi]
+    a6:  cmp al,0x6d
+    a8:  je <+0x185>
+    ae:  movzx ecx,al
+    b1:  cmp ecx,0x6e
+    b4:  jne <+0x213>
+    ba:  movzx eax,BYTE PTR [rsi+0x1]
+    be:  cmp al,0x61
+    c0:  jne <+0x629>
+    c6:  cmp BYTE PTR [rsi+0x2],0x6e
+    ca:  jne <+0x129>
+    cc:  cmp BYTE PTR [rsi+0x3],0x6f
+    d0:  jne <+0x129>
+    d2:  cmp BYTE PTR [rsi+0x4],0x73
+    d6:  jne <+0x129>
+    d8:  cmp BYTE PTR [rsi+0x5],0x65
+    dc:  jne <+0x129>
+    de:  cmp BYTE PTR [rsi+0x6],0x63
+    e2:  jne <+0x129>
+    e4:  cmp BYTE PTR [rsi+0x7],0x6f
+    e8:  jne <+0x129>
+    ea:  cmp BYTE PTR [rsi+0x8],0x6e
+    ee:  jne <+0x129>
+    f0:  cmp BYTE PTR [rsi+0x9],0x64
+    f4:  jne <+0x129>
+    f6:  cmp BYTE PTR [rsi+0xa],0x73
+    fa:  jne <+0x129>
+    fc:  xor ecx,ecx
+    fe:  mov eax,0xb
+   103:  jmp <+0xb6b>
+   108:  cmp rdx,0x9
+   10c:  jbe <+0x206>
+   112:  movzx eax,BYTE PTR [rsi]
+   115:  cmp al,0x6e
+   117:  jne <+0x213>
+   11d:  movzx eax,BYTE PTR [rsi+0x1]
+   121:  cmp al,0x61
+   123:  jne <+0x629>
+   129:  cmp BYTE PTR [rsi+0x2],0x6e
+   12d:  jne <+0x658>
+   133:  cmp BYTE PTR [rsi+0x3],0x6f
+   137:  jne <+0x658>
+   13d:  cmp BYTE PTR [rsi+0x4],0x73
+   141:  jne <+0x658>
+   147:  cmp BYTE PTR [rsi+0x5],0x65
+   14b:  jne <+0x658>
+   151:  cmp BYTE PTR [rsi+0x6],0x63
+   155:  jne <+0x658>
+   15b:  cmp BYTE PTR [rsi+0x7],0x6f
+   15f:  jne <+0x658>
+   165:  cmp BYTE PTR [rsi+0x8],0x6e
+   169:  jne <+0x658>
+   16f:  cmp BYTE PTR [rsi+0x9],0x64
+   173:  jne <+0x658>
+   179:  xor ecx,ecx
+   17b:  mov eax,0xa
+   180:  jmp <+0xb6b>
+   185:  movzx eax,BYTE PTR [rsi+0x1]
+   189:  cmp al,0x69
+   18b:  jne <+0x27a>
+   191:  movzx eax,BYTE PTR [rsi+0x2]
+   195:  cmp eax,0x63
+   198:  je <+0x2c5>
+   19e:  cmp eax,0x6c
+   1a1:  jne <+0x304>
+   1a7:  cmp BYTE PTR [rsi+0x3],0x6c
+   1ab:  jne <+0x304>
+   1b1:  cmp BYTE PTR [rsi+0x4],0x69
+   1b5:  jne <+0x304>
+   1bb:  cmp BYTE PTR [rsi+0x5],0x73
+   1bf:  jne <+0x304>
+   1c5:  cmp BYTE PTR [rsi+0x6],0x65
+   1c9:  jne <+0x304>
+   1cf:  cmp BYTE PTR [rsi+0x7],0x63
+   1d3:  jne <+0x304>
+   1d9:  cmp BYTE PTR [rsi+0x8],0x6f
+   1dd:  jne <+0x304>
+   1e3:  cmp BYTE PTR [rsi+0x9],0x6e
+   1e7:  jne <+0x304>
+   1ed:  cmp BYTE PTR [rsi+0xa],0x64
+   1f1:  jne <+0x304>
+   1f7:  mov ecx,0x2
+   1fc:  mov eax,0xb
+   201:  jmp <+0xb6b>
+   206:  cmp rdx,0x7
+   20a:  jb <+0x331>
+   210:  movzx eax,BYTE PTR [rsi]
+   213:  cmp al,0x6d
+   215:  je <+0x26e>
+   217:  movzx ecx,al
+   21a:  cmp ecx,0x73
+   21d:  jne <+0x33e>
+   223:  cmp BYTE PTR [rsi+0x1],0x65
+   227:  jne <+0xa6d>
+   22d:  cmp BYTE PTR [rsi+0x2],0x63
+   231:  jne <+0x478>
+   237:  cmp BYTE PTR [rsi+0x3],0x6f
+   23b:  jne <+0x478>
+   241:  cmp BYTE PTR [rsi+0x4],0x6e
+   245:  jne <+0x478>
+   24b:  cmp BYTE PTR [rsi+0x5],0x64
+   24f:  jne <+0x478>
+   255:  cmp BYTE PTR [rsi+0x6],0x73
+   259:  jne <+0x478>
+   25f:  mov ecx,0x3
+   264:  mov eax,0x7
+   269:  jmp <+0xb6b>
+   26e:  movzx eax,BYTE PTR [rsi+0x1]
+   272:  cmp al,0x69
+   274:  je <+0x304>
+   27a:  cmp al,0x69
+   27c:  je <+0x367>
+   282:  movzx ecx,al
+   285:  cmp ecx,0x6f
+   288:  jne <+0x6ec>
+   28e:  cmp BYTE PTR [rsi+0x2],0x6e
+   292:  jne <+0x755>
+   298:  cmp BYTE PTR [rsi+0x3],0x74
+   29c:  jne <+0x755>
+   2a2:  cmp BYTE PTR [rsi+0x4],0x68
+   2a6:  jne <+0x755>
+   2ac:  cmp BYTE PTR [rsi+0x5],0x73
+   2b0:  jne <+0x755>
+   2b6:  mov ecx,0x8
+   2bb:  mov eax,0x6
+   2c0:  jmp <+0xb6b>
+   2c5:  cmp BYTE PTR [rsi+0x3],0x72
+   2c9:  jne <+0x304>
+   2cb:  cmp BYTE PTR [rsi+0x4],0x6f
+   2cf:  jne <+0x304>
+   2d1:  cmp BYTE PTR [rsi+0x5],0x73
+   2d5:  jne <+0x304>
+   2d7:  cmp BYTE PTR [rsi+0x6],0x65
+   2db:  jne <+0x304>
+   2dd:  cmp BYTE PTR [rsi+0x7],0x63
+   2e1:  jne <+0x304>
+   2e3:  cmp BYTE PTR [rsi+0x8],0x6f
+   2e7:  jne <+0x304>
+   2e9:  cmp BYTE PTR [rsi+0x9],0x6e
+   2ed:  jne <+0x304>
+   2ef:  cmp BYTE PTR [rsi+0xa],0x64
+   2f3:  jne <+0x304>
+   2f5:  mov ecx,0x1
+   2fa:  mov eax,0xb
+   2ff:  jmp <+0xb6b>
+   304:  cmp BYTE PTR [rsi+0x2],0x6e
+   308:  jne <+0x367>
+   30a:  cmp BYTE PTR [rsi+0x3],0x75
+   30e:  jne <+0x367>
+   310:  cmp BYTE PTR [rsi+0x4],0x74
+   314:  jne <+0x367>
+   316:  cmp BYTE PTR [rsi+0x5],0x65
+   31a:  jne <+0x367>
+   31c:  cmp BYTE PTR [rsi+0x6],0x73
+   320:  jne <+0x367>
+   322:  mov ecx,0x4
+   327:  mov eax,0x7
+   32c:  jmp <+0xb6b>
+   331:  cmp rdx,0x6
+   335:  jne <+0x4e6>
+   33b:  movzx eax,BYTE PTR [rsi]
+   33e:  cmp al,0xc2
+   340:  je <+0x4af>
+   346:  movzx ecx,al
+   349:  cmp ecx,0x73
+   34c:  je <+0x46e>
+   352:  cmp ecx,0x6d
+   355:  jne <+0x4ef>
+   35b:  movzx eax,BYTE PTR [rsi+0x1]
+   35f:  cmp al,0x69
+   361:  jne <+0x282>
+   367:  movzx eax,BYTE PTR [rsi+0x2]
+   36b:  cmp eax,0x63
+   36e:  je <+0x3d8>
+   370:  cmp eax,0x6c
+   373:  je <+0x3ab>
+   375:  cmp eax,0x6e
+   378:  jne <+0x720>
+   37e:  cmp BYTE PTR [rsi+0x3],0x75
+   382:  jne <+0x720>
+   388:  cmp BYTE PTR [rsi+0x4],0x74
+   38c:  jne <+0x720>
+   392:  cmp BYTE PTR [rsi+0x5],0x65
+   396:  jne <+0x720>
+   39c:  mov ecx,0x4
+   3a1:  mov eax,0x6
+   3a6:  jmp <+0xb6b>
+   3ab:  cmp BYTE PTR [rsi+0x3],0x6c
+   3af:  jne <+0x720>
+   3b5:  cmp BYTE PTR [rsi+0x4],0x69
+   3b9:  jne <+0x720>
+   3bf:  cmp BYTE PTR [rsi+0x5],0x73
+   3c3:  jne <+0x720>
+   3c9:  mov ecx,0x2
+   3ce:  mov eax,0x6
+   3d3:  jmp <+0xb6b>
+   3d8:  cmp BYTE PTR [rsi+0x3],0x72
+   3dc:  jne <+0x720>
+   3e2:  cmp BYTE PTR [rsi+0x4],0x6f
+   3e6:  jne <+0x720>
+   3ec:  cmp BYTE PTR [rsi+0x5],0x73
+   3f0:  jne <+0x720>
+   3f6:  mov ecx,0x1
+   3fb:  mov eax,0x6
+   400:  jmp <+0xb6b>
+   405:  cmp BYTE PTR [rsi+0x3],0x72
+   409:  jne <+0x191>
+   40f:  cmp BYTE PTR [rsi+0x4],0x6f
+   413:  jne <+0x191>
+   419:  cmp BYTE PTR [rsi+0x5],0x73
+   41d:  jne <+0x191>
+   423:  cmp BYTE PTR [rsi+0x6],0x65
+   427:  jne <+0x191>
+   42d:  cmp BYTE PTR [rsi+0x7],0x63
+   431:  jne <+0x191>
+   437:  cmp BYTE PTR [rsi+0x8],0x6f
+   43b:  jne <+0x191>
+   441:  cmp BYTE PTR [rsi+0x9],0x6e
+   445:  jne <+0x191>
+   44b:  cmp BYTE PTR [rsi+0xa],0x64
+   44f:  jne <+0x191>
+   455:  cmp BYTE PTR [rsi+0xb],0x73
+   459:  jne <+0x191>
+   45f:  mov ecx,0x1
+   464:  mov eax,0xc
+   469:  jmp <+0xb6b>
+   46e:  cmp BYTE PTR [rsi+0x1],0x65
+   472:  jne <+0xa6d>
+   478:  cmp BYTE PTR [rsi+0x2],0x63
+   47c:  jne <+0x94b>
+   482:  cmp BYTE PTR [rsi+0x3],0x6f
+   486:  jne <+0x94b>
+   48c:  cmp BYTE PTR [rsi+0x4],0x6e
+   490:  jne <+0x94b>
+   496:  cmp BYTE PTR [rsi+0x5],0x64
+   49a:  jne <+0x94b>
+   4a0:  mov ecx,0x3
+   4a5:  mov eax,0x6
+   4aa:  jmp <+0xb6b>
+   4af:  cmp BYTE PTR [rsi+0x1],0xb5
+   4b3:  jne <+0xa6d>
+   4b9:  cmp BYTE PTR [rsi+0x2],0x73
+   4bd:  jne <+0x567>
+   4c3:  cmp BYTE PTR [rsi+0x3],0x65
+   4c7:  jne <+0x567>
+   4cd:  cmp BYTE PTR [rsi+0x4],0x63
+   4d1:  jne <+0x567>
+   4d7:  cmp BYTE PTR [rsi+0x5],0x73
+   4db:  je <+0x3f6>
+   4e1:  jmp <+0x567>
+   4e6:  cmp rdx,0x5
+   4ea:  jb <+0x545>
+   4ec:  movzx eax,BYTE PTR [rsi]
+   4ef:  movzx r8d,al
+   4f3:  lea ecx,[r8-0x68]
+   4f7:  cmp ecx,0x11
+   4fa:  ja <+0x554>
+   4fc:  lea r8,[rip]
+   503:  movsxd rcx,DWORD PTR [r8+rcx*4]
+   507:  add rcx,r8
+   50a:  jmp rcx
+   50c:  movzx eax,BYTE PTR [rsi+0x1]
+   510:  cmp al,0x6f
+   512:  jne <+0xa37>
+   518:  cmp BYTE PTR [rsi+0x2],0x75
+   51c:  jne <+0x83f>
+   522:  cmp BYTE PTR [rsi+0x3],0x72
+   526:  jne <+0x83f>
+   52c:  cmp BYTE PTR [rsi+0x4],0x73
+   530:  jne <+0x83f>
+   536:  mov ecx,0x5
+   53b:  mov eax,0x5
+   540:  jmp <+0xb6b>
+   545:  cmp rdx,0x4
+   549:  jne <+0x5d6>
+   54f:  movzx eax,BYTE PTR [rsi]
+   552:  jmp <+0x596>
+   554:  cmp r8d,0xc2
+   55b:  jne <+0x596>
+   55d:  cmp BYTE PTR [rsi+0x1],0xb5
+   561:  jne <+0xa6d>
+   567:  cmp BYTE PTR [rsi+0x2],0x73
+   56b:  jne <+0x57d>
+   56d:  cmp BYTE PTR [rsi+0x3],0x65
+   571:  jne <+0x57d>
+   573:  cmp BYTE PTR [rsi+0x4],0x63
+   577:  je <+0x796>
+   57d:  cmp BYTE PTR [rsi+0x2],0x73
+   581:  jne <+0xa6d>
+   587:  mov ecx,0x1
+   58c:  mov eax,0x3
+   591:  jmp <+0xb6b>
+   596:  movzx ecx,al
+   599:  add ecx,0xffffff9c
+   59c:  cmp ecx,0x15
+   59f:  ja <+0x5e3>
+   5a1:  lea r8,[rip]
+   5a8:  movsxd rcx,DWORD PTR [r8+rcx*4]
+   5ac:  add rcx,r8
+   5af:  jmp rcx
+   5b1:  cmp BYTE PTR [rsi+0x1],0x61
+   5b5:  jne <+0xa6d>
+   5bb:  cmp BYTE PTR [rsi+0x2],0x79
+   5bf:  jne <+0x60c>
+   5c1:  cmp BYTE PTR [rsi+0x3],0x73
+   5c5:  jne <+0x60c>
+   5c7:  mov ecx,0x6
+   5cc:  mov eax,0x4
+   5d1:  jmp <+0xb6b>
+   5d6:  cmp rdx,0x3
+   5da:  jb <+0x7eb>
+   5e0:  movzx eax,BYTE PTR [rsi]
+   5e3:  movzx ecx,al
+   5e6:  lea eax,[rcx-0x64]
+   5e9:  cmp eax,0x15
+   5ec:  ja <+0x99a>
+   5f2:  lea rcx,[rip]
+   5f9:  movsxd rax,DWORD PTR [rcx+rax*4]
+   5fd:  add rax,rcx
+   600:  jmp rax
+   602:  cmp BYTE PTR [rsi+0x1],0x61
+   606:  jne <+0xa6d>
+   60c:  cmp BYTE PTR [rsi+0x2],0x79
+   610:  jne <+0xa6d>
+   616:  mov ecx,0x6
+   61b:  mov eax,0x3
+   620:  jmp <+0xb6b>
+   625:  movzx eax,BYTE PTR [rsi+0x1]
+   629:  cmp al,0x61
+   62b:  je <+0x658>
+   62d:  movzx ecx,al
+   630:  cmp ecx,0x73
+   633:  jne <+0x8fc>
+   639:  cmp BYTE PTR [rsi+0x2],0x65
+   63d:  jne <+0x90c>
+   643:  cmp BYTE PTR [rsi+0x3],0x63
+   647:  jne <+0x90c>
+   64d:  cmp BYTE PTR [rsi+0x4],0x73
+   651:  je <+0x676>
+   653:  jmp <+0x90c>
+   658:  cmp BYTE PTR [rsi+0x2],0x6e
+   65c:  jne <+0x921>
+   662:  cmp BYTE PTR [rsi+0x3],0x6f
+   666:  jne <+0x921>
+   66c:  cmp BYTE PTR [rsi+0x4],0x73
+   670:  jne <+0x921>
+   676:  xor ecx,ecx
+   678:  mov eax,0x5
+   67d:  jmp <+0xb6b>
+   682:  movzx eax,BYTE PTR [rsi+0x1]
+   686:  cmp al,0x65
+   688:  jne <+0x9ff>
+   68e:  cmp BYTE PTR [rsi+0x2],0x61
+   692:  jne <+0x87b>
+   698:  cmp BYTE PTR [rsi+0x3],0x72
+   69c:  jne <+0x87b>
+   6a2:  cmp BYTE PTR [rsi+0x4],0x73
+   6a6:  jne <+0x87b>
+   6ac:  mov ecx,0x9
+   6b1:  mov eax,0x5
+   6b6:  jmp <+0xb6b>
+   6bb:  cmp BYTE PTR [rsi+0x1],0x73
+   6bf:  jne <+0xb8b>
+   6c5:  cmp BYTE PTR [rsi+0x2],0x65
+   6c9:  jne <+0x810>
+   6cf:  cmp BYTE PTR [rsi+0x3],0x63
+   6d3:  jne <+0x810>
+   6d9:  cmp BYTE PTR [rsi+0x4],0x73
+   6dd:  je <+0x796>
+   6e3:  jmp <+0x810>
+   6e8:  movzx eax,BYTE PTR [rsi+0x1]
+   6ec:  cmp al,0x69
+   6ee:  je <+0x720>
+   6f0:  movzx ecx,al
+   6f3:  cmp ecx,0x6f
+   6f6:  je <+0x755>
+   6f8:  cmp ecx,0x73
+   6fb:  jne <+0x8a2>
+   701:  cmp BYTE PTR [rsi+0x2],0x65
+   705:  jne <+0x8b2>
+   70b:  cmp BYTE PTR [rsi+0x3],0x63
+   70f:  jne <+0x8b2>
+   715:  cmp BYTE PTR [rsi+0x4],0x73
+   719:  je <+0x746>
+   71b:  jmp <+0x8b2>
+   720:  movzx eax,BYTE PTR [rsi+0x2]
+   724:  cmp eax,0x63
+   727:  je <+0x782>
+   729:  cmp eax,0x6c
+   72c:  jne <+0x8d5>
+   732:  cmp BYTE PTR [rsi+0x3],0x6c
+   736:  jne <+0x8d5>
+   73c:  cmp BYTE PTR [rsi+0x4],0x69
+   740:  jne <+0x8d5>
+   746:  mov ecx,0x2
+   74b:  mov eax,0x5
+   750:  jmp <+0xb6b>
+   755:  cmp BYTE PTR [rsi+0x2],0x6e
+   759:  jne <+0x9c9>
+   75f:  cmp BYTE PTR [rsi+0x3],0x74
+   763:  jne <+0x9c9>
+   769:  cmp BYTE PTR [rsi+0x4],0x68
+   76d:  jne <+0x9c9>
+   773:  mov ecx,0x8
+   778:  mov eax,0x5
+   77d:  jmp <+0xb6b>
+   782:  cmp BYTE PTR [rsi+0x3],0x72
+   786:  jne <+0x8d5>
+   78c:  cmp BYTE PTR [rsi+0x4],0x6f
+   790:  jne <+0x8d5>
+   796:  mov ecx,0x1
+   79b:  mov eax,0x5
+   7a0:  jmp <+0xb6b>
+   7a5:  movzx eax,BYTE PTR [rsi+0x1]
+   7a9:  movzx ecx,BYTE PTR [rsi+0x2]
+   7ad:  mov r8d,eax
+   7b0:  xor r8b,0x65
+   7b4:  mov r9d,ecx
+   7b7:  xor r9b,0x65
+   7bb:  or r9b,r8b
+   7be:  jne <+0x970>
+   7c4:  mov cl,0x65
+   7c6:  cmp BYTE PTR [rsi+0x3],0x6b
+   7ca:  jne <+0x96e>
+   7d0:  cmp BYTE PTR [rsi+0x4],0x73
+   7d4:  mov al,0x65
+   7d6:  jne <+0x970>
+   7dc:  mov ecx,0x7
+   7e1:  mov eax,0x5
+   7e6:  jmp <+0xb6b>
+   7eb:  cmp rdx,0x2
+   7ef:  je <+0xa6d>
+   7f5:  test rdx,rdx
+   7f8:  je <+0xb8b>
+   7fe:  movzx eax,BYTE PTR [rsi]
+   801:  jmp <+0xaa3>
+   806:  cmp BYTE PTR [rsi+0x1],0x73
+   80a:  jne <+0xb8b>
+   810:  cmp BYTE PTR [rsi+0x2],0x65
+   814:  jne <+0xa6d>
+   81a:  cmp BYTE PTR [rsi+0x3],0x63
+   81e:  jne <+0xa6d>
+   824:  mov ecx,0x1
+   829:  mov eax,0x4
+   82e:  jmp <+0xb6b>
+   833:  movzx eax,BYTE PTR [rsi+0x1]
+   837:  cmp al,0x6f
+   839:  jne <+0xa37>
+   83f:  cmp BYTE PTR [rsi+0x2],0x75
+   843:  jne <+0xa6d>
+   849:  cmp BYTE PTR [rsi+0x3],0x72
+   84d:  jne <+0xa6d>
+   853:  mov ecx,0x5
+   858:  mov eax,0x4
+   85d:  jmp <+0xb6b>
+   862:  movzx eax,BYTE PTR [rsi+0x1]
+   866:  movzx ecx,BYTE PTR [rsi+0x2]
+   86a:  jmp <+0x970>
+   86f:  movzx eax,BYTE PTR [rsi+0x1]
+   873:  cmp al,0x65
+   875:  jne <+0x9ff>
+   87b:  cmp BYTE PTR [rsi+0x2],0x61
+   87f:  jne <+0xa6d>
+   885:  cmp BYTE PTR [rsi+0x3],0x72
+   889:  jne <+0xa6d>
+   88f:  mov ecx,0x9
+   894:  mov eax,0x4
+   899:  jmp <+0xb6b>
+   89e:  movzx eax,BYTE PTR [rsi+0x1]
+   8a2:  cmp al,0x69
+   8a4:  je <+0x8d5>
+   8a6:  movzx ecx,al
+   8a9:  cmp ecx,0x73
+   8ac:  jne <+0x9b9>
+   8b2:  cmp BYTE PTR [rsi+0x2],0x65
+   8b6:  jne <+0xa6d>
+   8bc:  cmp BYTE PTR [rsi+0x3],0x63
+   8c0:  jne <+0xa6d>
+   8c6:  mov ecx,0x2
+   8cb:  mov eax,0x4
+   8d0:  jmp <+0xb6b>
+   8d5:  cmp BYTE PTR [rsi+0x2],0x6e
+   8d9:  jne <+0x9e2>
+   8df:  cmp BYTE PTR [rsi+0x3],0x73
+   8e3:  jne <+0x9e2>
+   8e9:  mov ecx,0x4
+   8ee:  mov eax,0x4
+   8f3:  jmp <+0xb6b>
+   8f8:  movzx eax,BYTE PTR [rsi+0x1]
+   8fc:  cmp al,0x61
+   8fe:  je <+0x921>
+   900:  movzx ecx,al
+   903:  cmp ecx,0x73
+   906:  jne <+0xad5>
+   90c:  cmp BYTE PTR [rsi+0x2],0x65
+   910:  jne <+0xa6d>
+   916:  cmp BYTE PTR [rsi+0x3],0x63
+   91a:  je <+0x935>
+   91c:  jmp <+0xa6d>
+   921:  cmp BYTE PTR [rsi+0x2],0x6e
+   925:  jne <+0xa6d>
+   92b:  cmp BYTE PTR [rsi+0x3],0x6f
+   92f:  jne <+0xa6d>
+   935:  xor ecx,ecx
+   937:  mov eax,0x4
+   93c:  jmp <+0xb6b>
+   941:  cmp BYTE PTR [rsi+0x1],0x65
+   945:  jne <+0xa6d>
+   94b:  cmp BYTE PTR [rsi+0x2],0x63
+   94f:  jne <+0xa1e>
+   955:  cmp BYTE PTR [rsi+0x3],0x73
+   959:  jne <+0xa1e>
+   95f:  mov ecx,0x3
+   964:  mov eax,0x4
+   969:  jmp <+0xb6b>
+   96e:  mov al,0x65
+   970:  cmp al,0x65
+   972:  jne <+0xa54>
+   978:  cmp cl,0x65
+   97b:  jne <+0xa54>
+   981:  cmp BYTE PTR [rsi+0x3],0x6b
+   985:  jne <+0xa6d>
+   98b:  mov ecx,0x7
+   990:  mov eax,0x4
+   995:  jmp <+0xb6b>
+   99a:  cmp ecx,0xc2
+   9a0:  jne <+0xa6d>
+   9a6:  cmp BYTE PTR [rsi+0x1],0xb5
+   9aa:  je <+0x57d>
+   9b0:  jmp <+0xa6d>
+   9b5:  movzx eax,BYTE PTR [rsi+0x1]
+   9b9:  cmp al,0x69
+   9bb:  je <+0x9e2>
+   9bd:  movzx eax,al
+   9c0:  cmp eax,0x6f
+   9c3:  jne <+0xa6d>
+   9c9:  cmp BYTE PTR [rsi+0x2],0x73
+   9cd:  jne <+0xa6d>
+   9d3:  mov ecx,0x8
+   9d8:  mov eax,0x3
+   9dd:  jmp <+0xb6b>
+   9e2:  cmp BYTE PTR [rsi+0x2],0x6e
+   9e6:  jne <+0xa6d>
+   9ec:  mov ecx,0x4
+   9f1:  mov eax,0x3
+   9f6:  jmp <+0xb6b>
+   9fb:  movzx eax,BYTE PTR [rsi+0x1]
+   9ff:  cmp al,0x72
+   a01:  jne <+0xa6d>
+   a03:  cmp BYTE PTR [rsi+0x2],0x73
+   a07:  jne <+0xa6d>
+   a09:  mov ecx,0x9
+   a0e:  mov eax,0x3
+   a13:  jmp <+0xb6b>
+   a18:  cmp BYTE PTR [rsi+0x1],0x65
+   a1c:  jne <+0xa6d>
+   a1e:  cmp BYTE PTR [rsi+0x2],0x63
+   a22:  jne <+0xa6d>
+   a24:  mov ecx,0x3
+   a29:  mov eax,0x3
+   a2e:  jmp <+0xb6b>
+   a33:  movzx eax,BYTE PTR [rsi+0x1]
+   a37:  cmp al,0x72
+   a39:  jne <+0xa6d>
+   a3b:  cmp BYTE PTR [rsi+0x2],0x73
+   a3f:  jne <+0xa6d>
+   a41:  mov ecx,0x5
+   a46:  mov eax,0x3
+   a4b:  jmp <+0xb6b>
+   a50:  movzx eax,BYTE PTR [rsi+0x1]
+   a54:  cmp al,0x6b
+   a56:  jne <+0xa6d>
+   a58:  cmp BYTE PTR [rsi+0x2],0x73
+   a5c:  jne <+0xa6d>
+   a5e:  mov ecx,0x7
+   a63:  mov eax,0x3
+   a68:  jmp <+0xb6b>
+   a6d:  movzx eax,BYTE PTR [rsi]
+   a70:  mov ecx,eax
+   a72:  add ecx,0xffffff98
+   a75:  cmp ecx,0x11
+   a78:  ja <+0xaa3>
+   a7a:  lea r8,[rip]
+   a81:  movsxd rcx,DWORD PTR [r8+rcx*4]
+   a85:  add rcx,r8
+   a88:  jmp rcx
+   a8a:  cmp BYTE PTR [rsi+0x1],0x72
+   a8e:  jne <+0xb47>
+   a94:  mov ecx,0x5
+   a99:  mov eax,0x2
+   a9e:  jmp <+0xb6b>
+   aa3:  movzx ecx,al
+   aa6:  add ecx,0xffffff9c
+   aa9:  cmp ecx,0x15
+   aac:  ja <+0xb8b>
+   ab2:  mov eax,0x1
+   ab7:  lea r8,[rip]
+   abe:  movsxd rcx,DWORD PTR [r8+rcx*4]
+   ac2:  add rcx,r8
+   ac5:  jmp rcx
+   ac7:  mov ecx,0x6
+   acc:  jmp <+0xb6b>
+   ad1:  movzx eax,BYTE PTR [rsi+0x1]
+   ad5:  cmp al,0x73
+   ad7:  jne <+0xb8b>
+   add:  xor ecx,ecx
+   adf:  mov eax,0x2
+   ae4:  jmp <+0xb6b>
+   ae9:  xor eax,eax
+   aeb:  cmp BYTE PTR [rsi+0x1],0x72
+   aef:  sete al
+   af2:  inc rax
+   af5:  mov ecx,0x9
+   afa:  jmp <+0xb6b>
+   afc:  movzx r8d,BYTE PTR [rsi+0x1]
+   b01:  mov eax,0x2
+   b06:  cmp r8d,0x6f
+   b0a:  je <+0xb5f>
+   b0c:  mov ecx,0x2
+   b11:  cmp r8d,0x73
+   b15:  je <+0xb6b>
+   b17:  mov ecx,0x4
+   b1c:  mov eax,0x1
+   b21:  jmp <+0xb6b>
+   b23:  cmp BYTE PTR [rsi+0x1],0x73
+   b27:  jne <+0xb8b>
+   b29:  mov ecx,0x1
+   b2e:  mov eax,0x2
+   b33:  jmp <+0xb6b>
+   b35:  cmp BYTE PTR [rsi+0x1],0x6b
+   b39:  jne <+0xb53>
+   b3b:  mov ecx,0x7
+   b40:  mov eax,0x2
+   b45:  jmp <+0xb6b>
+   b47:  mov ecx,0x5
+   b4c:  mov eax,0x1
+   b51:  jmp <+0xb6b>
+   b53:  mov ecx,0x7
+   b58:  mov eax,0x1
+   b5d:  jmp <+0xb6b>
+   b5f:  mov ecx,0x8
+   b64:  jmp <+0xb6b>
+   b66:  mov ecx,0x3
+   b6b:  mov r8,rdx
+   b6e:  sub r8,rax
+   b71:  jb <+0xbab>
+   b73:  add rsi,rax
+   b76:  mov DWORD PTR [rdi+0x8],ecx
+   b79:  mov QWORD PTR [rdi+0x10],rsi
+   b7d:  mov QWORD PTR [rdi+0x18],r8
+   b81:  xor eax,eax
+   b83:  mov QWORD PTR [rdi],rax
+   b86:  mov rax,rdi
+   b89:  pop rbx
+   b8a:  ret
+   b8b:  lea rax,[rdi+0x8]
+   b8f:  mov rbx,rdi
+   b92:  mov rdi,rax
+   b95:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+   b9b:  mov rdi,rbx
+   b9e:  mov eax,0x1
+   ba3:  mov QWORD PTR [rdi],rax
+   ba6:  mov rax,rdi
+   ba9:  pop rbx
+   baa:  ret
+   bab:  lea rcx,[rip]
+   bb2:  mov rdi,rax
+   bb5:  mov rsi,rdx
+   bb8:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
@@ -0,0 +1,1252 @@
+duration_unit_lookup::one_big_match_prefix::lookup_ignore_ascii_case:
+     0:  push rbx
+     1:  cmp rdx,0xb
+     5:  jbe <+0xe2>
+     b:  movzx eax,BYTE PTR [rsi]
+     e:  movzx ecx,al
+    11:  or ecx,0x20
+    14:  cmp ecx,0x6d
+    17:  jne <+0x18b>
+    1d:  movzx r8d,BYTE PTR [rsi+0x1]
+    22:  or r8d,0x20
+    26:  cmp r8d,0x69
+    2a:  jne <+0xf0>
+    30:  movzx r9d,BYTE PTR [rsi+0x2]
+    35:  or r9d,0x20
+    39:  cmp r9d,0x6c
+    3d:  jne <+0xf0>
+    43:  movzx r9d,BYTE PTR [rsi+0x3]
+    48:  or r9d,0x20
+    4c:  cmp r9d,0x6c
+    50:  jne <+0xf0>
+    56:  movzx r9d,BYTE PTR [rsi+0x4]
+    5b:  or r9d,0x20
+    5f:  cmp r9d,0x69
+    63:  jne <+0xf0>
+    69:  movzx r9d,BYTE PTR [rsi+0x5]
+    6e:  or r9d,0x20
+    72:  cmp r9d,0x73
+    76:  jne <+0xf0>
+    78:  movzx r9d,BYTE PTR [rsi+0x6]
+    7d:  or r9d,0x20
+    81:  cmp r9d,0x65
+    85:  jne <+0xf0>
+    87:  movzx r9d,BYTE PTR [rsi+0x7]
+    8c:  or r9d,0x20
+    90:  cmp r9d,0x63
+    94:  jne <+0xf0>
+    96:  movzx r9d,BYTE PTR [rsi+0x8]
+    9b:  or r9d,0x20
+    9f:  cmp r9d,0x6f
+    a3:  jne <+0xf0>
+    a5:  movzx r9d,BYTE PTR [rsi+0x9]
+    aa:  or r9d,0x20
+    ae:  cmp r9d,0x6e
+    b2:  jne <+0xf0>
+    b4:  movzx r9d,BYTE PTR [rsi+0xa]
+    b9:  or r9d,0x20
+    bd:  cmp r9d,0x64
+    c1:  jne <+0xf0>
+    c3:  movzx r9d,BYTE PTR [rsi+0xb]
+    c8:  or r9d,0x20
+    cc:  cmp r9d,0x73
+    d0:  jne <+0xf0>
+    d2:  mov r8d,0x2
+    d8:  mov eax,0xc
+    dd:  jmp <+0x12a1>
+    e2:  jne <+0x3a0>
+    e8:  movzx eax,BYTE PTR [rsi]
+    eb:  jmp <+0x18b>
+    f0:  cmp ecx,0x6d
+    f3:  jne <+0x18b>
+    f9:  cmp r8d,0x69
+    fd:  jne <+0x18b>
+   103:  movzx ecx,BYTE PTR [rsi+0x2]
+   107:  or ecx,0x20
+   10a:  cmp ecx,0x63
+   10d:  jne <+0x18b>
+   10f:  movzx ecx,BYTE PTR [rsi+0x3]
+   113:  or ecx,0x20
+   116:  cmp ecx,0x72
+   119:  jne <+0x18b>
+   11b:  movzx ecx,BYTE PTR [rsi+0x4]
+   11f:  or ecx,0x20
+   122:  cmp ecx,0x6f
+   125:  jne <+0x18b>
+   127:  movzx ecx,BYTE PTR [rsi+0x5]
+   12b:  or ecx,0x20
+   12e:  cmp ecx,0x73
+   131:  jne <+0x18b>
+   133:  movzx ecx,BYTE PTR [rsi+0x6]
+   137:  or ecx,0x20
+   13a:  cmp ecx,0x65
+   13d:  jne <+0x18b>
+   13f:  movzx ecx,BYTE PTR [rsi+0x7]
+   143:  or ecx,0x20
+   146:  cmp ecx,0x63
+   149:  jne <+0x18b>
+   14b:  movzx ecx,BYTE PTR [rsi+0x8]
+   14f:  or ecx,0x20
+   152:  cmp ecx,0x6f
+   155:  jne <+0x18b>
+   157:  movzx ecx,BYTE PTR [rsi+0x9]
+   15b:  or ecx,0x20
+   15e:  cmp ecx,0x6e
+   161:  jne <+0x18b>
+   163:  movzx ecx,BYTE PTR [rsi+0xa]
+   167:  or ecx,0x20
+   16a:  cmp ecx,0x64
+   16d:  jne <+0x18b>
+   16f:  movzx ecx,BYTE PTR [rsi+0xb]
+   173:  or ecx,0x20
+   176:  cmp ecx,0x73
+   179:  jne <+0x18b>
+   17b:  mov r8d,0x1
+   181:  mov eax,0xc
+   186:  jmp <+0x12a1>
+   18b:  movzx ecx,al
+   18e:  lea r8d,[rcx-0x4d]
+   192:  cmp r8d,0x21
+   196:  ja <+0x3ad>
+   19c:  lea r9,[rip]
+   1a3:  movsxd r8,DWORD PTR [r9+r8*4]
+   1a7:  add r8,r9
+   1aa:  jmp r8
+   1ad:  movzx r8d,BYTE PTR [rsi+0x1]
+   1b2:  or r8d,0x20
+   1b6:  cmp r8d,0x61
+   1ba:  jne <+0x258>
+   1c0:  movzx r8d,BYTE PTR [rsi+0x2]
+   1c5:  or r8d,0x20
+   1c9:  cmp r8d,0x6e
+   1cd:  jne <+0x258>
+   1d3:  movzx r8d,BYTE PTR [rsi+0x3]
+   1d8:  or r8d,0x20
+   1dc:  cmp r8d,0x6f
+   1e0:  jne <+0x258>
+   1e2:  movzx r8d,BYTE PTR [rsi+0x4]
+   1e7:  or r8d,0x20
+   1eb:  cmp r8d,0x73
+   1ef:  jne <+0x258>
+   1f1:  movzx r8d,BYTE PTR [rsi+0x5]
+   1f6:  or r8d,0x20
+   1fa:  cmp r8d,0x65
+   1fe:  jne <+0x258>
+   200:  movzx r8d,BYTE PTR [rsi+0x6]
+   205:  or r8d,0x20
+   209:  cmp r8d,0x63
+   20d:  jne <+0x258>
+   20f:  movzx r8d,BYTE PTR [rsi+0x7]
+   214:  or r8d,0x20
+   218:  cmp r8d,0x6f
+   21c:  jne <+0x258>
+   21e:  movzx r8d,BYTE PTR [rsi+0x8]
+   223:  or r8d,0x20
+   227:  cmp r8d,0x6e
+   22b:  jne <+0x258>
+   22d:  movzx r8d,BYTE PTR [rsi+0x9]
+   232:  or r8d,0x20
+   236:  cmp r8d,0x64
+   23a:  jne <+0x258>
+   23c:  movzx r8d,BYTE PTR [rsi+0xa]
+   241:  or r8d,0x20
+   245:  cmp r8d,0x73
+   249:  jne <+0x258>
+   24b:  xor r8d,r8d
+   24e:  mov eax,0xb
+   253:  jmp <+0x12a1>
+   258:  cmp al,0x6d
+   25a:  jne <+0x3ad>
+   260:  movzx r8d,BYTE PTR [rsi+0x1]
+   265:  or r8d,0x20
+   269:  cmp r8d,0x69
+   26d:  jne <+0x30e>
+   273:  movzx r9d,BYTE PTR [rsi+0x2]
+   278:  or r9d,0x20
+   27c:  cmp r9d,0x6c
+   280:  jne <+0x30e>
+   286:  movzx r9d,BYTE PTR [rsi+0x3]
+   28b:  or r9d,0x20
+   28f:  cmp r9d,0x6c
+   293:  jne <+0x30e>
+   295:  movzx r9d,BYTE PTR [rsi+0x4]
+   29a:  or r9d,0x20
+   29e:  cmp r9d,0x69
+   2a2:  jne <+0x30e>
+   2a4:  movzx r9d,BYTE PTR [rsi+0x5]
+   2a9:  or r9d,0x20
+   2ad:  cmp r9d,0x73
+   2b1:  jne <+0x30e>
+   2b3:  movzx r9d,BYTE PTR [rsi+0x6]
+   2b8:  or r9d,0x20
+   2bc:  cmp r9d,0x65
+   2c0:  jne <+0x30e>
+   2c2:  movzx r9d,BYTE PTR [rsi+0x7]
+   2c7:  or r9d,0x20
+   2cb:  cmp r9d,0x63
+   2cf:  jne <+0x30e>
+   2d1:  movzx r9d,BYTE PTR [rsi+0x8]
+   2d6:  or r9d,0x20
+   2da:  cmp r9d,0x6f
+   2de:  jne <+0x30e>
+   2e0:  movzx r9d,BYTE PTR [rsi+0x9]
+   2e5:  or r9d,0x20
+   2e9:  cmp r9d,0x6e
+   2ed:  jne <+0x30e>
+   2ef:  movzx r9d,BYTE PTR [rsi+0xa]
+   2f4:  or r9d,0x20
+   2f8:  cmp r9d,0x64
+   2fc:  jne <+0x30e>
+   2fe:  mov r8d,0x2
+   304:  mov eax,0xb
+   309:  jmp <+0x12a1>
+   30e:  or ecx,0x20
+   311:  cmp ecx,0x6d
+   314:  jne <+0x3ad>
+   31a:  cmp r8d,0x69
+   31e:  jne <+0x3ad>
+   324:  movzx ecx,BYTE PTR [rsi+0x2]
+   328:  or ecx,0x20
+   32b:  cmp ecx,0x63
+   32e:  jne <+0x3ad>
+   330:  movzx ecx,BYTE PTR [rsi+0x3]
+   334:  or ecx,0x20
+   337:  cmp ecx,0x72
+   33a:  jne <+0x3ad>
+   33c:  movzx ecx,BYTE PTR [rsi+0x4]
+   340:  or ecx,0x20
+   343:  cmp ecx,0x6f
+   346:  jne <+0x3ad>
+   348:  movzx ecx,BYTE PTR [rsi+0x5]
+   34c:  or ecx,0x20
+   34f:  cmp ecx,0x73
+   352:  jne <+0x3ad>
+   354:  movzx ecx,BYTE PTR [rsi+0x6]
+   358:  or ecx,0x20
+   35b:  cmp ecx,0x65
+   35e:  jne <+0x3ad>
+   360:  movzx ecx,BYTE PTR [rsi+0x7]
+   364:  or ecx,0x20
+   367:  cmp ecx,0x63
+   36a:  jne <+0x3ad>
+   36c:  movzx ecx,BYTE PTR [rsi+0x8]
+   370:  or ecx,0x20
+   373:  cmp ecx,0x6f
+   376:  jne <+0x3ad>
+   378:  movzx ecx,BYTE PTR [rsi+0x9]
+   37c:  or ecx,0x20
+   37f:  cmp ecx,0x6e
+   382:  jne <+0x3ad>
+   384:  movzx ecx,BYTE PTR [rsi+0xa]
+   388:  or ecx,0x20
+   38b:  cmp ecx,0x64
+   38e:  jne <+0x3ad>
+   390:  mov r8d,0x1
+   396:  mov eax,0xb
+   39b:  jmp <+0x12a1>
+   3a0:  cmp rdx,0xa
+   3a4:  jb <+0x435>
+   3aa:  movzx eax,BYTE PTR [rsi]
+   3ad:  movzx ecx,al
+   3b0:  or ecx,0x20
+   3b3:  cmp ecx,0x6e
+   3b6:  jne <+0x442>
+   3bc:  movzx ecx,BYTE PTR [rsi+0x1]
+   3c0:  or ecx,0x20
+   3c3:  cmp ecx,0x61
+   3c6:  jne <+0x442>
+   3c8:  movzx ecx,BYTE PTR [rsi+0x2]
+   3cc:  or ecx,0x20
+   3cf:  cmp ecx,0x6e
+   3d2:  jne <+0x442>
+   3d4:  movzx ecx,BYTE PTR [rsi+0x3]
+   3d8:  or ecx,0x20
+   3db:  cmp ecx,0x6f
+   3de:  jne <+0x442>
+   3e0:  movzx ecx,BYTE PTR [rsi+0x4]
+   3e4:  or ecx,0x20
+   3e7:  cmp ecx,0x73
+   3ea:  jne <+0x442>
+   3ec:  movzx ecx,BYTE PTR [rsi+0x5]
+   3f0:  or ecx,0x20
+   3f3:  cmp ecx,0x65
+   3f6:  jne <+0x442>
+   3f8:  movzx ecx,BYTE PTR [rsi+0x6]
+   3fc:  or ecx,0x20
+   3ff:  cmp ecx,0x63
+   402:  jne <+0x442>
+   404:  movzx ecx,BYTE PTR [rsi+0x7]
+   408:  or ecx,0x20
+   40b:  cmp ecx,0x6f
+   40e:  jne <+0x442>
+   410:  movzx ecx,BYTE PTR [rsi+0x8]
+   414:  or ecx,0x20
+   417:  cmp ecx,0x6e
+   41a:  jne <+0x442>
+   41c:  movzx ecx,BYTE PTR [rsi+0x9]
+   420:  or ecx,0x20
+   423:  cmp ecx,0x64
+   426:  jne <+0x442>
+   428:  xor r8d,r8d
+   42b:  mov eax,0xa
+   430:  jmp <+0x12a1>
+   435:  cmp rdx,0x7
+   439:  jb <+0x515>
+   43f:  movzx eax,BYTE PTR [rsi]
+   442:  movzx ecx,al
+   445:  add ecx,0xffffffb3
+   448:  cmp ecx,0x26
+   44b:  ja <+0x522>
+   451:  lea r8,[rip]
+   458:  movsxd rcx,DWORD PTR [r8+rcx*4]
+   45c:  add rcx,r8
+   45f:  jmp rcx
+   461:  movzx ecx,BYTE PTR [rsi+0x1]
+   465:  or ecx,0x20
+   468:  cmp ecx,0x65
+   46b:  jne <+0x4b9>
+   46d:  movzx ecx,BYTE PTR [rsi+0x2]
+   471:  or ecx,0x20
+   474:  cmp ecx,0x63
+   477:  jne <+0x4b9>
+   479:  movzx ecx,BYTE PTR [rsi+0x3]
+   47d:  or ecx,0x20
+   480:  cmp ecx,0x6f
+   483:  jne <+0x4b9>
+   485:  movzx ecx,BYTE PTR [rsi+0x4]
+   489:  or ecx,0x20
+   48c:  cmp ecx,0x6e
+   48f:  jne <+0x4b9>
+   491:  movzx ecx,BYTE PTR [rsi+0x5]
+   495:  or ecx,0x20
+   498:  cmp ecx,0x64
+   49b:  jne <+0x4b9>
+   49d:  movzx ecx,BYTE PTR [rsi+0x6]
+   4a1:  or ecx,0x20
+   4a4:  cmp ecx,0x73
+   4a7:  jne <+0x4b9>
+   4a9:  mov r8d,0x3
+   4af:  mov eax,0x7
+   4b4:  jmp <+0x12a1>
+   4b9:  cmp al,0x6d
+   4bb:  jne <+0x522>
+   4bd:  movzx ecx,BYTE PTR [rsi+0x1]
+   4c1:  or ecx,0x20
+   4c4:  cmp ecx,0x69
+   4c7:  jne <+0x522>
+   4c9:  movzx ecx,BYTE PTR [rsi+0x2]
+   4cd:  or ecx,0x20
+   4d0:  cmp ecx,0x6e
+   4d3:  jne <+0x522>
+   4d5:  movzx ecx,BYTE PTR [rsi+0x3]
+   4d9:  or ecx,0x20
+   4dc:  cmp ecx,0x75
+   4df:  jne <+0x522>
+   4e1:  movzx ecx,BYTE PTR [rsi+0x4]
+   4e5:  or ecx,0x20
+   4e8:  cmp ecx,0x74
+   4eb:  jne <+0x522>
+   4ed:  movzx ecx,BYTE PTR [rsi+0x5]
+   4f1:  or ecx,0x20
+   4f4:  cmp ecx,0x65
+   4f7:  jne <+0x522>
+   4f9:  movzx ecx,BYTE PTR [rsi+0x6]
+   4fd:  or ecx,0x20
+   500:  cmp ecx,0x73
+   503:  jne <+0x522>
+   505:  mov r8d,0x4
+   50b:  mov eax,0x7
+   510:  jmp <+0x12a1>
+   515:  cmp rdx,0x6
+   519:  jne <+0x76d>
+   51f:  movzx eax,BYTE PTR [rsi]
+   522:  movzx ecx,al
+   525:  lea r8d,[rcx-0x4d]
+   529:  cmp r8d,0x26
+   52d:  ja <+0x66c>
+   533:  lea r9,[rip]
+   53a:  movsxd r8,DWORD PTR [r9+r8*4]
+   53e:  add r8,r9
+   541:  jmp r8
+   544:  movzx r8d,BYTE PTR [rsi+0x1]
+   549:  or r8d,0x20
+   54d:  cmp r8d,0x65
+   551:  jne <+0x59f>
+   553:  movzx r8d,BYTE PTR [rsi+0x2]
+   558:  or r8d,0x20
+   55c:  cmp r8d,0x63
+   560:  jne <+0x59f>
+   562:  movzx r8d,BYTE PTR [rsi+0x3]
+   567:  or r8d,0x20
+   56b:  cmp r8d,0x6f
+   56f:  jne <+0x59f>
+   571:  movzx r8d,BYTE PTR [rsi+0x4]
+   576:  or r8d,0x20
+   57a:  cmp r8d,0x6e
+   57e:  jne <+0x59f>
+   580:  movzx r8d,BYTE PTR [rsi+0x5]
+   585:  or r8d,0x20
+   589:  cmp r8d,0x64
+   58d:  jne <+0x59f>
+   58f:  mov r8d,0x3
+   595:  mov eax,0x6
+   59a:  jmp <+0x12a1>
+   59f:  cmp al,0x6d
+   5a1:  jne <+0x77a>
+   5a7:  movzx r8d,BYTE PTR [rsi+0x1]
+   5ac:  or r8d,0x20
+   5b0:  cmp r8d,0x6f
+   5b4:  jne <+0x602>
+   5b6:  movzx r9d,BYTE PTR [rsi+0x2]
+   5bb:  or r9d,0x20
+   5bf:  cmp r9d,0x6e
+   5c3:  jne <+0x602>
+   5c5:  movzx r9d,BYTE PTR [rsi+0x3]
+   5ca:  or r9d,0x20
+   5ce:  cmp r9d,0x74
+   5d2:  jne <+0x602>
+   5d4:  movzx r9d,BYTE PTR [rsi+0x4]
+   5d9:  or r9d,0x20
+   5dd:  cmp r9d,0x68
+   5e1:  jne <+0x602>
+   5e3:  movzx r9d,BYTE PTR [rsi+0x5]
+   5e8:  or r9d,0x20
+   5ec:  cmp r9d,0x73
+   5f0:  jne <+0x602>
+   5f2:  mov r8d,0x8
+   5f8:  mov eax,0x6
+   5fd:  jmp <+0x12a1>
+   602:  or ecx,0x20
+   605:  cmp ecx,0x6d
+   608:  jne <+0x77a>
+   60e:  cmp r8d,0x69
+   612:  jne <+0x6c7>
+   618:  movzx r9d,BYTE PTR [rsi+0x2]
+   61d:  or r9d,0x20
+   621:  cmp r9d,0x6e
+   625:  jne <+0x6c7>
+   62b:  movzx r9d,BYTE PTR [rsi+0x3]
+   630:  or r9d,0x20
+   634:  cmp r9d,0x75
+   638:  jne <+0x6c7>
+   63e:  movzx r9d,BYTE PTR [rsi+0x4]
+   643:  or r9d,0x20
+   647:  cmp r9d,0x74
+   64b:  jne <+0x6c7>
+   64d:  movzx r9d,BYTE PTR [rsi+0x5]
+   652:  or r9d,0x20
+   656:  cmp r9d,0x65
+   65a:  jne <+0x6c7>
+   65c:  mov r8d,0x4
+   662:  mov eax,0x6
+   667:  jmp <+0x12a1>
+   66c:  cmp ecx,0xc2
+   672:  jne <+0x77a>
+   678:  cmp BYTE PTR [rsi+0x1],0xb5
+   67c:  jne <+0xe97>
+   682:  movzx eax,BYTE PTR [rsi+0x2]
+   686:  or eax,0x20
+   689:  cmp eax,0x73
+   68c:  jne <+0x97a>
+   692:  movzx eax,BYTE PTR [rsi+0x3]
+   696:  or eax,0x20
+   699:  cmp eax,0x65
+   69c:  jne <+0x97a>
+   6a2:  movzx eax,BYTE PTR [rsi+0x4]
+   6a6:  or eax,0x20
+   6a9:  cmp eax,0x63
+   6ac:  jne <+0x97a>
+   6b2:  movzx eax,BYTE PTR [rsi+0x5]
+   6b6:  or eax,0x20
+   6b9:  cmp eax,0x73
+   6bc:  je <+0x75d>
+   6c2:  jmp <+0x97a>
+   6c7:  cmp ecx,0x6d
+   6ca:  jne <+0x77a>
+   6d0:  cmp r8d,0x69
+   6d4:  jne <+0x722>
+   6d6:  movzx r9d,BYTE PTR [rsi+0x2]
+   6db:  or r9d,0x20
+   6df:  cmp r9d,0x6c
+   6e3:  jne <+0x722>
+   6e5:  movzx r9d,BYTE PTR [rsi+0x3]
+   6ea:  or r9d,0x20
+   6ee:  cmp r9d,0x6c
+   6f2:  jne <+0x722>
+   6f4:  movzx r9d,BYTE PTR [rsi+0x4]
+   6f9:  or r9d,0x20
+   6fd:  cmp r9d,0x69
+   701:  jne <+0x722>
+   703:  movzx r9d,BYTE PTR [rsi+0x5]
+   708:  or r9d,0x20
+   70c:  cmp r9d,0x73
+   710:  jne <+0x722>
+   712:  mov r8d,0x2
+   718:  mov eax,0x6
+   71d:  jmp <+0x12a1>
+   722:  cmp ecx,0x6d
+   725:  jne <+0x77a>
+   727:  cmp r8d,0x69
+   72b:  jne <+0x77a>
+   72d:  movzx ecx,BYTE PTR [rsi+0x2]
+   731:  or ecx,0x20
+   734:  cmp ecx,0x63
+   737:  jne <+0x77a>
+   739:  movzx ecx,BYTE PTR [rsi+0x3]
+   73d:  or ecx,0x20
+   740:  cmp ecx,0x72
+   743:  jne <+0x77a>
+   745:  movzx ecx,BYTE PTR [rsi+0x4]
+   749:  or ecx,0x20
+   74c:  cmp ecx,0x6f
+   74f:  jne <+0x77a>
+   751:  movzx ecx,BYTE PTR [rsi+0x5]
+   755:  or ecx,0x20
+   758:  cmp ecx,0x73
+   75b:  jne <+0x77a>
+   75d:  mov r8d,0x1
+   763:  mov eax,0x6
+   768:  jmp <+0x12a1>
+   76d:  cmp rdx,0x5
+   771:  jb <+0xb3d>
+   777:  movzx eax,BYTE PTR [rsi]
+   77a:  movzx ecx,al
+   77d:  lea r8d,[rcx-0x48]
+   781:  cmp r8d,0x7a
+   785:  ja <+0xb4a>
+   78b:  lea r9,[rip]
+   792:  movsxd r8,DWORD PTR [r9+r8*4]
+   796:  add r8,r9
+   799:  jmp r8
+   79c:  movzx r8d,BYTE PTR [rsi+0x1]
+   7a1:  or r8d,0x20
+   7a5:  cmp r8d,0x73
+   7a9:  jne <+0x7dc>
+   7ab:  movzx r8d,BYTE PTR [rsi+0x2]
+   7b0:  or r8d,0x20
+   7b4:  cmp r8d,0x65
+   7b8:  jne <+0x7dc>
+   7ba:  movzx r8d,BYTE PTR [rsi+0x3]
+   7bf:  or r8d,0x20
+   7c3:  cmp r8d,0x63
+   7c7:  jne <+0x7dc>
+   7c9:  movzx r8d,BYTE PTR [rsi+0x4]
+   7ce:  or r8d,0x20
+   7d2:  cmp r8d,0x73
+   7d6:  je <+0x9aa>
+   7dc:  cmp al,0x68
+   7de:  je <+0xafd>
+   7e4:  cmp ecx,0x6d
+   7e7:  je <+0x9cb>
+   7ed:  cmp ecx,0x6e
+   7f0:  je <+0x8d1>
+   7f6:  jmp <+0xb4a>
+   7fb:  movzx r8d,BYTE PTR [rsi+0x1]
+   800:  or r8d,0x20
+   804:  cmp r8d,0x65
+   808:  jne <+0x847>
+   80a:  movzx r8d,BYTE PTR [rsi+0x2]
+   80f:  or r8d,0x20
+   813:  cmp r8d,0x61
+   817:  jne <+0x847>
+   819:  movzx r8d,BYTE PTR [rsi+0x3]
+   81e:  or r8d,0x20
+   822:  cmp r8d,0x72
+   826:  jne <+0x847>
+   828:  movzx r8d,BYTE PTR [rsi+0x4]
+   82d:  or r8d,0x20
+   831:  cmp r8d,0x73
+   835:  jne <+0x847>
+   837:  mov r8d,0x9
+   83d:  mov eax,0x5
+   842:  jmp <+0x12a1>
+   847:  lea r8d,[rcx-0x68]
+   84b:  cmp r8d,0xf
+   84f:  ja <+0xb4a>
+   855:  lea r9,[rip]
+   85c:  movsxd r8,DWORD PTR [r9+r8*4]
+   860:  add r8,r9
+   863:  jmp r8
+   866:  movzx r8d,BYTE PTR [rsi+0x1]
+   86b:  or r8d,0x20
+   86f:  cmp r8d,0x65
+   873:  jne <+0x8b2>
+   875:  movzx r8d,BYTE PTR [rsi+0x2]
+   87a:  or r8d,0x20
+   87e:  cmp r8d,0x65
+   882:  jne <+0x8b2>
+   884:  movzx r8d,BYTE PTR [rsi+0x3]
+   889:  or r8d,0x20
+   88d:  cmp r8d,0x6b
+   891:  jne <+0x8b2>
+   893:  movzx r8d,BYTE PTR [rsi+0x4]
+   898:  or r8d,0x20
+   89c:  cmp r8d,0x73
+   8a0:  jne <+0x8b2>
+   8a2:  mov r8d,0x7
+   8a8:  mov eax,0x5
+   8ad:  jmp <+0x12a1>
+   8b2:  lea r8d,[rcx-0x68]
+   8b6:  cmp r8d,0xd
+   8ba:  ja <+0xb4a>
+   8c0:  lea r9,[rip]
+   8c7:  movsxd r8,DWORD PTR [r9+r8*4]
+   8cb:  add r8,r9
+   8ce:  jmp r8
+   8d1:  movzx r8d,BYTE PTR [rsi+0x1]
+   8d6:  or r8d,0x20
+   8da:  cmp r8d,0x73
+   8de:  jne <+0x90d>
+   8e0:  movzx r9d,BYTE PTR [rsi+0x2]
+   8e5:  or r9d,0x20
+   8e9:  cmp r9d,0x65
+   8ed:  jne <+0x90d>
+   8ef:  movzx r9d,BYTE PTR [rsi+0x3]
+   8f4:  or r9d,0x20
+   8f8:  cmp r9d,0x63
+   8fc:  jne <+0x90d>
+   8fe:  movzx r9d,BYTE PTR [rsi+0x4]
+   903:  or r9d,0x20
+   907:  cmp r9d,0x73
+   90b:  je <+0x963>
+   90d:  lea r9d,[rcx-0x4e]
+   911:  cmp r9d,0x20
+   915:  ja <+0xb4a>
+   91b:  lea r10,[rip]
+   922:  movsxd r9,DWORD PTR [r10+r9*4]
+   926:  add r9,r10
+   929:  jmp r9
+   92c:  cmp r8d,0x61
+   930:  jne <+0x9ba>
+   936:  movzx r8d,BYTE PTR [rsi+0x2]
+   93b:  or r8d,0x20
+   93f:  cmp r8d,0x6e
+   943:  jne <+0x9ba>
+   945:  movzx r8d,BYTE PTR [rsi+0x3]
+   94a:  or r8d,0x20
+   94e:  cmp r8d,0x6f
+   952:  jne <+0x9ba>
+   954:  movzx r8d,BYTE PTR [rsi+0x4]
+   959:  or r8d,0x20
+   95d:  cmp r8d,0x73
+   961:  jne <+0x9ba>
+   963:  xor r8d,r8d
+   966:  mov eax,0x5
+   96b:  jmp <+0x12a1>
+   970:  cmp BYTE PTR [rsi+0x1],0xb5
+   974:  jne <+0xe97>
+   97a:  movzx eax,BYTE PTR [rsi+0x2]
+   97e:  or eax,0x20
+   981:  cmp eax,0x73
+   984:  jne <+0xe97>
+   98a:  movzx eax,BYTE PTR [rsi+0x3]
+   98e:  or eax,0x20
+   991:  cmp eax,0x65
+   994:  jne <+0xe97>
+   99a:  movzx eax,BYTE PTR [rsi+0x4]
+   99e:  or eax,0x20
+   9a1:  cmp eax,0x63
+   9a4:  jne <+0xe97>
+   9aa:  mov r8d,0x1
+   9b0:  mov eax,0x5
+   9b5:  jmp <+0x12a1>
+   9ba:  cmp al,0x68
+   9bc:  je <+0xafd>
+   9c2:  cmp ecx,0x6d
+   9c5:  jne <+0xb4a>
+   9cb:  movzx r8d,BYTE PTR [rsi+0x1]
+   9d0:  or r8d,0x20
+   9d4:  cmp r8d,0x73
+   9d8:  jne <+0xa17>
+   9da:  movzx r9d,BYTE PTR [rsi+0x2]
+   9df:  or r9d,0x20
+   9e3:  cmp r9d,0x65
+   9e7:  jne <+0xa17>
+   9e9:  movzx r9d,BYTE PTR [rsi+0x3]
+   9ee:  or r9d,0x20
+   9f2:  cmp r9d,0x63
+   9f6:  jne <+0xa17>
+   9f8:  movzx r9d,BYTE PTR [rsi+0x4]
+   9fd:  or r9d,0x20
+   a01:  cmp r9d,0x73
+   a05:  jne <+0xa17>
+   a07:  mov r8d,0x2
+   a0d:  mov eax,0x5
+   a12:  jmp <+0x12a1>
+   a17:  cmp al,0x4d
+   a19:  je <+0xa2d>
+   a1b:  cmp ecx,0x68
+   a1e:  je <+0xafd>
+   a24:  cmp ecx,0x6d
+   a27:  jne <+0xb4a>
+   a2d:  cmp r8d,0x6f
+   a31:  jne <+0xa70>
+   a33:  movzx r9d,BYTE PTR [rsi+0x2]
+   a38:  or r9d,0x20
+   a3c:  cmp r9d,0x6e
+   a40:  jne <+0xa70>
+   a42:  movzx r9d,BYTE PTR [rsi+0x3]
+   a47:  or r9d,0x20
+   a4b:  cmp r9d,0x74
+   a4f:  jne <+0xa70>
+   a51:  movzx r9d,BYTE PTR [rsi+0x4]
+   a56:  or r9d,0x20
+   a5a:  cmp r9d,0x68
+   a5e:  jne <+0xa70>
+   a60:  mov r8d,0x8
+   a66:  mov eax,0x5
+   a6b:  jmp <+0x12a1>
+   a70:  cmp al,0x4d
+   a72:  je <+0xa86>
+   a74:  cmp ecx,0x68
+   a77:  je <+0xafd>
+   a7d:  cmp ecx,0x6d
+   a80:  jne <+0xb4a>
+   a86:  cmp r8d,0x69
+   a8a:  jne <+0xabd>
+   a8c:  movzx r9d,BYTE PTR [rsi+0x2]
+   a91:  or r9d,0x20
+   a95:  cmp r9d,0x6c
+   a99:  jne <+0xabd>
+   a9b:  movzx r9d,BYTE PTR [rsi+0x3]
+   aa0:  or r9d,0x20
+   aa4:  cmp r9d,0x6c
+   aa8:  jne <+0xabd>
+   aaa:  movzx r9d,BYTE PTR [rsi+0x4]
+   aaf:  or r9d,0x20
+   ab3:  cmp r9d,0x69
+   ab7:  je <+0xa07>
+   abd:  cmp al,0x4d
+   abf:  je <+0xacb>
+   ac1:  cmp ecx,0x68
+   ac4:  je <+0xafd>
+   ac6:  cmp ecx,0x6d
+   ac9:  jne <+0xb4a>
+   acb:  cmp r8d,0x69
+   acf:  jne <+0xaf9>
+   ad1:  movzx ecx,BYTE PTR [rsi+0x2]
+   ad5:  or ecx,0x20
+   ad8:  cmp ecx,0x63
+   adb:  jne <+0xaf9>
+   add:  movzx ecx,BYTE PTR [rsi+0x3]
+   ae1:  or ecx,0x20
+   ae4:  cmp ecx,0x72
+   ae7:  jne <+0xaf9>
+   ae9:  movzx ecx,BYTE PTR [rsi+0x4]
+   aed:  or ecx,0x20
+   af0:  cmp ecx,0x6f
+   af3:  je <+0x9aa>
+   af9:  cmp al,0x68
+   afb:  jne <+0xb4a>
+   afd:  movzx ecx,BYTE PTR [rsi+0x1]
+   b01:  or ecx,0x20
+   b04:  cmp ecx,0x6f
+   b07:  jne <+0xb4a>
+   b09:  movzx ecx,BYTE PTR [rsi+0x2]
+   b0d:  or ecx,0x20
+   b10:  cmp ecx,0x75
+   b13:  jne <+0xb4a>
+   b15:  movzx ecx,BYTE PTR [rsi+0x3]
+   b19:  or ecx,0x20
+   b1c:  cmp ecx,0x72
+   b1f:  jne <+0xb4a>
+   b21:  movzx ecx,BYTE PTR [rsi+0x4]
+   b25:  or ecx,0x20
+   b28:  cmp ecx,0x73
+   b2b:  jne <+0xb4a>
+   b2d:  mov r8d,0x5
+   b33:  mov eax,0x5
+   b38:  jmp <+0x12a1>
+   b3d:  cmp rdx,0x4
+   b41:  jne <+0xe8d>
+   b47:  movzx eax,BYTE PTR [rsi]
+   b4a:  movzx ecx,al
+   b4d:  lea r8d,[rcx-0x44]
+   b51:  cmp r8d,0x35
+   b55:  ja <+0xe97>
+   b5b:  lea r9,[rip]
+   b62:  movsxd r8,DWORD PTR [r9+r8*4]
+   b66:  add r8,r9
+   b69:  jmp r8
+   b6c:  movzx ecx,BYTE PTR [rsi+0x1]
+   b70:  or ecx,0x20
+   b73:  cmp ecx,0x6f
+   b76:  jne <+0xba0>
+   b78:  movzx ecx,BYTE PTR [rsi+0x2]
+   b7c:  or ecx,0x20
+   b7f:  cmp ecx,0x75
+   b82:  jne <+0xba0>
+   b84:  movzx ecx,BYTE PTR [rsi+0x3]
+   b88:  or ecx,0x20
+   b8b:  cmp ecx,0x72
+   b8e:  jne <+0xba0>
+   b90:  mov r8d,0x5
+   b96:  mov eax,0x4
+   b9b:  jmp <+0x12a1>
+   ba0:  cmp al,0x64
+   ba2:  je <+0xe59>
+   ba8:  jmp <+0xe97>
+   bad:  movzx r8d,BYTE PTR [rsi+0x1]
+   bb2:  or r8d,0x20
+   bb6:  cmp r8d,0x65
+   bba:  jne <+0xbea>
+   bbc:  movzx r8d,BYTE PTR [rsi+0x2]
+   bc1:  or r8d,0x20
+   bc5:  cmp r8d,0x61
+   bc9:  jne <+0xbea>
+   bcb:  movzx r8d,BYTE PTR [rsi+0x3]
+   bd0:  or r8d,0x20
+   bd4:  cmp r8d,0x72
+   bd8:  jne <+0xbea>
+   bda:  mov r8d,0x9
+   be0:  mov eax,0x4
+   be5:  jmp <+0x12a1>
+   bea:  lea r8d,[rcx-0x64]
+   bee:  cmp r8d,0x13
+   bf2:  ja <+0xe97>
+   bf8:  lea r9,[rip]
+   bff:  movsxd r8,DWORD PTR [r9+r8*4]
+   c03:  add r8,r9
+   c06:  jmp r8
+   c09:  movzx r8d,BYTE PTR [rsi+0x1]
+   c0e:  or r8d,0x20
+   c12:  cmp r8d,0x65
+   c16:  jne <+0xc46>
+   c18:  movzx r8d,BYTE PTR [rsi+0x2]
+   c1d:  or r8d,0x20
+   c21:  cmp r8d,0x65
+   c25:  jne <+0xc46>
+   c27:  movzx r8d,BYTE PTR [rsi+0x3]
+   c2c:  or r8d,0x20
+   c30:  cmp r8d,0x6b
+   c34:  jne <+0xc46>
+   c36:  mov r8d,0x7
+   c3c:  mov eax,0x4
+   c41:  jmp <+0x12a1>
+   c46:  lea r8d,[rcx-0x64]
+   c4a:  cmp r8d,0x11
+   c4e:  ja <+0xe97>
+   c54:  lea r9,[rip]
+   c5b:  movsxd r8,DWORD PTR [r9+r8*4]
+   c5f:  add r8,r9
+   c62:  jmp r8
+   c65:  movzx r8d,BYTE PTR [rsi+0x1]
+   c6a:  or r8d,0x20
+   c6e:  cmp r8d,0x73
+   c72:  jne <+0xca2>
+   c74:  movzx r8d,BYTE PTR [rsi+0x2]
+   c79:  or r8d,0x20
+   c7d:  cmp r8d,0x65
+   c81:  jne <+0xca2>
+   c83:  movzx r8d,BYTE PTR [rsi+0x3]
+   c88:  or r8d,0x20
+   c8c:  cmp r8d,0x63
+   c90:  jne <+0xca2>
+   c92:  mov r8d,0x1
+   c98:  mov eax,0x4
+   c9d:  jmp <+0x12a1>
+   ca2:  lea r8d,[rcx-0x64]
+   ca6:  cmp r8d,0xf
+   caa:  ja <+0xe97>
+   cb0:  lea r9,[rip]
+   cb7:  movsxd r8,DWORD PTR [r9+r8*4]
+   cbb:  add r8,r9
+   cbe:  jmp r8
+   cc1:  movzx r8d,BYTE PTR [rsi+0x1]
+   cc6:  or r8d,0x20
+   cca:  cmp r8d,0x65
+   cce:  jne <+0xcfe>
+   cd0:  movzx r8d,BYTE PTR [rsi+0x2]
+   cd5:  or r8d,0x20
+   cd9:  cmp r8d,0x63
+   cdd:  jne <+0xcfe>
+   cdf:  movzx r8d,BYTE PTR [rsi+0x3]
+   ce4:  or r8d,0x20
+   ce8:  cmp r8d,0x73
+   cec:  jne <+0xcfe>
+   cee:  mov r8d,0x3
+   cf4:  mov eax,0x4
+   cf9:  jmp <+0x12a1>
+   cfe:  lea r8d,[rcx-0x64]
+   d02:  cmp r8d,0xa
+   d06:  ja <+0xe97>
+   d0c:  lea r9,[rip]
+   d13:  movsxd r8,DWORD PTR [r9+r8*4]
+   d17:  add r8,r9
+   d1a:  jmp r8
+   d1d:  movzx r8d,BYTE PTR [rsi+0x1]
+   d22:  or r8d,0x20
+   d26:  cmp r8d,0x73
+   d2a:  jne <+0xd57>
+   d2c:  movzx r9d,BYTE PTR [rsi+0x2]
+   d31:  or r9d,0x20
+   d35:  cmp r9d,0x65
+   d39:  jne <+0xd57>
+   d3b:  movzx r9d,BYTE PTR [rsi+0x3]
+   d40:  or r9d,0x20
+   d44:  cmp r9d,0x63
+   d48:  jne <+0xd57>
+   d4a:  xor r8d,r8d
+   d4d:  mov eax,0x4
+   d52:  jmp <+0x12a1>
+   d57:  lea r9d,[rcx-0x4e]
+   d5b:  cmp r9d,0x20
+   d5f:  ja <+0xe97>
+   d65:  lea r10,[rip]
+   d6c:  movsxd r9,DWORD PTR [r10+r9*4]
+   d70:  add r9,r10
+   d73:  jmp r9
+   d76:  cmp r8d,0x61
+   d7a:  jne <+0xd9a>
+   d7c:  movzx r8d,BYTE PTR [rsi+0x2]
+   d81:  or r8d,0x20
+   d85:  cmp r8d,0x6e
+   d89:  jne <+0xd9a>
+   d8b:  movzx r8d,BYTE PTR [rsi+0x3]
+   d90:  or r8d,0x20
+   d94:  cmp r8d,0x6f
+   d98:  je <+0xd4a>
+   d9a:  cmp al,0x64
+   d9c:  je <+0xe59>
+   da2:  cmp ecx,0x68
+   da5:  je <+0xb6c>
+   dab:  cmp ecx,0x6d
+   dae:  jne <+0xe97>
+   db4:  movzx r8d,BYTE PTR [rsi+0x1]
+   db9:  or r8d,0x20
+   dbd:  cmp r8d,0x73
+   dc1:  jne <+0xdf1>
+   dc3:  movzx r9d,BYTE PTR [rsi+0x2]
+   dc8:  or r9d,0x20
+   dcc:  cmp r9d,0x65
+   dd0:  jne <+0xdf1>
+   dd2:  movzx r9d,BYTE PTR [rsi+0x3]
+   dd7:  or r9d,0x20
+   ddb:  cmp r9d,0x63
+   ddf:  jne <+0xdf1>
+   de1:  mov r8d,0x2
+   de7:  mov eax,0x4
+   dec:  jmp <+0x12a1>
+   df1:  add ecx,0xffffffb3
+   df4:  cmp ecx,0x20
+   df7:  ja <+0xe97>
+   dfd:  lea r9,[rip]
+   e04:  movsxd rcx,DWORD PTR [r9+rcx*4]
+   e08:  add rcx,r9
+   e0b:  jmp rcx
+   e0d:  cmp r8d,0x69
+   e11:  jne <+0xe3b>
+   e13:  movzx ecx,BYTE PTR [rsi+0x2]
+   e17:  or ecx,0x20
+   e1a:  cmp ecx,0x6e
+   e1d:  jne <+0xe3b>
+   e1f:  movzx ecx,BYTE PTR [rsi+0x3]
+   e23:  or ecx,0x20
+   e26:  cmp ecx,0x73
+   e29:  jne <+0xe3b>
+   e2b:  mov r8d,0x4
+   e31:  mov eax,0x4
+   e36:  jmp <+0x12a1>
+   e3b:  lea ecx,[rax-0x44]
+   e3e:  rol cl,0x6
+   e41:  cmp cl,0x9
+   e44:  ja <+0xe97>
+   e46:  movzx ecx,cl
+   e49:  lea r8,[rip]
+   e50:  movsxd rcx,DWORD PTR [r8+rcx*4]
+   e54:  add rcx,r8
+   e57:  jmp rcx
+   e59:  movzx eax,BYTE PTR [rsi+0x1]
+   e5d:  or eax,0x20
+   e60:  cmp eax,0x61
+   e63:  jne <+0xe97>
+   e65:  movzx eax,BYTE PTR [rsi+0x2]
+   e69:  or eax,0x20
+   e6c:  cmp eax,0x79
+   e6f:  jne <+0xe97>
+   e71:  movzx eax,BYTE PTR [rsi+0x3]
+   e75:  or eax,0x20
+   e78:  cmp eax,0x73
+   e7b:  jne <+0xe97>
+   e7d:  mov r8d,0x6
+   e83:  mov eax,0x4
+   e88:  jmp <+0x12a1>
+   e8d:  cmp rdx,0x2
+   e91:  jbe <+0x10ed>
+   e97:  movzx ecx,BYTE PTR [rsi]
+   e9a:  movzx eax,cl
+   e9d:  lea r8d,[rax-0x4d]
+   ea1:  cmp r8d,0x2c
+   ea5:  ja <+0xf20>
+   ea7:  lea r9,[rip]
+   eae:  movsxd r8,DWORD PTR [r9+r8*4]
+   eb2:  add r8,r9
+   eb5:  jmp r8
+   eb8:  movzx r8d,BYTE PTR [rsi+0x1]
+   ebd:  or r8d,0x20
+   ec1:  cmp r8d,0x6f
+   ec5:  jne <+0xf51>
+   ecb:  movzx r8d,BYTE PTR [rsi+0x2]
+   ed0:  or r8d,0x20
+   ed4:  cmp r8d,0x73
+   ed8:  jne <+0xf51>
+   eda:  mov r8d,0x8
+   ee0:  mov eax,0x3
+   ee5:  jmp <+0x12a1>
+   eea:  movzx r8d,BYTE PTR [rsi+0x1]
+   eef:  or r8d,0x20
+   ef3:  cmp r8d,0x72
+   ef7:  jne <+0xfc9>
+   efd:  movzx r8d,BYTE PTR [rsi+0x2]
+   f02:  or r8d,0x20
+   f06:  cmp r8d,0x73
+   f0a:  jne <+0xfc9>
+   f10:  mov r8d,0x9
+   f16:  mov eax,0x3
+   f1b:  jmp <+0x12a1>
+   f20:  cmp eax,0xc2
+   f25:  jne <+0xf51>
+   f27:  cmp BYTE PTR [rsi+0x1],0xb5
+   f2b:  jne <+0x12c2>
+   f31:  movzx eax,BYTE PTR [rsi+0x2]
+   f35:  or eax,0x20
+   f38:  cmp eax,0x73
+   f3b:  jne <+0x12c2>
+   f41:  mov r8d,0x1
+   f47:  mov eax,0x3
+   f4c:  jmp <+0x12a1>
+   f51:  add eax,0xffffffbc
+   f54:  cmp eax,0x29
+   f57:  ja <+0x1103>
+   f5d:  movabs r8,0x100000001
+   f67:  bt r8,rax
+   f6b:  jb <+0x10c5>
+   f71:  movabs r8,0x1000000010
+   f7b:  bt r8,rax
+   f7f:  jb <+0x1098>
+   f85:  movabs r8,0x20000000200
+   f8f:  bt r8,rax
+   f93:  jae <+0x1103>
+   f99:  movzx eax,BYTE PTR [rsi+0x1]
+   f9d:  or eax,0x20
+   fa0:  cmp eax,0x69
+   fa3:  jne <+0x107b>
+   fa9:  movzx eax,BYTE PTR [rsi+0x2]
+   fad:  or eax,0x20
+   fb0:  cmp eax,0x6e
+   fb3:  jne <+0x107b>
+   fb9:  mov r8d,0x4
+   fbf:  mov eax,0x3
+   fc4:  jmp <+0x12a1>
+   fc9:  lea r8d,[rax-0x64]
+   fcd:  cmp r8d,0x13
+   fd1:  ja <+0x1103>
+   fd7:  lea r9,[rip]
+   fde:  movsxd r8,DWORD PTR [r9+r8*4]
+   fe2:  add r8,r9
+   fe5:  jmp r8
+   fe8:  movzx r8d,BYTE PTR [rsi+0x1]
+   fed:  or r8d,0x20
+   ff1:  cmp r8d,0x6b
+   ff5:  jne <+0x1016>
+   ff7:  movzx r8d,BYTE PTR [rsi+0x2]
+   ffc:  or r8d,0x20
+  1000:  cmp r8d,0x73
+  1004:  jne <+0x1016>
+  1006:  mov r8d,0x7
+  100c:  mov eax,0x3
+  1011:  jmp <+0x12a1>
+  1016:  lea r8d,[rax-0x64]
+  101a:  cmp r8d,0xf
+  101e:  ja <+0x1103>
+  1024:  lea r9,[rip]
+  102b:  movsxd r8,DWORD PTR [r9+r8*4]
+  102f:  add r8,r9
+  1032:  jmp r8
+  1035:  movzx r8d,BYTE PTR [rsi+0x1]
+  103a:  or r8d,0x20
+  103e:  cmp r8d,0x65
+  1042:  jne <+0x1063>
+  1044:  movzx r8d,BYTE PTR [rsi+0x2]
+  1049:  or r8d,0x20
+  104d:  cmp r8d,0x63
+  1051:  jne <+0x1063>
+  1053:  mov r8d,0x3
+  1059:  mov eax,0x3
+  105e:  jmp <+0x12a1>
+  1063:  cmp eax,0x64
+  1066:  je <+0x10c5>
+  1068:  cmp eax,0x68
+  106b:  je <+0x1098>
+  106d:  cmp eax,0x6d
+  1070:  je <+0xeb8>
+  1076:  jmp <+0x1103>
+  107b:  lea eax,[rcx-0x44]
+  107e:  rol al,0x6
+  1081:  cmp al,0x9
+  1083:  ja <+0x1103>
+  1085:  movzx eax,al
+  1088:  lea r8,[rip]
+  108f:  movsxd rax,DWORD PTR [r8+rax*4]
+  1093:  add rax,r8
+  1096:  jmp rax
+  1098:  movzx eax,BYTE PTR [rsi+0x1]
+  109c:  or eax,0x20
+  109f:  cmp eax,0x72
+  10a2:  jne <+0x10c0>
+  10a4:  movzx eax,BYTE PTR [rsi+0x2]
+  10a8:  or eax,0x20
+  10ab:  cmp eax,0x73
+  10ae:  jne <+0x10c0>
+  10b0:  mov r8d,0x5
+  10b6:  mov eax,0x3
+  10bb:  jmp <+0x12a1>
+  10c0:  cmp cl,0x64
+  10c3:  jne <+0x1103>
+  10c5:  movzx eax,BYTE PTR [rsi+0x1]
+  10c9:  or eax,0x20
+  10cc:  cmp eax,0x61
+  10cf:  jne <+0x1103>
+  10d1:  movzx eax,BYTE PTR [rsi+0x2]
+  10d5:  or eax,0x20
+  10d8:  cmp eax,0x79
+  10db:  jne <+0x1103>
+  10dd:  mov r8d,0x6
+  10e3:  mov eax,0x3
+  10e8:  jmp <+0x12a1>
+  10ed:  je <+0x1100>
+  10ef:  test rdx,rdx
+  10f2:  je <+0x12c2>
+  10f8:  movzx ecx,BYTE PTR [rsi]
+  10fb:  jmp <+0x1253>
+  1100:  movzx ecx,BYTE PTR [rsi]
+  1103:  movzx r9d,cl
+  1107:  lea eax,[r9-0x48]
+  110b:  cmp eax,0x31
+  110e:  ja <+0x1253>
+  1114:  lea r8,[rip]
+  111b:  movsxd rax,DWORD PTR [r8+rax*4]
+  111f:  add rax,r8
+  1122:  jmp rax
+  1124:  movzx eax,BYTE PTR [rsi+0x1]
+  1128:  or eax,0x20
+  112b:  cmp eax,0x72
+  112e:  jne <+0x1253>
+  1134:  mov r8d,0x5
+  113a:  mov eax,0x2
+  113f:  jmp <+0x12a1>
+  1144:  movzx eax,BYTE PTR [rsi+0x1]
+  1148:  or eax,0x20
+  114b:  cmp eax,0x72
+  114e:  jne <+0x1160>
+  1150:  mov r8d,0x9
+  1156:  mov eax,0x2
+  115b:  jmp <+0x12a1>
+  1160:  lea eax,[r9-0x68]
+  1164:  cmp eax,0xf
+  1167:  ja <+0x1253>
+  116d:  lea r8,[rip]
+  1174:  movsxd rax,DWORD PTR [r8+rax*4]
+  1178:  add rax,r8
+  117b:  jmp rax
+  117d:  movzx eax,BYTE PTR [rsi+0x1]
+  1181:  or eax,0x20
+  1184:  cmp eax,0x6b
+  1187:  jne <+0x1199>
+  1189:  mov r8d,0x7
+  118f:  mov eax,0x2
+  1194:  jmp <+0x12a1>
+  1199:  lea eax,[r9-0x68]
+  119d:  cmp eax,0xd
+  11a0:  ja <+0x1253>
+  11a6:  lea r8,[rip]
+  11ad:  movsxd rax,DWORD PTR [r8+rax*4]
+  11b1:  add rax,r8
+  11b4:  jmp rax
+  11b6:  movzx eax,BYTE PTR [rsi+0x1]
+  11ba:  or eax,0x20
+  11bd:  cmp eax,0x73
+  11c0:  jne <+0x11d2>
+  11c2:  mov r8d,0x1
+  11c8:  mov eax,0x2
+  11cd:  jmp <+0x12a1>
+  11d2:  cmp cl,0x68
+  11d5:  je <+0x1124>
+  11db:  cmp r9d,0x6d
+  11df:  je <+0x120f>
+  11e1:  cmp r9d,0x6e
+  11e5:  jne <+0x1253>
+  11e7:  movzx eax,BYTE PTR [rsi+0x1]
+  11eb:  or eax,0x20
+  11ee:  cmp eax,0x73
+  11f1:  jne <+0x1200>
+  11f3:  xor r8d,r8d
+  11f6:  mov eax,0x2
+  11fb:  jmp <+0x12a1>
+  1200:  cmp cl,0x68
+  1203:  je <+0x1124>
+  1209:  cmp r9d,0x6d
+  120d:  jne <+0x1253>
+  120f:  movzx r10d,BYTE PTR [rsi+0x1]
+  1214:  mov r8d,0x2
+  121a:  mov eax,0x2
+  121f:  or r10d,0x20
+  1223:  cmp r10d,0x73
+  1227:  je <+0x12a1>
+  1229:  cmp cl,0x4d
+  122c:  je <+0x123e>
+  122e:  cmp r9d,0x68
+  1232:  je <+0x1124>
+  1238:  cmp r9d,0x6d
+  123c:  jne <+0x1253>
+  123e:  mov r8d,0x8
+  1244:  cmp r10d,0x6f
+  1248:  je <+0x12a1>
+  124a:  cmp cl,0x68
+  124d:  je <+0x1124>
+  1253:  movzx ecx,cl
+  1256:  add ecx,0xffffffbc
+  1259:  cmp ecx,0x35
+  125c:  ja <+0x12c2>
+  125e:  mov eax,0x1
+  1263:  lea r8,[rip]
+  126a:  movsxd rcx,DWORD PTR [r8+rcx*4]
+  126e:  add rcx,r8
+  1271:  jmp rcx
+  1273:  mov r8d,0x6
+  1279:  jmp <+0x12a1>
+  127b:  mov r8d,0x7
+  1281:  jmp <+0x12a1>
+  1283:  mov r8d,0x4
+  1289:  jmp <+0x12a1>
+  128b:  mov r8d,0x9
+  1291:  jmp <+0x12a1>
+  1293:  mov r8d,0x3
+  1299:  jmp <+0x12a1>
+  129b:  mov r8d,0x5
+  12a1:  mov rcx,rdx
+  12a4:  sub rcx,rax
+  12a7:  jb <+0x12e2>
+  12a9:  add rsi,rax
+  12ac:  mov DWORD PTR [rdi+0x8],r8d
+  12b0:  mov QWORD PTR [rdi+0x10],rsi
+  12b4:  mov QWORD PTR [rdi+0x18],rcx
+  12b8:  xor eax,eax
+  12ba:  mov QWORD PTR [rdi],rax
+  12bd:  mov rax,rdi
+  12c0:  pop rbx
+  12c1:  ret
+  12c2:  lea rax,[rdi+0x8]
+  12c6:  mov rbx,rdi
+  12c9:  mov rdi,rax
+  12cc:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+  12d2:  mov rdi,rbx
+  12d5:  mov eax,0x1
+  12da:  mov QWORD PTR [rdi],rax
+  12dd:  mov rax,rdi
+  12e0:  pop rbx
+  12e1:  ret
+  12e2:  lea rcx,[rip]
+  12e9:  mov rdi,rax
+  12ec:  mov rsi,rdx
+  12ef:  call QWORD PTR [rip] <core::slice::index::slice_index_fail>
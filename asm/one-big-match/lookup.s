@@ -0,0 +1,579 @@
+duration_unit_lookup::one_big_match::lookup:
+     0:  mov rax,rdi
+     3:  test rdx,rdx
+     6:  je <+0x992>
+     c:  xor ecx,ecx
+     e:  jmp <+0x18>
+    10:  inc rcx
+    13:  cmp rdx,rcx
+    16:  je <+0x38>
+    18:  movzx edi,BYTE PTR [rsi+rcx*1]
+    1c:  lea r8d,[rdi-0x7b]
+    20:  cmp r8b,0xe5
+    24:  ja <+0x10>
+    26:  cmp edi,0xc2
+    2c:  je <+0x10>
+    2e:  cmp edi,0xb5
+    34:  je <+0x10>
+    36:  jmp <+0x3b>
+    38:  mov rcx,rdx
+    3b:  lea rdi,[rcx-0x1]
+    3f:  cmp rdi,0xb
+    43:  ja <+0x992>
+    49:  lea r8,[rip]
+    50:  movsxd rdi,DWORD PTR [r8+rdi*4]
+    54:  add rdi,r8
+    57:  jmp rdi
+    59:  movzx edi,BYTE PTR [rsi]
+    5c:  add edi,0xffffff9c
+    5f:  cmp edi,0x15
+    62:  ja <+0x992>
+    68:  lea r8,[rip]
+    6f:  movsxd rdi,DWORD PTR [r8+rdi*4]
+    73:  add rdi,r8
+    76:  jmp rdi
+    78:  mov edi,0x6
+    7d:  jmp <+0x96a>
+    82:  movzx r8d,BYTE PTR [rsi]
+    86:  lea edi,[r8-0x68]
+    8a:  cmp edi,0x11
+    8d:  ja <+0x66b>
+    93:  lea r8,[rip]
+    9a:  movsxd rdi,DWORD PTR [r8+rdi*4]
+    9e:  add rdi,r8
+    a1:  jmp rdi
+    a3:  cmp BYTE PTR [rsi+0x1],0x6f
+    a7:  jne <+0x992>
+    ad:  cmp BYTE PTR [rsi+0x2],0x75
+    b1:  jne <+0x992>
+    b7:  cmp BYTE PTR [rsi+0x3],0x72
+    bb:  jne <+0x992>
+    c1:  mov edi,0x5
+    c6:  jmp <+0x814>
+    cb:  cmp BYTE PTR [rsi],0x6e
+    ce:  jne <+0x992>
+    d4:  cmp BYTE PTR [rsi+0x1],0x61
+    d8:  jne <+0x992>
+    de:  cmp BYTE PTR [rsi+0x2],0x6e
+    e2:  jne <+0x992>
+    e8:  cmp BYTE PTR [rsi+0x3],0x6f
+    ec:  jne <+0x992>
+    f2:  cmp BYTE PTR [rsi+0x4],0x73
+    f6:  jne <+0x992>
+    fc:  cmp BYTE PTR [rsi+0x5],0x65
+   100:  jne <+0x992>
+   106:  cmp BYTE PTR [rsi+0x6],0x63
+   10a:  jne <+0x992>
+   110:  cmp BYTE PTR [rsi+0x7],0x6f
+   114:  jne <+0x992>
+   11a:  cmp BYTE PTR [rsi+0x8],0x6e
+   11e:  jne <+0x992>
+   124:  cmp BYTE PTR [rsi+0x9],0x64
+   128:  je <+0x968>
+   12e:  jmp <+0x992>
+   133:  movzx edi,BYTE PTR [rsi]
+   136:  add edi,0xffffff9c
+   139:  cmp edi,0x15
+   13c:  ja <+0x992>
+   142:  lea r8,[rip]
+   149:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   14d:  add rdi,r8
+   150:  jmp rdi
+   152:  cmp BYTE PTR [rsi+0x1],0x61
+   156:  jne <+0x992>
+   15c:  cmp BYTE PTR [rsi+0x2],0x79
+   160:  jne <+0x992>
+   166:  mov edi,0x6
+   16b:  jmp <+0x98c>
+   170:  movzx edi,BYTE PTR [rsi]
+   173:  cmp edi,0x73
+   176:  je <+0x39a>
+   17c:  cmp edi,0x6d
+   17f:  jne <+0x992>
+   185:  cmp BYTE PTR [rsi+0x1],0x69
+   189:  jne <+0x992>
+   18f:  cmp BYTE PTR [rsi+0x2],0x6e
+   193:  jne <+0x992>
+   199:  cmp BYTE PTR [rsi+0x3],0x75
+   19d:  jne <+0x992>
+   1a3:  cmp BYTE PTR [rsi+0x4],0x74
+   1a7:  jne <+0x992>
+   1ad:  cmp BYTE PTR [rsi+0x5],0x65
+   1b1:  jne <+0x992>
+   1b7:  mov edi,0x4
+   1bc:  jmp <+0x3d1>
+   1c1:  movzx r8d,BYTE PTR [rsi]
+   1c5:  lea edi,[r8-0x64]
+   1c9:  cmp edi,0x15
+   1cc:  ja <+0x6aa>
+   1d2:  lea r8,[rip]
+   1d9:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   1dd:  add rdi,r8
+   1e0:  jmp rdi
+   1e2:  cmp BYTE PTR [rsi+0x1],0x61
+   1e6:  jne <+0x992>
+   1ec:  mov edi,0x6
+   1f1:  cmp BYTE PTR [rsi+0x2],0x79
+   1f5:  je <+0x96a>
+   1fb:  jmp <+0x992>
+   200:  movzx edi,BYTE PTR [rsi]
+   203:  add edi,0xffffff98
+   206:  cmp edi,0x11
+   209:  ja <+0x992>
+   20f:  lea r8,[rip]
+   216:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   21a:  add rdi,r8
+   21d:  jmp rdi
+   21f:  mov edi,0x5
+   224:  jmp <+0x615>
+   229:  movzx edi,BYTE PTR [rsi]
+   22c:  cmp edi,0xc2
+   232:  je <+0x4ad>
+   238:  cmp edi,0x73
+   23b:  je <+0x4e9>
+   241:  cmp edi,0x6d
+   244:  jne <+0x992>
+   24a:  movzx edi,BYTE PTR [rsi+0x1]
+   24e:  cmp edi,0x69
+   251:  je <+0x624>
+   257:  cmp edi,0x6f
+   25a:  jne <+0x992>
+   260:  cmp BYTE PTR [rsi+0x2],0x6e
+   264:  jne <+0x992>
+   26a:  cmp BYTE PTR [rsi+0x3],0x74
+   26e:  jne <+0x992>
+   274:  cmp BYTE PTR [rsi+0x4],0x68
+   278:  jne <+0x992>
+   27e:  mov edi,0x8
+   283:  cmp BYTE PTR [rsi+0x5],0x73
+   287:  je <+0x96a>
+   28d:  jmp <+0x992>
+   292:  movzx edi,BYTE PTR [rsi]
+   295:  cmp edi,0x6e
+   298:  je <+0x3e0>
+   29e:  cmp edi,0x6d
+   2a1:  jne <+0x992>
+   2a7:  cmp BYTE PTR [rsi+0x1],0x69
+   2ab:  jne <+0x992>
+   2b1:  movzx edi,BYTE PTR [rsi+0x2]
+   2b5:  cmp edi,0x63
+   2b8:  je <+0x525>
+   2be:  cmp edi,0x6c
+   2c1:  jne <+0x992>
+   2c7:  cmp BYTE PTR [rsi+0x3],0x6c
+   2cb:  jne <+0x992>
+   2d1:  cmp BYTE PTR [rsi+0x4],0x69
+   2d5:  jne <+0x992>
+   2db:  cmp BYTE PTR [rsi+0x5],0x73
+   2df:  jne <+0x992>
+   2e5:  cmp BYTE PTR [rsi+0x6],0x65
+   2e9:  jne <+0x992>
+   2ef:  cmp BYTE PTR [rsi+0x7],0x63
+   2f3:  jne <+0x992>
+   2f9:  cmp BYTE PTR [rsi+0x8],0x6f
+   2fd:  jne <+0x992>
+   303:  cmp BYTE PTR [rsi+0x9],0x6e
+   307:  jne <+0x992>
+   30d:  mov edi,0x2
+   312:  jmp <+0x570>
+   317:  cmp BYTE PTR [rsi],0x6d
+   31a:  jne <+0x992>
+   320:  cmp BYTE PTR [rsi+0x1],0x69
+   324:  jne <+0x992>
+   32a:  movzx edi,BYTE PTR [rsi+0x2]
+   32e:  cmp edi,0x63
+   331:  je <+0x449>
+   337:  cmp edi,0x6c
+   33a:  jne <+0x992>
+   340:  cmp BYTE PTR [rsi+0x3],0x6c
+   344:  jne <+0x992>
+   34a:  cmp BYTE PTR [rsi+0x4],0x69
+   34e:  jne <+0x992>
+   354:  cmp BYTE PTR [rsi+0x5],0x73
+   358:  jne <+0x992>
+   35e:  cmp BYTE PTR [rsi+0x6],0x65
+   362:  jne <+0x992>
+   368:  cmp BYTE PTR [rsi+0x7],0x63
+   36c:  jne <+0x992>
+   372:  cmp BYTE PTR [rsi+0x8],0x6f
+   376:  jne <+0x992>
+   37c:  cmp BYTE PTR [rsi+0x9],0x6e
+   380:  jne <+0x992>
+   386:  cmp BYTE PTR [rsi+0xa],0x64
+   38a:  jne <+0x992>
+   390:  mov edi,0x2
+   395:  jmp <+0x49e>
+   39a:  cmp BYTE PTR [rsi+0x1],0x65
+   39e:  jne <+0x992>
+   3a4:  cmp BYTE PTR [rsi+0x2],0x63
+   3a8:  jne <+0x992>
+   3ae:  cmp BYTE PTR [rsi+0x3],0x6f
+   3b2:  jne <+0x992>
+   3b8:  cmp BYTE PTR [rsi+0x4],0x6e
+   3bc:  jne <+0x992>
+   3c2:  cmp BYTE PTR [rsi+0x5],0x64
+   3c6:  jne <+0x992>
+   3cc:  mov edi,0x3
+   3d1:  cmp BYTE PTR [rsi+0x6],0x73
+   3d5:  je <+0x96a>
+   3db:  jmp <+0x992>
+   3e0:  cmp BYTE PTR [rsi+0x1],0x61
+   3e4:  jne <+0x992>
+   3ea:  cmp BYTE PTR [rsi+0x2],0x6e
+   3ee:  jne <+0x992>
+   3f4:  cmp BYTE PTR [rsi+0x3],0x6f
+   3f8:  jne <+0x992>
+   3fe:  cmp BYTE PTR [rsi+0x4],0x73
+   402:  jne <+0x992>
+   408:  cmp BYTE PTR [rsi+0x5],0x65
+   40c:  jne <+0x992>
+   412:  cmp BYTE PTR [rsi+0x6],0x63
+   416:  jne <+0x992>
+   41c:  cmp BYTE PTR [rsi+0x7],0x6f
+   420:  jne <+0x992>
+   426:  cmp BYTE PTR [rsi+0x8],0x6e
+   42a:  jne <+0x992>
+   430:  cmp BYTE PTR [rsi+0x9],0x64
+   434:  jne <+0x992>
+   43a:  cmp BYTE PTR [rsi+0xa],0x73
+   43e:  je <+0x968>
+   444:  jmp <+0x992>
+   449:  cmp BYTE PTR [rsi+0x3],0x72
+   44d:  jne <+0x992>
+   453:  cmp BYTE PTR [rsi+0x4],0x6f
+   457:  jne <+0x992>
+   45d:  cmp BYTE PTR [rsi+0x5],0x73
+   461:  jne <+0x992>
+   467:  cmp BYTE PTR [rsi+0x6],0x65
+   46b:  jne <+0x992>
+   471:  cmp BYTE PTR [rsi+0x7],0x63
+   475:  jne <+0x992>
+   47b:  cmp BYTE PTR [rsi+0x8],0x6f
+   47f:  jne <+0x992>
+   485:  cmp BYTE PTR [rsi+0x9],0x6e
+   489:  jne <+0x992>
+   48f:  cmp BYTE PTR [rsi+0xa],0x64
+   493:  jne <+0x992>
+   499:  mov edi,0x1
+   49e:  cmp BYTE PTR [rsi+0xb],0x73
+   4a2:  je <+0x96a>
+   4a8:  jmp <+0x992>
+   4ad:  cmp BYTE PTR [rsi+0x1],0xb5
+   4b1:  jne <+0x992>
+   4b7:  cmp BYTE PTR [rsi+0x2],0x73
+   4bb:  jne <+0x992>
+   4c1:  cmp BYTE PTR [rsi+0x3],0x65
+   4c5:  jne <+0x992>
+   4cb:  cmp BYTE PTR [rsi+0x4],0x63
+   4cf:  jne <+0x992>
+   4d5:  mov edi,0x1
+   4da:  cmp BYTE PTR [rsi+0x5],0x73
+   4de:  je <+0x96a>
+   4e4:  jmp <+0x992>
+   4e9:  cmp BYTE PTR [rsi+0x1],0x65
+   4ed:  jne <+0x992>
+   4f3:  cmp BYTE PTR [rsi+0x2],0x63
+   4f7:  jne <+0x992>
+   4fd:  cmp BYTE PTR [rsi+0x3],0x6f
+   501:  jne <+0x992>
+   507:  cmp BYTE PTR [rsi+0x4],0x6e
+   50b:  jne <+0x992>
+   511:  mov edi,0x3
+   516:  cmp BYTE PTR [rsi+0x5],0x64
+   51a:  je <+0x96a>
+   520:  jmp <+0x992>
+   525:  cmp BYTE PTR [rsi+0x3],0x72
+   529:  jne <+0x992>
+   52f:  cmp BYTE PTR [rsi+0x4],0x6f
+   533:  jne <+0x992>
+   539:  cmp BYTE PTR [rsi+0x5],0x73
+   53d:  jne <+0x992>
+   543:  cmp BYTE PTR [rsi+0x6],0x65
+   547:  jne <+0x992>
+   54d:  cmp BYTE PTR [rsi+0x7],0x63
+   551:  jne <+0x992>
+   557:  cmp BYTE PTR [rsi+0x8],0x6f
+   55b:  jne <+0x992>
+   561:  cmp BYTE PTR [rsi+0x9],0x6e
+   565:  jne <+0x992>
+   56b:  mov edi,0x1
+   570:  cmp BYTE PTR [rsi+0xa],0x64
+   574:  je <+0x96a>
+   57a:  jmp <+0x992>
+   57f:  mov edi,0x4
+   584:  jmp <+0x96a>
+   589:  mov edi,0x5
+   58e:  jmp <+0x96a>
+   593:  cmp BYTE PTR [rsi+0x1],0x73
+   597:  je <+0x968>
+   59d:  jmp <+0x992>
+   5a2:  movzx r8d,BYTE PTR [rsi+0x1]
+   5a7:  mov edi,0x8
+   5ac:  cmp r8d,0x6f
+   5b0:  je <+0x96a>
+   5b6:  cmp r8d,0x73
+   5ba:  jne <+0x992>
+   5c0:  mov edi,0x2
+   5c5:  jmp <+0x96a>
+   5ca:  mov edi,0x7
+   5cf:  jmp <+0x96a>
+   5d4:  mov edi,0x3
+   5d9:  jmp <+0x96a>
+   5de:  mov edi,0x7
+   5e3:  cmp BYTE PTR [rsi+0x1],0x6b
+   5e7:  je <+0x96a>
+   5ed:  jmp <+0x992>
+   5f2:  mov edi,0x1
+   5f7:  cmp BYTE PTR [rsi+0x1],0x73
+   5fb:  je <+0x96a>
+   601:  jmp <+0x992>
+   606:  mov edi,0x9
+   60b:  jmp <+0x96a>
+   610:  mov edi,0x9
+   615:  cmp BYTE PTR [rsi+0x1],0x72
+   619:  je <+0x96a>
+   61f:  jmp <+0x992>
+   624:  movzx edi,BYTE PTR [rsi+0x2]
+   628:  cmp edi,0x63
+   62b:  je <+0x9ad>
+   631:  cmp edi,0x6c
+   634:  je <+0x9bf>
+   63a:  cmp edi,0x6e
+   63d:  jne <+0x992>
+   643:  cmp BYTE PTR [rsi+0x3],0x75
+   647:  jne <+0x992>
+   64d:  cmp BYTE PTR [rsi+0x4],0x74
+   651:  jne <+0x992>
+   657:  mov edi,0x4
+   65c:  cmp BYTE PTR [rsi+0x5],0x65
+   660:  je <+0x96a>
+   666:  jmp <+0x992>
+   66b:  cmp r8d,0xc2
+   672:  jne <+0x992>
+   678:  cmp BYTE PTR [rsi+0x1],0xb5
+   67c:  jne <+0x992>
+   682:  cmp BYTE PTR [rsi+0x2],0x73
+   686:  jne <+0x992>
+   68c:  cmp BYTE PTR [rsi+0x3],0x65
+   690:  jne <+0x992>
+   696:  mov edi,0x1
+   69b:  cmp BYTE PTR [rsi+0x4],0x63
+   69f:  je <+0x96a>
+   6a5:  jmp <+0x992>
+   6aa:  cmp r8d,0xc2
+   6b1:  jne <+0x992>
+   6b7:  cmp BYTE PTR [rsi+0x1],0xb5
+   6bb:  jne <+0x992>
+   6c1:  mov edi,0x1
+   6c6:  jmp <+0x7e2>
+   6cb:  cmp BYTE PTR [rsi+0x1],0x65
+   6cf:  jne <+0x992>
+   6d5:  cmp BYTE PTR [rsi+0x2],0x61
+   6d9:  jne <+0x992>
+   6df:  cmp BYTE PTR [rsi+0x3],0x72
+   6e3:  jne <+0x992>
+   6e9:  mov edi,0x9
+   6ee:  jmp <+0x814>
+   6f3:  cmp BYTE PTR [rsi+0x1],0x72
+   6f7:  jne <+0x992>
+   6fd:  mov edi,0x9
+   702:  jmp <+0x7e2>
+   707:  movzx edi,BYTE PTR [rsi+0x1]
+   70b:  cmp edi,0x69
+   70e:  je <+0x9d8>
+   714:  cmp edi,0x6f
+   717:  je <+0x9fd>
+   71d:  cmp edi,0x73
+   720:  jne <+0x992>
+   726:  cmp BYTE PTR [rsi+0x2],0x65
+   72a:  jne <+0x992>
+   730:  cmp BYTE PTR [rsi+0x3],0x63
+   734:  jne <+0x992>
+   73a:  mov edi,0x2
+   73f:  jmp <+0x814>
+   744:  cmp BYTE PTR [rsi+0x1],0x65
+   748:  jne <+0x992>
+   74e:  cmp BYTE PTR [rsi+0x2],0x65
+   752:  jne <+0x992>
+   758:  cmp BYTE PTR [rsi+0x3],0x6b
+   75c:  jne <+0x992>
+   762:  mov edi,0x7
+   767:  jmp <+0x814>
+   76c:  cmp BYTE PTR [rsi+0x1],0x72
+   770:  jne <+0x992>
+   776:  mov edi,0x5
+   77b:  jmp <+0x7e2>
+   77d:  cmp BYTE PTR [rsi+0x1],0x6b
+   781:  jne <+0x992>
+   787:  mov edi,0x7
+   78c:  jmp <+0x7e2>
+   78e:  movzx edi,BYTE PTR [rsi+0x1]
+   792:  cmp edi,0x61
+   795:  je <+0x93b>
+   79b:  cmp edi,0x73
+   79e:  jne <+0x992>
+   7a4:  cmp BYTE PTR [rsi+0x2],0x65
+   7a8:  jne <+0x992>
+   7ae:  cmp BYTE PTR [rsi+0x3],0x63
+   7b2:  jne <+0x992>
+   7b8:  cmp BYTE PTR [rsi+0x4],0x73
+   7bc:  je <+0x968>
+   7c2:  jmp <+0x992>
+   7c7:  movzx edi,BYTE PTR [rsi+0x1]
+   7cb:  cmp edi,0x69
+   7ce:  je <+0x94f>
+   7d4:  cmp edi,0x6f
+   7d7:  jne <+0x992>
+   7dd:  mov edi,0x8
+   7e2:  cmp BYTE PTR [rsi+0x2],0x73
+   7e6:  je <+0x96a>
+   7ec:  jmp <+0x992>
+   7f1:  cmp BYTE PTR [rsi+0x1],0x73
+   7f5:  jne <+0x992>
+   7fb:  cmp BYTE PTR [rsi+0x2],0x65
+   7ff:  jne <+0x992>
+   805:  cmp BYTE PTR [rsi+0x3],0x63
+   809:  jne <+0x992>
+   80f:  mov edi,0x1
+   814:  cmp BYTE PTR [rsi+0x4],0x73
+   818:  je <+0x96a>
+   81e:  jmp <+0x992>
+   823:  cmp BYTE PTR [rsi+0x1],0x65
+   827:  jne <+0x992>
+   82d:  mov edi,0x3
+   832:  cmp BYTE PTR [rsi+0x2],0x63
+   836:  je <+0x96a>
+   83c:  jmp <+0x992>
+   841:  cmp BYTE PTR [rsi+0x1],0x65
+   845:  jne <+0x992>
+   84b:  cmp BYTE PTR [rsi+0x2],0x63
+   84f:  jne <+0x992>
+   855:  mov edi,0x3
+   85a:  jmp <+0x98c>
+   85f:  cmp BYTE PTR [rsi+0x1],0x65
+   863:  jne <+0x992>
+   869:  cmp BYTE PTR [rsi+0x2],0x61
+   86d:  jne <+0x992>
+   873:  mov edi,0x9
+   878:  cmp BYTE PTR [rsi+0x3],0x72
+   87c:  je <+0x96a>
+   882:  jmp <+0x992>
+   887:  movzx edi,BYTE PTR [rsi+0x1]
+   88b:  cmp edi,0x61
+   88e:  je <+0x95c>
+   894:  cmp edi,0x73
+   897:  jne <+0x992>
+   89d:  cmp BYTE PTR [rsi+0x2],0x65
+   8a1:  jne <+0x992>
+   8a7:  cmp BYTE PTR [rsi+0x3],0x63
+   8ab:  je <+0x968>
+   8b1:  jmp <+0x992>
+   8b6:  cmp BYTE PTR [rsi+0x1],0x65
+   8ba:  jne <+0x992>
+   8c0:  cmp BYTE PTR [rsi+0x2],0x65
+   8c4:  jne <+0x992>
+   8ca:  mov edi,0x7
+   8cf:  cmp BYTE PTR [rsi+0x3],0x6b
+   8d3:  je <+0x96a>
+   8d9:  jmp <+0x992>
+   8de:  movzx edi,BYTE PTR [rsi+0x1]
+   8e2:  cmp edi,0x69
+   8e5:  je <+0x981>
+   8eb:  cmp edi,0x73
+   8ee:  jne <+0x992>
+   8f4:  cmp BYTE PTR [rsi+0x2],0x65
+   8f8:  jne <+0x992>
+   8fe:  mov edi,0x2
+   903:  jmp <+0x933>
+   905:  cmp BYTE PTR [rsi+0x1],0x6f
+   909:  jne <+0x992>
+   90f:  cmp BYTE PTR [rsi+0x2],0x75
+   913:  jne <+0x992>
+   915:  mov edi,0x5
+   91a:  cmp BYTE PTR [rsi+0x3],0x72
+   91e:  je <+0x96a>
+   920:  jmp <+0x992>
+   922:  cmp BYTE PTR [rsi+0x1],0x73
+   926:  jne <+0x992>
+   928:  cmp BYTE PTR [rsi+0x2],0x65
+   92c:  jne <+0x992>
+   92e:  mov edi,0x1
+   933:  cmp BYTE PTR [rsi+0x3],0x63
+   937:  je <+0x96a>
+   939:  jmp <+0x992>
+   93b:  cmp BYTE PTR [rsi+0x2],0x6e
+   93f:  jne <+0x992>
+   941:  cmp BYTE PTR [rsi+0x3],0x6f
+   945:  jne <+0x992>
+   947:  cmp BYTE PTR [rsi+0x4],0x73
+   94b:  je <+0x968>
+   94d:  jmp <+0x992>
+   94f:  mov edi,0x4
+   954:  cmp BYTE PTR [rsi+0x2],0x6e
+   958:  je <+0x96a>
+   95a:  jmp <+0x992>
+   95c:  cmp BYTE PTR [rsi+0x2],0x6e
+   960:  jne <+0x992>
+   962:  cmp BYTE PTR [rsi+0x3],0x6f
+   966:  jne <+0x992>
+   968:  xor edi,edi
+   96a:  sub rdx,rcx
+   96d:  add rsi,rcx
+   970:  mov DWORD PTR [rax+0x8],edi
+   973:  mov QWORD PTR [rax+0x10],rsi
+   977:  mov QWORD PTR [rax+0x18],rdx
+   97b:  xor ecx,ecx
+   97d:  mov QWORD PTR [rax],rcx
+   980:  ret
+   981:  cmp BYTE PTR [rsi+0x2],0x6e
+   985:  jne <+0x992>
+   987:  mov edi,0x4
+   98c:  cmp BYTE PTR [rsi+0x3],0x73
+   990:  je <+0x96a>
+   992:  push rbx
+   993:  lea rdi,[rax+0x8]
+   997:  mov rbx,rax
+   99a:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+   9a0:  mov rax,rbx
+   9a3:  mov ecx,0x1
+   9a8:  pop rbx
+   9a9:  mov QWORD PTR [rax],rcx
+   9ac:  ret
+   9ad:  cmp BYTE PTR [rsi+0x3],0x72
+   9b1:  jne <+0x992>
+   9b3:  cmp BYTE PTR [rsi+0x4],0x6f
+   9b7:  je <+0x4d5>
+   9bd:  jmp <+0x992>
+   9bf:  cmp BYTE PTR [rsi+0x3],0x6c
+   9c3:  jne <+0x992>
+   9c5:  cmp BYTE PTR [rsi+0x4],0x69
+   9c9:  jne <+0x992>
+   9cb:  mov edi,0x2
+   9d0:  cmp BYTE PTR [rsi+0x5],0x73
+   9d4:  je <+0x96a>
+   9d6:  jmp <+0x992>
+   9d8:  movzx edi,BYTE PTR [rsi+0x2]
+   9dc:  cmp edi,0x63
+   9df:  je <+0xa1d>
+   9e1:  cmp edi,0x6c
+   9e4:  jne <+0x992>
+   9e6:  cmp BYTE PTR [rsi+0x3],0x6c
+   9ea:  jne <+0x992>
+   9ec:  mov edi,0x2
+   9f1:  cmp BYTE PTR [rsi+0x4],0x69
+   9f5:  je <+0x96a>
+   9fb:  jmp <+0x992>
+   9fd:  cmp BYTE PTR [rsi+0x2],0x6e
+   a01:  jne <+0x992>
+   a03:  cmp BYTE PTR [rsi+0x3],0x74
+   a07:  jne <+0x992>
+   a09:  mov edi,0x8
+   a0e:  cmp BYTE PTR [rsi+0x4],0x68
+   a12:  je <+0x96a>
+   a18:  jmp <+0x992>
+   a1d:  cmp BYTE PTR [rsi+0x3],0x72
+   a21:  jne <+0x992>
+   a27:  mov edi,0x1
+   a2c:  cmp BYTE PTR [rsi+0x4],0x6f
+   a30:  je <+0x96a>
+   a36:  jmp <+0x992>
@@ -0,0 +1,501 @@
+duration_unit_lookup::one_big_match::lookup_ignore_ascii_case:
+     0:  push rbx
+     1:  sub rsp,0x10
+     5:  mov rax,rdi
+     8:  test rdx,rdx
+     b:  je <+0x87d>
+    11:  xor ecx,ecx
+    13:  jmp <+0x28>
+    15:  data16 cs nop WORD PTR [rax+rax*1+0x0]
+    20:  inc rcx
+    23:  cmp rdx,rcx
+    26:  je <+0x77>
+    28:  movzx r8d,BYTE PTR [rsi+rcx*1]
+    2d:  lea edi,[r8-0x41]
+    31:  cmp dil,0x1a
+    35:  setb dil
+    39:  shl dil,0x5
+    3d:  or dil,r8b
+    40:  lea r8d,[rdi-0x7b]
+    44:  cmp r8b,0xe5
+    48:  ja <+0x20>
+    4a:  cmp dil,0xc2
+    4e:  je <+0x20>
+    50:  movzx edi,dil
+    54:  cmp edi,0xb5
+    5a:  je <+0x20>
+    5c:  mov DWORD PTR [rsp+0x8],0x0
+    64:  mov QWORD PTR [rsp],0x0
+    6c:  cmp rcx,0xc
+    70:  jbe <+0x94>
+    72:  jmp <+0x87d>
+    77:  mov rcx,rdx
+    7a:  mov DWORD PTR [rsp+0x8],0x0
+    82:  mov QWORD PTR [rsp],0x0
+    8a:  cmp rcx,0xc
+    8e:  ja <+0x87d>
+    94:  test rcx,rcx
+    97:  je <+0x87d>
+    9d:  cmp rcx,0x1
+    a1:  jne <+0xa8>
+    a3:  xor r8d,r8d
+    a6:  jmp <+0xf8>
+    a8:  mov edi,ecx
+    aa:  and edi,0xe
+    ad:  xor r8d,r8d
+    b0:  movzx r9d,BYTE PTR [rsi+r8*1]
+    b5:  lea r10d,[r9-0x41]
+    b9:  cmp r10b,0x1a
+    bd:  setb r10b
+    c1:  shl r10b,0x5
+    c5:  or r10b,r9b
+    c8:  mov BYTE PTR [rsp+r8*1],r10b
+    cc:  movzx r9d,BYTE PTR [rsi+r8*1+0x1]
+    d2:  lea r10d,[r9-0x41]
+    d6:  cmp r10b,0x1a
+    da:  setb r10b
+    de:  shl r10b,0x5
+    e2:  or r10b,r9b
+    e5:  mov BYTE PTR [rsp+r8*1+0x1],r10b
+    ea:  add r8,0x2
+    ee:  cmp rdi,r8
+    f1:  jne <+0xb0>
+    f3:  test cl,0x1
+    f6:  je <+0x114>
+    f8:  movzx edi,BYTE PTR [rsi+r8*1]
+    fd:  lea r9d,[rdi-0x41]
+   101:  cmp r9b,0x1a
+   105:  setb r9b
+   109:  shl r9b,0x5
+   10d:  or r9b,dil
+   110:  mov BYTE PTR [rsp+r8*1],r9b
+   114:  lea rdi,[rcx-0x1]
+   118:  cmp rdi,0xb
+   11c:  ja <+0x87d>
+   122:  lea r8,[rip]
+   129:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   12d:  add rdi,r8
+   130:  jmp rdi
+   132:  movzx edi,BYTE PTR [rsp]
+   136:  add edi,0xffffff9c
+   139:  cmp edi,0x15
+   13c:  ja <+0x87d>
+   142:  lea r8,[rip]
+   149:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   14d:  add rdi,r8
+   150:  jmp rdi
+   152:  mov edi,0x6
+   157:  jmp <+0x84e>
+   15c:  movzx r8d,BYTE PTR [rsp]
+   161:  lea edi,[r8-0x68]
+   165:  cmp edi,0x11
+   168:  ja <+0x57f>
+   16e:  lea r8,[rip]
+   175:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   179:  add rdi,r8
+   17c:  jmp rdi
+   17e:  mov edi,0x5
+   183:  cmp DWORD PTR [rsp+0x1],0x7372756f
+   18b:  je <+0x84e>
+   191:  jmp <+0x87d>
+   196:  movabs rdi,0x6f6365736f6e616e
+   1a0:  cmp QWORD PTR [rsp],rdi
+   1a4:  jne <+0x87d>
+   1aa:  cmp BYTE PTR [rsp+0x8],0x6e
+   1af:  jne <+0x87d>
+   1b5:  cmp BYTE PTR [rsp+0x9],0x64
+   1ba:  je <+0x84c>
+   1c0:  jmp <+0x87d>
+   1c5:  movzx edi,BYTE PTR [rsp]
+   1c9:  add edi,0xffffff9c
+   1cc:  cmp edi,0x15
+   1cf:  ja <+0x87d>
+   1d5:  lea r8,[rip]
+   1dc:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   1e0:  add rdi,r8
+   1e3:  jmp rdi
+   1e5:  cmp BYTE PTR [rsp+0x1],0x61
+   1ea:  jne <+0x87d>
+   1f0:  cmp BYTE PTR [rsp+0x2],0x79
+   1f5:  jne <+0x87d>
+   1fb:  mov edi,0x6
+   200:  jmp <+0x876>
+   205:  movzx edi,BYTE PTR [rsp]
+   209:  cmp edi,0x73
+   20c:  je <+0x38f>
+   212:  cmp edi,0x6d
+   215:  jne <+0x87d>
+   21b:  cmp DWORD PTR [rsp+0x1],0x74756e69
+   223:  jne <+0x87d>
+   229:  cmp BYTE PTR [rsp+0x5],0x65
+   22e:  jne <+0x87d>
+   234:  mov edi,0x4
+   239:  jmp <+0x3ad>
+   23e:  movzx r8d,BYTE PTR [rsp]
+   243:  lea edi,[r8-0x64]
+   247:  cmp edi,0x15
+   24a:  ja <+0x5a4>
+   250:  lea r8,[rip]
+   257:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   25b:  add rdi,r8
+   25e:  jmp rdi
+   260:  cmp BYTE PTR [rsp+0x1],0x61
+   265:  jne <+0x87d>
+   26b:  mov edi,0x6
+   270:  cmp BYTE PTR [rsp+0x2],0x79
+   275:  je <+0x84e>
+   27b:  jmp <+0x87d>
+   280:  movzx edi,BYTE PTR [rsp]
+   284:  add edi,0xffffff98
+   287:  cmp edi,0x11
+   28a:  ja <+0x87d>
+   290:  lea r8,[rip]
+   297:  movsxd rdi,DWORD PTR [r8+rdi*4]
+   29b:  add rdi,r8
+   29e:  jmp rdi
+   2a0:  mov edi,0x5
+   2a5:  jmp <+0x524>
+   2aa:  movzx edi,BYTE PTR [rsp]
+   2ae:  cmp edi,0xc2
+   2b4:  je <+0x41e>
+   2ba:  cmp edi,0x73
+   2bd:  je <+0x441>
+   2c3:  cmp edi,0x6d
+   2c6:  jne <+0x87d>
+   2cc:  movzx edi,BYTE PTR [rsp+0x1]
+   2d1:  cmp edi,0x69
+   2d4:  je <+0x534>
+   2da:  cmp edi,0x6f
+   2dd:  jne <+0x87d>
+   2e3:  mov edi,0x8
+   2e8:  cmp DWORD PTR [rsp+0x2],0x7368746e
+   2f0:  je <+0x84e>
+   2f6:  jmp <+0x87d>
+   2fb:  movzx edi,BYTE PTR [rsp]
+   2ff:  cmp edi,0x6e
+   302:  je <+0x3bd>
+   308:  cmp edi,0x6d
+   30b:  jne <+0x87d>
+   311:  cmp BYTE PTR [rsp+0x1],0x69
+   316:  jne <+0x87d>
+   31c:  movzx edi,BYTE PTR [rsp+0x2]
+   321:  cmp edi,0x63
+   324:  je <+0x464>
+   32a:  cmp edi,0x6c
+   32d:  jne <+0x87d>
+   333:  mov edi,0x2
+   338:  movabs r8,0x646e6f636573696c
+   342:  jmp <+0x47a>
+   347:  cmp BYTE PTR [rsp],0x6d
+   34b:  jne <+0x87d>
+   351:  cmp BYTE PTR [rsp+0x1],0x69
+   356:  jne <+0x87d>
+   35c:  movzx edi,BYTE PTR [rsp+0x2]
+   361:  cmp edi,0x63
+   364:  je <+0x3ed>
+   36a:  cmp edi,0x6c
+   36d:  jne <+0x87d>
+   373:  movabs rdi,0x646e6f636573696c
+   37d:  cmp QWORD PTR [rsp+0x3],rdi
+   382:  jne <+0x87d>
+   388:  mov edi,0x2
+   38d:  jmp <+0x40e>
+   38f:  cmp DWORD PTR [rsp+0x1],0x6e6f6365
+   397:  jne <+0x87d>
+   39d:  cmp BYTE PTR [rsp+0x5],0x64
+   3a2:  jne <+0x87d>
+   3a8:  mov edi,0x3
+   3ad:  cmp BYTE PTR [rsp+0x6],0x73
+   3b2:  je <+0x84e>
+   3b8:  jmp <+0x87d>
+   3bd:  movabs rdi,0x6e6f6365736f6e61
+   3c7:  cmp QWORD PTR [rsp+0x1],rdi
+   3cc:  jne <+0x87d>
+   3d2:  cmp BYTE PTR [rsp+0x9],0x64
+   3d7:  jne <+0x87d>
+   3dd:  cmp BYTE PTR [rsp+0xa],0x73
+   3e2:  je <+0x84c>
+   3e8:  jmp <+0x87d>
+   3ed:  movabs rdi,0x646e6f636573696c
+   3f7:  add rdi,0x606
+   3fe:  cmp QWORD PTR [rsp+0x3],rdi
+   403:  jne <+0x87d>
+   409:  mov edi,0x1
+   40e:  cmp BYTE PTR [rsp+0xb],0x73
+   413:  je <+0x84e>
+   419:  jmp <+0x87d>
+   41e:  cmp DWORD PTR [rsp+0x1],0x636573b5
+   426:  jne <+0x87d>
+   42c:  mov edi,0x1
+   431:  cmp BYTE PTR [rsp+0x5],0x73
+   436:  je <+0x84e>
+   43c:  jmp <+0x87d>
+   441:  cmp DWORD PTR [rsp+0x1],0x6e6f6365
+   449:  jne <+0x87d>
+   44f:  mov edi,0x3
+   454:  cmp BYTE PTR [rsp+0x5],0x64
+   459:  je <+0x84e>
+   45f:  jmp <+0x87d>
+   464:  movabs r8,0x646e6f636573696c
+   46e:  add r8,0x606
+   475:  mov edi,0x1
+   47a:  cmp QWORD PTR [rsp+0x3],r8
+   47f:  je <+0x84e>
+   485:  jmp <+0x87d>
+   48a:  mov edi,0x4
+   48f:  jmp <+0x84e>
+   494:  mov edi,0x5
+   499:  jmp <+0x84e>
+   49e:  cmp BYTE PTR [rsp+0x1],0x73
+   4a3:  je <+0x84c>
+   4a9:  jmp <+0x87d>
+   4ae:  movzx r8d,BYTE PTR [rsp+0x1]
+   4b4:  mov edi,0x8
+   4b9:  cmp r8d,0x6f
+   4bd:  je <+0x84e>
+   4c3:  cmp r8d,0x73
+   4c7:  jne <+0x87d>
+   4cd:  mov edi,0x2
+   4d2:  jmp <+0x84e>
+   4d7:  mov edi,0x7
+   4dc:  jmp <+0x84e>
+   4e1:  mov edi,0x3
+   4e6:  jmp <+0x84e>
+   4eb:  mov edi,0x7
+   4f0:  cmp BYTE PTR [rsp+0x1],0x6b
+   4f5:  je <+0x84e>
+   4fb:  jmp <+0x87d>
+   500:  mov edi,0x1
+   505:  cmp BYTE PTR [rsp+0x1],0x73
+   50a:  je <+0x84e>
+   510:  jmp <+0x87d>
+   515:  mov edi,0x9
+   51a:  jmp <+0x84e>
+   51f:  mov edi,0x9
+   524:  cmp BYTE PTR [rsp+0x1],0x72
+   529:  je <+0x84e>
+   52f:  jmp <+0x87d>
+   534:  movzx edi,BYTE PTR [rsp+0x2]
+   539:  cmp edi,0x63
+   53c:  je <+0x89b>
+   542:  cmp edi,0x6c
+   545:  je <+0x8af>
+   54b:  cmp edi,0x6e
+   54e:  jne <+0x87d>
+   554:  cmp BYTE PTR [rsp+0x3],0x75
+   559:  jne <+0x87d>
+   55f:  cmp BYTE PTR [rsp+0x4],0x74
+   564:  jne <+0x87d>
+   56a:  mov edi,0x4
+   56f:  cmp BYTE PTR [rsp+0x5],0x65
+   574:  je <+0x84e>
+   57a:  jmp <+0x87d>
+   57f:  cmp r8d,0xc2
+   586:  jne <+0x87d>
+   58c:  mov edi,0x1
+   591:  cmp DWORD PTR [rsp+0x1],0x636573b5
+   599:  je <+0x84e>
+   59f:  jmp <+0x87d>
+   5a4:  cmp r8d,0xc2
+   5ab:  jne <+0x87d>
+   5b1:  cmp BYTE PTR [rsp+0x1],0xb5
+   5b6:  jne <+0x87d>
+   5bc:  mov edi,0x1
+   5c1:  jmp <+0x6c8>
+   5c6:  mov edi,0x9
+   5cb:  cmp DWORD PTR [rsp+0x1],0x73726165
+   5d3:  je <+0x84e>
+   5d9:  jmp <+0x87d>
+   5de:  cmp BYTE PTR [rsp+0x1],0x72
+   5e3:  jne <+0x87d>
+   5e9:  mov edi,0x9
+   5ee:  jmp <+0x6c8>
+   5f3:  movzx edi,BYTE PTR [rsp+0x1]
+   5f8:  cmp edi,0x69
+   5fb:  je <+0x8cb>
+   601:  cmp edi,0x6f
+   604:  je <+0x8f3>
+   60a:  cmp edi,0x73
+   60d:  jne <+0x87d>
+   613:  cmp BYTE PTR [rsp+0x2],0x65
+   618:  jne <+0x87d>
+   61e:  cmp BYTE PTR [rsp+0x3],0x63
+   623:  jne <+0x87d>
+   629:  mov edi,0x2
+   62e:  cmp BYTE PTR [rsp+0x4],0x73
+   633:  je <+0x84e>
+   639:  jmp <+0x87d>
+   63e:  mov edi,0x7
+   643:  cmp DWORD PTR [rsp+0x1],0x736b6565
+   64b:  je <+0x84e>
+   651:  jmp <+0x87d>
+   656:  cmp BYTE PTR [rsp+0x1],0x72
+   65b:  jne <+0x87d>
+   661:  mov edi,0x5
+   666:  jmp <+0x6c8>
+   668:  cmp BYTE PTR [rsp+0x1],0x6b
+   66d:  jne <+0x87d>
+   673:  mov edi,0x7
+   678:  jmp <+0x6c8>
+   67a:  movzx edi,BYTE PTR [rsp+0x1]
+   67f:  cmp edi,0x61
+   682:  je <+0x819>
+   688:  cmp edi,0x73
+   68b:  jne <+0x87d>
+   691:  cmp BYTE PTR [rsp+0x2],0x65
+   696:  jne <+0x87d>
+   69c:  cmp BYTE PTR [rsp+0x3],0x63
+   6a1:  je <+0x827>
+   6a7:  jmp <+0x87d>
+   6ac:  movzx edi,BYTE PTR [rsp+0x1]
+   6b1:  cmp edi,0x69
+   6b4:  je <+0x830>
+   6ba:  cmp edi,0x6f
+   6bd:  jne <+0x87d>
+   6c3:  mov edi,0x8
+   6c8:  cmp BYTE PTR [rsp+0x2],0x73
+   6cd:  je <+0x84e>
+   6d3:  jmp <+0x87d>
+   6d8:  mov edi,0x1
+   6dd:  cmp DWORD PTR [rsp+0x1],0x73636573
+   6e5:  je <+0x84e>
+   6eb:  jmp <+0x87d>
+   6f0:  cmp BYTE PTR [rsp+0x1],0x65
+   6f5:  jne <+0x87d>
+   6fb:  mov edi,0x3
+   700:  cmp BYTE PTR [rsp+0x2],0x63
+   705:  je <+0x84e>
+   70b:  jmp <+0x87d>
+   710:  cmp BYTE PTR [rsp+0x1],0x65
+   715:  jne <+0x87d>
+   71b:  cmp BYTE PTR [rsp+0x2],0x63
+   720:  jne <+0x87d>
+   726:  mov edi,0x3
+   72b:  jmp <+0x876>
+   730:  cmp BYTE PTR [rsp+0x1],0x65
+   735:  jne <+0x87d>
+   73b:  cmp BYTE PTR [rsp+0x2],0x61
+   740:  jne <+0x87d>
+   746:  mov edi,0x9
+   74b:  jmp <+0x7f1>
+   750:  movzx edi,BYTE PTR [rsp+0x1]
+   755:  cmp edi,0x61
+   758:  je <+0x83e>
+   75e:  cmp edi,0x73
+   761:  jne <+0x87d>
+   767:  cmp BYTE PTR [rsp+0x2],0x65
+   76c:  jne <+0x87d>
+   772:  cmp BYTE PTR [rsp+0x3],0x63
+   777:  je <+0x84c>
+   77d:  jmp <+0x87d>
+   782:  cmp BYTE PTR [rsp+0x1],0x65
+   787:  jne <+0x87d>
+   78d:  cmp BYTE PTR [rsp+0x2],0x65
+   792:  jne <+0x87d>
+   798:  mov edi,0x7
+   79d:  cmp BYTE PTR [rsp+0x3],0x6b
+   7a2:  je <+0x84e>
+   7a8:  jmp <+0x87d>
+   7ad:  movzx edi,BYTE PTR [rsp+0x1]
+   7b2:  cmp edi,0x69
+   7b5:  je <+0x86a>
+   7bb:  cmp edi,0x73
+   7be:  jne <+0x87d>
+   7c4:  cmp BYTE PTR [rsp+0x2],0x65
+   7c9:  jne <+0x87d>
+   7cf:  mov edi,0x2
+   7d4:  jmp <+0x810>
+   7d6:  cmp BYTE PTR [rsp+0x1],0x6f
+   7db:  jne <+0x87d>
+   7e1:  cmp BYTE PTR [rsp+0x2],0x75
+   7e6:  jne <+0x87d>
+   7ec:  mov edi,0x5
+   7f1:  cmp BYTE PTR [rsp+0x3],0x72
+   7f6:  je <+0x84e>
+   7f8:  jmp <+0x87d>
+   7fd:  cmp BYTE PTR [rsp+0x1],0x73
+   802:  jne <+0x87d>
+   804:  cmp BYTE PTR [rsp+0x2],0x65
+   809:  jne <+0x87d>
+   80b:  mov edi,0x1
+   810:  cmp BYTE PTR [rsp+0x3],0x63
+   815:  je <+0x84e>
+   817:  jmp <+0x87d>
+   819:  cmp BYTE PTR [rsp+0x2],0x6e
+   81e:  jne <+0x87d>
+   820:  cmp BYTE PTR [rsp+0x3],0x6f
+   825:  jne <+0x87d>
+   827:  cmp BYTE PTR [rsp+0x4],0x73
+   82c:  je <+0x84c>
+   82e:  jmp <+0x87d>
+   830:  mov edi,0x4
+   835:  cmp BYTE PTR [rsp+0x2],0x6e
+   83a:  je <+0x84e>
+   83c:  jmp <+0x87d>
+   83e:  cmp BYTE PTR [rsp+0x2],0x6e
+   843:  jne <+0x87d>
+   845:  cmp BYTE PTR [rsp+0x3],0x6f
+   84a:  jne <+0x87d>
+   84c:  xor edi,edi
+   84e:  add rsi,rcx
+   851:  sub rdx,rcx
+   854:  mov DWORD PTR [rax+0x8],edi
+   857:  mov QWORD PTR [rax+0x10],rsi
+   85b:  mov QWORD PTR [rax+0x18],rdx
+   85f:  xor ecx,ecx
+   861:  mov QWORD PTR [rax],rcx
+   864:  add rsp,0x10
+   868:  pop rbx
+   869:  ret
+   86a:  cmp BYTE PTR [rsp+0x2],0x6e
+   86f:  jne <+0x87d>
+   871:  mov edi,0x4
+   876:  cmp BYTE PTR [rsp+0x3],0x73
+   87b:  je <+0x84e>
+   87d:  lea rdi,[rax+0x8]
+   881:  mov rbx,rax
+   884:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+   88a:  mov rax,rbx
+   88d:  mov ecx,0x1
+   892:  mov QWORD PTR [rax],rcx
+   895:  add rsp,0x10
+   899:  pop rbx
+   89a:  ret
+   89b:  cmp BYTE PTR [rsp+0x3],0x72
+   8a0:  jne <+0x87d>
+   8a2:  cmp BYTE PTR [rsp+0x4],0x6f
+   8a7:  je <+0x42c>
+   8ad:  jmp <+0x87d>
+   8af:  cmp BYTE PTR [rsp+0x3],0x6c
+   8b4:  jne <+0x87d>
+   8b6:  cmp BYTE PTR [rsp+0x4],0x69
+   8bb:  jne <+0x87d>
+   8bd:  mov edi,0x2
+   8c2:  cmp BYTE PTR [rsp+0x5],0x73
+   8c7:  je <+0x84e>
+   8c9:  jmp <+0x87d>
+   8cb:  movzx edi,BYTE PTR [rsp+0x2]
+   8d0:  cmp edi,0x63
+   8d3:  je <+0x91a>
+   8d5:  cmp edi,0x6c
+   8d8:  jne <+0x87d>
+   8da:  cmp BYTE PTR [rsp+0x3],0x6c
+   8df:  jne <+0x87d>
+   8e1:  mov edi,0x2
+   8e6:  cmp BYTE PTR [rsp+0x4],0x69
+   8eb:  je <+0x84e>
+   8f1:  jmp <+0x87d>
+   8f3:  cmp BYTE PTR [rsp+0x2],0x6e
+   8f8:  jne <+0x87d>
+   8fa:  cmp BYTE PTR [rsp+0x3],0x74
+   8ff:  jne <+0x87d>
+   905:  mov edi,0x8
+   90a:  cmp BYTE PTR [rsp+0x4],0x68
+   90f:  je <+0x84e>
+   915:  jmp <+0x87d>
+   91a:  cmp BYTE PTR [rsp+0x3],0x72
+   91f:  jne <+0x87d>
+   925:  mov edi,0x1
+   92a:  cmp BYTE PTR [rsp+0x4],0x6f
+   92f:  je <+0x84e>
+   935:  jmp <+0x87d>
@@ -0,0 +1,54 @@
+duration_unit_lookup::phf::lookup:
+     0:  push r15
+     2:  push r14
+     4:  push r12
+     6:  push rbx
+     7:  push rax
+     8:  mov r14,rdx
+     b:  mov r15,rsi
+     e:  mov rbx,rdi
+    11:  xor r12d,r12d
+    14:  test rdx,rdx
+    17:  jne <+0x28>
+    19:  jmp <+0x48>
+    1b:  nop DWORD PTR [rax+rax*1+0x0]
+    20:  inc r12
+    23:  cmp r14,r12
+    26:  je <+0x45>
+    28:  movzx eax,BYTE PTR [r15+r12*1]
+    2d:  lea ecx,[rax-0x7b]
+    30:  cmp cl,0xe5
+    33:  ja <+0x20>
+    35:  cmp eax,0xc2
+    3a:  je <+0x20>
+    3c:  cmp eax,0xb5
+    41:  je <+0x20>
+    43:  jmp <+0x48>
+    45:  mov r12,r14
+    48:  mov rdi,QWORD PTR [rip]
+    4f:  mov rsi,r15
+    52:  mov rdx,r12
+    55:  call <phf::map::Map<K,V>::get_entry>
+    5a:  test rax,rax
+    5d:  je <+0x86>
+    5f:  mov eax,DWORD PTR [rdx]
+    61:  sub r14,r12
+    64:  add r15,r12
+    67:  mov DWORD PTR [rbx+0x8],eax
+    6a:  mov QWORD PTR [rbx+0x10],r15
+    6e:  mov QWORD PTR [rbx+0x18],r14
+    72:  xor eax,eax
+    74:  mov QWORD PTR [rbx],rax
+    77:  mov rax,rbx
+    7a:  add rsp,0x8
+    7e:  pop rbx
+    7f:  pop r12
+    81:  pop r14
+    83:  pop r15
+    85:  ret
+    86:  lea rdi,[rbx+0x8]
+    8a:  mov rsi,r15
+    8d:  mov rdx,r14
+    90:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new>
+    96:  mov eax,0x1
+    9b:  jmp <+0x74>
@@ -0,0 +1,108 @@
+duration_unit_lookup::phf::lookup_ignore_ascii_case:
+     0:  push r15
+     2:  push r14
+     4:  push r13
+     6:  push r12
+     8:  push rbx
+     9:  sub rsp,0x10
+     d:  mov r14,rdx
+    10:  mov r15,rsi
+    13:  mov rbx,rdi
+    16:  test rdx,rdx
+    19:  je <+0x82>
+    1b:  xor r12d,r12d
+    1e:  jmp <+0x28>
+    20:  inc r12
+    23:  cmp r14,r12
+    26:  je <+0x52>
+    28:  movzx ecx,BYTE PTR [r15+r12*1]
+    2d:  lea eax,[rcx-0x41]
+    30:  cmp al,0x1a
+    32:  setb al
+    35:  shl al,0x5
+    38:  or al,cl
+    3a:  lea ecx,[rax-0x7b]
+    3d:  cmp cl,0xe5
+    40:  ja <+0x20>
+    42:  cmp al,0xc2
+    44:  je <+0x20>
+    46:  movzx eax,al
+    49:  cmp eax,0xb5
+    4e:  je <+0x20>
+    50:  jmp <+0x55>
+    52:  mov r12,r14
+    55:  mov DWORD PTR [rsp+0x8],0x0
+    5d:  mov QWORD PTR [rsp],0x0
+    65:  cmp r12,0xc
+    69:  ja <+0x14a>
+    6f:  lea r13,[r15+r12*1]
+    73:  test r12,r12
+    76:  je <+0x9a>
+    78:  cmp r12,0x1
+    7c:  jne <+0x9f>
+    7e:  xor ecx,ecx
+    80:  jmp <+0xf7>
+    82:  mov DWORD PTR [rsp+0x8],0x0
+    8a:  mov QWORD PTR [rsp],0x0
+    92:  xor r12d,r12d
+    95:  mov r13,r15
+    98:  jmp <+0x10d>
+    9a:  xor r12d,r12d
+    9d:  jmp <+0x10d>
+    9f:  mov eax,r12d
+    a2:  and eax,0xe
+    a5:  xor ecx,ecx
+    a7:  nop WORD PTR [rax+rax*1+0x0]
+    b0:  movzx edx,BYTE PTR [r15+rcx*1]
+    b5:  lea esi,[rdx-0x41]
+    b8:  cmp sil,0x1a
+    bc:  setb sil
+    c0:  shl sil,0x5
+    c4:  or sil,dl
+    c7:  mov BYTE PTR [rsp+rcx*1],sil
+    cb:  movzx edx,BYTE PTR [r15+rcx*1+0x1]
+    d1:  lea esi,[rdx-0x41]
+    d4:  cmp sil,0x1a
+    d8:  setb sil
+    dc:  shl sil,0x5
+    e0:  or sil,dl
+    e3:  mov BYTE PTR [rsp+rcx*1+0x1],sil
+    e8:  add rcx,0x2
+    ec:  cmp rax,rcx
+    ef:  jne <+0xb0>
+    f1:  test r12b,0x1
+    f5:  je <+0x10d>
+    f7:  movzx eax,BYTE PTR [r15+rcx*1]
+    fc:  lea edx,[rax-0x41]
+    ff:  cmp dl,0x1a
+   102:  setb dl
+   105:  shl dl,0x5
+   108:  or dl,al
+   10a:  mov BYTE PTR [rsp+rcx*1],dl
+   10d:  mov rdi,QWORD PTR [rip]
+   114:  mov rsi,rsp
+   117:  mov rdx,r12
+   11a:  call <phf::map::Map<K,V>::get_entry>
+   11f:  test rax,rax
+   122:  je <+0x14a>
+   124:  mov eax,DWORD PTR [rdx]
+   126:  sub r14,r12
+   129:  mov DWORD PTR [rbx+0x8],eax
+   12c:  mov QWORD PTR [rbx+0x10],r13
+   130:  mov QWORD PTR [rbx+0x18],r14
+   134:  xor eax,eax
+   136:  mov QWORD PTR [rbx],rax
+   139:  mov rax,rbx
+   13c:  add rsp,0x10
+   140:  pop rbx
+   141:  pop r12
+   143:  pop r13
+   145:  pop r14
+   147:  pop r15
+   149:  ret
+   14a:  lea rdi,[rbx+0x8]
+   14e:  mov rsi,r15
+   151:  mov rdx,r14
+   154:  call QWORD PTR [rip] <duration_unit_lookup::error::LookupError::new_ignore_ascii_case>
+   15a:  mov eax,0x1
+   15f:  jmp <+0x136>
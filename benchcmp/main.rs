@@ -0,0 +1,623 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Write,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+Compare Criterion benchmark results saved by `cargo bench`.
+
+USAGE:
+    benchcmp [OPTIONS] <baseline>
+
+Prints a table with one row per input and one column per technique, where
+each cell is the time relative to the fastest technique for that input along
+with the time itself. Benchmarks are named `{technique}/{input}`.
+
+With --against, this instead compares <baseline> with an older baseline and
+exits with status 1 if any of the techniques chosen with --technique (or any
+technique at all, if none are chosen) got slower by more than --threshold, or
+if any of their benchmarks in the older baseline are missing from <baseline>.
+
+OPTIONS:
+    --against <baseline>    Compare with an older baseline.
+    --technique <name>      Only check this technique for regressions.
+                            May be given more than once.
+    --threshold <percent>   The slowdown allowed before a benchmark counts
+                            as a regression. [default: 5]
+    --allow-missing         Don't fail when a benchmark in the older
+                            baseline is missing from <baseline>.
+    --filter <text>         Only include benchmarks whose name contains
+                            this. May be given more than once.
+    --transpose             Treat benchmarks as `{input}/{technique}`
+                            instead, e.g., for `in-context/*`.
+    --format <format>       One of table, json or csv. [default: table]
+    --dir <path>            Criterion's output directory.
+                            [default: $CARGO_TARGET_DIR/criterion]
+    -h, --help              Print this help.
+";
+
+/// A single benchmark result read from a saved baseline.
+#[derive(Clone, Debug)]
+struct Bench {
+    technique: String,
+    input: String,
+    /// The mean time per iteration, in nanoseconds.
+    mean: f64,
+    /// The standard deviation of the time per iteration, in nanoseconds.
+    std_dev: f64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Debug)]
+struct Args {
+    baseline: String,
+    against: Option<String>,
+    techniques: Vec<String>,
+    threshold: f64,
+    allow_missing: bool,
+    filters: Vec<String>,
+    transpose: bool,
+    format: Format,
+    dir: PathBuf,
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Runs the comparison and returns false if a regression was found.
+fn run() -> anyhow::Result<bool> {
+    let args = parse_args()?;
+    let new = read_baseline(&args, &args.baseline)?;
+    let mut out = std::io::stdout().lock();
+    let Some(ref against) = args.against else {
+        print_ratios(&args, &new, &mut out)?;
+        return Ok(true);
+    };
+    let old = read_baseline(&args, against)?;
+    check_regressions(&args, &old, &new, &mut out)
+}
+
+fn parse_args() -> anyhow::Result<Args> {
+    use lexopt::{Arg, ValueExt};
+
+    let mut parser = lexopt::Parser::from_env();
+    let mut baseline = None;
+    let mut args = Args {
+        baseline: String::new(),
+        against: None,
+        techniques: vec![],
+        threshold: 5.0,
+        allow_missing: false,
+        filters: vec![],
+        transpose: false,
+        format: Format::Table,
+        dir: match std::env::var_os("CARGO_TARGET_DIR") {
+            Some(dir) => PathBuf::from(dir).join("criterion"),
+            None => PathBuf::from("target/criterion"),
+        },
+    };
+    while let Some(arg) = parser.next()? {
+        match arg {
+            Arg::Short('h') | Arg::Long("help") => {
+                print!("{USAGE}");
+                std::process::exit(0);
+            }
+            Arg::Long("against") => {
+                args.against = Some(parser.value()?.string()?);
+            }
+            Arg::Long("technique") => {
+                args.techniques.push(parser.value()?.string()?);
+            }
+            Arg::Long("threshold") => {
+                args.threshold = parser.value()?.parse()?;
+            }
+            Arg::Long("allow-missing") => args.allow_missing = true,
+            Arg::Long("filter") => {
+                args.filters.push(parser.value()?.string()?);
+            }
+            Arg::Long("transpose") => args.transpose = true,
+            Arg::Long("format") => {
+                args.format = match &*parser.value()?.string()? {
+                    "table" => Format::Table,
+                    "json" => Format::Json,
+                    "csv" => Format::Csv,
+                    unk => anyhow::bail!("unknown format: {unk}"),
+                };
+            }
+            Arg::Long("dir") => args.dir = parser.value()?.into(),
+            Arg::Value(value) if baseline.is_none() => {
+                baseline = Some(value.string()?);
+            }
+            _ => return Err(arg.unexpected().into()),
+        }
+    }
+    let Some(baseline) = baseline else {
+        anyhow::bail!("missing baseline name (see --help)");
+    };
+    args.baseline = baseline;
+    Ok(args)
+}
+
+/// Reads every benchmark saved under the baseline given.
+///
+/// Criterion saves each benchmark's results in
+/// `{dir}/{benchmark}/{baseline}/estimates.json`, along with a
+/// `benchmark.json` that has the benchmark's full name.
+fn read_baseline(args: &Args, baseline: &str) -> anyhow::Result<Vec<Bench>> {
+    let mut dirs = vec![];
+    find_baseline_dirs(&args.dir, baseline, &mut dirs)?;
+    let mut benches = vec![];
+    for dir in dirs {
+        let id = read_json(&dir.join("benchmark.json"))?["full_id"]
+            .as_str()
+            .map(|id| id.to_string())
+            .ok_or_else(|| anyhow::anyhow!("{}: no full_id", dir.display()))?;
+        if !args.filters.is_empty()
+            && !args.filters.iter().any(|f| id.contains(&**f))
+        {
+            continue;
+        }
+        let estimates = read_json(&dir.join("estimates.json"))?;
+        let estimate = |name: &str| {
+            estimates[name]["point_estimate"].as_f64().ok_or_else(|| {
+                anyhow::anyhow!("{}: no {name} estimate", dir.display())
+            })
+        };
+        let (mut technique, mut input) = match id.split_once('/') {
+            Some((technique, input)) => (technique, input),
+            None => (&*id, ""),
+        };
+        if args.transpose {
+            std::mem::swap(&mut technique, &mut input);
+        }
+        benches.push(Bench {
+            technique: technique.to_string(),
+            input: input.to_string(),
+            mean: estimate("mean")?,
+            std_dev: estimate("std_dev")?,
+        });
+    }
+    anyhow::ensure!(
+        !benches.is_empty(),
+        "no benchmarks found for baseline {baseline:?} in {}",
+        args.dir.display(),
+    );
+    Ok(benches)
+}
+
+/// Recursively finds every directory named `baseline` beneath `dir` that
+/// contains saved benchmark results.
+fn find_baseline_dirs(
+    dir: &Path,
+    baseline: &str,
+    dirs: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| anyhow::anyhow!("{}: {e}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.file_name().is_some_and(|name| name == baseline)
+            && path.join("estimates.json").exists()
+        {
+            dirs.push(path);
+        } else {
+            find_baseline_dirs(&path, baseline, dirs)?;
+        }
+    }
+    dirs.sort();
+    Ok(())
+}
+
+fn read_json(path: &Path) -> anyhow::Result<serde_json::Value> {
+    let data = std::fs::read(path)
+        .map_err(|e| anyhow::anyhow!("{}: {e}", path.display()))?;
+    serde_json::from_slice(&data)
+        .map_err(|e| anyhow::anyhow!("{}: {e}", path.display()))
+}
+
+/// Prints the time of every benchmark relative to the fastest technique for
+/// the same input.
+fn print_ratios(
+    args: &Args,
+    benches: &[Bench],
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let mut fastest: BTreeMap<InputKey, f64> = BTreeMap::new();
+    for bench in benches {
+        let min = fastest
+            .entry(InputKey::new(&bench.input))
+            .or_insert(f64::INFINITY);
+        *min = min.min(bench.mean);
+    }
+    let ratio =
+        |bench: &Bench| bench.mean / fastest[&InputKey::new(&bench.input)];
+
+    match args.format {
+        Format::Json => {
+            let rows: Vec<serde_json::Value> = benches
+                .iter()
+                .map(|bench| {
+                    serde_json::json!({
+                        "technique": bench.technique,
+                        "input": bench.input,
+                        "mean_ns": bench.mean,
+                        "std_dev_ns": bench.std_dev,
+                        "ratio": ratio(bench),
+                    })
+                })
+                .collect();
+            serde_json::to_writer_pretty(&mut *out, &rows)?;
+            writeln!(out)?;
+        }
+        Format::Csv => {
+            let mut wtr = csv::Writer::from_writer(out);
+            wtr.write_record([
+                "technique",
+                "input",
+                "mean_ns",
+                "std_dev_ns",
+                "ratio",
+            ])?;
+            for bench in benches {
+                wtr.write_record([
+                    bench.technique.clone(),
+                    bench.input.clone(),
+                    bench.mean.to_string(),
+                    bench.std_dev.to_string(),
+                    ratio(bench).to_string(),
+                ])?;
+            }
+            wtr.flush()?;
+        }
+        Format::Table => {
+            let techniques: BTreeSet<&str> =
+                benches.iter().map(|b| &*b.technique).collect();
+            let mut rows = vec![];
+            let mut header = vec!["input".to_string()];
+            header.extend(techniques.iter().map(|t| t.to_string()));
+            rows.push(header);
+            for &InputKey(_, _, input) in fastest.keys() {
+                let mut row = vec![input.to_string()];
+                for &technique in techniques.iter() {
+                    let cell = benches
+                        .iter()
+                        .find(|b| b.technique == technique && b.input == input)
+                        .map(|b| {
+                            format!(
+                                "{:.2}  {}",
+                                ratio(b),
+                                format_time(b.mean, b.std_dev),
+                            )
+                        });
+                    row.push(cell.unwrap_or_else(|| "-".to_string()));
+                }
+                rows.push(row);
+            }
+            write_table(&rows, out)?;
+        }
+    }
+    Ok(())
+}
+
+/// Sorts inputs by name, except that a number at the end of a name is
+/// compared numerically. So `scaling-50` comes before `scaling-200`.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct InputKey<'a>(&'a str, Option<u64>, &'a str);
+
+impl<'a> InputKey<'a> {
+    fn new(input: &'a str) -> InputKey<'a> {
+        let prefix = input.trim_end_matches(|c: char| c.is_ascii_digit());
+        InputKey(prefix, input[prefix.len()..].parse().ok(), input)
+    }
+}
+
+/// Compares every benchmark in `new` with the same benchmark in `old`, and
+/// returns false if any of the chosen techniques regressed or, unless
+/// `--allow-missing` is given, if any of their benchmarks in `old` are
+/// missing from `new`.
+fn check_regressions(
+    args: &Args,
+    old: &[Bench],
+    new: &[Bench],
+    out: &mut dyn Write,
+) -> anyhow::Result<bool> {
+    let checked = |bench: &Bench| {
+        args.techniques.is_empty()
+            || args.techniques.contains(&bench.technique)
+    };
+    // Each comparison is (new, old, change in percent, regressed).
+    let mut comparisons = vec![];
+    for bench in new.iter().filter(|&bench| checked(bench)) {
+        let Some(before) = old.iter().find(|b| {
+            b.technique == bench.technique && b.input == bench.input
+        }) else {
+            continue;
+        };
+        let change = change_percent(before.mean, bench.mean);
+        comparisons.push((bench, before, change, change > args.threshold));
+    }
+    let missing: Vec<&Bench> = old
+        .iter()
+        .filter(|&before| checked(before))
+        .filter(|&before| {
+            !new.iter().any(|b| {
+                b.technique == before.technique && b.input == before.input
+            })
+        })
+        .collect();
+    anyhow::ensure!(
+        !comparisons.is_empty(),
+        "no benchmarks in common between {:?} and {:?}",
+        args.baseline,
+        args.against.as_deref().unwrap_or(""),
+    );
+
+    match args.format {
+        Format::Json => {
+            let rows: Vec<serde_json::Value> = comparisons
+                .iter()
+                .map(|&(bench, before, change, regressed)| {
+                    serde_json::json!({
+                        "technique": bench.technique,
+                        "input": bench.input,
+                        "old_mean_ns": before.mean,
+                        "new_mean_ns": bench.mean,
+                        "change_percent": change,
+                        "regressed": regressed,
+                    })
+                })
+                .collect();
+            serde_json::to_writer_pretty(&mut *out, &rows)?;
+            writeln!(out)?;
+        }
+        Format::Csv => {
+            let mut wtr = csv::Writer::from_writer(&mut *out);
+            wtr.write_record([
+                "technique",
+                "input",
+                "old_mean_ns",
+                "new_mean_ns",
+                "change_percent",
+                "regressed",
+            ])?;
+            for &(bench, before, change, regressed) in comparisons.iter() {
+                wtr.write_record([
+                    bench.technique.clone(),
+                    bench.input.clone(),
+                    before.mean.to_string(),
+                    bench.mean.to_string(),
+                    change.to_string(),
+                    regressed.to_string(),
+                ])?;
+            }
+            wtr.flush()?;
+        }
+        Format::Table => {
+            let mut rows = vec![vec![
+                "benchmark".to_string(),
+                args.against.clone().unwrap_or_default(),
+                args.baseline.clone(),
+                "change".to_string(),
+                String::new(),
+            ]];
+            for &(bench, before, change, regressed) in comparisons.iter() {
+                rows.push(vec![
+                    format!("{}/{}", bench.technique, bench.input),
+                    format_time(before.mean, before.std_dev),
+                    format_time(bench.mean, bench.std_dev),
+                    format!("{change:+.2}%"),
+                    if regressed { "REGRESSED" } else { "" }.to_string(),
+                ]);
+            }
+            write_table(&rows, out)?;
+        }
+    }
+
+    for before in missing.iter() {
+        eprintln!(
+            "{}/{} is in {:?} but missing from {:?}",
+            before.technique,
+            before.input,
+            args.against.as_deref().unwrap_or(""),
+            args.baseline,
+        );
+    }
+    let regressions = comparisons.iter().filter(|c| c.3).count();
+    if regressions > 0 {
+        eprintln!(
+            "{regressions} benchmark(s) regressed by more than {}%",
+            args.threshold,
+        );
+    }
+    Ok(regressions == 0 && (missing.is_empty() || args.allow_missing))
+}
+
+/// Returns how much slower `new` is than `old`, in percent. This is negative
+/// when `new` is faster.
+fn change_percent(old: f64, new: f64) -> f64 {
+    (new / old - 1.0) * 100.0
+}
+
+/// Writes the rows given as left aligned columns, with a line under the
+/// first row.
+fn write_table(
+    rows: &[Vec<String>],
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let mut widths = vec![0; rows[0].len()];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for (i, row) in rows.iter().enumerate() {
+        let mut line = String::new();
+        for (cell, &width) in row.iter().zip(&widths) {
+            line.push_str(&format!("{cell:<width$}    "));
+        }
+        writeln!(out, "{}", line.trim_end())?;
+        if i == 0 {
+            let mut line = String::new();
+            for &width in widths.iter() {
+                line.push_str(&format!("{:-<width$}    ", ""));
+            }
+            writeln!(out, "{}", line.trim_end())?;
+        }
+    }
+    Ok(())
+}
+
+/// Formats a time in nanoseconds (and its standard deviation, when non-zero)
+/// the same way `critcmp` does, e.g., `4.8±0.12ns`.
+fn format_time(nanos: f64, std_dev: f64) -> String {
+    let (divisor, unit) = if nanos < 1e3 {
+        (1.0, "ns")
+    } else if nanos < 1e6 {
+        (1e3, "µs")
+    } else if nanos < 1e9 {
+        (1e6, "ms")
+    } else {
+        (1e9, "s")
+    };
+    if std_dev == 0.0 {
+        format!("{:.1}{unit}", nanos / divisor)
+    } else {
+        format!("{:.1}±{:.2}{unit}", nanos / divisor, std_dev / divisor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(format: Format) -> Args {
+        Args {
+            baseline: "new".to_string(),
+            against: Some("old".to_string()),
+            techniques: vec![],
+            threshold: 5.0,
+            allow_missing: false,
+            filters: vec![],
+            transpose: false,
+            format,
+            dir: PathBuf::new(),
+        }
+    }
+
+    fn bench(technique: &str, input: &str, mean: f64) -> Bench {
+        Bench {
+            technique: technique.to_string(),
+            input: input.to_string(),
+            mean,
+            std_dev: 0.0,
+        }
+    }
+
+    fn json(out: Vec<u8>) -> Vec<serde_json::Value> {
+        serde_json::from_slice::<serde_json::Value>(&out)
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn input_key() {
+        assert_eq!(
+            InputKey::new("scaling-50"),
+            InputKey("scaling-", Some(50), "scaling-50"),
+        );
+        assert_eq!(InputKey::new("mins"), InputKey("mins", None, "mins"));
+        assert_eq!(InputKey::new("123"), InputKey("", Some(123), "123"));
+        assert_eq!(InputKey::new(""), InputKey("", None, ""));
+
+        let mut inputs = vec!["scaling-200", "b", "scaling-50", "a10", "a9"];
+        inputs.sort_by_key(|input| InputKey::new(input));
+        assert_eq!(inputs, ["a9", "a10", "b", "scaling-50", "scaling-200"]);
+    }
+
+    #[test]
+    fn ratio() {
+        let benches = [
+            bench("fast", "x", 2.0),
+            bench("slow", "x", 5.0),
+            bench("fast", "y", 4.0),
+            bench("slow", "y", 3.0),
+        ];
+        let mut out = vec![];
+        print_ratios(&args(Format::Json), &benches, &mut out).unwrap();
+        let ratios: Vec<f64> = json(out)
+            .iter()
+            .map(|row| row["ratio"].as_f64().unwrap())
+            .collect();
+        assert_eq!(ratios, [1.0, 2.5, 4.0 / 3.0, 1.0]);
+    }
+
+    #[test]
+    fn change() {
+        assert_eq!(change_percent(2.0, 3.0), 50.0);
+        assert_eq!(change_percent(4.0, 3.0), -25.0);
+        assert_eq!(change_percent(4.0, 4.0), 0.0);
+    }
+
+    #[test]
+    fn threshold() {
+        let old = [bench("a", "x", 100.0), bench("b", "x", 100.0)];
+        let check = |args: &Args, new: &[Bench]| {
+            let mut out = vec![];
+            let ok = check_regressions(args, &old, new, &mut out).unwrap();
+            let regressed: Vec<bool> = json(out)
+                .iter()
+                .map(|row| row["regressed"].as_bool().unwrap())
+                .collect();
+            (ok, regressed)
+        };
+
+        let mut args = args(Format::Json);
+        let new = [bench("a", "x", 104.0), bench("b", "x", 90.0)];
+        assert_eq!(check(&args, &new), (true, vec![false, false]));
+        let new = [bench("a", "x", 106.0), bench("b", "x", 90.0)];
+        assert_eq!(check(&args, &new), (false, vec![true, false]));
+        args.threshold = 10.0;
+        assert_eq!(check(&args, &new), (true, vec![false, false]));
+        args.threshold = 5.0;
+        args.techniques = vec!["b".to_string()];
+        assert_eq!(check(&args, &new), (true, vec![false]));
+    }
+
+    #[test]
+    fn missing() {
+        let old = [bench("a", "x", 100.0), bench("b", "x", 100.0)];
+        let new = [bench("a", "x", 100.0)];
+        let check = |args: &Args| {
+            check_regressions(args, &old, &new, &mut vec![]).unwrap()
+        };
+
+        let mut args = args(Format::Json);
+        assert!(!check(&args));
+        args.allow_missing = true;
+        assert!(check(&args));
+        args.allow_missing = false;
+        args.techniques = vec!["a".to_string()];
+        assert!(check(&args));
+    }
+}
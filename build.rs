@@ -1,4 +1,262 @@
 fn main() {
     cc::Build::new().file("src/gencdfa1.c").compile("gencdfa1");
-    println!("cargo:rerun-if-changed=src/gencdfa1.c")
+    cc::Build::new().file("src/gencdfa1_ci.c").compile("gencdfa1_ci");
+    cc::Build::new().file("src/gencdfa2.c").compile("gencdfa2");
+    cc::Build::new().file("src/gencdfa2_ci.c").compile("gencdfa2_ci");
+    println!("cargo:rerun-if-changed=src/gencdfa1.c");
+    println!("cargo:rerun-if-changed=src/gencdfa1_ci.c");
+    println!("cargo:rerun-if-changed=src/gencdfa2.c");
+    println!("cargo:rerun-if-changed=src/gencdfa2_ci.c");
+
+    #[cfg(feature = "scaling")]
+    scaling::generate();
+}
+
+#[cfg(feature = "scaling")]
+#[path = "src/rng.rs"]
+#[allow(dead_code)]
+mod rng;
+
+#[cfg(feature = "scaling")]
+#[path = "src/synthetic.rs"]
+#[allow(dead_code)]
+mod synthetic;
+
+/// Generates everything `src/scaling.rs` needs, which is only built with the
+/// `scaling` feature.
+#[cfg(feature = "scaling")]
+mod scaling {
+    use std::{collections::HashSet, fmt::Write};
+
+    use gendfa_emit::{Backend, Config};
+    use labelset::{Label, LabelSet};
+
+    use super::synthetic;
+
+    /// The sizes of the synthetic label sets used by the scaling benchmarks.
+    ///
+    /// Each one gets its own generated file in `OUT_DIR`, which is included by
+    /// `src/scaling.rs`.
+    const SYNTHETIC_SIZES: &[usize] = &[10, 50, 200, 1000];
+
+    /// The `Unit` variants assigned to synthetic labels, in round robin order,
+    /// along with their discriminants.
+    const UNITS: &[(&str, i64)] = &[
+        ("Year", 9),
+        ("Month", 8),
+        ("Week", 7),
+        ("Day", 6),
+        ("Hour", 5),
+        ("Minute", 4),
+        ("Second", 3),
+        ("Millisecond", 2),
+        ("Microsecond", 1),
+        ("Nanosecond", 0),
+    ];
+
+    /// The name of the module each Rust DFA technique is generated into for
+    /// every synthetic set, along with how `gendfa` generates it in `src`.
+    fn rust_dfas() -> [(&'static str, Config); 6] {
+        [
+            ("gendfa1", Config::new(Backend::Rust)),
+            ("gendfa2", Config::new(Backend::RustTree)),
+            ("gendfa3", Config::new(Backend::RustFn { tail_calls: false })),
+            ("gendfa4", Config::new(Backend::RustLabeledBlocks)),
+            ("gendfa5", Config::new(Backend::RustTable)),
+            ("gendfa6", Config::new(Backend::Rust).byte_classes(true)),
+        ]
+    }
+
+    /// Like `rust_dfas`, but for the C DFA techniques. Each one is compiled
+    /// into its own library.
+    fn c_dfas() -> [(&'static str, Config); 2] {
+        [
+            ("gencdfa1", Config::new(Backend::C)),
+            ("gencdfa2", Config::new(Backend::C).byte_classes(true)),
+        ]
+    }
+
+    /// Writes the code for every synthetic label set to `OUT_DIR`, and
+    /// compiles its C DFAs.
+    pub fn generate() {
+        let out_dir = std::env::var("OUT_DIR").unwrap();
+        for &size in SYNTHETIC_SIZES {
+            let labels = synthetic::Config::new(size).generate();
+            let labels = LabelSet::new(
+                labels
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, label)| {
+                        let (unit, discriminant) = UNITS[i % UNITS.len()];
+                        Label { label, value: unit.to_string(), discriminant }
+                    })
+                    .collect(),
+            )
+            .unwrap();
+            let path = format!("{out_dir}/synthetic{size}.rs");
+            std::fs::write(&path, synthetic_set(size, &labels)).unwrap();
+
+            for (name, config) in c_dfas() {
+                let fn_name = format!("synthetic{size}_{name}_find");
+                let mut code = vec![];
+                config.fn_name(&fn_name).generate(&labels, &mut code).unwrap();
+                let path = format!("{out_dir}/synthetic{size}_{name}.c");
+                std::fs::write(&path, code).unwrap();
+                cc::Build::new()
+                    .file(&path)
+                    .compile(&format!("synthetic{size}_{name}"));
+            }
+        }
+        println!("cargo:rerun-if-changed=src/synthetic.rs");
+        println!("cargo:rerun-if-changed=src/rng.rs");
+
+        // The tries in `src` use the smallest node IDs that fit the 56 unit
+        // designator labels, which aren't big enough for the larger synthetic
+        // sets. So the scaling benchmarks use copies of them with 32-bit node
+        // IDs instead.
+        for i in 1..=5 {
+            let path = format!("src/trie{i}.rs");
+            let code = std::fs::read_to_string(&path).unwrap();
+            std::fs::write(
+                format!("{out_dir}/trie{i}_wide.rs"),
+                widen_trie(&path, &code),
+            )
+            .unwrap();
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+
+    /// Returns a copy of the trie defined by `code` whose node IDs are
+    /// `u32`.
+    ///
+    /// This fails the build unless `code` defines `TrieNodeId` exactly once,
+    /// as one of the unsigned integer types, so that changing how the tries
+    /// define it can't silently leave the copy with narrow node IDs.
+    fn widen_trie(path: &str, code: &str) -> String {
+        const ALIAS: &str = "type TrieNodeId";
+        let mut aliases = code.match_indices(ALIAS);
+        let Some((start, _)) = aliases.next() else {
+            panic!("{path} doesn't define {ALIAS}");
+        };
+        assert!(
+            aliases.next().is_none(),
+            "{path} defines {ALIAS} more than once",
+        );
+        let end = start + code[start..].find(';').unwrap();
+        let ty = code[start + ALIAS.len()..end]
+            .trim()
+            .strip_prefix('=')
+            .map(str::trim);
+        assert!(
+            matches!(ty, Some("u8" | "u16" | "u32")),
+            "{path} defines {ALIAS} in an unexpected way: {:?}",
+            &code[start..end],
+        );
+        format!("{}{ALIAS} = u32{}", &code[..start], &code[end..])
+    }
+
+    /// Generates the Rust source for the synthetic label set given.
+    ///
+    /// This defines a `synthetic_labels!` macro that works just like
+    /// `designator_labels!`, the same labels in a `LABELS` slice, the number
+    /// of nodes a trie of them needs and a `find_prefix` function that works
+    /// like the code generated by `gen-match-prefix`. It also has a module for
+    /// every Rust DFA generated by `gendfa` (e.g., `gendfa1::find`) and
+    /// declares the `find` function of every C DFA (e.g., `gencdfa1_find`).
+    fn synthetic_set(size: usize, set: &LabelSet) -> String {
+        let mut out = String::new();
+        let labels: Vec<(&str, &str)> = set
+            .labels()
+            .iter()
+            .map(|l| (l.label.as_str(), l.value.as_str()))
+            .collect();
+
+        writeln!(out, "// This file was generated by build.rs. Do not edit.")
+            .unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(out, "macro_rules! synthetic_labels {{").unwrap();
+        writeln!(out, "    ($callback:ident) => {{").unwrap();
+        writeln!(out, "        $callback! {{").unwrap();
+        for &(label, unit) in labels.iter() {
+            writeln!(out, "            b\"{label}\" => {unit},").unwrap();
+        }
+        writeln!(out, "        }}").unwrap();
+        writeln!(out, "    }};").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(out, "pub const LABELS: &[(&str, Unit)] = &[").unwrap();
+        for &(label, unit) in labels.iter() {
+            writeln!(out, "    (\"{label}\", Unit::{unit}),").unwrap();
+        }
+        writeln!(out, "];").unwrap();
+        writeln!(out).unwrap();
+
+        // A trie has one node per distinct non-empty prefix, plus a root node
+        // and a fail node.
+        let mut prefixes = HashSet::new();
+        for &(label, _) in labels.iter() {
+            for end in 1..=label.len() {
+                prefixes.insert(&label[..end]);
+            }
+        }
+        writeln!(out, "#[allow(dead_code)]").unwrap();
+        writeln!(out, "const TRIE_NODES: usize = {};", prefixes.len() + 2)
+            .unwrap();
+        writeln!(out).unwrap();
+
+        // Just like `gen-match-prefix`, longer labels have to come first so
+        // that the longest label is always the one that matches.
+        let mut sorted = labels.clone();
+        sorted.sort_by(|&(lab1, _), &(lab2, _)| {
+            (lab1.len(), lab1).cmp(&(lab2.len(), lab2)).reverse()
+        });
+        writeln!(out, "#[allow(clippy::match_ref_pats)]").unwrap();
+        writeln!(out, "#[inline(always)]").unwrap();
+        writeln!(
+            out,
+            "fn find_prefix(haystack: &[u8]) -> Option<(Unit, usize)> {{"
+        )
+        .unwrap();
+        writeln!(out, "    match haystack {{").unwrap();
+        for &(label, unit) in sorted.iter() {
+            write!(out, "        &[").unwrap();
+            for byte in label.bytes() {
+                write!(out, "b'{}', ", char::from(byte)).unwrap();
+            }
+            writeln!(out, "..] => Some((Unit::{unit}, {})),", label.len())
+                .unwrap();
+        }
+        writeln!(out, "        _ => None,").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+
+        for (name, config) in rust_dfas() {
+            let mut code = vec![];
+            config.generate(set, &mut code).unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "mod {name} {{").unwrap();
+            out.push_str(&String::from_utf8(code).unwrap());
+            writeln!(out, "}}").unwrap();
+        }
+
+        writeln!(out).unwrap();
+        writeln!(out, "extern \"C\" {{").unwrap();
+        for (name, _) in c_dfas() {
+            writeln!(
+                out,
+                "    #[link_name = \"synthetic{size}_{name}_find\"]"
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "    fn {name}_find(start: *const u8, end: *const u8) \
+                 -> Output;"
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+        out
+    }
 }
@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+
+use duration_unit_lookup::{footprint, STRATEGIES};
+use object::{Object, ObjectSymbol, SymbolKind};
+
+fn main() -> anyhow::Result<()> {
+    if let Some(arg) = std::env::args_os().nth(1) {
+        anyhow::bail!("unknown argument: {}", arg.to_string_lossy());
+    }
+    if cfg!(debug_assertions) {
+        eprintln!(
+            "warning: this is a debug build, so code sizes aren't \
+             representative (use `cargo run --release -p footprint`)",
+        );
+    }
+
+    // Every technique is called once, which makes sure its code is linked
+    // into this binary and that everything lazily initialized is built.
+    for strategy in STRATEGIES {
+        let _ = std::hint::black_box(strategy.lookup(b"5 days"));
+    }
+    let footprints = footprint::all();
+    let symbols = symbols()?;
+
+    println!(
+        "{:<22} {:>8} {:>8} {:>8} {:>8} {:>12}",
+        "technique", "lookup", "code", "static", "heap", "lazy init",
+    );
+    for footprint in footprints {
+        let lookup = format!("{}::lookup", footprint.module);
+        let lookup_size: u64 = symbols
+            .iter()
+            .filter(|&(path, _)| *path == lookup)
+            .map(|(_, &size)| size)
+            .sum();
+        let code_size: u64 = symbols
+            .iter()
+            .filter(|&(path, _)| footprint.contains(path))
+            .map(|(_, &size)| size)
+            .sum();
+        let lazy_init = match footprint.lazy_init {
+            None => "-".to_string(),
+            Some(duration) => format!("{duration:.2?}"),
+        };
+        println!(
+            "{:<22} {:>8} {:>8} {:>8} {:>8} {:>12}",
+            footprint.name,
+            lookup_size,
+            code_size,
+            footprint.static_bytes,
+            footprint.heap_bytes,
+            lazy_init,
+        );
+    }
+    Ok(())
+}
+
+/// Returns the size of every function in this executable, keyed by its
+/// demangled path (without the hash).
+///
+/// Functions with more than one symbol (i.e., aliases at the same address)
+/// are only counted once. Functions with the same path (e.g., different
+/// instantiations of a generic function) have their sizes summed.
+fn symbols() -> anyhow::Result<BTreeMap<String, u64>> {
+    let exe = std::env::current_exe()?;
+    let data = std::fs::read(&exe)?;
+    let file = object::File::parse(&*data)?;
+    let mut addresses = std::collections::HashSet::new();
+    let mut symbols = BTreeMap::new();
+    for symbol in file.symbols() {
+        if symbol.kind() != SymbolKind::Text || symbol.size() == 0 {
+            continue;
+        }
+        if !addresses.insert(symbol.address()) {
+            continue;
+        }
+        let Ok(name) = symbol.name() else { continue };
+        let path = format!("{:#}", rustc_demangle::demangle(name));
+        *symbols.entry(path).or_insert(0) += symbol.size();
+    }
+    anyhow::ensure!(
+        !symbols.is_empty(),
+        "no symbols found in {} (was it stripped?)",
+        exe.display(),
+    );
+    Ok(symbols)
+}
@@ -1,26 +1,110 @@
 use std::io::Write;
 
+use labelset::{Label, LabelSet};
+
+const USAGE: &str = "\
+Generate a single `match` on slice patterns that recognizes a set of labels.
+
+USAGE:
+    gen-match-prefix [OPTIONS]
+
+The generated Rust code is written to stdout. By default, it recognizes the
+unit designator labels in `labelset/lib.rs` and imports the `Unit` enum from
+the root of this crate. With `--labels`, the labels are read from a TSV, JSON
+or TOML file instead (see `labelset/lib.rs` for the format), and the enum is
+defined in the generated code.
+
+OPTIONS:
+    --ascii-case-insensitive   Match ASCII letters case insensitively.
+    --labels <path>            Read the labels from the file given.
+    --enum <name>              The name of the enum. Requires --labels.
+                               [default: Unit]
+    --fn <name>                The name of the generated function.
+                               [default: find]
+    -h, --help                 Print this help.
+";
+
 fn main() -> anyhow::Result<()> {
-    let mut labels = LABELS.iter().copied().collect::<Vec<(&str, Unit)>>();
-    labels.sort_by(|&(lab1, _), &(lab2, _)| {
+    use lexopt::{Arg, ValueExt};
+
+    let mut ascii_case_insensitive = false;
+    let mut labels_path: Option<std::path::PathBuf> = None;
+    let mut enum_name = None;
+    let mut fn_name = "find".to_string();
+    let mut parser = lexopt::Parser::from_env();
+    while let Some(arg) = parser.next()? {
+        match arg {
+            Arg::Short('h') | Arg::Long("help") => {
+                print!("{USAGE}");
+                return Ok(());
+            }
+            Arg::Long("ascii-case-insensitive") => {
+                ascii_case_insensitive = true
+            }
+            Arg::Long("labels") => labels_path = Some(parser.value()?.into()),
+            Arg::Long("enum") => enum_name = Some(parser.value()?.string()?),
+            Arg::Long("fn") => fn_name = parser.value()?.string()?,
+            _ => return Err(arg.unexpected().into()),
+        }
+    }
+    // The builtin labels always map to `Unit`, which is imported from the
+    // root of this crate, so another name would refer to nothing.
+    anyhow::ensure!(
+        enum_name.is_none() || labels_path.is_some(),
+        "--enum can only be used with --labels",
+    );
+    let enum_name = enum_name.unwrap_or_else(|| "Unit".to_string());
+    let set = match labels_path {
+        None => LabelSet::designators(),
+        Some(ref path) => LabelSet::read(path)?,
+    };
+
+    let mut labels = set.labels().iter().collect::<Vec<&Label>>();
+    labels.sort_by(|lab1, lab2| {
+        let (lab1, lab2) = (&lab1.label, &lab2.label);
         (lab1.len(), lab1).cmp(&(lab2.len(), lab2)).reverse()
     });
 
     let mut out = std::io::stdout().lock();
-    writeln!(out, "use crate::Unit;")?;
-    writeln!(out, "")?;
+    if labels_path.is_some() {
+        set.write_rust_enum(&enum_name, &mut out)?;
+    } else {
+        writeln!(out, "use crate::{enum_name};")?;
+    }
+    writeln!(out)?;
+    // Clippy would rather we match on `[b'a', ..]`, but the explicit
+    // reference makes it clear that we're matching on a slice.
+    writeln!(out, "#[allow(clippy::match_ref_pats)]")?;
     writeln!(out, "#[inline(always)]")?;
     writeln!(
         out,
-        "pub(super) fn find(haystack: &[u8]) -> Option<(Unit, usize)> {{"
+        "pub(super) fn {fn_name}(haystack: &[u8]) -> \
+         Option<({enum_name}, usize)> {{"
     )?;
     writeln!(out, "  match haystack {{")?;
-    for (label, unit) in labels {
+    for label in labels {
         write!(out, "    &[")?;
-        for &byte in label.as_bytes() {
-            write!(out, "{}, ", ByteLiteral(byte))?;
+        for &byte in label.label.as_bytes() {
+            write!(out, "{}", ByteLiteral(byte))?;
+            // Add the other case of each ASCII letter. (Every builtin label
+            // is lowercase, so this adds the uppercase variant.) Non-ASCII
+            // bytes (like those in `µ`) are always matched exactly.
+            if ascii_case_insensitive && byte.is_ascii_alphabetic() {
+                let other = if byte.is_ascii_lowercase() {
+                    byte.to_ascii_uppercase()
+                } else {
+                    byte.to_ascii_lowercase()
+                };
+                write!(out, " | {}", ByteLiteral(other))?;
+            }
+            write!(out, ", ")?;
         }
-        writeln!(out, "..] => Some((Unit::{unit:?}, {})),", label.len())?;
+        writeln!(
+            out,
+            "..] => Some(({enum_name}::{}, {})),",
+            label.value,
+            label.label.len(),
+        )?;
     }
     writeln!(out, "  _ => None,")?;
     writeln!(out, "  }}")?;
@@ -41,78 +125,3 @@ impl std::fmt::Display for ByteLiteral {
         Ok(())
     }
 }
-
-/// The same `Unit` type used in Jiff, reproduced here for readability
-/// mostly.
-#[derive(Clone, Copy, Debug)]
-enum Unit {
-    Year = 9,
-    Month = 8,
-    Week = 7,
-    Day = 6,
-    Hour = 5,
-    Minute = 4,
-    Second = 3,
-    Millisecond = 2,
-    Microsecond = 1,
-    Nanosecond = 0,
-}
-
-static LABELS: &[(&str, Unit)] = &[
-    ("years", Unit::Year),
-    ("year", Unit::Year),
-    ("yrs", Unit::Year),
-    ("yr", Unit::Year),
-    ("y", Unit::Year),
-    ("months", Unit::Month),
-    ("month", Unit::Month),
-    ("mos", Unit::Month),
-    ("mo", Unit::Month),
-    ("weeks", Unit::Week),
-    ("week", Unit::Week),
-    ("wks", Unit::Week),
-    ("wk", Unit::Week),
-    ("w", Unit::Week),
-    ("days", Unit::Day),
-    ("day", Unit::Day),
-    ("d", Unit::Day),
-    ("hours", Unit::Hour),
-    ("hour", Unit::Hour),
-    ("hrs", Unit::Hour),
-    ("hr", Unit::Hour),
-    ("h", Unit::Hour),
-    ("minutes", Unit::Minute),
-    ("minute", Unit::Minute),
-    ("mins", Unit::Minute),
-    ("min", Unit::Minute),
-    ("m", Unit::Minute),
-    ("seconds", Unit::Second),
-    ("second", Unit::Second),
-    ("secs", Unit::Second),
-    ("sec", Unit::Second),
-    ("s", Unit::Second),
-    ("milliseconds", Unit::Millisecond),
-    ("millisecond", Unit::Millisecond),
-    ("millis", Unit::Millisecond),
-    ("milli", Unit::Millisecond),
-    ("msecs", Unit::Millisecond),
-    ("msec", Unit::Millisecond),
-    ("ms", Unit::Millisecond),
-    ("microseconds", Unit::Microsecond),
-    ("microsecond", Unit::Microsecond),
-    ("micros", Unit::Microsecond),
-    ("micro", Unit::Microsecond),
-    ("usecs", Unit::Microsecond),
-    ("usec", Unit::Microsecond),
-    ("µsecs", Unit::Microsecond),
-    ("µsec", Unit::Microsecond),
-    ("us", Unit::Microsecond),
-    ("µs", Unit::Microsecond),
-    ("nanoseconds", Unit::Nanosecond),
-    ("nanosecond", Unit::Nanosecond),
-    ("nanos", Unit::Nanosecond),
-    ("nano", Unit::Nanosecond),
-    ("nsecs", Unit::Nanosecond),
-    ("nsec", Unit::Nanosecond),
-    ("ns", Unit::Nanosecond),
-];
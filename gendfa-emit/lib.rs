@@ -0,0 +1,1939 @@
+/*!
+Generates code for a DFA that recognizes a set of labels.
+
+This is the code generator behind the `gendfa` program, which writes the
+`gendfa*` and `gencdfa*` files in `src`. The build script uses it too, to
+generate the same DFAs for every synthetic label set used by the scaling
+benchmarks.
+
+Every backend is generated from the same minimized `regex-automata` DFA. The
+search stops as soon as no label can match any more input, and a match is
+reported only if it ends right there. (i.e., A shorter match seen earlier
+isn't remembered.)
+
+# Example
+
+```
+use gendfa_emit::{Backend, Config};
+use labelset::{Label, LabelSet};
+
+let labels = LabelSet::new(vec![
+    Label { label: "yrs".into(), value: "Year".into(), discriminant: 9 },
+    Label { label: "hrs".into(), value: "Hour".into(), discriminant: 5 },
+])
+.unwrap();
+let mut out = vec![];
+Config::new(Backend::Rust).define_enum(true).generate(&labels, &mut out)?;
+let code = String::from_utf8(out).unwrap();
+assert!(code.contains("pub(super) fn find(haystack: &[u8])"));
+# Ok::<(), anyhow::Error>(())
+```
+*/
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    io::Write,
+    ops::RangeInclusive,
+};
+
+use labelset::LabelSet;
+use regex_automata::{
+    dfa::{dense::DFA, Automaton, StartKind},
+    util::{primitives::StateID, start, syntax},
+    Anchored, MatchKind,
+};
+
+/// The kind of code to generate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Backend {
+    /// A loop over a state enum.
+    Rust,
+    /// Nested `match` expressions. This requires a DFA without cycles.
+    RustTree,
+    /// One function per state. Without `tail_calls`, this requires a DFA
+    /// without cycles. With it, the code uses `become`, which requires a
+    /// nightly compiler.
+    RustFn { tail_calls: bool },
+    /// A loop over labeled blocks.
+    RustLabeledBlocks,
+    /// Tables for the search loop in `src/dfa.rs`. These always map every
+    /// byte to its equivalence class.
+    RustTable,
+    /// C with `goto`.
+    C,
+}
+
+/// A configuration for generating code for a DFA.
+#[derive(Clone, Debug)]
+pub struct Config {
+    backend: Backend,
+    ascii_case_insensitive: bool,
+    regex: bool,
+    byte_classes: bool,
+    enum_name: String,
+    define_enum: bool,
+    fn_name: Option<String>,
+}
+
+impl Config {
+    /// Create a new configuration for generating code with the backend
+    /// given.
+    ///
+    /// By default, labels are literals matched case sensitively, and the
+    /// generated Rust code imports the `Unit` enum from the root of the
+    /// crate it's included in.
+    pub fn new(backend: Backend) -> Config {
+        Config {
+            backend,
+            ascii_case_insensitive: false,
+            regex: false,
+            byte_classes: false,
+            enum_name: "Unit".to_string(),
+            define_enum: false,
+            fn_name: None,
+        }
+    }
+
+    /// Match ASCII letters case insensitively.
+    pub fn ascii_case_insensitive(mut self, yes: bool) -> Config {
+        self.ascii_case_insensitive = yes;
+        self
+    }
+
+    /// Treat every label as a regular expression instead of a literal.
+    ///
+    /// The length reported is then wherever the match ends. Unicode word
+    /// boundaries aren't supported, but ASCII ones like `(?-u:\b)` are.
+    pub fn regex(mut self, yes: bool) -> Config {
+        self.regex = yes;
+        self
+    }
+
+    /// Map every byte to its equivalence class with a table first, and
+    /// match on the class instead of on the byte.
+    ///
+    /// This isn't supported with [`Backend::RustTable`], which always does
+    /// this.
+    pub fn byte_classes(mut self, yes: bool) -> Config {
+        self.byte_classes = yes;
+        self
+    }
+
+    /// Set the name of the enum with a variant for every value.
+    pub fn enum_name(mut self, name: &str) -> Config {
+        self.enum_name = name.to_string();
+        self
+    }
+
+    /// Define the enum in the generated Rust code, instead of importing it
+    /// from the root of the crate.
+    ///
+    /// The C code always defines its enum.
+    pub fn define_enum(mut self, yes: bool) -> Config {
+        self.define_enum = yes;
+        self
+    }
+
+    /// Set the name of the generated function, or of the generated static
+    /// for [`Backend::RustTable`].
+    ///
+    /// The default is `DFA` for [`Backend::RustTable`], `gencdfa1_find` (or
+    /// `gencdfa1_ci_find`) for [`Backend::C`] and `find` otherwise.
+    pub fn fn_name(mut self, name: &str) -> Config {
+        self.fn_name = Some(name.to_string());
+        self
+    }
+
+    /// Builds a DFA from the labels given and writes the code for it.
+    ///
+    /// This fails if the labels don't make a valid DFA, or if the backend
+    /// can't represent the DFA, e.g., because it has cycles.
+    pub fn generate(
+        &self,
+        labels: &LabelSet,
+        out: &mut dyn Write,
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !self.byte_classes || self.backend != Backend::RustTable,
+            "byte classes can't be used with the table backend",
+        );
+        let default_fn_name = match self.backend {
+            Backend::RustTable => "DFA",
+            Backend::C if self.ascii_case_insensitive => "gencdfa1_ci_find",
+            Backend::C => "gencdfa1_find",
+            _ => "find",
+        };
+        let fn_name =
+            self.fn_name.as_deref().unwrap_or(default_fn_name).to_string();
+
+        // Unless labels are regexes, anything that means something in a
+        // regex has to be escaped.
+        let pattern = |label: &str| {
+            if self.regex {
+                label.to_string()
+            } else {
+                escape(label)
+            }
+        };
+        let patterns: Vec<String> =
+            labels.labels().iter().map(|l| pattern(&l.label)).collect();
+        let config = DFA::config()
+            .accelerate(false)
+            .starts_for_each_pattern(false) // default
+            .specialize_start_states(false) // default
+            .start_kind(StartKind::Anchored)
+            .match_kind(MatchKind::All)
+            // Unless byte classes are asked for, this makes it so our
+            // alphabet is always the special EOI symbol and every possible
+            // byte value. i.e., No alphabet compression. Otherwise, bytes
+            // that every state treats the same share a class, and the
+            // generated code matches on classes instead of bytes. See
+            // `StateTransitionIter`.
+            .byte_classes(self.byte_classes)
+            .minimize(true);
+        // Unicode mode is disabled so that case insensitivity only applies
+        // to ASCII letters. Otherwise, `s` would also match `ſ` and `k`
+        // would also match the Kelvin sign. This matches what every other
+        // technique in this repository does. Non-ASCII literals like `µ`
+        // still work without Unicode mode and just match their UTF-8
+        // encoding.
+        let syntax = syntax::Config::new()
+            .case_insensitive(self.ascii_case_insensitive)
+            .unicode(!self.ascii_case_insensitive);
+        let dfa = DFA::builder()
+            .configure(config)
+            .syntax(syntax)
+            .build_many(&patterns)?;
+        let start =
+            dfa.start_state(&start::Config::new().anchored(Anchored::Yes))?;
+        let spec = Spec {
+            labels,
+            enum_name: self.enum_name.clone(),
+            define_enum: self.define_enum,
+            fn_name,
+            positions: positions(&dfa, start),
+        };
+
+        match self.backend {
+            Backend::Rust => print_rust_dfa(&dfa, &spec, out),
+            Backend::RustTree => print_rust_tree_dfa(&dfa, &spec, out),
+            Backend::RustFn { tail_calls } => {
+                print_rust_fn_dfa(&dfa, &spec, tail_calls, out)
+            }
+            Backend::RustLabeledBlocks => {
+                print_rust_labeled_blocks_dfa(&dfa, &spec, out)
+            }
+            Backend::RustTable => print_rust_table_dfa(&dfa, &spec, out),
+            Backend::C => print_c_dfa(&dfa, &spec, out),
+        }
+    }
+}
+
+/// Escapes every regex meta character in the string given.
+fn escape(label: &str) -> String {
+    let mut pattern = String::new();
+    for ch in label.chars() {
+        if "\\.+*?()|[]{}^$#&-~ ".contains(ch) {
+            pattern.push('\\');
+        }
+        pattern.push(ch);
+    }
+    pattern
+}
+
+/// The labels to recognize, the names of things in the generated code and
+/// what is known about the DFA built from the labels before running it.
+struct Spec<'a> {
+    labels: &'a LabelSet,
+    /// The name of the enum with a variant for every value.
+    enum_name: String,
+    /// Whether the Rust code defines the enum itself, instead of importing
+    /// it from the root of the crate (which is where `Unit` is defined).
+    define_enum: bool,
+    /// The name of the generated function, or of the generated static for
+    /// [`Backend::RustTable`].
+    fn_name: String,
+    /// The position of every state reachable from the start state, if it's
+    /// always the same. See `positions`.
+    positions: HashMap<StateID, Option<usize>>,
+}
+
+impl Spec<'_> {
+    /// Prints either an import of the enum or its definition.
+    fn print_rust_enum(&self, out: &mut dyn Write) -> anyhow::Result<()> {
+        if self.define_enum {
+            self.labels.write_rust_enum(&self.enum_name, out)?;
+        } else {
+            writeln!(out, "use crate::{};", self.enum_name)?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if the position of every state is known. This is always
+    /// the case when every label is a literal.
+    fn fixed_positions(&self) -> bool {
+        self.positions.values().all(|pos| pos.is_some())
+    }
+}
+
+/// Returns the position of every state reachable from `start`, i.e., the
+/// number of bytes consumed before entering it, if it's the same on every
+/// path from `start`. A state in a cycle (or reachable from one) never has a
+/// fixed position, and neither does a state reachable by paths of different
+/// lengths, like the state after both `min` and `mins` for `mins?`.
+///
+/// The EOI transition counts as consuming a byte. Since matches are delayed
+/// by one byte, this means that the match reported by a state without any
+/// transitions always ends one byte before its position, no matter how the
+/// state was entered.
+fn positions(
+    dfa: &DFA<Vec<u32>>,
+    start: StateID,
+) -> HashMap<StateID, Option<usize>> {
+    let mut positions = HashMap::from([(start, Some(0))]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(sid) = queue.pop_front() {
+        let next_pos = positions[&sid].map(|pos| pos + 1);
+        let eoi_next = dfa.next_eoi_state(sid);
+        let nexts = StateTransitionIter::new(dfa, sid)
+            .map(|trans| trans.next)
+            .chain(Some(eoi_next).filter(|&next| !is_dead(dfa, next)));
+        for next in nexts {
+            // Every state's position changes at most twice: once when it's
+            // first seen and once when it turns out not to be fixed.
+            let pos = match positions.get(&next) {
+                None => next_pos,
+                Some(&pos) if pos.is_none() || pos == next_pos => continue,
+                Some(_) => None,
+            };
+            positions.insert(next, pos);
+            queue.push_back(next);
+        }
+    }
+    positions
+}
+
+/// Returns true if there is a path from `start` back to a state that was
+/// already on it.
+fn has_cycle(dfa: &DFA<Vec<u32>>, start: StateID) -> bool {
+    // A depth first search, where a state is on the current path from the
+    // time it's pushed until all of its successors have been visited.
+    let mut on_path = HashSet::new();
+    let mut done = HashSet::new();
+    let mut stack = vec![(start, false)];
+    while let Some((sid, finished)) = stack.pop() {
+        if finished {
+            on_path.remove(&sid);
+            done.insert(sid);
+            continue;
+        }
+        if done.contains(&sid) {
+            continue;
+        }
+        if !on_path.insert(sid) {
+            return true;
+        }
+        stack.push((sid, true));
+        for trans in StateTransitionIter::new(dfa, sid) {
+            if on_path.contains(&trans.next) {
+                return true;
+            }
+            if !done.contains(&trans.next) {
+                stack.push((trans.next, false));
+            }
+        }
+    }
+    false
+}
+
+/// Returns true if the state given can never lead to a match.
+///
+/// Quit states are treated just like the dead state. We never configure any
+/// quit bytes, so there shouldn't be any, but if there were, then giving up
+/// is reported as not finding a match.
+fn is_dead(dfa: &DFA<Vec<u32>>, sid: StateID) -> bool {
+    dfa.is_dead_state(sid) || dfa.is_quit_state(sid)
+}
+
+/// Returns true if the DFA was built with byte classes, in which case the
+/// generated code matches on the class of every byte instead of the byte
+/// itself.
+fn has_classes(dfa: &DFA<Vec<u32>>) -> bool {
+    !dfa.byte_classes().is_singleton()
+}
+
+/// Prints the map from every byte value to its class as a Rust array, if
+/// the DFA was built with byte classes.
+fn print_rust_classes(
+    dfa: &DFA<Vec<u32>>,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    if !has_classes(dfa) {
+        return Ok(());
+    }
+    let classes = dfa.byte_classes();
+    let classes: Vec<u8> = (0..=255).map(|byte| classes.get(byte)).collect();
+    writeln!(out, "pub(super) static CLASSES: [u8; 256] = [")?;
+    for chunk in classes.chunks(16) {
+        print_rust_table_row(chunk, out)?;
+    }
+    writeln!(out, "];")?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn print_rust_dfa(
+    dfa: &DFA<Vec<u32>>,
+    spec: &Spec,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let mut trav = TraversalState::default();
+
+    let start =
+        dfa.start_state(&start::Config::new().anchored(Anchored::Yes))?;
+    trav.enqueue(start);
+
+    spec.print_rust_enum(out)?;
+    writeln!(out)?;
+    print_rust_classes(dfa, out)?;
+    writeln!(out, "#[inline(always)]")?;
+    writeln!(
+        out,
+        "pub(super) fn {}(haystack: &[u8]) -> Option<({}, usize)> {{",
+        spec.fn_name, spec.enum_name,
+    )?;
+    writeln!(out, "  let mut sid = State::S{};", trav.gen_id(start))?;
+    // The position of the byte looked at is only needed if some match
+    // doesn't end at a fixed position.
+    if spec.fixed_positions() {
+        writeln!(out, "  for byte in haystack.iter().copied() {{")?;
+    } else {
+        writeln!(
+            out,
+            "  for (at, byte) in haystack.iter().copied().enumerate() {{",
+        )?;
+    }
+
+    writeln!(out, "    sid = match sid {{")?;
+    writeln!(out, "      State::DEAD => return None,")?;
+    while let Some(sid) = trav.dequeue() {
+        writeln!(out, "      State::S{} => {{", trav.gen_id(sid))?;
+
+        let (trans, all_bytes_covered) =
+            StateTransitionIter::new(dfa, sid).into_map();
+        if trans.is_empty() {
+            // We never visit dead/quit states, since transitions to them
+            // aren't followed. And in a minimized DFA, any other state that
+            // can't lead to a match is merged into the dead state. So any
+            // state with no outgoing transitions has to be a sentinel for a
+            // match state.
+            assert!(
+                dfa.match_len(sid) > 0,
+                "expected {sid:?} with no transitions to be a match state"
+            );
+            // This state was entered by the byte before the one at `at`.
+            let m = RustMatch::leaf(dfa, spec, sid).to_rust(Pos::Var("at", 0));
+            writeln!(out, "        return {m};")?;
+            // Since matches are delayed by one byte, the search loop might
+            // end right after entering this state. E.g., for `d `, the space
+            // enters the state reporting `d`, and then there are no bytes left
+            // to visit it with. Its EOI transition is never taken either,
+            // since the EOI is only seen from the state before it. So we need
+            // to report the match after the loop too.
+            trav.matches.push(sid);
+        } else {
+            if has_classes(dfa) {
+                writeln!(out, "        match CLASSES[usize::from(byte)] {{")?;
+            } else {
+                writeln!(out, "        match byte {{")?;
+            }
+            for (next, ranges) in trans {
+                trav.enqueue(next);
+                write!(out, "          ")?;
+                for (i, range) in ranges.into_iter().enumerate() {
+                    if i > 0 {
+                        write!(out, " | ")?;
+                    }
+                    write!(out, "{range}")?;
+                }
+                writeln!(out, " => State::S{},", trav.gen_id(next))?;
+            }
+            // A class is a `u8` too, so the compiler can't tell that every
+            // class is covered.
+            if !all_bytes_covered || has_classes(dfa) {
+                writeln!(out, "          _ => State::DEAD,")?;
+            }
+            writeln!(out, "        }}")?;
+        }
+        writeln!(out, "      }}")?;
+
+        // We didn't handle the EOI transition above intentionally. Instead,
+        // we collect all non-dead transitions here. We write out one last
+        // `match` statement after the search loop for these.
+        let eoi_next = dfa.next_eoi_state(sid);
+        if !is_dead(dfa, eoi_next) {
+            trav.enqueue(eoi_next);
+            trav.eois.push(sid);
+        }
+    }
+    writeln!(out, "    }};")?;
+    writeln!(out, "  }}")?;
+
+    if trav.eois.is_empty() && trav.matches.is_empty() {
+        writeln!(out, "  return None;")?;
+    } else {
+        // Every byte has been consumed, so the position of every state
+        // entered last is the length of the haystack.
+        let pos = Pos::Var("haystack.len()", 0);
+        writeln!(out, "  return match sid {{")?;
+        for sid in trav.matches.clone().into_iter() {
+            let gen_id = trav.gen_id(sid);
+            let m = RustMatch::leaf(dfa, spec, sid).to_rust(pos);
+            writeln!(out, "    State::S{gen_id} => {m},")?;
+        }
+        for sid in trav.eois.clone().into_iter() {
+            let eoi_sid = dfa.next_eoi_state(sid);
+            assert!(
+                dfa.is_match_state(eoi_sid),
+                "expected all non-dead EOI transitions to map to a match",
+            );
+
+            let gen_id = trav.gen_id(sid);
+            let m = RustMatch::eoi(dfa, spec, sid).to_rust(pos);
+            writeln!(out, "    State::S{gen_id} => {m},")?;
+        }
+        writeln!(out, "    _ => None,")?;
+        writeln!(out, "  }};")?;
+    }
+
+    writeln!(out)?;
+    writeln!(out, "  #[allow(clippy::upper_case_acronyms)]")?;
+    writeln!(out, "  enum State {{")?;
+    writeln!(out, "    DEAD,")?;
+    for i in 0..trav.state_ids.len() {
+        writeln!(out, "    S{i},")?;
+    }
+    writeln!(out, "  }}")?;
+
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
+
+/// Prints the DFA as nested `match` expressions, one per byte position.
+///
+/// Since our DFA is acyclic, every path through it can be written out as
+/// straight-line code. The state at any point is implicit in where we are in
+/// the tree of `match` expressions, and the position of the byte to look at
+/// next is a constant. So unlike `print_rust_dfa`, there is no state
+/// variable and no loop for the compiler to see through.
+///
+/// A DFA with cycles (e.g., from a regex like `[0-9]+`) would need an
+/// infinite tree, so it's rejected.
+fn print_rust_tree_dfa(
+    dfa: &DFA<Vec<u32>>,
+    spec: &Spec,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let start =
+        dfa.start_state(&start::Config::new().anchored(Anchored::Yes))?;
+    anyhow::ensure!(
+        !has_cycle(dfa, start),
+        "--rust-tree doesn't support DFAs with cycles",
+    );
+
+    // Clippy would rather we write `haystack.first()`, but using `get` for
+    // every position keeps the generated code uniform.
+    writeln!(out, "#![allow(clippy::get_first)]")?;
+    writeln!(out)?;
+    spec.print_rust_enum(out)?;
+    writeln!(out)?;
+    print_rust_classes(dfa, out)?;
+    writeln!(out, "#[inline(always)]")?;
+    writeln!(
+        out,
+        "pub(super) fn {}(haystack: &[u8]) -> Option<({}, usize)> {{",
+        spec.fn_name, spec.enum_name,
+    )?;
+    write!(out, "  ")?;
+    print_rust_tree_state(dfa, spec, start, 0, 1, out)?;
+    writeln!(out)?;
+    writeln!(out, "}}")?;
+    Ok(())
+}
+
+/// Prints the expression for the state given, where `at` is the position of
+/// the next byte to look at and `depth` is the level of indentation.
+///
+/// The expression printed doesn't start with indentation or end with a new
+/// line, so that it can be used as the right hand side of a `match` arm.
+fn print_rust_tree_state(
+    dfa: &DFA<Vec<u32>>,
+    spec: &Spec,
+    sid: StateID,
+    at: usize,
+    depth: usize,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let arms = match RustState::new(dfa, spec, sid) {
+        RustState::Match(m) => {
+            write!(out, "{}", m.to_rust(Pos::Const(at)))?;
+            return Ok(());
+        }
+        RustState::Arms(arms) => arms,
+    };
+    arms.print(Pos::Const(at), "", depth, out, &mut |next, out| {
+        print_rust_tree_state(dfa, spec, next, at + 1, depth + 1, out)
+    })
+}
+
+/// Prints the DFA as one function per state, where each state calls the
+/// function for the next state.
+///
+/// This is another way of emulating the `goto` layout of `print_c_dfa`. The
+/// functions are all `#[inline(always)]`, which (since our DFA is acyclic)
+/// means the compiler ends up with one big function that has no state
+/// variable, just like the tree of `print_rust_tree_dfa`. Unlike that tree
+/// though, states reachable from more than one other state aren't
+/// duplicated in the source.
+///
+/// When `tail_calls` is enabled, every call to the next state is written as
+/// `become`, which guarantees that it is compiled to a jump. This requires
+/// the unstable `explicit_tail_calls` feature. The state functions aren't
+/// inlined in that case, since the whole point is to get one jump per
+/// transition. This is also the only way to support DFAs with cycles, since
+/// otherwise every byte in a cycle would use more stack space.
+fn print_rust_fn_dfa(
+    dfa: &DFA<Vec<u32>>,
+    spec: &Spec,
+    tail_calls: bool,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let mut trav = TraversalState::default();
+    let become_ = if tail_calls { "become " } else { "" };
+
+    let start =
+        dfa.start_state(&start::Config::new().anchored(Anchored::Yes))?;
+    anyhow::ensure!(
+        tail_calls || !has_cycle(dfa, start),
+        "--rust-fn only supports DFAs with cycles with --tail-calls",
+    );
+    trav.enqueue(start);
+
+    spec.print_rust_enum(out)?;
+    writeln!(out)?;
+    print_rust_classes(dfa, out)?;
+    writeln!(out, "#[inline(always)]")?;
+    writeln!(
+        out,
+        "pub(super) fn {}(haystack: &[u8]) -> Option<({}, usize)> {{",
+        spec.fn_name, spec.enum_name,
+    )?;
+    // No `become` here, since this is inlined into its caller anyway.
+    writeln!(out, "  s{}(haystack, 0)", trav.gen_id(start))?;
+    writeln!(out, "}}")?;
+
+    while let Some(sid) = trav.dequeue() {
+        writeln!(out)?;
+        if tail_calls {
+            writeln!(out, "#[inline(never)]")?;
+        } else {
+            writeln!(out, "#[inline(always)]")?;
+        }
+        writeln!(
+            out,
+            "fn s{}(haystack: &[u8], at: usize) -> Option<({}, usize)> {{",
+            trav.gen_id(sid),
+            spec.enum_name,
+        )?;
+        write!(out, "  ")?;
+        match RustState::new(dfa, spec, sid) {
+            // Only the start state can get here, since transitions to match
+            // states report the match directly instead of calling a
+            // function. But for that, the start state would have to match
+            // the empty string, which no label does.
+            RustState::Match(m) => {
+                write!(out, "{}", m.to_rust(Pos::Var("at", 0)))?
+            }
+            RustState::Arms(arms) => {
+                let at = Pos::Var("at", 0);
+                arms.print(at, "", 1, out, &mut |next, out| {
+                    match RustState::new(dfa, spec, next) {
+                        RustState::Match(m) => {
+                            write!(out, "{}", m.to_rust(at.add(1)))?
+                        }
+                        RustState::Arms(_) => {
+                            trav.enqueue(next);
+                            write!(
+                                out,
+                                "{become_}s{}(haystack, at + 1)",
+                                trav.gen_id(next),
+                            )?;
+                        }
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+        writeln!(out)?;
+        writeln!(out, "}}")?;
+    }
+    Ok(())
+}
+
+/// Prints the DFA as a `loop` over a state variable, where every state is a
+/// labeled block. The state variable is a `u8` unless there are more than 256
+/// states.
+///
+/// This is yet another way of emulating the `goto` layout of `print_c_dfa`.
+/// Each state's block starts by checking whether it's the current state, and
+/// breaks out of itself (i.e., falls through to the next block) if it isn't.
+/// The states are written in topological order, so that every transition in
+/// our acyclic DFA goes to a block further down. Since the state assigned by
+/// a transition is a constant, LLVM can thread the jump straight to the block
+/// of the next state, skipping over all of the checks in between. A
+/// transition to an earlier state (which would need a cycle) still works, by
+/// falling through the rest of the blocks and going around the loop.
+fn print_rust_labeled_blocks_dfa(
+    dfa: &DFA<Vec<u32>>,
+    spec: &Spec,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let start =
+        dfa.start_state(&start::Config::new().anchored(Anchored::Yes))?;
+    let order = topological_order(dfa, spec, start);
+    let state_type = if order.len() <= 1 << 8 {
+        "u8"
+    } else if order.len() <= 1 << 16 {
+        "u16"
+    } else {
+        anyhow::bail!("DFA has {} states, which is too many", order.len())
+    };
+    let ids: HashMap<StateID, usize> =
+        order.iter().enumerate().map(|(i, &sid)| (sid, i)).collect();
+    let at = Pos::Var("at", 0);
+
+    spec.print_rust_enum(out)?;
+    writeln!(out)?;
+    print_rust_classes(dfa, out)?;
+    writeln!(out, "#[inline(always)]")?;
+    writeln!(
+        out,
+        "pub(super) fn {}(haystack: &[u8]) -> Option<({}, usize)> {{",
+        spec.fn_name, spec.enum_name,
+    )?;
+    writeln!(out, "  let mut state: {state_type} = 0;")?;
+    writeln!(out, "  let mut at = 0;")?;
+    writeln!(out, "  loop {{")?;
+    for &sid in order.iter() {
+        let id = ids[&sid];
+        writeln!(out, "    's{id}: {{")?;
+        writeln!(out, "      if state != {id} {{")?;
+        writeln!(out, "        break 's{id};")?;
+        writeln!(out, "      }}")?;
+        match RustState::new(dfa, spec, sid) {
+            // As in `print_rust_fn_dfa`, only the start state can get here.
+            RustState::Match(m) => {
+                writeln!(out, "      return {};", m.to_rust(at))?
+            }
+            // If every arm ends the search, then the whole `match` is the
+            // result. Otherwise, it's the next state and every arm that ends
+            // the search has to return explicitly.
+            RustState::Arms(arms) if arms.is_final(dfa, spec) => {
+                write!(out, "      return ")?;
+                arms.print(at, "", 3, out, &mut |next, out| {
+                    match RustState::new(dfa, spec, next) {
+                        RustState::Match(m) => {
+                            write!(out, "{}", m.to_rust(at.add(1)))?
+                        }
+                        RustState::Arms(_) => unreachable!(),
+                    }
+                    Ok(())
+                })?;
+                writeln!(out, ";")?;
+            }
+            RustState::Arms(arms) => {
+                write!(out, "      state = ")?;
+                arms.print(at, "return ", 3, out, &mut |next, out| {
+                    match RustState::new(dfa, spec, next) {
+                        RustState::Match(m) => {
+                            write!(out, "return {}", m.to_rust(at.add(1)))?
+                        }
+                        RustState::Arms(_) => write!(out, "{}", ids[&next])?,
+                    }
+                    Ok(())
+                })?;
+                writeln!(out, ";")?;
+                writeln!(out, "      at += 1;")?;
+            }
+        }
+        writeln!(out, "    }}")?;
+    }
+    writeln!(out, "  }}")?;
+    writeln!(out, "}}")?;
+    Ok(())
+}
+
+/// Returns every state reachable from `start` that has to look at the next
+/// byte (i.e., excluding states that always report the same match), in
+/// topological order, starting with `start`.
+///
+/// If the DFA has cycles, then the order is still one where as many
+/// transitions as possible go forward.
+fn topological_order(
+    dfa: &DFA<Vec<u32>>,
+    spec: &Spec,
+    start: StateID,
+) -> Vec<StateID> {
+    // This is a depth first search that records each state after all of its
+    // successors, which is the reverse of a topological order.
+    let mut seen = HashSet::new();
+    let mut postorder = vec![];
+    let mut stack = vec![(start, false)];
+    while let Some((sid, finished)) = stack.pop() {
+        if finished {
+            postorder.push(sid);
+            continue;
+        }
+        if !seen.insert(sid) {
+            continue;
+        }
+        stack.push((sid, true));
+        let RustState::Arms(arms) = RustState::new(dfa, spec, sid) else {
+            continue;
+        };
+        // Since the postorder is reversed at the end, pushing successors in
+        // the same order as the arms of the `match` that transitions to them
+        // makes them end up in that order in the generated code.
+        for &(_, next) in arms.bytes.iter() {
+            if matches!(RustState::new(dfa, spec, next), RustState::Arms(_)) {
+                stack.push((next, false));
+            }
+        }
+    }
+    postorder.reverse();
+    postorder
+}
+
+/// What to do in a single DFA state when generating Rust code that looks at
+/// the next byte with `haystack.get(at)`.
+enum RustState {
+    /// The state reports the same match no matter what comes next, so
+    /// there's no need to look at the next byte.
+    Match(RustMatch),
+    /// The state has to look at the next byte.
+    Arms(RustArms),
+}
+
+impl RustState {
+    fn new(dfa: &DFA<Vec<u32>>, spec: &Spec, sid: StateID) -> RustState {
+        let (trans, all_bytes_covered) =
+            StateTransitionIter::new(dfa, sid).into_map();
+        if trans.is_empty() {
+            // Just like in `print_rust_dfa`, a state without any transitions
+            // has to be a match state. Since we only get here by way of a
+            // non-dead transition, there's no need to bother with dead
+            // states.
+            assert!(
+                dfa.match_len(sid) > 0,
+                "expected {sid:?} with no transitions to be a match state"
+            );
+            return RustState::Match(RustMatch::leaf(dfa, spec, sid));
+        }
+
+        let eoi_next = dfa.next_eoi_state(sid);
+        let eoi = if is_dead(dfa, eoi_next) {
+            None
+        } else {
+            assert!(
+                dfa.is_match_state(eoi_next),
+                "expected all non-dead EOI transitions to map to a match",
+            );
+            Some(RustMatch::eoi(dfa, spec, sid))
+        };
+        // When every byte has an outgoing transition, one of them (usually
+        // the one for all bytes that can't continue a label) typically goes
+        // to a match state for the same label as the EOI transition. Both
+        // can then be folded into a single wildcard arm, which keeps the
+        // generated code much smaller. (Both matches end at the position of
+        // this state, so only the values have to be the same.)
+        let fallback = if all_bytes_covered {
+            trans.keys().copied().find(|&next| {
+                StateTransitionIter::new(dfa, next).next().is_none()
+                    && eoi.as_ref().is_some_and(|eoi| {
+                        RustMatch::leaf(dfa, spec, next).value == eoi.value
+                    })
+            })
+        } else {
+            None
+        };
+        // Just like the special case in `print_c_dfa`, if everything goes to
+        // the same match, then there's no need to look at the next byte at
+        // all.
+        if let (Some(eoi), Some(_), 1) = (&eoi, fallback, trans.len()) {
+            return RustState::Match(eoi.clone());
+        }
+        let bytes = trans
+            .into_iter()
+            .filter(|&(next, _)| Some(next) != fallback)
+            .map(|(next, ranges)| (ranges, next))
+            .collect();
+        RustState::Arms(RustArms {
+            bytes,
+            classes: has_classes(dfa),
+            eoi,
+            fallback: fallback.is_some(),
+            all_bytes_covered,
+        })
+    }
+}
+
+/// The arms of a `match` on the next byte (or the end of the haystack) in a
+/// single DFA state.
+struct RustArms {
+    /// The bytes with a transition to another state. This excludes the
+    /// transitions folded into the wildcard arm.
+    bytes: Vec<(Vec<ByteRange>, StateID)>,
+    /// Whether `bytes` are byte classes, i.e., whether to match on the class
+    /// of the next byte instead of the byte itself.
+    classes: bool,
+    /// The match reported by the EOI transition, if it isn't dead.
+    eoi: Option<RustMatch>,
+    /// Whether some byte transitions were folded into a wildcard arm along
+    /// with the EOI transition.
+    fallback: bool,
+    /// Whether every byte has an outgoing transition.
+    all_bytes_covered: bool,
+}
+
+impl RustArms {
+    /// Returns true if every arm ends the search, i.e., none of them
+    /// transition to a state that has to look at another byte.
+    fn is_final(&self, dfa: &DFA<Vec<u32>>, spec: &Spec) -> bool {
+        self.bytes.iter().all(|&(_, next)| {
+            matches!(RustState::new(dfa, spec, next), RustState::Match(_))
+        })
+    }
+
+    /// Prints the `match` expression for these arms, where `at` is the
+    /// position of the byte to look at (which is also the position of the
+    /// state with these arms) and `depth` is the level of indentation.
+    /// `next` prints the expression for a transition to the state given. `ret` is printed before the result of every arm that
+    /// ends the search (e.g., `return `), if any.
+    ///
+    /// Just like in `print_rust_tree_state`, this doesn't start with
+    /// indentation or end with a new line.
+    fn print(
+        self,
+        at: Pos,
+        ret: &str,
+        depth: usize,
+        out: &mut dyn Write,
+        next: &mut dyn FnMut(StateID, &mut dyn Write) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        let indent = "  ".repeat(depth);
+        let eoi = match self.eoi {
+            None => format!("{ret}None"),
+            Some(ref m) => format!("{ret}{}", m.to_rust(at)),
+        };
+        if self.classes {
+            writeln!(
+                out,
+                "match haystack.get({at}).map(|&byte| CLASSES[usize::from(byte)]) {{",
+            )?;
+        } else {
+            writeln!(out, "match haystack.get({at}).copied() {{")?;
+        }
+        for (ranges, sid) in self.bytes {
+            write!(out, "{indent}  Some(")?;
+            for (i, range) in ranges.into_iter().enumerate() {
+                if i > 0 {
+                    write!(out, " | ")?;
+                }
+                write!(out, "{range}")?;
+            }
+            write!(out, ") => ")?;
+            next(sid, out)?;
+            writeln!(out, ",")?;
+        }
+        if self.fallback {
+            writeln!(out, "{indent}  _ => {eoi},")?;
+        } else if self.all_bytes_covered && !self.classes {
+            writeln!(out, "{indent}  None => {eoi},")?;
+        } else if self.eoi.is_none() {
+            writeln!(out, "{indent}  _ => {ret}None,")?;
+        } else {
+            writeln!(out, "{indent}  None => {eoi},")?;
+            writeln!(out, "{indent}  _ => {ret}None,")?;
+        }
+        write!(out, "{indent}}}")?;
+        Ok(())
+    }
+}
+
+/// Prints the DFA as tables for the generic search loop in `src/dfa.rs`.
+///
+/// Every other backend turns the DFA into control flow. This one serializes
+/// it into a transition table instead, with one row per state and one column
+/// per byte class, in the same style as the tries in `trie4` and `trie5`. See
+/// `src/dfa.rs` for how the tables are laid out.
+fn print_rust_table_dfa(
+    dfa: &DFA<Vec<u32>>,
+    spec: &Spec,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let start =
+        dfa.start_state(&start::Config::new().anchored(Anchored::Yes))?;
+    let is_leaf =
+        |sid: StateID| StateTransitionIter::new(dfa, sid).next().is_none();
+
+    // Find every state reachable from the start state, split into states
+    // that end the search as soon as they're entered (the dead state and
+    // match states without any outgoing transitions) and everything else.
+    // Quit states (if any) share the row of the dead state.
+    let dead = StateID::ZERO;
+    assert!(dfa.is_dead_state(dead), "expected the dead state to come first");
+    let mut leaves = vec![];
+    let mut states = vec![];
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(sid) = queue.pop_front() {
+        if !seen.insert(sid) {
+            continue;
+        }
+        if is_dead(dfa, sid) {
+            continue;
+        }
+        if is_leaf(sid) {
+            assert!(
+                dfa.match_len(sid) > 0,
+                "expected {sid:?} with no transitions to be a match state"
+            );
+            leaves.push(sid);
+            continue;
+        }
+        states.push(sid);
+        let eoi_next = dfa.next_eoi_state(sid);
+        assert!(
+            is_dead(dfa, eoi_next) || is_leaf(eoi_next),
+            "expected all non-dead EOI transitions to map to a match",
+        );
+        queue.push_back(eoi_next);
+        for byte in 0..=255 {
+            queue.push_back(dfa.next_state(sid, byte));
+        }
+    }
+
+    // Two bytes can share a class if every state has the same transition
+    // for both of them. The classes are numbered in the order in which they
+    // first appear.
+    let mut classes = [0u8; 256];
+    let mut class_bytes: Vec<u8> = vec![];
+    let mut signatures: HashMap<Vec<StateID>, u8> = HashMap::new();
+    for byte in 0..=255u8 {
+        let signature: Vec<StateID> =
+            states.iter().map(|&sid| dfa.next_state(sid, byte)).collect();
+        let class = *signatures.entry(signature).or_insert_with(|| {
+            class_bytes.push(byte);
+            (class_bytes.len() - 1) as u8
+        });
+        classes[usize::from(byte)] = class;
+    }
+    let eoi = class_bytes.len();
+    let stride = (eoi + 1).next_power_of_two();
+    let stride2 = stride.trailing_zeros();
+
+    // The dead state comes first, then the match states, then everything
+    // else. State identifiers are premultiplied by the stride.
+    let order: Vec<StateID> = std::iter::once(dead)
+        .chain(leaves.iter().copied())
+        .chain(states.iter().copied())
+        .collect();
+    let ids: HashMap<StateID, usize> = order
+        .iter()
+        .enumerate()
+        .map(|(i, &sid)| (sid, i << stride2))
+        .collect();
+    let id = |sid: StateID| if is_dead(dfa, sid) { 0 } else { ids[&sid] };
+    let max_id = (order.len() - 1) << stride2;
+    let state_type = if max_id <= usize::from(u8::MAX) {
+        "u8"
+    } else if max_id <= usize::from(u16::MAX) {
+        "u16"
+    } else if max_id <= u32::MAX as usize {
+        "u32"
+    } else {
+        anyhow::bail!("DFA is too big, its largest state ID is {max_id}")
+    };
+
+    if spec.define_enum {
+        writeln!(out, "use crate::dfa::Dfa;")?;
+        writeln!(out)?;
+        spec.print_rust_enum(out)?;
+    } else {
+        writeln!(out, "use crate::{{dfa::Dfa, {}}};", spec.enum_name)?;
+    }
+    writeln!(out)?;
+    writeln!(
+        out,
+        "pub(super) static {}: Dfa<{state_type}, {}, {}, {}> = Dfa {{",
+        spec.fn_name,
+        order.len() * stride,
+        1 + leaves.len(),
+        spec.enum_name,
+    )?;
+    writeln!(out, "  classes: [")?;
+    for chunk in classes.chunks(16) {
+        print_rust_table_row(chunk, out)?;
+    }
+    writeln!(out, "  ],")?;
+    writeln!(out, "  transitions: [")?;
+    for &sid in order.iter() {
+        let mut row = vec![0; stride];
+        // The rows of the dead state and the match states are never used,
+        // since the search stops as soon as one of them is entered. So they
+        // just transition to the dead state.
+        if sid != dead && !leaves.contains(&sid) {
+            for (class, &byte) in class_bytes.iter().enumerate() {
+                row[class] = id(dfa.next_state(sid, byte));
+            }
+            row[eoi] = id(dfa.next_eoi_state(sid));
+        }
+        writeln!(out, "    // {}", ids[&sid])?;
+        for chunk in row.chunks(16) {
+            print_rust_table_row(chunk, out)?;
+        }
+    }
+    writeln!(out, "  ],")?;
+    writeln!(out, "  matches: [")?;
+    writeln!(out, "    None,")?;
+    // The search loop computes where each match ends, since a match state
+    // can be entered at more than one position.
+    for &sid in leaves.iter() {
+        let m = RustMatch::leaf(dfa, spec, sid);
+        writeln!(out, "    Some({}),", m.value)?;
+    }
+    writeln!(out, "  ],")?;
+    writeln!(out, "  start: {},", ids[&start])?;
+    writeln!(out, "  max_match: {},", leaves.len() << stride2)?;
+    writeln!(out, "  stride2: {stride2},")?;
+    writeln!(out, "  eoi: {eoi},")?;
+    writeln!(out, "}};")?;
+    Ok(())
+}
+
+/// Prints a single line of numbers in an array literal.
+fn print_rust_table_row<T: std::fmt::Display>(
+    row: &[T],
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    write!(out, "   ")?;
+    for n in row {
+        write!(out, " {n},")?;
+    }
+    writeln!(out)?;
+    Ok(())
+}
+
+fn print_c_dfa(
+    dfa: &DFA<Vec<u32>>,
+    spec: &Spec,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let mut trav = TraversalState::default();
+
+    let start =
+        dfa.start_state(&start::Config::new().anchored(Anchored::Yes))?;
+    trav.enqueue(start);
+
+    writeln!(out, "#include <stddef.h>")?;
+    writeln!(out, "#include <stdint.h>")?;
+    writeln!(out)?;
+    // The C enum has to have the same discriminants as the Rust enum, since
+    // the Rust code calling this reads it as the Rust enum. (The Rust enum
+    // has to be `#[repr(C)]` too.)
+    let values = spec.labels.values();
+    let enum_name = spec.enum_name.to_ascii_lowercase();
+    writeln!(out, "enum {enum_name} {{")?;
+    for &(value, discriminant) in values.iter() {
+        writeln!(out, "  {value} = {discriminant},")?;
+    }
+    writeln!(out, "}};")?;
+    writeln!(out)?;
+    writeln!(out, "struct output {{")?;
+    writeln!(out, "  enum {enum_name} unit;")?;
+    writeln!(out, "  size_t length;")?;
+    writeln!(out, "}};")?;
+    writeln!(out)?;
+    if has_classes(dfa) {
+        let classes = dfa.byte_classes();
+        writeln!(out, "static const uint8_t classes[256] = {{")?;
+        for chunk in (0..=255).collect::<Vec<u8>>().chunks(16) {
+            write!(out, "  ")?;
+            for &byte in chunk {
+                write!(out, " {},", classes.get(byte))?;
+            }
+            writeln!(out)?;
+        }
+        writeln!(out, "}};")?;
+        writeln!(out)?;
+    }
+    writeln!(out, "struct output {}(uint8_t *p, uint8_t *end)", spec.fn_name,)?;
+    writeln!(out, "{{")?;
+    writeln!(
+        out,
+        "  struct output o = {{ .unit = {}, .length = 0 }};",
+        values[0].0,
+    )?;
+    // Where a match ends is computed from where the search started if it
+    // isn't fixed.
+    if !spec.fixed_positions() {
+        writeln!(out, "  uint8_t *start = p;")?;
+    }
+    // Prints the match reported by `sid` in the state at `pos`, which ends
+    // one byte before `pos` if `sid` is a state without any transitions.
+    // (When it's entered by a byte, `p` already points past that byte.)
+    let print_match = |indent: &str,
+                       sid: StateID,
+                       pos: Option<usize>,
+                       leaf: bool,
+                       out: &mut dyn Write|
+     -> anyhow::Result<()> {
+        let label = spec.labels.get(dfa.match_pattern(sid, 0).as_usize());
+        let length = match (pos, leaf) {
+            (Some(pos), false) => pos.to_string(),
+            (Some(pos), true) => (pos - 1).to_string(),
+            (None, false) => "p - start".to_string(),
+            (None, true) => "p - start - 1".to_string(),
+        };
+        writeln!(out, "{indent}o.unit = {};", label.value)?;
+        writeln!(out, "{indent}o.length = {length};")?;
+        Ok(())
+    };
+    // With cycles, the start state might be entered again.
+    let start_entered = spec.positions.keys().any(|&sid| {
+        StateTransitionIter::new(dfa, sid).any(|trans| trans.next == start)
+    });
+
+    while let Some(sid) = trav.dequeue() {
+        let (trans, all_bytes_covered) =
+            StateTransitionIter::new(dfa, sid).into_map();
+        let eoi_next = dfa.next_eoi_state(sid);
+
+        // When every label is a literal, our DFA is always a directed acyclic
+        // word graph (DAWG), so we'll never come back to the start state.
+        // Thus, the start state usually needs no label. (It's harmless, but
+        // if we write it, we get a warning about an unused label. So let's
+        // be tidy.)
+        if sid != start || start_entered {
+            writeln!(out, "S{}:", trav.gen_id(sid))?;
+        }
+
+        // As a special case, if all bytes have outgoing transitions and all
+        // of those transitions point to the same state as an outgoing EOI
+        // transition, then we can skip the bound check and byte matching and
+        // just jump straight to the end.
+        if all_bytes_covered && trans.iter().all(|(&next, _)| next == eoi_next)
+        {
+            if dfa.is_match_state(eoi_next) {
+                print_match("  ", eoi_next, spec.positions[&sid], false, out)?;
+            }
+            writeln!(out, "  goto DONE;")?;
+            continue;
+        }
+
+        if trans.is_empty() {
+            // Just like in `print_rust_dfa`, any state with no outgoing
+            // transitions has to be a sentinel for a match state. And just
+            // like in `RustMatch`, we ignore any matches past the first.
+            assert!(
+                dfa.match_len(sid) > 0,
+                "expected {sid:?} with no transitions to be a match state"
+            );
+            print_match("  ", sid, spec.positions[&sid], true, out)?;
+            writeln!(out, "  goto DONE;")?;
+        } else {
+            writeln!(out, "  if (p >= end) {{")?;
+            if is_dead(dfa, eoi_next) {
+                writeln!(out, "    goto DONE;")?;
+            } else if spec.positions[&eoi_next].is_none() {
+                // The match state's code assumes it was entered by a byte,
+                // which isn't true here. So report the match directly.
+                let pos = spec.positions[&sid];
+                print_match("    ", eoi_next, pos, false, out)?;
+                writeln!(out, "    goto DONE;")?;
+            } else {
+                trav.enqueue(eoi_next);
+                writeln!(out, "    goto S{};", trav.gen_id(eoi_next))?;
+            }
+            writeln!(out, "  }}")?;
+
+            if has_classes(dfa) {
+                writeln!(out, "  switch (classes[*p++]) {{")?;
+            } else {
+                writeln!(out, "  switch (*p++) {{")?;
+            }
+            for (next, ranges) in trans {
+                trav.enqueue(next);
+                for range in ranges {
+                    writeln!(
+                        out,
+                        "    case {range}: goto S{next};",
+                        range = range.to_c(),
+                        next = trav.gen_id(next),
+                    )?;
+                }
+            }
+            if !all_bytes_covered {
+                writeln!(out, "    default: goto DONE;")?;
+            }
+            writeln!(out, "  }}")?;
+        }
+    }
+
+    writeln!(out, "DONE:")?;
+    writeln!(out, "  return o;")?;
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
+
+/// Some state grouped together for facilitating the traversal of a DFA.
+#[derive(Debug, Default)]
+struct TraversalState {
+    /// A map from regex-automata's DFA state ID to the state IDs used in the
+    /// generated code. The generated code state IDs are contiguous starting
+    /// from zero and have no special significance. The regex-automata DFA
+    /// state IDs are not contiguous (because they are pre-multiplified).
+    state_ids: HashMap<StateID, usize>,
+    /// States that have an outgoing EOI transition.
+    ///
+    /// For our particular use case, these EOI transitions always map to
+    /// match states.
+    eois: Vec<StateID>,
+    /// Match states, i.e., states with no outgoing transitions that report
+    /// a match as soon as they are entered.
+    matches: Vec<StateID>,
+    /// The queue of states to visit. Pushing to this queue is guarded by
+    /// checking `seen` if the state has been visited.
+    queue: VecDeque<StateID>,
+    /// The set of state IDs we've visited. No need to visit a state more than
+    /// once.
+    seen: HashSet<StateID>,
+}
+
+impl TraversalState {
+    /// Push the given state on to the queue of states to visit.
+    ///
+    /// This is a no-op if the given state ID has been pushed to the queue
+    /// before (thus, a state can never be revisited).
+    fn enqueue(&mut self, sid: StateID) {
+        if self.seen.insert(sid) {
+            self.gen_id(sid);
+            self.queue.push_back(sid);
+        }
+    }
+
+    /// Pop a state ID from the queue.
+    fn dequeue(&mut self) -> Option<StateID> {
+        self.queue.pop_front()
+    }
+
+    /// Return the ID of the given state for use in the generated code.
+    ///
+    /// If one doesn't exist for the state given, then one is generated.
+    ///
+    /// This maintains a contiguous sequence of state IDs for use in the
+    /// generated code.
+    fn gen_id(&mut self, sid: StateID) -> usize {
+        if let Some(&id) = self.state_ids.get(&sid) {
+            return id;
+        }
+        let id = self.state_ids.len();
+        self.state_ids.insert(sid, id);
+        id
+    }
+}
+
+/// Copied from `regex-automata`. This is used to print the `Debug`
+/// representation of a DFA in `regex-automata`. We adapt it slightly to
+/// iterate over distinct byte values instead of the actual transitions in the
+/// state (which should be equivalent).
+///
+/// If the DFA was built with byte classes, then this iterates over classes
+/// instead, and the ranges yielded are ranges of classes.
+///
+/// This does not include the EOI transition, which we handle specially.
+#[derive(Debug)]
+struct StateTransitionIter<'a> {
+    dfa: &'a DFA<Vec<u32>>,
+    sid: StateID,
+    alphabet: RangeInclusive<u8>,
+    /// The smallest byte in every class, indexed by class. This is empty
+    /// if the DFA doesn't have byte classes.
+    representatives: Vec<u8>,
+    cur: Option<ContiguousTransitions>,
+}
+
+impl<'a> StateTransitionIter<'a> {
+    fn new(dfa: &'a DFA<Vec<u32>>, sid: StateID) -> StateTransitionIter<'a> {
+        let mut representatives = vec![];
+        if has_classes(dfa) {
+            for byte in 0..=255 {
+                let class = usize::from(dfa.byte_classes().get(byte));
+                if class == representatives.len() {
+                    representatives.push(byte);
+                }
+            }
+        }
+        let alphabet = match representatives.len() {
+            0 => 0..=255,
+            len => 0..=u8::try_from(len - 1).unwrap(),
+        };
+        StateTransitionIter { dfa, sid, alphabet, representatives, cur: None }
+    }
+
+    fn into_map(self) -> (BTreeMap<StateID, Vec<ByteRange>>, bool) {
+        let mut map: BTreeMap<StateID, Vec<ByteRange>> = BTreeMap::new();
+        let class = !self.representatives.is_empty();
+        let mut covered = vec![false; usize::from(*self.alphabet.end()) + 1];
+        for ContiguousTransitions { start, end, next } in self {
+            for unit in start..=end {
+                covered[usize::from(unit)] = true;
+            }
+            map.entry(next).or_default().push(ByteRange { start, end, class });
+        }
+        (map, covered.into_iter().all(|covered| covered))
+    }
+}
+
+impl<'a> Iterator for StateTransitionIter<'a> {
+    type Item = ContiguousTransitions;
+
+    #[allow(clippy::while_let_on_iterator)]
+    fn next(&mut self) -> Option<ContiguousTransitions> {
+        while let Some(byte) = self.alphabet.next() {
+            // The transitions of a class are the transitions of any byte in
+            // it.
+            let next = match self.representatives.get(usize::from(byte)) {
+                Some(&rep) => self.dfa.next_state(self.sid, rep),
+                None => self.dfa.next_state(self.sid, byte),
+            };
+            let prev = match self.cur {
+                Some(prev) => prev,
+                None => {
+                    self.cur = Some(ContiguousTransitions {
+                        start: byte,
+                        end: byte,
+                        next,
+                    });
+                    continue;
+                }
+            };
+            if prev.next == next {
+                self.cur = Some(ContiguousTransitions { end: byte, ..prev });
+            } else {
+                self.cur = Some(ContiguousTransitions {
+                    start: byte,
+                    end: byte,
+                    next,
+                });
+                if !is_dead(self.dfa, prev.next) {
+                    return Some(prev);
+                }
+            }
+        }
+        if let Some(trans) = self.cur.take() {
+            if !is_dead(self.dfa, trans.next) {
+                return Some(trans);
+            }
+        }
+        None
+    }
+}
+
+/// A helper type for formatting the match reported by a match state as a
+/// Rust expression.
+#[derive(Clone, Debug)]
+struct RustMatch {
+    /// The path to the enum variant reported, e.g., `Unit::Day`.
+    value: String,
+    /// The position of the state reporting the match, if it's fixed.
+    pos: Option<usize>,
+    /// Whether the match ends one byte before the position of the state
+    /// reporting it, instead of right at it. See `positions`.
+    leaf: bool,
+}
+
+impl RustMatch {
+    /// Returns the match reported by entering the state given, which has to
+    /// be a state without any transitions.
+    fn leaf(dfa: &DFA<Vec<u32>>, spec: &Spec, sid: StateID) -> RustMatch {
+        RustMatch {
+            value: RustMatch::value(dfa, spec, sid),
+            pos: spec.positions[&sid],
+            leaf: true,
+        }
+    }
+
+    /// Returns the match reported by the EOI transition of the state given.
+    fn eoi(dfa: &DFA<Vec<u32>>, spec: &Spec, sid: StateID) -> RustMatch {
+        RustMatch {
+            value: RustMatch::value(dfa, spec, dfa.next_eoi_state(sid)),
+            pos: spec.positions[&sid],
+            leaf: false,
+        }
+    }
+
+    fn value(dfa: &DFA<Vec<u32>>, spec: &Spec, sid: StateID) -> String {
+        // We ignore any matches past the first. If there were multiple, that
+        // would imply two identical literal labels (which `LabelSet` rejects)
+        // or two regexes matching the same string, in which case the first
+        // one wins.
+        let label = spec.labels.get(dfa.match_pattern(sid, 0).as_usize());
+        format!("{}::{}", spec.enum_name, label.value)
+    }
+
+    /// Returns the match as a Rust expression, where `pos` is the position
+    /// of the state reporting it in the generated code. (It's only used if
+    /// the position isn't fixed.)
+    fn to_rust(&self, pos: Pos) -> String {
+        let pos = self.pos.map_or(pos, Pos::Const);
+        let end = if self.leaf { pos.add(-1) } else { pos };
+        format!("Some(({}, {end}))", self.value)
+    }
+}
+
+/// A helper type for formatting a position in the haystack in Rust source,
+/// either as a constant or as a variable plus a constant.
+#[derive(Clone, Copy, Debug)]
+enum Pos<'a> {
+    Const(usize),
+    Var(&'a str, isize),
+}
+
+impl<'a> Pos<'a> {
+    fn add(self, n: isize) -> Pos<'a> {
+        match self {
+            Pos::Const(pos) => Pos::Const(pos.checked_add_signed(n).unwrap()),
+            Pos::Var(var, pos) => Pos::Var(var, pos + n),
+        }
+    }
+}
+
+impl<'a> std::fmt::Display for Pos<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Pos::Const(pos) => write!(f, "{pos}"),
+            Pos::Var(var, 0) => write!(f, "{var}"),
+            Pos::Var(var, n) if n > 0 => write!(f, "{var} + {n}"),
+            Pos::Var(var, n) => write!(f, "{var} - {}", -n),
+        }
+    }
+}
+
+/// A helper type for formatting a single contiguous range of bytes as a
+/// range in a Rust `match` expression.
+///
+/// When `class` is true, the range is a range of byte classes instead, which
+/// are written as plain numbers.
+#[derive(Clone, Copy)]
+struct ByteRange {
+    start: u8,
+    end: u8,
+    class: bool,
+}
+
+impl ByteRange {
+    fn to_c(self) -> CByteRange {
+        CByteRange(self)
+    }
+}
+
+impl std::fmt::Display for ByteRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (start, end) = (self.start, self.end);
+        match (self.class, start == end) {
+            (true, true) => write!(f, "{start}"),
+            (true, false) => write!(f, "{start}..={end}"),
+            (false, true) => std::fmt::Display::fmt(&ByteLiteral(start), f),
+            (false, false) => {
+                write!(f, "{}..={}", ByteLiteral(start), ByteLiteral(end))
+            }
+        }
+    }
+}
+
+/// A helper type for formatting a single contiguous range of bytes as a
+/// range in a C `case` block (part of a `switch` statement).
+struct CByteRange(ByteRange);
+
+impl std::fmt::Display for CByteRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let ByteRange { start, end, class } = self.0;
+        match (class, start == end) {
+            (true, true) => write!(f, "{start}"),
+            (true, false) => write!(f, "{start} ... {end}"),
+            (false, true) => std::fmt::Display::fmt(&CByteLiteral(start), f),
+            (false, false) => {
+                write!(f, "{} ... {}", CByteLiteral(start), CByteLiteral(end))
+            }
+        }
+    }
+}
+
+/// A helper type for formatting a byte literal in Rust source.
+///
+/// We could just always use hex escapes, but I like using the actual
+/// ASCII characters for printable characters since it makes reading the
+/// generated code easier.
+struct ByteLiteral(u8);
+
+impl std::fmt::Display for ByteLiteral {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "b'")?;
+        for escaped_byte in std::ascii::escape_default(self.0) {
+            write!(f, "{}", escaped_byte as char)?;
+        }
+        write!(f, "'")?;
+        Ok(())
+    }
+}
+
+/// A helper type for formatting a byte literal in C source.
+struct CByteLiteral(u8);
+
+impl std::fmt::Display for CByteLiteral {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.0.is_ascii_alphabetic() {
+            write!(f, "'{}'", self.0 as char)
+        } else {
+            write!(f, "0x{:02x}", self.0)
+        }
+    }
+}
+
+/// A helper type for combining a range of bytes (inclusive) with its
+/// corresponding outgoing transition state ID.
+#[derive(Clone, Copy, Debug)]
+struct ContiguousTransitions {
+    start: u8,
+    end: u8,
+    next: StateID,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::{Path, PathBuf},
+        process::Command,
+    };
+
+    use labelset::Label;
+
+    use super::*;
+
+    /// Labels whose DFA has cycles, which mixes them with literal-like
+    /// patterns and inline case insensitivity.
+    const CYCLIC: &[(&str, &str, i64)] = &[
+        (r"[0-9]+\s*(y|yrs?)", "Year", 9),
+        (r"[0-9]+\s*(h|hrs?)", "Hour", 5),
+        ("mins?", "Minute", 4),
+        ("(?i)hours?", "Hour", 5),
+        ("m", "Minute", 4),
+    ];
+
+    /// Like `CYCLIC`, but without any cycles, so that every backend works.
+    const ACYCLIC: &[(&str, &str, i64)] = &[
+        ("mins?", "Minute", 4),
+        ("(?i)hours?", "Hour", 5),
+        ("m", "Minute", 4),
+        ("y|yrs?", "Year", 9),
+        ("µs", "Microsecond", 1),
+    ];
+
+    /// Inputs that are checked in addition to every string of up to 4 bytes
+    /// in `ALPHABET`.
+    const INPUTS: &[&str] = &[
+        "12 yrs",
+        "12yrs",
+        "123 y",
+        "5 \t yr",
+        "5\u{2003}y",
+        "1 yrsx",
+        "1 yrs 2 hrs",
+        "99999 h",
+        "hOuRs",
+        "HOURS5",
+        "mins ",
+        "minsx",
+        "mix",
+        "µs",
+        "µsx",
+        "µ",
+        "Minutes",
+    ];
+
+    /// The bytes every short input is made of.
+    const ALPHABET: &[u8] = b"1 yrsminhoHOUx\t\xC2\xB5";
+
+    /// Generates every Rust backend (except tail calls, which need a nightly
+    /// compiler) and the C backend from patterns with cycles and case
+    /// insensitivity, compiles the code and checks that it agrees with a
+    /// DFA built by `regex-automata` on a bunch of inputs.
+    #[test]
+    fn backends() {
+        let cyclic = labels(CYCLIC);
+        let acyclic = labels(ACYCLIC);
+        let dir = std::env::temp_dir()
+            .join(format!("gendfa-emit-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let backends = [
+            (Backend::Rust, false),
+            (Backend::Rust, true),
+            (Backend::RustTree, false),
+            (Backend::RustFn { tail_calls: false }, false),
+            (Backend::RustLabeledBlocks, false),
+            (Backend::RustTable, false),
+            (Backend::C, false),
+            (Backend::C, true),
+        ];
+        for (i, &(backend, byte_classes)) in backends.iter().enumerate() {
+            let acyclic_only = matches!(
+                backend,
+                Backend::RustTree | Backend::RustFn { tail_calls: false }
+            );
+            for ascii_case_insensitive in [false, true] {
+                let config = Config::new(backend)
+                    .byte_classes(byte_classes)
+                    .ascii_case_insensitive(ascii_case_insensitive)
+                    .regex(true)
+                    .define_enum(true);
+                // The default name of the C function depends on the case
+                // sensitivity, so use the same one for both.
+                let config = if backend == Backend::C {
+                    config.fn_name("find")
+                } else {
+                    config
+                };
+                let name = format!(
+                    "{backend:?}-{byte_classes}-{ascii_case_insensitive}"
+                );
+                if acyclic_only {
+                    let result = config.generate(&cyclic, &mut vec![]);
+                    assert!(result.is_err(), "{name} accepted a cyclic DFA");
+                } else {
+                    let dir = dir
+                        .join(format!("{i}-{ascii_case_insensitive}-cyclic"));
+                    check(&dir, &name, &config, &cyclic);
+                }
+                let dir =
+                    dir.join(format!("{i}-{ascii_case_insensitive}-acyclic"));
+                check(&dir, &name, &config, &acyclic);
+            }
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn labels(labels: &[(&str, &str, i64)]) -> LabelSet {
+        LabelSet::new(
+            labels
+                .iter()
+                .map(|&(label, value, discriminant)| Label {
+                    label: label.to_string(),
+                    value: value.to_string(),
+                    discriminant,
+                })
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    /// Generates the code for `labels` with the configuration given, runs it
+    /// on every input and compares its results with `expected`.
+    fn check(dir: &Path, name: &str, config: &Config, labels: &LabelSet) {
+        std::fs::create_dir_all(dir).unwrap();
+        let mut inputs: Vec<Vec<u8>> =
+            INPUTS.iter().map(|input| input.as_bytes().to_vec()).collect();
+        let mut short: Vec<Vec<u8>> = vec![vec![]];
+        for _ in 0..4 {
+            short = short
+                .iter()
+                .flat_map(|prefix| {
+                    ALPHABET.iter().map(move |&byte| {
+                        let mut input = prefix.clone();
+                        input.push(byte);
+                        input
+                    })
+                })
+                .collect();
+            inputs.extend(short.iter().cloned());
+        }
+        inputs.push(vec![]);
+        std::fs::write(dir.join("inputs"), inputs.join(&b'\n')).unwrap();
+
+        let mut code = vec![];
+        config.generate(labels, &mut code).unwrap();
+        let binary = if config.backend == Backend::C {
+            compile_c(dir, code)
+        } else {
+            compile_rust(dir, config, code)
+        };
+        let output =
+            Command::new(&binary).arg(dir.join("inputs")).output().unwrap();
+        assert!(output.status.success(), "{name} failed to run");
+        let got = String::from_utf8(output.stdout).unwrap();
+
+        let expected = Expected::new(config, labels);
+        for (input, got) in inputs.iter().zip(got.lines()) {
+            assert_eq!(
+                got,
+                expected.find(input),
+                "{name} on {:?}",
+                input.escape_ascii().to_string(),
+            );
+        }
+        assert_eq!(got.lines().count(), inputs.len(), "{name}");
+    }
+
+    /// Compiles the generated Rust code into a program that runs it on every
+    /// line of a file, printing `{discriminant} {length}` or `None`.
+    fn compile_rust(dir: &Path, config: &Config, code: Vec<u8>) -> PathBuf {
+        let find = if config.backend == Backend::RustTable {
+            "generated::DFA.find(input)"
+        } else {
+            "generated::find(input)"
+        };
+        let dfa = Path::new(env!("CARGO_MANIFEST_DIR")).join("../src/dfa.rs");
+        let main = format!(
+            "#![allow(dead_code)]\n\
+             #[path = {dfa:?}]\n\
+             mod dfa;\n\
+             mod generated;\n\
+             fn main() {{\n\
+             let path = std::env::args().nth(1).unwrap();\n\
+             let inputs = std::fs::read(path).unwrap();\n\
+             for input in inputs.split(|&b| b == b'\\n') {{\n\
+             match {find} {{\n\
+             Some((value, len)) => println!(\"{{}} {{len}}\", value as i64),\n\
+             None => println!(\"None\"),\n\
+             }}\n\
+             }}\n\
+             }}\n",
+        );
+        std::fs::write(dir.join("main.rs"), main).unwrap();
+        std::fs::write(dir.join("generated.rs"), code).unwrap();
+        let binary = dir.join("main");
+        let rustc = std::env::var("RUSTC").unwrap_or("rustc".to_string());
+        let status = Command::new(rustc)
+            .args(["--edition", "2021", "-o"])
+            .arg(&binary)
+            .arg(dir.join("main.rs"))
+            .status()
+            .unwrap();
+        assert!(status.success(), "failed to compile {}", dir.display());
+        binary
+    }
+
+    /// Like `compile_rust`, but for the generated C code.
+    fn compile_c(dir: &Path, code: Vec<u8>) -> PathBuf {
+        let main = "\
+            #include <stdio.h>\n\
+            #include <stdlib.h>\n\
+            #include \"generated.c\"\n\
+            int main(int argc, char **argv) {\n\
+            FILE *f = fopen(argv[1], \"rb\");\n\
+            uint8_t *buf = malloc(1 << 24);\n\
+            size_t len = fread(buf, 1, 1 << 24, f);\n\
+            uint8_t *start = buf, *end = buf + len;\n\
+            for (uint8_t *p = buf; p <= end; p++) {\n\
+            if (p < end && *p != '\\n') continue;\n\
+            struct output o = find(start, p);\n\
+            if (o.length == 0) printf(\"None\\n\");\n\
+            else printf(\"%d %zu\\n\", (int)o.unit, o.length);\n\
+            start = p + 1;\n\
+            }\n\
+            return 0;\n\
+            }\n";
+        std::fs::write(dir.join("main.c"), main).unwrap();
+        std::fs::write(dir.join("generated.c"), code).unwrap();
+        let binary = dir.join("main");
+        let cc = std::env::var("CC").unwrap_or("cc".to_string());
+        let status = Command::new(cc)
+            .arg("-o")
+            .arg(&binary)
+            .arg(dir.join("main.c"))
+            .status()
+            .unwrap();
+        assert!(status.success(), "failed to compile {}", dir.display());
+        binary
+    }
+
+    /// What the generated code is expected to report, which is computed by
+    /// walking a DFA built by `regex-automata` from the same patterns.
+    ///
+    /// Unlike the DFA the generated code comes from, this one isn't
+    /// minimized and uses the default configuration otherwise.
+    struct Expected<'a> {
+        dfa: DFA<Vec<u32>>,
+        labels: &'a LabelSet,
+    }
+
+    impl<'a> Expected<'a> {
+        fn new(config: &Config, labels: &'a LabelSet) -> Expected<'a> {
+            let patterns: Vec<&str> =
+                labels.labels().iter().map(|l| l.label.as_str()).collect();
+            let syntax = syntax::Config::new()
+                .case_insensitive(config.ascii_case_insensitive)
+                .unicode(!config.ascii_case_insensitive);
+            let dfa = DFA::builder()
+                .configure(
+                    DFA::config()
+                        .start_kind(StartKind::Anchored)
+                        .match_kind(MatchKind::All),
+                )
+                .syntax(syntax)
+                .build_many(&patterns)
+                .unwrap();
+            Expected { dfa, labels }
+        }
+
+        /// Returns what the generated code prints for the input given.
+        ///
+        /// The search stops at the first dead state. A match is reported
+        /// when it enters a match state that can't go anywhere else, in
+        /// which case the match ends right before the last byte (since
+        /// matches are delayed by one byte), or when the input ends in a
+        /// state whose EOI transition is a match.
+        fn find(&self, input: &[u8]) -> String {
+            let dfa = &self.dfa;
+            let start = start::Config::new().anchored(Anchored::Yes);
+            let mut sid = dfa.start_state(&start).unwrap();
+            for (at, &byte) in input.iter().enumerate() {
+                sid = dfa.next_state(sid, byte);
+                if dfa.is_dead_state(sid) || dfa.is_quit_state(sid) {
+                    return "None".to_string();
+                }
+                let leaf = (0..=255).all(|byte| {
+                    let next = dfa.next_state(sid, byte);
+                    dfa.is_dead_state(next) || dfa.is_quit_state(next)
+                });
+                if leaf {
+                    assert!(dfa.is_match_state(sid));
+                    return self.report(sid, at);
+                }
+            }
+            let eoi = dfa.next_eoi_state(sid);
+            if dfa.is_match_state(eoi) {
+                return self.report(eoi, input.len());
+            }
+            "None".to_string()
+        }
+
+        fn report(&self, sid: StateID, len: usize) -> String {
+            let pattern = self.dfa.match_pattern(sid, 0).as_usize();
+            format!("{} {len}", self.labels.get(pattern).discriminant)
+        }
+    }
+}
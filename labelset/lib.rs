@@ -0,0 +1,515 @@
+/*!
+The set of labels recognized by the code generators in this repository.
+
+By default, the generators recognize the unit designator labels, which are
+defined here by [`designator_labels!`] so that both the generators and
+`duration_unit_lookup` itself can use them without depending on each other.
+But the generators can also read a label set from a file, which makes it
+possible to generate recognizers for other things, like month names, weekday
+names or keywords. Every label in a file maps to a value, which is a variant
+of the enum the generated code returns. Each value has a discriminant, so that
+the Rust and C versions of the enum agree.
+
+Three formats are supported, chosen by the file's extension. TSV files
+(`.tsv`) have one label per line, with the label, value name and discriminant
+separated by tabs (written as `\t` below). Empty lines and lines starting with
+`#` are ignored:
+
+```text
+# label\tvalue\tdiscriminant
+january\tJanuary\t1
+jan\tJanuary\t1
+```
+
+JSON (`.json`) and TOML (`.toml`) files have a `labels` array of objects with
+`label`, `value` and `discriminant` keys:
+
+```toml
+[[labels]]
+label = "january"
+value = "January"
+discriminant = 1
+```
+
+Reading files needs the `files` feature, which is enabled by default.
+*/
+
+use std::io::Write;
+#[cfg(feature = "files")]
+use std::path::Path;
+
+/// Invokes the macro given with every unit designator label and the name of
+/// the `Unit` variant it maps to.
+///
+/// This is the one and only place where the set of supported labels is
+/// written down. Techniques that can consume a table at compile time (like
+/// the tries) or at runtime (like Aho-Corasick) use
+/// `duration_unit_lookup::LABELS`, which is built from this. But some
+/// techniques need each label to appear as a literal in source code, e.g.,
+/// as a pattern in a `match` expression or as a key in `phf_map!`. Those
+/// techniques use this macro instead, by passing the name of a local macro
+/// that expands the labels into whatever shape they need. The generators use
+/// it too, via [`LabelSet::designators`].
+///
+/// The input given to the callback macro is a sequence of entries of the
+/// form `b"label" => Variant,`. The labels are byte string literals so that
+/// they can be used directly as patterns when matching on a `&[u8]`.
+///
+/// The order of the entries is significant in one respect:
+/// `duration_unit_lookup::LABELS` uses the same order, and so does anything
+/// that builds an automaton from it where pattern IDs are assigned
+/// sequentially.
+#[macro_export]
+macro_rules! designator_labels {
+    ($callback:ident) => {
+        $callback! {
+            b"years" => Year,
+            b"year" => Year,
+            b"yrs" => Year,
+            b"yr" => Year,
+            b"y" => Year,
+            b"months" => Month,
+            b"month" => Month,
+            b"mos" => Month,
+            b"mo" => Month,
+            b"weeks" => Week,
+            b"week" => Week,
+            b"wks" => Week,
+            b"wk" => Week,
+            b"w" => Week,
+            b"days" => Day,
+            b"day" => Day,
+            b"d" => Day,
+            b"hours" => Hour,
+            b"hour" => Hour,
+            b"hrs" => Hour,
+            b"hr" => Hour,
+            b"h" => Hour,
+            b"minutes" => Minute,
+            b"minute" => Minute,
+            b"mins" => Minute,
+            b"min" => Minute,
+            b"m" => Minute,
+            b"seconds" => Second,
+            b"second" => Second,
+            b"secs" => Second,
+            b"sec" => Second,
+            b"s" => Second,
+            b"milliseconds" => Millisecond,
+            b"millisecond" => Millisecond,
+            b"millis" => Millisecond,
+            b"milli" => Millisecond,
+            b"msecs" => Millisecond,
+            b"msec" => Millisecond,
+            b"ms" => Millisecond,
+            b"microseconds" => Microsecond,
+            b"microsecond" => Microsecond,
+            b"micros" => Microsecond,
+            b"micro" => Microsecond,
+            b"usecs" => Microsecond,
+            b"usec" => Microsecond,
+            b"\xC2\xB5secs" => Microsecond,
+            b"\xC2\xB5sec" => Microsecond,
+            b"us" => Microsecond,
+            b"\xC2\xB5s" => Microsecond,
+            b"nanoseconds" => Nanosecond,
+            b"nanosecond" => Nanosecond,
+            b"nanos" => Nanosecond,
+            b"nano" => Nanosecond,
+            b"nsecs" => Nanosecond,
+            b"nsec" => Nanosecond,
+            b"ns" => Nanosecond,
+        }
+    };
+}
+
+/// The name and discriminant of every `duration_unit_lookup::Unit` variant.
+///
+/// The C code generated for the unit designator labels defines its own enum
+/// with these discriminants, which must agree with the Rust one. (A test in
+/// `duration_unit_lookup` checks that they do.)
+pub const DESIGNATOR_UNITS: &[(&str, i64)] = &[
+    ("Year", 9),
+    ("Month", 8),
+    ("Week", 7),
+    ("Day", 6),
+    ("Hour", 5),
+    ("Minute", 4),
+    ("Second", 3),
+    ("Millisecond", 2),
+    ("Microsecond", 1),
+    ("Nanosecond", 0),
+];
+
+/// A single label and the value it maps to.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "files", derive(serde::Deserialize))]
+pub struct Label {
+    /// The label to recognize.
+    pub label: String,
+    /// The name of the enum variant reported when the label is recognized.
+    pub value: String,
+    /// The discriminant of the enum variant named by `value`.
+    pub discriminant: i64,
+}
+
+/// A list of labels, in the order in which they were given.
+///
+/// The order is significant for the same reason as the order of
+/// [`designator_labels!`]: pattern IDs in an automaton built from
+/// the labels are assigned sequentially.
+#[derive(Clone, Debug)]
+pub struct LabelSet {
+    labels: Vec<Label>,
+}
+
+/// The layout of JSON and TOML label files.
+#[cfg(feature = "files")]
+#[derive(serde::Deserialize)]
+struct LabelFile {
+    labels: Vec<Label>,
+}
+
+impl LabelSet {
+    /// Builds a label set from the labels given.
+    ///
+    /// This fails for the same reasons as reading a label set from a file,
+    /// e.g., when there are no labels or when a label appears twice.
+    pub fn new(labels: Vec<Label>) -> anyhow::Result<LabelSet> {
+        let set = LabelSet { labels };
+        set.validate()?;
+        Ok(set)
+    }
+
+    /// Returns the unit designator labels defined by [`designator_labels!`],
+    /// mapped to the `Unit` variants in [`DESIGNATOR_UNITS`].
+    ///
+    /// This is what the generators use when they aren't given a file.
+    pub fn designators() -> LabelSet {
+        macro_rules! labels {
+            ($($label:literal => $unit:ident,)*) => {
+                [$(($label.as_slice(), stringify!($unit)),)*]
+            };
+        }
+        let labels = designator_labels!(labels)
+            .into_iter()
+            .map(|(label, unit)| {
+                let &(_, discriminant) = DESIGNATOR_UNITS
+                    .iter()
+                    .find(|&&(name, _)| name == unit)
+                    .unwrap();
+                Label {
+                    label: String::from_utf8(label.to_vec()).unwrap(),
+                    value: unit.to_string(),
+                    discriminant,
+                }
+            })
+            .collect();
+        LabelSet::new(labels).unwrap()
+    }
+
+    /// Reads a label set from the file given. Its format is determined by
+    /// its extension.
+    #[cfg(feature = "files")]
+    pub fn read(path: &Path) -> anyhow::Result<LabelSet> {
+        let data = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("{}: {e}", path.display()))?;
+        let ext = path.extension().and_then(|ext| ext.to_str());
+        LabelSet::parse(&data, ext)
+            .map_err(|e| anyhow::anyhow!("{}: {e}", path.display()))
+    }
+
+    /// Parses a label set in the format named by the file extension given.
+    #[cfg(feature = "files")]
+    fn parse(data: &str, ext: Option<&str>) -> anyhow::Result<LabelSet> {
+        let labels = match ext {
+            Some("tsv") => parse_tsv(data)?,
+            Some("json") => serde_json::from_str::<LabelFile>(data)?.labels,
+            Some("toml") => toml::from_str::<LabelFile>(data)?.labels,
+            _ => anyhow::bail!(
+                "unrecognized label file format \
+                 (expected a .tsv, .json or .toml extension)",
+            ),
+        };
+        LabelSet::new(labels)
+    }
+
+    /// Returns every label, in order.
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    /// Returns the label with the index given.
+    pub fn get(&self, index: usize) -> &Label {
+        &self.labels[index]
+    }
+
+    /// Returns every distinct value and its discriminant, in the order in
+    /// which they first appear.
+    pub fn values(&self) -> Vec<(&str, i64)> {
+        let mut values: Vec<(&str, i64)> = vec![];
+        for label in self.labels.iter() {
+            if !values.iter().any(|&(value, _)| value == label.value) {
+                values.push((&label.value, label.discriminant));
+            }
+        }
+        values
+    }
+
+    /// Writes the definition of a Rust enum with the name given, with one
+    /// variant for every value.
+    pub fn write_rust_enum(
+        &self,
+        name: &str,
+        out: &mut dyn Write,
+    ) -> std::io::Result<()> {
+        writeln!(out, "#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]")?;
+        writeln!(out, "#[repr(C)]")?;
+        writeln!(out, "pub enum {name} {{")?;
+        for (value, discriminant) in self.values() {
+            writeln!(out, "  {value} = {discriminant},")?;
+        }
+        writeln!(out, "}}")?;
+        Ok(())
+    }
+
+    /// Checks that the labels can be turned into code.
+    fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(!self.labels.is_empty(), "no labels given");
+        let mut seen = std::collections::HashSet::new();
+        for label in self.labels.iter() {
+            anyhow::ensure!(
+                !label.label.is_empty(),
+                "labels must not be empty"
+            );
+            anyhow::ensure!(
+                seen.insert(&label.label),
+                "duplicate label {:?}",
+                label.label,
+            );
+            anyhow::ensure!(
+                is_identifier(&label.value),
+                "value {:?} is not a valid identifier",
+                label.value,
+            );
+        }
+        let values = self.values();
+        for label in self.labels.iter() {
+            let &(_, discriminant) =
+                values.iter().find(|&&(v, _)| v == label.value).unwrap();
+            anyhow::ensure!(
+                label.discriminant == discriminant,
+                "value {} has more than one discriminant ({} and {})",
+                label.value,
+                discriminant,
+                label.discriminant,
+            );
+        }
+        for (i, &(value1, discriminant1)) in values.iter().enumerate() {
+            if let Some(&(value2, _)) =
+                values[i + 1..].iter().find(|&&(_, d)| d == discriminant1)
+            {
+                anyhow::bail!(
+                    "values {value1} and {value2} have the same discriminant",
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a label file in the TSV format.
+#[cfg(feature = "files")]
+fn parse_tsv(data: &str) -> anyhow::Result<Vec<Label>> {
+    let mut labels = vec![];
+    for (i, line) in data.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').collect();
+        let &[label, value, discriminant] = &*fields else {
+            anyhow::bail!(
+                "line {}: expected 3 tab separated fields, but found {}",
+                i + 1,
+                fields.len(),
+            );
+        };
+        let discriminant = discriminant.trim().parse().map_err(|e| {
+            anyhow::anyhow!("line {}: invalid discriminant: {e}", i + 1)
+        })?;
+        labels.push(Label {
+            label: label.to_string(),
+            value: value.trim().to_string(),
+            discriminant,
+        });
+    }
+    Ok(labels)
+}
+
+/// Returns true if the string given can be used as an identifier in both
+/// Rust and C.
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(data: &str, ext: &str) -> anyhow::Result<LabelSet> {
+        LabelSet::parse(data, Some(ext))
+    }
+
+    fn labels(set: &LabelSet) -> Vec<(&str, &str, i64)> {
+        set.labels()
+            .iter()
+            .map(|l| (&*l.label, &*l.value, l.discriminant))
+            .collect()
+    }
+
+    fn error(data: &str, ext: &str) -> String {
+        parse(data, ext).unwrap_err().to_string()
+    }
+
+    const MONTHS: &[(&str, &str, i64)] = &[
+        ("january", "January", 1),
+        ("jan", "January", 1),
+        ("february", "February", 2),
+    ];
+
+    #[test]
+    fn tsv() {
+        let data = "\
+# label\tvalue\tdiscriminant
+january\tJanuary\t1
+
+jan\tJanuary\t 1
+february\tFebruary\t2
+";
+        let set = parse(data, "tsv").unwrap();
+        assert_eq!(labels(&set), MONTHS);
+        assert_eq!(set.values(), [("January", 1), ("February", 2)]);
+    }
+
+    #[test]
+    fn json() {
+        let data = r#"{"labels": [
+            {"label": "january", "value": "January", "discriminant": 1},
+            {"label": "jan", "value": "January", "discriminant": 1},
+            {"label": "february", "value": "February", "discriminant": 2}
+        ]}"#;
+        assert_eq!(labels(&parse(data, "json").unwrap()), MONTHS);
+    }
+
+    #[test]
+    fn toml() {
+        let data = r#"
+[[labels]]
+label = "january"
+value = "January"
+discriminant = 1
+
+[[labels]]
+label = "jan"
+value = "January"
+discriminant = 1
+
+[[labels]]
+label = "february"
+value = "February"
+discriminant = 2
+"#;
+        assert_eq!(labels(&parse(data, "toml").unwrap()), MONTHS);
+    }
+
+    #[test]
+    fn designators() {
+        let set = LabelSet::designators();
+        assert_eq!(set.labels().len(), 56);
+        assert_eq!(set.values().len(), DESIGNATOR_UNITS.len());
+        assert_eq!(set.get(0).label, "years");
+        assert_eq!(set.get(47).label, "us");
+        assert_eq!(set.get(48).label, "µs");
+        assert_eq!(set.get(48).value, "Microsecond");
+        assert_eq!(set.get(48).discriminant, 1);
+    }
+
+    #[test]
+    fn rust_enum() {
+        let set = parse("a\tA\t1\nb\tB\t-2\n", "tsv").unwrap();
+        let mut out = vec![];
+        set.write_rust_enum("E", &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub enum E {
+  A = 1,
+  B = -2,
+}
+",
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(error("", "tsv"), "no labels given");
+        assert_eq!(error("# only a comment\n", "tsv"), "no labels given");
+        assert_eq!(error(r#"{"labels": []}"#, "json"), "no labels given");
+        assert_eq!(error("\tA\t1\n", "tsv"), "labels must not be empty");
+        assert_eq!(
+            error("a\tA\t1\nb\tB\t2\na\tA\t1\n", "tsv"),
+            r#"duplicate label "a""#,
+        );
+        assert_eq!(
+            error("a\t1A\t1\n", "tsv"),
+            r#"value "1A" is not a valid identifier"#,
+        );
+        assert_eq!(
+            error("a\tA-B\t1\n", "tsv"),
+            r#"value "A-B" is not a valid identifier"#,
+        );
+        assert_eq!(
+            error("a\t\t1\n", "tsv"),
+            r#"value "" is not a valid identifier"#,
+        );
+        assert_eq!(
+            error("a\tA\t1\nb\tA\t2\n", "tsv"),
+            "value A has more than one discriminant (1 and 2)",
+        );
+        assert_eq!(
+            error("a\tA\t1\nb\tB\t1\n", "tsv"),
+            "values A and B have the same discriminant",
+        );
+        assert_eq!(
+            error("a\tA\n", "tsv"),
+            "line 1: expected 3 tab separated fields, but found 2",
+        );
+        assert!(error("# c\na\tA\tone\n", "tsv")
+            .starts_with("line 2: invalid discriminant: "));
+        assert!(error(r#"{"labels": [{"label": "a"}]}"#, "json")
+            .contains("missing field `value`"));
+        assert!(
+            error("[[labels]]\nlabel = 1\n", "toml").contains("invalid type")
+        );
+        assert_eq!(
+            LabelSet::parse("a\tA\t1\n", None).unwrap_err().to_string(),
+            "unrecognized label file format \
+             (expected a .tsv, .json or .toml extension)",
+        );
+        assert!(error("", "csv").starts_with("unrecognized label file"));
+    }
+
+    #[test]
+    fn read() {
+        let path = std::env::temp_dir()
+            .join(format!("labelset-{}.tsv", std::process::id()));
+        std::fs::write(&path, "a\tA\t1\na\tA\t1\n").unwrap();
+        let err = LabelSet::read(&path).unwrap_err().to_string();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(err, format!(r#"{}: duplicate label "a""#, path.display()));
+    }
+}
@@ -4,152 +4,117 @@ use aho_corasick::{
     automaton::Automaton, dfa::DFA, Anchored, Input, MatchKind, StartKind,
 };
 
-use crate::Unit;
-
-static PATTERNID_TO_UNIT: &'static [Unit] = &[
-    Unit::Year,
-    Unit::Year,
-    Unit::Year,
-    Unit::Year,
-    Unit::Year,
-    Unit::Month,
-    Unit::Month,
-    Unit::Month,
-    Unit::Month,
-    Unit::Week,
-    Unit::Week,
-    Unit::Week,
-    Unit::Week,
-    Unit::Week,
-    Unit::Day,
-    Unit::Day,
-    Unit::Day,
-    Unit::Hour,
-    Unit::Hour,
-    Unit::Hour,
-    Unit::Hour,
-    Unit::Hour,
-    Unit::Minute,
-    Unit::Minute,
-    Unit::Minute,
-    Unit::Minute,
-    Unit::Minute,
-    Unit::Second,
-    Unit::Second,
-    Unit::Second,
-    Unit::Second,
-    Unit::Second,
-    Unit::Millisecond,
-    Unit::Millisecond,
-    Unit::Millisecond,
-    Unit::Millisecond,
-    Unit::Millisecond,
-    Unit::Millisecond,
-    Unit::Millisecond,
-    Unit::Microsecond,
-    Unit::Microsecond,
-    Unit::Microsecond,
-    Unit::Microsecond,
-    Unit::Microsecond,
-    Unit::Microsecond,
-    Unit::Microsecond,
-    Unit::Microsecond,
-    Unit::Microsecond,
-    Unit::Microsecond,
-    Unit::Nanosecond,
-    Unit::Nanosecond,
-    Unit::Nanosecond,
-    Unit::Nanosecond,
-    Unit::Nanosecond,
-    Unit::Nanosecond,
-    Unit::Nanosecond,
-];
-
-static NEEDLES: &'static [&'static [u8]] = &[
-    b"years",
-    b"year",
-    b"yrs",
-    b"yr",
-    b"y",
-    b"months",
-    b"month",
-    b"mos",
-    b"mo",
-    b"weeks",
-    b"week",
-    b"wks",
-    b"wk",
-    b"w",
-    b"days",
-    b"day",
-    b"d",
-    b"hours",
-    b"hour",
-    b"hrs",
-    b"hr",
-    b"h",
-    b"minutes",
-    b"minute",
-    b"mins",
-    b"min",
-    b"m",
-    b"seconds",
-    b"second",
-    b"secs",
-    b"sec",
-    b"s",
-    b"milliseconds",
-    b"millisecond",
-    b"millis",
-    b"milli",
-    b"msecs",
-    b"msec",
-    b"ms",
-    b"microseconds",
-    b"microsecond",
-    b"micros",
-    b"micro",
-    b"usecs",
-    b"usec",
-    b"\xC2\xB5secs",
-    b"\xC2\xB5sec",
-    b"us",
-    b"\xC2\xB5s",
-    b"nanoseconds",
-    b"nanosecond",
-    b"nanos",
-    b"nano",
-    b"nsecs",
-    b"nsec",
-    b"ns",
-];
-
-static SEARCHER: LazyLock<DFA> = LazyLock::new(|| {
+use crate::{
+    footprint::{self, Footprint},
+    inline_lookup_strategy,
+    labels::LABELS,
+    LookupError, Unit, UnitLookup,
+};
+
+static SEARCHER: LazyLock<DFA> = LazyLock::new(|| searcher(false));
+
+static SEARCHER_CI: LazyLock<DFA> = LazyLock::new(|| searcher(true));
+
+/// Builds an anchored DFA that finds the longest label at the beginning of
+/// the input.
+fn searcher(ascii_case_insensitive: bool) -> DFA {
     DFA::builder()
         .match_kind(MatchKind::LeftmostLongest)
         .start_kind(StartKind::Anchored)
-        .build(NEEDLES)
+        .ascii_case_insensitive(ascii_case_insensitive)
+        .build(LABELS.iter().map(|&(label, _)| label))
         .unwrap()
-});
+}
+
+/// The `aho-corasick` technique.
+#[derive(Clone, Copy, Debug)]
+pub struct AhoCorasick;
+
+impl UnitLookup for AhoCorasick {
+    fn name(&self) -> &'static str {
+        "aho-corasick"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
+    }
+}
+
+inline_lookup_strategy!(AhoCorasickInline, AhoCorasick, "aho-corasick-inline");
 
-#[inline(never)]
-pub fn lookup<'i>(input: &'i [u8]) -> Result<(Unit, &'i [u8]), anyhow::Error> {
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
     let search = Input::new(input).anchored(Anchored::Yes);
     let Some(mat) = SEARCHER.try_find(&search).unwrap() else {
-        if input.is_empty() {
-            anyhow::bail!(
-                "expected to find unit designator suffix \
-                 (e.g., 'years' or 'secs'), \
-                 but found end of input",
-            );
-        } else {
-            anyhow::bail!(
-                "expected to find unit designator suffix \
-                 (e.g., 'years' or 'secs'), \
-                 but found input beginning with {found:?} instead",
-                found = std::str::from_utf8(input).unwrap_or("N/A"),
-            );
-        }
+        return Err(LookupError::new(input));
     };
-    Ok((PATTERNID_TO_UNIT[mat.pattern()], &input[mat.end()..]))
+    Ok((LABELS[mat.pattern()].1, &input[mat.end()..]))
+}
+
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    let search = Input::new(input).anchored(Anchored::Yes);
+    let mat = SEARCHER.try_find(&search).unwrap()?;
+    Some((LABELS[mat.pattern()].1, mat.end()))
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let search = Input::new(input).anchored(Anchored::Yes);
+    let Some(mat) = SEARCHER_CI.try_find(&search).unwrap() else {
+        return Err(LookupError::new_ignore_ascii_case(input));
+    };
+    Ok((LABELS[mat.pattern()].1, &input[mat.end()..]))
+}
+
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    let search = Input::new(input).anchored(Anchored::Yes);
+    let mat = SEARCHER_CI.try_find(&search).unwrap()?;
+    Some((LABELS[mat.pattern()].1, mat.end()))
+}
+
+/// Returns the footprint of this technique.
+///
+/// Both DFAs are built on the heap on first use. Only the `LazyLock`s that
+/// hold them are static.
+pub(crate) fn footprint() -> Footprint {
+    Footprint {
+        name: "aho-corasick",
+        module: "duration_unit_lookup::aho",
+        code: &["aho_corasick"],
+        static_bytes: std::mem::size_of_val(&SEARCHER)
+            + std::mem::size_of_val(&SEARCHER_CI),
+        heap_bytes: SEARCHER.memory_usage() + SEARCHER_CI.memory_usage(),
+        lazy_init: Some(
+            footprint::time(|| searcher(false))
+                + footprint::time(|| searcher(true)),
+        ),
+    }
 }
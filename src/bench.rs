@@ -1,349 +1,513 @@
 use std::hint::black_box as bb;
 
-use criterion::Criterion;
+#[cfg(feature = "scaling")]
+use criterion::{measurement::WallTime, BenchmarkGroup};
+use criterion::{Criterion, Throughput};
 
-use duration_unit_lookup::*;
+use duration_unit_lookup::{rng::Rng, *};
 
-fn one_big_match(c: &mut Criterion) {
-    c.bench_function(&format!("one-big-match/short"), |b| {
-        b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) = one_big_match::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("one-big-match/medium"), |b| {
-        b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) = one_big_match::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
-        })
-    });
-    c.bench_function(&format!("one-big-match/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) = one_big_match::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
-        })
-    });
+/// A single input that every technique is benchmarked with.
+struct Case {
+    /// The name of the benchmark, which is prefixed with the name of the
+    /// technique. e.g., `one-big-match/short`.
+    name: &'static str,
+    /// The input given to `lookup`.
+    input: &'static [u8],
+    /// The unit expected to be found at the beginning of `input`.
+    unit: Unit,
+    /// The input expected to remain after the label is found.
+    remaining: &'static [u8],
 }
 
-fn one_big_match_prefix(c: &mut Criterion) {
-    c.bench_function(&format!("one-big-match-prefix/short"), |b| {
-        b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) =
-                one_big_match_prefix::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("one-big-match-prefix/medium"), |b| {
-        b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) =
-                one_big_match_prefix::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
-        })
-    });
-    c.bench_function(&format!("one-big-match-prefix/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) =
-                one_big_match_prefix::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
-        })
-    });
+/// The inputs for the default (case sensitive) mode of every technique.
+///
+/// Adding a case here benchmarks it with every technique.
+const CASES: &[Case] = &[
+    Case {
+        name: "short",
+        input: b"y 5 months",
+        unit: Unit::Year,
+        remaining: b" 5 months",
+    },
+    Case {
+        name: "medium",
+        input: b"months 5 days",
+        unit: Unit::Month,
+        remaining: b" 5 days",
+    },
+    Case {
+        name: "long",
+        input: b"milliseconds 5 nanoseconds",
+        unit: Unit::Millisecond,
+        remaining: b" 5 nanoseconds",
+    },
+];
+
+/// The inputs for the ASCII case insensitive mode of every technique.
+///
+/// These are the same as `CASES`, except some of the letters are uppercase.
+/// Each benchmark is named `{technique}-ci/{case}`, so that comparing, e.g.,
+/// `one-big-match/long` with `one-big-match-ci/long` shows what the case
+/// insensitive mode costs.
+const CASES_IGNORE_ASCII_CASE: &[Case] = &[
+    Case {
+        name: "short",
+        input: b"Y 5 months",
+        unit: Unit::Year,
+        remaining: b" 5 months",
+    },
+    Case {
+        name: "medium",
+        input: b"Months 5 days",
+        unit: Unit::Month,
+        remaining: b" 5 days",
+    },
+    Case {
+        name: "long",
+        input: b"MilliSeconds 5 nanoseconds",
+        unit: Unit::Millisecond,
+        remaining: b" 5 nanoseconds",
+    },
+];
+
+/// The input used for the end-to-end parsing benchmarks.
+///
+/// This is the duration from the top of the README. It only uses labels that
+/// `humantime` also recognizes.
+const FRIENDLY: &str = "2 years 1 month 15 days 5 hours 59 minutes 1 second";
+
+/// Benchmarks `lookup` for the technique given on every input in `CASES`.
+fn lookup<L: UnitLookup>(c: &mut Criterion, strategy: L) {
+    let name = strategy.name();
+    for case in CASES {
+        c.bench_function(&format!("{name}/{}", case.name), |b| {
+            b.iter(|| {
+                let (unit, remaining) =
+                    strategy.lookup(bb(case.input)).unwrap();
+                assert_eq!(unit, case.unit);
+                assert_eq!(remaining, case.remaining);
+            })
+        });
+    }
 }
 
-fn aho_corasick(c: &mut Criterion) {
-    c.bench_function(&format!("aho-corasick/short"), |b| {
-        b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) = aho::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("aho-corasick/medium"), |b| {
-        b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) = aho::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
-        })
-    });
-    c.bench_function(&format!("aho-corasick/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) = aho::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
-        })
-    });
+/// Benchmarks `lookup_ignore_ascii_case` for the technique given on every
+/// input in `CASES_IGNORE_ASCII_CASE`.
+fn lookup_ignore_ascii_case<L: UnitLookup>(c: &mut Criterion, strategy: L) {
+    let name = strategy.name();
+    for case in CASES_IGNORE_ASCII_CASE {
+        c.bench_function(&format!("{name}-ci/{}", case.name), |b| {
+            b.iter(|| {
+                let (unit, remaining) =
+                    strategy.lookup_ignore_ascii_case(bb(case.input)).unwrap();
+                assert_eq!(unit, case.unit);
+                assert_eq!(remaining, case.remaining);
+            })
+        });
+    }
 }
 
-fn phf(c: &mut Criterion) {
-    c.bench_function(&format!("phf/short"), |b| {
+/// Benchmarks parsing a complete friendly duration with `parse::Parser`
+/// using the technique given.
+///
+/// These are named `parse/{technique}`, and can be compared with
+/// `parse/humantime`.
+fn parse<L: UnitLookup>(c: &mut Criterion, strategy: L) {
+    let name = strategy.name();
+    let parser = parse::Parser::new(strategy);
+    c.bench_function(&format!("parse/{name}"), |b| {
         b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) = phf::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("phf/medium"), |b| {
-        b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) = phf::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
-        })
-    });
-    c.bench_function(&format!("phf/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) = phf::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
+            let components = parser.parse(bb(FRIENDLY.as_bytes())).unwrap();
+            assert_eq!(components.len(), 6);
         })
     });
 }
 
-fn hashify(c: &mut Criterion) {
-    c.bench_function(&format!("hashify/short"), |b| {
-        b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) = hashify::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("hashify/medium"), |b| {
-        b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) = hashify::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
-        })
-    });
-    c.bench_function(&format!("hashify/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) = hashify::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
-        })
-    });
+/// Complete durations with several components each, for the in-context
+/// benchmarks.
+///
+/// These mix short and long labels, with and without whitespace, so that a
+/// parser using a recognizer sees a realistic variety of labels in a row.
+const DURATIONS: &[&str] = &[
+    FRIENDLY,
+    "1h30m",
+    "2h45m30s",
+    "1d12h",
+    "1 day, 12 hours",
+    "3 weeks 2 days",
+    "1y2mo3w4d5h6m7s",
+    "5 minutes 30 seconds",
+    "2 hrs 15 mins",
+    "1 year, 6 months",
+    "500ms 250\u{b5}s 100ns",
+    "1 hour 1 minute 1 second 1 millisecond 1 microsecond 1 nanosecond",
+    "10 secs 5 msecs",
+    "4 wks 3 days 2 hrs",
+    "1m 30s",
+    "12 hours, 30 minutes",
+];
+
+/// Benchmarks parsing every duration in `DURATIONS` with `parse::Parser`
+/// using the technique given.
+///
+/// These are named `in-context/{technique}`. Unlike `lookup`, this measures
+/// each recognizer the way it would be used inside a real duration parser:
+/// called many times in a loop, surrounded by integer and whitespace
+/// parsing. Comparing, e.g., `in-context/by-trie5` with
+/// `in-context/by-trie5-inline` shows what inlining the recognizer into the
+/// parser is worth. The throughput reported is the number of durations
+/// parsed per second.
+fn in_context<L: UnitLookup>(c: &mut Criterion, strategy: L) {
+    let name = strategy.name();
+    let parser = parse::Parser::new(strategy);
+    let mut group = c.benchmark_group("in-context");
+    group.throughput(Throughput::Elements(DURATIONS.len() as u64));
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            let mut components = 0;
+            for duration in DURATIONS {
+                let parsed = parser.parse(bb(duration.as_bytes())).unwrap();
+                components += parsed.len();
+            }
+            assert_eq!(components, 48);
+        })
+    });
+    group.finish();
 }
 
-fn by_trie1(c: &mut Criterion) {
-    c.bench_function(&format!("by-trie1/short"), |b| {
-        b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) = by_trie1::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("by-trie1/medium"), |b| {
-        b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) = by_trie1::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
-        })
-    });
-    c.bench_function(&format!("by-trie1/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) = by_trie1::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
-        })
-    });
+/// Durations as they are typically written by hand, e.g., in configuration
+/// files and command line arguments.
+///
+/// The `corpus` workload draws labels from here at random, so labels that
+/// are common here (like `s`, `m` and `h`) are common in the workload too.
+const CORPUS: &[&str] = &[
+    "30s",
+    "5s",
+    "10s",
+    "500ms",
+    "100ms",
+    "250ms",
+    "1m",
+    "5m",
+    "15m",
+    "30m",
+    "1h",
+    "2h",
+    "24h",
+    "1h30m",
+    "1h 30m",
+    "2h45m30s",
+    "7d",
+    "30d",
+    "1d12h",
+    "100us",
+    "50ns",
+    "1 second",
+    "30 seconds",
+    "90 seconds",
+    "1 minute",
+    "5 minutes",
+    "10 mins",
+    "45 min",
+    "1 hour",
+    "2 hours",
+    "3 hrs",
+    "1 day",
+    "3 days",
+    "1 week",
+    "2 weeks",
+    "1 month",
+    "6 months",
+    "1 year",
+    "2 years",
+    "1 year 6 months",
+    "1 hour, 30 minutes",
+    "5 days 4 hours",
+    "2 years 1 month 15 days 5 hours 59 minutes 1 second",
+    "10 secs",
+    "20 msec",
+    "15 milliseconds",
+    "3 \u{b5}s",
+    "4 usec",
+    "8 nanoseconds",
+    "2wks",
+    "1yr",
+];
+
+/// Inputs that don't begin with any known label, for measuring the failure
+/// path of each technique.
+const INVALID: &[&[u8]] = &[
+    b"",
+    b" 5 days",
+    b"5 days",
+    b"yx",
+    b"minutez",
+    b"hourz 5 minutes",
+    b"millisecondss",
+    b"x",
+    b"zebras",
+    b"Minutes",
+    b"MS",
+    b"\xC2",
+    b"\xC2\xB5",
+    b"\xC3\xA9t\xC3\xA9",
+    b"\xE2\x80\x8Bs",
+    b"\xFF",
+];
+
+/// The number of inputs in each workload.
+///
+/// This is big enough that the branch predictor can't simply memorize the
+/// sequence of labels, but small enough that all inputs fit in L1 cache.
+const WORKLOAD_LEN: usize = 1024;
+
+/// A sequence of inputs that every technique looks up one after the other.
+///
+/// Unlike `CASES`, where every iteration looks up the same label, these
+/// vary the label from one lookup to the next. So branch mispredictions and
+/// the failure paths show up in the measurements.
+struct Workload {
+    /// The name of the benchmark, which is prefixed with the name of the
+    /// technique. e.g., `one-big-match/shuffled`.
+    name: &'static str,
+    inputs: Vec<Vec<u8>>,
+    /// The number of inputs that begin with a known label.
+    found: usize,
 }
 
-fn by_trie2(c: &mut Criterion) {
-    c.bench_function(&format!("by-trie2/short"), |b| {
-        b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) = by_trie2::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("by-trie2/medium"), |b| {
-        b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) = by_trie2::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
-        })
-    });
-    c.bench_function(&format!("by-trie2/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) = by_trie2::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
-        })
-    });
+impl Workload {
+    /// Returns every workload.
+    fn all() -> Vec<Workload> {
+        let mut rng = Rng::default();
+
+        // Every place in the corpus where a label begins, along with
+        // everything that follows it.
+        let mut labels = vec![];
+        for duration in CORPUS {
+            let duration = duration.as_bytes();
+            let mut at = 0;
+            while at < duration.len() {
+                while at < duration.len() && !is_label_byte(duration[at]) {
+                    at += 1;
+                }
+                if at < duration.len() {
+                    labels.push(&duration[at..]);
+                }
+                while at < duration.len() && is_label_byte(duration[at]) {
+                    at += 1;
+                }
+            }
+        }
+        let corpus = (0..WORKLOAD_LEN)
+            .map(|_| labels[rng.below(labels.len())].to_vec())
+            .collect();
+
+        let mut shuffled = vec![];
+        while shuffled.len() < WORKLOAD_LEN {
+            let mut round: Vec<Vec<u8>> = LABELS
+                .iter()
+                .map(|&(label, _)| format!("{label} 5 days").into_bytes())
+                .collect();
+            rng.shuffle(&mut round);
+            shuffled.extend(round);
+        }
+        shuffled.truncate(WORKLOAD_LEN);
+
+        let invalid = (0..WORKLOAD_LEN)
+            .map(|_| INVALID[rng.below(INVALID.len())].to_vec())
+            .collect();
+
+        let workloads = vec![
+            Workload::new("corpus", corpus),
+            Workload::new("shuffled", shuffled),
+            Workload::new("invalid", invalid),
+        ];
+        // Make sure every label in the corpus is actually known, and that
+        // no invalid input accidentally begins with a known label.
+        assert_eq!(workloads[0].found, WORKLOAD_LEN);
+        assert_eq!(workloads[1].found, WORKLOAD_LEN);
+        assert_eq!(workloads[2].found, 0);
+        workloads
+    }
+
+    fn new(name: &'static str, inputs: Vec<Vec<u8>>) -> Workload {
+        let found = inputs
+            .iter()
+            .filter(|input| one_big_match::lookup(input).is_ok())
+            .count();
+        Workload { name, inputs, found }
+    }
 }
 
-fn by_trie3(c: &mut Criterion) {
-    c.bench_function(&format!("by-trie3/short"), |b| {
-        b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) = by_trie3::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("by-trie3/medium"), |b| {
-        b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) = by_trie3::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
-        })
-    });
-    c.bench_function(&format!("by-trie3/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) = by_trie3::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
-        })
-    });
+/// Returns true if the byte given may appear in a label in `CORPUS`.
+fn is_label_byte(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || byte >= 0x80
 }
 
-fn by_trie4(c: &mut Criterion) {
-    c.bench_function(&format!("by-trie4/short"), |b| {
-        b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) = by_trie4::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("by-trie4/medium"), |b| {
-        b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) = by_trie4::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
-        })
-    });
-    c.bench_function(&format!("by-trie4/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) = by_trie4::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
-        })
-    });
+/// Benchmarks `lookup` for the technique given on every workload.
+///
+/// The throughput reported is the number of lookups per second.
+fn workload<L: UnitLookup>(
+    c: &mut Criterion,
+    workloads: &[Workload],
+    strategy: L,
+) {
+    let mut group = c.benchmark_group(strategy.name());
+    for workload in workloads {
+        group.throughput(Throughput::Elements(workload.inputs.len() as u64));
+        group.bench_function(workload.name, |b| {
+            b.iter(|| {
+                let mut found = 0;
+                for input in workload.inputs.iter() {
+                    if let Ok(result) = strategy.lookup(bb(input)) {
+                        bb(result);
+                        found += 1;
+                    }
+                }
+                assert_eq!(found, workload.found);
+            })
+        });
+    }
+    group.finish();
 }
 
-fn by_trie5(c: &mut Criterion) {
-    c.bench_function(&format!("by-trie5/short"), |b| {
-        b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) = by_trie5::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("by-trie5/medium"), |b| {
-        b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) = by_trie5::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
-        })
-    });
-    c.bench_function(&format!("by-trie5/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) = by_trie5::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
-        })
-    });
+/// Defines one benchmark function per kind of benchmark, each of which runs
+/// that benchmark for every technique.
+///
+/// Each technique is passed by value, so that every benchmark is
+/// monomorphized and calls the technique directly.
+macro_rules! benches {
+    ($($strategy:path),* $(,)?) => {
+        fn lookups(c: &mut Criterion) {
+            $(lookup(c, $strategy);)*
+        }
+
+        fn lookups_ignore_ascii_case(c: &mut Criterion) {
+            $(lookup_ignore_ascii_case(c, $strategy);)*
+        }
+
+        fn parses(c: &mut Criterion) {
+            $(parse(c, $strategy);)*
+        }
+
+        fn workloads(c: &mut Criterion) {
+            let workloads = Workload::all();
+            $(workload(c, &workloads, $strategy);)*
+        }
+
+        fn in_context_out_of_line(c: &mut Criterion) {
+            $(in_context(c, $strategy);)*
+        }
+    };
 }
 
-fn by_gendfa1(c: &mut Criterion) {
-    c.bench_function(&format!("by-gendfa1/short"), |b| {
-        b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) = by_gendfa1::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("by-gendfa1/medium"), |b| {
-        b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) = by_gendfa1::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
-        })
-    });
-    c.bench_function(&format!("by-gendfa1/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) = by_gendfa1::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
-        })
-    });
+lookup_strategies!(benches);
+
+/// Like `benches`, but for the inlined variant of every technique.
+macro_rules! inline_benches {
+    ($($strategy:path),* $(,)?) => {
+        fn in_context_inline(c: &mut Criterion) {
+            $(in_context(c, $strategy);)*
+        }
+    };
 }
 
-fn by_gencdfa1(c: &mut Criterion) {
-    c.bench_function(&format!("by-gencdfa1/short"), |b| {
-        b.iter(|| {
-            let input = bb(b"y 5 months");
-            let (unit, remaining) = by_gencdfa1::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Year);
-            assert_eq!(remaining, b" 5 months");
-        })
-    });
-    c.bench_function(&format!("by-gencdfa1/medium"), |b| {
+inline_lookup_strategies!(inline_benches);
+
+fn parse_humantime(c: &mut Criterion) {
+    c.bench_function("parse/humantime", |b| {
         b.iter(|| {
-            let input = bb(b"months 5 days");
-            let (unit, remaining) = by_gencdfa1::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Month);
-            assert_eq!(remaining, b" 5 days");
+            let duration = humantime::parse_duration(bb(FRIENDLY)).unwrap();
+            assert!(duration.as_secs() > 0);
         })
     });
-    c.bench_function(&format!("by-gencdfa1/long"), |b| {
-        b.iter(|| {
-            let input = bb(b"milliseconds 5 nanoseconds");
-            let (unit, remaining) = by_gencdfa1::lookup(input).unwrap();
-            assert_eq!(unit, Unit::Millisecond);
-            assert_eq!(remaining, b" 5 nanoseconds");
+}
+
+/// Benchmarks `find` for the technique given, on the shuffled labels of the
+/// synthetic set it was built over.
+///
+/// The throughput reported is the number of lookups per second.
+#[cfg(feature = "scaling")]
+fn scaling_bench<T: scaling::Technique>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    inputs: &[Vec<u8>],
+    technique: T,
+) {
+    group.bench_function(technique.name(), |b| {
+        b.iter(|| {
+            let mut found = 0;
+            for input in inputs.iter() {
+                if let Some(result) = technique.find(bb(input)) {
+                    bb(result);
+                    found += 1;
+                }
+            }
+            assert_eq!(found, inputs.len());
         })
     });
 }
 
+/// Returns a shuffled sequence of all of the labels in the set given, each
+/// followed by more input, repeated until it's at least `WORKLOAD_LEN`
+/// long.
+#[cfg(feature = "scaling")]
+fn scaling_inputs(
+    rng: &mut Rng,
+    labels: &[(&'static str, Unit)],
+) -> Vec<Vec<u8>> {
+    let mut inputs = vec![];
+    while inputs.len() < WORKLOAD_LEN {
+        let mut round: Vec<Vec<u8>> = labels
+            .iter()
+            .map(|&(label, _)| format!("{label} 5 days").into_bytes())
+            .collect();
+        rng.shuffle(&mut round);
+        inputs.extend(round);
+    }
+    inputs
+}
+
+/// Defines the `scaling` benchmark function, which benchmarks `find` for
+/// every technique built over each synthetic label set.
+///
+/// The benchmarks are named after the number of labels in the set, e.g.,
+/// `scaling-200/phf`. Just like with `benches`, every technique is passed by
+/// value, so that each one is called directly.
+#[cfg(feature = "scaling")]
+macro_rules! scaling_benches {
+    ($($set:ident => [$($technique:ident),* $(,)?]),* $(,)?) => {
+        fn scaling(c: &mut Criterion) {
+            let mut rng = Rng::default();
+            $({
+                let labels = scaling::$set::LABELS;
+                let inputs = scaling_inputs(&mut rng, labels);
+                let mut group =
+                    c.benchmark_group(format!("scaling-{}", labels.len()));
+                group.throughput(Throughput::Elements(inputs.len() as u64));
+                $(scaling_bench(&mut group, &inputs, scaling::$set::$technique);)*
+                group.finish();
+            })*
+        }
+    };
+}
+
+#[cfg(feature = "scaling")]
+scaling_techniques!(scaling_benches);
+
+/// The synthetic label sets are only built with the `scaling` feature, so
+/// there is nothing to benchmark without it.
+#[cfg(not(feature = "scaling"))]
+fn scaling(_: &mut Criterion) {}
+
 criterion::criterion_group!(
     benches,
-    one_big_match,
-    one_big_match_prefix,
-    aho_corasick,
-    phf,
-    hashify,
-    by_trie1,
-    by_trie2,
-    by_trie3,
-    by_trie4,
-    by_trie5,
-    by_gendfa1,
-    by_gencdfa1,
+    lookups,
+    lookups_ignore_ascii_case,
+    parses,
+    parse_humantime,
+    workloads,
+    scaling,
+    in_context_out_of_line,
+    in_context_inline,
 );
 criterion::criterion_main!(benches);
@@ -1,4 +1,4 @@
-use crate::Unit;
+use crate::{inline_lookup_strategy, LookupError, Unit, UnitLookup};
 
 #[repr(C)]
 struct Output {
@@ -8,27 +8,87 @@ struct Output {
 
 extern "C" {
     fn gencdfa1_find(start: *const u8, end: *const u8) -> Output;
+    fn gencdfa1_ci_find(start: *const u8, end: *const u8) -> Output;
 }
 
-#[inline(never)]
-pub fn lookup<'i>(input: &'i [u8]) -> Result<(Unit, &'i [u8]), anyhow::Error> {
+/// The `by-gencdfa1` technique.
+#[derive(Clone, Copy, Debug)]
+pub struct ByGenCDfa1;
+
+impl UnitLookup for ByGenCDfa1 {
+    fn name(&self) -> &'static str {
+        "by-gencdfa1"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
+    }
+}
+
+inline_lookup_strategy!(ByGenCDfa1Inline, ByGenCDfa1, "by-gencdfa1-inline");
+
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let p = input.as_ptr();
+    let o = unsafe { gencdfa1_find(p, p.add(input.len())) };
+    if o.len == 0 {
+        return Err(LookupError::new(input));
+    }
+    Ok((o.unit, &input[o.len..]))
+}
+
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
     let p = input.as_ptr();
     let o = unsafe { gencdfa1_find(p, p.add(input.len())) };
     if o.len == 0 {
-        if input.is_empty() {
-            anyhow::bail!(
-                "expected to find unit designator suffix \
-                 (e.g., 'years' or 'secs'), \
-                 but found end of input",
-            );
-        } else {
-            anyhow::bail!(
-                "expected to find unit designator suffix \
-                 (e.g., 'years' or 'secs'), \
-                 but found input beginning with {found:?} instead",
-                found = std::str::from_utf8(input).unwrap_or("N/A"),
-            );
-        }
+        return None;
+    }
+    Some((o.unit, o.len))
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let p = input.as_ptr();
+    let o = unsafe { gencdfa1_ci_find(p, p.add(input.len())) };
+    if o.len == 0 {
+        return Err(LookupError::new_ignore_ascii_case(input));
     }
     Ok((o.unit, &input[o.len..]))
 }
+
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    let p = input.as_ptr();
+    let o = unsafe { gencdfa1_ci_find(p, p.add(input.len())) };
+    if o.len == 0 {
+        return None;
+    }
+    Some((o.unit, o.len))
+}
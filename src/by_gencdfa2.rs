@@ -0,0 +1,110 @@
+use crate::{
+    footprint::Footprint, inline_lookup_strategy, LookupError, Unit,
+    UnitLookup,
+};
+
+#[repr(C)]
+struct Output {
+    unit: Unit,
+    len: usize,
+}
+
+extern "C" {
+    fn gencdfa2_find(start: *const u8, end: *const u8) -> Output;
+    fn gencdfa2_ci_find(start: *const u8, end: *const u8) -> Output;
+}
+
+/// The `by-gencdfa2` technique.
+#[derive(Clone, Copy, Debug)]
+pub struct ByGenCDfa2;
+
+impl UnitLookup for ByGenCDfa2 {
+    fn name(&self) -> &'static str {
+        "by-gencdfa2"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
+    }
+}
+
+inline_lookup_strategy!(ByGenCDfa2Inline, ByGenCDfa2, "by-gencdfa2-inline");
+
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let p = input.as_ptr();
+    let o = unsafe { gencdfa2_find(p, p.add(input.len())) };
+    if o.len == 0 {
+        return Err(LookupError::new(input));
+    }
+    Ok((o.unit, &input[o.len..]))
+}
+
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    let p = input.as_ptr();
+    let o = unsafe { gencdfa2_find(p, p.add(input.len())) };
+    if o.len == 0 {
+        return None;
+    }
+    Some((o.unit, o.len))
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let p = input.as_ptr();
+    let o = unsafe { gencdfa2_ci_find(p, p.add(input.len())) };
+    if o.len == 0 {
+        return Err(LookupError::new_ignore_ascii_case(input));
+    }
+    Ok((o.unit, &input[o.len..]))
+}
+
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    let p = input.as_ptr();
+    let o = unsafe { gencdfa2_ci_find(p, p.add(input.len())) };
+    if o.len == 0 {
+        return None;
+    }
+    Some((o.unit, o.len))
+}
+
+pub(crate) fn footprint() -> Footprint {
+    Footprint {
+        name: "by-gencdfa2",
+        module: "duration_unit_lookup::by_gencdfa2",
+        code: &["gencdfa2_find", "gencdfa2_ci_find"],
+        // The byte class tables of both C functions, which aren't visible
+        // from Rust.
+        static_bytes: 2 * 256,
+        heap_bytes: 0,
+        lazy_init: None,
+    }
+}
@@ -1,22 +1,71 @@
-use crate::{gendfa1, Unit};
+use crate::{
+    gendfa1, gendfa1_ci, inline_lookup_strategy, LookupError, Unit, UnitLookup,
+};
 
-#[inline(never)]
-pub fn lookup<'i>(input: &'i [u8]) -> Result<(Unit, &'i [u8]), anyhow::Error> {
+/// The `by-gendfa1` technique.
+#[derive(Clone, Copy, Debug)]
+pub struct ByGenDfa1;
+
+impl UnitLookup for ByGenDfa1 {
+    fn name(&self) -> &'static str {
+        "by-gendfa1"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
+    }
+}
+
+inline_lookup_strategy!(ByGenDfa1Inline, ByGenDfa1, "by-gendfa1-inline");
+
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
     let Some((unit, len)) = gendfa1::find(input) else {
-        if input.is_empty() {
-            anyhow::bail!(
-                "expected to find unit designator suffix \
-                 (e.g., 'years' or 'secs'), \
-                 but found end of input",
-            );
-        } else {
-            anyhow::bail!(
-                "expected to find unit designator suffix \
-                 (e.g., 'years' or 'secs'), \
-                 but found input beginning with {found:?} instead",
-                found = std::str::from_utf8(input).unwrap_or("N/A"),
-            );
-        }
+        return Err(LookupError::new(input));
     };
     Ok((unit, &input[len..]))
 }
+
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa1::find(input)
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, len)) = gendfa1_ci::find(input) else {
+        return Err(LookupError::new_ignore_ascii_case(input));
+    };
+    Ok((unit, &input[len..]))
+}
+
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa1_ci::find(input)
+}
@@ -0,0 +1,71 @@
+use crate::{
+    gendfa2, gendfa2_ci, inline_lookup_strategy, LookupError, Unit, UnitLookup,
+};
+
+/// The `by-gendfa2` technique.
+#[derive(Clone, Copy, Debug)]
+pub struct ByGenDfa2;
+
+impl UnitLookup for ByGenDfa2 {
+    fn name(&self) -> &'static str {
+        "by-gendfa2"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
+    }
+}
+
+inline_lookup_strategy!(ByGenDfa2Inline, ByGenDfa2, "by-gendfa2-inline");
+
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, len)) = gendfa2::find(input) else {
+        return Err(LookupError::new(input));
+    };
+    Ok((unit, &input[len..]))
+}
+
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa2::find(input)
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, len)) = gendfa2_ci::find(input) else {
+        return Err(LookupError::new_ignore_ascii_case(input));
+    };
+    Ok((unit, &input[len..]))
+}
+
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa2_ci::find(input)
+}
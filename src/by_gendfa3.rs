@@ -0,0 +1,74 @@
+use crate::{
+    gendfa3, gendfa3_ci, inline_lookup_strategy, LookupError, Unit, UnitLookup,
+};
+
+/// The `by-gendfa3` technique.
+///
+/// When the `nightly` feature is enabled, the generated state functions use
+/// `become` to call each other.
+#[derive(Clone, Copy, Debug)]
+pub struct ByGenDfa3;
+
+impl UnitLookup for ByGenDfa3 {
+    fn name(&self) -> &'static str {
+        "by-gendfa3"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
+    }
+}
+
+inline_lookup_strategy!(ByGenDfa3Inline, ByGenDfa3, "by-gendfa3-inline");
+
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, len)) = gendfa3::find(input) else {
+        return Err(LookupError::new(input));
+    };
+    Ok((unit, &input[len..]))
+}
+
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa3::find(input)
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, len)) = gendfa3_ci::find(input) else {
+        return Err(LookupError::new_ignore_ascii_case(input));
+    };
+    Ok((unit, &input[len..]))
+}
+
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa3_ci::find(input)
+}
@@ -0,0 +1,71 @@
+use crate::{
+    gendfa4, gendfa4_ci, inline_lookup_strategy, LookupError, Unit, UnitLookup,
+};
+
+/// The `by-gendfa4` technique.
+#[derive(Clone, Copy, Debug)]
+pub struct ByGenDfa4;
+
+impl UnitLookup for ByGenDfa4 {
+    fn name(&self) -> &'static str {
+        "by-gendfa4"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
+    }
+}
+
+inline_lookup_strategy!(ByGenDfa4Inline, ByGenDfa4, "by-gendfa4-inline");
+
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, len)) = gendfa4::find(input) else {
+        return Err(LookupError::new(input));
+    };
+    Ok((unit, &input[len..]))
+}
+
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa4::find(input)
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, len)) = gendfa4_ci::find(input) else {
+        return Err(LookupError::new_ignore_ascii_case(input));
+    };
+    Ok((unit, &input[len..]))
+}
+
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa4_ci::find(input)
+}
@@ -0,0 +1,101 @@
+use crate::{
+    footprint::Footprint, gendfa5, gendfa5_ci, inline_lookup_strategy,
+    LookupError, Unit, UnitLookup,
+};
+
+/// The `by-gendfa5` technique.
+#[derive(Clone, Copy, Debug)]
+pub struct ByGenDfa5;
+
+impl UnitLookup for ByGenDfa5 {
+    fn name(&self) -> &'static str {
+        "by-gendfa5"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
+    }
+}
+
+inline_lookup_strategy!(ByGenDfa5Inline, ByGenDfa5, "by-gendfa5-inline");
+
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, len)) = gendfa5::DFA.find(input) else {
+        return Err(LookupError::new(input));
+    };
+    Ok((unit, &input[len..]))
+}
+
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa5::DFA.find(input)
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, len)) = gendfa5_ci::DFA.find(input) else {
+        return Err(LookupError::new_ignore_ascii_case(input));
+    };
+    Ok((unit, &input[len..]))
+}
+
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa5_ci::DFA.find(input)
+}
+
+pub(crate) fn footprint() -> Footprint {
+    Footprint {
+        name: "by-gendfa5",
+        module: "duration_unit_lookup::by_gendfa5",
+        code: &["duration_unit_lookup::dfa"],
+        static_bytes: std::mem::size_of_val(&gendfa5::DFA)
+            + std::mem::size_of_val(&gendfa5_ci::DFA),
+        heap_bytes: 0,
+        lazy_init: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The README says that our DFA uses `u16` state identifiers, and its
+    /// footprint table has both DFAs at about 20KB. If either changes, then
+    /// the generator probably stopped merging byte classes or picking the
+    /// smallest state identifier type. (And the README needs updating.)
+    #[test]
+    fn size() {
+        for dfa in [&gendfa5::DFA, &gendfa5_ci::DFA] {
+            assert_eq!(std::mem::size_of_val(&dfa.transitions[0]), 2);
+            assert!(std::mem::size_of_val(dfa) <= 12 * 1024);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    footprint::Footprint, gendfa6, gendfa6_ci, inline_lookup_strategy,
+    LookupError, Unit, UnitLookup,
+};
+
+/// The `by-gendfa6` technique.
+#[derive(Clone, Copy, Debug)]
+pub struct ByGenDfa6;
+
+impl UnitLookup for ByGenDfa6 {
+    fn name(&self) -> &'static str {
+        "by-gendfa6"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
+    }
+}
+
+inline_lookup_strategy!(ByGenDfa6Inline, ByGenDfa6, "by-gendfa6-inline");
+
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, len)) = gendfa6::find(input) else {
+        return Err(LookupError::new(input));
+    };
+    Ok((unit, &input[len..]))
+}
+
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa6::find(input)
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, len)) = gendfa6_ci::find(input) else {
+        return Err(LookupError::new_ignore_ascii_case(input));
+    };
+    Ok((unit, &input[len..]))
+}
+
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    gendfa6_ci::find(input)
+}
+
+pub(crate) fn footprint() -> Footprint {
+    Footprint {
+        name: "by-gendfa6",
+        module: "duration_unit_lookup::by_gendfa6",
+        code: &[
+            "duration_unit_lookup::gendfa6",
+            "duration_unit_lookup::gendfa6_ci",
+        ],
+        static_bytes: std::mem::size_of_val(&gendfa6::CLASSES)
+            + std::mem::size_of_val(&gendfa6_ci::CLASSES),
+        heap_bytes: 0,
+        lazy_init: None,
+    }
+}
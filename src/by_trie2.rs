@@ -1,6 +1,9 @@
 use crate::{
+    footprint::Footprint,
+    inline_lookup_strategy,
+    labels::LABELS,
     trie2::{Trie, TrieNeedles},
-    Unit,
+    LookupError, Unit, UnitLookup,
 };
 
 type DesignatorTrie = Trie<99, { DESIGNATOR_NEEDLES.alphabet_len() }, Unit>;
@@ -8,86 +11,88 @@ type DesignatorTrie = Trie<99, { DESIGNATOR_NEEDLES.alphabet_len() }, Unit>;
 static DESIGNATOR_TRIE: &'static DesignatorTrie =
     &Trie::new(&DESIGNATOR_NEEDLES);
 
-const DESIGNATOR_NEEDLES: TrieNeedles<Unit> = TrieNeedles::new(&[
-    ("years", Unit::Year),
-    ("year", Unit::Year),
-    ("yrs", Unit::Year),
-    ("yr", Unit::Year),
-    ("y", Unit::Year),
-    ("months", Unit::Month),
-    ("month", Unit::Month),
-    ("mos", Unit::Month),
-    ("mo", Unit::Month),
-    ("weeks", Unit::Week),
-    ("week", Unit::Week),
-    ("wks", Unit::Week),
-    ("wk", Unit::Week),
-    ("w", Unit::Week),
-    ("days", Unit::Day),
-    ("day", Unit::Day),
-    ("d", Unit::Day),
-    ("hours", Unit::Hour),
-    ("hour", Unit::Hour),
-    ("hrs", Unit::Hour),
-    ("hr", Unit::Hour),
-    ("h", Unit::Hour),
-    ("minutes", Unit::Minute),
-    ("minute", Unit::Minute),
-    ("mins", Unit::Minute),
-    ("min", Unit::Minute),
-    ("m", Unit::Minute),
-    ("seconds", Unit::Second),
-    ("second", Unit::Second),
-    ("secs", Unit::Second),
-    ("sec", Unit::Second),
-    ("s", Unit::Second),
-    ("milliseconds", Unit::Millisecond),
-    ("millisecond", Unit::Millisecond),
-    ("millis", Unit::Millisecond),
-    ("milli", Unit::Millisecond),
-    ("msecs", Unit::Millisecond),
-    ("msec", Unit::Millisecond),
-    ("ms", Unit::Millisecond),
-    ("microseconds", Unit::Microsecond),
-    ("microsecond", Unit::Microsecond),
-    ("micros", Unit::Microsecond),
-    ("micro", Unit::Microsecond),
-    ("usecs", Unit::Microsecond),
-    ("usec", Unit::Microsecond),
-    ("µsecs", Unit::Microsecond),
-    ("µsec", Unit::Microsecond),
-    ("us", Unit::Microsecond),
-    ("µs", Unit::Microsecond),
-    ("nanoseconds", Unit::Nanosecond),
-    ("nanosecond", Unit::Nanosecond),
-    ("nanos", Unit::Nanosecond),
-    ("nano", Unit::Nanosecond),
-    ("nsecs", Unit::Nanosecond),
-    ("nsec", Unit::Nanosecond),
-    ("ns", Unit::Nanosecond),
-]);
-
-#[inline(never)]
-pub fn lookup<'i>(input: &'i [u8]) -> Result<(Unit, &'i [u8]), anyhow::Error> {
+const DESIGNATOR_NEEDLES: TrieNeedles<Unit> = TrieNeedles::new(LABELS);
+
+/// The `by-trie2` technique.
+#[derive(Clone, Copy, Debug)]
+pub struct ByTrie2;
+
+impl UnitLookup for ByTrie2 {
+    fn name(&self) -> &'static str {
+        "by-trie2"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
+    }
+}
+
+inline_lookup_strategy!(ByTrie2Inline, ByTrie2, "by-trie2-inline");
+
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
     let Some((unit, offset)) = DESIGNATOR_TRIE.find(input) else {
-        if input.is_empty() {
-            anyhow::bail!(
-                "expected to find unit designator suffix \
-                 (e.g., 'years' or 'secs'), \
-                 but found end of input",
-            );
-        } else {
-            anyhow::bail!(
-                "expected to find unit designator suffix \
-                 (e.g., 'years' or 'secs'), \
-                 but found input beginning with {found:?} instead",
-                found = std::str::from_utf8(input).unwrap_or("N/A"),
-            );
-        }
+        return Err(LookupError::new(input));
+    };
+    Ok((unit, &input[offset..]))
+}
+
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    DESIGNATOR_TRIE.find(input)
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let Some((unit, offset)) = DESIGNATOR_TRIE.find_lowercase(input) else {
+        return Err(LookupError::new_ignore_ascii_case(input));
     };
     Ok((unit, &input[offset..]))
 }
 
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    DESIGNATOR_TRIE.find_lowercase(input)
+}
+
+/// Returns the footprint of this technique. The trie is entirely static.
+pub(crate) fn footprint() -> Footprint {
+    Footprint {
+        name: "by-trie2",
+        module: "duration_unit_lookup::by_trie2",
+        code: &["duration_unit_lookup::trie2"],
+        static_bytes: std::mem::size_of::<DesignatorTrie>(),
+        heap_bytes: 0,
+        lazy_init: None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
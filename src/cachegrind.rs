@@ -0,0 +1,328 @@
+/*!
+Deterministic benchmarks that count instructions instead of measuring time.
+
+The Criterion benchmarks in `bench.rs` report wall clock time, which varies
+from run to run by more than the difference between some of the techniques.
+This benchmark instead runs each technique under Valgrind's `cachegrind`
+tool, which simulates the CPU and counts the instructions executed, branch
+mispredictions and cache misses. Those counts are the same on every run, so
+even small changes can be judged reliably.
+
+Each measurement runs this same executable under `cachegrind` twice: once
+doing a single lookup and once doing `1 + ITERATIONS` lookups. The difference
+between the two is divided by `ITERATIONS`, which cancels out everything
+except the lookups themselves (like process startup and building the
+`aho-corasick` DFA on first use).
+
+Run it with:
+
+```text
+cargo bench --bench cachegrind
+cargo bench --bench cachegrind -- trie --json results.json
+```
+
+Any positional argument filters the benchmarks to those whose name (e.g.,
+`by-trie5/long`) contains it. With `--json <path>`, the results are also
+written as JSON to the path given. With `--json -`, the JSON is written to
+stdout and the table to stderr. If Valgrind isn't installed, this prints a
+message and exits successfully without measuring anything.
+
+Just like Criterion's harness, nothing is measured unless Cargo passes
+`--bench`, so that `cargo test --all-targets` doesn't run the whole sweep.
+*/
+
+use std::{
+    hint::black_box as bb,
+    io::Write,
+    path::Path,
+    process::{Command, ExitCode},
+};
+
+use duration_unit_lookup::*;
+
+/// The number of lookups measured in each benchmark.
+///
+/// Every lookup is simulated, so this is a lot smaller than what Criterion
+/// does. Since the counts are deterministic, it doesn't need to be big.
+const ITERATIONS: u64 = 10_000;
+
+/// The argument that tells this executable to run a benchmark directly,
+/// instead of running it under `cachegrind`.
+const CHILD: &str = "--cachegrind-child";
+
+/// The inputs every technique is measured with.
+///
+/// The first three are the same as the Criterion `short`, `medium` and `long`
+/// cases. `invalid` measures the failure path.
+const CASES: &[(&str, &[u8])] = &[
+    ("short", b"y 5 months"),
+    ("medium", b"months 5 days"),
+    ("long", b"milliseconds 5 nanoseconds"),
+    ("invalid", b"minutez 5 days"),
+];
+
+/// The events recorded by `cachegrind` when cache and branch simulation are
+/// enabled, in the order they are reported.
+///
+/// See the "Cachegrind" chapter of the Valgrind manual for what each one
+/// means.
+const EVENTS: &[&str] = &[
+    "Ir", "I1mr", "ILmr", "Dr", "D1mr", "DLmr", "Dw", "D1mw", "DLmw", "Bc",
+    "Bcm", "Bi", "Bim",
+];
+
+/// Defines `techniques`, which returns the name of every technique, and
+/// `run`, which runs `lookup` with the technique of the name given.
+///
+/// Like the Criterion benchmarks, every technique is called directly rather
+/// than through a `&dyn UnitLookup`.
+macro_rules! techniques {
+    ($($strategy:path),* $(,)?) => {
+        fn techniques() -> Vec<&'static str> {
+            vec![$($strategy.name()),*]
+        }
+
+        fn run(name: &str, input: &[u8], iterations: u64) {
+            $(
+                if name == $strategy.name() {
+                    return lookups($strategy, input, iterations);
+                }
+            )*
+            panic!("unknown technique {name:?}");
+        }
+    };
+}
+
+lookup_strategies!(techniques);
+
+/// Runs `lookup` on the input given with the technique given `iterations`
+/// times.
+fn lookups<L: UnitLookup>(strategy: L, input: &[u8], iterations: u64) {
+    for _ in 0..iterations {
+        let _ = bb(strategy.lookup(bb(input)));
+    }
+}
+
+/// The counts recorded for a single lookup, averaged over `ITERATIONS`.
+struct Counts {
+    instructions: f64,
+    branch_mispredicts: f64,
+    l1_misses: f64,
+    ll_misses: f64,
+}
+
+impl Counts {
+    /// Computes the counts per lookup from the events of a run with a single
+    /// lookup and a run with `1 + ITERATIONS` lookups.
+    fn new(baseline: &[u64], measured: &[u64]) -> Counts {
+        let event = |name: &str| {
+            let i = EVENTS.iter().position(|&e| e == name).unwrap();
+            let diff = measured[i].saturating_sub(baseline[i]);
+            diff as f64 / ITERATIONS as f64
+        };
+        Counts {
+            instructions: event("Ir"),
+            branch_mispredicts: event("Bcm") + event("Bim"),
+            l1_misses: event("I1mr") + event("D1mr") + event("D1mw"),
+            ll_misses: event("ILmr") + event("DLmr") + event("DLmw"),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let mut filters = vec![];
+    let mut json = None;
+    let mut bench = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            CHILD => {
+                let name = args.next().unwrap();
+                let case = args.next().unwrap();
+                let iterations = args.next().unwrap().parse().unwrap();
+                let &(_, input) =
+                    CASES.iter().find(|&&(c, _)| c == case).unwrap();
+                run(&name, input, iterations);
+                return ExitCode::SUCCESS;
+            }
+            "--json" => {
+                json = Some(args.next().expect("--json needs a path"));
+            }
+            "--bench" => bench = true,
+            // `cargo bench -- <flags>` passes Criterion's flags here too.
+            _ if arg.starts_with('-') => {}
+            _ => filters.push(arg),
+        }
+    }
+    // `cargo bench` passes `--bench`, but `cargo test` doesn't.
+    if !bench {
+        return ExitCode::SUCCESS;
+    }
+
+    let version = Command::new("valgrind").arg("--version").output();
+    match version {
+        Ok(out) if out.status.success() => {}
+        _ => {
+            eprintln!(
+                "valgrind not found, skipping the cachegrind benchmarks \
+                 (install valgrind to run them)",
+            );
+            return ExitCode::SUCCESS;
+        }
+    }
+
+    // When the JSON goes to stdout, the table goes to stderr so that the
+    // JSON can be piped somewhere else.
+    let mut table: Box<dyn Write> = if json.as_deref() == Some("-") {
+        Box::new(std::io::stderr())
+    } else {
+        Box::new(std::io::stdout())
+    };
+    let mut results = vec![];
+    writeln!(
+        table,
+        "{:<32} {:>12} {:>12} {:>12} {:>12}",
+        "benchmark", "instructions", "mispredicts", "L1 misses", "LL misses",
+    )
+    .unwrap();
+    for name in techniques() {
+        for &(case, _) in CASES {
+            let bench = format!("{name}/{case}");
+            if !filters.is_empty()
+                && !filters.iter().any(|f| bench.contains(f))
+            {
+                continue;
+            }
+            let baseline = match cachegrind(name, case, 1) {
+                Ok(events) => events,
+                Err(err) => {
+                    eprintln!("{bench}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let measured = match cachegrind(name, case, 1 + ITERATIONS) {
+                Ok(events) => events,
+                Err(err) => {
+                    eprintln!("{bench}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let counts = Counts::new(&baseline, &measured);
+            writeln!(
+                table,
+                "{bench:<32} {:>12.2} {:>12.2} {:>12.2} {:>12.2}",
+                counts.instructions,
+                counts.branch_mispredicts,
+                counts.l1_misses,
+                counts.ll_misses,
+            )
+            .unwrap();
+            results.push((name, case, counts));
+        }
+    }
+
+    if let Some(path) = json {
+        let json = to_json(&results);
+        if path == "-" {
+            print!("{json}");
+        } else if let Err(err) = std::fs::write(&path, json) {
+            eprintln!("failed to write {path}: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Runs the benchmark given under `cachegrind` and returns the total of
+/// every event in `EVENTS`, in the same order.
+fn cachegrind(
+    name: &str,
+    case: &str,
+    iterations: u64,
+) -> Result<Vec<u64>, String> {
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let out = std::env::temp_dir().join(format!(
+        "cachegrind.{}.{name}.{case}.{iterations}.out",
+        std::process::id(),
+    ));
+    let status = Command::new("valgrind")
+        .arg("--tool=cachegrind")
+        .arg("--cache-sim=yes")
+        .arg("--branch-sim=yes")
+        .arg(format!("--cachegrind-out-file={}", out.display()))
+        .arg(&exe)
+        .arg(CHILD)
+        .arg(name)
+        .arg(case)
+        .arg(iterations.to_string())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map_err(|e| format!("failed to run valgrind: {e}"))?;
+    if !status.success() {
+        return Err(format!("valgrind failed: {status}"));
+    }
+    let events = parse(&out);
+    let _ = std::fs::remove_file(&out);
+    events
+}
+
+/// Parses a `cachegrind` output file and returns the total of every event in
+/// `EVENTS`, in the same order.
+///
+/// The totals are read from the `summary:` line. Events that weren't
+/// recorded are reported as zero.
+fn parse(path: &Path) -> Result<Vec<u64>, String> {
+    let data = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let mut names: Vec<&str> = vec![];
+    let mut summary: Vec<u64> = vec![];
+    for line in data.lines() {
+        if let Some(rest) = line.strip_prefix("events:") {
+            names = rest.split_whitespace().collect();
+        } else if let Some(rest) = line.strip_prefix("summary:") {
+            summary = rest
+                .split_whitespace()
+                .map(|n| n.parse().map_err(|e| format!("{n:?}: {e}")))
+                .collect::<Result<_, _>>()?;
+        }
+    }
+    if names.is_empty() || summary.is_empty() {
+        return Err(format!("no summary found in {}", path.display()));
+    }
+    Ok(EVENTS
+        .iter()
+        .map(|event| {
+            names
+                .iter()
+                .position(|name| name == event)
+                .and_then(|i| summary.get(i).copied())
+                .unwrap_or(0)
+        })
+        .collect())
+}
+
+/// Formats the results as a JSON object.
+///
+/// Technique and case names never need escaping, so this doesn't bother
+/// with a JSON library.
+fn to_json(results: &[(&str, &str, Counts)]) -> String {
+    let mut json = String::new();
+    json.push_str("{\n");
+    json.push_str(&format!("  \"iterations\": {ITERATIONS},\n"));
+    json.push_str("  \"results\": [");
+    for (i, (name, case, counts)) in results.iter().enumerate() {
+        json.push_str(if i == 0 { "\n" } else { ",\n" });
+        json.push_str(&format!(
+            "    {{\"technique\": \"{name}\", \"case\": \"{case}\", \
+             \"instructions\": {:.2}, \"branch_mispredicts\": {:.2}, \
+             \"l1_misses\": {:.2}, \"ll_misses\": {:.2}}}",
+            counts.instructions,
+            counts.branch_mispredicts,
+            counts.l1_misses,
+            counts.ll_misses,
+        ));
+    }
+    json.push_str("\n  ]\n}\n");
+    json
+}
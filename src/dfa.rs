@@ -0,0 +1,159 @@
+// This is the search loop for the table based DFAs generated by `gendfa` with
+// `--rust-table`. The tables themselves are generated from a minimized
+// `regex-automata` DFA, so unlike the tries (which are built by hand and
+// have one node per distinct prefix), states that behave the same are
+// merged. The search loop is otherwise very similar to `trie4`: we walk the
+// transitions one byte at a time and stop as soon as we hit a state that
+// needs special attention.
+//
+// The state identifiers in the tables are premultiplied by the stride (the
+// number of byte classes plus one for the EOI class, rounded up to a power of
+// 2), so that they can be used as an index into the transition table
+// directly. The generator picks the smallest unsigned integer type that can
+// represent every premultiplied identifier.
+//
+// All of the special states come first. The dead state is always `0`,
+// followed by every state that reports a match as soon as it is entered.
+// This makes it possible to check for both with one comparison:
+//
+//     if sid <= max_match {
+//         return matches[sid >> stride2];
+//     }
+//
+// (The match reported for the dead state is `None`.)
+//
+// Since matches are delayed by one byte, a match state is entered by the byte
+// following the match (or by the EOI transition). So the match ends right
+// before the byte that was just looked at, or at the end of the haystack.
+// Only the values are stored in the table, since the same match state can be
+// entered at different positions when the DFA has cycles.
+
+/// A state identifier in a table based DFA.
+pub(crate) trait StateId: Copy + PartialOrd {
+    fn as_usize(self) -> usize;
+}
+
+impl StateId for u8 {
+    #[inline(always)]
+    fn as_usize(self) -> usize {
+        usize::from(self)
+    }
+}
+
+impl StateId for u16 {
+    #[inline(always)]
+    fn as_usize(self) -> usize {
+        usize::from(self)
+    }
+}
+
+impl StateId for u32 {
+    #[inline(always)]
+    fn as_usize(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Dfa<
+    S: 'static,
+    const TRANSITION_LEN: usize,
+    const MATCH_LEN: usize,
+    V: 'static,
+> {
+    /// A map from every byte value to its equivalence class.
+    pub(crate) classes: [u8; 256],
+    /// The transitions of every state, with a row of `1 << stride2`
+    /// transitions per state. The class `eoi` in each row is the transition
+    /// taken at the end of the haystack.
+    pub(crate) transitions: [S; TRANSITION_LEN],
+    /// The match reported by each special state, indexed by its state
+    /// identifier shifted right by `stride2`.
+    pub(crate) matches: [Option<V>; MATCH_LEN],
+    pub(crate) start: S,
+    pub(crate) max_match: S,
+    pub(crate) stride2: u32,
+    pub(crate) eoi: u8,
+}
+
+impl<
+        S: StateId,
+        const TRANSITION_LEN: usize,
+        const MATCH_LEN: usize,
+        V: Copy + 'static,
+    > Dfa<S, TRANSITION_LEN, MATCH_LEN, V>
+{
+    #[inline(always)]
+    pub(crate) fn find(&self, haystack: &[u8]) -> Option<(V, usize)> {
+        let mut sid = self.start;
+        for (at, &byte) in haystack.iter().enumerate() {
+            let class = self.classes[usize::from(byte)];
+            sid = self.transitions[sid.as_usize() + usize::from(class)];
+            if sid <= self.max_match {
+                return self.matches[sid.as_usize() >> self.stride2]
+                    .map(|value| (value, at));
+            }
+        }
+        sid = self.transitions[sid.as_usize() + usize::from(self.eoi)];
+        if sid <= self.max_match {
+            self.matches[sid.as_usize() >> self.stride2]
+                .map(|value| (value, haystack.len()))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A DFA for `foo` and `bar`, written by hand.
+    ///
+    /// The classes are `1` through `5` for `f`, `o`, `b`, `a` and `r`, `0`
+    /// for every other byte and `6` for EOI. That's a stride of 8. The states
+    /// are the dead state `0`, the match states for `foo` (`8`) and `bar`
+    /// (`16`), the start state (`24`) and then the states after `f`, `fo`,
+    /// `foo`, `b`, `ba` and `bar` (`32` through `72`). Just like in the
+    /// generated DFAs, matches are delayed by one byte.
+    fn foobar() -> Dfa<u8, 80, 3, char> {
+        let mut classes = [0; 256];
+        for (class, &byte) in b"fobar".iter().enumerate() {
+            classes[usize::from(byte)] = class as u8 + 1;
+        }
+        let mut transitions = [0; 80];
+        let mut set = |from: u8, class: u8, to: u8| {
+            transitions[usize::from(from + class)] = to;
+        };
+        set(24, 1, 32);
+        set(32, 2, 40);
+        set(40, 2, 48);
+        set(24, 3, 56);
+        set(56, 4, 64);
+        set(64, 5, 72);
+        for class in 0..=6 {
+            set(48, class, 8);
+            set(72, class, 16);
+        }
+        Dfa {
+            classes,
+            transitions,
+            matches: [None, Some('a'), Some('b')],
+            start: 24,
+            max_match: 16,
+            stride2: 3,
+            eoi: 6,
+        }
+    }
+
+    #[test]
+    fn fubar() {
+        let dfa = foobar();
+        assert_eq!(dfa.find(b""), None);
+        assert_eq!(dfa.find(b"fo"), None);
+        assert_eq!(dfa.find(b"foo"), Some(('a', 3)));
+        assert_eq!(dfa.find(b"bar"), Some(('b', 3)));
+        assert_eq!(dfa.find(b"foobar"), Some(('a', 3)));
+        assert_eq!(dfa.find(b"quux"), None);
+    }
+}
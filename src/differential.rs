@@ -0,0 +1,352 @@
+/*!
+A differential check that every lookup technique behaves identically, save
+for where a label ends.
+
+There are many independent implementations of the same function in this
+crate, and the unit tests for each one only cover a handful of inputs. The
+[`check`] routine in this module runs every technique in
+[`STRATEGIES`](crate::STRATEGIES), along with its inlined variant in
+[`INLINE_STRATEGIES`](crate::INLINE_STRATEGIES), on the same input and
+panics if any of them disagree. It is used by the fuzz target in `fuzz/`, and
+by a test below that runs it on lots of randomly generated inputs without
+needing libFuzzer.
+
+The techniques do differ in where a label ends when it's immediately
+followed by more bytes that may appear in a label. The ones that scan for
+the full label first (`one-big-match`, `phf`, `hashify` and `by-trie5`)
+reject both `daysx` and `dax`. The generated DFAs recognize `days` in
+`daysx`, but reject `dax`, since they only report a match where the DFA
+stops and `da` could still have become `day`. The rest report the longest
+label they find, so they recognize `days` in `daysx` and `d` in `dax`. So
+[`check`] normalizes every result the way a scan would have it, by
+discarding a label that is immediately followed by a byte that may appear
+in a label, before comparing it. The techniques themselves are left alone,
+since all of them agree whenever a label is followed by something else,
+like a space or a digit, which is all that a duration parser needs.
+
+The random test runs a modest number of iterations by default. For a longer
+local run, set `DIFFERENTIAL_ITERATIONS` (and optionally
+`DIFFERENTIAL_SEED`):
+
+```text
+DIFFERENTIAL_ITERATIONS=100000000 cargo test --release differential
+```
+*/
+
+use crate::{
+    is_designator_byte, is_designator_byte_ignore_ascii_case, labels::LABELS,
+    LookupError, Unit, UnitLookup, INLINE_STRATEGIES, STRATEGIES,
+};
+
+/// Runs every technique on `input` and panics if any two of them disagree,
+/// once their results are normalized.
+///
+/// Specifically, this checks that:
+///
+/// * Every label that `lookup` finds is a prefix of the input, and is
+///   mapped to the unit found.
+/// * Once the labels that are immediately followed by a byte that may
+///   appear in a label are discarded, `lookup` either returns the same unit
+///   and remaining input for every technique, or fails with the same error
+///   for every technique.
+/// * `find` agrees with `lookup`.
+/// * The same is true of the ASCII case insensitive routines.
+/// * Looking up a label while ignoring ASCII case is the same as looking up
+///   the ASCII lowercase form of the input.
+///
+/// This returns true if any label was discarded, i.e., if the techniques
+/// only agree because of the normalization, in either the case sensitive or
+/// the ASCII case insensitive routines.
+pub fn check(input: &[u8]) -> bool {
+    let normalized = check_mode(input, false);
+    check_mode(input, true) || normalized
+}
+
+/// Like [`check`], but only checks either the case sensitive routines or the
+/// ASCII case insensitive ones.
+fn check_mode(input: &[u8], ci: bool) -> bool {
+    let mode = if ci { "ASCII case insensitive lookup" } else { "lookup" };
+    let reference = STRATEGIES[0];
+    let expected = lookup(reference, input, ci);
+    assert!(
+        expected.as_ref().map_or(true, |&(_, rest)| is_label_end(rest, ci)),
+        "{} is expected to scan for the full label",
+        reference.name(),
+    );
+    let lowercase = input.to_ascii_lowercase();
+    let mut normalized = false;
+    for strategy in STRATEGIES.iter().chain(INLINE_STRATEGIES) {
+        let name = strategy.name();
+        let got = lookup(*strategy, input, ci);
+        if let Ok((unit, rest)) = got {
+            let label = &input[..input.len() - rest.len()];
+            assert!(
+                LABELS.iter().any(|&(l, u)| {
+                    u == unit
+                        && if ci {
+                            l.as_bytes().eq_ignore_ascii_case(label)
+                        } else {
+                            l.as_bytes() == label
+                        }
+                }),
+                "{name} found {unit:?} in {mode} of \"{}\", which doesn't \
+                 begin with one of its labels",
+                input.escape_ascii(),
+            );
+        }
+        assert_eq!(
+            find(*strategy, input, ci),
+            to_find(input, got.ok()),
+            "{name} find disagrees with {mode} of \"{}\"",
+            input.escape_ascii(),
+        );
+        if ci {
+            assert_eq!(
+                to_find(input, got.ok()),
+                to_find(&lowercase, strategy.lookup(&lowercase).ok()),
+                "{name} ASCII case insensitive lookup of \"{}\" disagrees \
+                 with lookup of its lowercase form",
+                input.escape_ascii(),
+            );
+        }
+
+        let got = match got {
+            Ok((_, rest)) if !is_label_end(rest, ci) => {
+                normalized = true;
+                Err(if ci {
+                    LookupError::new_ignore_ascii_case(input)
+                } else {
+                    LookupError::new(input)
+                })
+            }
+            got => got,
+        };
+        assert_eq!(
+            got,
+            expected,
+            "{name} disagrees with {} on {mode} of \"{}\"",
+            reference.name(),
+            input.escape_ascii(),
+        );
+    }
+    normalized
+}
+
+/// Returns true when `rest`, the input following a label, doesn't begin
+/// with a byte that may appear in a label. That is, when a technique that
+/// scans for the full label first would have found the same label.
+pub(crate) fn is_label_end(rest: &[u8], ci: bool) -> bool {
+    match rest.first() {
+        None => true,
+        Some(&b) if ci => !is_designator_byte_ignore_ascii_case(b),
+        Some(&b) => !is_designator_byte(b),
+    }
+}
+
+fn lookup<'i>(
+    strategy: &dyn UnitLookup,
+    input: &'i [u8],
+    ci: bool,
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    if ci {
+        strategy.lookup_ignore_ascii_case(input)
+    } else {
+        strategy.lookup(input)
+    }
+}
+
+fn find(
+    strategy: &dyn UnitLookup,
+    input: &[u8],
+    ci: bool,
+) -> Option<(Unit, usize)> {
+    if ci {
+        strategy.find_ignore_ascii_case(input)
+    } else {
+        strategy.find(input)
+    }
+}
+
+/// Converts the result of a successful `lookup` into the corresponding
+/// result of `find`.
+fn to_find(
+    input: &[u8],
+    found: Option<(Unit, &[u8])>,
+) -> Option<(Unit, usize)> {
+    found.map(|(unit, remaining)| (unit, input.len() - remaining.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::Rng;
+
+    use super::*;
+
+    /// Bytes that are interesting to put next to a label. That is, bytes
+    /// that may be part of a label, the bytes that separate components of a
+    /// friendly duration and a few that are neither.
+    const INTERESTING: &[u8] =
+        b"abcdeghiklmnorstuwyxzMSHYDN\xC2\xB5 ,5\t-\x00\xFF\xC3\x80";
+
+    fn env_or(name: &str, default: u64) -> u64 {
+        match std::env::var(name) {
+            Ok(value) => value.parse().expect("invalid integer"),
+            Err(_) => default,
+        }
+    }
+
+    /// Generates an input that is likely to begin with a known label, or
+    /// something close to one.
+    fn generate(rng: &mut Rng, out: &mut Vec<u8>) {
+        out.clear();
+        for _ in 0..1 + rng.below(3) {
+            match rng.below(6) {
+                // A whole label, possibly with some letters uppercased.
+                0 | 1 => {
+                    let (label, _) = LABELS[rng.below(LABELS.len())];
+                    for &byte in label.as_bytes() {
+                        out.push(if rng.below(4) == 0 {
+                            byte.to_ascii_uppercase()
+                        } else {
+                            byte
+                        });
+                    }
+                }
+                // A truncated label.
+                2 => {
+                    let (label, _) = LABELS[rng.below(LABELS.len())];
+                    out.extend_from_slice(
+                        &label.as_bytes()[..rng.below(label.len())],
+                    );
+                }
+                // A label with one of its bytes replaced.
+                3 => {
+                    let (label, _) = LABELS[rng.below(LABELS.len())];
+                    let start = out.len();
+                    out.extend_from_slice(label.as_bytes());
+                    let at = start + rng.below(label.len());
+                    out[at] = INTERESTING[rng.below(INTERESTING.len())];
+                }
+                // A few interesting bytes.
+                4 => {
+                    for _ in 0..1 + rng.below(3) {
+                        out.push(INTERESTING[rng.below(INTERESTING.len())]);
+                    }
+                }
+                // Completely arbitrary bytes.
+                _ => {
+                    for _ in 0..rng.below(4) {
+                        out.push(rng.next_u64() as u8);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn known() {
+        // Each input along with whether some technique finds a label that
+        // is discarded when normalizing.
+        let inputs: &[(&[u8], bool)] = &[
+            (b"", false),
+            (b"daysx", true),
+            (b"days5", false),
+            (b"days 5", false),
+            (b"dayS", false),
+            (b"dax", true),
+            (b"da5", true),
+            (b"d ", false),
+            (b"DAYSx", true),
+            (b"m", false),
+            (b"mx", true),
+            (b"mo", false),
+            (b"mon", true),
+            (b"msx", true),
+            (b"msec\xC2", true),
+            (b"\xC2\xB5", false),
+            (b"\xC2\xB5s", false),
+            (b"\xC2\xB5sx", true),
+            (b"\xC2\xB5s\xC2\xB5", true),
+            (b"\xC2\xB5S", false),
+            (b"\xC3\x80", false),
+            (b"years\xFF", false),
+            (b"yearsZ", true),
+        ];
+        for &(input, normalizes) in inputs {
+            assert_eq!(
+                check(input),
+                normalizes,
+                "unexpected normalization of \"{}\"",
+                input.escape_ascii(),
+            );
+        }
+    }
+
+    /// Checks every input that could possibly make a difference.
+    ///
+    /// Whether a label is found only depends on the longest prefix of the
+    /// input that is also a prefix of some label, and on the byte following
+    /// it. So it's enough to check every prefix of every label, each followed
+    /// by every possible byte.
+    ///
+    /// Since the longest label is 12 bytes, this covers every input up to 13
+    /// bytes long that matters, including the ones ending with a space, a
+    /// digit, an uppercase letter or half of a `µ`. (Errors are always built
+    /// from the whole input by the same routine, so the bytes following an
+    /// unknown label can't make techniques disagree either.)
+    ///
+    /// Each input is also checked with all ASCII letters uppercased, and
+    /// with each ASCII letter of the prefix uppercased on its own. So the
+    /// ASCII case insensitive routines see labels in mixed case too, not
+    /// just all lowercase or all uppercase.
+    #[test]
+    fn exhaustive() {
+        let mut prefixes = std::collections::BTreeSet::new();
+        for &(label, _) in LABELS {
+            for end in 0..=label.len() {
+                prefixes.insert(&label.as_bytes()[..end]);
+            }
+        }
+        // Just make sure we aren't vacuously passing.
+        assert!(prefixes.len() > LABELS.len());
+
+        let mut input = vec![];
+        let mut normalized = 0;
+        for prefix in prefixes {
+            let mut variants = vec![prefix.to_vec()];
+            for at in 0..prefix.len() {
+                if prefix[at].is_ascii_lowercase() {
+                    let mut variant = prefix.to_vec();
+                    variant[at].make_ascii_uppercase();
+                    variants.push(variant);
+                }
+            }
+            variants.push(prefix.to_ascii_uppercase());
+            for variant in variants.iter() {
+                normalized += usize::from(check(variant));
+                for byte in 0..=255u8 {
+                    input.clear();
+                    input.extend_from_slice(variant);
+                    input.push(byte);
+                    assert!(input.len() <= crate::labels::MAX_LABEL_LEN + 1);
+                    normalized += usize::from(check(&input));
+                }
+            }
+        }
+        // Every label followed by a lowercase ASCII letter is normalized,
+        // at least.
+        assert!(normalized >= LABELS.len() * 26, "{normalized}");
+    }
+
+    #[test]
+    fn random() {
+        let iterations = env_or("DIFFERENTIAL_ITERATIONS", 200_000);
+        let seed = env_or("DIFFERENTIAL_SEED", Rng::DEFAULT_SEED);
+        let mut rng = Rng::new(seed);
+        let mut input = vec![];
+        for _ in 0..iterations {
+            generate(&mut rng, &mut input);
+            check(&input);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    designator_len, designator_len_ignore_ascii_case, suggest::Suggestions,
+};
+
+/// An error that occurs when a unit designator label could not be recognized.
+///
+/// Every technique in this crate reports failures with this type, and they
+/// all report them in the same way. In particular, the byte offsets in an
+/// [`LookupError::UnknownDesignator`] error are not affected by how far any
+/// particular technique happened to look into the input before giving up.
+///
+/// Building one of these never allocates. The offsets are relative to the
+/// beginning of the input given to `lookup`, which means `start` is always
+/// `0` when an error is returned directly by a lookup routine. Callers (like
+/// a parser) that know where that input begins in some larger string may
+/// adjust the offsets with [`LookupError::offset`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LookupError<'i> {
+    /// The input was empty, and so no label could be found.
+    EndOfInput,
+    /// The input did not begin with any known unit designator label.
+    UnknownDesignator {
+        /// The offset at which the unknown label begins.
+        start: usize,
+        /// The offset at which the unknown label ends.
+        ///
+        /// This is the end of the longest run of bytes that may appear in a
+        /// label, or `start + 1` if the first byte can't appear in any label.
+        end: usize,
+        /// The bytes in the range `start..end`.
+        found: &'i [u8],
+    },
+}
+
+impl<'i> LookupError<'i> {
+    /// Creates an error for when `input` doesn't begin with any known label.
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn new(input: &'i [u8]) -> LookupError<'i> {
+        if input.is_empty() {
+            return LookupError::EndOfInput;
+        }
+        let end = designator_len(input).max(1);
+        LookupError::UnknownDesignator { start: 0, end, found: &input[..end] }
+    }
+
+    /// Like `new`, but for the ASCII case insensitive lookup routines.
+    ///
+    /// The only difference is that uppercase ASCII letters are included in
+    /// the unknown label reported.
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn new_ignore_ascii_case(input: &'i [u8]) -> LookupError<'i> {
+        if input.is_empty() {
+            return LookupError::EndOfInput;
+        }
+        let end = designator_len_ignore_ascii_case(input).max(1);
+        LookupError::UnknownDesignator { start: 0, end, found: &input[..end] }
+    }
+
+    /// Shifts the offsets in this error by `offset` bytes.
+    ///
+    /// This is useful when the input given to `lookup` is itself a suffix
+    /// of some larger string, and errors ought to report offsets into that
+    /// larger string.
+    pub fn offset(self, offset: usize) -> LookupError<'i> {
+        match self {
+            LookupError::EndOfInput => LookupError::EndOfInput,
+            LookupError::UnknownDesignator { start, end, found } => {
+                LookupError::UnknownDesignator {
+                    start: start + offset,
+                    end: end + offset,
+                    found,
+                }
+            }
+        }
+    }
+
+    /// Returns the known labels that are closest to the unknown label in
+    /// this error, ranked by edit distance.
+    ///
+    /// For example, if the input was `minuts`, then this returns `minutes`,
+    /// `minute` and `mins`, each with [`Unit::Minute`](crate::Unit::Minute).
+    ///
+    /// Suggestions are computed each time this is called. This never
+    /// allocates. When this error is [`LookupError::EndOfInput`], or when no
+    /// known label is close enough, the suggestions returned are empty.
+    pub fn suggestions(&self) -> Suggestions {
+        match *self {
+            LookupError::EndOfInput => Suggestions::new(b""),
+            LookupError::UnknownDesignator { found, .. } => {
+                Suggestions::new(found)
+            }
+        }
+    }
+}
+
+impl<'i> std::error::Error for LookupError<'i> {}
+
+impl<'i> std::fmt::Display for LookupError<'i> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "expected to find unit designator suffix \
+             (e.g., 'years' or 'secs'), ",
+        )?;
+        match *self {
+            LookupError::EndOfInput => write!(f, "but found end of input"),
+            LookupError::UnknownDesignator { found, .. } => {
+                write!(f, "but found input beginning with \"")?;
+                for chunk in found.utf8_chunks() {
+                    write!(f, "{}", chunk.valid().escape_debug())?;
+                    for &byte in chunk.invalid() {
+                        write!(f, "\\x{byte:02X}")?;
+                    }
+                }
+                write!(f, "\" instead")?;
+                let suggestions = self.suggestions();
+                if !suggestions.is_empty() {
+                    write!(f, " (did you mean {suggestions}?)")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Unit, STRATEGIES};
+
+    use super::*;
+
+    #[test]
+    fn consistent() {
+        let inputs: &[&[u8]] =
+            &[b"", b"zebras", b"zebras 5 days", b" 5 days", b"\xC2", b"5"];
+        for &input in inputs {
+            let expected = LookupError::new(input);
+            for strategy in STRATEGIES {
+                let name = strategy.name();
+                let err = strategy.lookup(input).unwrap_err();
+                assert_eq!(err, expected, "{name}: {input:?}");
+                let err =
+                    strategy.lookup_ignore_ascii_case(input).unwrap_err();
+                assert_eq!(err, expected, "{name}: {input:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(LookupError::new(b""), LookupError::EndOfInput);
+        assert_eq!(
+            LookupError::new(b"zebras 5 days"),
+            LookupError::UnknownDesignator {
+                start: 0,
+                end: 6,
+                found: b"zebras"
+            },
+        );
+        assert_eq!(
+            LookupError::new(b"5 days"),
+            LookupError::UnknownDesignator { start: 0, end: 1, found: b"5" },
+        );
+        assert_eq!(
+            LookupError::new(b"zebras 5 days").offset(2),
+            LookupError::UnknownDesignator {
+                start: 2,
+                end: 8,
+                found: b"zebras"
+            },
+        );
+        // Just make sure things still work when there's no error.
+        assert_eq!(
+            crate::one_big_match::lookup(b"days").unwrap(),
+            (Unit::Day, &b""[..]),
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            LookupError::EndOfInput.to_string(),
+            "expected to find unit designator suffix (e.g., 'years' or \
+             'secs'), but found end of input",
+        );
+        assert_eq!(
+            LookupError::new(b"zebras").to_string(),
+            "expected to find unit designator suffix (e.g., 'years' or \
+             'secs'), but found input beginning with \"zebras\" instead",
+        );
+        assert_eq!(
+            LookupError::new(b"\xC2\xB5x\xC2").to_string(),
+            "expected to find unit designator suffix (e.g., 'years' or \
+             'secs'), but found input beginning with \"µx\\xC2\" instead",
+        );
+        assert_eq!(
+            LookupError::new(b"minuts 5 secs").to_string(),
+            "expected to find unit designator suffix (e.g., 'years' or \
+             'secs'), but found input beginning with \"minuts\" instead \
+             (did you mean \"minutes\", \"minute\" or \"mins\"?)",
+        );
+    }
+
+    #[test]
+    fn suggestions() {
+        let err = crate::one_big_match::lookup(b"secnds").unwrap_err();
+        let got: Vec<(&str, Unit)> =
+            err.suggestions().iter().map(|s| (s.label(), s.unit())).collect();
+        assert_eq!(
+            got,
+            vec![
+                ("seconds", Unit::Second),
+                ("second", Unit::Second),
+                ("secs", Unit::Second),
+            ],
+        );
+        assert!(LookupError::EndOfInput.suggestions().is_empty());
+    }
+}
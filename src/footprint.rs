@@ -0,0 +1,190 @@
+/*!
+The memory footprint of every lookup technique.
+
+Every technique trades memory for speed in a different way. Some encode
+everything in machine code (like `one-big-match`), some use tables in static
+data (like the tries) and some build their data structures on the heap on
+first use (like `aho-corasick`). This module reports everything except the
+size of the machine code, which can only be measured from a compiled binary.
+The `footprint` tool in this repository does that, and prints both as a
+table:
+
+```text
+cargo run --release -p footprint
+```
+*/
+
+use std::time::{Duration, Instant};
+
+use crate::STRATEGIES;
+
+/// The memory footprint of a single technique.
+#[derive(Clone, Debug)]
+pub struct Footprint {
+    /// The name of the technique, as returned by `UnitLookup::name`.
+    pub name: &'static str,
+    /// The module containing the technique's `lookup` routine, e.g.,
+    /// `duration_unit_lookup::by_trie5`.
+    pub module: &'static str,
+    /// The paths of everything else whose machine code belongs to this
+    /// technique, e.g., the `trie5` module or the C functions generated for
+    /// `by-gencdfa1`.
+    pub code: &'static [&'static str],
+    /// The number of bytes of static data used, e.g., for trie tables.
+    pub static_bytes: usize,
+    /// The number of bytes of heap memory used once everything has been
+    /// initialized.
+    pub heap_bytes: usize,
+    /// The time it takes to build everything that is lazily initialized on
+    /// first use, if anything.
+    pub lazy_init: Option<Duration>,
+}
+
+impl Footprint {
+    /// Create a footprint for a technique that keeps all of its data in its
+    /// machine code.
+    fn code_only(
+        name: &'static str,
+        module: &'static str,
+        code: &'static [&'static str],
+    ) -> Footprint {
+        Footprint {
+            name,
+            module,
+            code,
+            static_bytes: 0,
+            heap_bytes: 0,
+            lazy_init: None,
+        }
+    }
+}
+
+impl Footprint {
+    /// Returns true if the function with the demangled path given belongs
+    /// to this technique, i.e., if it's `module`, one of the paths in `code`
+    /// or inside of any of them.
+    ///
+    /// Paths of methods in trait implementations look like
+    /// `<duration_unit_lookup::by_trie5::ByTrie5 as ...>::lookup`, so they
+    /// are attributed to the module of the type implementing the trait. Parts
+    /// that a C compiler splits off a function, like `gencdfa2_find.cold`,
+    /// are attributed to the function they were split off of.
+    pub fn contains(&self, path: &str) -> bool {
+        let path = path.trim_start_matches('<');
+        std::iter::once(self.module).chain(self.code.iter().copied()).any(
+            |prefix| match path.strip_prefix(prefix) {
+                None => false,
+                Some(rest) => {
+                    rest.is_empty()
+                        || rest.starts_with("::")
+                        || rest.starts_with('.')
+                }
+            },
+        )
+    }
+}
+
+/// Returns the footprint of every technique, in the same order as
+/// [`STRATEGIES`].
+///
+/// This forces any lazily initialized data to be built, and times how long
+/// it takes to build it again.
+pub fn all() -> Vec<Footprint> {
+    let footprints = vec![
+        Footprint::code_only(
+            "one-big-match",
+            "duration_unit_lookup::one_big_match",
+            &[],
+        ),
+        Footprint::code_only(
+            "one-big-match-prefix",
+            "duration_unit_lookup::one_big_match_prefix",
+            &[
+                "duration_unit_lookup::one_big_match_prefix_gen",
+                "duration_unit_lookup::one_big_match_prefix_ci_gen",
+            ],
+        ),
+        crate::aho::footprint(),
+        crate::phf::footprint(),
+        Footprint::code_only("hashify", "duration_unit_lookup::hashify", &[]),
+        crate::by_trie1::footprint(),
+        crate::by_trie2::footprint(),
+        crate::by_trie3::footprint(),
+        crate::by_trie4::footprint(),
+        crate::by_trie5::footprint(),
+        Footprint::code_only(
+            "by-gendfa1",
+            "duration_unit_lookup::by_gendfa1",
+            &[
+                "duration_unit_lookup::gendfa1",
+                "duration_unit_lookup::gendfa1_ci",
+            ],
+        ),
+        Footprint::code_only(
+            "by-gendfa2",
+            "duration_unit_lookup::by_gendfa2",
+            &[
+                "duration_unit_lookup::gendfa2",
+                "duration_unit_lookup::gendfa2_ci",
+            ],
+        ),
+        Footprint::code_only(
+            "by-gendfa3",
+            "duration_unit_lookup::by_gendfa3",
+            &[
+                "duration_unit_lookup::gendfa3",
+                "duration_unit_lookup::gendfa3_ci",
+            ],
+        ),
+        Footprint::code_only(
+            "by-gendfa4",
+            "duration_unit_lookup::by_gendfa4",
+            &[
+                "duration_unit_lookup::gendfa4",
+                "duration_unit_lookup::gendfa4_ci",
+            ],
+        ),
+        crate::by_gendfa5::footprint(),
+        crate::by_gendfa6::footprint(),
+        Footprint::code_only(
+            "by-gencdfa1",
+            "duration_unit_lookup::by_gencdfa1",
+            &["gencdfa1_find", "gencdfa1_ci_find"],
+        ),
+        crate::by_gencdfa2::footprint(),
+    ];
+    debug_assert_eq!(footprints.len(), STRATEGIES.len());
+    footprints
+}
+
+/// Returns the time it takes to call `build`.
+pub(crate) fn time<T>(build: impl FnOnce() -> T) -> Duration {
+    let start = Instant::now();
+    std::hint::black_box(build());
+    start.elapsed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_strategy() {
+        let footprints = all();
+        for (footprint, strategy) in footprints.iter().zip(STRATEGIES) {
+            assert_eq!(footprint.name, strategy.name());
+        }
+
+        let by_name = |name: &str| {
+            footprints.iter().find(|f| f.name == name).unwrap().clone()
+        };
+        let aho = by_name("aho-corasick");
+        assert!(aho.heap_bytes > 0);
+        assert!(aho.lazy_init.is_some());
+        assert!(by_name("by-trie5").static_bytes > 0);
+        assert!(by_name("by-gendfa5").static_bytes > 0);
+        assert!(by_name("by-gendfa6").static_bytes > 0);
+        assert!(by_name("phf").static_bytes > 0);
+        assert_eq!(by_name("one-big-match").static_bytes, 0);
+    }
+}
@@ -0,0 +1,1095 @@
+#include <stddef.h>
+#include <stdint.h>
+
+enum unit {
+  Year = 9,
+  Month = 8,
+  Week = 7,
+  Day = 6,
+  Hour = 5,
+  Minute = 4,
+  Second = 3,
+  Millisecond = 2,
+  Microsecond = 1,
+  Nanosecond = 0,
+};
+
+struct output {
+  enum unit unit;
+  size_t length;
+};
+
+static const uint8_t classes[256] = {
+   0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+   0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+   0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+   0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+   0, 1, 2, 3, 4, 5, 6, 6, 7, 8, 9, 10, 11, 12, 13, 14,
+   15, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 24, 24, 24, 24, 24,
+   24, 25, 26, 27, 28, 29, 30, 30, 31, 32, 33, 34, 35, 36, 37, 38,
+   39, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 48, 48, 48, 48, 48,
+   48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+   48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+   48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+   48, 48, 48, 48, 48, 49, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50,
+   50, 50, 51, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52,
+   52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52,
+   52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52,
+   52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52,
+};
+
+struct output gencdfa2_ci_find(uint8_t *p, uint8_t *end)
+{
+  struct output o = { .unit = Year, .length = 0 };
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 4: goto S1;
+    case 28: goto S1;
+    case 7: goto S2;
+    case 31: goto S2;
+    case 12: goto S3;
+    case 36: goto S3;
+    case 13: goto S4;
+    case 37: goto S4;
+    case 17: goto S5;
+    case 41: goto S5;
+    case 19: goto S6;
+    case 43: goto S6;
+    case 21: goto S7;
+    case 45: goto S7;
+    case 23: goto S8;
+    case 47: goto S8;
+    case 51: goto S9;
+    default: goto DONE;
+  }
+S1:
+  if (p >= end) {
+    goto S10;
+  }
+  switch (classes[*p++]) {
+    case 0: goto S10;
+    case 2 ... 24: goto S10;
+    case 26 ... 52: goto S10;
+    case 1: goto S11;
+    case 25: goto S11;
+  }
+S2:
+  if (p >= end) {
+    goto S12;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 13: goto S12;
+    case 15: goto S12;
+    case 17 ... 37: goto S12;
+    case 39: goto S12;
+    case 41 ... 52: goto S12;
+    case 14: goto S13;
+    case 38: goto S13;
+    case 16: goto S14;
+    case 40: goto S14;
+  }
+S3:
+  if (p >= end) {
+    goto S15;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 7: goto S15;
+    case 9 ... 13: goto S15;
+    case 15 ... 16: goto S15;
+    case 18 ... 31: goto S15;
+    case 33 ... 37: goto S15;
+    case 39 ... 40: goto S15;
+    case 42 ... 52: goto S15;
+    case 8: goto S16;
+    case 32: goto S16;
+    case 14: goto S17;
+    case 38: goto S17;
+    case 17: goto S18;
+    case 41: goto S18;
+  }
+S4:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 1: goto S19;
+    case 25: goto S19;
+    case 17: goto S20;
+    case 41: goto S20;
+    default: goto DONE;
+  }
+S5:
+  if (p >= end) {
+    goto S21;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 4: goto S21;
+    case 6 ... 28: goto S21;
+    case 30 ... 52: goto S21;
+    case 5: goto S22;
+    case 29: goto S22;
+  }
+S6:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 17: goto S23;
+    case 41: goto S23;
+    default: goto DONE;
+  }
+S7:
+  if (p >= end) {
+    goto S24;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 4: goto S24;
+    case 6 ... 9: goto S24;
+    case 11 ... 28: goto S24;
+    case 30 ... 33: goto S24;
+    case 35 ... 52: goto S24;
+    case 5: goto S25;
+    case 29: goto S25;
+    case 10: goto S26;
+    case 34: goto S26;
+  }
+S8:
+  if (p >= end) {
+    goto S27;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 4: goto S27;
+    case 6 ... 15: goto S27;
+    case 17 ... 28: goto S27;
+    case 30 ... 39: goto S27;
+    case 41 ... 52: goto S27;
+    case 5: goto S28;
+    case 29: goto S28;
+    case 16: goto S29;
+    case 40: goto S29;
+  }
+S9:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 49: goto S30;
+    default: goto DONE;
+  }
+S10:
+  o.unit = Day;
+  o.length = 1;
+  goto DONE;
+S11:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 23: goto S31;
+    case 47: goto S31;
+    default: goto DONE;
+  }
+S12:
+  o.unit = Hour;
+  o.length = 1;
+  goto DONE;
+S13:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 19: goto S32;
+    case 43: goto S32;
+    default: goto DONE;
+  }
+S14:
+  if (p >= end) {
+    goto S33;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S33;
+    case 18 ... 40: goto S33;
+    case 42 ... 52: goto S33;
+    case 17: goto S34;
+    case 41: goto S34;
+  }
+S15:
+  o.unit = Minute;
+  o.length = 1;
+  goto DONE;
+S16:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 13: goto S35;
+    case 37: goto S35;
+    case 11: goto S36;
+    case 35: goto S36;
+    case 3: goto S37;
+    case 27: goto S37;
+    default: goto DONE;
+  }
+S17:
+  if (p >= end) {
+    goto S38;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 12: goto S38;
+    case 14 ... 16: goto S38;
+    case 18 ... 36: goto S38;
+    case 38 ... 40: goto S38;
+    case 42 ... 52: goto S38;
+    case 13: goto S39;
+    case 37: goto S39;
+    case 17: goto S40;
+    case 41: goto S40;
+  }
+S18:
+  if (p >= end) {
+    goto S41;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 4: goto S41;
+    case 6 ... 28: goto S41;
+    case 30 ... 52: goto S41;
+    case 5: goto S42;
+    case 29: goto S42;
+  }
+S19:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 13: goto S43;
+    case 37: goto S43;
+    default: goto DONE;
+  }
+S20:
+  if (p >= end) {
+    goto S44;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 4: goto S44;
+    case 6 ... 28: goto S44;
+    case 30 ... 52: goto S44;
+    case 5: goto S45;
+    case 29: goto S45;
+  }
+S21:
+  o.unit = Second;
+  o.length = 1;
+  goto DONE;
+S22:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 3: goto S46;
+    case 27: goto S46;
+    default: goto DONE;
+  }
+S23:
+  if (p >= end) {
+    goto S47;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 4: goto S47;
+    case 6 ... 28: goto S47;
+    case 30 ... 52: goto S47;
+    case 5: goto S48;
+    case 29: goto S48;
+  }
+S24:
+  o.unit = Week;
+  o.length = 1;
+  goto DONE;
+S25:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 5: goto S49;
+    case 29: goto S49;
+    default: goto DONE;
+  }
+S26:
+  if (p >= end) {
+    goto S50;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S50;
+    case 18 ... 40: goto S50;
+    case 42 ... 52: goto S50;
+    case 17: goto S51;
+    case 41: goto S51;
+  }
+S27:
+  o.unit = Year;
+  o.length = 1;
+  goto DONE;
+S28:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 1: goto S52;
+    case 25: goto S52;
+    default: goto DONE;
+  }
+S29:
+  if (p >= end) {
+    goto S53;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S53;
+    case 18 ... 40: goto S53;
+    case 42 ... 52: goto S53;
+    case 17: goto S54;
+    case 41: goto S54;
+  }
+S30:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 17: goto S55;
+    case 41: goto S55;
+    default: goto DONE;
+  }
+S31:
+  if (p >= end) {
+    goto S56;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S56;
+    case 18 ... 40: goto S56;
+    case 42 ... 52: goto S56;
+    case 17: goto S57;
+    case 41: goto S57;
+  }
+S32:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 16: goto S58;
+    case 40: goto S58;
+    default: goto DONE;
+  }
+S33:
+  o.unit = Hour;
+  o.length = 2;
+  goto DONE;
+S34:
+  o.unit = Hour;
+  o.length = 3;
+  goto DONE;
+S35:
+  if (p >= end) {
+    goto S59;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S59;
+    case 18: goto S59;
+    case 20 ... 40: goto S59;
+    case 42: goto S59;
+    case 44 ... 52: goto S59;
+    case 17: goto S60;
+    case 41: goto S60;
+    case 19: goto S61;
+    case 43: goto S61;
+  }
+S36:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 11: goto S62;
+    case 35: goto S62;
+    default: goto DONE;
+  }
+S37:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 16: goto S63;
+    case 40: goto S63;
+    default: goto DONE;
+  }
+S38:
+  o.unit = Month;
+  o.length = 2;
+  goto DONE;
+S39:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 18: goto S64;
+    case 42: goto S64;
+    default: goto DONE;
+  }
+S40:
+  o.unit = Month;
+  o.length = 3;
+  goto DONE;
+S41:
+  o.unit = Millisecond;
+  o.length = 2;
+  goto DONE;
+S42:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 3: goto S65;
+    case 27: goto S65;
+    default: goto DONE;
+  }
+S43:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 14: goto S66;
+    case 38: goto S66;
+    default: goto DONE;
+  }
+S44:
+  o.unit = Nanosecond;
+  o.length = 2;
+  goto DONE;
+S45:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 3: goto S67;
+    case 27: goto S67;
+    default: goto DONE;
+  }
+S46:
+  if (p >= end) {
+    goto S68;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 13: goto S68;
+    case 15 ... 16: goto S68;
+    case 18 ... 37: goto S68;
+    case 39 ... 40: goto S68;
+    case 42 ... 52: goto S68;
+    case 14: goto S69;
+    case 38: goto S69;
+    case 17: goto S70;
+    case 41: goto S70;
+  }
+S47:
+  o.unit = Microsecond;
+  o.length = 2;
+  goto DONE;
+S48:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 3: goto S71;
+    case 27: goto S71;
+    default: goto DONE;
+  }
+S49:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 10: goto S72;
+    case 34: goto S72;
+    default: goto DONE;
+  }
+S50:
+  o.unit = Week;
+  o.length = 2;
+  goto DONE;
+S51:
+  o.unit = Week;
+  o.length = 3;
+  goto DONE;
+S52:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 16: goto S73;
+    case 40: goto S73;
+    default: goto DONE;
+  }
+S53:
+  o.unit = Year;
+  o.length = 2;
+  goto DONE;
+S54:
+  o.unit = Year;
+  o.length = 3;
+  goto DONE;
+S55:
+  if (p >= end) {
+    goto S74;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 4: goto S74;
+    case 6 ... 28: goto S74;
+    case 30 ... 52: goto S74;
+    case 5: goto S75;
+    case 29: goto S75;
+  }
+S56:
+  o.unit = Day;
+  o.length = 3;
+  goto DONE;
+S57:
+  o.unit = Day;
+  o.length = 4;
+  goto DONE;
+S58:
+  if (p >= end) {
+    goto S76;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S76;
+    case 18 ... 40: goto S76;
+    case 42 ... 52: goto S76;
+    case 17: goto S77;
+    case 41: goto S77;
+  }
+S59:
+  o.unit = Minute;
+  o.length = 3;
+  goto DONE;
+S60:
+  o.unit = Minute;
+  o.length = 4;
+  goto DONE;
+S61:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 18: goto S78;
+    case 42: goto S78;
+    default: goto DONE;
+  }
+S62:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 8: goto S79;
+    case 32: goto S79;
+    default: goto DONE;
+  }
+S63:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 14: goto S80;
+    case 38: goto S80;
+    default: goto DONE;
+  }
+S64:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 7: goto S81;
+    case 31: goto S81;
+    default: goto DONE;
+  }
+S65:
+  if (p >= end) {
+    goto S82;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S82;
+    case 18 ... 40: goto S82;
+    case 42 ... 52: goto S82;
+    case 17: goto S83;
+    case 41: goto S83;
+  }
+S66:
+  if (p >= end) {
+    goto S84;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S84;
+    case 18 ... 40: goto S84;
+    case 42 ... 52: goto S84;
+    case 17: goto S85;
+    case 41: goto S85;
+  }
+S67:
+  if (p >= end) {
+    goto S86;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S86;
+    case 18 ... 40: goto S86;
+    case 42 ... 52: goto S86;
+    case 17: goto S87;
+    case 41: goto S87;
+  }
+S68:
+  o.unit = Second;
+  o.length = 3;
+  goto DONE;
+S69:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 13: goto S88;
+    case 37: goto S88;
+    default: goto DONE;
+  }
+S70:
+  o.unit = Second;
+  o.length = 4;
+  goto DONE;
+S71:
+  if (p >= end) {
+    goto S89;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S89;
+    case 18 ... 40: goto S89;
+    case 42 ... 52: goto S89;
+    case 17: goto S90;
+    case 41: goto S90;
+  }
+S72:
+  if (p >= end) {
+    goto S91;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S91;
+    case 18 ... 40: goto S91;
+    case 42 ... 52: goto S91;
+    case 17: goto S92;
+    case 41: goto S92;
+  }
+S73:
+  if (p >= end) {
+    goto S93;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S93;
+    case 18 ... 40: goto S93;
+    case 42 ... 52: goto S93;
+    case 17: goto S94;
+    case 41: goto S94;
+  }
+S74:
+  o.unit = Microsecond;
+  o.length = 3;
+  goto DONE;
+S75:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 3: goto S95;
+    case 27: goto S95;
+    default: goto DONE;
+  }
+S76:
+  o.unit = Hour;
+  o.length = 4;
+  goto DONE;
+S77:
+  o.unit = Hour;
+  o.length = 5;
+  goto DONE;
+S78:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 5: goto S96;
+    case 29: goto S96;
+    default: goto DONE;
+  }
+S79:
+  if (p >= end) {
+    goto S97;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S97;
+    case 18 ... 40: goto S97;
+    case 42 ... 52: goto S97;
+    case 17: goto S98;
+    case 41: goto S98;
+  }
+S80:
+  if (p >= end) {
+    goto S99;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S99;
+    case 18 ... 40: goto S99;
+    case 42 ... 52: goto S99;
+    case 17: goto S100;
+    case 41: goto S100;
+  }
+S81:
+  if (p >= end) {
+    goto S101;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S101;
+    case 18 ... 40: goto S101;
+    case 42 ... 52: goto S101;
+    case 17: goto S102;
+    case 41: goto S102;
+  }
+S82:
+  o.unit = Millisecond;
+  o.length = 4;
+  goto DONE;
+S83:
+  o.unit = Millisecond;
+  o.length = 5;
+  goto DONE;
+S84:
+  o.unit = Nanosecond;
+  o.length = 4;
+  goto DONE;
+S85:
+  if (p >= end) {
+    goto S103;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 4: goto S103;
+    case 6 ... 28: goto S103;
+    case 30 ... 52: goto S103;
+    case 5: goto S104;
+    case 29: goto S104;
+  }
+S86:
+  o.unit = Nanosecond;
+  o.length = 4;
+  goto DONE;
+S87:
+  o.unit = Nanosecond;
+  o.length = 5;
+  goto DONE;
+S88:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 4: goto S105;
+    case 28: goto S105;
+    default: goto DONE;
+  }
+S89:
+  o.unit = Microsecond;
+  o.length = 4;
+  goto DONE;
+S90:
+  o.unit = Microsecond;
+  o.length = 5;
+  goto DONE;
+S91:
+  o.unit = Week;
+  o.length = 4;
+  goto DONE;
+S92:
+  o.unit = Week;
+  o.length = 5;
+  goto DONE;
+S93:
+  o.unit = Year;
+  o.length = 4;
+  goto DONE;
+S94:
+  o.unit = Year;
+  o.length = 5;
+  goto DONE;
+S95:
+  if (p >= end) {
+    goto S106;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S106;
+    case 18 ... 40: goto S106;
+    case 42 ... 52: goto S106;
+    case 17: goto S107;
+    case 41: goto S107;
+  }
+S96:
+  if (p >= end) {
+    goto S108;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S108;
+    case 18 ... 40: goto S108;
+    case 42 ... 52: goto S108;
+    case 17: goto S109;
+    case 41: goto S109;
+  }
+S97:
+  o.unit = Millisecond;
+  o.length = 5;
+  goto DONE;
+S98:
+  if (p >= end) {
+    goto S110;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 4: goto S110;
+    case 6 ... 28: goto S110;
+    case 30 ... 52: goto S110;
+    case 5: goto S111;
+    case 29: goto S111;
+  }
+S99:
+  o.unit = Microsecond;
+  o.length = 5;
+  goto DONE;
+S100:
+  if (p >= end) {
+    goto S112;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 4: goto S112;
+    case 6 ... 28: goto S112;
+    case 30 ... 52: goto S112;
+    case 5: goto S113;
+    case 29: goto S113;
+  }
+S101:
+  o.unit = Month;
+  o.length = 5;
+  goto DONE;
+S102:
+  o.unit = Month;
+  o.length = 6;
+  goto DONE;
+S103:
+  o.unit = Nanosecond;
+  o.length = 5;
+  goto DONE;
+S104:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 3: goto S114;
+    case 27: goto S114;
+    default: goto DONE;
+  }
+S105:
+  if (p >= end) {
+    goto S115;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S115;
+    case 18 ... 40: goto S115;
+    case 42 ... 52: goto S115;
+    case 17: goto S116;
+    case 41: goto S116;
+  }
+S106:
+  o.unit = Microsecond;
+  o.length = 5;
+  goto DONE;
+S107:
+  o.unit = Microsecond;
+  o.length = 6;
+  goto DONE;
+S108:
+  o.unit = Minute;
+  o.length = 6;
+  goto DONE;
+S109:
+  o.unit = Minute;
+  o.length = 7;
+  goto DONE;
+S110:
+  o.unit = Millisecond;
+  o.length = 6;
+  goto DONE;
+S111:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 3: goto S117;
+    case 27: goto S117;
+    default: goto DONE;
+  }
+S112:
+  o.unit = Microsecond;
+  o.length = 6;
+  goto DONE;
+S113:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 3: goto S118;
+    case 27: goto S118;
+    default: goto DONE;
+  }
+S114:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 14: goto S119;
+    case 38: goto S119;
+    default: goto DONE;
+  }
+S115:
+  o.unit = Second;
+  o.length = 6;
+  goto DONE;
+S116:
+  o.unit = Second;
+  o.length = 7;
+  goto DONE;
+S117:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 14: goto S120;
+    case 38: goto S120;
+    default: goto DONE;
+  }
+S118:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 14: goto S121;
+    case 38: goto S121;
+    default: goto DONE;
+  }
+S119:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 13: goto S122;
+    case 37: goto S122;
+    default: goto DONE;
+  }
+S120:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 13: goto S123;
+    case 37: goto S123;
+    default: goto DONE;
+  }
+S121:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 13: goto S124;
+    case 37: goto S124;
+    default: goto DONE;
+  }
+S122:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 4: goto S125;
+    case 28: goto S125;
+    default: goto DONE;
+  }
+S123:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 4: goto S126;
+    case 28: goto S126;
+    default: goto DONE;
+  }
+S124:
+  if (p >= end) {
+    goto DONE;
+  }
+  switch (classes[*p++]) {
+    case 4: goto S127;
+    case 28: goto S127;
+    default: goto DONE;
+  }
+S125:
+  if (p >= end) {
+    goto S128;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S128;
+    case 18 ... 40: goto S128;
+    case 42 ... 52: goto S128;
+    case 17: goto S129;
+    case 41: goto S129;
+  }
+S126:
+  if (p >= end) {
+    goto S130;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S130;
+    case 18 ... 40: goto S130;
+    case 42 ... 52: goto S130;
+    case 17: goto S131;
+    case 41: goto S131;
+  }
+S127:
+  if (p >= end) {
+    goto S132;
+  }
+  switch (classes[*p++]) {
+    case 0 ... 16: goto S132;
+    case 18 ... 40: goto S132;
+    case 42 ... 52: goto S132;
+    case 17: goto S133;
+    case 41: goto S133;
+  }
+S128:
+  o.unit = Nanosecond;
+  o.length = 10;
+  goto DONE;
+S129:
+  o.unit = Nanosecond;
+  o.length = 11;
+  goto DONE;
+S130:
+  o.unit = Millisecond;
+  o.length = 11;
+  goto DONE;
+S131:
+  o.unit = Millisecond;
+  o.length = 12;
+  goto DONE;
+S132:
+  o.unit = Microsecond;
+  o.length = 11;
+  goto DONE;
+S133:
+  o.unit = Microsecond;
+  o.length = 12;
+  goto DONE;
+DONE:
+  return o;
+}
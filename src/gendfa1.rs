@@ -764,6 +764,62 @@ pub(super) fn find(haystack: &[u8]) -> Option<(Unit, usize)> {
     };
   }
   return match sid {
+    State::S10 => Some((Unit::Day, 1)),
+    State::S12 => Some((Unit::Hour, 1)),
+    State::S15 => Some((Unit::Minute, 1)),
+    State::S21 => Some((Unit::Second, 1)),
+    State::S24 => Some((Unit::Week, 1)),
+    State::S27 => Some((Unit::Year, 1)),
+    State::S33 => Some((Unit::Hour, 2)),
+    State::S38 => Some((Unit::Month, 2)),
+    State::S41 => Some((Unit::Millisecond, 2)),
+    State::S44 => Some((Unit::Nanosecond, 2)),
+    State::S47 => Some((Unit::Microsecond, 2)),
+    State::S50 => Some((Unit::Week, 2)),
+    State::S53 => Some((Unit::Year, 2)),
+    State::S56 => Some((Unit::Day, 3)),
+    State::S59 => Some((Unit::Hour, 3)),
+    State::S60 => Some((Unit::Minute, 3)),
+    State::S66 => Some((Unit::Month, 3)),
+    State::S70 => Some((Unit::Second, 3)),
+    State::S75 => Some((Unit::Week, 3)),
+    State::S77 => Some((Unit::Year, 3)),
+    State::S78 => Some((Unit::Microsecond, 3)),
+    State::S80 => Some((Unit::Day, 4)),
+    State::S81 => Some((Unit::Hour, 4)),
+    State::S83 => Some((Unit::Minute, 4)),
+    State::S88 => Some((Unit::Millisecond, 4)),
+    State::S90 => Some((Unit::Nanosecond, 4)),
+    State::S92 => Some((Unit::Nanosecond, 4)),
+    State::S95 => Some((Unit::Second, 4)),
+    State::S96 => Some((Unit::Microsecond, 4)),
+    State::S98 => Some((Unit::Week, 4)),
+    State::S100 => Some((Unit::Year, 4)),
+    State::S103 => Some((Unit::Hour, 5)),
+    State::S105 => Some((Unit::Millisecond, 5)),
+    State::S107 => Some((Unit::Microsecond, 5)),
+    State::S109 => Some((Unit::Month, 5)),
+    State::S111 => Some((Unit::Millisecond, 5)),
+    State::S112 => Some((Unit::Nanosecond, 5)),
+    State::S114 => Some((Unit::Nanosecond, 5)),
+    State::S116 => Some((Unit::Microsecond, 5)),
+    State::S117 => Some((Unit::Week, 5)),
+    State::S118 => Some((Unit::Year, 5)),
+    State::S119 => Some((Unit::Microsecond, 5)),
+    State::S121 => Some((Unit::Minute, 6)),
+    State::S123 => Some((Unit::Millisecond, 6)),
+    State::S125 => Some((Unit::Microsecond, 6)),
+    State::S127 => Some((Unit::Month, 6)),
+    State::S129 => Some((Unit::Second, 6)),
+    State::S131 => Some((Unit::Microsecond, 6)),
+    State::S132 => Some((Unit::Minute, 7)),
+    State::S136 => Some((Unit::Second, 7)),
+    State::S145 => Some((Unit::Nanosecond, 10)),
+    State::S147 => Some((Unit::Millisecond, 11)),
+    State::S149 => Some((Unit::Microsecond, 11)),
+    State::S151 => Some((Unit::Nanosecond, 11)),
+    State::S152 => Some((Unit::Millisecond, 12)),
+    State::S153 => Some((Unit::Microsecond, 12)),
     State::S1 => Some((Unit::Day, 1)),
     State::S2 => Some((Unit::Hour, 1)),
     State::S3 => Some((Unit::Minute, 1)),
@@ -823,6 +879,7 @@ pub(super) fn find(haystack: &[u8]) -> Option<(Unit, usize)> {
     _ => None,
   };
 
+  #[allow(clippy::upper_case_acronyms)]
   enum State {
     DEAD,
     S0,
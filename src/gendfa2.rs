@@ -0,0 +1,261 @@
+#![allow(clippy::get_first)]
+
+use crate::Unit;
+
+#[inline(always)]
+pub(super) fn find(haystack: &[u8]) -> Option<(Unit, usize)> {
+  match haystack.get(0).copied() {
+    Some(b'd') => match haystack.get(1).copied() {
+      Some(b'a') => match haystack.get(2).copied() {
+        Some(b'y') => match haystack.get(3).copied() {
+          Some(b's') => Some((Unit::Day, 4)),
+          _ => Some((Unit::Day, 3)),
+        },
+        _ => None,
+      },
+      _ => Some((Unit::Day, 1)),
+    },
+    Some(b'h') => match haystack.get(1).copied() {
+      Some(b'o') => match haystack.get(2).copied() {
+        Some(b'u') => match haystack.get(3).copied() {
+          Some(b'r') => match haystack.get(4).copied() {
+            Some(b's') => Some((Unit::Hour, 5)),
+            _ => Some((Unit::Hour, 4)),
+          },
+          _ => None,
+        },
+        _ => None,
+      },
+      Some(b'r') => match haystack.get(2).copied() {
+        Some(b's') => Some((Unit::Hour, 3)),
+        _ => Some((Unit::Hour, 2)),
+      },
+      _ => Some((Unit::Hour, 1)),
+    },
+    Some(b'm') => match haystack.get(1).copied() {
+      Some(b'i') => match haystack.get(2).copied() {
+        Some(b'n') => match haystack.get(3).copied() {
+          Some(b's') => Some((Unit::Minute, 4)),
+          Some(b'u') => match haystack.get(4).copied() {
+            Some(b't') => match haystack.get(5).copied() {
+              Some(b'e') => match haystack.get(6).copied() {
+                Some(b's') => Some((Unit::Minute, 7)),
+                _ => Some((Unit::Minute, 6)),
+              },
+              _ => None,
+            },
+            _ => None,
+          },
+          _ => Some((Unit::Minute, 3)),
+        },
+        Some(b'l') => match haystack.get(3).copied() {
+          Some(b'l') => match haystack.get(4).copied() {
+            Some(b'i') => match haystack.get(5).copied() {
+              Some(b's') => match haystack.get(6).copied() {
+                Some(b'e') => match haystack.get(7).copied() {
+                  Some(b'c') => match haystack.get(8).copied() {
+                    Some(b'o') => match haystack.get(9).copied() {
+                      Some(b'n') => match haystack.get(10).copied() {
+                        Some(b'd') => match haystack.get(11).copied() {
+                          Some(b's') => Some((Unit::Millisecond, 12)),
+                          _ => Some((Unit::Millisecond, 11)),
+                        },
+                        _ => None,
+                      },
+                      _ => None,
+                    },
+                    _ => None,
+                  },
+                  _ => None,
+                },
+                _ => Some((Unit::Millisecond, 6)),
+              },
+              _ => Some((Unit::Millisecond, 5)),
+            },
+            _ => None,
+          },
+          _ => None,
+        },
+        Some(b'c') => match haystack.get(3).copied() {
+          Some(b'r') => match haystack.get(4).copied() {
+            Some(b'o') => match haystack.get(5).copied() {
+              Some(b's') => match haystack.get(6).copied() {
+                Some(b'e') => match haystack.get(7).copied() {
+                  Some(b'c') => match haystack.get(8).copied() {
+                    Some(b'o') => match haystack.get(9).copied() {
+                      Some(b'n') => match haystack.get(10).copied() {
+                        Some(b'd') => match haystack.get(11).copied() {
+                          Some(b's') => Some((Unit::Microsecond, 12)),
+                          _ => Some((Unit::Microsecond, 11)),
+                        },
+                        _ => None,
+                      },
+                      _ => None,
+                    },
+                    _ => None,
+                  },
+                  _ => None,
+                },
+                _ => Some((Unit::Microsecond, 6)),
+              },
+              _ => Some((Unit::Microsecond, 5)),
+            },
+            _ => None,
+          },
+          _ => None,
+        },
+        _ => None,
+      },
+      Some(b'o') => match haystack.get(2).copied() {
+        Some(b'n') => match haystack.get(3).copied() {
+          Some(b't') => match haystack.get(4).copied() {
+            Some(b'h') => match haystack.get(5).copied() {
+              Some(b's') => Some((Unit::Month, 6)),
+              _ => Some((Unit::Month, 5)),
+            },
+            _ => None,
+          },
+          _ => None,
+        },
+        Some(b's') => Some((Unit::Month, 3)),
+        _ => Some((Unit::Month, 2)),
+      },
+      Some(b's') => match haystack.get(2).copied() {
+        Some(b'e') => match haystack.get(3).copied() {
+          Some(b'c') => match haystack.get(4).copied() {
+            Some(b's') => Some((Unit::Millisecond, 5)),
+            _ => Some((Unit::Millisecond, 4)),
+          },
+          _ => None,
+        },
+        _ => Some((Unit::Millisecond, 2)),
+      },
+      _ => Some((Unit::Minute, 1)),
+    },
+    Some(b'n') => match haystack.get(1).copied() {
+      Some(b'a') => match haystack.get(2).copied() {
+        Some(b'n') => match haystack.get(3).copied() {
+          Some(b'o') => match haystack.get(4).copied() {
+            Some(b's') => match haystack.get(5).copied() {
+              Some(b'e') => match haystack.get(6).copied() {
+                Some(b'c') => match haystack.get(7).copied() {
+                  Some(b'o') => match haystack.get(8).copied() {
+                    Some(b'n') => match haystack.get(9).copied() {
+                      Some(b'd') => match haystack.get(10).copied() {
+                        Some(b's') => Some((Unit::Nanosecond, 11)),
+                        _ => Some((Unit::Nanosecond, 10)),
+                      },
+                      _ => None,
+                    },
+                    _ => None,
+                  },
+                  _ => None,
+                },
+                _ => None,
+              },
+              _ => Some((Unit::Nanosecond, 5)),
+            },
+            _ => Some((Unit::Nanosecond, 4)),
+          },
+          _ => None,
+        },
+        _ => None,
+      },
+      Some(b's') => match haystack.get(2).copied() {
+        Some(b'e') => match haystack.get(3).copied() {
+          Some(b'c') => match haystack.get(4).copied() {
+            Some(b's') => Some((Unit::Nanosecond, 5)),
+            _ => Some((Unit::Nanosecond, 4)),
+          },
+          _ => None,
+        },
+        _ => Some((Unit::Nanosecond, 2)),
+      },
+      _ => None,
+    },
+    Some(b's') => match haystack.get(1).copied() {
+      Some(b'e') => match haystack.get(2).copied() {
+        Some(b'c') => match haystack.get(3).copied() {
+          Some(b'o') => match haystack.get(4).copied() {
+            Some(b'n') => match haystack.get(5).copied() {
+              Some(b'd') => match haystack.get(6).copied() {
+                Some(b's') => Some((Unit::Second, 7)),
+                _ => Some((Unit::Second, 6)),
+              },
+              _ => None,
+            },
+            _ => None,
+          },
+          Some(b's') => Some((Unit::Second, 4)),
+          _ => Some((Unit::Second, 3)),
+        },
+        _ => None,
+      },
+      _ => Some((Unit::Second, 1)),
+    },
+    Some(b'u') => match haystack.get(1).copied() {
+      Some(b's') => match haystack.get(2).copied() {
+        Some(b'e') => match haystack.get(3).copied() {
+          Some(b'c') => match haystack.get(4).copied() {
+            Some(b's') => Some((Unit::Microsecond, 5)),
+            _ => Some((Unit::Microsecond, 4)),
+          },
+          _ => None,
+        },
+        _ => Some((Unit::Microsecond, 2)),
+      },
+      _ => None,
+    },
+    Some(b'w') => match haystack.get(1).copied() {
+      Some(b'e') => match haystack.get(2).copied() {
+        Some(b'e') => match haystack.get(3).copied() {
+          Some(b'k') => match haystack.get(4).copied() {
+            Some(b's') => Some((Unit::Week, 5)),
+            _ => Some((Unit::Week, 4)),
+          },
+          _ => None,
+        },
+        _ => None,
+      },
+      Some(b'k') => match haystack.get(2).copied() {
+        Some(b's') => Some((Unit::Week, 3)),
+        _ => Some((Unit::Week, 2)),
+      },
+      _ => Some((Unit::Week, 1)),
+    },
+    Some(b'y') => match haystack.get(1).copied() {
+      Some(b'e') => match haystack.get(2).copied() {
+        Some(b'a') => match haystack.get(3).copied() {
+          Some(b'r') => match haystack.get(4).copied() {
+            Some(b's') => Some((Unit::Year, 5)),
+            _ => Some((Unit::Year, 4)),
+          },
+          _ => None,
+        },
+        _ => None,
+      },
+      Some(b'r') => match haystack.get(2).copied() {
+        Some(b's') => Some((Unit::Year, 3)),
+        _ => Some((Unit::Year, 2)),
+      },
+      _ => Some((Unit::Year, 1)),
+    },
+    Some(b'\xc2') => match haystack.get(1).copied() {
+      Some(b'\xb5') => match haystack.get(2).copied() {
+        Some(b's') => match haystack.get(3).copied() {
+          Some(b'e') => match haystack.get(4).copied() {
+            Some(b'c') => match haystack.get(5).copied() {
+              Some(b's') => Some((Unit::Microsecond, 6)),
+              _ => Some((Unit::Microsecond, 5)),
+            },
+            _ => None,
+          },
+          _ => Some((Unit::Microsecond, 3)),
+        },
+        _ => None,
+      },
+      _ => None,
+    },
+    _ => None,
+  }
+}
@@ -0,0 +1,649 @@
+use crate::Unit;
+
+#[inline(always)]
+pub(super) fn find(haystack: &[u8]) -> Option<(Unit, usize)> {
+  s0(haystack, 0)
+}
+
+#[inline(always)]
+fn s0(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'd') => s1(haystack, at + 1),
+    Some(b'h') => s2(haystack, at + 1),
+    Some(b'm') => s3(haystack, at + 1),
+    Some(b'n') => s4(haystack, at + 1),
+    Some(b's') => s5(haystack, at + 1),
+    Some(b'u') => s6(haystack, at + 1),
+    Some(b'w') => s7(haystack, at + 1),
+    Some(b'y') => s8(haystack, at + 1),
+    Some(b'\xc2') => s9(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s1(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'a') => s10(haystack, at + 1),
+    _ => Some((Unit::Day, 1)),
+  }
+}
+
+#[inline(always)]
+fn s2(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'o') => s11(haystack, at + 1),
+    Some(b'r') => s12(haystack, at + 1),
+    _ => Some((Unit::Hour, 1)),
+  }
+}
+
+#[inline(always)]
+fn s3(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'i') => s13(haystack, at + 1),
+    Some(b'o') => s14(haystack, at + 1),
+    Some(b's') => s15(haystack, at + 1),
+    _ => Some((Unit::Minute, 1)),
+  }
+}
+
+#[inline(always)]
+fn s4(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'a') => s16(haystack, at + 1),
+    Some(b's') => s17(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s5(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s18(haystack, at + 1),
+    _ => Some((Unit::Second, 1)),
+  }
+}
+
+#[inline(always)]
+fn s6(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => s19(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s7(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s20(haystack, at + 1),
+    Some(b'k') => s21(haystack, at + 1),
+    _ => Some((Unit::Week, 1)),
+  }
+}
+
+#[inline(always)]
+fn s8(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s22(haystack, at + 1),
+    Some(b'r') => s23(haystack, at + 1),
+    _ => Some((Unit::Year, 1)),
+  }
+}
+
+#[inline(always)]
+fn s9(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'\xb5') => s24(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s10(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'y') => s25(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s11(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'u') => s26(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s12(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Hour, 3)),
+    _ => Some((Unit::Hour, 2)),
+  }
+}
+
+#[inline(always)]
+fn s13(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'n') => s27(haystack, at + 1),
+    Some(b'l') => s28(haystack, at + 1),
+    Some(b'c') => s29(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s14(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'n') => s30(haystack, at + 1),
+    Some(b's') => Some((Unit::Month, 3)),
+    _ => Some((Unit::Month, 2)),
+  }
+}
+
+#[inline(always)]
+fn s15(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s31(haystack, at + 1),
+    _ => Some((Unit::Millisecond, 2)),
+  }
+}
+
+#[inline(always)]
+fn s16(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'n') => s32(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s17(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s33(haystack, at + 1),
+    _ => Some((Unit::Nanosecond, 2)),
+  }
+}
+
+#[inline(always)]
+fn s18(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'c') => s34(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s19(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s35(haystack, at + 1),
+    _ => Some((Unit::Microsecond, 2)),
+  }
+}
+
+#[inline(always)]
+fn s20(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s36(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s21(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Week, 3)),
+    _ => Some((Unit::Week, 2)),
+  }
+}
+
+#[inline(always)]
+fn s22(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'a') => s37(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s23(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Year, 3)),
+    _ => Some((Unit::Year, 2)),
+  }
+}
+
+#[inline(always)]
+fn s24(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => s38(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s25(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Day, 4)),
+    _ => Some((Unit::Day, 3)),
+  }
+}
+
+#[inline(always)]
+fn s26(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'r') => s39(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s27(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Minute, 4)),
+    Some(b'u') => s40(haystack, at + 1),
+    _ => Some((Unit::Minute, 3)),
+  }
+}
+
+#[inline(always)]
+fn s28(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'l') => s41(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s29(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'r') => s42(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s30(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b't') => s43(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s31(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'c') => s44(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s32(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'o') => s45(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s33(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'c') => s46(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s34(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'o') => s47(haystack, at + 1),
+    Some(b's') => Some((Unit::Second, 4)),
+    _ => Some((Unit::Second, 3)),
+  }
+}
+
+#[inline(always)]
+fn s35(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'c') => s48(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s36(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'k') => s49(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s37(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'r') => s50(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s38(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s51(haystack, at + 1),
+    _ => Some((Unit::Microsecond, 3)),
+  }
+}
+
+#[inline(always)]
+fn s39(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Hour, 5)),
+    _ => Some((Unit::Hour, 4)),
+  }
+}
+
+#[inline(always)]
+fn s40(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b't') => s52(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s41(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'i') => s53(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s42(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'o') => s54(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s43(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'h') => s55(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s44(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Millisecond, 5)),
+    _ => Some((Unit::Millisecond, 4)),
+  }
+}
+
+#[inline(always)]
+fn s45(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => s56(haystack, at + 1),
+    _ => Some((Unit::Nanosecond, 4)),
+  }
+}
+
+#[inline(always)]
+fn s46(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Nanosecond, 5)),
+    _ => Some((Unit::Nanosecond, 4)),
+  }
+}
+
+#[inline(always)]
+fn s47(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'n') => s57(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s48(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Microsecond, 5)),
+    _ => Some((Unit::Microsecond, 4)),
+  }
+}
+
+#[inline(always)]
+fn s49(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Week, 5)),
+    _ => Some((Unit::Week, 4)),
+  }
+}
+
+#[inline(always)]
+fn s50(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Year, 5)),
+    _ => Some((Unit::Year, 4)),
+  }
+}
+
+#[inline(always)]
+fn s51(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'c') => s58(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s52(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s59(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s53(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => s60(haystack, at + 1),
+    _ => Some((Unit::Millisecond, 5)),
+  }
+}
+
+#[inline(always)]
+fn s54(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => s61(haystack, at + 1),
+    _ => Some((Unit::Microsecond, 5)),
+  }
+}
+
+#[inline(always)]
+fn s55(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Month, 6)),
+    _ => Some((Unit::Month, 5)),
+  }
+}
+
+#[inline(always)]
+fn s56(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s62(haystack, at + 1),
+    _ => Some((Unit::Nanosecond, 5)),
+  }
+}
+
+#[inline(always)]
+fn s57(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'd') => s63(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s58(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Microsecond, 6)),
+    _ => Some((Unit::Microsecond, 5)),
+  }
+}
+
+#[inline(always)]
+fn s59(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Minute, 7)),
+    _ => Some((Unit::Minute, 6)),
+  }
+}
+
+#[inline(always)]
+fn s60(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s64(haystack, at + 1),
+    _ => Some((Unit::Millisecond, 6)),
+  }
+}
+
+#[inline(always)]
+fn s61(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'e') => s65(haystack, at + 1),
+    _ => Some((Unit::Microsecond, 6)),
+  }
+}
+
+#[inline(always)]
+fn s62(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'c') => s66(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s63(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Second, 7)),
+    _ => Some((Unit::Second, 6)),
+  }
+}
+
+#[inline(always)]
+fn s64(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'c') => s67(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s65(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'c') => s68(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s66(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'o') => s69(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s67(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'o') => s70(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s68(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'o') => s71(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s69(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'n') => s72(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s70(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'n') => s73(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s71(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'n') => s74(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s72(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'd') => s75(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s73(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'd') => s76(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s74(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'd') => s77(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(always)]
+fn s75(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Nanosecond, 11)),
+    _ => Some((Unit::Nanosecond, 10)),
+  }
+}
+
+#[inline(always)]
+fn s76(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Millisecond, 12)),
+    _ => Some((Unit::Millisecond, 11)),
+  }
+}
+
+#[inline(always)]
+fn s77(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b's') => Some((Unit::Microsecond, 12)),
+    _ => Some((Unit::Microsecond, 11)),
+  }
+}
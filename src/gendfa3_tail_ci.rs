@@ -0,0 +1,649 @@
+use crate::Unit;
+
+#[inline(always)]
+pub(super) fn find(haystack: &[u8]) -> Option<(Unit, usize)> {
+  s0(haystack, 0)
+}
+
+#[inline(never)]
+fn s0(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'D' | b'd') => become s1(haystack, at + 1),
+    Some(b'H' | b'h') => become s2(haystack, at + 1),
+    Some(b'M' | b'm') => become s3(haystack, at + 1),
+    Some(b'N' | b'n') => become s4(haystack, at + 1),
+    Some(b'S' | b's') => become s5(haystack, at + 1),
+    Some(b'U' | b'u') => become s6(haystack, at + 1),
+    Some(b'W' | b'w') => become s7(haystack, at + 1),
+    Some(b'Y' | b'y') => become s8(haystack, at + 1),
+    Some(b'\xc2') => become s9(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s1(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'A' | b'a') => become s10(haystack, at + 1),
+    _ => Some((Unit::Day, 1)),
+  }
+}
+
+#[inline(never)]
+fn s2(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'O' | b'o') => become s11(haystack, at + 1),
+    Some(b'R' | b'r') => become s12(haystack, at + 1),
+    _ => Some((Unit::Hour, 1)),
+  }
+}
+
+#[inline(never)]
+fn s3(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'I' | b'i') => become s13(haystack, at + 1),
+    Some(b'O' | b'o') => become s14(haystack, at + 1),
+    Some(b'S' | b's') => become s15(haystack, at + 1),
+    _ => Some((Unit::Minute, 1)),
+  }
+}
+
+#[inline(never)]
+fn s4(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'A' | b'a') => become s16(haystack, at + 1),
+    Some(b'S' | b's') => become s17(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s5(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s18(haystack, at + 1),
+    _ => Some((Unit::Second, 1)),
+  }
+}
+
+#[inline(never)]
+fn s6(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => become s19(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s7(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s20(haystack, at + 1),
+    Some(b'K' | b'k') => become s21(haystack, at + 1),
+    _ => Some((Unit::Week, 1)),
+  }
+}
+
+#[inline(never)]
+fn s8(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s22(haystack, at + 1),
+    Some(b'R' | b'r') => become s23(haystack, at + 1),
+    _ => Some((Unit::Year, 1)),
+  }
+}
+
+#[inline(never)]
+fn s9(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'\xb5') => become s24(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s10(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'Y' | b'y') => become s25(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s11(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'U' | b'u') => become s26(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s12(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Hour, 3)),
+    _ => Some((Unit::Hour, 2)),
+  }
+}
+
+#[inline(never)]
+fn s13(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'N' | b'n') => become s27(haystack, at + 1),
+    Some(b'L' | b'l') => become s28(haystack, at + 1),
+    Some(b'C' | b'c') => become s29(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s14(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'N' | b'n') => become s30(haystack, at + 1),
+    Some(b'S' | b's') => Some((Unit::Month, 3)),
+    _ => Some((Unit::Month, 2)),
+  }
+}
+
+#[inline(never)]
+fn s15(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s31(haystack, at + 1),
+    _ => Some((Unit::Millisecond, 2)),
+  }
+}
+
+#[inline(never)]
+fn s16(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'N' | b'n') => become s32(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s17(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s33(haystack, at + 1),
+    _ => Some((Unit::Nanosecond, 2)),
+  }
+}
+
+#[inline(never)]
+fn s18(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'C' | b'c') => become s34(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s19(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s35(haystack, at + 1),
+    _ => Some((Unit::Microsecond, 2)),
+  }
+}
+
+#[inline(never)]
+fn s20(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s36(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s21(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Week, 3)),
+    _ => Some((Unit::Week, 2)),
+  }
+}
+
+#[inline(never)]
+fn s22(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'A' | b'a') => become s37(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s23(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Year, 3)),
+    _ => Some((Unit::Year, 2)),
+  }
+}
+
+#[inline(never)]
+fn s24(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => become s38(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s25(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Day, 4)),
+    _ => Some((Unit::Day, 3)),
+  }
+}
+
+#[inline(never)]
+fn s26(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'R' | b'r') => become s39(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s27(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Minute, 4)),
+    Some(b'U' | b'u') => become s40(haystack, at + 1),
+    _ => Some((Unit::Minute, 3)),
+  }
+}
+
+#[inline(never)]
+fn s28(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'L' | b'l') => become s41(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s29(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'R' | b'r') => become s42(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s30(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'T' | b't') => become s43(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s31(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'C' | b'c') => become s44(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s32(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'O' | b'o') => become s45(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s33(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'C' | b'c') => become s46(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s34(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'O' | b'o') => become s47(haystack, at + 1),
+    Some(b'S' | b's') => Some((Unit::Second, 4)),
+    _ => Some((Unit::Second, 3)),
+  }
+}
+
+#[inline(never)]
+fn s35(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'C' | b'c') => become s48(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s36(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'K' | b'k') => become s49(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s37(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'R' | b'r') => become s50(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s38(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s51(haystack, at + 1),
+    _ => Some((Unit::Microsecond, 3)),
+  }
+}
+
+#[inline(never)]
+fn s39(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Hour, 5)),
+    _ => Some((Unit::Hour, 4)),
+  }
+}
+
+#[inline(never)]
+fn s40(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'T' | b't') => become s52(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s41(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'I' | b'i') => become s53(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s42(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'O' | b'o') => become s54(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s43(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'H' | b'h') => become s55(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s44(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Millisecond, 5)),
+    _ => Some((Unit::Millisecond, 4)),
+  }
+}
+
+#[inline(never)]
+fn s45(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => become s56(haystack, at + 1),
+    _ => Some((Unit::Nanosecond, 4)),
+  }
+}
+
+#[inline(never)]
+fn s46(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Nanosecond, 5)),
+    _ => Some((Unit::Nanosecond, 4)),
+  }
+}
+
+#[inline(never)]
+fn s47(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'N' | b'n') => become s57(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s48(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Microsecond, 5)),
+    _ => Some((Unit::Microsecond, 4)),
+  }
+}
+
+#[inline(never)]
+fn s49(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Week, 5)),
+    _ => Some((Unit::Week, 4)),
+  }
+}
+
+#[inline(never)]
+fn s50(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Year, 5)),
+    _ => Some((Unit::Year, 4)),
+  }
+}
+
+#[inline(never)]
+fn s51(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'C' | b'c') => become s58(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s52(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s59(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s53(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => become s60(haystack, at + 1),
+    _ => Some((Unit::Millisecond, 5)),
+  }
+}
+
+#[inline(never)]
+fn s54(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => become s61(haystack, at + 1),
+    _ => Some((Unit::Microsecond, 5)),
+  }
+}
+
+#[inline(never)]
+fn s55(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Month, 6)),
+    _ => Some((Unit::Month, 5)),
+  }
+}
+
+#[inline(never)]
+fn s56(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s62(haystack, at + 1),
+    _ => Some((Unit::Nanosecond, 5)),
+  }
+}
+
+#[inline(never)]
+fn s57(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'D' | b'd') => become s63(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s58(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Microsecond, 6)),
+    _ => Some((Unit::Microsecond, 5)),
+  }
+}
+
+#[inline(never)]
+fn s59(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Minute, 7)),
+    _ => Some((Unit::Minute, 6)),
+  }
+}
+
+#[inline(never)]
+fn s60(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s64(haystack, at + 1),
+    _ => Some((Unit::Millisecond, 6)),
+  }
+}
+
+#[inline(never)]
+fn s61(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'E' | b'e') => become s65(haystack, at + 1),
+    _ => Some((Unit::Microsecond, 6)),
+  }
+}
+
+#[inline(never)]
+fn s62(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'C' | b'c') => become s66(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s63(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Second, 7)),
+    _ => Some((Unit::Second, 6)),
+  }
+}
+
+#[inline(never)]
+fn s64(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'C' | b'c') => become s67(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s65(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'C' | b'c') => become s68(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s66(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'O' | b'o') => become s69(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s67(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'O' | b'o') => become s70(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s68(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'O' | b'o') => become s71(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s69(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'N' | b'n') => become s72(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s70(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'N' | b'n') => become s73(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s71(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'N' | b'n') => become s74(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s72(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'D' | b'd') => become s75(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s73(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'D' | b'd') => become s76(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s74(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'D' | b'd') => become s77(haystack, at + 1),
+    _ => None,
+  }
+}
+
+#[inline(never)]
+fn s75(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Nanosecond, 11)),
+    _ => Some((Unit::Nanosecond, 10)),
+  }
+}
+
+#[inline(never)]
+fn s76(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Millisecond, 12)),
+    _ => Some((Unit::Millisecond, 11)),
+  }
+}
+
+#[inline(never)]
+fn s77(haystack: &[u8], at: usize) -> Option<(Unit, usize)> {
+  match haystack.get(at).copied() {
+    Some(b'S' | b's') => Some((Unit::Microsecond, 12)),
+    _ => Some((Unit::Microsecond, 11)),
+  }
+}
@@ -0,0 +1,791 @@
+use crate::Unit;
+
+#[inline(always)]
+pub(super) fn find(haystack: &[u8]) -> Option<(Unit, usize)> {
+  let mut state: u8 = 0;
+  let mut at = 0;
+  loop {
+    's0: {
+      if state != 0 {
+        break 's0;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'd') => 1,
+        Some(b'h') => 4,
+        Some(b'm') => 9,
+        Some(b'n') => 40,
+        Some(b's') => 53,
+        Some(b'u') => 59,
+        Some(b'w') => 63,
+        Some(b'y') => 68,
+        Some(b'\xc2') => 73,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's1: {
+      if state != 1 {
+        break 's1;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'a') => 2,
+        _ => return Some((Unit::Day, 1)),
+      };
+      at += 1;
+    }
+    's2: {
+      if state != 2 {
+        break 's2;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'y') => 3,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's3: {
+      if state != 3 {
+        break 's3;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Day, 4)),
+        _ => Some((Unit::Day, 3)),
+      };
+    }
+    's4: {
+      if state != 4 {
+        break 's4;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'o') => 5,
+        Some(b'r') => 8,
+        _ => return Some((Unit::Hour, 1)),
+      };
+      at += 1;
+    }
+    's5: {
+      if state != 5 {
+        break 's5;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'u') => 6,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's6: {
+      if state != 6 {
+        break 's6;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'r') => 7,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's7: {
+      if state != 7 {
+        break 's7;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Hour, 5)),
+        _ => Some((Unit::Hour, 4)),
+      };
+    }
+    's8: {
+      if state != 8 {
+        break 's8;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Hour, 3)),
+        _ => Some((Unit::Hour, 2)),
+      };
+    }
+    's9: {
+      if state != 9 {
+        break 's9;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'i') => 10,
+        Some(b'o') => 33,
+        Some(b's') => 37,
+        _ => return Some((Unit::Minute, 1)),
+      };
+      at += 1;
+    }
+    's10: {
+      if state != 10 {
+        break 's10;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'n') => 11,
+        Some(b'l') => 15,
+        Some(b'c') => 24,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's11: {
+      if state != 11 {
+        break 's11;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b's') => return Some((Unit::Minute, 4)),
+        Some(b'u') => 12,
+        _ => return Some((Unit::Minute, 3)),
+      };
+      at += 1;
+    }
+    's12: {
+      if state != 12 {
+        break 's12;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b't') => 13,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's13: {
+      if state != 13 {
+        break 's13;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 14,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's14: {
+      if state != 14 {
+        break 's14;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Minute, 7)),
+        _ => Some((Unit::Minute, 6)),
+      };
+    }
+    's15: {
+      if state != 15 {
+        break 's15;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'l') => 16,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's16: {
+      if state != 16 {
+        break 's16;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'i') => 17,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's17: {
+      if state != 17 {
+        break 's17;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b's') => 18,
+        _ => return Some((Unit::Millisecond, 5)),
+      };
+      at += 1;
+    }
+    's18: {
+      if state != 18 {
+        break 's18;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 19,
+        _ => return Some((Unit::Millisecond, 6)),
+      };
+      at += 1;
+    }
+    's19: {
+      if state != 19 {
+        break 's19;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'c') => 20,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's20: {
+      if state != 20 {
+        break 's20;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'o') => 21,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's21: {
+      if state != 21 {
+        break 's21;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'n') => 22,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's22: {
+      if state != 22 {
+        break 's22;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'd') => 23,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's23: {
+      if state != 23 {
+        break 's23;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Millisecond, 12)),
+        _ => Some((Unit::Millisecond, 11)),
+      };
+    }
+    's24: {
+      if state != 24 {
+        break 's24;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'r') => 25,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's25: {
+      if state != 25 {
+        break 's25;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'o') => 26,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's26: {
+      if state != 26 {
+        break 's26;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b's') => 27,
+        _ => return Some((Unit::Microsecond, 5)),
+      };
+      at += 1;
+    }
+    's27: {
+      if state != 27 {
+        break 's27;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 28,
+        _ => return Some((Unit::Microsecond, 6)),
+      };
+      at += 1;
+    }
+    's28: {
+      if state != 28 {
+        break 's28;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'c') => 29,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's29: {
+      if state != 29 {
+        break 's29;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'o') => 30,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's30: {
+      if state != 30 {
+        break 's30;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'n') => 31,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's31: {
+      if state != 31 {
+        break 's31;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'd') => 32,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's32: {
+      if state != 32 {
+        break 's32;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Microsecond, 12)),
+        _ => Some((Unit::Microsecond, 11)),
+      };
+    }
+    's33: {
+      if state != 33 {
+        break 's33;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'n') => 34,
+        Some(b's') => return Some((Unit::Month, 3)),
+        _ => return Some((Unit::Month, 2)),
+      };
+      at += 1;
+    }
+    's34: {
+      if state != 34 {
+        break 's34;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b't') => 35,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's35: {
+      if state != 35 {
+        break 's35;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'h') => 36,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's36: {
+      if state != 36 {
+        break 's36;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Month, 6)),
+        _ => Some((Unit::Month, 5)),
+      };
+    }
+    's37: {
+      if state != 37 {
+        break 's37;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 38,
+        _ => return Some((Unit::Millisecond, 2)),
+      };
+      at += 1;
+    }
+    's38: {
+      if state != 38 {
+        break 's38;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'c') => 39,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's39: {
+      if state != 39 {
+        break 's39;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Millisecond, 5)),
+        _ => Some((Unit::Millisecond, 4)),
+      };
+    }
+    's40: {
+      if state != 40 {
+        break 's40;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'a') => 41,
+        Some(b's') => 50,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's41: {
+      if state != 41 {
+        break 's41;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'n') => 42,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's42: {
+      if state != 42 {
+        break 's42;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'o') => 43,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's43: {
+      if state != 43 {
+        break 's43;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b's') => 44,
+        _ => return Some((Unit::Nanosecond, 4)),
+      };
+      at += 1;
+    }
+    's44: {
+      if state != 44 {
+        break 's44;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 45,
+        _ => return Some((Unit::Nanosecond, 5)),
+      };
+      at += 1;
+    }
+    's45: {
+      if state != 45 {
+        break 's45;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'c') => 46,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's46: {
+      if state != 46 {
+        break 's46;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'o') => 47,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's47: {
+      if state != 47 {
+        break 's47;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'n') => 48,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's48: {
+      if state != 48 {
+        break 's48;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'd') => 49,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's49: {
+      if state != 49 {
+        break 's49;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Nanosecond, 11)),
+        _ => Some((Unit::Nanosecond, 10)),
+      };
+    }
+    's50: {
+      if state != 50 {
+        break 's50;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 51,
+        _ => return Some((Unit::Nanosecond, 2)),
+      };
+      at += 1;
+    }
+    's51: {
+      if state != 51 {
+        break 's51;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'c') => 52,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's52: {
+      if state != 52 {
+        break 's52;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Nanosecond, 5)),
+        _ => Some((Unit::Nanosecond, 4)),
+      };
+    }
+    's53: {
+      if state != 53 {
+        break 's53;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 54,
+        _ => return Some((Unit::Second, 1)),
+      };
+      at += 1;
+    }
+    's54: {
+      if state != 54 {
+        break 's54;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'c') => 55,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's55: {
+      if state != 55 {
+        break 's55;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'o') => 56,
+        Some(b's') => return Some((Unit::Second, 4)),
+        _ => return Some((Unit::Second, 3)),
+      };
+      at += 1;
+    }
+    's56: {
+      if state != 56 {
+        break 's56;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'n') => 57,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's57: {
+      if state != 57 {
+        break 's57;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'd') => 58,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's58: {
+      if state != 58 {
+        break 's58;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Second, 7)),
+        _ => Some((Unit::Second, 6)),
+      };
+    }
+    's59: {
+      if state != 59 {
+        break 's59;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b's') => 60,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's60: {
+      if state != 60 {
+        break 's60;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 61,
+        _ => return Some((Unit::Microsecond, 2)),
+      };
+      at += 1;
+    }
+    's61: {
+      if state != 61 {
+        break 's61;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'c') => 62,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's62: {
+      if state != 62 {
+        break 's62;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Microsecond, 5)),
+        _ => Some((Unit::Microsecond, 4)),
+      };
+    }
+    's63: {
+      if state != 63 {
+        break 's63;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 64,
+        Some(b'k') => 67,
+        _ => return Some((Unit::Week, 1)),
+      };
+      at += 1;
+    }
+    's64: {
+      if state != 64 {
+        break 's64;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 65,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's65: {
+      if state != 65 {
+        break 's65;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'k') => 66,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's66: {
+      if state != 66 {
+        break 's66;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Week, 5)),
+        _ => Some((Unit::Week, 4)),
+      };
+    }
+    's67: {
+      if state != 67 {
+        break 's67;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Week, 3)),
+        _ => Some((Unit::Week, 2)),
+      };
+    }
+    's68: {
+      if state != 68 {
+        break 's68;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 69,
+        Some(b'r') => 72,
+        _ => return Some((Unit::Year, 1)),
+      };
+      at += 1;
+    }
+    's69: {
+      if state != 69 {
+        break 's69;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'a') => 70,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's70: {
+      if state != 70 {
+        break 's70;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'r') => 71,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's71: {
+      if state != 71 {
+        break 's71;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Year, 5)),
+        _ => Some((Unit::Year, 4)),
+      };
+    }
+    's72: {
+      if state != 72 {
+        break 's72;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Year, 3)),
+        _ => Some((Unit::Year, 2)),
+      };
+    }
+    's73: {
+      if state != 73 {
+        break 's73;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'\xb5') => 74,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's74: {
+      if state != 74 {
+        break 's74;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b's') => 75,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's75: {
+      if state != 75 {
+        break 's75;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'e') => 76,
+        _ => return Some((Unit::Microsecond, 3)),
+      };
+      at += 1;
+    }
+    's76: {
+      if state != 76 {
+        break 's76;
+      }
+      state = match haystack.get(at).copied() {
+        Some(b'c') => 77,
+        _ => return None,
+      };
+      at += 1;
+    }
+    's77: {
+      if state != 77 {
+        break 's77;
+      }
+      return match haystack.get(at).copied() {
+        Some(b's') => Some((Unit::Microsecond, 6)),
+        _ => Some((Unit::Microsecond, 5)),
+      };
+    }
+  }
+}
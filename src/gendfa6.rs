@@ -0,0 +1,1115 @@
+use crate::Unit;
+
+pub(super) static CLASSES: [u8; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 2, 3, 4, 5, 6, 6, 7, 8, 9, 10, 11, 12, 13, 14,
+    15, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 24, 24, 24, 24, 24,
+    24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24,
+    24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24,
+    24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24,
+    24, 24, 24, 24, 24, 25, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+    26, 26, 27, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+];
+
+#[inline(always)]
+pub(super) fn find(haystack: &[u8]) -> Option<(Unit, usize)> {
+  let mut sid = State::S0;
+  for byte in haystack.iter().copied() {
+    sid = match sid {
+      State::DEAD => return None,
+      State::S0 => {
+        match CLASSES[usize::from(byte)] {
+          4 => State::S1,
+          7 => State::S2,
+          12 => State::S3,
+          13 => State::S4,
+          17 => State::S5,
+          19 => State::S6,
+          21 => State::S7,
+          23 => State::S8,
+          27 => State::S9,
+          _ => State::DEAD,
+        }
+      }
+      State::S1 => {
+        match CLASSES[usize::from(byte)] {
+          0 | 2..=28 => State::S10,
+          1 => State::S11,
+          _ => State::DEAD,
+        }
+      }
+      State::S2 => {
+        match CLASSES[usize::from(byte)] {
+          0..=13 | 15 | 17..=28 => State::S12,
+          14 => State::S13,
+          16 => State::S14,
+          _ => State::DEAD,
+        }
+      }
+      State::S3 => {
+        match CLASSES[usize::from(byte)] {
+          0..=7 | 9..=13 | 15..=16 | 18..=28 => State::S15,
+          8 => State::S16,
+          14 => State::S17,
+          17 => State::S18,
+          _ => State::DEAD,
+        }
+      }
+      State::S4 => {
+        match CLASSES[usize::from(byte)] {
+          1 => State::S19,
+          17 => State::S20,
+          _ => State::DEAD,
+        }
+      }
+      State::S5 => {
+        match CLASSES[usize::from(byte)] {
+          0..=4 | 6..=28 => State::S21,
+          5 => State::S22,
+          _ => State::DEAD,
+        }
+      }
+      State::S6 => {
+        match CLASSES[usize::from(byte)] {
+          17 => State::S23,
+          _ => State::DEAD,
+        }
+      }
+      State::S7 => {
+        match CLASSES[usize::from(byte)] {
+          0..=4 | 6..=9 | 11..=28 => State::S24,
+          5 => State::S25,
+          10 => State::S26,
+          _ => State::DEAD,
+        }
+      }
+      State::S8 => {
+        match CLASSES[usize::from(byte)] {
+          0..=4 | 6..=15 | 17..=28 => State::S27,
+          5 => State::S28,
+          16 => State::S29,
+          _ => State::DEAD,
+        }
+      }
+      State::S9 => {
+        match CLASSES[usize::from(byte)] {
+          25 => State::S30,
+          _ => State::DEAD,
+        }
+      }
+      State::S10 => {
+        return Some((Unit::Day, 1));
+      }
+      State::S11 => {
+        match CLASSES[usize::from(byte)] {
+          23 => State::S31,
+          _ => State::DEAD,
+        }
+      }
+      State::S12 => {
+        return Some((Unit::Hour, 1));
+      }
+      State::S13 => {
+        match CLASSES[usize::from(byte)] {
+          19 => State::S32,
+          _ => State::DEAD,
+        }
+      }
+      State::S14 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S33,
+          17 => State::S34,
+          _ => State::DEAD,
+        }
+      }
+      State::S15 => {
+        return Some((Unit::Minute, 1));
+      }
+      State::S16 => {
+        match CLASSES[usize::from(byte)] {
+          13 => State::S35,
+          11 => State::S36,
+          3 => State::S37,
+          _ => State::DEAD,
+        }
+      }
+      State::S17 => {
+        match CLASSES[usize::from(byte)] {
+          0..=12 | 14..=16 | 18..=28 => State::S38,
+          13 => State::S39,
+          17 => State::S40,
+          _ => State::DEAD,
+        }
+      }
+      State::S18 => {
+        match CLASSES[usize::from(byte)] {
+          0..=4 | 6..=28 => State::S41,
+          5 => State::S42,
+          _ => State::DEAD,
+        }
+      }
+      State::S19 => {
+        match CLASSES[usize::from(byte)] {
+          13 => State::S43,
+          _ => State::DEAD,
+        }
+      }
+      State::S20 => {
+        match CLASSES[usize::from(byte)] {
+          0..=4 | 6..=28 => State::S44,
+          5 => State::S45,
+          _ => State::DEAD,
+        }
+      }
+      State::S21 => {
+        return Some((Unit::Second, 1));
+      }
+      State::S22 => {
+        match CLASSES[usize::from(byte)] {
+          3 => State::S46,
+          _ => State::DEAD,
+        }
+      }
+      State::S23 => {
+        match CLASSES[usize::from(byte)] {
+          0..=4 | 6..=28 => State::S47,
+          5 => State::S48,
+          _ => State::DEAD,
+        }
+      }
+      State::S24 => {
+        return Some((Unit::Week, 1));
+      }
+      State::S25 => {
+        match CLASSES[usize::from(byte)] {
+          5 => State::S49,
+          _ => State::DEAD,
+        }
+      }
+      State::S26 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S50,
+          17 => State::S51,
+          _ => State::DEAD,
+        }
+      }
+      State::S27 => {
+        return Some((Unit::Year, 1));
+      }
+      State::S28 => {
+        match CLASSES[usize::from(byte)] {
+          1 => State::S52,
+          _ => State::DEAD,
+        }
+      }
+      State::S29 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S53,
+          17 => State::S54,
+          _ => State::DEAD,
+        }
+      }
+      State::S30 => {
+        match CLASSES[usize::from(byte)] {
+          17 => State::S55,
+          _ => State::DEAD,
+        }
+      }
+      State::S31 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S56,
+          17 => State::S57,
+          _ => State::DEAD,
+        }
+      }
+      State::S32 => {
+        match CLASSES[usize::from(byte)] {
+          16 => State::S58,
+          _ => State::DEAD,
+        }
+      }
+      State::S33 => {
+        return Some((Unit::Hour, 2));
+      }
+      State::S34 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S59,
+          _ => State::DEAD,
+        }
+      }
+      State::S35 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18 | 20..=28 => State::S60,
+          17 => State::S61,
+          19 => State::S62,
+          _ => State::DEAD,
+        }
+      }
+      State::S36 => {
+        match CLASSES[usize::from(byte)] {
+          11 => State::S63,
+          _ => State::DEAD,
+        }
+      }
+      State::S37 => {
+        match CLASSES[usize::from(byte)] {
+          16 => State::S64,
+          _ => State::DEAD,
+        }
+      }
+      State::S38 => {
+        return Some((Unit::Month, 2));
+      }
+      State::S39 => {
+        match CLASSES[usize::from(byte)] {
+          18 => State::S65,
+          _ => State::DEAD,
+        }
+      }
+      State::S40 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S66,
+          _ => State::DEAD,
+        }
+      }
+      State::S41 => {
+        return Some((Unit::Millisecond, 2));
+      }
+      State::S42 => {
+        match CLASSES[usize::from(byte)] {
+          3 => State::S67,
+          _ => State::DEAD,
+        }
+      }
+      State::S43 => {
+        match CLASSES[usize::from(byte)] {
+          14 => State::S68,
+          _ => State::DEAD,
+        }
+      }
+      State::S44 => {
+        return Some((Unit::Nanosecond, 2));
+      }
+      State::S45 => {
+        match CLASSES[usize::from(byte)] {
+          3 => State::S69,
+          _ => State::DEAD,
+        }
+      }
+      State::S46 => {
+        match CLASSES[usize::from(byte)] {
+          0..=13 | 15..=16 | 18..=28 => State::S70,
+          14 => State::S71,
+          17 => State::S72,
+          _ => State::DEAD,
+        }
+      }
+      State::S47 => {
+        return Some((Unit::Microsecond, 2));
+      }
+      State::S48 => {
+        match CLASSES[usize::from(byte)] {
+          3 => State::S73,
+          _ => State::DEAD,
+        }
+      }
+      State::S49 => {
+        match CLASSES[usize::from(byte)] {
+          10 => State::S74,
+          _ => State::DEAD,
+        }
+      }
+      State::S50 => {
+        return Some((Unit::Week, 2));
+      }
+      State::S51 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S75,
+          _ => State::DEAD,
+        }
+      }
+      State::S52 => {
+        match CLASSES[usize::from(byte)] {
+          16 => State::S76,
+          _ => State::DEAD,
+        }
+      }
+      State::S53 => {
+        return Some((Unit::Year, 2));
+      }
+      State::S54 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S77,
+          _ => State::DEAD,
+        }
+      }
+      State::S55 => {
+        match CLASSES[usize::from(byte)] {
+          0..=4 | 6..=28 => State::S78,
+          5 => State::S79,
+          _ => State::DEAD,
+        }
+      }
+      State::S56 => {
+        return Some((Unit::Day, 3));
+      }
+      State::S57 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S80,
+          _ => State::DEAD,
+        }
+      }
+      State::S58 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S81,
+          17 => State::S82,
+          _ => State::DEAD,
+        }
+      }
+      State::S59 => {
+        return Some((Unit::Hour, 3));
+      }
+      State::S60 => {
+        return Some((Unit::Minute, 3));
+      }
+      State::S61 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S83,
+          _ => State::DEAD,
+        }
+      }
+      State::S62 => {
+        match CLASSES[usize::from(byte)] {
+          18 => State::S84,
+          _ => State::DEAD,
+        }
+      }
+      State::S63 => {
+        match CLASSES[usize::from(byte)] {
+          8 => State::S85,
+          _ => State::DEAD,
+        }
+      }
+      State::S64 => {
+        match CLASSES[usize::from(byte)] {
+          14 => State::S86,
+          _ => State::DEAD,
+        }
+      }
+      State::S65 => {
+        match CLASSES[usize::from(byte)] {
+          7 => State::S87,
+          _ => State::DEAD,
+        }
+      }
+      State::S66 => {
+        return Some((Unit::Month, 3));
+      }
+      State::S67 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S88,
+          17 => State::S89,
+          _ => State::DEAD,
+        }
+      }
+      State::S68 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S90,
+          17 => State::S91,
+          _ => State::DEAD,
+        }
+      }
+      State::S69 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S92,
+          17 => State::S93,
+          _ => State::DEAD,
+        }
+      }
+      State::S70 => {
+        return Some((Unit::Second, 3));
+      }
+      State::S71 => {
+        match CLASSES[usize::from(byte)] {
+          13 => State::S94,
+          _ => State::DEAD,
+        }
+      }
+      State::S72 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S95,
+          _ => State::DEAD,
+        }
+      }
+      State::S73 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S96,
+          17 => State::S97,
+          _ => State::DEAD,
+        }
+      }
+      State::S74 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S98,
+          17 => State::S99,
+          _ => State::DEAD,
+        }
+      }
+      State::S75 => {
+        return Some((Unit::Week, 3));
+      }
+      State::S76 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S100,
+          17 => State::S101,
+          _ => State::DEAD,
+        }
+      }
+      State::S77 => {
+        return Some((Unit::Year, 3));
+      }
+      State::S78 => {
+        return Some((Unit::Microsecond, 3));
+      }
+      State::S79 => {
+        match CLASSES[usize::from(byte)] {
+          3 => State::S102,
+          _ => State::DEAD,
+        }
+      }
+      State::S80 => {
+        return Some((Unit::Day, 4));
+      }
+      State::S81 => {
+        return Some((Unit::Hour, 4));
+      }
+      State::S82 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S103,
+          _ => State::DEAD,
+        }
+      }
+      State::S83 => {
+        return Some((Unit::Minute, 4));
+      }
+      State::S84 => {
+        match CLASSES[usize::from(byte)] {
+          5 => State::S104,
+          _ => State::DEAD,
+        }
+      }
+      State::S85 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S105,
+          17 => State::S106,
+          _ => State::DEAD,
+        }
+      }
+      State::S86 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S107,
+          17 => State::S108,
+          _ => State::DEAD,
+        }
+      }
+      State::S87 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S109,
+          17 => State::S110,
+          _ => State::DEAD,
+        }
+      }
+      State::S88 => {
+        return Some((Unit::Millisecond, 4));
+      }
+      State::S89 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S111,
+          _ => State::DEAD,
+        }
+      }
+      State::S90 => {
+        return Some((Unit::Nanosecond, 4));
+      }
+      State::S91 => {
+        match CLASSES[usize::from(byte)] {
+          0..=4 | 6..=28 => State::S112,
+          5 => State::S113,
+          _ => State::DEAD,
+        }
+      }
+      State::S92 => {
+        return Some((Unit::Nanosecond, 4));
+      }
+      State::S93 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S114,
+          _ => State::DEAD,
+        }
+      }
+      State::S94 => {
+        match CLASSES[usize::from(byte)] {
+          4 => State::S115,
+          _ => State::DEAD,
+        }
+      }
+      State::S95 => {
+        return Some((Unit::Second, 4));
+      }
+      State::S96 => {
+        return Some((Unit::Microsecond, 4));
+      }
+      State::S97 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S116,
+          _ => State::DEAD,
+        }
+      }
+      State::S98 => {
+        return Some((Unit::Week, 4));
+      }
+      State::S99 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S117,
+          _ => State::DEAD,
+        }
+      }
+      State::S100 => {
+        return Some((Unit::Year, 4));
+      }
+      State::S101 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S118,
+          _ => State::DEAD,
+        }
+      }
+      State::S102 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S119,
+          17 => State::S120,
+          _ => State::DEAD,
+        }
+      }
+      State::S103 => {
+        return Some((Unit::Hour, 5));
+      }
+      State::S104 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S121,
+          17 => State::S122,
+          _ => State::DEAD,
+        }
+      }
+      State::S105 => {
+        return Some((Unit::Millisecond, 5));
+      }
+      State::S106 => {
+        match CLASSES[usize::from(byte)] {
+          0..=4 | 6..=28 => State::S123,
+          5 => State::S124,
+          _ => State::DEAD,
+        }
+      }
+      State::S107 => {
+        return Some((Unit::Microsecond, 5));
+      }
+      State::S108 => {
+        match CLASSES[usize::from(byte)] {
+          0..=4 | 6..=28 => State::S125,
+          5 => State::S126,
+          _ => State::DEAD,
+        }
+      }
+      State::S109 => {
+        return Some((Unit::Month, 5));
+      }
+      State::S110 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S127,
+          _ => State::DEAD,
+        }
+      }
+      State::S111 => {
+        return Some((Unit::Millisecond, 5));
+      }
+      State::S112 => {
+        return Some((Unit::Nanosecond, 5));
+      }
+      State::S113 => {
+        match CLASSES[usize::from(byte)] {
+          3 => State::S128,
+          _ => State::DEAD,
+        }
+      }
+      State::S114 => {
+        return Some((Unit::Nanosecond, 5));
+      }
+      State::S115 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S129,
+          17 => State::S130,
+          _ => State::DEAD,
+        }
+      }
+      State::S116 => {
+        return Some((Unit::Microsecond, 5));
+      }
+      State::S117 => {
+        return Some((Unit::Week, 5));
+      }
+      State::S118 => {
+        return Some((Unit::Year, 5));
+      }
+      State::S119 => {
+        return Some((Unit::Microsecond, 5));
+      }
+      State::S120 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S131,
+          _ => State::DEAD,
+        }
+      }
+      State::S121 => {
+        return Some((Unit::Minute, 6));
+      }
+      State::S122 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S132,
+          _ => State::DEAD,
+        }
+      }
+      State::S123 => {
+        return Some((Unit::Millisecond, 6));
+      }
+      State::S124 => {
+        match CLASSES[usize::from(byte)] {
+          3 => State::S133,
+          _ => State::DEAD,
+        }
+      }
+      State::S125 => {
+        return Some((Unit::Microsecond, 6));
+      }
+      State::S126 => {
+        match CLASSES[usize::from(byte)] {
+          3 => State::S134,
+          _ => State::DEAD,
+        }
+      }
+      State::S127 => {
+        return Some((Unit::Month, 6));
+      }
+      State::S128 => {
+        match CLASSES[usize::from(byte)] {
+          14 => State::S135,
+          _ => State::DEAD,
+        }
+      }
+      State::S129 => {
+        return Some((Unit::Second, 6));
+      }
+      State::S130 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S136,
+          _ => State::DEAD,
+        }
+      }
+      State::S131 => {
+        return Some((Unit::Microsecond, 6));
+      }
+      State::S132 => {
+        return Some((Unit::Minute, 7));
+      }
+      State::S133 => {
+        match CLASSES[usize::from(byte)] {
+          14 => State::S137,
+          _ => State::DEAD,
+        }
+      }
+      State::S134 => {
+        match CLASSES[usize::from(byte)] {
+          14 => State::S138,
+          _ => State::DEAD,
+        }
+      }
+      State::S135 => {
+        match CLASSES[usize::from(byte)] {
+          13 => State::S139,
+          _ => State::DEAD,
+        }
+      }
+      State::S136 => {
+        return Some((Unit::Second, 7));
+      }
+      State::S137 => {
+        match CLASSES[usize::from(byte)] {
+          13 => State::S140,
+          _ => State::DEAD,
+        }
+      }
+      State::S138 => {
+        match CLASSES[usize::from(byte)] {
+          13 => State::S141,
+          _ => State::DEAD,
+        }
+      }
+      State::S139 => {
+        match CLASSES[usize::from(byte)] {
+          4 => State::S142,
+          _ => State::DEAD,
+        }
+      }
+      State::S140 => {
+        match CLASSES[usize::from(byte)] {
+          4 => State::S143,
+          _ => State::DEAD,
+        }
+      }
+      State::S141 => {
+        match CLASSES[usize::from(byte)] {
+          4 => State::S144,
+          _ => State::DEAD,
+        }
+      }
+      State::S142 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S145,
+          17 => State::S146,
+          _ => State::DEAD,
+        }
+      }
+      State::S143 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S147,
+          17 => State::S148,
+          _ => State::DEAD,
+        }
+      }
+      State::S144 => {
+        match CLASSES[usize::from(byte)] {
+          0..=16 | 18..=28 => State::S149,
+          17 => State::S150,
+          _ => State::DEAD,
+        }
+      }
+      State::S145 => {
+        return Some((Unit::Nanosecond, 10));
+      }
+      State::S146 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S151,
+          _ => State::DEAD,
+        }
+      }
+      State::S147 => {
+        return Some((Unit::Millisecond, 11));
+      }
+      State::S148 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S152,
+          _ => State::DEAD,
+        }
+      }
+      State::S149 => {
+        return Some((Unit::Microsecond, 11));
+      }
+      State::S150 => {
+        match CLASSES[usize::from(byte)] {
+          0..=28 => State::S153,
+          _ => State::DEAD,
+        }
+      }
+      State::S151 => {
+        return Some((Unit::Nanosecond, 11));
+      }
+      State::S152 => {
+        return Some((Unit::Millisecond, 12));
+      }
+      State::S153 => {
+        return Some((Unit::Microsecond, 12));
+      }
+    };
+  }
+  return match sid {
+    State::S10 => Some((Unit::Day, 1)),
+    State::S12 => Some((Unit::Hour, 1)),
+    State::S15 => Some((Unit::Minute, 1)),
+    State::S21 => Some((Unit::Second, 1)),
+    State::S24 => Some((Unit::Week, 1)),
+    State::S27 => Some((Unit::Year, 1)),
+    State::S33 => Some((Unit::Hour, 2)),
+    State::S38 => Some((Unit::Month, 2)),
+    State::S41 => Some((Unit::Millisecond, 2)),
+    State::S44 => Some((Unit::Nanosecond, 2)),
+    State::S47 => Some((Unit::Microsecond, 2)),
+    State::S50 => Some((Unit::Week, 2)),
+    State::S53 => Some((Unit::Year, 2)),
+    State::S56 => Some((Unit::Day, 3)),
+    State::S59 => Some((Unit::Hour, 3)),
+    State::S60 => Some((Unit::Minute, 3)),
+    State::S66 => Some((Unit::Month, 3)),
+    State::S70 => Some((Unit::Second, 3)),
+    State::S75 => Some((Unit::Week, 3)),
+    State::S77 => Some((Unit::Year, 3)),
+    State::S78 => Some((Unit::Microsecond, 3)),
+    State::S80 => Some((Unit::Day, 4)),
+    State::S81 => Some((Unit::Hour, 4)),
+    State::S83 => Some((Unit::Minute, 4)),
+    State::S88 => Some((Unit::Millisecond, 4)),
+    State::S90 => Some((Unit::Nanosecond, 4)),
+    State::S92 => Some((Unit::Nanosecond, 4)),
+    State::S95 => Some((Unit::Second, 4)),
+    State::S96 => Some((Unit::Microsecond, 4)),
+    State::S98 => Some((Unit::Week, 4)),
+    State::S100 => Some((Unit::Year, 4)),
+    State::S103 => Some((Unit::Hour, 5)),
+    State::S105 => Some((Unit::Millisecond, 5)),
+    State::S107 => Some((Unit::Microsecond, 5)),
+    State::S109 => Some((Unit::Month, 5)),
+    State::S111 => Some((Unit::Millisecond, 5)),
+    State::S112 => Some((Unit::Nanosecond, 5)),
+    State::S114 => Some((Unit::Nanosecond, 5)),
+    State::S116 => Some((Unit::Microsecond, 5)),
+    State::S117 => Some((Unit::Week, 5)),
+    State::S118 => Some((Unit::Year, 5)),
+    State::S119 => Some((Unit::Microsecond, 5)),
+    State::S121 => Some((Unit::Minute, 6)),
+    State::S123 => Some((Unit::Millisecond, 6)),
+    State::S125 => Some((Unit::Microsecond, 6)),
+    State::S127 => Some((Unit::Month, 6)),
+    State::S129 => Some((Unit::Second, 6)),
+    State::S131 => Some((Unit::Microsecond, 6)),
+    State::S132 => Some((Unit::Minute, 7)),
+    State::S136 => Some((Unit::Second, 7)),
+    State::S145 => Some((Unit::Nanosecond, 10)),
+    State::S147 => Some((Unit::Millisecond, 11)),
+    State::S149 => Some((Unit::Microsecond, 11)),
+    State::S151 => Some((Unit::Nanosecond, 11)),
+    State::S152 => Some((Unit::Millisecond, 12)),
+    State::S153 => Some((Unit::Microsecond, 12)),
+    State::S1 => Some((Unit::Day, 1)),
+    State::S2 => Some((Unit::Hour, 1)),
+    State::S3 => Some((Unit::Minute, 1)),
+    State::S5 => Some((Unit::Second, 1)),
+    State::S7 => Some((Unit::Week, 1)),
+    State::S8 => Some((Unit::Year, 1)),
+    State::S14 => Some((Unit::Hour, 2)),
+    State::S17 => Some((Unit::Month, 2)),
+    State::S18 => Some((Unit::Millisecond, 2)),
+    State::S20 => Some((Unit::Nanosecond, 2)),
+    State::S23 => Some((Unit::Microsecond, 2)),
+    State::S26 => Some((Unit::Week, 2)),
+    State::S29 => Some((Unit::Year, 2)),
+    State::S31 => Some((Unit::Day, 3)),
+    State::S34 => Some((Unit::Hour, 3)),
+    State::S35 => Some((Unit::Minute, 3)),
+    State::S40 => Some((Unit::Month, 3)),
+    State::S46 => Some((Unit::Second, 3)),
+    State::S51 => Some((Unit::Week, 3)),
+    State::S54 => Some((Unit::Year, 3)),
+    State::S55 => Some((Unit::Microsecond, 3)),
+    State::S57 => Some((Unit::Day, 4)),
+    State::S58 => Some((Unit::Hour, 4)),
+    State::S61 => Some((Unit::Minute, 4)),
+    State::S67 => Some((Unit::Millisecond, 4)),
+    State::S68 => Some((Unit::Nanosecond, 4)),
+    State::S69 => Some((Unit::Nanosecond, 4)),
+    State::S72 => Some((Unit::Second, 4)),
+    State::S73 => Some((Unit::Microsecond, 4)),
+    State::S74 => Some((Unit::Week, 4)),
+    State::S76 => Some((Unit::Year, 4)),
+    State::S82 => Some((Unit::Hour, 5)),
+    State::S85 => Some((Unit::Millisecond, 5)),
+    State::S86 => Some((Unit::Microsecond, 5)),
+    State::S87 => Some((Unit::Month, 5)),
+    State::S89 => Some((Unit::Millisecond, 5)),
+    State::S91 => Some((Unit::Nanosecond, 5)),
+    State::S93 => Some((Unit::Nanosecond, 5)),
+    State::S97 => Some((Unit::Microsecond, 5)),
+    State::S99 => Some((Unit::Week, 5)),
+    State::S101 => Some((Unit::Year, 5)),
+    State::S102 => Some((Unit::Microsecond, 5)),
+    State::S104 => Some((Unit::Minute, 6)),
+    State::S106 => Some((Unit::Millisecond, 6)),
+    State::S108 => Some((Unit::Microsecond, 6)),
+    State::S110 => Some((Unit::Month, 6)),
+    State::S115 => Some((Unit::Second, 6)),
+    State::S120 => Some((Unit::Microsecond, 6)),
+    State::S122 => Some((Unit::Minute, 7)),
+    State::S130 => Some((Unit::Second, 7)),
+    State::S142 => Some((Unit::Nanosecond, 10)),
+    State::S143 => Some((Unit::Millisecond, 11)),
+    State::S144 => Some((Unit::Microsecond, 11)),
+    State::S146 => Some((Unit::Nanosecond, 11)),
+    State::S148 => Some((Unit::Millisecond, 12)),
+    State::S150 => Some((Unit::Microsecond, 12)),
+    _ => None,
+  };
+
+  #[allow(clippy::upper_case_acronyms)]
+  enum State {
+    DEAD,
+    S0,
+    S1,
+    S2,
+    S3,
+    S4,
+    S5,
+    S6,
+    S7,
+    S8,
+    S9,
+    S10,
+    S11,
+    S12,
+    S13,
+    S14,
+    S15,
+    S16,
+    S17,
+    S18,
+    S19,
+    S20,
+    S21,
+    S22,
+    S23,
+    S24,
+    S25,
+    S26,
+    S27,
+    S28,
+    S29,
+    S30,
+    S31,
+    S32,
+    S33,
+    S34,
+    S35,
+    S36,
+    S37,
+    S38,
+    S39,
+    S40,
+    S41,
+    S42,
+    S43,
+    S44,
+    S45,
+    S46,
+    S47,
+    S48,
+    S49,
+    S50,
+    S51,
+    S52,
+    S53,
+    S54,
+    S55,
+    S56,
+    S57,
+    S58,
+    S59,
+    S60,
+    S61,
+    S62,
+    S63,
+    S64,
+    S65,
+    S66,
+    S67,
+    S68,
+    S69,
+    S70,
+    S71,
+    S72,
+    S73,
+    S74,
+    S75,
+    S76,
+    S77,
+    S78,
+    S79,
+    S80,
+    S81,
+    S82,
+    S83,
+    S84,
+    S85,
+    S86,
+    S87,
+    S88,
+    S89,
+    S90,
+    S91,
+    S92,
+    S93,
+    S94,
+    S95,
+    S96,
+    S97,
+    S98,
+    S99,
+    S100,
+    S101,
+    S102,
+    S103,
+    S104,
+    S105,
+    S106,
+    S107,
+    S108,
+    S109,
+    S110,
+    S111,
+    S112,
+    S113,
+    S114,
+    S115,
+    S116,
+    S117,
+    S118,
+    S119,
+    S120,
+    S121,
+    S122,
+    S123,
+    S124,
+    S125,
+    S126,
+    S127,
+    S128,
+    S129,
+    S130,
+    S131,
+    S132,
+    S133,
+    S134,
+    S135,
+    S136,
+    S137,
+    S138,
+    S139,
+    S140,
+    S141,
+    S142,
+    S143,
+    S144,
+    S145,
+    S146,
+    S147,
+    S148,
+    S149,
+    S150,
+    S151,
+    S152,
+    S153,
+  }
+}
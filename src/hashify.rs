@@ -1,92 +1,95 @@
-use crate::Unit;
+use crate::{
+    ascii_lowercase, designator_len, designator_len_ignore_ascii_case,
+    inline_lookup_strategy,
+    labels::{designator_labels, MAX_LABEL_LEN},
+    LookupError, Unit, UnitLookup,
+};
 
-#[inline(never)]
-pub fn lookup<'i>(input: &'i [u8]) -> Result<(Unit, &'i [u8]), anyhow::Error> {
-    let is_valid_designator_byte =
-            // The \xC2 and \xB5 is for `µ`.
-            |b: u8| b'a' <= b && b <= b'z' || b == 0xC2 || b == 0xB5;
-    let end = input
-        .iter()
-        .position(|&b| !is_valid_designator_byte(b))
-        .unwrap_or(input.len());
-    let needle = &input[..end];
-    if needle.is_empty() {
-        anyhow::bail!(
-            "expected to find unit designator suffix \
-             (e.g., 'years' or 'secs'), \
-             but found end of input",
-        );
+/// The `hashify` technique.
+#[derive(Clone, Copy, Debug)]
+pub struct Hashify;
+
+impl UnitLookup for Hashify {
+    fn name(&self) -> &'static str {
+        "hashify"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
     }
-    let unit = find(needle).ok_or_else(|| {
-        anyhow::anyhow!(
-            "expected to find unit designator suffix \
-             (e.g., 'years' or 'secs'), \
-             but found input beginning with {found:?} instead",
-            found = std::str::from_utf8(needle).unwrap_or("N/A"),
-        )
-    })?;
+}
+
+inline_lookup_strategy!(HashifyInline, Hashify, "hashify-inline");
+
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let end = designator_len(input);
+    let Some(unit) = designator(&input[..end]) else {
+        return Err(LookupError::new(input));
+    };
     Ok((unit, &input[end..]))
 }
 
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    let end = designator_len(input);
+    let unit = designator(&input[..end])?;
+    Some((unit, end))
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let end = designator_len_ignore_ascii_case(input);
+    let mut buf = [0; MAX_LABEL_LEN];
+    let Some(unit) =
+        ascii_lowercase(&input[..end], &mut buf).and_then(designator)
+    else {
+        return Err(LookupError::new_ignore_ascii_case(input));
+    };
+    Ok((unit, &input[end..]))
+}
+
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    let end = designator_len_ignore_ascii_case(input);
+    let mut buf = [0; MAX_LABEL_LEN];
+    let unit = designator(ascii_lowercase(&input[..end], &mut buf)?)?;
+    Some((unit, end))
+}
+
+/// Maps a complete unit designator label to its unit.
 #[inline(always)]
-fn find(input: &[u8]) -> Option<Unit> {
-    hashify::tiny_map! {
-        input,
-        "years" => Unit::Year,
-        "year" => Unit::Year,
-        "yrs" => Unit::Year,
-        "yr" => Unit::Year,
-        "y" => Unit::Year,
-        "months" => Unit::Month,
-        "month" => Unit::Month,
-        "mos" => Unit::Month,
-        "mo" => Unit::Month,
-        "weeks" => Unit::Week,
-        "week" => Unit::Week,
-        "wks" => Unit::Week,
-        "wk" => Unit::Week,
-        "w" => Unit::Week,
-        "days" => Unit::Day,
-        "day" => Unit::Day,
-        "d" => Unit::Day,
-        "hours" => Unit::Hour,
-        "hour" => Unit::Hour,
-        "hrs" => Unit::Hour,
-        "hr" => Unit::Hour,
-        "h" => Unit::Hour,
-        "minutes" => Unit::Minute,
-        "minute" => Unit::Minute,
-        "mins" => Unit::Minute,
-        "min" => Unit::Minute,
-        "m" => Unit::Minute,
-        "seconds" => Unit::Second,
-        "second" => Unit::Second,
-        "secs" => Unit::Second,
-        "sec" => Unit::Second,
-        "s" => Unit::Second,
-        "milliseconds" => Unit::Millisecond,
-        "millisecond" => Unit::Millisecond,
-        "millis" => Unit::Millisecond,
-        "milli" => Unit::Millisecond,
-        "msecs" => Unit::Millisecond,
-        "msec" => Unit::Millisecond,
-        "ms" => Unit::Millisecond,
-        "microseconds" => Unit::Microsecond,
-        "microsecond" => Unit::Microsecond,
-        "micros" => Unit::Microsecond,
-        "micro" => Unit::Microsecond,
-        "usecs" => Unit::Microsecond,
-        "usec" => Unit::Microsecond,
-        "µsecs" => Unit::Microsecond,
-        "µsec" => Unit::Microsecond,
-        "us" => Unit::Microsecond,
-        "µs" => Unit::Microsecond,
-        "nanoseconds" => Unit::Nanosecond,
-        "nanosecond" => Unit::Nanosecond,
-        "nanos" => Unit::Nanosecond,
-        "nano" => Unit::Nanosecond,
-        "nsecs" => Unit::Nanosecond,
-        "nsec" => Unit::Nanosecond,
-        "ns" => Unit::Nanosecond,
+fn designator(input: &[u8]) -> Option<Unit> {
+    macro_rules! designators {
+        ($($label:literal => $unit:ident,)*) => {
+            hashify::tiny_map! { input, $($label => Unit::$unit,)* }
+        };
     }
+    designator_labels!(designators)
 }
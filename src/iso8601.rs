@@ -0,0 +1,676 @@
+/*!
+Parsing and printing of ISO 8601 durations, e.g., `P2Y1M15DT5H59M1S`.
+
+Both directions use the same representation as the friendly duration parser
+in [`parse`](crate::parse): a list of `(i64, Unit)` components. This makes it
+possible to convert between the two formats.
+
+The grammar accepted is:
+
+```text
+duration  = sign? 'P' date* ('T' time+)?
+date      = digit+ ('Y' | 'M' | 'W' | 'D')
+time      = digit+ ('H' | 'M') | digit+ fraction? 'S'
+fraction  = ('.' | ',') digit{1,9}
+sign      = '+' | '-'
+```
+
+Designators must appear in the order written above and each may appear at
+most once. At least one component is required. The `M` designator means
+months before the `T` separator and minutes after it.
+
+ISO 8601 has no designators for units smaller than a second. Instead, seconds
+may have a fractional part. When parsing, the fraction is split into
+millisecond, microsecond and nanosecond components, and any of those that are
+zero are omitted. If the fraction is non-zero and the integral number of
+seconds is zero, then the seconds component is omitted too. So `PT1.5S` is
+`[(1, Second), (500, Millisecond)]` and `PT0.000002S` is
+`[(2, Microsecond)]`.
+
+A sign applies to the duration as a whole. So `-P1DT2H` is
+`[(-1, Day), (-2, Hour)]`.
+
+# Round tripping
+
+[`print`] only succeeds for component lists that can be represented exactly,
+and for any list that it succeeds on, [`parse`] returns the very same list.
+That is, the conversion is lossless. A list can be represented when:
+
+* It is not empty.
+* Its units are in strictly descending order. e.g., years before days.
+* Its non-zero values all have the same sign.
+* Every millisecond, microsecond and nanosecond value is non-zero and less
+  than `1000` in magnitude.
+* If there is a zero seconds component, then it isn't followed by any
+  sub-second components. (Since parsing would omit it.)
+
+The friendly duration parser doesn't enforce any of these, since they are
+policy decisions. So, for example, `1 hour 90 minutes` parses as a friendly
+duration but can't be printed as ISO 8601 without first doing some duration
+arithmetic, which is out of scope for this crate.
+*/
+
+use crate::Unit;
+
+/// Parses an ISO 8601 duration into a list of components.
+///
+/// See the [module documentation](self) for the grammar and how fractional
+/// seconds are handled.
+///
+/// # Example
+///
+/// ```
+/// use duration_unit_lookup::{iso8601, Unit::*};
+///
+/// let components = iso8601::parse(b"P2Y1M15DT5H59M1S").unwrap();
+/// assert_eq!(components, vec![
+///     (2, Year), (1, Month), (15, Day), (5, Hour), (59, Minute), (1, Second),
+/// ]);
+/// ```
+pub fn parse(input: &[u8]) -> Result<Vec<(i64, Unit)>, ParseError> {
+    let mut at = 0;
+    let negative = match input.first() {
+        Some(&b'-') => {
+            at += 1;
+            true
+        }
+        Some(&b'+') => {
+            at += 1;
+            false
+        }
+        _ => false,
+    };
+    if input.get(at) != Some(&b'P') {
+        return Err(ParseError::ExpectedPeriod { offset: at });
+    }
+    at += 1;
+
+    let mut components = vec![];
+    let mut time = false;
+    // The last unit parsed. Every unit must be smaller than the previous one.
+    let mut last: Option<Unit> = None;
+    while at < input.len() {
+        if input[at] == b'T' && !time {
+            time = true;
+            at += 1;
+            // At least one time component must follow the separator.
+            if at == input.len() {
+                return Err(ParseError::ExpectedNumber { offset: at });
+            }
+            continue;
+        }
+
+        let start = at;
+        let (magnitude, end) = parse_digits(input, at)?;
+        at = end;
+        let mut nanos = None;
+        if matches!(input.get(at), Some(&b'.') | Some(&b',')) {
+            let (fraction, end) = parse_fraction(input, at + 1)?;
+            nanos = Some(fraction);
+            at = end;
+        }
+        let Some(&designator) = input.get(at) else {
+            return Err(ParseError::ExpectedDesignator { offset: at });
+        };
+        let unit = match (time, designator) {
+            (false, b'Y') => Unit::Year,
+            (false, b'M') => Unit::Month,
+            (false, b'W') => Unit::Week,
+            (false, b'D') => Unit::Day,
+            (true, b'H') => Unit::Hour,
+            (true, b'M') => Unit::Minute,
+            (true, b'S') => Unit::Second,
+            _ => return Err(ParseError::UnexpectedDesignator { offset: at }),
+        };
+        if last.is_some_and(|last| unit >= last) {
+            return Err(ParseError::UnexpectedDesignator { offset: at });
+        }
+        if nanos.is_some() && unit != Unit::Second {
+            return Err(ParseError::InvalidFraction { offset: end });
+        }
+        at += 1;
+        last = Some(unit);
+
+        let value = signed(magnitude, negative)
+            .ok_or(ParseError::Overflow { start, end })?;
+        let nanos = nanos.unwrap_or(0);
+        if value != 0 || nanos == 0 {
+            components.push((value, unit));
+        }
+        let groups = [
+            (nanos / 1_000_000, Unit::Millisecond),
+            (nanos / 1_000 % 1_000, Unit::Microsecond),
+            (nanos % 1_000, Unit::Nanosecond),
+        ];
+        for (group, unit) in groups {
+            if group != 0 {
+                let group = i64::from(group);
+                components.push((if negative { -group } else { group }, unit));
+            }
+        }
+    }
+    if last.is_none() {
+        return Err(ParseError::ExpectedNumber { offset: at });
+    }
+    Ok(components)
+}
+
+/// Prints a list of components as an ISO 8601 duration.
+///
+/// This returns an error if the components given can't be represented
+/// exactly. When this succeeds, parsing the result with [`parse`] is
+/// guaranteed to return the same list of components. See the
+/// [module documentation](self) for details.
+///
+/// # Example
+///
+/// ```
+/// use duration_unit_lookup::{iso8601, Unit::*};
+///
+/// let components = [(1, Hour), (30, Minute), (1, Second), (5, Millisecond)];
+/// assert_eq!(iso8601::print(&components).unwrap(), "PT1H30M1.005S");
+/// ```
+pub fn print(components: &[(i64, Unit)]) -> Result<String, PrintError> {
+    use std::fmt::Write;
+
+    if components.is_empty() {
+        return Err(PrintError::Empty);
+    }
+    // The sign of the duration is the sign of its first non-zero value.
+    let negative = components
+        .iter()
+        .find(|&&(value, _)| value != 0)
+        .is_some_and(|&(value, _)| value < 0);
+    for (index, &(value, unit)) in components.iter().enumerate() {
+        if index > 0 && unit >= components[index - 1].1 {
+            return Err(PrintError::OutOfOrder { index });
+        }
+        if (negative && value > 0) || (!negative && value < 0) {
+            return Err(PrintError::MixedSign { index });
+        }
+        if unit < Unit::Second {
+            if value == 0 || value.unsigned_abs() >= 1_000 {
+                return Err(PrintError::SubSecond { index });
+            }
+            if index > 0 && components[index - 1] == (0, Unit::Second) {
+                return Err(PrintError::SubSecond { index });
+            }
+        }
+    }
+
+    let mut out = String::new();
+    if negative {
+        out.push('-');
+    }
+    out.push('P');
+    let mut time = false;
+    let mut seconds = None;
+    let mut nanos = 0;
+    for &(value, unit) in components {
+        let value = value.unsigned_abs();
+        if unit <= Unit::Hour && !time {
+            out.push('T');
+            time = true;
+        }
+        let designator = match unit {
+            Unit::Year => 'Y',
+            Unit::Month => 'M',
+            Unit::Week => 'W',
+            Unit::Day => 'D',
+            Unit::Hour => 'H',
+            Unit::Minute => 'M',
+            // Seconds and sub-seconds are all written together at the end.
+            Unit::Second => {
+                seconds = Some(value);
+                continue;
+            }
+            Unit::Millisecond => {
+                nanos += value * 1_000_000;
+                continue;
+            }
+            Unit::Microsecond => {
+                nanos += value * 1_000;
+                continue;
+            }
+            Unit::Nanosecond => {
+                nanos += value;
+                continue;
+            }
+        };
+        write!(out, "{value}{designator}").unwrap();
+    }
+    if seconds.is_some() || nanos > 0 {
+        write!(out, "{}", seconds.unwrap_or(0)).unwrap();
+        if nanos > 0 {
+            let fraction = format!("{nanos:09}");
+            write!(out, ".{}", fraction.trim_end_matches('0')).unwrap();
+        }
+        out.push('S');
+    }
+    Ok(out)
+}
+
+/// An error that occurs when parsing an ISO 8601 duration.
+///
+/// All offsets are byte offsets into the input given to [`parse`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The `P` designator (optionally preceded by a sign) was expected at
+    /// the given offset.
+    ExpectedPeriod {
+        /// The offset at which `P` was expected.
+        offset: usize,
+    },
+    /// An integer was expected at the given offset.
+    ExpectedNumber {
+        /// The offset at which an integer was expected.
+        offset: usize,
+    },
+    /// A designator was expected at the given offset, but the input ended.
+    ExpectedDesignator {
+        /// The offset at which a designator was expected.
+        offset: usize,
+    },
+    /// The byte at the given offset isn't a designator that is allowed at
+    /// that position.
+    ///
+    /// This occurs for unknown designators, for time designators before the
+    /// `T` separator (or date designators after it) and for designators that
+    /// are repeated or out of order.
+    UnexpectedDesignator {
+        /// The offset of the unexpected designator.
+        offset: usize,
+    },
+    /// A fraction was found on a unit other than seconds, or it had no
+    /// digits or more than 9 digits.
+    InvalidFraction {
+        /// The offset at which the problem was detected.
+        offset: usize,
+    },
+    /// An integer was found but it doesn't fit into an `i64`.
+    Overflow {
+        /// The offset at which the integer begins.
+        start: usize,
+        /// The offset at which the integer ends.
+        end: usize,
+    },
+}
+
+impl std::error::Error for ParseError {}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            ParseError::ExpectedPeriod { offset } => write!(
+                f,
+                "expected 'P' at offset {offset} to begin an ISO 8601 \
+                 duration",
+            ),
+            ParseError::ExpectedNumber { offset } => {
+                write!(f, "expected an integer at offset {offset}")
+            }
+            ParseError::ExpectedDesignator { offset } => write!(
+                f,
+                "expected a designator at offset {offset}, but found end \
+                 of input",
+            ),
+            ParseError::UnexpectedDesignator { offset } => write!(
+                f,
+                "unexpected designator at offset {offset} (designators must \
+                 be one of 'Y', 'M', 'W' or 'D' before 'T' and one of 'H', \
+                 'M' or 'S' after it, in that order, with no repeats)",
+            ),
+            ParseError::InvalidFraction { offset } => write!(
+                f,
+                "invalid fraction at offset {offset} (only seconds may have \
+                 a fraction, and it must have between 1 and 9 digits)",
+            ),
+            ParseError::Overflow { start, end } => write!(
+                f,
+                "integer at offsets {start}..{end} is too big to fit in a \
+                 64-bit signed integer",
+            ),
+        }
+    }
+}
+
+/// An error that occurs when a list of components can't be printed as an
+/// ISO 8601 duration.
+///
+/// Every index refers to a position in the list of components given to
+/// [`print`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PrintError {
+    /// There were no components.
+    Empty,
+    /// The unit at the given index is not smaller than the unit before it.
+    OutOfOrder {
+        /// The index of the out of order component.
+        index: usize,
+    },
+    /// The value at the given index has a different sign than a value that
+    /// came before it.
+    MixedSign {
+        /// The index of the offending component.
+        index: usize,
+    },
+    /// The sub-second component at the given index is zero, is `1000` or
+    /// more in magnitude, or follows a zero seconds component.
+    SubSecond {
+        /// The index of the offending component.
+        index: usize,
+    },
+}
+
+impl std::error::Error for PrintError {}
+
+impl std::fmt::Display for PrintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            PrintError::Empty => {
+                write!(f, "an ISO 8601 duration requires at least one unit")
+            }
+            PrintError::OutOfOrder { index } => write!(
+                f,
+                "unit at index {index} must be smaller than the one before \
+                 it in an ISO 8601 duration",
+            ),
+            PrintError::MixedSign { index } => write!(
+                f,
+                "value at index {index} has a different sign than a previous \
+                 value, but an ISO 8601 duration has one sign for all units",
+            ),
+            PrintError::SubSecond { index } => write!(
+                f,
+                "sub-second value at index {index} can't be represented \
+                 exactly as a fraction of a second in an ISO 8601 duration",
+            ),
+        }
+    }
+}
+
+/// Parses one or more ASCII digits beginning at `at`.
+fn parse_digits(input: &[u8], at: usize) -> Result<(u64, usize), ParseError> {
+    let start = at;
+    let mut at = at;
+    let mut overflow = false;
+    let mut number: u64 = 0;
+    while let Some(&byte) = input.get(at) {
+        if !byte.is_ascii_digit() {
+            break;
+        }
+        let digit = u64::from(byte - b'0');
+        match number.checked_mul(10).and_then(|n| n.checked_add(digit)) {
+            Some(n) => number = n,
+            None => overflow = true,
+        }
+        at += 1;
+    }
+    if at == start {
+        return Err(ParseError::ExpectedNumber { offset: start });
+    }
+    if overflow {
+        return Err(ParseError::Overflow { start, end: at });
+    }
+    Ok((number, at))
+}
+
+/// Parses the digits of a fraction beginning at `at`, i.e., just after the
+/// decimal separator, and returns the fraction as a number of nanoseconds.
+fn parse_fraction(
+    input: &[u8],
+    at: usize,
+) -> Result<(u32, usize), ParseError> {
+    let start = at;
+    let mut at = at;
+    let mut nanos: u32 = 0;
+    while let Some(&byte) = input.get(at) {
+        if !byte.is_ascii_digit() {
+            break;
+        }
+        if at - start == 9 {
+            return Err(ParseError::InvalidFraction { offset: at });
+        }
+        nanos = nanos * 10 + u32::from(byte - b'0');
+        at += 1;
+    }
+    if at == start {
+        return Err(ParseError::InvalidFraction { offset: at });
+    }
+    for _ in (at - start)..9 {
+        nanos *= 10;
+    }
+    Ok((nanos, at))
+}
+
+/// Applies a sign to a magnitude, returning `None` on overflow.
+fn signed(magnitude: u64, negative: bool) -> Option<i64> {
+    if negative {
+        0i64.checked_sub_unsigned(magnitude)
+    } else {
+        i64::try_from(magnitude).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        one_big_match::OneBigMatch, parse::Parser, rng::Rng, Unit::*,
+    };
+
+    use super::*;
+
+    #[test]
+    fn parse_basic() {
+        assert_eq!(
+            parse(b"P2Y1M15DT5H59M1S"),
+            Ok(vec![
+                (2, Year),
+                (1, Month),
+                (15, Day),
+                (5, Hour),
+                (59, Minute),
+                (1, Second),
+            ]),
+        );
+        assert_eq!(parse(b"P1M"), Ok(vec![(1, Month)]));
+        assert_eq!(parse(b"PT1M"), Ok(vec![(1, Minute)]));
+        assert_eq!(parse(b"P1MT1M"), Ok(vec![(1, Month), (1, Minute)]));
+        assert_eq!(parse(b"P1W2D"), Ok(vec![(1, Week), (2, Day)]));
+        assert_eq!(parse(b"PT0S"), Ok(vec![(0, Second)]));
+        assert_eq!(parse(b"P0D"), Ok(vec![(0, Day)]));
+        assert_eq!(parse(b"-P1DT2H"), Ok(vec![(-1, Day), (-2, Hour)]));
+        assert_eq!(parse(b"+P1D"), Ok(vec![(1, Day)]));
+        assert_eq!(
+            parse(b"-PT9223372036854775808S"),
+            Ok(vec![(i64::MIN, Second)]),
+        );
+    }
+
+    #[test]
+    fn parse_fractions() {
+        assert_eq!(
+            parse(b"PT1.5S"),
+            Ok(vec![(1, Second), (500, Millisecond)]),
+        );
+        assert_eq!(
+            parse(b"PT1,123456789S"),
+            Ok(vec![
+                (1, Second),
+                (123, Millisecond),
+                (456, Microsecond),
+                (789, Nanosecond),
+            ]),
+        );
+        assert_eq!(parse(b"PT0.000002S"), Ok(vec![(2, Microsecond)]));
+        assert_eq!(parse(b"PT0.000S"), Ok(vec![(0, Second)]));
+        assert_eq!(parse(b"PT3.000S"), Ok(vec![(3, Second)]));
+        assert_eq!(
+            parse(b"-PT1.000001S"),
+            Ok(vec![(-1, Second), (-1, Microsecond)]),
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        use ParseError::*;
+
+        assert_eq!(parse(b""), Err(ExpectedPeriod { offset: 0 }));
+        assert_eq!(parse(b"1D"), Err(ExpectedPeriod { offset: 0 }));
+        assert_eq!(parse(b"-1D"), Err(ExpectedPeriod { offset: 1 }));
+        assert_eq!(parse(b"P"), Err(ExpectedNumber { offset: 1 }));
+        assert_eq!(parse(b"PT"), Err(ExpectedNumber { offset: 2 }));
+        assert_eq!(parse(b"P1DT"), Err(ExpectedNumber { offset: 4 }));
+        assert_eq!(parse(b"PD"), Err(ExpectedNumber { offset: 1 }));
+        assert_eq!(parse(b"P1"), Err(ExpectedDesignator { offset: 2 }));
+        assert_eq!(parse(b"P1H"), Err(UnexpectedDesignator { offset: 2 }));
+        assert_eq!(parse(b"PT1D"), Err(UnexpectedDesignator { offset: 3 }));
+        assert_eq!(parse(b"P1D1Y"), Err(UnexpectedDesignator { offset: 4 }));
+        assert_eq!(parse(b"P1D1D"), Err(UnexpectedDesignator { offset: 4 }));
+        assert_eq!(parse(b"PT1HT1M"), Err(ExpectedNumber { offset: 4 }));
+        assert_eq!(parse(b"P1d"), Err(UnexpectedDesignator { offset: 2 }));
+        assert_eq!(parse(b"PT1.5M"), Err(InvalidFraction { offset: 3 }));
+        assert_eq!(parse(b"PT1.S"), Err(InvalidFraction { offset: 4 }));
+        assert_eq!(
+            parse(b"PT1.1234567891S"),
+            Err(InvalidFraction { offset: 13 })
+        );
+        assert_eq!(
+            parse(b"PT9223372036854775808S"),
+            Err(Overflow { start: 2, end: 21 }),
+        );
+        assert_eq!(
+            parse(b"PT99999999999999999999S"),
+            Err(Overflow { start: 2, end: 22 }),
+        );
+    }
+
+    #[test]
+    fn print_basic() {
+        let print = |components: &[(i64, Unit)]| print(components).unwrap();
+        assert_eq!(
+            print(&[
+                (2, Year),
+                (1, Month),
+                (15, Day),
+                (5, Hour),
+                (59, Minute),
+                (1, Second),
+            ]),
+            "P2Y1M15DT5H59M1S",
+        );
+        assert_eq!(print(&[(1, Month)]), "P1M");
+        assert_eq!(print(&[(1, Minute)]), "PT1M");
+        assert_eq!(print(&[(0, Second)]), "PT0S");
+        assert_eq!(print(&[(-1, Day), (0, Hour)]), "-P1DT0H");
+        assert_eq!(print(&[(5, Millisecond)]), "PT0.005S");
+        assert_eq!(print(&[(1, Second), (5, Microsecond)]), "PT1.000005S",);
+        assert_eq!(
+            print(&[(-1, Minute), (-999, Millisecond), (-1, Nanosecond)]),
+            "-PT1M0.999000001S",
+        );
+        assert_eq!(print(&[(i64::MIN, Week)]), "-P9223372036854775808W",);
+    }
+
+    #[test]
+    fn print_errors() {
+        use PrintError::*;
+
+        assert_eq!(print(&[]), Err(Empty));
+        assert_eq!(
+            print(&[(1, Hour), (1, Day)]),
+            Err(OutOfOrder { index: 1 })
+        );
+        assert_eq!(
+            print(&[(1, Hour), (1, Hour)]),
+            Err(OutOfOrder { index: 1 })
+        );
+        assert_eq!(
+            print(&[(1, Day), (-1, Hour)]),
+            Err(MixedSign { index: 1 })
+        );
+        assert_eq!(print(&[(0, Millisecond)]), Err(SubSecond { index: 0 }));
+        assert_eq!(print(&[(1000, Nanosecond)]), Err(SubSecond { index: 0 }));
+        assert_eq!(
+            print(&[(0, Second), (1, Nanosecond)]),
+            Err(SubSecond { index: 1 }),
+        );
+    }
+
+    /// Tests that printing then parsing is lossless for many random lists of
+    /// components, and that parsing a printed duration then printing it again
+    /// gives back the same string.
+    #[test]
+    fn roundtrip() {
+        let mut rng = Rng::new(0x2545F4914F6CDD1D);
+        let units = [
+            Year,
+            Month,
+            Week,
+            Day,
+            Hour,
+            Minute,
+            Second,
+            Millisecond,
+            Microsecond,
+            Nanosecond,
+        ];
+        let mut printed = 0;
+        for _ in 0..100_000 {
+            let negative = rng.below(2) == 0;
+            let mut components = vec![];
+            for &unit in units.iter() {
+                if rng.below(3) != 0 {
+                    continue;
+                }
+                let magnitude = match rng.below(4) {
+                    0 => 0,
+                    1 => rng.next_u64() % 1_000,
+                    2 => rng.next_u64() % 100_000,
+                    _ => rng.next_u64() >> 1,
+                };
+                let value = magnitude as i64;
+                components.push((if negative { -value } else { value }, unit));
+            }
+            let Ok(iso) = print(&components) else { continue };
+            printed += 1;
+            assert_eq!(
+                parse(iso.as_bytes()).as_ref(),
+                Ok(&components),
+                "{iso}",
+            );
+            assert_eq!(print(&parse(iso.as_bytes()).unwrap()), Ok(iso));
+        }
+        // Make sure we aren't just rejecting everything.
+        assert!(printed > 10_000, "{printed}");
+    }
+
+    #[test]
+    fn friendly() {
+        let friendly = Parser::new(OneBigMatch)
+            .parse(b"2 years 1 month 15 days 5 hours 59 minutes 1 second")
+            .unwrap();
+        let iso = print(&friendly).unwrap();
+        assert_eq!(iso, "P2Y1M15DT5H59M1S");
+        assert_eq!(parse(iso.as_bytes()).unwrap(), friendly);
+
+        let friendly =
+            Parser::new(OneBigMatch).parse(b"1h 2s 3ms 4us 5ns").unwrap();
+        let iso = print(&friendly).unwrap();
+        assert_eq!(iso, "PT1H2.003004005S");
+        assert_eq!(parse(iso.as_bytes()).unwrap(), friendly);
+
+        // Friendly durations may have units in any order, but ISO 8601
+        // durations can't.
+        let friendly = Parser::new(OneBigMatch).parse(b"1m 1h").unwrap();
+        assert_eq!(print(&friendly), Err(PrintError::OutOfOrder { index: 1 }));
+    }
+
+    #[test]
+    fn unit_order() {
+        // The ordering of units is relied upon above. This is just a sanity
+        // check that the derived `Ord` uses the explicit discriminants.
+        assert!(Year > Month);
+        assert!(Second > Millisecond);
+    }
+}
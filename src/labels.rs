@@ -0,0 +1,73 @@
+use crate::Unit;
+
+/// Invokes the macro given with every unit designator label and the name of
+/// the `Unit` variant it maps to. See `labelset::designator_labels!`, which
+/// is where the labels are defined so that the generators can use them
+/// without depending on this crate.
+pub(crate) use labelset::designator_labels;
+
+/// Every unit designator label recognized by this crate, along with the unit
+/// it maps to.
+///
+/// There are no duplicate labels, but many labels map to the same unit.
+pub static LABELS: &[(&str, Unit)] = {
+    macro_rules! labels {
+        ($($label:literal => $unit:ident,)*) => {
+            &[$((utf8($label), Unit::$unit),)*]
+        };
+    }
+    designator_labels!(labels)
+};
+
+/// The length, in bytes, of the longest label in [`LABELS`].
+pub(crate) const MAX_LABEL_LEN: usize = {
+    let mut max = 0;
+    let mut i = 0;
+    while i < LABELS.len() {
+        if LABELS[i].0.len() > max {
+            max = LABELS[i].0.len();
+        }
+        i += 1;
+    }
+    max
+};
+
+/// Converts a label written as a byte string literal to a `&str`.
+///
+/// This panics at compile time if the label isn't valid UTF-8.
+const fn utf8(label: &'static [u8]) -> &'static str {
+    match core::str::from_utf8(label) {
+        Ok(label) => label,
+        Err(_) => panic!("unit designator labels must be valid UTF-8"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_duplicates() {
+        let mut seen = std::collections::HashSet::new();
+        for &(label, _) in LABELS {
+            assert!(seen.insert(label), "duplicate label {label:?}");
+        }
+        assert_eq!(LABELS.len(), 56);
+        assert_eq!(MAX_LABEL_LEN, "milliseconds".len());
+    }
+
+    /// The C code generated for the labels defines its own enum with the
+    /// discriminants given by `labelset`, so they have to match `Unit`.
+    #[test]
+    fn discriminants() {
+        let mut units: Vec<Unit> = LABELS.iter().map(|&(_, u)| u).collect();
+        units.dedup();
+        let got: Vec<(String, i64)> =
+            units.iter().map(|&u| (format!("{u:?}"), u as i64)).collect();
+        let expected: Vec<(String, i64)> = labelset::DESIGNATOR_UNITS
+            .iter()
+            .map(|&(name, d)| (name.to_string(), d))
+            .collect();
+        assert_eq!(got, expected);
+    }
+}
@@ -1,23 +1,117 @@
+#![cfg_attr(
+    feature = "nightly",
+    feature(explicit_tail_calls),
+    allow(incomplete_features)
+)]
+
 pub mod aho;
 pub mod by_gencdfa1;
+pub mod by_gencdfa2;
 pub mod by_gendfa1;
+pub mod by_gendfa2;
+pub mod by_gendfa3;
+pub mod by_gendfa4;
+pub mod by_gendfa5;
+pub mod by_gendfa6;
+// The tries and the techniques built on them are written in a style that
+// clippy complains about in a few places. These are the only lints they
+// trip, so they're allowed here instead of on each item.
+#[allow(clippy::redundant_static_lifetimes)]
 pub mod by_trie1;
+#[allow(clippy::redundant_static_lifetimes)]
 pub mod by_trie2;
+#[allow(clippy::redundant_static_lifetimes)]
 pub mod by_trie3;
+#[allow(clippy::redundant_static_lifetimes)]
 pub mod by_trie4;
+#[allow(clippy::redundant_static_lifetimes)]
 pub mod by_trie5;
+mod dfa;
+#[doc(hidden)]
+pub mod differential;
+mod error;
+#[doc(hidden)]
+pub mod footprint;
 mod gendfa1;
+mod gendfa1_ci;
+mod gendfa2;
+mod gendfa2_ci;
+#[cfg(not(feature = "nightly"))]
+mod gendfa3;
+#[cfg(feature = "nightly")]
+#[path = "gendfa3_tail.rs"]
+mod gendfa3;
+#[cfg(not(feature = "nightly"))]
+mod gendfa3_ci;
+#[cfg(feature = "nightly")]
+#[path = "gendfa3_tail_ci.rs"]
+mod gendfa3_ci;
+mod gendfa4;
+mod gendfa4_ci;
+mod gendfa5;
+mod gendfa5_ci;
+mod gendfa6;
+mod gendfa6_ci;
 pub mod hashify;
+pub mod iso8601;
+mod labels;
 pub mod one_big_match;
 pub mod one_big_match_prefix;
+mod one_big_match_prefix_ci_gen;
 mod one_big_match_prefix_gen;
+pub mod parse;
 pub mod phf;
+pub mod print;
+#[doc(hidden)]
+pub mod rng;
+#[cfg(feature = "scaling")]
+pub mod scaling;
+mod suggest;
+#[cfg(feature = "scaling")]
+pub mod synthetic;
+// See the comment on the `by_trie*` modules.
+#[allow(
+    clippy::doc_lazy_continuation,
+    clippy::redundant_pattern_matching,
+    clippy::redundant_static_lifetimes,
+    clippy::unnecessary_cast
+)]
 mod trie1;
+#[allow(
+    clippy::doc_lazy_continuation,
+    clippy::redundant_pattern_matching,
+    clippy::redundant_static_lifetimes,
+    clippy::unnecessary_cast
+)]
 mod trie2;
+#[allow(
+    clippy::doc_lazy_continuation,
+    clippy::redundant_pattern_matching,
+    clippy::redundant_static_lifetimes,
+    clippy::unnecessary_cast
+)]
 mod trie3;
+#[allow(
+    clippy::doc_lazy_continuation,
+    clippy::redundant_pattern_matching,
+    clippy::redundant_static_lifetimes,
+    clippy::unnecessary_cast
+)]
 mod trie4;
+#[allow(
+    clippy::doc_lazy_continuation,
+    clippy::redundant_pattern_matching,
+    clippy::redundant_static_lifetimes,
+    clippy::unnecessary_cast
+)]
 mod trie5;
 
+pub use crate::{
+    error::LookupError,
+    labels::LABELS,
+    suggest::{Suggestion, Suggestions},
+};
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[repr(C)]
 pub enum Unit {
@@ -32,3 +126,430 @@ pub enum Unit {
     Microsecond = 1,
     Nanosecond = 0,
 }
+
+/// A technique for recognizing a unit designator label at the beginning of
+/// some input.
+///
+/// Every technique in this crate implements this trait, which makes it
+/// possible to write code (like a duration parser) that is generic over the
+/// recognizer used. See [`STRATEGIES`] for a list of all of them.
+///
+/// Matching is always anchored. That is, a label is only ever recognized at
+/// the very beginning of the input given.
+///
+/// Techniques differ in where they think a label ends when it's immediately
+/// followed by another byte that may appear in a label. Those that scan for
+/// the full label first (`one-big-match`, `phf`, `hashify` and `by-trie5`)
+/// reject `daysx`, since `daysx` isn't a label. The others match on label
+/// prefixes, so they recognize `days` and leave `x` as the remaining input.
+/// (The generated DFAs still reject `dax` though, while the rest recognize
+/// `d` in it.) Whenever a label is followed by something else, every
+/// technique agrees. (See the `differential` module.)
+///
+/// Matching is case sensitive by default. Every technique also provides an
+/// opt-in ASCII case insensitive mode via `lookup_ignore_ascii_case` and
+/// `find_ignore_ascii_case`, such that, e.g., `Hours` and `MS` are
+/// recognized. Non-ASCII bytes (i.e., those in `µ`) are always matched
+/// exactly.
+pub trait UnitLookup: Sync {
+    /// Returns the name of this technique.
+    ///
+    /// This is the same name used to identify the technique in benchmarks,
+    /// e.g., `one-big-match` or `by-trie5`.
+    fn name(&self) -> &'static str;
+
+    /// Recognizes a unit designator label at the beginning of `input`.
+    ///
+    /// On success, this returns the unit corresponding to the label found
+    /// along with the remaining input following the label.
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>>;
+
+    /// Like `lookup`, but returns the length of the label found (in bytes)
+    /// instead of the remaining input. If no label could be found, then this
+    /// returns `None`.
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)>;
+
+    /// Like `lookup`, but ASCII letters are matched case insensitively.
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>>;
+
+    /// Like `find`, but ASCII letters are matched case insensitively.
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)>;
+}
+
+impl<T: UnitLookup + ?Sized> UnitLookup for &T {
+    fn name(&self) -> &'static str {
+        (**self).name()
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        (**self).lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        (**self).find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        (**self).lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        (**self).find_ignore_ascii_case(input)
+    }
+}
+
+/// Invokes the macro given with the path to every technique's `UnitLookup`
+/// implementation.
+///
+/// This is the one and only place where the set of techniques is written
+/// down. [`STRATEGIES`] is built from it, and so are the benchmarks. The
+/// benchmarks can't just iterate over [`STRATEGIES`], since calling through
+/// a `&dyn UnitLookup` would add an indirect call to every measurement. So
+/// they pass the name of a local macro instead, which expands to one
+/// monomorphized call per technique.
+///
+/// The input given to the callback macro is a comma separated sequence of
+/// paths, in the same order as the benchmarks.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! lookup_strategies {
+    ($callback:ident) => {
+        $callback! {
+            $crate::one_big_match::OneBigMatch,
+            $crate::one_big_match_prefix::OneBigMatchPrefix,
+            $crate::aho::AhoCorasick,
+            $crate::phf::Phf,
+            $crate::hashify::Hashify,
+            $crate::by_trie1::ByTrie1,
+            $crate::by_trie2::ByTrie2,
+            $crate::by_trie3::ByTrie3,
+            $crate::by_trie4::ByTrie4,
+            $crate::by_trie5::ByTrie5,
+            $crate::by_gendfa1::ByGenDfa1,
+            $crate::by_gendfa2::ByGenDfa2,
+            $crate::by_gendfa3::ByGenDfa3,
+            $crate::by_gendfa4::ByGenDfa4,
+            $crate::by_gendfa5::ByGenDfa5,
+            $crate::by_gendfa6::ByGenDfa6,
+            $crate::by_gencdfa1::ByGenCDfa1,
+            $crate::by_gencdfa2::ByGenCDfa2,
+        }
+    };
+}
+
+/// Every unit designator lookup technique in this crate.
+///
+/// This is in the same order as the benchmarks.
+pub static STRATEGIES: &[&dyn UnitLookup] = {
+    macro_rules! strategies {
+        ($($strategy:path),* $(,)?) => {
+            &[$(&$strategy),*]
+        };
+    }
+    lookup_strategies!(strategies)
+};
+
+/// Like [`lookup_strategies!`], but invokes the macro given with the path to
+/// the inlined variant of every technique.
+///
+/// Every `lookup` and `find` routine in this crate is `#[inline(never)]`, so
+/// that each technique is measured in isolation. But a real duration parser
+/// would want its label recognizer inlined into its parse loop. So every
+/// technique also has a variant (e.g., `OneBigMatchInline`) whose routines
+/// are all `#[inline(always)]`. The only exception to the inlining is
+/// whatever a technique calls into, e.g., `by-gencdfa1` still has to call
+/// into C.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! inline_lookup_strategies {
+    ($callback:ident) => {
+        $callback! {
+            $crate::one_big_match::OneBigMatchInline,
+            $crate::one_big_match_prefix::OneBigMatchPrefixInline,
+            $crate::aho::AhoCorasickInline,
+            $crate::phf::PhfInline,
+            $crate::hashify::HashifyInline,
+            $crate::by_trie1::ByTrie1Inline,
+            $crate::by_trie2::ByTrie2Inline,
+            $crate::by_trie3::ByTrie3Inline,
+            $crate::by_trie4::ByTrie4Inline,
+            $crate::by_trie5::ByTrie5Inline,
+            $crate::by_gendfa1::ByGenDfa1Inline,
+            $crate::by_gendfa2::ByGenDfa2Inline,
+            $crate::by_gendfa3::ByGenDfa3Inline,
+            $crate::by_gendfa4::ByGenDfa4Inline,
+            $crate::by_gendfa5::ByGenDfa5Inline,
+            $crate::by_gendfa6::ByGenDfa6Inline,
+            $crate::by_gencdfa1::ByGenCDfa1Inline,
+            $crate::by_gencdfa2::ByGenCDfa2Inline,
+        }
+    };
+}
+
+/// Defines the inlined variant of a technique, i.e., one of the types listed
+/// by [`inline_lookup_strategies!`], given its name, the technique's type and
+/// the technique's name with `-inline` appended.
+///
+/// This also defines the `#[inline(never)]` routines `lookup`, `find`,
+/// `lookup_ignore_ascii_case` and `find_ignore_ascii_case` that the
+/// technique's own `UnitLookup` impl calls. Every one of these routines,
+/// inlined or not, just calls the routine with the same name and an `_inline`
+/// suffix, which the module invoking this macro has to define.
+macro_rules! inline_lookup_strategy {
+    ($name:ident, $strategy:ident, $label:literal) => {
+        #[doc = concat!("Like [`", stringify!($strategy), "`], but inlined.")]
+        ///
+        /// Every routine is inlined into its caller. See
+        /// [`INLINE_STRATEGIES`](crate::INLINE_STRATEGIES).
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name;
+
+        impl $crate::UnitLookup for $name {
+            fn name(&self) -> &'static str {
+                $label
+            }
+
+            #[inline(always)]
+            fn lookup<'i>(
+                &self,
+                input: &'i [u8],
+            ) -> Result<($crate::Unit, &'i [u8]), $crate::LookupError<'i>>
+            {
+                lookup_inline(input)
+            }
+
+            #[inline(always)]
+            fn find(&self, input: &[u8]) -> Option<($crate::Unit, usize)> {
+                find_inline(input)
+            }
+
+            #[inline(always)]
+            fn lookup_ignore_ascii_case<'i>(
+                &self,
+                input: &'i [u8],
+            ) -> Result<($crate::Unit, &'i [u8]), $crate::LookupError<'i>>
+            {
+                lookup_ignore_ascii_case_inline(input)
+            }
+
+            #[inline(always)]
+            fn find_ignore_ascii_case(
+                &self,
+                input: &[u8],
+            ) -> Option<($crate::Unit, usize)> {
+                find_ignore_ascii_case_inline(input)
+            }
+        }
+
+        #[inline(never)]
+        pub fn lookup<'i>(
+            input: &'i [u8],
+        ) -> Result<($crate::Unit, &'i [u8]), $crate::LookupError<'i>> {
+            lookup_inline(input)
+        }
+
+        #[inline(never)]
+        pub fn find(input: &[u8]) -> Option<($crate::Unit, usize)> {
+            find_inline(input)
+        }
+
+        #[inline(never)]
+        pub fn lookup_ignore_ascii_case<'i>(
+            input: &'i [u8],
+        ) -> Result<($crate::Unit, &'i [u8]), $crate::LookupError<'i>> {
+            lookup_ignore_ascii_case_inline(input)
+        }
+
+        #[inline(never)]
+        pub fn find_ignore_ascii_case(
+            input: &[u8],
+        ) -> Option<($crate::Unit, usize)> {
+            find_ignore_ascii_case_inline(input)
+        }
+    };
+}
+pub(crate) use inline_lookup_strategy;
+
+/// The inlined variant of every technique in [`STRATEGIES`], in the same
+/// order.
+///
+/// Calling through a `&dyn UnitLookup` defeats inlining of course. These
+/// are only useful via generic code, like [`parse::Parser`]. They are listed
+/// here so that they can be tested like every other technique.
+pub static INLINE_STRATEGIES: &[&dyn UnitLookup] = {
+    macro_rules! strategies {
+        ($($strategy:path),* $(,)?) => {
+            &[$(&$strategy),*]
+        };
+    }
+    inline_lookup_strategies!(strategies)
+};
+
+/// Returns true if the byte given may appear in a unit designator label.
+#[inline(always)]
+fn is_designator_byte(b: u8) -> bool {
+    // The \xC2 and \xB5 is for `µ`.
+    b.is_ascii_lowercase() || b == 0xC2 || b == 0xB5
+}
+
+/// Like `is_designator_byte`, but also permits uppercase ASCII letters.
+#[inline(always)]
+fn is_designator_byte_ignore_ascii_case(b: u8) -> bool {
+    is_designator_byte(b.to_ascii_lowercase())
+}
+
+/// Returns the length of the longest prefix of `input` consisting only of
+/// bytes that may appear in a unit designator label.
+///
+/// This is used by the techniques that first scan for the full label and only
+/// then look it up.
+#[inline(always)]
+fn designator_len(input: &[u8]) -> usize {
+    input.iter().position(|&b| !is_designator_byte(b)).unwrap_or(input.len())
+}
+
+/// Like `designator_len`, but also permits uppercase ASCII letters.
+#[inline(always)]
+fn designator_len_ignore_ascii_case(input: &[u8]) -> usize {
+    input
+        .iter()
+        .position(|&b| !is_designator_byte_ignore_ascii_case(b))
+        .unwrap_or(input.len())
+}
+
+/// Writes the ASCII lowercase form of `label` into `buf` and returns it.
+///
+/// This is used by the techniques that scan for the full label and then need
+/// to look it up case insensitively. Since all known labels are lowercase,
+/// lowercasing the candidate is all that's needed. If `label` is longer than
+/// every known label, then it can't possibly match and `None` is returned.
+#[inline(always)]
+fn ascii_lowercase<'b>(
+    label: &[u8],
+    buf: &'b mut [u8; labels::MAX_LABEL_LEN],
+) -> Option<&'b [u8]> {
+    let buf = buf.get_mut(..label.len())?;
+    for (dst, &src) in buf.iter_mut().zip(label) {
+        *dst = src.to_ascii_lowercase();
+    }
+    Some(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strategies() {
+        let mut names = std::collections::HashSet::new();
+        for strategy in STRATEGIES.iter().chain(INLINE_STRATEGIES) {
+            let name = strategy.name();
+            assert!(names.insert(name), "duplicate strategy name {name}");
+
+            let (unit, remaining) = strategy.lookup(b"months 5 days").unwrap();
+            assert_eq!(unit, Unit::Month, "{name}");
+            assert_eq!(remaining, b" 5 days", "{name}");
+            assert_eq!(
+                strategy.find(b"milliseconds 5 nanoseconds"),
+                Some((Unit::Millisecond, 12)),
+                "{name}",
+            );
+            assert!(strategy.lookup(b"").is_err(), "{name}");
+            assert_eq!(strategy.find(b" 5 days"), None, "{name}");
+        }
+    }
+
+    /// Every label followed by exactly one byte that can't continue it.
+    /// The DFA backends only see a match one byte after it ends, so for
+    /// these inputs they enter the state reporting it on the last byte.
+    #[test]
+    fn match_before_last_byte() {
+        for strategy in STRATEGIES.iter().chain(INLINE_STRATEGIES) {
+            let name = strategy.name();
+            for &(label, unit) in LABELS {
+                for end in [" ", "5"] {
+                    let input = format!("{label}{end}");
+                    assert_eq!(
+                        strategy.find(input.as_bytes()),
+                        Some((unit, label.len())),
+                        "{name}: {input:?}",
+                    );
+                    let input = input.to_ascii_uppercase();
+                    assert_eq!(
+                        strategy.find_ignore_ascii_case(input.as_bytes()),
+                        Some((unit, label.len())),
+                        "{name}: {input:?}",
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ignore_ascii_case() {
+        for strategy in STRATEGIES.iter().chain(INLINE_STRATEGIES) {
+            let name = strategy.name();
+            for &(label, unit) in LABELS {
+                let mut input = label.to_ascii_uppercase();
+                input.push_str(" 5 days");
+                assert_eq!(
+                    strategy.lookup_ignore_ascii_case(input.as_bytes()),
+                    Ok((unit, &b" 5 days"[..])),
+                    "{name}: {input:?}",
+                );
+                assert_eq!(
+                    strategy.find_ignore_ascii_case(label.as_bytes()),
+                    Some((unit, label.len())),
+                    "{name}: {label:?}",
+                );
+            }
+
+            assert_eq!(
+                strategy.find_ignore_ascii_case(b"MilliSeconds 5 ns"),
+                Some((Unit::Millisecond, 12)),
+                "{name}",
+            );
+            assert_eq!(
+                strategy.find_ignore_ascii_case(b"\xC2\xB5S"),
+                Some((Unit::Microsecond, 3)),
+                "{name}",
+            );
+            // The default mode is still case sensitive.
+            assert!(strategy.lookup(b"Hours").is_err(), "{name}");
+            assert_eq!(strategy.find(b"MS"), None, "{name}");
+            // Only ASCII case folding is done. With Unicode case folding,
+            // `ſ` would be equivalent to `s`.
+            assert_eq!(
+                strategy.find_ignore_ascii_case("\u{17F}ec".as_bytes()),
+                None,
+                "{name}",
+            );
+            assert_eq!(
+                strategy.lookup_ignore_ascii_case(b"Zebras 5 days"),
+                Err(LookupError::UnknownDesignator {
+                    start: 0,
+                    end: 6,
+                    found: b"Zebras",
+                }),
+                "{name}",
+            );
+            assert_eq!(
+                strategy.lookup_ignore_ascii_case(b""),
+                Err(LookupError::EndOfInput),
+                "{name}",
+            );
+        }
+    }
+}
@@ -1,87 +1,102 @@
-use crate::Unit;
+use crate::{
+    ascii_lowercase, designator_len, designator_len_ignore_ascii_case,
+    inline_lookup_strategy,
+    labels::{designator_labels, MAX_LABEL_LEN},
+    LookupError, Unit, UnitLookup,
+};
 
-#[inline(never)]
-pub fn lookup<'i>(input: &'i [u8]) -> Result<(Unit, &'i [u8]), anyhow::Error> {
-    let is_valid_designator_byte =
-            // The \xC2 and \xB5 is for `µ`.
-            |b: u8| b'a' <= b && b <= b'z' || b == 0xC2 || b == 0xB5;
-    let end = input
-        .iter()
-        .position(|&b| !is_valid_designator_byte(b))
-        .unwrap_or(input.len());
-    let needle = &input[..end];
-    if needle.is_empty() {
-        anyhow::bail!(
-            "expected to find unit designator suffix \
-             (e.g., 'years' or 'secs'), \
-             but found end of input",
-        );
+/// The `one-big-match` technique.
+#[derive(Clone, Copy, Debug)]
+pub struct OneBigMatch;
+
+impl UnitLookup for OneBigMatch {
+    fn name(&self) -> &'static str {
+        "one-big-match"
+    }
+
+    fn lookup<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup(input)
+    }
+
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find(input)
+    }
+
+    fn lookup_ignore_ascii_case<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        lookup_ignore_ascii_case(input)
+    }
+
+    fn find_ignore_ascii_case(&self, input: &[u8]) -> Option<(Unit, usize)> {
+        find_ignore_ascii_case(input)
     }
-    let unit = match needle {
-        b"years" => Unit::Year,
-        b"year" => Unit::Year,
-        b"yrs" => Unit::Year,
-        b"yr" => Unit::Year,
-        b"y" => Unit::Year,
-        b"months" => Unit::Month,
-        b"month" => Unit::Month,
-        b"mos" => Unit::Month,
-        b"mo" => Unit::Month,
-        b"weeks" => Unit::Week,
-        b"week" => Unit::Week,
-        b"wks" => Unit::Week,
-        b"wk" => Unit::Week,
-        b"w" => Unit::Week,
-        b"days" => Unit::Day,
-        b"day" => Unit::Day,
-        b"d" => Unit::Day,
-        b"hours" => Unit::Hour,
-        b"hour" => Unit::Hour,
-        b"hrs" => Unit::Hour,
-        b"hr" => Unit::Hour,
-        b"h" => Unit::Hour,
-        b"minutes" => Unit::Minute,
-        b"minute" => Unit::Minute,
-        b"mins" => Unit::Minute,
-        b"min" => Unit::Minute,
-        b"m" => Unit::Minute,
-        b"seconds" => Unit::Second,
-        b"second" => Unit::Second,
-        b"secs" => Unit::Second,
-        b"sec" => Unit::Second,
-        b"s" => Unit::Second,
-        b"milliseconds" => Unit::Millisecond,
-        b"millisecond" => Unit::Millisecond,
-        b"millis" => Unit::Millisecond,
-        b"milli" => Unit::Millisecond,
-        b"msecs" => Unit::Millisecond,
-        b"msec" => Unit::Millisecond,
-        b"ms" => Unit::Millisecond,
-        b"microseconds" => Unit::Microsecond,
-        b"microsecond" => Unit::Microsecond,
-        b"micros" => Unit::Microsecond,
-        b"micro" => Unit::Microsecond,
-        b"usecs" => Unit::Microsecond,
-        b"usec" => Unit::Microsecond,
-        b"\xC2\xB5secs" => Unit::Microsecond,
-        b"\xC2\xB5sec" => Unit::Microsecond,
-        b"us" => Unit::Microsecond,
-        b"\xC2\xB5s" => Unit::Microsecond,
-        b"nanoseconds" => Unit::Nanosecond,
-        b"nanosecond" => Unit::Nanosecond,
-        b"nanos" => Unit::Nanosecond,
-        b"nano" => Unit::Nanosecond,
-        b"nsecs" => Unit::Nanosecond,
-        b"nsec" => Unit::Nanosecond,
-        b"ns" => Unit::Nanosecond,
-        unk => {
-            anyhow::bail!(
-                "expected to find unit designator suffix \
-                 (e.g., 'years' or 'secs'), \
-                 but found input beginning with {found:?} instead",
-                found = std::str::from_utf8(unk).unwrap_or("N/A"),
-            );
-        }
+}
+
+inline_lookup_strategy!(
+    OneBigMatchInline,
+    OneBigMatch,
+    "one-big-match-inline"
+);
+
+/// Like [`lookup`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let end = designator_len(input);
+    let Some(unit) = designator(&input[..end]) else {
+        return Err(LookupError::new(input));
     };
     Ok((unit, &input[end..]))
 }
+
+/// Like [`find`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    let end = designator_len(input);
+    let unit = designator(&input[..end])?;
+    Some((unit, end))
+}
+
+/// Like [`lookup_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn lookup_ignore_ascii_case_inline<'i>(
+    input: &'i [u8],
+) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+    let end = designator_len_ignore_ascii_case(input);
+    let mut buf = [0; MAX_LABEL_LEN];
+    let Some(unit) =
+        ascii_lowercase(&input[..end], &mut buf).and_then(designator)
+    else {
+        return Err(LookupError::new_ignore_ascii_case(input));
+    };
+    Ok((unit, &input[end..]))
+}
+
+/// Like [`find_ignore_ascii_case`], but always inlined into its caller.
+#[inline(always)]
+pub fn find_ignore_ascii_case_inline(input: &[u8]) -> Option<(Unit, usize)> {
+    let end = designator_len_ignore_ascii_case(input);
+    let mut buf = [0; MAX_LABEL_LEN];
+    let unit = designator(ascii_lowercase(&input[..end], &mut buf)?)?;
+    Some((unit, end))
+}
+
+/// Maps a complete unit designator label to its unit.
+#[inline(always)]
+fn designator(needle: &[u8]) -> Option<Unit> {
+    macro_rules! one_big_match {
+        ($($label:literal => $unit:ident,)*) => {
+            match needle {
+                $($label => Some(Unit::$unit),)*
+                _ => None,
+            }
+        };
+    }
+    designator_labels!(one_big_match)
+}
@@ -0,0 +1,139 @@
+use crate::Unit;
+
+#[allow(clippy::match_ref_pats)]
+#[inline(always)]
+pub(super) fn find(haystack: &[u8]) -> Option<(Unit, usize)> {
+    match haystack {
+        &[b'm' | b'M', b'i' | b'I', b'l' | b'L', b'l' | b'L', b'i' | b'I', b's' | b'S', b'e' | b'E', b'c' | b'C', b'o' | b'O', b'n' | b'N', b'd' | b'D', b's' | b'S', ..] => {
+            Some((Unit::Millisecond, 12))
+        }
+        &[b'm' | b'M', b'i' | b'I', b'c' | b'C', b'r' | b'R', b'o' | b'O', b's' | b'S', b'e' | b'E', b'c' | b'C', b'o' | b'O', b'n' | b'N', b'd' | b'D', b's' | b'S', ..] => {
+            Some((Unit::Microsecond, 12))
+        }
+        &[b'n' | b'N', b'a' | b'A', b'n' | b'N', b'o' | b'O', b's' | b'S', b'e' | b'E', b'c' | b'C', b'o' | b'O', b'n' | b'N', b'd' | b'D', b's' | b'S', ..] => {
+            Some((Unit::Nanosecond, 11))
+        }
+        &[b'm' | b'M', b'i' | b'I', b'l' | b'L', b'l' | b'L', b'i' | b'I', b's' | b'S', b'e' | b'E', b'c' | b'C', b'o' | b'O', b'n' | b'N', b'd' | b'D', ..] => {
+            Some((Unit::Millisecond, 11))
+        }
+        &[b'm' | b'M', b'i' | b'I', b'c' | b'C', b'r' | b'R', b'o' | b'O', b's' | b'S', b'e' | b'E', b'c' | b'C', b'o' | b'O', b'n' | b'N', b'd' | b'D', ..] => {
+            Some((Unit::Microsecond, 11))
+        }
+        &[b'n' | b'N', b'a' | b'A', b'n' | b'N', b'o' | b'O', b's' | b'S', b'e' | b'E', b'c' | b'C', b'o' | b'O', b'n' | b'N', b'd' | b'D', ..] => {
+            Some((Unit::Nanosecond, 10))
+        }
+        &[b's' | b'S', b'e' | b'E', b'c' | b'C', b'o' | b'O', b'n' | b'N', b'd' | b'D', b's' | b'S', ..] => {
+            Some((Unit::Second, 7))
+        }
+        &[b'm' | b'M', b'i' | b'I', b'n' | b'N', b'u' | b'U', b't' | b'T', b'e' | b'E', b's' | b'S', ..] => {
+            Some((Unit::Minute, 7))
+        }
+        &[b'\xc2', b'\xb5', b's' | b'S', b'e' | b'E', b'c' | b'C', b's' | b'S', ..] => {
+            Some((Unit::Microsecond, 6))
+        }
+        &[b's' | b'S', b'e' | b'E', b'c' | b'C', b'o' | b'O', b'n' | b'N', b'd' | b'D', ..] => {
+            Some((Unit::Second, 6))
+        }
+        &[b'm' | b'M', b'o' | b'O', b'n' | b'N', b't' | b'T', b'h' | b'H', b's' | b'S', ..] => {
+            Some((Unit::Month, 6))
+        }
+        &[b'm' | b'M', b'i' | b'I', b'n' | b'N', b'u' | b'U', b't' | b'T', b'e' | b'E', ..] => {
+            Some((Unit::Minute, 6))
+        }
+        &[b'm' | b'M', b'i' | b'I', b'l' | b'L', b'l' | b'L', b'i' | b'I', b's' | b'S', ..] => {
+            Some((Unit::Millisecond, 6))
+        }
+        &[b'm' | b'M', b'i' | b'I', b'c' | b'C', b'r' | b'R', b'o' | b'O', b's' | b'S', ..] => {
+            Some((Unit::Microsecond, 6))
+        }
+        &[b'\xc2', b'\xb5', b's' | b'S', b'e' | b'E', b'c' | b'C', ..] => {
+            Some((Unit::Microsecond, 5))
+        }
+        &[b'y' | b'Y', b'e' | b'E', b'a' | b'A', b'r' | b'R', b's' | b'S', ..] => {
+            Some((Unit::Year, 5))
+        }
+        &[b'w' | b'W', b'e' | b'E', b'e' | b'E', b'k' | b'K', b's' | b'S', ..] => {
+            Some((Unit::Week, 5))
+        }
+        &[b'u' | b'U', b's' | b'S', b'e' | b'E', b'c' | b'C', b's' | b'S', ..] => {
+            Some((Unit::Microsecond, 5))
+        }
+        &[b'n' | b'N', b's' | b'S', b'e' | b'E', b'c' | b'C', b's' | b'S', ..] => {
+            Some((Unit::Nanosecond, 5))
+        }
+        &[b'n' | b'N', b'a' | b'A', b'n' | b'N', b'o' | b'O', b's' | b'S', ..] => {
+            Some((Unit::Nanosecond, 5))
+        }
+        &[b'm' | b'M', b's' | b'S', b'e' | b'E', b'c' | b'C', b's' | b'S', ..] => {
+            Some((Unit::Millisecond, 5))
+        }
+        &[b'm' | b'M', b'o' | b'O', b'n' | b'N', b't' | b'T', b'h' | b'H', ..] => {
+            Some((Unit::Month, 5))
+        }
+        &[b'm' | b'M', b'i' | b'I', b'l' | b'L', b'l' | b'L', b'i' | b'I', ..] => {
+            Some((Unit::Millisecond, 5))
+        }
+        &[b'm' | b'M', b'i' | b'I', b'c' | b'C', b'r' | b'R', b'o' | b'O', ..] => {
+            Some((Unit::Microsecond, 5))
+        }
+        &[b'h' | b'H', b'o' | b'O', b'u' | b'U', b'r' | b'R', b's' | b'S', ..] => {
+            Some((Unit::Hour, 5))
+        }
+        &[b'y' | b'Y', b'e' | b'E', b'a' | b'A', b'r' | b'R', ..] => {
+            Some((Unit::Year, 4))
+        }
+        &[b'w' | b'W', b'e' | b'E', b'e' | b'E', b'k' | b'K', ..] => {
+            Some((Unit::Week, 4))
+        }
+        &[b'u' | b'U', b's' | b'S', b'e' | b'E', b'c' | b'C', ..] => {
+            Some((Unit::Microsecond, 4))
+        }
+        &[b's' | b'S', b'e' | b'E', b'c' | b'C', b's' | b'S', ..] => {
+            Some((Unit::Second, 4))
+        }
+        &[b'n' | b'N', b's' | b'S', b'e' | b'E', b'c' | b'C', ..] => {
+            Some((Unit::Nanosecond, 4))
+        }
+        &[b'n' | b'N', b'a' | b'A', b'n' | b'N', b'o' | b'O', ..] => {
+            Some((Unit::Nanosecond, 4))
+        }
+        &[b'm' | b'M', b's' | b'S', b'e' | b'E', b'c' | b'C', ..] => {
+            Some((Unit::Millisecond, 4))
+        }
+        &[b'm' | b'M', b'i' | b'I', b'n' | b'N', b's' | b'S', ..] => {
+            Some((Unit::Minute, 4))
+        }
+        &[b'h' | b'H', b'o' | b'O', b'u' | b'U', b'r' | b'R', ..] => {
+            Some((Unit::Hour, 4))
+        }
+        &[b'd' | b'D', b'a' | b'A', b'y' | b'Y', b's' | b'S', ..] => {
+            Some((Unit::Day, 4))
+        }
+        &[b'\xc2', b'\xb5', b's' | b'S', ..] => Some((Unit::Microsecond, 3)),
+        &[b'y' | b'Y', b'r' | b'R', b's' | b'S', ..] => Some((Unit::Year, 3)),
+        &[b'w' | b'W', b'k' | b'K', b's' | b'S', ..] => Some((Unit::Week, 3)),
+        &[b's' | b'S', b'e' | b'E', b'c' | b'C', ..] => {
+            Some((Unit::Second, 3))
+        }
+        &[b'm' | b'M', b'o' | b'O', b's' | b'S', ..] => Some((Unit::Month, 3)),
+        &[b'm' | b'M', b'i' | b'I', b'n' | b'N', ..] => {
+            Some((Unit::Minute, 3))
+        }
+        &[b'h' | b'H', b'r' | b'R', b's' | b'S', ..] => Some((Unit::Hour, 3)),
+        &[b'd' | b'D', b'a' | b'A', b'y' | b'Y', ..] => Some((Unit::Day, 3)),
+        &[b'y' | b'Y', b'r' | b'R', ..] => Some((Unit::Year, 2)),
+        &[b'w' | b'W', b'k' | b'K', ..] => Some((Unit::Week, 2)),
+        &[b'u' | b'U', b's' | b'S', ..] => Some((Unit::Microsecond, 2)),
+        &[b'n' | b'N', b's' | b'S', ..] => Some((Unit::Nanosecond, 2)),
+        &[b'm' | b'M', b's' | b'S', ..] => Some((Unit::Millisecond, 2)),
+        &[b'm' | b'M', b'o' | b'O', ..] => Some((Unit::Month, 2)),
+        &[b'h' | b'H', b'r' | b'R', ..] => Some((Unit::Hour, 2)),
+        &[b'y' | b'Y', ..] => Some((Unit::Year, 1)),
+        &[b'w' | b'W', ..] => Some((Unit::Week, 1)),
+        &[b's' | b'S', ..] => Some((Unit::Second, 1)),
+        &[b'm' | b'M', ..] => Some((Unit::Minute, 1)),
+        &[b'h' | b'H', ..] => Some((Unit::Hour, 1)),
+        &[b'd' | b'D', ..] => Some((Unit::Day, 1)),
+        _ => None,
+    }
+}
@@ -1,5 +1,6 @@
 use crate::Unit;
 
+#[allow(clippy::match_ref_pats)]
 #[inline(always)]
 pub(super) fn find(haystack: &[u8]) -> Option<(Unit, usize)> {
     match haystack {
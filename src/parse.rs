@@ -0,0 +1,339 @@
+/*!
+A parser for "friendly" durations built on top of a unit designator lookup.
+
+This parses durations like `2 years 1 month 15 days 5 hours 59 minutes 1
+second` into a sequence of components, where each component is an integer
+and the unit it applies to. The grammar is roughly:
+
+```text
+duration  = ws* component (separator component)* ws*
+component = sign? digit+ ws* designator
+separator = ws* ','? ws*
+sign      = '+' | '-'
+ws        = ' ' | '\t' | '\n' | '\r'
+```
+
+So, `1h30m`, `1 h 30 m` and `1 hour, 30 minutes` are all accepted. The
+recognition of each designator is delegated to a [`UnitLookup`]
+implementation, which makes it possible to compare the techniques in this
+crate in the context of a complete parser.
+
+This parser intentionally does not do any duration arithmetic. It doesn't
+check that units are in descending order, nor does it reject duplicate units.
+Those are policy decisions best left to the caller.
+*/
+
+use crate::{LookupError, Unit, UnitLookup};
+
+/// A parser for friendly durations that uses `L` to recognize unit
+/// designator labels.
+///
+/// # Example
+///
+/// ```
+/// use duration_unit_lookup::{
+///     one_big_match::OneBigMatch, parse::Parser, Unit,
+/// };
+///
+/// let parser = Parser::new(OneBigMatch);
+/// let components = parser.parse(b"1 hour, 30 mins").unwrap();
+/// assert_eq!(components, vec![(1, Unit::Hour), (30, Unit::Minute)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Parser<L> {
+    lookup: L,
+    ignore_ascii_case: bool,
+}
+
+impl<L: UnitLookup> Parser<L> {
+    /// Create a new parser that recognizes unit designator labels with the
+    /// technique given.
+    ///
+    /// Any `&dyn UnitLookup` (like the ones in
+    /// [`STRATEGIES`](crate::STRATEGIES)) may be given here too.
+    pub fn new(lookup: L) -> Parser<L> {
+        Parser { lookup, ignore_ascii_case: false }
+    }
+
+    /// When enabled, unit designator labels are recognized without regard
+    /// to ASCII case. e.g., `5 Hours` is accepted.
+    ///
+    /// This is disabled by default.
+    pub fn ignore_ascii_case(mut self, yes: bool) -> Parser<L> {
+        self.ignore_ascii_case = yes;
+        self
+    }
+
+    /// Parse a friendly duration into its components.
+    ///
+    /// The components are returned in the order in which they appear in the
+    /// input. At least one component is always returned on success.
+    pub fn parse<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Vec<(i64, Unit)>, ParseError<'i>> {
+        let mut components = vec![];
+        let mut at = skip_whitespace(input, 0);
+        if at == input.len() {
+            return Err(ParseError::Empty);
+        }
+        loop {
+            let (number, end) = parse_number(input, at)?;
+            at = skip_whitespace(input, end);
+            let (unit, remaining) =
+                self.lookup_designator(&input[at..]).map_err(|err| {
+                    ParseError::UnknownDesignator(err.offset(at))
+                })?;
+            components.push((number, unit));
+
+            at = skip_whitespace(input, input.len() - remaining.len());
+            if at == input.len() {
+                return Ok(components);
+            }
+            if input[at] == b',' {
+                at = skip_whitespace(input, at + 1);
+            }
+        }
+    }
+
+    /// Looks up a designator using the configured case sensitivity.
+    #[inline(always)]
+    fn lookup_designator<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<(Unit, &'i [u8]), LookupError<'i>> {
+        if self.ignore_ascii_case {
+            self.lookup.lookup_ignore_ascii_case(input)
+        } else {
+            self.lookup.lookup(input)
+        }
+    }
+}
+
+/// An error that occurs when parsing a friendly duration.
+///
+/// All offsets are byte offsets into the input given to [`Parser::parse`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseError<'i> {
+    /// The input was empty or consisted entirely of whitespace.
+    Empty,
+    /// An integer was expected at the given offset, but something else was
+    /// found (or the input ended).
+    ExpectedNumber {
+        /// The offset at which an integer was expected.
+        offset: usize,
+    },
+    /// An integer was found but it doesn't fit into an `i64`.
+    Overflow {
+        /// The offset at which the integer (including its sign) begins.
+        start: usize,
+        /// The offset at which the integer ends.
+        end: usize,
+    },
+    /// An integer was found, but it wasn't followed by a known unit
+    /// designator label.
+    ///
+    /// The offsets in the error are relative to the input given to
+    /// [`Parser::parse`].
+    UnknownDesignator(LookupError<'i>),
+}
+
+impl<'i> std::error::Error for ParseError<'i> {}
+
+impl<'i> std::fmt::Display for ParseError<'i> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            ParseError::Empty => {
+                write!(f, "expected a duration, but found empty input")
+            }
+            ParseError::ExpectedNumber { offset } => write!(
+                f,
+                "expected an integer at offset {offset}, but found \
+                 something else",
+            ),
+            ParseError::Overflow { start, end } => write!(
+                f,
+                "integer at offsets {start}..{end} is too big to fit in a \
+                 64-bit signed integer",
+            ),
+            ParseError::UnknownDesignator(ref err) => match *err {
+                LookupError::EndOfInput => write!(f, "{err}"),
+                LookupError::UnknownDesignator { start, .. } => {
+                    write!(f, "at offset {start}: {err}")
+                }
+            },
+        }
+    }
+}
+
+/// Parses an optionally signed integer beginning at `at`, and returns it
+/// along with the offset at which it ends.
+#[inline(always)]
+fn parse_number<'i>(
+    input: &'i [u8],
+    at: usize,
+) -> Result<(i64, usize), ParseError<'i>> {
+    let start = at;
+    let mut at = at;
+    let negative = match input.get(at) {
+        Some(&b'-') => {
+            at += 1;
+            true
+        }
+        Some(&b'+') => {
+            at += 1;
+            false
+        }
+        _ => false,
+    };
+    let digits_start = at;
+    // We accumulate a negative number so that `i64::MIN` can be parsed.
+    let mut number: i64 = 0;
+    let mut overflow = false;
+    while let Some(&byte) = input.get(at) {
+        if !byte.is_ascii_digit() {
+            break;
+        }
+        let digit = i64::from(byte - b'0');
+        match number.checked_mul(10).and_then(|n| n.checked_sub(digit)) {
+            Some(n) => number = n,
+            None => overflow = true,
+        }
+        at += 1;
+    }
+    if at == digits_start {
+        return Err(ParseError::ExpectedNumber { offset: start });
+    }
+    if overflow {
+        return Err(ParseError::Overflow { start, end: at });
+    }
+    if !negative {
+        number = number
+            .checked_neg()
+            .ok_or(ParseError::Overflow { start, end: at })?;
+    }
+    Ok((number, at))
+}
+
+/// Returns the offset of the first non-whitespace byte at or after `at`.
+#[inline(always)]
+fn skip_whitespace(input: &[u8], mut at: usize) -> usize {
+    while at < input.len() && matches!(input[at], b' ' | b'\t' | b'\n' | b'\r')
+    {
+        at += 1;
+    }
+    at
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{one_big_match::OneBigMatch, INLINE_STRATEGIES, STRATEGIES};
+
+    use super::*;
+
+    #[test]
+    fn basic() {
+        use Unit::*;
+
+        let p = Parser::new(OneBigMatch);
+        assert_eq!(
+            p.parse(b"2 years 1 month 15 days 5 hours 59 minutes 1 second"),
+            Ok(vec![
+                (2, Year),
+                (1, Month),
+                (15, Day),
+                (5, Hour),
+                (59, Minute),
+                (1, Second),
+            ]),
+        );
+        assert_eq!(p.parse(b"1h30m"), Ok(vec![(1, Hour), (30, Minute)]));
+        assert_eq!(
+            p.parse(b"  1 hour,30 mins , 5s  "),
+            Ok(vec![(1, Hour), (30, Minute), (5, Second)]),
+        );
+        assert_eq!(p.parse(b"-5 days"), Ok(vec![(-5, Day)]));
+        assert_eq!(p.parse(b"+5 \xC2\xB5s"), Ok(vec![(5, Microsecond)]));
+        assert_eq!(
+            p.parse(b"-9223372036854775808ns"),
+            Ok(vec![(i64::MIN, Nanosecond)]),
+        );
+        assert_eq!(
+            p.parse(b"9223372036854775807ns"),
+            Ok(vec![(i64::MAX, Nanosecond)]),
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let p = Parser::new(OneBigMatch);
+        assert_eq!(p.parse(b""), Err(ParseError::Empty));
+        assert_eq!(p.parse(b" \t "), Err(ParseError::Empty));
+        assert_eq!(
+            p.parse(b"days"),
+            Err(ParseError::ExpectedNumber { offset: 0 })
+        );
+        assert_eq!(
+            p.parse(b"5 days,"),
+            Err(ParseError::ExpectedNumber { offset: 7 })
+        );
+        assert_eq!(
+            p.parse(b"5 days,, 1h"),
+            Err(ParseError::ExpectedNumber { offset: 7 })
+        );
+        assert_eq!(
+            p.parse(b"- 5 days"),
+            Err(ParseError::ExpectedNumber { offset: 0 })
+        );
+        assert_eq!(
+            p.parse(b"9223372036854775808ns"),
+            Err(ParseError::Overflow { start: 0, end: 19 })
+        );
+        assert_eq!(
+            p.parse(b"1 day 99999999999999999999ns"),
+            Err(ParseError::Overflow { start: 6, end: 26 })
+        );
+        assert_eq!(
+            p.parse(b"5 days 3 minuts"),
+            Err(ParseError::UnknownDesignator(
+                LookupError::UnknownDesignator {
+                    start: 9,
+                    end: 15,
+                    found: b"minuts"
+                }
+            )),
+        );
+        assert_eq!(
+            p.parse(b"5 days 3"),
+            Err(ParseError::UnknownDesignator(LookupError::EndOfInput)),
+        );
+        assert_eq!(
+            p.parse(b"5 days 3 minuts").unwrap_err().to_string(),
+            "at offset 9: expected to find unit designator suffix (e.g., \
+             'years' or 'secs'), but found input beginning with \"minuts\" \
+             instead (did you mean \"minutes\", \"minute\" or \"mins\"?)",
+        );
+    }
+
+    #[test]
+    fn ignore_ascii_case() {
+        let p = Parser::new(OneBigMatch);
+        assert!(p.parse(b"5 Hours").is_err());
+        let p = p.ignore_ascii_case(true);
+        assert_eq!(
+            p.parse(b"5 Hours, 10 MS"),
+            Ok(vec![(5, Unit::Hour), (10, Unit::Millisecond)]),
+        );
+    }
+
+    #[test]
+    fn every_strategy() {
+        let input = b"2 years, 1 month 15d 5 hrs 59 minutes 1s 3ms 4\xC2\xB5s";
+        let expected = Parser::new(OneBigMatch).parse(input).unwrap();
+        assert_eq!(expected.len(), 8);
+        for &strategy in STRATEGIES.iter().chain(INLINE_STRATEGIES) {
+            let got = Parser::new(strategy).parse(input).unwrap();
+            assert_eq!(got, expected, "{}", strategy.name());
+        }
+    }
+}
@@ -0,0 +1,353 @@
+/*!
+A printer for "friendly" durations. This is the inverse of [`parse`].
+
+The printer renders a list of `(i64, Unit)` components (the same
+representation produced by [`parse::Parser`]) using one of a few styles of
+unit designator labels:
+
+* [`Style::Verbose`] prints `5 hours 1 minute`.
+* [`Style::Short`] prints `5hrs 1min`.
+* [`Style::Compact`] prints `5h 1m`.
+
+On top of a style, the label used for each unit may be customized with
+[`Printer::designator`]. Only labels that are recognized by the lookup
+routines in this crate may be used, so anything printed can always be parsed
+back into the very same components by [`parse::Parser`] with any of the
+techniques in [`STRATEGIES`](crate::STRATEGIES).
+
+[`parse`]: crate::parse
+[`parse::Parser`]: crate::parse::Parser
+*/
+
+use crate::{labels::LABELS, Unit};
+
+/// The style of unit designator labels used by a [`Printer`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Style {
+    /// Full unit names separated from the value by a space, e.g., `5 hours`.
+    Verbose,
+    /// Abbreviated unit names with no space, e.g., `5hrs`.
+    Short,
+    /// The shortest label for each unit with no space, e.g., `5h`.
+    Compact,
+}
+
+impl Style {
+    /// Returns the singular and plural labels for the unit given in this
+    /// style.
+    fn labels(self, unit: Unit) -> (&'static str, &'static str) {
+        use self::Style::*;
+        use crate::Unit::*;
+
+        match (self, unit) {
+            (Verbose, Year) => ("year", "years"),
+            (Verbose, Month) => ("month", "months"),
+            (Verbose, Week) => ("week", "weeks"),
+            (Verbose, Day) => ("day", "days"),
+            (Verbose, Hour) => ("hour", "hours"),
+            (Verbose, Minute) => ("minute", "minutes"),
+            (Verbose, Second) => ("second", "seconds"),
+            (Verbose, Millisecond) => ("millisecond", "milliseconds"),
+            (Verbose, Microsecond) => ("microsecond", "microseconds"),
+            (Verbose, Nanosecond) => ("nanosecond", "nanoseconds"),
+            (Short, Year) => ("yr", "yrs"),
+            (Short, Month) => ("mo", "mos"),
+            (Short, Week) => ("wk", "wks"),
+            (Short, Day) => ("day", "days"),
+            (Short, Hour) => ("hr", "hrs"),
+            (Short, Minute) => ("min", "mins"),
+            (Short, Second) => ("sec", "secs"),
+            (Short, Millisecond) => ("msec", "msecs"),
+            (Short, Microsecond) => ("\u{b5}sec", "\u{b5}secs"),
+            (Short, Nanosecond) => ("nsec", "nsecs"),
+            (Compact, Year) => ("y", "y"),
+            (Compact, Month) => ("mo", "mo"),
+            (Compact, Week) => ("w", "w"),
+            (Compact, Day) => ("d", "d"),
+            (Compact, Hour) => ("h", "h"),
+            (Compact, Minute) => ("m", "m"),
+            (Compact, Second) => ("s", "s"),
+            (Compact, Millisecond) => ("ms", "ms"),
+            (Compact, Microsecond) => ("\u{b5}s", "\u{b5}s"),
+            (Compact, Nanosecond) => ("ns", "ns"),
+        }
+    }
+}
+
+/// A printer for friendly durations.
+///
+/// # Example
+///
+/// ```
+/// use duration_unit_lookup::{
+///     print::{Printer, Style},
+///     Unit,
+/// };
+///
+/// let components = [(5, Unit::Hour), (1, Unit::Minute)];
+///
+/// let printer = Printer::new(Style::Verbose);
+/// assert_eq!(printer.print(&components).unwrap(), "5 hours 1 minute");
+/// let printer = Printer::new(Style::Short);
+/// assert_eq!(printer.print(&components).unwrap(), "5hrs 1min");
+/// let printer = Printer::new(Style::Compact);
+/// assert_eq!(printer.print(&components).unwrap(), "5h 1m");
+///
+/// let printer = Printer::new(Style::Compact)
+///     .designator(Unit::Minute, "min", "mins")
+///     .unwrap();
+/// assert_eq!(printer.print(&components).unwrap(), "5h 1min");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Printer {
+    style: Style,
+    /// Custom singular and plural labels, indexed by `Unit as usize`.
+    custom: [Option<(&'static str, &'static str)>; 10],
+}
+
+impl Printer {
+    /// Create a new printer that uses the style of labels given.
+    pub fn new(style: Style) -> Printer {
+        Printer { style, custom: [None; 10] }
+    }
+
+    /// Use the labels given for `unit` instead of the ones from this
+    /// printer's style.
+    ///
+    /// The singular label is used when the value is `1` or `-1`, and the
+    /// plural label is used otherwise. They may be the same.
+    ///
+    /// This returns an error if either label isn't a known unit designator
+    /// label for `unit`. (See [`LABELS`](crate::LABELS).)
+    pub fn designator(
+        mut self,
+        unit: Unit,
+        singular: &str,
+        plural: &str,
+    ) -> Result<Printer, PrintError> {
+        let singular = known_label(unit, singular)?;
+        let plural = known_label(unit, plural)?;
+        self.custom[unit as usize] = Some((singular, plural));
+        Ok(self)
+    }
+
+    /// Print the components given as a friendly duration.
+    ///
+    /// The components are printed in the order given and are separated by a
+    /// single space. This returns an error if there are no components, since
+    /// there is no way to write an empty friendly duration.
+    pub fn print(
+        &self,
+        components: &[(i64, Unit)],
+    ) -> Result<String, PrintError> {
+        use std::fmt::Write;
+
+        if components.is_empty() {
+            return Err(PrintError::Empty);
+        }
+        let space = if self.style == Style::Verbose { " " } else { "" };
+        let mut out = String::new();
+        for (i, &(value, unit)) in components.iter().enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            let (singular, plural) = self.custom[unit as usize]
+                .unwrap_or_else(|| self.style.labels(unit));
+            let label =
+                if value.unsigned_abs() == 1 { singular } else { plural };
+            write!(out, "{value}{space}{label}").unwrap();
+        }
+        Ok(out)
+    }
+}
+
+/// An error that occurs when configuring or using a [`Printer`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PrintError {
+    /// There were no components to print.
+    Empty,
+    /// A custom label for the given unit isn't a known unit designator
+    /// label.
+    UnknownLabel {
+        /// The unit the label was given for.
+        unit: Unit,
+    },
+    /// A custom label for the given unit is a known unit designator label,
+    /// but for a different unit.
+    WrongUnit {
+        /// The unit the label was given for.
+        unit: Unit,
+        /// The unit the label actually maps to.
+        actual: Unit,
+    },
+}
+
+impl std::error::Error for PrintError {}
+
+impl std::fmt::Display for PrintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            PrintError::Empty => {
+                write!(f, "a friendly duration requires at least one unit")
+            }
+            PrintError::UnknownLabel { unit } => write!(
+                f,
+                "label given for {unit:?} is not a known unit designator \
+                 label",
+            ),
+            PrintError::WrongUnit { unit, actual } => write!(
+                f,
+                "label given for {unit:?} is a unit designator label for \
+                 {actual:?} instead",
+            ),
+        }
+    }
+}
+
+/// Returns the static form of `label` if it is a known label for `unit`.
+fn known_label(unit: Unit, label: &str) -> Result<&'static str, PrintError> {
+    let Some(&(known, actual)) = LABELS.iter().find(|&&(l, _)| l == label)
+    else {
+        return Err(PrintError::UnknownLabel { unit });
+    };
+    if actual != unit {
+        return Err(PrintError::WrongUnit { unit, actual });
+    }
+    Ok(known)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse::Parser, rng::Rng, Unit::*, STRATEGIES};
+
+    use super::*;
+
+    const UNITS: [Unit; 10] = [
+        Year,
+        Month,
+        Week,
+        Day,
+        Hour,
+        Minute,
+        Second,
+        Millisecond,
+        Microsecond,
+        Nanosecond,
+    ];
+
+    /// Asserts that the output of `printer` for `components` parses back to
+    /// the same components with every strategy, and returns the output.
+    fn roundtrip(printer: &Printer, components: &[(i64, Unit)]) -> String {
+        let printed = printer.print(components).unwrap();
+        for &strategy in STRATEGIES {
+            let parsed = Parser::new(strategy).parse(printed.as_bytes());
+            assert_eq!(
+                parsed.as_deref(),
+                Ok(components),
+                "{}: {printed:?}",
+                strategy.name(),
+            );
+        }
+        printed
+    }
+
+    #[test]
+    fn styles() {
+        let components = [
+            (2, Year),
+            (1, Month),
+            (0, Week),
+            (15, Day),
+            (5, Hour),
+            (59, Minute),
+            (1, Second),
+            (-1, Millisecond),
+            (3, Microsecond),
+            (i64::MIN, Nanosecond),
+        ];
+        assert_eq!(
+            roundtrip(&Printer::new(Style::Verbose), &components),
+            "2 years 1 month 0 weeks 15 days 5 hours 59 minutes 1 second \
+             -1 millisecond 3 microseconds -9223372036854775808 nanoseconds",
+        );
+        assert_eq!(
+            roundtrip(&Printer::new(Style::Short), &components),
+            "2yrs 1mo 0wks 15days 5hrs 59mins 1sec -1msec 3\u{b5}secs \
+             -9223372036854775808nsecs",
+        );
+        assert_eq!(
+            roundtrip(&Printer::new(Style::Compact), &components),
+            "2y 1mo 0w 15d 5h 59m 1s -1ms 3\u{b5}s -9223372036854775808ns",
+        );
+    }
+
+    #[test]
+    fn custom() {
+        let printer = Printer::new(Style::Verbose)
+            .designator(Microsecond, "usec", "usecs")
+            .unwrap()
+            .designator(Hour, "h", "h")
+            .unwrap();
+        assert_eq!(
+            roundtrip(&printer, &[(1, Hour), (1, Microsecond)]),
+            "1 h 1 usec",
+        );
+        assert_eq!(
+            roundtrip(&printer, &[(2, Hour), (2, Microsecond)]),
+            "2 h 2 usecs",
+        );
+
+        // Every known label can be used as a custom label.
+        for &(label, unit) in LABELS {
+            for style in [Style::Verbose, Style::Short, Style::Compact] {
+                let printer = Printer::new(style)
+                    .designator(unit, label, label)
+                    .unwrap();
+                for &other in UNITS.iter() {
+                    roundtrip(&printer, &[(1, unit), (7, other), (-1, unit)]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let printer = Printer::new(Style::Compact);
+        assert_eq!(printer.print(&[]), Err(PrintError::Empty));
+        assert_eq!(
+            printer.clone().designator(Hour, "hour", "hourz").unwrap_err(),
+            PrintError::UnknownLabel { unit: Hour },
+        );
+        assert_eq!(
+            printer.clone().designator(Hour, "HOUR", "hours").unwrap_err(),
+            PrintError::UnknownLabel { unit: Hour },
+        );
+        assert_eq!(
+            printer.designator(Month, "m", "m").unwrap_err(),
+            PrintError::WrongUnit { unit: Month, actual: Minute },
+        );
+    }
+
+    /// Tests that printing and then parsing is lossless for many random
+    /// lists of components in every style.
+    #[test]
+    fn random() {
+        let mut rng = Rng::default();
+        for _ in 0..1_000 {
+            let len = 1 + rng.below(12);
+            let components: Vec<(i64, Unit)> = (0..len)
+                .map(|_| {
+                    let value = match rng.below(4) {
+                        0 => rng.below(3) as i64 - 1,
+                        1 => rng.below(1_000) as i64,
+                        _ => rng.next_u64() as i64,
+                    };
+                    (value, UNITS[rng.below(10)])
+                })
+                .collect();
+            for style in [Style::Verbose, Style::Short, Style::Compact] {
+                roundtrip(&Printer::new(style), &components);
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+/*!
+A simple pseudorandom number generator.
+
+The randomized tests, the benchmark workloads and the synthetic label sets
+all need random numbers that are the same on every run, but none of them
+need good ones. So they all share this xorshift generator instead of
+depending on a crate for it.
+
+This file is also compiled into the build script (for `synthetic.rs`), and
+so it must not refer to anything else in this crate.
+*/
+
+/// A xorshift PRNG.
+///
+/// The same seed always produces the same sequence of numbers.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// The seed used when there's no reason to pick any other.
+    pub const DEFAULT_SEED: u64 = 0x9E3779B97F4A7C15;
+
+    /// Create a new PRNG from the seed given.
+    ///
+    /// A xorshift PRNG seeded with zero only ever produces zero, so a zero
+    /// seed is treated as `1`.
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed.max(1))
+    }
+
+    /// Returns the next number in the sequence.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a number in the range `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % (n as u64)) as usize
+    }
+
+    /// Returns a number in the range `0.0..1.0`.
+    pub fn probability(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Shuffles the slice given in place.
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            slice.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+impl Default for Rng {
+    fn default() -> Rng {
+        Rng::new(Rng::DEFAULT_SEED)
+    }
+}
@@ -0,0 +1,491 @@
+/*!
+Lookup techniques built over synthetic label sets of increasing size.
+
+Every conclusion in the README is drawn from the one real set of 56 labels.
+This module builds every technique over [`synthetic`] sets of 10, 50, 200
+and 1000 labels instead, so that we can see how each one scales with the
+number of labels. These are used by the `scaling-*` benchmarks, and are only
+built with the `scaling` feature.
+
+The build script generates the labels for each set, along with the code that
+`gen-match-prefix` and `gendfa` would generate for them. The rest is built
+from the labels the same way as the corresponding technique in this crate:
+
+* `one-big-match` and `hashify` expand the labels into a `match` expression
+  and a `hashify::tiny_map!`, respectively, via a `synthetic_labels!` macro
+  that works just like `designator_labels!`.
+* `one-big-match-prefix` uses the generated prefix `match`.
+* `aho-corasick` builds a DFA from the labels at runtime.
+* `phf` expands the labels into a `phf_map!`.
+* `by-trie1` through `by-trie5` build the same tries as their counterparts
+  at compile time. Since the tries in this crate use node identifiers that
+  are only as big as the 56 real labels need, these use copies of them with
+  32-bit node identifiers, which the build script writes out.
+* `by-gendfa1` through `by-gendfa6` and `by-gencdfa1` and `by-gencdfa2` use
+  the generated DFAs, with the same options their counterparts are
+  generated with. The C code is compiled by the build script.
+
+Each technique over each set is its own type, so that the benchmarks can
+call every one of them directly. See [`scaling_techniques!`].
+
+[`synthetic`]: crate::synthetic
+[`scaling_techniques!`]: crate::scaling_techniques
+*/
+
+use crate::Unit;
+
+/// A technique built over a synthetic label set.
+///
+/// This works like [`UnitLookup`], but only has a case sensitive `find`
+/// routine, which is `#[inline(never)]` just like the ones in this crate.
+///
+/// [`UnitLookup`]: crate::UnitLookup
+pub trait Technique: Sync {
+    /// Returns the name of this technique, e.g., `by-trie5`.
+    fn name(&self) -> &'static str;
+
+    /// Recognizes a label at the beginning of `input`. This works like
+    /// [`UnitLookup::find`].
+    ///
+    /// [`UnitLookup::find`]: crate::UnitLookup::find
+    fn find(&self, input: &[u8]) -> Option<(Unit, usize)>;
+}
+
+/// A synthetic set of labels, along with every technique built over it.
+pub struct LabelSet {
+    labels: &'static [(&'static str, Unit)],
+    techniques: &'static [&'static dyn Technique],
+}
+
+impl LabelSet {
+    /// Returns the labels in this set, along with the unit each one maps to.
+    pub fn labels(&self) -> &'static [(&'static str, Unit)] {
+        self.labels
+    }
+
+    /// Returns every technique built over this set.
+    ///
+    /// Calling through a `&dyn Technique` adds an indirect call to every
+    /// lookup, so the benchmarks use [`scaling_techniques!`] instead.
+    ///
+    /// [`scaling_techniques!`]: crate::scaling_techniques
+    pub fn techniques(&self) -> &'static [&'static dyn Technique] {
+        self.techniques
+    }
+}
+
+impl std::fmt::Debug for LabelSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let names: Vec<&str> =
+            self.techniques.iter().map(|t| t.name()).collect();
+        f.debug_struct("LabelSet")
+            .field("labels", &self.labels.len())
+            .field("techniques", &names)
+            .finish()
+    }
+}
+
+/// Invokes the macro given with every synthetic label set's module, each
+/// followed by the types of the techniques in it.
+///
+/// This works like [`lookup_strategies!`]. [`LABEL_SETS`] is built from it,
+/// and so are the `scaling-*` benchmarks. The input given to the callback
+/// macro is a comma separated sequence of `module => [Type, ...]`, where
+/// `module` is a module in [`scaling`](crate::scaling). Every set has the
+/// same techniques, in the same order as [`STRATEGIES`].
+///
+/// [`lookup_strategies!`]: crate::lookup_strategies
+/// [`STRATEGIES`]: crate::STRATEGIES
+#[doc(hidden)]
+#[macro_export]
+macro_rules! scaling_techniques {
+    ($callback:ident) => {
+        $crate::scaling_techniques! { @sets $callback, [
+            OneBigMatch,
+            OneBigMatchPrefix,
+            AhoCorasick,
+            Phf,
+            Hashify,
+            ByTrie1,
+            ByTrie2,
+            ByTrie3,
+            ByTrie4,
+            ByTrie5,
+            ByGenDfa1,
+            ByGenDfa2,
+            ByGenDfa3,
+            ByGenDfa4,
+            ByGenDfa5,
+            ByGenDfa6,
+            ByGenCDfa1,
+            ByGenCDfa2,
+        ] }
+    };
+    (@sets $callback:ident, $techniques:tt) => {
+        $callback! {
+            labels10 => $techniques,
+            labels50 => $techniques,
+            labels200 => $techniques,
+            labels1000 => $techniques,
+        }
+    };
+}
+
+/// Every synthetic label set, from smallest to largest.
+pub static LABEL_SETS: &[&LabelSet] = {
+    macro_rules! label_sets {
+        ($($set:ident => [$($technique:ident),* $(,)?]),* $(,)?) => {
+            &[$(&LabelSet {
+                labels: $set::LABELS,
+                techniques: &[$(&$set::$technique),*],
+            }),*]
+        };
+    }
+    scaling_techniques!(label_sets)
+};
+
+/// The copies of the tries in this crate with 32-bit node identifiers.
+///
+/// These allow the same lints as the originals do in `lib.rs`.
+#[allow(
+    dead_code,
+    clippy::doc_lazy_continuation,
+    clippy::redundant_pattern_matching,
+    clippy::redundant_static_lifetimes,
+    clippy::unnecessary_cast
+)]
+mod wide {
+    pub(super) mod trie1 {
+        include!(concat!(env!("OUT_DIR"), "/trie1_wide.rs"));
+    }
+
+    pub(super) mod trie2 {
+        include!(concat!(env!("OUT_DIR"), "/trie2_wide.rs"));
+    }
+
+    pub(super) mod trie3 {
+        include!(concat!(env!("OUT_DIR"), "/trie3_wide.rs"));
+    }
+
+    pub(super) mod trie4 {
+        include!(concat!(env!("OUT_DIR"), "/trie4_wide.rs"));
+    }
+
+    pub(super) mod trie5 {
+        include!(concat!(env!("OUT_DIR"), "/trie5_wide.rs"));
+    }
+}
+
+/// What the generated C DFAs return. This is the same as in
+/// `by_gencdfa1`.
+#[repr(C)]
+struct Output {
+    unit: Unit,
+    len: usize,
+}
+
+/// Defines a technique over the current set, given its type, its name and
+/// its `find` routine.
+macro_rules! technique {
+    ($ty:ident, $name:literal, |$input:ident| $find:block) => {
+        #[doc = concat!("The `", $name, "` technique over this set.")]
+        #[derive(Clone, Copy, Debug)]
+        pub struct $ty;
+
+        impl $crate::scaling::Technique for $ty {
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            #[inline(never)]
+            fn find(&self, $input: &[u8]) -> Option<(Unit, usize)> {
+                $find
+            }
+        }
+    };
+}
+
+/// Expands the labels given into a closure that maps a complete label to its
+/// unit with a `match` expression.
+///
+/// This and the other callbacks below are given to `synthetic_labels!`.
+macro_rules! one_big_match {
+    ($($label:literal => $unit:ident,)*) => {
+        |needle: &[u8]| match needle {
+            $($label => Some(Unit::$unit),)*
+            _ => None,
+        }
+    };
+}
+
+/// Expands the labels given into a closure that maps a complete label to its
+/// unit with `hashify::tiny_map!`.
+macro_rules! tiny_map {
+    ($($label:literal => $unit:ident,)*) => {
+        |needle: &[u8]| hashify::tiny_map! { needle, $($label => Unit::$unit,)* }
+    };
+}
+
+/// Expands the labels given into a `phf::Map` from label to unit.
+macro_rules! phf_map {
+    ($($label:literal => $unit:ident,)*) => {
+        phf::phf_map! { $($label => Unit::$unit,)* }
+    };
+}
+
+/// Defines a module with every technique built over the synthetic label set
+/// in the generated file given.
+macro_rules! label_set {
+    ($module:ident, $file:literal) => {
+        #[allow(clippy::redundant_static_lifetimes)]
+        pub mod $module {
+            use std::sync::LazyLock;
+
+            use aho_corasick::{
+                automaton::Automaton, dfa::DFA, Anchored, Input, MatchKind,
+                StartKind,
+            };
+
+            use crate::{designator_len, Unit};
+
+            use super::{wide, Output};
+
+            include!(concat!(env!("OUT_DIR"), "/", $file));
+
+            technique!(OneBigMatch, "one-big-match", |input| {
+                let end = designator_len(input);
+                let designator = synthetic_labels!(one_big_match);
+                let unit = designator(&input[..end])?;
+                Some((unit, end))
+            });
+
+            technique!(OneBigMatchPrefix, "one-big-match-prefix", |input| {
+                find_prefix(input)
+            });
+
+            static SEARCHER: LazyLock<DFA> = LazyLock::new(|| {
+                DFA::builder()
+                    .match_kind(MatchKind::LeftmostLongest)
+                    .start_kind(StartKind::Anchored)
+                    .build(LABELS.iter().map(|&(label, _)| label))
+                    .unwrap()
+            });
+
+            technique!(AhoCorasick, "aho-corasick", |input| {
+                let search = Input::new(input).anchored(Anchored::Yes);
+                let mat = SEARCHER.try_find(&search).unwrap()?;
+                Some((LABELS[mat.pattern()].1, mat.end()))
+            });
+
+            static PHF: phf::Map<&'static [u8], Unit> =
+                synthetic_labels!(phf_map);
+
+            technique!(Phf, "phf", |input| {
+                let end = designator_len(input);
+                let unit = PHF.get(&input[..end]).copied()?;
+                Some((unit, end))
+            });
+
+            technique!(Hashify, "hashify", |input| {
+                let end = designator_len(input);
+                let designator = synthetic_labels!(tiny_map);
+                let unit = designator(&input[..end])?;
+                Some((unit, end))
+            });
+
+            static TRIE1: &'static wide::trie1::Trie<
+                TRIE_NODES,
+                { NEEDLES1.alphabet_len() },
+                Unit,
+            > = &wide::trie1::Trie::new(&NEEDLES1);
+
+            const NEEDLES1: wide::trie1::TrieNeedles<Unit> =
+                wide::trie1::TrieNeedles::new(LABELS);
+
+            technique!(ByTrie1, "by-trie1", |input| {
+                let (unit, remaining) = TRIE1.find(input)?;
+                Some((unit, input.len() - remaining.len()))
+            });
+
+            static TRIE2: &'static wide::trie2::Trie<
+                TRIE_NODES,
+                { NEEDLES2.alphabet_len() },
+                Unit,
+            > = &wide::trie2::Trie::new(&NEEDLES2);
+
+            const NEEDLES2: wide::trie2::TrieNeedles<Unit> =
+                wide::trie2::TrieNeedles::new(LABELS);
+
+            technique!(ByTrie2, "by-trie2", |input| { TRIE2.find(input) });
+
+            static TRIE3: &'static wide::trie3::Trie<
+                TRIE_NODES,
+                { NEEDLES3.alphabet_len() },
+                Unit,
+            > = &wide::trie3::Trie::new(&NEEDLES3);
+
+            const NEEDLES3: wide::trie3::TrieNeedles<Unit> =
+                wide::trie3::TrieNeedles::new(LABELS);
+
+            technique!(ByTrie3, "by-trie3", |input| { TRIE3.find(input) });
+
+            static TRIE4: &'static wide::trie4::Trie<
+                { NEEDLES4.transition_len(TRIE_NODES) },
+                { NEEDLES4.len() },
+                { NEEDLES4.alphabet_len() },
+                Unit,
+            > = &wide::trie4::Trie::new(&NEEDLES4, Unit::Nanosecond);
+
+            const NEEDLES4: wide::trie4::TrieNeedles<Unit> =
+                wide::trie4::TrieNeedles::new(LABELS);
+
+            technique!(ByTrie4, "by-trie4", |input| { TRIE4.find(input) });
+
+            static TRIE5: &'static wide::trie5::Trie<
+                { NEEDLES5.transition_len(TRIE_NODES) },
+                { NEEDLES5.len() },
+                { NEEDLES5.alphabet_len() },
+                Unit,
+            > = &wide::trie5::Trie::new(&NEEDLES5, Unit::Nanosecond);
+
+            const NEEDLES5: wide::trie5::TrieNeedles<Unit> =
+                wide::trie5::TrieNeedles::new(LABELS);
+
+            technique!(ByTrie5, "by-trie5", |input| {
+                let end = designator_len(input);
+                let unit = TRIE5.find(&input[..end])?;
+                Some((unit, end))
+            });
+
+            technique!(ByGenDfa1, "by-gendfa1", |input| {
+                gendfa1::find(input)
+            });
+
+            technique!(ByGenDfa2, "by-gendfa2", |input| {
+                gendfa2::find(input)
+            });
+
+            technique!(ByGenDfa3, "by-gendfa3", |input| {
+                gendfa3::find(input)
+            });
+
+            technique!(ByGenDfa4, "by-gendfa4", |input| {
+                gendfa4::find(input)
+            });
+
+            technique!(ByGenDfa5, "by-gendfa5", |input| {
+                gendfa5::DFA.find(input)
+            });
+
+            technique!(ByGenDfa6, "by-gendfa6", |input| {
+                gendfa6::find(input)
+            });
+
+            technique!(ByGenCDfa1, "by-gencdfa1", |input| {
+                let p = input.as_ptr();
+                let o = unsafe { gencdfa1_find(p, p.add(input.len())) };
+                if o.len == 0 {
+                    return None;
+                }
+                Some((o.unit, o.len))
+            });
+
+            technique!(ByGenCDfa2, "by-gencdfa2", |input| {
+                let p = input.as_ptr();
+                let o = unsafe { gencdfa2_find(p, p.add(input.len())) };
+                if o.len == 0 {
+                    return None;
+                }
+                Some((o.unit, o.len))
+            });
+        }
+    };
+}
+
+label_set!(labels10, "synthetic10.rs");
+label_set!(labels50, "synthetic50.rs");
+label_set!(labels200, "synthetic200.rs");
+label_set!(labels1000, "synthetic1000.rs");
+
+#[cfg(test)]
+mod tests {
+    use crate::differential::is_label_end;
+
+    use super::*;
+
+    /// Checks that every technique agrees with a naive lookup on every set,
+    /// once its results are normalized the same way as in the differential
+    /// check.
+    #[test]
+    fn consistent() {
+        for set in LABEL_SETS {
+            let naive = |input: &[u8]| {
+                let end = crate::designator_len(input);
+                let &(label, unit) = set
+                    .labels()
+                    .iter()
+                    .find(|&&(label, _)| label.as_bytes() == &input[..end])?;
+                Some((unit, label.len()))
+            };
+
+            let mut inputs: Vec<Vec<u8>> = vec![vec![], b" 5 days".to_vec()];
+            for &(label, _) in set.labels() {
+                for end in 1..=label.len() {
+                    inputs.push(label.as_bytes()[..end].to_vec());
+                    inputs.push(format!("{} 5 days", &label[..end]).into());
+                    inputs.push(format!("{}x", &label[..end]).into());
+                }
+            }
+            for technique in set.techniques() {
+                for input in inputs.iter() {
+                    let got = technique.find(input);
+                    if let Some((unit, len)) = got {
+                        assert!(
+                            set.labels().iter().any(|&(label, u)| {
+                                u == unit && label.as_bytes() == &input[..len]
+                            }),
+                            "{} with {} labels found {unit:?} in {:?}",
+                            technique.name(),
+                            set.labels().len(),
+                            input.escape_ascii().to_string(),
+                        );
+                    }
+                    assert_eq!(
+                        got.filter(|&(_, len)| is_label_end(
+                            &input[len..],
+                            false
+                        )),
+                        naive(input),
+                        "{} with {} labels: {:?}",
+                        technique.name(),
+                        set.labels().len(),
+                        input.escape_ascii().to_string(),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sizes() {
+        let sizes: Vec<usize> =
+            LABEL_SETS.iter().map(|set| set.labels().len()).collect();
+        assert_eq!(sizes, vec![10, 50, 200, 1000]);
+        let techniques: Vec<usize> =
+            LABEL_SETS.iter().map(|set| set.techniques().len()).collect();
+        assert_eq!(techniques, vec![18; 4]);
+    }
+
+    /// Checks that every set has the same techniques as this crate, in the
+    /// same order.
+    #[test]
+    fn names() {
+        let names: Vec<&str> =
+            crate::STRATEGIES.iter().map(|s| s.name()).collect();
+        for set in LABEL_SETS {
+            let got: Vec<&str> =
+                set.techniques().iter().map(|t| t.name()).collect();
+            assert_eq!(got, names);
+        }
+    }
+}
@@ -0,0 +1,312 @@
+/*!
+Computes "did you mean?" suggestions for unknown unit designator labels.
+
+The approach taken here is to build a Levenshtein automaton for the unknown
+label and then run every known label through it. The automaton is never
+determinized. Instead, we simulate its NFA using bit-parallelism (in the
+style of Wu and Manber), where each row of the NFA (one row per number of
+edits) is represented by the bits of a single `u64`. Since the labels are
+short and there aren't many of them, this is plenty fast for an error path.
+Moreover, it doesn't require any allocation.
+*/
+
+use crate::{labels::LABELS, Unit};
+
+/// The maximum number of suggestions reported.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The maximum number of edits that a suggestion may be away from the
+/// unknown label.
+///
+/// The number of edits actually permitted also depends on the length of the
+/// unknown label. See `max_distance`.
+const MAX_DISTANCE: usize = 2;
+
+/// A known unit designator label that is close to an unknown label.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Suggestion {
+    label: &'static str,
+    unit: Unit,
+    distance: usize,
+}
+
+impl Suggestion {
+    /// Returns the suggested label.
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// Returns the unit that the suggested label maps to.
+    pub fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    /// Returns the edit distance between the unknown label and this
+    /// suggestion.
+    pub fn distance(&self) -> usize {
+        self.distance
+    }
+}
+
+/// A ranked list of suggestions for an unknown unit designator label.
+///
+/// Suggestions are ordered by edit distance, closest first. Ties are broken
+/// by the order of labels in [`LABELS`](crate::LABELS), which tends to put
+/// the full unit name first.
+///
+/// This is created by [`LookupError::suggestions`](crate::LookupError::suggestions).
+#[derive(Clone, Debug)]
+pub struct Suggestions {
+    slots: [Option<Suggestion>; MAX_SUGGESTIONS],
+    len: usize,
+}
+
+impl Suggestions {
+    /// Computes suggestions for the unknown label given.
+    ///
+    /// If the label given is very short or very long, then no suggestions
+    /// are returned.
+    pub(crate) fn new(unknown: &[u8]) -> Suggestions {
+        let mut sugs = Suggestions { slots: [None; MAX_SUGGESTIONS], len: 0 };
+        let Some(max) = max_distance(unknown) else { return sugs };
+        let lev = Levenshtein::new(unknown);
+        for &(label, unit) in LABELS.iter() {
+            let Some(distance) = lev.distance(label.as_bytes(), max) else {
+                continue;
+            };
+            sugs.insert(Suggestion { label, unit, distance });
+        }
+        sugs
+    }
+
+    /// Returns the number of suggestions.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when there are no suggestions.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the suggestions, closest first.
+    pub fn iter(&self) -> impl Iterator<Item = Suggestion> + '_ {
+        self.slots[..self.len].iter().map(|sug| sug.unwrap())
+    }
+
+    /// Inserts the suggestion given in rank order, evicting the worst
+    /// suggestion if there's no more room.
+    ///
+    /// Since candidates are inserted in the order they appear in `LABELS`,
+    /// a new suggestion is always placed after existing suggestions with the
+    /// same distance.
+    fn insert(&mut self, sug: Suggestion) {
+        let mut i = self.len;
+        while i > 0 && self.slots[i - 1].unwrap().distance > sug.distance {
+            i -= 1;
+        }
+        if i == MAX_SUGGESTIONS {
+            return;
+        }
+        let end = core::cmp::min(self.len + 1, MAX_SUGGESTIONS);
+        self.slots.copy_within(i..end - 1, i + 1);
+        self.slots[i] = Some(sug);
+        self.len = end;
+    }
+}
+
+impl std::fmt::Display for Suggestions {
+    /// Writes the suggestions as a human readable list, e.g.,
+    /// `"minutes", "minute" or "mins"`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, sug) in self.iter().enumerate() {
+            if i > 0 {
+                let sep = if i + 1 == self.len { " or " } else { ", " };
+                f.write_str(sep)?;
+            }
+            write!(f, "{:?}", sug.label)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the maximum edit distance allowed for suggestions for the unknown
+/// label given.
+///
+/// When there shouldn't be any suggestions at all, this returns `None`.
+fn max_distance(unknown: &[u8]) -> Option<usize> {
+    match unknown.len() {
+        // A single byte is within one edit of every single byte label, so
+        // suggestions would just be noise. And if the unknown label doesn't
+        // fit into our bit-parallel representation, then it's probably
+        // garbage anyway.
+        0 | 1 | Levenshtein::MAX_LEN.. => None,
+        2..=4 => Some(1),
+        _ => Some(MAX_DISTANCE),
+    }
+}
+
+/// A Levenshtein automaton that recognizes all strings within some edit
+/// distance of a fixed string.
+///
+/// The NFA has a state `(i, e)` for every `i` in `0..=len` (the number of
+/// bytes of the fixed string consumed) and `e` in `0..=MAX_DISTANCE` (the
+/// number of edits used). Each set of states with the same `e` is a row,
+/// and each row is represented as a bitset where bit `i` corresponds to the
+/// state `(i, e)`.
+#[derive(Debug)]
+struct Levenshtein {
+    /// For each byte value, a bitset with bit `i + 1` set if and only if the
+    /// byte at position `i` in the fixed string is equal to that byte value.
+    /// That is, for each byte, the set of states that are reachable by
+    /// matching that byte.
+    masks: [u64; 256],
+    /// The length of the fixed string.
+    len: usize,
+}
+
+impl Levenshtein {
+    /// The maximum length of a string that this automaton can be built for.
+    /// One bit is reserved for the start state.
+    const MAX_LEN: usize = 64;
+
+    /// Build a new Levenshtein automaton for the given string.
+    ///
+    /// Since every known label is lowercase, ASCII letters in the string
+    /// given are lowercased. This way, `MINUTS` gets the same suggestions as
+    /// `minuts`.
+    ///
+    /// The length of the string given must be less than `MAX_LEN`.
+    fn new(fixed: &[u8]) -> Levenshtein {
+        assert!(fixed.len() < Levenshtein::MAX_LEN);
+        let mut masks = [0u64; 256];
+        for (i, &byte) in fixed.iter().enumerate() {
+            masks[usize::from(byte.to_ascii_lowercase())] |= 1 << (i + 1);
+        }
+        Levenshtein { masks, len: fixed.len() }
+    }
+
+    /// Returns the edit distance between the fixed string in this automaton
+    /// and the `candidate` given. If the distance exceeds `max`, then `None`
+    /// is returned.
+    ///
+    /// `max` must not exceed `MAX_DISTANCE`.
+    fn distance(&self, candidate: &[u8], max: usize) -> Option<usize> {
+        assert!(max <= MAX_DISTANCE);
+        // All bits for the states `0..=len`.
+        let valid = u64::MAX >> (63 - self.len);
+        // Initially, the only state with zero edits is the start state. But
+        // with `e` edits, we can skip (i.e., delete) up to `e` bytes from
+        // the fixed string.
+        let mut rows = [0u64; MAX_DISTANCE + 1];
+        for (e, row) in rows.iter_mut().enumerate().take(max + 1) {
+            *row = ((1u64 << (e + 1)) - 1) & valid;
+        }
+        for &byte in candidate {
+            let mask = self.masks[usize::from(byte)];
+            let mut prev_old = rows[0];
+            rows[0] = (rows[0] << 1) & mask;
+            for e in 1..=max {
+                let old = rows[e];
+                rows[e] = ((old << 1) & mask)
+                    // Insertion: consume a byte from the candidate without
+                    // advancing in the fixed string.
+                    | prev_old
+                    // Substitution: consume a byte from both.
+                    | (prev_old << 1)
+                    // Deletion: advance in the fixed string without
+                    // consuming a byte from the candidate.
+                    | (rows[e - 1] << 1);
+                rows[e] &= valid;
+                prev_old = old;
+            }
+            if rows[..=max].iter().all(|&row| row == 0) {
+                return None;
+            }
+        }
+        let accept = 1u64 << self.len;
+        (0..=max).find(|&e| rows[e] & accept != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A simple quadratic implementation of edit distance to compare with.
+    fn naive(a: &[u8], b: &[u8]) -> usize {
+        let mut prev: Vec<usize> = (0..=b.len()).collect();
+        for (i, &x) in a.iter().enumerate() {
+            let mut cur = vec![i + 1];
+            for (j, &y) in b.iter().enumerate() {
+                let sub = prev[j] + usize::from(x != y);
+                cur.push(sub.min(prev[j + 1] + 1).min(cur[j] + 1));
+            }
+            prev = cur;
+        }
+        prev[b.len()]
+    }
+
+    fn labels(unknown: &[u8]) -> Vec<(&'static str, Unit, usize)> {
+        Suggestions::new(unknown)
+            .iter()
+            .map(|sug| (sug.label(), sug.unit(), sug.distance()))
+            .collect()
+    }
+
+    #[test]
+    fn agrees_with_naive() {
+        let long = [b's'; 63];
+        let unknowns: &[&[u8]] = &[
+            b"minuts", b"secnds", b"hrs", b"xyz", b"mllis", b"", b"yeras",
+            &long,
+        ];
+        for &unknown in unknowns {
+            let lev = Levenshtein::new(unknown);
+            for &(label, _) in LABELS {
+                let expected = naive(unknown, label.as_bytes());
+                let got = lev.distance(label.as_bytes(), MAX_DISTANCE);
+                if expected <= MAX_DISTANCE {
+                    assert_eq!(got, Some(expected), "{unknown:?} {label:?}");
+                } else {
+                    assert_eq!(got, None, "{unknown:?} {label:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn suggestions() {
+        assert_eq!(
+            labels(b"minuts"),
+            vec![
+                ("minutes", Unit::Minute, 1),
+                ("minute", Unit::Minute, 1),
+                ("mins", Unit::Minute, 2),
+            ],
+        );
+        assert_eq!(
+            labels(b"secnds"),
+            vec![
+                ("seconds", Unit::Second, 1),
+                ("second", Unit::Second, 2),
+                ("secs", Unit::Second, 2),
+            ],
+        );
+        assert_eq!(
+            labels(b"hors"),
+            vec![("hours", Unit::Hour, 1), ("hrs", Unit::Hour, 1)],
+        );
+        assert_eq!(labels(b"MINUTS"), labels(b"minuts"));
+        assert_eq!(labels(b"x"), vec![]);
+        assert_eq!(labels(b"zebras"), vec![]);
+    }
+
+    #[test]
+    fn display() {
+        let sugs = Suggestions::new(b"minuts");
+        assert_eq!(sugs.to_string(), r#""minutes", "minute" or "mins""#);
+        let sugs = Suggestions::new(b"hors");
+        assert_eq!(sugs.to_string(), r#""hours" or "hrs""#);
+    }
+}
@@ -0,0 +1,189 @@
+/*!
+A generator for synthetic sets of unit designator labels.
+
+Every technique in this crate is tuned for (and benchmarked on) the one real
+set of 56 labels in [`LABELS`](crate::LABELS). This generates sets of made up
+labels of any size, so that the techniques can be compared as the number of
+labels grows. See the `scaling-*` benchmarks.
+
+The labels generated consist only of the ASCII lowercase letters `a-z`, and
+so every technique can recognize them. Generation is deterministic: the same
+configuration always produces the same labels.
+
+This file is also compiled into the build script (which generates code for
+each of the synthetic sets used by the benchmarks), and so it must not refer
+to anything else in this crate except [`Rng`], which the build script
+includes too.
+*/
+
+use crate::rng::Rng;
+
+/// A configuration for generating a synthetic set of labels.
+///
+/// # Example
+///
+/// ```
+/// use duration_unit_lookup::synthetic::Config;
+///
+/// let labels = Config::new(100).lengths(2, 8).shared_prefix(0.5).generate();
+/// assert_eq!(labels.len(), 100);
+/// assert!(labels.iter().all(|label| (2..=8).contains(&label.len())));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Config {
+    count: usize,
+    min_len: usize,
+    max_len: usize,
+    shared_prefix: f64,
+    seed: u64,
+}
+
+impl Config {
+    /// Create a new configuration for generating `count` distinct labels.
+    ///
+    /// By default, labels are between 1 and 12 bytes long (like the real
+    /// labels) and half of them share a prefix with some other label.
+    pub fn new(count: usize) -> Config {
+        Config {
+            count,
+            min_len: 1,
+            max_len: 12,
+            shared_prefix: 0.5,
+            seed: Rng::DEFAULT_SEED,
+        }
+    }
+
+    /// Set the range of label lengths, in bytes.
+    ///
+    /// The length of each label is chosen uniformly at random from
+    /// `min..=max`. This panics if `min` is zero or if `min > max`.
+    pub fn lengths(mut self, min: usize, max: usize) -> Config {
+        assert!(min >= 1 && min <= max, "invalid label lengths {min}..={max}");
+        self.min_len = min;
+        self.max_len = max;
+        self
+    }
+
+    /// Set the probability that a label extends a prefix of a label
+    /// generated before it.
+    ///
+    /// At `0.0`, every label is made of random letters, and so labels rarely
+    /// share more than their first letter. At `1.0`, nearly every label
+    /// shares a prefix with another one, which is closer to real keyword
+    /// sets like `min`, `mins`, `minute` and `minutes`. This panics if the
+    /// probability given isn't in the range `0.0..=1.0`.
+    pub fn shared_prefix(mut self, probability: f64) -> Config {
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "invalid shared prefix probability {probability}",
+        );
+        self.shared_prefix = probability;
+        self
+    }
+
+    /// Set the seed of the random number generator.
+    ///
+    /// Different seeds produce different labels with the same
+    /// characteristics.
+    pub fn seed(mut self, seed: u64) -> Config {
+        self.seed = seed;
+        self
+    }
+
+    /// Generate the labels.
+    ///
+    /// The labels are returned in the order in which they were generated,
+    /// and are all distinct. This panics if there aren't enough distinct
+    /// labels with the configured lengths.
+    pub fn generate(&self) -> Vec<String> {
+        let mut rng = Rng::new(self.seed);
+        let mut labels: Vec<String> = Vec::with_capacity(self.count);
+        let mut seen = std::collections::HashSet::new();
+        let mut attempts = 0;
+        while labels.len() < self.count {
+            attempts += 1;
+            assert!(
+                attempts <= 1_000 * self.count.max(1),
+                "could not generate {} distinct labels of length {}..={}",
+                self.count,
+                self.min_len,
+                self.max_len,
+            );
+
+            let len =
+                self.min_len + rng.below(self.max_len - self.min_len + 1);
+            let mut label = String::with_capacity(len);
+            if !labels.is_empty() && rng.probability() < self.shared_prefix {
+                let other = &labels[rng.below(labels.len())];
+                // Always leave room for at least one more letter, so that
+                // the new label isn't just a prefix of `other`. Otherwise,
+                // share at least half of what we can, since real labels
+                // tend to share long prefixes (like `minute` and `minutes`).
+                let max = other.len().min(len - 1);
+                if max > 0 {
+                    label.push_str(&other[..max - rng.below(max.div_ceil(2))]);
+                }
+            }
+            while label.len() < len {
+                label.push(char::from(b'a' + rng.below(26) as u8));
+            }
+            if seen.insert(label.clone()) {
+                labels.push(label);
+            }
+        }
+        labels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the number of distinct non-empty prefixes of all labels, i.e.,
+    /// the number of non-root nodes in a trie of the labels.
+    fn prefixes(labels: &[String]) -> usize {
+        let mut prefixes = std::collections::HashSet::new();
+        for label in labels {
+            for end in 1..=label.len() {
+                prefixes.insert(&label[..end]);
+            }
+        }
+        prefixes.len()
+    }
+
+    #[test]
+    fn generate() {
+        for count in [0, 1, 10, 1000] {
+            let labels = Config::new(count).lengths(3, 6).generate();
+            assert_eq!(labels.len(), count);
+            let distinct: std::collections::HashSet<&String> =
+                labels.iter().collect();
+            assert_eq!(distinct.len(), count);
+            for label in labels.iter() {
+                assert!((3..=6).contains(&label.len()), "{label:?}");
+                assert!(label.bytes().all(|b| b.is_ascii_lowercase()));
+            }
+        }
+    }
+
+    #[test]
+    fn deterministic() {
+        let config = Config::new(100);
+        assert_eq!(config.generate(), config.generate());
+        assert_ne!(config.generate(), config.clone().seed(1).generate());
+    }
+
+    #[test]
+    fn shared_prefix() {
+        let config = Config::new(500).lengths(4, 10);
+        let sparse = prefixes(&config.clone().shared_prefix(0.0).generate());
+        let dense = prefixes(&config.clone().shared_prefix(1.0).generate());
+        assert!(dense * 2 < sparse, "dense: {dense}, sparse: {sparse}");
+    }
+
+    #[test]
+    #[should_panic(expected = "could not generate")]
+    fn too_many() {
+        Config::new(27).lengths(1, 1).generate();
+    }
+}
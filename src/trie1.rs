@@ -74,7 +74,13 @@ type TrieNodeId = u8;
 /// it makes the alphabet bigger which makes the trie bigger. So, like the
 /// Unicode case, it might just make sense to use lowercase needles and then
 /// write a new `find_lowercase` routine that lowercases each byte it sees. For
-/// ASCII, this is very cheap.
+/// ASCII, this is very cheap. That's what `Trie::find_lowercase` does, and
+/// it's how every trie in this crate supports ASCII case insensitive lookups.
+/// To avoid a second copy of the search loop, both `find` and
+/// `find_lowercase` call a `find_by` routine that maps each byte before
+/// looking it up, with the identity function and `u8::to_ascii_lowercase`,
+/// respectively. Since it's always inlined, the identity function costs
+/// nothing.
 ///
 /// # Alternatives
 ///
@@ -190,7 +196,7 @@ impl<
                     // If this assertion fails, then the Trie needs more
                     // capacity.
                     assert!(trie.len < NODE_CAPACITY);
-                    next_id = trie.len as u8;
+                    next_id = trie.len as TrieNodeId;
                     trie.len += 1;
                 }
                 let equiv_id = trie.alphabet.equiv_id(byte);
@@ -219,13 +225,34 @@ impl<
     pub(crate) fn find<'h>(
         &self,
         haystack: &'h [u8],
+    ) -> Option<(V, &'h [u8])> {
+        self.find_by(haystack, |byte| byte)
+    }
+
+    /// Like `find`, but ASCII case insensitive. See the docs of `Trie` for
+    /// how this works.
+    #[inline(always)]
+    pub(crate) fn find_lowercase<'h>(
+        &self,
+        haystack: &'h [u8],
+    ) -> Option<(V, &'h [u8])> {
+        self.find_by(haystack, |byte| byte.to_ascii_lowercase())
+    }
+
+    /// Like `find`, but every byte of `haystack` is passed through `map`
+    /// before it's looked up in the trie.
+    #[inline(always)]
+    fn find_by<'h>(
+        &self,
+        haystack: &'h [u8],
+        map: impl Fn(u8) -> u8,
     ) -> Option<(V, &'h [u8])> {
         let mut node_id = Self::ROOT_ID;
         let mut found =
             self.nodes[node_id as usize].value.map(|v| (v, haystack));
         let mut it = haystack.iter();
         while let Some(&byte) = it.next() {
-            node_id = self.next(node_id, byte);
+            node_id = self.next(node_id, map(byte));
             if node_id == Self::FAIL_ID {
                 break;
             }
@@ -67,7 +67,7 @@ impl<
                     // If this assertion fails, then the Trie needs more
                     // capacity.
                     assert!(trie.len < NODE_CAPACITY);
-                    next_id = trie.len as u8;
+                    next_id = trie.len as TrieNodeId;
                     trie.len += 1;
                 }
                 let equiv_id = trie.alphabet.equiv_id(byte);
@@ -88,11 +88,32 @@ impl<
     }
 
     #[inline(always)]
-    pub(crate) fn find<'h>(&self, haystack: &'h [u8]) -> Option<(V, usize)> {
+    pub(crate) fn find(&self, haystack: &[u8]) -> Option<(V, usize)> {
+        self.find_by(haystack, |byte| byte)
+    }
+
+    /// Like `find`, but ASCII case insensitive. See the docs of
+    /// `trie1::Trie` for how this works.
+    #[inline(always)]
+    pub(crate) fn find_lowercase(
+        &self,
+        haystack: &[u8],
+    ) -> Option<(V, usize)> {
+        self.find_by(haystack, |byte| byte.to_ascii_lowercase())
+    }
+
+    /// Like `find`, but every byte of `haystack` is passed through `map`
+    /// before it's looked up in the trie.
+    #[inline(always)]
+    fn find_by(
+        &self,
+        haystack: &[u8],
+        map: impl Fn(u8) -> u8,
+    ) -> Option<(V, usize)> {
         let mut node_id = Self::ROOT_ID;
         let mut found = self.nodes[node_id as usize].value.map(|v| (v, 0));
         for (i, &byte) in haystack.iter().enumerate() {
-            node_id = self.next(node_id, byte);
+            node_id = self.next(node_id, map(byte));
             if node_id == Self::FAIL_ID {
                 break;
             }
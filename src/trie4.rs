@@ -206,7 +206,28 @@ impl<
     }
 
     #[inline(always)]
-    pub(crate) fn find<'h>(&self, haystack: &'h [u8]) -> Option<(V, usize)> {
+    pub(crate) fn find(&self, haystack: &[u8]) -> Option<(V, usize)> {
+        self.find_by(haystack, |byte| byte)
+    }
+
+    /// Like `find`, but ASCII case insensitive. See the docs of
+    /// `trie1::Trie` for how this works.
+    #[inline(always)]
+    pub(crate) fn find_lowercase(
+        &self,
+        haystack: &[u8],
+    ) -> Option<(V, usize)> {
+        self.find_by(haystack, |byte| byte.to_ascii_lowercase())
+    }
+
+    /// Like `find`, but every byte of `haystack` is passed through `map`
+    /// before it's looked up in the trie.
+    #[inline(always)]
+    fn find_by(
+        &self,
+        haystack: &[u8],
+        map: impl Fn(u8) -> u8,
+    ) -> Option<(V, usize)> {
         let mut node_id = self.root_node_id;
         let mut found = if node_id <= self.max_match_node_id {
             Some((node_id, 0))
@@ -214,7 +235,7 @@ impl<
             None
         };
         for (i, &byte) in haystack.iter().enumerate() {
-            let equiv_id = self.alphabet.equiv_id(byte);
+            let equiv_id = self.alphabet.equiv_id(map(byte));
             node_id = self.transitions[node_id as usize + equiv_id as usize];
             if node_id <= self.max_match_node_id {
                 if node_id == Self::FAIL_ID {
@@ -193,10 +193,24 @@ impl<
     }
 
     #[inline(always)]
-    pub(crate) fn find<'h>(&self, haystack: &'h [u8]) -> Option<V> {
+    pub(crate) fn find(&self, haystack: &[u8]) -> Option<V> {
+        self.find_by(haystack, |byte| byte)
+    }
+
+    /// Like `find`, but ASCII case insensitive. See the docs of
+    /// `trie1::Trie` for how this works.
+    #[inline(always)]
+    pub(crate) fn find_lowercase(&self, haystack: &[u8]) -> Option<V> {
+        self.find_by(haystack, |byte| byte.to_ascii_lowercase())
+    }
+
+    /// Like `find`, but every byte of `haystack` is passed through `map`
+    /// before it's looked up in the trie.
+    #[inline(always)]
+    fn find_by(&self, haystack: &[u8], map: impl Fn(u8) -> u8) -> Option<V> {
         let mut node_id = self.root_node_id;
         for &byte in haystack.iter() {
-            let equiv_id = self.alphabet.equiv_id(byte);
+            let equiv_id = self.alphabet.equiv_id(map(byte));
             node_id = self.transitions[node_id as usize + equiv_id as usize];
         }
         if node_id <= self.max_match_node_id && node_id != Self::FAIL_ID {